@@ -0,0 +1,98 @@
+use decorum::R64;
+use nalgebra::Point3;
+use plexus::buffer::MeshBuffer;
+use plexus::graph::MeshGraph;
+use plexus::prelude::*;
+use plexus::primitive::generate::Position;
+use plexus::primitive::sphere::UvSphere;
+use plexus::primitive::BoundedPolygon;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+type E3 = Point3<f64>;
+
+// Counts allocated bytes so that the peak memory of each construction path can
+// be compared. Timing is measured separately by the `collect` bench, so that
+// the allocator does not affect it. Unlike RSS, this is independent of the
+// platform and the behavior of the system allocator.
+struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+fn grow(size: usize) {
+    let allocated = ALLOCATED.fetch_add(size, Ordering::SeqCst) + size;
+    PEAK.fetch_max(allocated, Ordering::SeqCst);
+}
+
+fn shrink(size: usize) {
+    ALLOCATED.fetch_sub(size, Ordering::SeqCst);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            grow(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, size: usize) -> *mut u8 {
+        let pointer = System.realloc(pointer, layout, size);
+        if !pointer.is_null() {
+            if size > layout.size() {
+                grow(size - layout.size());
+            }
+            else {
+                shrink(layout.size() - size);
+            }
+        }
+        pointer
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// Gets the peak number of bytes allocated while executing the given function in
+// excess of those allocated beforehand.
+fn peak_allocation<T>(f: impl FnOnce() -> T) -> usize {
+    let base = ALLOCATED.load(Ordering::SeqCst);
+    PEAK.store(base, Ordering::SeqCst);
+    let output = f();
+    let peak = PEAK.load(Ordering::SeqCst);
+    drop(output);
+    peak - base
+}
+
+fn streaming(sphere: &UvSphere) -> MeshGraph<E3> {
+    sphere
+        .polygons::<Position<Point3<R64>>>()
+        .collect::<MeshGraph<E3>>()
+}
+
+fn buffered(sphere: &UvSphere) -> MeshGraph<E3> {
+    let buffer: MeshBuffer<BoundedPolygon<usize>, Point3<R64>> =
+        sphere.polygons::<Position<Point3<R64>>>().collect();
+    MeshGraph::<E3>::try_from(buffer).unwrap()
+}
+
+fn main() {
+    // Compare the peak allocation of each path when collecting a dense sphere.
+    // This is measured once, because it is deterministic.
+    let sphere = UvSphere::new(2048, 2048);
+    let streaming_peak = peak_allocation(|| streaming(&sphere));
+    let buffered_peak = peak_allocation(|| buffered(&sphere));
+    println!("collect/streaming: {streaming_peak} bytes at peak");
+    println!("collect/buffered: {buffered_peak} bytes at peak");
+    assert!(
+        streaming_peak < buffered_peak,
+        "streaming collection allocates more than buffered collection",
+    );
+}
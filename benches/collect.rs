@@ -0,0 +1,36 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use decorum::R64;
+use nalgebra::Point3;
+use plexus::buffer::MeshBuffer;
+use plexus::graph::MeshGraph;
+use plexus::prelude::*;
+use plexus::primitive::generate::Position;
+use plexus::primitive::sphere::UvSphere;
+use plexus::primitive::BoundedPolygon;
+
+type E3 = Point3<f64>;
+
+fn streaming(sphere: &UvSphere) -> MeshGraph<E3> {
+    sphere
+        .polygons::<Position<Point3<R64>>>()
+        .collect::<MeshGraph<E3>>()
+}
+
+fn buffered(sphere: &UvSphere) -> MeshGraph<E3> {
+    let buffer: MeshBuffer<BoundedPolygon<usize>, Point3<R64>> =
+        sphere.polygons::<Position<Point3<R64>>>().collect();
+    MeshGraph::<E3>::try_from(buffer).unwrap()
+}
+
+fn benchmark(criterion: &mut Criterion) {
+    // The peak allocation of each path is compared by the `allocate` bench.
+    let sphere = UvSphere::new(256, 128);
+    let mut group = criterion.benchmark_group("collect");
+    group.sample_size(10);
+    group.bench_function("streaming", |bencher| bencher.iter(|| streaming(&sphere)));
+    group.bench_function("buffered", |bencher| bencher.iter(|| buffered(&sphere)));
+    group.finish();
+}
+
+criterion_group!(benches, benchmark);
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use decorum::R64;
+use nalgebra::Point3;
+use plexus::graph::{MeshGraph, VertexKey};
+use plexus::prelude::*;
+use plexus::primitive::generate::Position;
+use plexus::primitive::sphere::UvSphere;
+
+type E3 = Point3<f64>;
+
+fn sphere() -> (MeshGraph<E3>, Vec<VertexKey>) {
+    let graph: MeshGraph<E3> = UvSphere::new(128, 64)
+        .polygons::<Position<Point3<R64>>>()
+        .collect();
+    // Gather every other vertex in reverse order.
+    let keys = graph.vertices().keys().step_by(2).collect::<Vec<_>>();
+    let keys = keys.into_iter().rev().collect();
+    (graph, keys)
+}
+
+fn benchmark(criterion: &mut Criterion) {
+    let (graph, keys) = sphere();
+    let mut group = criterion.benchmark_group("gather");
+    group.bench_function("naive", |bencher| {
+        bencher.iter(|| {
+            keys.iter()
+                .map(|key| *graph.vertex(*key).unwrap().position())
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("gather_positions", |bencher| {
+        bencher.iter(|| graph.gather_positions(&keys).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, benchmark);
+criterion_main!(benches);
@@ -2,8 +2,23 @@ use plexus::integration::nalgebra;
 
 use lazy_static::lazy_static;
 use nalgebra::{Isometry3, Matrix4, Orthographic3, Perspective3, Point3, Vector3};
+use std::f32::consts::FRAC_PI_2;
 use wgpu::SwapChainDescriptor;
 
+use crate::input::InputState;
+
+// The minimum angle between the view vector and the poles. The view vector
+// must never be parallel to the up vector or `look_at_rh` produces NaNs.
+const POLE_EPSILON: f32 = 1e-3;
+
+// Rates at which input deltas are applied by `OrbitController::update`.
+// Rotation is in radians per pixel, panning is in proportion of the distance
+// from the target per pixel, and zooming is in proportion of the distance from
+// the target per line.
+const ROTATION_RATE: f32 = 0.01;
+const PAN_RATE: f32 = 0.002;
+const ZOOM_RATE: f32 = 0.1;
+
 lazy_static! {
     #[rustfmt::skip]
     static ref OPENGL_TO_WGPU_TRANSFORM: Matrix4<f32> = Matrix4::new(
@@ -48,6 +63,59 @@ impl Camera {
         self.view = Isometry3::look_at_rh(from, to, &Vector3::y());
     }
 
+    /// Positions the camera such that an axis-aligned bounding box fills the
+    /// view.
+    ///
+    /// The camera keeps its view direction and is moved along it to frame the
+    /// bounding sphere of the box given by its minimum and maximum points. The
+    /// near and far planes are set to enclose the box. Perspective
+    /// projections fit the sphere within the narrower of the vertical and
+    /// horizontal fields of view, while orthographic projections are resized
+    /// to fit the sphere and keep their aspect ratio.
+    pub fn fit(&mut self, aabb: (Point3<f32>, Point3<f32>)) {
+        let (min, max) = aabb;
+        let center = nalgebra::center(&min, &max);
+        let radius = ((max - min).norm() * 0.5).max(f32::EPSILON);
+        let forward = self.view.inverse_transform_vector(&-Vector3::z());
+        let distance = match self.projection {
+            Projection::Perspective(ref perspective) => {
+                let vertical = perspective.fovy() * 0.5;
+                let horizontal = (vertical.tan() * perspective.aspect()).atan();
+                radius / vertical.min(horizontal).sin()
+            }
+            Projection::Orthographic(ref mut orthographic) => {
+                let aspect = (orthographic.right() - orthographic.left())
+                    / (orthographic.top() - orthographic.bottom());
+                let (width, height) = if aspect >= 1.0 {
+                    (radius * aspect, radius)
+                }
+                else {
+                    (radius, radius / aspect)
+                };
+                orthographic.set_left_and_right(-width, width);
+                orthographic.set_bottom_and_top(-height, height);
+                radius * 2.0
+            }
+        };
+        self.look_at(&(center - (forward * distance)), &center);
+        self.set_near_and_far((distance - radius).max(distance * 1e-3), distance + radius);
+    }
+
+    pub fn view(&self) -> &Isometry3<f32> {
+        &self.view
+    }
+
+    pub fn set_near_and_far(&mut self, near: f32, far: f32) {
+        match self.projection {
+            Projection::Perspective(ref mut perspective) => {
+                perspective.set_znear_and_zfar(near, far);
+            }
+            Projection::Orthographic(ref mut orthographic) => {
+                orthographic.set_znear_and_zfar(near, far);
+            }
+        }
+    }
+
     pub fn reproject(&mut self, descriptor: &SwapChainDescriptor) {
         match self.projection {
             Projection::Perspective(ref mut perspective) => {
@@ -78,3 +146,200 @@ impl From<Projection> for Camera {
         }
     }
 }
+
+/// Orbits a camera about a target point.
+///
+/// The camera is positioned on a sphere about the target by yaw (about the Y
+/// axis) and pitch (above or below the XZ plane) angles in radians and a
+/// distance. Pitch is always clamped short of the poles.
+#[derive(Clone, Debug)]
+pub struct OrbitController {
+    pub target: Point3<f32>,
+    distance: f32,
+    yaw: f32,
+    pitch: f32,
+    min_pitch: f32,
+    max_pitch: f32,
+    min_distance: f32,
+    max_distance: f32,
+}
+
+impl OrbitController {
+    pub fn new(target: Point3<f32>, distance: f32) -> Self {
+        let mut controller = OrbitController {
+            target,
+            distance,
+            yaw: 0.0,
+            pitch: 0.0,
+            min_pitch: -FRAC_PI_2,
+            max_pitch: FRAC_PI_2,
+            min_distance: 0.1,
+            max_distance: f32::MAX,
+        };
+        controller.clamp();
+        controller
+    }
+
+    /// Creates a controller with its eye at the given point.
+    pub fn look_at(eye: Point3<f32>, target: Point3<f32>) -> Self {
+        let offset = eye - target;
+        let distance = offset.norm();
+        let mut controller = OrbitController::new(target, distance);
+        if distance > 0.0 {
+            controller.yaw = offset.x.atan2(offset.z);
+            controller.pitch = (offset.y / distance).clamp(-1.0, 1.0).asin();
+            controller.clamp();
+        }
+        controller
+    }
+
+    /// Sets the limits of pitch in radians.
+    ///
+    /// Limits are clamped short of the poles.
+    pub fn set_pitch_limits(&mut self, min: f32, max: f32) {
+        self.min_pitch = min;
+        self.max_pitch = max;
+        self.clamp();
+    }
+
+    /// Sets the limits of the distance from the target.
+    pub fn set_distance_limits(&mut self, min: f32, max: f32) {
+        self.min_distance = min;
+        self.max_distance = max;
+        self.clamp();
+    }
+
+    /// Rotates about the target by yaw (`dx`) and pitch (`dy`) in radians.
+    pub fn rotate(&mut self, dx: f32, dy: f32) {
+        self.yaw += dx;
+        self.pitch += dy;
+        self.clamp();
+    }
+
+    /// Translates the target in the view plane.
+    ///
+    /// Translation is proportional to the distance from the target, so the
+    /// apparent motion is similar at any distance.
+    pub fn pan(&mut self, dx: f32, dy: f32) {
+        let forward = (self.target - self.eye()).normalize();
+        let right = forward.cross(&Vector3::y()).normalize();
+        let up = right.cross(&forward);
+        self.target += ((right * dx) + (up * dy)) * self.distance;
+    }
+
+    /// Moves toward (positive `delta`) or away from (negative `delta`) the
+    /// target.
+    pub fn zoom(&mut self, delta: f32) {
+        self.distance -= delta;
+        self.clamp();
+    }
+
+    pub fn eye(&self) -> Point3<f32> {
+        let (sy, cy) = self.yaw.sin_cos();
+        let (sp, cp) = self.pitch.sin_cos();
+        self.target + (Vector3::new(cp * sy, sp, cp * cy) * self.distance)
+    }
+
+    pub fn apply(&self, camera: &mut Camera) {
+        camera.look_at(&self.eye(), &self.target);
+    }
+
+    /// Applies pointer input and then the controller to a camera.
+    ///
+    /// Dragging rotates about the target, panning translates the target, and
+    /// scrolling zooms toward (up) or away from (down) the target in
+    /// proportion to the distance from the target. The input is not cleared.
+    pub fn update(&mut self, camera: &mut Camera, input: &InputState) {
+        let (dx, dy) = input.drag();
+        self.rotate(-dx * ROTATION_RATE, dy * ROTATION_RATE);
+        let (dx, dy) = input.pan();
+        self.pan(-dx * PAN_RATE, dy * PAN_RATE);
+        self.zoom(input.scroll() * ZOOM_RATE * self.distance);
+        self.apply(camera);
+    }
+
+    fn clamp(&mut self) {
+        let limit = FRAC_PI_2 - POLE_EPSILON;
+        self.pitch = self
+            .pitch
+            .clamp(self.min_pitch.max(-limit), self.max_pitch.min(limit));
+        self.distance = self.distance.clamp(self.min_distance, self.max_distance);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use plexus::integration::nalgebra::Point3;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use crate::camera::{Camera, OrbitController, Projection};
+
+    #[test]
+    fn rotate_yaw() {
+        let mut controller = OrbitController::new(Point3::new(1.0, 0.0, 0.0), 2.0);
+        controller.rotate(0.3, 0.2);
+        let a = controller.eye() - controller.target;
+        controller.rotate(PI, 0.0);
+        let b = controller.eye() - controller.target;
+
+        assert!((a.x + b.x).abs() < 1e-5);
+        assert!((a.z + b.z).abs() < 1e-5);
+        assert!((a.y - b.y).abs() < 1e-5);
+    }
+
+    #[test]
+    fn look_at_eye() {
+        let eye = Point3::new(1.0, 2.0, -3.0);
+        let target = Point3::new(0.5, 0.0, 1.0);
+        let controller = OrbitController::look_at(eye, target);
+        assert!((controller.eye() - eye).norm() < 1e-5);
+        assert!((controller.target - target).norm() < 1e-5);
+    }
+
+    #[test]
+    fn clamp_pitch_and_distance() {
+        let mut controller = OrbitController::new(Point3::origin(), 2.0);
+        controller.set_distance_limits(1.0, 4.0);
+        for _ in 0..8 {
+            controller.zoom(0.5);
+            assert!((controller.eye() - controller.target).norm() >= 1.0 - 1e-5);
+        }
+        controller.rotate(0.0, PI);
+        let eye = controller.eye();
+        assert!(eye.coords.iter().all(|x| x.is_finite()));
+        assert!(eye.x.abs() + eye.z.abs() > 0.0);
+        controller.rotate(0.0, -FRAC_PI_2 * 4.0);
+        assert!(controller.eye().y < 0.0);
+        assert!(controller.eye().x.abs() + controller.eye().z.abs() > 0.0);
+    }
+
+    #[test]
+    fn fit_aabb() {
+        let min = Point3::new(-1.0, 2.0, 0.0);
+        let max = Point3::new(3.0, 4.0, 1.0);
+        let corners = (0..8)
+            .map(|i| {
+                Point3::new(
+                    [min.x, max.x][i & 1],
+                    [min.y, max.y][(i >> 1) & 1],
+                    [min.z, max.z][(i >> 2) & 1],
+                )
+            })
+            .collect::<Vec<_>>();
+        for projection in [
+            Projection::perspective(2.0, FRAC_PI_4, 0.1, 8.0),
+            Projection::orthographic(-1.0, 1.0, -2.0, 2.0, -8.0, 8.0),
+        ] {
+            let mut camera = Camera::from(projection);
+            camera.fit((min, max));
+            let transform = camera.transform();
+            for corner in corners.iter() {
+                let position = transform * corner.to_homogeneous();
+                let position = position.xyz() / position.w;
+                assert!(position.x.abs() <= 1.0 + 1e-5);
+                assert!(position.y.abs() <= 1.0 + 1e-5);
+                assert!(position.z >= -1e-5 && position.z <= 1.0 + 1e-5);
+            }
+        }
+    }
+}
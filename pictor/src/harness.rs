@@ -37,6 +37,7 @@ impl Executor {
 pub enum Reaction {
     #[default]
     Continue,
+    Redraw,
     Abort,
 }
 
@@ -118,11 +119,15 @@ where
                         .device
                         .create_swap_chain(&renderer.surface, &renderer.swap_chain_descriptor);
                 }
-                _ => {
-                    if let Reaction::Abort = application.react(event) {
+                _ => match application.react(event) {
+                    Reaction::Abort => {
                         *reaction = Exit;
                     }
-                }
+                    Reaction::Redraw => {
+                        renderer.window.request_redraw();
+                    }
+                    Reaction::Continue => {}
+                },
             },
             _ => {}
         }
@@ -0,0 +1,97 @@
+use winit::event::{ElementState, MouseButton, MouseScrollDelta, WindowEvent};
+
+// The approximate number of pixels scrolled by one line of a mouse wheel.
+// This normalizes pixel deltas (e.g., from touchpads) into lines.
+const PIXELS_PER_LINE: f32 = 20.0;
+
+/// Pointer input accumulated from window events.
+///
+/// The cursor is dragged while the left mouse button is pressed and panned
+/// while the right or middle mouse button is pressed. Deltas are in pixels
+/// (dragging and panning) and lines (scrolling) and accumulate until the state
+/// is cleared, typically once per frame after the state has been consumed.
+#[derive(Clone, Debug, Default)]
+pub struct InputState {
+    cursor: Option<(f32, f32)>,
+    is_dragging: bool,
+    is_panning: bool,
+    drag: (f32, f32),
+    pan: (f32, f32),
+    scroll: f32,
+}
+
+impl InputState {
+    /// Updates the state from a window event.
+    ///
+    /// Returns `true` if any deltas changed.
+    pub fn react(&mut self, event: &WindowEvent) -> bool {
+        match *event {
+            WindowEvent::CursorMoved { position, .. } => {
+                let position = (position.x as f32, position.y as f32);
+                let mut changed = false;
+                if let Some(cursor) = self.cursor {
+                    let (dx, dy) = (position.0 - cursor.0, position.1 - cursor.1);
+                    if self.is_dragging {
+                        self.drag = (self.drag.0 + dx, self.drag.1 + dy);
+                        changed = true;
+                    }
+                    if self.is_panning {
+                        self.pan = (self.pan.0 + dx, self.pan.1 + dy);
+                        changed = true;
+                    }
+                }
+                self.cursor = Some(position);
+                changed
+            }
+            WindowEvent::CursorLeft { .. } => {
+                self.cursor = None;
+                false
+            }
+            WindowEvent::MouseInput { state, button, .. } => {
+                let is_pressed = state == ElementState::Pressed;
+                match button {
+                    MouseButton::Left => {
+                        self.is_dragging = is_pressed;
+                    }
+                    MouseButton::Right | MouseButton::Middle => {
+                        self.is_panning = is_pressed;
+                    }
+                    _ => {}
+                }
+                false
+            }
+            WindowEvent::MouseWheel { delta, .. } => {
+                self.scroll += match delta {
+                    MouseScrollDelta::LineDelta(_, y) => y,
+                    MouseScrollDelta::PixelDelta(position) => position.y as f32 / PIXELS_PER_LINE,
+                };
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Gets the distance in pixels that the cursor has been dragged.
+    pub fn drag(&self) -> (f32, f32) {
+        self.drag
+    }
+
+    /// Gets the distance in pixels that the cursor has been panned.
+    pub fn pan(&self) -> (f32, f32) {
+        self.pan
+    }
+
+    /// Gets the number of lines scrolled. Scrolling up is positive.
+    pub fn scroll(&self) -> f32 {
+        self.scroll
+    }
+
+    /// Clears the accumulated deltas.
+    ///
+    /// The cursor and pressed buttons are retained.
+    pub fn clear(&mut self) {
+        self.drag = (0.0, 0.0);
+        self.pan = (0.0, 0.0);
+        self.scroll = 0.0;
+    }
+}
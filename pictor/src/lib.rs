@@ -1,7 +1,9 @@
 mod camera;
 mod harness;
+mod input;
 pub mod pipeline;
 mod renderer;
 
 pub use crate::camera::*;
 pub use crate::harness::*;
+pub use crate::input::*;
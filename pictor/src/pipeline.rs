@@ -9,34 +9,39 @@ use nalgebra::{Point3, Scalar, Vector3, Vector4};
 use num::{self, One};
 use plexus::buffer::MeshBuffer;
 use plexus::geometry::UnitGeometry;
+use plexus::graph::{FaceKey, GraphData, MeshGraph};
 use plexus::index::Flat3;
+use plexus::prelude::*;
 use rand::distributions::{Distribution, Standard};
 use rand::{self, Rng};
+use std::collections::HashMap;
 use std::f32::consts::FRAC_PI_4;
 use std::hash::{Hash, Hasher};
 use std::mem;
 use theon::adjunct::Extend;
 use wgpu::util::{BufferInitDescriptor, DeviceExt};
 use wgpu::{
-    include_spirv, BindGroup, BindGroupDescriptor, BindGroupEntry, BindGroupLayoutDescriptor,
-    BindGroupLayoutEntry, BindingType, BlendDescriptor, Buffer, BufferAddress, BufferSize,
-    BufferUsage, Color, ColorStateDescriptor, ColorWrite, CommandEncoderDescriptor,
-    CompareFunction, CullMode, DepthStencilStateDescriptor, Extent3d, FrontFace, IndexFormat,
-    InputStepMode, LoadOp, Operations, PipelineLayoutDescriptor, PrimitiveTopology,
-    ProgrammableStageDescriptor, RasterizationStateDescriptor, RenderPassColorAttachmentDescriptor,
-    RenderPassDepthStencilAttachmentDescriptor, RenderPassDescriptor, RenderPipeline,
-    RenderPipelineDescriptor, ShaderStage, SwapChainTexture, TextureDescriptor, TextureDimension,
-    TextureFormat, TextureUsage, TextureView, VertexAttributeDescriptor, VertexBufferDescriptor,
-    VertexFormat, VertexStateDescriptor,
+    include_spirv, BindGroup, BindGroupDescriptor, BindGroupEntry, BindGroupLayout,
+    BindGroupLayoutDescriptor, BindGroupLayoutEntry, BindingType, BlendDescriptor, Buffer,
+    BufferAddress, BufferSize, BufferUsage, Color, ColorStateDescriptor, ColorWrite,
+    CommandEncoderDescriptor, CompareFunction, CullMode, DepthStencilStateDescriptor, Extent3d,
+    FrontFace, IndexFormat, InputStepMode, LoadOp, Operations, PipelineLayoutDescriptor,
+    PrimitiveTopology, ProgrammableStageDescriptor, RasterizationStateDescriptor,
+    RenderPassColorAttachmentDescriptor, RenderPassDepthStencilAttachmentDescriptor,
+    RenderPassDescriptor, RenderPipeline, RenderPipelineDescriptor, ShaderStage, SwapChainTexture,
+    TextureDescriptor, TextureDimension, TextureFormat, TextureUsage, TextureView,
+    VertexAttributeDescriptor, VertexBufferDescriptor, VertexFormat, VertexStateDescriptor,
 };
 use winit::event::{ElementState, KeyboardInput, VirtualKeyCode, WindowEvent};
 use winit::window::WindowBuilder;
 
-use crate::camera::{Camera, Projection};
+use crate::camera::{Camera, OrbitController, Projection};
 use crate::harness::{self, Application, ConfigureStage, Reaction, RenderStage};
+use crate::input::InputState;
 
 use Reaction::Abort;
 use Reaction::Continue;
+use Reaction::Redraw;
 
 #[derive(Clone, Copy, Debug)]
 pub struct Color4<T>(pub Vector4<T>)
@@ -136,22 +141,83 @@ unsafe impl Pod for Vertex {}
 
 unsafe impl Zeroable for Vertex {}
 
+/// Vertex data that can be rendered from a [`MeshGraph`].
+///
+/// Only positions are required. Flat normals are computed for faces when
+/// vertices do not provide normals and the color in [`DrawOptions`] is used
+/// when vertices do not provide colors.
+pub trait RenderVertex {
+    fn position(&self) -> Point3<f32>;
+
+    fn normal(&self) -> Option<Vector3<f32>> {
+        None
+    }
+
+    fn color(&self) -> Option<Color4<f32>> {
+        None
+    }
+}
+
+impl RenderVertex for Point3<f32> {
+    fn position(&self) -> Point3<f32> {
+        *self
+    }
+}
+
+/// Options for rendering a [`MeshGraph`].
+#[derive(Clone, Copy, Debug)]
+pub struct DrawOptions {
+    /// The color of vertices that do not provide a color.
+    pub color: Color4<f32>,
+    /// Whether or not edges are drawn over faces.
+    ///
+    /// The wireframe can also be toggled with the `W` key.
+    pub wireframe: bool,
+    /// The color of the wireframe.
+    pub wireframe_color: Color4<f32>,
+    /// Whether or not faces are shaded flat.
+    ///
+    /// If `true`, then each vertex of a face uses the normal of that face,
+    /// even if vertices provide normals. This is appropriate for faceted
+    /// models with hard surfaces.
+    pub flat: bool,
+}
+
+impl Default for DrawOptions {
+    fn default() -> Self {
+        DrawOptions {
+            color: Color4(Vector4::new(1.0, 0.6, 0.2, 1.0)),
+            wireframe: false,
+            wireframe_color: Color4(Vector4::new(0.0, 0.0, 0.0, 1.0)),
+            flat: false,
+        }
+    }
+}
+
 struct RenderConfiguration {
     camera: Camera,
-    from: Point3<f32>,
+    controller: OrbitController,
     buffer: MeshBuffer<Flat3<u32>, Vertex>,
+    lines: Vec<Vertex>,
+    wireframe: bool,
 }
 
 struct RenderApplication {
     camera: Camera,
-    _viewpoint: Buffer,
+    controller: OrbitController,
+    input: InputState,
+    viewpoint: Buffer,
     transform: Buffer,
     vertices: Buffer,
     indices: Buffer,
+    lines: Buffer,
     depth: TextureView,
     n: u32,
+    m: u32,
+    wireframe: bool,
     bind_group: BindGroup,
     pipeline: RenderPipeline,
+    wireframe_pipeline: RenderPipeline,
 }
 
 impl RenderApplication {
@@ -173,6 +239,90 @@ impl RenderApplication {
             })
             .create_view(&Default::default())
     }
+
+    fn configure_pipeline(
+        stage: &impl ConfigureStage,
+        bind_group_layout: &BindGroupLayout,
+        topology: PrimitiveTopology,
+    ) -> RenderPipeline {
+        let cull_mode = match topology {
+            PrimitiveTopology::TriangleList | PrimitiveTopology::TriangleStrip => CullMode::Back,
+            _ => CullMode::None,
+        };
+        stage
+            .device()
+            .create_render_pipeline(&RenderPipelineDescriptor {
+                label: None,
+                layout: Some(
+                    &stage
+                        .device()
+                        .create_pipeline_layout(&PipelineLayoutDescriptor {
+                            label: None,
+                            bind_group_layouts: &[bind_group_layout],
+                            push_constant_ranges: &[],
+                        }),
+                ),
+                vertex_stage: ProgrammableStageDescriptor {
+                    module: &stage
+                        .device()
+                        .create_shader_module(include_spirv!("shader.spv.vert")),
+                    entry_point: "main",
+                },
+                fragment_stage: Some(ProgrammableStageDescriptor {
+                    module: &stage
+                        .device()
+                        .create_shader_module(include_spirv!("shader.spv.frag")),
+                    entry_point: "main",
+                }),
+                vertex_state: VertexStateDescriptor {
+                    index_format: IndexFormat::Uint32,
+                    vertex_buffers: &[VertexBufferDescriptor {
+                        stride: mem::size_of::<Vertex>() as BufferAddress,
+                        step_mode: InputStepMode::Vertex,
+                        attributes: &[
+                            #[allow(clippy::erasing_op)]
+                            VertexAttributeDescriptor {
+                                format: VertexFormat::Float4,
+                                offset: 0 * 4 * 4,
+                                shader_location: 0,
+                            },
+                            #[allow(clippy::identity_op)]
+                            VertexAttributeDescriptor {
+                                format: VertexFormat::Float4,
+                                offset: 1 * 4 * 4,
+                                shader_location: 1,
+                            },
+                            VertexAttributeDescriptor {
+                                format: VertexFormat::Float4,
+                                offset: 2 * 4 * 4,
+                                shader_location: 2,
+                            },
+                        ],
+                    }],
+                },
+                primitive_topology: topology,
+                rasterization_state: Some(RasterizationStateDescriptor {
+                    front_face: FrontFace::Ccw,
+                    cull_mode,
+                    ..Default::default()
+                }),
+                color_states: &[ColorStateDescriptor {
+                    format: stage.swap_chain_descriptor().format,
+                    color_blend: BlendDescriptor::REPLACE,
+                    alpha_blend: BlendDescriptor::REPLACE,
+                    write_mask: ColorWrite::ALL,
+                }],
+                depth_stencil_state: Some(DepthStencilStateDescriptor {
+                    format: TextureFormat::Depth32Float,
+                    depth_write_enabled: true,
+                    depth_compare: CompareFunction::LessEqual,
+                    stencil: Default::default(),
+                }),
+                alpha_to_coverage_enabled: false,
+                sample_count: 1,
+                sample_mask: !0,
+            })
+    }
 }
 
 impl Application for RenderApplication {
@@ -185,8 +335,10 @@ impl Application for RenderApplication {
     ) -> Result<Self, Self::Error> {
         let RenderConfiguration {
             mut camera,
-            from,
+            controller,
             buffer,
+            lines,
+            wireframe,
         } = configuration;
         camera.reproject(stage.swap_chain_descriptor());
         let vertices = stage.device().create_buffer_init(&BufferInitDescriptor {
@@ -199,6 +351,12 @@ impl Application for RenderApplication {
             contents: bytemuck::cast_slice(buffer.as_index_slice()),
             usage: BufferUsage::INDEX,
         });
+        let m = lines.len() as u32;
+        let lines = stage.device().create_buffer_init(&BufferInitDescriptor {
+            label: None,
+            contents: bytemuck::cast_slice(&lines),
+            usage: BufferUsage::VERTEX,
+        });
         let transform = stage.device().create_buffer_init(&BufferInitDescriptor {
             label: None,
             contents: bytemuck::cast_slice(camera.transform().as_slice()),
@@ -206,7 +364,7 @@ impl Application for RenderApplication {
         });
         let viewpoint = stage.device().create_buffer_init(&BufferInitDescriptor {
             label: None,
-            contents: bytemuck::cast_slice(from.coords.as_slice()),
+            contents: bytemuck::cast_slice(controller.eye().coords.as_slice()),
             usage: BufferUsage::COPY_DST | BufferUsage::UNIFORM,
         });
         let depth = Self::configure_depth_buffer(stage);
@@ -251,87 +409,25 @@ impl Application for RenderApplication {
             ],
         });
         let pipeline =
-            stage
-                .device()
-                .create_render_pipeline(&RenderPipelineDescriptor {
-                    label: None,
-                    layout: Some(&stage.device().create_pipeline_layout(
-                        &PipelineLayoutDescriptor {
-                            label: None,
-                            bind_group_layouts: &[&bind_group_layout],
-                            push_constant_ranges: &[],
-                        },
-                    )),
-                    vertex_stage: ProgrammableStageDescriptor {
-                        module: &stage
-                            .device()
-                            .create_shader_module(include_spirv!("shader.spv.vert")),
-                        entry_point: "main",
-                    },
-                    fragment_stage: Some(ProgrammableStageDescriptor {
-                        module: &stage
-                            .device()
-                            .create_shader_module(include_spirv!("shader.spv.frag")),
-                        entry_point: "main",
-                    }),
-                    vertex_state: VertexStateDescriptor {
-                        index_format: IndexFormat::Uint32,
-                        vertex_buffers: &[VertexBufferDescriptor {
-                            stride: mem::size_of::<Vertex>() as BufferAddress,
-                            step_mode: InputStepMode::Vertex,
-                            attributes: &[
-                                #[allow(clippy::erasing_op)]
-                                VertexAttributeDescriptor {
-                                    format: VertexFormat::Float4,
-                                    offset: 0 * 4 * 4,
-                                    shader_location: 0,
-                                },
-                                #[allow(clippy::identity_op)]
-                                VertexAttributeDescriptor {
-                                    format: VertexFormat::Float4,
-                                    offset: 1 * 4 * 4,
-                                    shader_location: 1,
-                                },
-                                VertexAttributeDescriptor {
-                                    format: VertexFormat::Float4,
-                                    offset: 2 * 4 * 4,
-                                    shader_location: 2,
-                                },
-                            ],
-                        }],
-                    },
-                    primitive_topology: PrimitiveTopology::TriangleList,
-                    rasterization_state: Some(RasterizationStateDescriptor {
-                        front_face: FrontFace::Ccw,
-                        cull_mode: CullMode::Back,
-                        ..Default::default()
-                    }),
-                    color_states: &[ColorStateDescriptor {
-                        format: stage.swap_chain_descriptor().format,
-                        color_blend: BlendDescriptor::REPLACE,
-                        alpha_blend: BlendDescriptor::REPLACE,
-                        write_mask: ColorWrite::ALL,
-                    }],
-                    depth_stencil_state: Some(DepthStencilStateDescriptor {
-                        format: TextureFormat::Depth32Float,
-                        depth_write_enabled: true,
-                        depth_compare: CompareFunction::Less,
-                        stencil: Default::default(),
-                    }),
-                    alpha_to_coverage_enabled: false,
-                    sample_count: 1,
-                    sample_mask: !0,
-                });
+            Self::configure_pipeline(stage, &bind_group_layout, PrimitiveTopology::TriangleList);
+        let wireframe_pipeline =
+            Self::configure_pipeline(stage, &bind_group_layout, PrimitiveTopology::LineList);
         Ok(RenderApplication {
             camera,
-            _viewpoint: viewpoint,
+            controller,
+            input: InputState::default(),
+            viewpoint,
             transform,
             vertices,
             indices,
+            lines,
             depth,
             n: buffer.as_index_slice().len() as u32,
+            m,
+            wireframe,
             bind_group,
             pipeline,
+            wireframe_pipeline,
         })
     }
 
@@ -346,7 +442,26 @@ impl Application for RenderApplication {
                     },
                 ..
             } => Abort,
-            _ => Continue,
+            WindowEvent::KeyboardInput {
+                input:
+                    KeyboardInput {
+                        virtual_keycode: Some(VirtualKeyCode::W),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => {
+                self.wireframe = !self.wireframe;
+                Redraw
+            }
+            _ => {
+                if self.input.react(&event) {
+                    Redraw
+                }
+                else {
+                    Continue
+                }
+            }
         }
     }
 
@@ -361,6 +476,18 @@ impl Application for RenderApplication {
     }
 
     fn render(&mut self, stage: &impl RenderStage, frame: &SwapChainTexture, _: &impl LocalSpawn) {
+        self.controller.update(&mut self.camera, &self.input);
+        self.input.clear();
+        stage.queue().write_buffer(
+            &self.transform,
+            0,
+            bytemuck::cast_slice(self.camera.transform().as_slice()),
+        );
+        stage.queue().write_buffer(
+            &self.viewpoint,
+            0,
+            bytemuck::cast_slice(self.controller.eye().coords.as_slice()),
+        );
         let mut encoder = stage
             .device()
             .create_command_encoder(&CommandEncoderDescriptor { label: None });
@@ -392,6 +519,11 @@ impl Application for RenderApplication {
         pass.set_index_buffer(self.indices.slice(..));
         pass.set_vertex_buffer(0, self.vertices.slice(..));
         pass.draw_indexed(0..self.n, 0, 0..1);
+        if self.wireframe && self.m > 0 {
+            pass.set_pipeline(&self.wireframe_pipeline);
+            pass.set_vertex_buffer(0, self.lines.slice(..));
+            pass.draw(0..self.m, 0..1);
+        }
         drop(pass); // Release `encoder`.
         stage.queue().submit(Some(encoder.finish()));
     }
@@ -401,24 +533,166 @@ pub fn render_mesh_buffer_with<F>(from: Point3<f32>, to: Point3<f32>, f: F)
 where
     F: FnOnce() -> MeshBuffer<Flat3<u32>, Vertex>,
 {
+    let buffer = f();
+    render(from, to, buffer, Vec::new(), false, "Plexus".to_owned());
+}
+
+/// Renders a [`MeshGraph`].
+///
+/// Faces are triangulated into a vertex buffer and, if enabled in the given
+/// [`DrawOptions`], unique edges are drawn as a wireframe over the faces. The
+/// window title reports the number of faces and edges in the graph.
+pub fn render_mesh_graph_with<G, F>(from: Point3<f32>, to: Point3<f32>, options: DrawOptions, f: F)
+where
+    G: GraphData,
+    G::Vertex: RenderVertex,
+    F: FnOnce() -> MeshGraph<G>,
+{
+    let graph = f();
+    let title = format!(
+        "Plexus ({} faces, {} edges)",
+        graph.face_count(),
+        graph.edge_count()
+    );
+    let normals = face_normals(&graph);
+    let buffer = to_render_buffer(&graph, &options);
+    let lines = {
+        // Offset edges from faces along their normals in proportion to the
+        // extent of the graph to avoid z-fighting.
+        let (min, max) = graph.vertices().map(|vertex| vertex.get().position()).fold(
+            (
+                Point3::from(Vector3::repeat(f32::MAX)),
+                Point3::from(Vector3::repeat(f32::MIN)),
+            ),
+            |(min, max), position| (min.inf(&position), max.sup(&position)),
+        );
+        let offset = (max - min).norm() * 1e-3;
+        graph
+            .edges()
+            .flat_map(|edge| {
+                let arc = edge.arc();
+                let normal = arc
+                    .adjacent_faces()
+                    .map(|face| normals[&face.key()])
+                    .fold(Vector3::zeros(), |sum, normal| sum + normal)
+                    .try_normalize(f32::EPSILON)
+                    .unwrap_or_else(Vector3::zeros);
+                [arc.source_vertex(), arc.destination_vertex()].map(|vertex| Vertex {
+                    position: (vertex.get().position() + (normal * offset))
+                        .to_homogeneous()
+                        .into(),
+                    normal: normal.to_homogeneous().into(),
+                    color: options.wireframe_color.0.into(),
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+    render(from, to, buffer, lines, options.wireframe, title);
+}
+
+/// Creates a buffer for rendering the faces of a [`MeshGraph`].
+///
+/// Vertices are duplicated for each of their faces and faces are triangulated
+/// as fans, so the buffer can be drawn directly without sharing vertices
+/// between faces. Vertices use the normal of their face if they do not provide
+/// a normal or flat shading is enabled in the given [`DrawOptions`].
+pub fn to_render_buffer<G>(
+    graph: &MeshGraph<G>,
+    options: &DrawOptions,
+) -> MeshBuffer<Flat3<u32>, Vertex>
+where
+    G: GraphData,
+    G::Vertex: RenderVertex,
+{
+    let normals = face_normals(graph);
+    let mut vertices = Vec::new();
+    for face in graph.faces() {
+        let normal = normals[&face.key()];
+        let ring = face
+            .adjacent_vertices()
+            .map(|vertex| {
+                let data = vertex.get();
+                let normal = if options.flat {
+                    normal
+                }
+                else {
+                    data.normal().unwrap_or(normal)
+                };
+                Vertex {
+                    position: data.position().to_homogeneous().into(),
+                    normal: normal.to_homogeneous().into(),
+                    color: data.color().unwrap_or(options.color).0.into(),
+                }
+            })
+            .collect::<Vec<_>>();
+        // Triangulate faces as fans.
+        for bc in ring[1..].windows(2) {
+            vertices.extend([ring[0], bc[0], bc[1]]);
+        }
+    }
+    let indices = (0..vertices.len() as u32).collect::<Vec<_>>();
+    // Indices are always in bounds and the arity is always three.
+    MeshBuffer::from_raw_buffers(indices, vertices).unwrap()
+}
+
+fn render(
+    from: Point3<f32>,
+    to: Point3<f32>,
+    buffer: MeshBuffer<Flat3<u32>, Vertex>,
+    lines: Vec<Vertex>,
+    wireframe: bool,
+    title: String,
+) {
+    let controller = OrbitController::look_at(from, to);
     let camera = {
         let mut camera = Camera::from(Projection::perspective(1.0, FRAC_PI_4, 0.1, 8.0));
         //let mut camera = Camera::from(Projection::orthographic(-4.0, 4.0, -4.0, 4.0, -8.0, 8.0));
-        camera.look_at(&from, &to);
+        controller.apply(&mut camera);
         camera
     };
-    let buffer = f();
     harness::run::<RenderApplication, _>(
         RenderConfiguration {
             camera,
-            from,
+            controller,
             buffer,
+            lines,
+            wireframe,
         },
         |reactor| {
             WindowBuilder::default()
-                .with_title("Plexus")
+                .with_title(title)
                 .build(reactor)
                 .unwrap()
         },
     );
 }
+
+fn face_normals<G>(graph: &MeshGraph<G>) -> HashMap<FaceKey, Vector3<f32>>
+where
+    G: GraphData,
+    G::Vertex: RenderVertex,
+{
+    graph
+        .faces()
+        .map(|face| {
+            let positions = face
+                .adjacent_vertices()
+                .map(|vertex| vertex.get().position())
+                .collect::<Vec<_>>();
+            (face.key(), flat_normal(&positions))
+        })
+        .collect()
+}
+
+// Computes the normal of a polygon using Newell's method, which is robust to
+// non-planar and concave polygons.
+fn flat_normal(positions: &[Point3<f32>]) -> Vector3<f32> {
+    positions
+        .iter()
+        .zip(positions.iter().cycle().skip(1))
+        .fold(Vector3::zeros(), |normal, (a, b)| {
+            normal + a.coords.cross(&b.coords)
+        })
+        .try_normalize(f32::EPSILON)
+        .unwrap_or_else(Vector3::zeros)
+}
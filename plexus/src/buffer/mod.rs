@@ -488,14 +488,45 @@ where
     /// Appends the contents of a flat `MeshBuffer` into another `MeshBuffer`.
     /// The source buffer is drained.
     ///
+    /// Both buffers must have the same arity, which is enforced at compile
+    /// time. For example, a buffer of quadrilaterals cannot be appended to a
+    /// buffer of triangles:
+    ///
+    /// ```rust,compile_fail
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::N64;
+    /// use nalgebra::Point3;
+    /// use plexus::buffer::MeshBuffer;
+    /// use plexus::index::{Flat3, Flat4};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<N64>;
+    ///
+    /// let mut buffer: MeshBuffer<Flat3<usize>, E3> = Cube::new()
+    ///     .polygons::<Position<E3>>()
+    ///     .triangulate()
+    ///     .collect();
+    /// let mut quads: MeshBuffer<Flat4<usize>, E3> =
+    ///     Cube::new().polygons::<Position<E3>>().collect();
+    /// buffer.append(&mut quads).unwrap();
+    /// ```
+    ///
     /// # Errors
     ///
-    /// Returns an error if an index overflows.
-    pub fn append<R, H>(&mut self, buffer: &mut MeshBuffer<R, H>) -> Result<(), BufferError>
+    /// Returns an error if an index overflows. The source buffer is not
+    /// modified if an error occurs.
+    pub fn append<U, H>(
+        &mut self,
+        buffer: &mut MeshBuffer<Flat<U, N>, H>,
+    ) -> Result<(), BufferError>
     where
         G: FromGeometry<H>,
-        R: Grouping,
-        R::Group: Into<<Flat<T, N> as Grouping>::Group>,
+        U: Copy + Integer + Into<T> + Unsigned,
     {
         let offset = T::from(self.vertices.len()).ok_or(BufferError::IndexOverflow)?;
         self.vertices.extend(
@@ -1152,6 +1183,8 @@ mod tests {
     use decorum::N64;
     use nalgebra::Point3;
 
+    use std::convert::TryFrom;
+
     use crate::buffer::{MeshBuffer, MeshBuffer4, MeshBufferN};
     use crate::graph::MeshGraph;
     use crate::index::Flat3;
@@ -1213,6 +1246,41 @@ mod tests {
         assert_eq!(13, buffer.as_vertex_slice().len());
     }
 
+    #[test]
+    fn append_flat_buffers() {
+        let mut buffer: MeshBuffer<Flat3<usize>, E3> = Cube::new()
+            .polygons::<Position<E3>>() // 6 quadrilaterals, 24 vertices.
+            .triangulate()
+            .collect();
+
+        assert_eq!(36, buffer.as_index_slice().len());
+        assert_eq!(8, buffer.as_vertex_slice().len());
+
+        buffer
+            .append(
+                &mut UvSphere::new(3, 2)
+                    .polygons::<Position<E3>>() // 6 triangles, 18 vertices.
+                    .triangulate()
+                    .collect::<MeshBuffer<Flat3<usize>, E3>>(),
+            )
+            .unwrap();
+
+        assert_eq!(54, buffer.as_index_slice().len());
+        assert_eq!(13, buffer.as_vertex_slice().len());
+        // Indices of the appended buffer are offset past the original vertices.
+        assert!(buffer.as_index_slice()[36..]
+            .iter()
+            .all(|index| *index >= 8));
+
+        let graph = MeshGraph::<E3>::try_from(buffer).unwrap();
+        assert_eq!(18, graph.face_count());
+        assert_eq!(13, graph.vertex_count());
+
+        let buffer: MeshBuffer<Flat3<usize>, E3> = graph.to_mesh_by_vertex().unwrap();
+        assert_eq!(54, buffer.as_index_slice().len());
+        assert_eq!(13, buffer.as_vertex_slice().len());
+    }
+
     #[test]
     fn convert_mesh_to_buffer_by_vertex() {
         let graph: MeshGraph<E3> = UvSphere::new(3, 2)
@@ -0,0 +1,1068 @@
+//! Deformation of surfaces.
+//!
+//! This module provides [`ArapSolver`], which deforms the surface of a mesh
+//! such that handle vertices reach target positions while the shape of the
+//! surface about the remaining vertices is preserved as much as possible, and
+//! [`linear_blend_skin`] and [`linear_blend_skin_with_normals`], which deform
+//! the surface of a mesh by the weighted transforms of the joints of a
+//! skeleton.
+//!
+//! [`ArapSolver`]: crate::deformation::ArapSolver
+//! [`linear_blend_skin`]: crate::deformation::linear_blend_skin
+//! [`linear_blend_skin_with_normals`]: crate::deformation::linear_blend_skin_with_normals
+
+use num::NumCast;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use theon::space::{Basis, EuclideanSpace, FiniteDimensional, Scalar, Vector};
+use theon::{AsPosition, AsPositionMut};
+use thiserror::Error;
+use typenum::U3;
+
+use crate::entity::storage::Key;
+use crate::geometry::{self, dot, norm, sub};
+use crate::graph::{
+    GraphData, GraphError, KeyIndex, MeshGraph, TopologyKind, VertexKey, VertexPosition,
+};
+use crate::metric::{conjugate_gradient, CotangentLaplacian, SparseMatrix};
+
+/// Errors concerning as-rigid-as-possible deformation.
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ArapError {
+    /// No handle vertices are given.
+    ///
+    /// Without handles, the positions of vertices are only determined up to a
+    /// rigid motion and there is nothing to deform.
+    #[error("no handles")]
+    NoHandles,
+    /// A handle is not found in the graph or the graph cannot be deformed,
+    /// such as when it has non-triangular faces or degenerate geometry.
+    #[error("graph operation failed: {0}")]
+    Graph(#[from] GraphError),
+}
+
+/// Errors concerning skinning.
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum SkinningError<K = u64>
+where
+    K: KeyIndex,
+{
+    /// The weights of a vertex do not sum to one.
+    #[error("weights of vertex {key:?} do not sum to one")]
+    WeightSumNotOne { key: VertexKey<K> },
+    /// A vertex is weighted by a joint that has no transform.
+    #[error("joint {joint} of vertex {key:?} not found")]
+    JointNotFound { key: VertexKey<K>, joint: usize },
+    /// A vertex is not found in the graph or its geometry cannot be
+    /// transformed.
+    #[error("graph operation failed: {0}")]
+    Graph(#[from] GraphError),
+}
+
+/// As-rigid-as-possible (ARAP) deformation of the surface of a graph.
+///
+/// The solver of Sorkine and Alexa moves the free vertices of a graph to
+/// minimize the deviation of the one-ring of each vertex from a rotation of
+/// its rest shape, while handle vertices are fixed at target positions. Edges
+/// are weighted by the [cotangent Laplacian][`CotangentLaplacian`]. Each
+/// iteration alternates between fitting a rotation to each vertex (the local
+/// step) and solving for the positions of the free vertices (the global step),
+/// which never increases the deformation energy.
+///
+/// The solver copies the geometry of the graph when it is constructed, so the
+/// graph is not borrowed while the solver is used. Deformed positions can be
+/// queried with [`ArapSolver::position`] or written to a graph with
+/// [`ArapSolver::apply`].
+///
+/// # Examples
+///
+/// Bending a strip by moving the handles at one of its ends:
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::Point3;
+/// use plexus::deformation::ArapSolver;
+/// use plexus::graph::MeshGraph;
+/// use plexus::prelude::*;
+///
+/// let mut graph = MeshGraph::<Point3<f64>>::from_heightmap(&[0.0; 16], 8, 2).unwrap();
+/// graph.triangulate();
+/// let handles = graph
+///     .vertices()
+///     .filter(|vertex| vertex.position().x == 0.0 || vertex.position().x == 7.0)
+///     .map(|vertex| {
+///         let mut position = *vertex.position();
+///         if position.x == 7.0 {
+///             position.z = 2.0;
+///         }
+///         (vertex.key(), position)
+///     })
+///     .collect::<Vec<_>>();
+///
+/// let mut solver = ArapSolver::new(&graph, handles).unwrap();
+/// solver.solve(16);
+/// solver.apply(&mut graph).unwrap();
+/// ```
+///
+/// [`ArapSolver::apply`]: crate::deformation::ArapSolver::apply
+/// [`ArapSolver::position`]: crate::deformation::ArapSolver::position
+/// [`CotangentLaplacian`]: crate::metric::CotangentLaplacian
+pub struct ArapSolver<G, K = u64>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    keys: Vec<VertexKey<K>>,
+    indices: HashMap<VertexKey<K>, usize>,
+    laplacian: SparseMatrix,
+    rest: Vec<[f64; 3]>,
+    positions: Vec<[f64; 3]>,
+    rotations: Vec<[[f64; 3]; 3]>,
+    // Maps vertices to their index in the global system or `None` if the vertex
+    // is a handle.
+    columns: Vec<Option<usize>>,
+    free: Vec<usize>,
+    tolerance: f64,
+    phantom: PhantomData<fn() -> G>,
+}
+
+impl<G, K> ArapSolver<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Constructs a solver from a graph and handle vertices paired with their
+    /// target positions.
+    ///
+    /// Free vertices are initially at their positions in the graph. Each
+    /// connected component of the graph should contain at least one handle,
+    /// otherwise the positions of its vertices are not well defined.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArapError::NoHandles`] if no handles are given. Returns a
+    /// graph error if a handle is not found in the graph, if the graph has any
+    /// non-triangular faces, or if geometry is degenerate. Errors caused by a
+    /// particular vertex or face are wrapped with its key (see
+    /// [`GraphError::AtKey`]).
+    ///
+    /// [`ArapError::NoHandles`]: crate::deformation::ArapError::NoHandles
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    pub fn new<I>(graph: &MeshGraph<G, K>, handles: I) -> Result<Self, ArapError>
+    where
+        I: IntoIterator<Item = (VertexKey<K>, VertexPosition<G>)>,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let laplacian = CotangentLaplacian::from_graph(graph)?;
+        let keys = laplacian.keys().to_vec();
+        let indices = keys
+            .iter()
+            .enumerate()
+            .map(|(index, key)| (*key, index))
+            .collect::<HashMap<_, _>>();
+        let rest = keys
+            .iter()
+            .map(|key| {
+                geometry::into_f64(*graph.vertex(*key).unwrap().position())
+                    .ok_or_else(|| GraphError::Geometry.at_key(*key))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut positions = rest.clone();
+        let mut columns = vec![Some(0); keys.len()];
+        for (key, position) in handles {
+            let index = laplacian
+                .index(key)
+                .ok_or_else(|| GraphError::TopologyNotFound(TopologyKind::Vertex).at_key(key))?;
+            positions[index] =
+                geometry::into_f64(position).ok_or_else(|| GraphError::Geometry.at_key(key))?;
+            columns[index] = None;
+        }
+        if columns.iter().all(|column| column.is_some()) {
+            return Err(ArapError::NoHandles);
+        }
+        let mut free = Vec::with_capacity(keys.len());
+        for (index, column) in columns.iter_mut().enumerate() {
+            if column.is_some() {
+                *column = Some(free.len());
+                free.push(index);
+            }
+        }
+        let extent = rest
+            .iter()
+            .map(|position| norm(sub(*position, rest[0])))
+            .fold(0.0, f64::max);
+        Ok(ArapSolver {
+            keys,
+            indices,
+            laplacian: laplacian.laplacian().clone(),
+            rotations: vec![IDENTITY; rest.len()],
+            rest,
+            positions,
+            columns,
+            free,
+            tolerance: 1e-9 * extent,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Performs one local/global iteration.
+    pub fn step(&mut self) {
+        // Local step: fit a rotation to the one-ring of each vertex.
+        for (index, rotation) in self.rotations.iter_mut().enumerate() {
+            let mut covariance = [[0.0; 3]; 3];
+            for (neighbor, weight) in neighbors(&self.laplacian, index) {
+                let e = sub(self.rest[index], self.rest[neighbor]);
+                let f = sub(self.positions[index], self.positions[neighbor]);
+                for (row, e) in covariance.iter_mut().zip(e) {
+                    for (entry, f) in row.iter_mut().zip(f) {
+                        *entry += weight * e * f;
+                    }
+                }
+            }
+            *rotation = fit_rotation(covariance);
+        }
+        // Global step: solve `Lx = b` for each coordinate of the free vertices,
+        // where `b` is formed from the rotated rest shape and handles.
+        let mut rhs = [
+            vec![0.0; self.free.len()],
+            vec![0.0; self.free.len()],
+            vec![0.0; self.free.len()],
+        ];
+        for (column, index) in self.free.iter().cloned().enumerate() {
+            for (neighbor, weight) in neighbors(&self.laplacian, index) {
+                let e = sub(self.rest[index], self.rest[neighbor]);
+                let (r, s) = (self.rotations[index], self.rotations[neighbor]);
+                let handle = self.columns[neighbor].is_none();
+                for (axis, rhs) in rhs.iter_mut().enumerate() {
+                    rhs[column] += weight * (dot(r[axis], e) + dot(s[axis], e)) / 2.0;
+                    if handle {
+                        rhs[column] += weight * self.positions[neighbor][axis];
+                    }
+                }
+            }
+        }
+        let multiply = |x: &[f64]| {
+            self.free
+                .iter()
+                .map(|index| {
+                    self.laplacian
+                        .row(*index)
+                        .iter()
+                        .filter_map(|(neighbor, value)| {
+                            self.columns[*neighbor].map(|column| value * x[column])
+                        })
+                        .sum::<f64>()
+                })
+                .collect::<Vec<_>>()
+        };
+        let solutions = [
+            conjugate_gradient(multiply, &rhs[0]),
+            conjugate_gradient(multiply, &rhs[1]),
+            conjugate_gradient(multiply, &rhs[2]),
+        ];
+        for (axis, solution) in solutions.iter().enumerate() {
+            for (index, x) in self.free.iter().zip(solution) {
+                self.positions[*index][axis] = *x;
+            }
+        }
+    }
+
+    /// Performs iterations until the positions of the vertices converge or the
+    /// given number of iterations have been performed.
+    ///
+    /// Returns the number of iterations performed.
+    pub fn solve(&mut self, iterations: usize) -> usize {
+        for n in 0..iterations {
+            let previous = self.positions.clone();
+            self.step();
+            let displacement = previous
+                .iter()
+                .zip(self.positions.iter())
+                .map(|(previous, position)| norm(sub(*position, *previous)))
+                .fold(0.0, f64::max);
+            if displacement <= self.tolerance {
+                return n + 1;
+            }
+        }
+        iterations
+    }
+
+    /// Gets the deformation energy of the current positions.
+    ///
+    /// This is the weighted sum of the squared deviations of the edges of the
+    /// one-ring of each vertex from the rotations of the most recent local
+    /// step.
+    pub fn energy(&self) -> f64 {
+        (0..self.positions.len())
+            .flat_map(move |index| {
+                neighbors(&self.laplacian, index).map(move |(neighbor, weight)| {
+                    let e = sub(self.rest[index], self.rest[neighbor]);
+                    let f = sub(self.positions[index], self.positions[neighbor]);
+                    let r = self.rotations[index];
+                    let deviation = sub(f, [dot(r[0], e), dot(r[1], e), dot(r[2], e)]);
+                    weight * dot(deviation, deviation)
+                })
+            })
+            .sum()
+    }
+
+    /// Gets the deformed position of a vertex.
+    ///
+    /// Returns `None` if the vertex is not found or its deformed position
+    /// cannot be represented by the position type of the graph.
+    pub fn position(&self, key: VertexKey<K>) -> Option<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: Basis,
+    {
+        self.indices
+            .get(&key)
+            .and_then(|index| from_f64(self.positions[*index]).ok())
+    }
+
+    /// Writes the deformed positions of vertices to a graph.
+    ///
+    /// Vertices that are not found in the graph are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the deformed position of a vertex cannot be
+    /// represented by the position type of the graph. The error is wrapped
+    /// with the key of the vertex (see [`GraphError::AtKey`]). The graph is
+    /// unmodified if an error occurs.
+    ///
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    pub fn apply(&self, graph: &mut MeshGraph<G, K>) -> Result<(), GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: Basis,
+    {
+        let positions = self
+            .keys
+            .iter()
+            .zip(self.positions.iter())
+            .map(|(key, position)| {
+                from_f64(*position)
+                    .map(|position| (*key, position))
+                    .map_err(|error| error.at_key(*key))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for (key, position) in positions {
+            if let Some(mut vertex) = graph.vertex_mut(key) {
+                *vertex.get_mut().as_position_mut() = position;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Vertex data with a normal.
+///
+/// Normals are typically derived from the positions of vertices (see
+/// [`VertexNormal`]), but vertex data may also carry normals, such as normals
+/// read from an encoding. This trait exposes such normals so that they can be
+/// deformed along with positions. See [`linear_blend_skin_with_normals`].
+///
+/// [`linear_blend_skin_with_normals`]: crate::deformation::linear_blend_skin_with_normals
+/// [`VertexNormal`]: crate::graph::VertexNormal
+pub trait AsNormal {
+    type Normal;
+
+    fn as_normal(&self) -> &Self::Normal;
+}
+
+/// Vertex data with a mutable normal.
+///
+/// See [`AsNormal`].
+///
+/// [`AsNormal`]: crate::deformation::AsNormal
+pub trait AsNormalMut: AsNormal {
+    fn as_normal_mut(&mut self) -> &mut Self::Normal;
+}
+
+/// Deforms the surface of a graph by linear blend skinning (LBS).
+///
+/// Each joint of a skeleton is given as an affine transform in row-major
+/// order, such that the last column is the translation of the transform.
+/// Vertices are mapped to joints paired with weights, and the position of each
+/// vertex is moved to the weighted sum of its position transformed by each of
+/// its joints, $\mathbf{p}' = \sum_i w_i J_i \mathbf{p}$. Vertices that are not
+/// weighted are unaffected. Normals derived from the geometry of the graph
+/// (see [`VertexNormal`]) follow the deformed positions. To also deform normals
+/// stored in vertex data, use [`linear_blend_skin_with_normals`].
+///
+/// The graph is deformed in place rather than copied. Graphs cannot be cloned
+/// in general (their layers may hold interpolation functions), and deforming
+/// in place keeps keys and layers valid. Skinning is applied to the current
+/// positions of vertices, so to animate a graph, skin a graph in its rest pose
+/// for each frame, such as a graph rebuilt from the same buffers.
+///
+/// Skinning does not change the topology of the graph. The graph is
+/// unmodified if an error occurs.
+///
+/// # Errors
+///
+/// Returns an error if the weights of a vertex do not sum to one (within a
+/// small tolerance), if a weight refers to a joint that has no transform, if a
+/// vertex is not found in the graph, if a transform of a vertex has entries
+/// that are not finite, or if the position of a vertex cannot be transformed
+/// or its deformed position cannot be represented by the position type of the
+/// graph. Vertices are validated in the order of their keys, so the
+/// error concerns the vertex with the least key among any invalid vertices.
+///
+/// # Examples
+///
+/// Raising one end of a strip with a second joint:
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::Point3;
+/// use plexus::deformation;
+/// use plexus::graph::MeshGraph;
+/// use plexus::prelude::*;
+/// use std::collections::HashMap;
+///
+/// let mut graph = MeshGraph::<Point3<f64>>::from_heightmap(&[0.0; 8], 4, 2).unwrap();
+///
+/// let identity = [
+///     [1.0, 0.0, 0.0, 0.0],
+///     [0.0, 1.0, 0.0, 0.0],
+///     [0.0, 0.0, 1.0, 0.0],
+///     [0.0, 0.0, 0.0, 1.0],
+/// ];
+/// let mut raise = identity;
+/// raise[2][3] = 1.0;
+/// let weights = graph
+///     .vertices()
+///     .map(|vertex| {
+///         let w = vertex.position().x / 3.0;
+///         (vertex.key(), vec![(0, 1.0 - w), (1, w)])
+///     })
+///     .collect::<HashMap<_, _>>();
+/// deformation::linear_blend_skin(&mut graph, &[identity, raise], &weights).unwrap();
+/// ```
+///
+/// [`linear_blend_skin_with_normals`]: crate::deformation::linear_blend_skin_with_normals
+/// [`VertexNormal`]: crate::graph::VertexNormal
+pub fn linear_blend_skin<G, K>(
+    graph: &mut MeshGraph<G, K>,
+    transforms: &[[[f64; 4]; 4]],
+    weights: &HashMap<VertexKey<K>, Vec<(usize, f64)>>,
+) -> Result<(), SkinningError<K>>
+where
+    G: GraphData,
+    K: KeyIndex,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<VertexPosition<G>>: Basis,
+{
+    for (key, position, _) in skin(graph, transforms, weights)? {
+        if let Some(mut vertex) = graph.vertex_mut(key) {
+            *vertex.get_mut().as_position_mut() = position;
+        }
+    }
+    Ok(())
+}
+
+/// Deforms the surface of a graph and the normals in its vertex data by linear
+/// blend skinning (LBS).
+///
+/// Positions are deformed exactly as in [`linear_blend_skin`]. The normal of
+/// each weighted vertex is transformed by the inverse transpose of the
+/// weighted sum of the linear parts of its joints and then normalized, which
+/// keeps normals perpendicular to the deformed surface under non-uniform
+/// scaling. Normals with no magnitude are unaffected.
+///
+/// # Errors
+///
+/// Returns an error in the same cases as [`linear_blend_skin`]. Also returns
+/// an error if the weighted sum of the linear parts of the joints of a vertex
+/// is singular and so cannot transform its normal. The graph is unmodified if
+/// an error occurs.
+///
+/// [`linear_blend_skin`]: crate::deformation::linear_blend_skin
+pub fn linear_blend_skin_with_normals<G, K>(
+    graph: &mut MeshGraph<G, K>,
+    transforms: &[[[f64; 4]; 4]],
+    weights: &HashMap<VertexKey<K>, Vec<(usize, f64)>>,
+) -> Result<(), SkinningError<K>>
+where
+    G: GraphData,
+    K: KeyIndex,
+    G::Vertex: AsNormalMut<Normal = Vector<VertexPosition<G>>> + AsPositionMut,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<VertexPosition<G>>: Basis,
+{
+    // Normals are converted via positions relative to the origin.
+    let origin = VertexPosition::<G>::origin();
+    let deformations = skin(graph, transforms, weights)?
+        .into_iter()
+        .map(|(key, position, linear)| {
+            let transform =
+                inverse_transpose(linear).ok_or_else(|| GraphError::Geometry.at_key(key))?;
+            let vertex = graph
+                .vertex(key)
+                .ok_or_else(|| GraphError::TopologyNotFound(TopologyKind::Vertex).at_key(key))?;
+            let n = geometry::into_f64(origin + *vertex.get().as_normal())
+                .ok_or_else(|| GraphError::Geometry.at_key(key))?;
+            let n = [
+                dot(transform[0], n),
+                dot(transform[1], n),
+                dot(transform[2], n),
+            ];
+            let magnitude = norm(n);
+            let normal = if magnitude > 0.0 {
+                let n = from_f64::<VertexPosition<G>>(n.map(|x| x / magnitude))
+                    .map_err(|error| error.at_key(key))?;
+                Some(n - origin)
+            }
+            else {
+                None
+            };
+            Ok((key, position, normal))
+        })
+        .collect::<Result<Vec<_>, SkinningError<K>>>()?;
+    for (key, position, normal) in deformations {
+        if let Some(mut vertex) = graph.vertex_mut(key) {
+            let data = vertex.get_mut();
+            *data.as_position_mut() = position;
+            if let Some(normal) = normal {
+                *data.as_normal_mut() = normal;
+            }
+        }
+    }
+    Ok(())
+}
+
+// Validates the weights and transforms of vertices and computes their deformed
+// positions. Returns the key, deformed position, and weighted sum of the linear
+// parts of the joints of each weighted vertex. Vertices are visited in the
+// order of their keys, so that the same error is returned for the same input.
+#[allow(clippy::type_complexity)]
+fn skin<G, K>(
+    graph: &MeshGraph<G, K>,
+    transforms: &[[[f64; 4]; 4]],
+    weights: &HashMap<VertexKey<K>, Vec<(usize, f64)>>,
+) -> Result<Vec<(VertexKey<K>, VertexPosition<G>, [[f64; 3]; 3])>, SkinningError<K>>
+where
+    G: GraphData,
+    K: KeyIndex,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<VertexPosition<G>>: Basis,
+{
+    // Tolerance for the sum of the weights of a vertex.
+    const EPSILON: f64 = 1e-6;
+
+    let mut weights = weights.iter().collect::<Vec<_>>();
+    weights.sort_unstable_by_key(|(key, _)| key.into_inner());
+    let mut deformations = Vec::with_capacity(weights.len());
+    for (key, weights) in weights {
+        let sum = weights.iter().map(|(_, weight)| weight).sum::<f64>();
+        if sum.is_nan() || (sum - 1.0).abs() > EPSILON {
+            return Err(SkinningError::WeightSumNotOne { key: *key });
+        }
+        let vertex = graph
+            .vertex(*key)
+            .ok_or_else(|| GraphError::TopologyNotFound(TopologyKind::Vertex).at_key(*key))?;
+        let rest = geometry::into_f64(*vertex.position())
+            .ok_or_else(|| GraphError::Geometry.at_key(*key))?;
+        let mut position = [0.0; 3];
+        let mut linear = [[0.0; 3]; 3];
+        for (joint, weight) in weights {
+            let transform = transforms.get(*joint).ok_or(SkinningError::JointNotFound {
+                key: *key,
+                joint: *joint,
+            })?;
+            if !transform.iter().flatten().all(|x| x.is_finite()) {
+                return Err(GraphError::Geometry.at_key(*key).into());
+            }
+            for ((x, row), blend) in position.iter_mut().zip(transform).zip(linear.iter_mut()) {
+                *x += weight * (dot([row[0], row[1], row[2]], rest) + row[3]);
+                for (y, z) in blend.iter_mut().zip(row) {
+                    *y += weight * z;
+                }
+            }
+        }
+        let position = from_f64(position).map_err(|error| error.at_key(*key))?;
+        deformations.push((*key, position, linear));
+    }
+    Ok(deformations)
+}
+
+// Computes the inverse transpose of a matrix or `None` if the matrix is
+// singular. This is the cofactor matrix divided by the determinant.
+fn inverse_transpose(m: [[f64; 3]; 3]) -> Option<[[f64; 3]; 3]> {
+    let cofactor = |i: usize, j: usize| {
+        let (r0, r1) = ((i + 1) % 3, (i + 2) % 3);
+        let (c0, c1) = ((j + 1) % 3, (j + 2) % 3);
+        (m[r0][c0] * m[r1][c1]) - (m[r0][c1] * m[r1][c0])
+    };
+    let cofactors = [
+        [cofactor(0, 0), cofactor(0, 1), cofactor(0, 2)],
+        [cofactor(1, 0), cofactor(1, 1), cofactor(1, 2)],
+        [cofactor(2, 0), cofactor(2, 1), cofactor(2, 2)],
+    ];
+    let determinant = dot(m[0], cofactors[0]);
+    if determinant.is_nan() || determinant.abs() <= f64::EPSILON {
+        return None;
+    }
+    Some(cofactors.map(|row| row.map(|x| x / determinant)))
+}
+
+const IDENTITY: [[f64; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+// Gets the adjacent vertices of a vertex paired with the (positive) weights of
+// their edges.
+fn neighbors(laplacian: &SparseMatrix, index: usize) -> impl '_ + Iterator<Item = (usize, f64)> {
+    laplacian
+        .row(index)
+        .iter()
+        .filter(move |(neighbor, _)| *neighbor != index)
+        .map(|(neighbor, value)| (*neighbor, -value))
+}
+
+// Fits the rotation `R` that maximizes `tr(RC)` for the covariance `C` of a
+// set of rest and deformed edges using the quaternion method of Horn. The
+// rotation is the quaternion given by the eigenvector of the greatest
+// eigenvalue of a symmetric matrix formed from the covariance, which is found
+// using Jacobi iteration.
+fn fit_rotation(c: [[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut n = [
+        [
+            c[0][0] + c[1][1] + c[2][2],
+            c[1][2] - c[2][1],
+            c[2][0] - c[0][2],
+            c[0][1] - c[1][0],
+        ],
+        [
+            c[1][2] - c[2][1],
+            c[0][0] - c[1][1] - c[2][2],
+            c[0][1] + c[1][0],
+            c[2][0] + c[0][2],
+        ],
+        [
+            c[2][0] - c[0][2],
+            c[0][1] + c[1][0],
+            -c[0][0] + c[1][1] - c[2][2],
+            c[1][2] + c[2][1],
+        ],
+        [
+            c[0][1] - c[1][0],
+            c[2][0] + c[0][2],
+            c[1][2] + c[2][1],
+            -c[0][0] - c[1][1] + c[2][2],
+        ],
+    ];
+    let mut v = [
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ];
+    for _ in 0..32 {
+        let off = (0..4)
+            .flat_map(|p| ((p + 1)..4).map(move |q| (p, q)))
+            .map(|(p, q)| n[p][q] * n[p][q])
+            .sum::<f64>();
+        let diagonal = (0..4).map(|p| n[p][p] * n[p][p]).sum::<f64>();
+        if off.is_nan() || off <= 1e-30 * diagonal {
+            break;
+        }
+        for p in 0..3 {
+            for q in (p + 1)..4 {
+                if n[p][q] == 0.0 {
+                    continue;
+                }
+                // Apply the Jacobi rotation that annihilates `n[p][q]`.
+                let theta = (n[q][q] - n[p][p]) / (2.0 * n[p][q]);
+                let t = theta.signum() / (theta.abs() + ((theta * theta) + 1.0).sqrt());
+                let cosine = 1.0 / ((t * t) + 1.0).sqrt();
+                let sine = t * cosine;
+                for row in n.iter_mut().chain(v.iter_mut()) {
+                    let (a, b) = (row[p], row[q]);
+                    row[p] = (cosine * a) - (sine * b);
+                    row[q] = (sine * a) + (cosine * b);
+                }
+                let (a, b) = (n[p], n[q]);
+                for (k, (a, b)) in a.iter().zip(b.iter()).enumerate() {
+                    n[p][k] = (cosine * a) - (sine * b);
+                    n[q][k] = (sine * a) + (cosine * b);
+                }
+            }
+        }
+    }
+    let m = (0..4)
+        .max_by(|a, b| n[*a][*a].partial_cmp(&n[*b][*b]).unwrap_or(Ordering::Equal))
+        .unwrap();
+    let [w, x, y, z] = [v[0][m], v[1][m], v[2][m], v[3][m]];
+    let length = ((w * w) + (x * x) + (y * y) + (z * z)).sqrt();
+    if length.is_nan() || length <= 0.0 {
+        return IDENTITY;
+    }
+    let [w, x, y, z] = [w / length, x / length, y / length, z / length];
+    [
+        [
+            1.0 - (2.0 * ((y * y) + (z * z))),
+            2.0 * ((x * y) - (w * z)),
+            2.0 * ((x * z) + (w * y)),
+        ],
+        [
+            2.0 * ((x * y) + (w * z)),
+            1.0 - (2.0 * ((x * x) + (z * z))),
+            2.0 * ((y * z) - (w * x)),
+        ],
+        [
+            2.0 * ((x * z) - (w * y)),
+            2.0 * ((y * z) + (w * x)),
+            1.0 - (2.0 * ((x * x) + (y * y))),
+        ],
+    ]
+}
+
+fn from_f64<S>(position: [f64; 3]) -> Result<S, GraphError>
+where
+    S: EuclideanSpace,
+    Vector<S>: Basis,
+{
+    Vector::<S>::canonical_basis()
+        .into_iter()
+        .zip(position)
+        .try_fold(S::origin(), |origin, (basis, x)| {
+            x.is_finite()
+                .then(|| <Scalar<S> as NumCast>::from(x))
+                .flatten()
+                .map(|x| origin + (basis * x))
+                .ok_or(GraphError::Geometry)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Point3, Vector3};
+    use std::collections::HashMap;
+
+    use crate::deformation::{self, ArapError, ArapSolver, AsNormal, AsNormalMut, SkinningError};
+    use crate::entity::storage::Key;
+    use crate::geometry::{AsPosition, AsPositionMut};
+    use crate::graph::{GraphData, GraphError, GraphKey, MeshGraph, TopologyKind, VertexKey};
+    use crate::prelude::*;
+    use crate::primitive::Trigon;
+
+    type E3 = Point3<f64>;
+
+    const IDENTITY: [[f64; 4]; 4] = [
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ];
+
+    #[test]
+    fn arap_rigid_motion() {
+        // Handles that are moved by a rigid motion move the rest of the
+        // surface rigidly.
+        let mut graph = MeshGraph::<E3>::from_heightmap(&[0.0; 25], 5, 5).unwrap();
+        graph.triangulate();
+        let transform = |position: E3| Point3::new(1.0 - position.y, position.x + 2.0, 3.0);
+        let handles = graph
+            .vertices()
+            .filter(|vertex| vertex.is_boundary_vertex())
+            .map(|vertex| (vertex.key(), transform(*vertex.position())))
+            .collect::<Vec<_>>();
+        let mut solver = ArapSolver::new(&graph, handles).unwrap();
+        solver.solve(100);
+        for vertex in graph.vertices() {
+            let position = solver.position(vertex.key()).unwrap();
+            assert!((position - transform(*vertex.position())).norm() < 1e-4);
+        }
+        assert!(solver.energy() < 1e-6);
+
+        let expected = graph
+            .vertices()
+            .map(|vertex| (vertex.key(), transform(*vertex.position())))
+            .collect::<Vec<_>>();
+        solver.apply(&mut graph).unwrap();
+        for (key, position) in expected {
+            assert!((graph.vertex(key).unwrap().position() - position).norm() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn arap_bend() {
+        let mut graph = MeshGraph::<E3>::from_heightmap(&[0.0; 16], 8, 2).unwrap();
+        graph.triangulate();
+        let handles = graph
+            .vertices()
+            .filter(|vertex| vertex.position().x == 0.0 || vertex.position().x == 7.0)
+            .map(|vertex| {
+                let position = *vertex.position();
+                if position.x == 7.0 {
+                    (vertex.key(), position + Vector3::new(-1.0, 0.0, 3.0))
+                }
+                else {
+                    (vertex.key(), position)
+                }
+            })
+            .collect::<Vec<_>>();
+        let mut solver = ArapSolver::new(&graph, handles.clone()).unwrap();
+        solver.step();
+        let energy = solver.energy();
+        solver.solve(32);
+        assert!(solver.energy() <= energy + 1e-9);
+        // Handles are fixed at their targets.
+        for (key, position) in handles {
+            assert_eq!(position, solver.position(key).unwrap());
+        }
+    }
+
+    #[test]
+    fn arap_errors() {
+        let mut graph = MeshGraph::<E3>::from_heightmap(&[0.0; 9], 3, 3).unwrap();
+        graph.triangulate();
+        assert_eq!(
+            ArapError::NoHandles,
+            ArapSolver::new(&graph, Vec::<(VertexKey, E3)>::new())
+                .err()
+                .unwrap()
+        );
+
+        let other = MeshGraph::<E3>::from_heightmap(&[0.0; 25], 5, 5).unwrap();
+        let key = other
+            .vertices()
+            .map(|vertex| vertex.key())
+            .find(|key| graph.vertex(*key).is_none())
+            .unwrap();
+        assert_eq!(
+            ArapError::Graph(GraphError::TopologyNotFound(TopologyKind::Vertex).at_key(key)),
+            ArapSolver::new(&graph, vec![(key, Point3::origin())])
+                .err()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn linear_blend_skin() {
+        let mut graph = MeshGraph::<E3>::from_heightmap(&[0.0; 8], 4, 2).unwrap();
+        let rest = graph
+            .vertices()
+            .map(|vertex| (vertex.key(), *vertex.position()))
+            .collect::<HashMap<_, _>>();
+        // Rotate a quarter turn about the z-axis and translate along it.
+        let transform = [
+            [0.0, -1.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 2.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ];
+        let weights = graph
+            .vertices()
+            .map(|vertex| {
+                let w = vertex.position().x / 3.0;
+                (vertex.key(), vec![(0, 1.0 - w), (1, w)])
+            })
+            .collect::<HashMap<_, _>>();
+        deformation::linear_blend_skin(&mut graph, &[IDENTITY, transform], &weights).unwrap();
+        for (key, position) in rest {
+            let w = position.x / 3.0;
+            let expected = Point3::new(
+                ((1.0 - w) * position.x) - (w * position.y),
+                ((1.0 - w) * position.y) + (w * position.x),
+                position.z + (2.0 * w),
+            );
+            assert!((graph.vertex(key).unwrap().position() - expected).norm() < 1e-9);
+        }
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(3, graph.face_count());
+    }
+
+    #[test]
+    fn linear_blend_skin_with_normals() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Vertex {
+            position: Point3<f64>,
+            normal: Vector3<f64>,
+        }
+
+        impl GraphData for Vertex {
+            type Vertex = Self;
+            type Arc = ();
+            type Edge = ();
+            type Face = ();
+        }
+
+        impl AsPosition for Vertex {
+            type Position = Point3<f64>;
+
+            fn as_position(&self) -> &Self::Position {
+                &self.position
+            }
+        }
+
+        impl AsPositionMut for Vertex {
+            fn as_position_mut(&mut self) -> &mut Self::Position {
+                &mut self.position
+            }
+        }
+
+        impl AsNormal for Vertex {
+            type Normal = Vector3<f64>;
+
+            fn as_normal(&self) -> &Self::Normal {
+                &self.normal
+            }
+        }
+
+        impl AsNormalMut for Vertex {
+            fn as_normal_mut(&mut self) -> &mut Self::Normal {
+                &mut self.normal
+            }
+        }
+
+        // Construct a triangle in the plane x + y = 1.
+        let normal = Vector3::new(1.0, 1.0, 0.0).normalize();
+        let mut graph = MeshGraph::<Vertex>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+                Point3::new(1.0, 0.0, 1.0),
+            ]
+            .into_iter()
+            .map(|position| Vertex { position, normal })
+            .collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let weights = graph
+            .vertices()
+            .map(|vertex| (vertex.key(), vec![(0, 1.0)]))
+            .collect::<HashMap<_, _>>();
+
+        // A singular transform cannot transform normals and the graph is
+        // unmodified.
+        let mut collapse = IDENTITY;
+        collapse[0][0] = 0.0;
+        match deformation::linear_blend_skin_with_normals(&mut graph, &[collapse], &weights)
+            .unwrap_err()
+        {
+            SkinningError::Graph(error) => {
+                assert_eq!(&GraphError::Geometry, error.without_key());
+            }
+            _ => panic!(),
+        }
+        assert!(graph.vertices().all(|vertex| vertex.get().normal == normal));
+
+        // Transforms with entries that are not finite are rejected.
+        let mut invalid = IDENTITY;
+        invalid[1][3] = f64::NAN;
+        match deformation::linear_blend_skin(&mut graph, &[invalid], &weights).unwrap_err() {
+            SkinningError::Graph(error) => {
+                assert_eq!(&GraphError::Geometry, error.without_key());
+            }
+            _ => panic!(),
+        }
+        invalid[1][3] = f64::INFINITY;
+        match deformation::linear_blend_skin_with_normals(&mut graph, &[invalid], &weights)
+            .unwrap_err()
+        {
+            SkinningError::Graph(error) => {
+                assert_eq!(&GraphError::Geometry, error.without_key());
+            }
+            _ => panic!(),
+        }
+        assert!(graph.vertices().all(|vertex| vertex.get().normal == normal));
+
+        // Scale non-uniformly along the x-axis. The deformed triangle lies in
+        // the plane x / 2 + y = 1 and normals remain perpendicular to it.
+        let mut scale = IDENTITY;
+        scale[0][0] = 2.0;
+        deformation::linear_blend_skin_with_normals(&mut graph, &[scale], &weights).unwrap();
+        let expected = Vector3::new(1.0, 2.0, 0.0).normalize();
+        for vertex in graph.vertices() {
+            let Vertex { position, normal } = *vertex.get();
+            assert!(((position.x / 2.0) + position.y - 1.0).abs() < 1e-9);
+            assert!((normal - expected).norm() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn linear_blend_skin_errors() {
+        let mut graph = MeshGraph::<E3>::from_heightmap(&[0.0; 4], 2, 2).unwrap();
+        let key = graph.vertices().map(|vertex| vertex.key()).next().unwrap();
+        let position = *graph.vertex(key).unwrap().position();
+
+        let weights = [(key, vec![(0, 0.5), (1, 0.25)])].into_iter().collect();
+        assert_eq!(
+            SkinningError::WeightSumNotOne { key },
+            deformation::linear_blend_skin(&mut graph, &[IDENTITY; 2], &weights).unwrap_err()
+        );
+        let weights = [(key, vec![(0, 0.5), (2, 0.5)])].into_iter().collect();
+        assert_eq!(
+            SkinningError::JointNotFound { key, joint: 2 },
+            deformation::linear_blend_skin(&mut graph, &[IDENTITY; 2], &weights).unwrap_err()
+        );
+        // The graph is unmodified.
+        assert_eq!(position, *graph.vertex(key).unwrap().position());
+
+        // The error concerns the invalid vertex with the least key.
+        let mut keys = graph
+            .vertices()
+            .map(|vertex| vertex.key())
+            .collect::<Vec<_>>();
+        keys.sort_unstable_by_key(|key| key.into_inner());
+        for _ in 0..8 {
+            // Each map iterates its vertices in a different order.
+            let weights = keys
+                .iter()
+                .map(|key| (*key, vec![(0, 0.5)]))
+                .collect::<HashMap<_, _>>();
+            assert_eq!(
+                SkinningError::WeightSumNotOne { key: keys[0] },
+                deformation::linear_blend_skin(&mut graph, &[IDENTITY], &weights).unwrap_err()
+            );
+        }
+
+        // Deformed positions that cannot be represented are rejected.
+        let mut overflow = IDENTITY;
+        overflow[0][0] = f64::MAX;
+        overflow[0][3] = f64::MAX;
+        let weights = keys
+            .iter()
+            .map(|key| (*key, vec![(0, 1.0)]))
+            .collect::<HashMap<_, _>>();
+        match deformation::linear_blend_skin(&mut graph, &[overflow], &weights).unwrap_err() {
+            SkinningError::Graph(error) => {
+                assert_eq!(&GraphError::Geometry, error.without_key());
+            }
+            _ => panic!(),
+        }
+        assert_eq!(position, *graph.vertex(key).unwrap().position());
+
+        let other = MeshGraph::<E3>::from_heightmap(&[0.0; 25], 5, 5).unwrap();
+        let key = other
+            .vertices()
+            .map(|vertex| vertex.key())
+            .find(|key| graph.vertex(*key).is_none())
+            .unwrap();
+        let weights = [(key, vec![(0, 1.0)])].into_iter().collect();
+        let error = deformation::linear_blend_skin(&mut graph, &[IDENTITY], &weights).unwrap_err();
+        match error {
+            SkinningError::Graph(error) => {
+                assert_eq!(Some(GraphKey::from(key)), error.key());
+                assert_eq!(
+                    &GraphError::TopologyNotFound(TopologyKind::Vertex),
+                    error.without_key()
+                );
+            }
+            _ => panic!(),
+        }
+    }
+}
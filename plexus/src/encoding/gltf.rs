@@ -0,0 +1,406 @@
+//! [glTF] encoding.
+//!
+//! This module provides support for reading meshes from the [glTF] format via
+//! the [`from_gltf`] and [`from_gltf_with`] functions. Each primitive of each
+//! mesh in a [glTF] document is read into its own mesh data structure.
+//! [`MeshGraph`]s can be written as binary [glTF] files via [`to_glb`].
+//!
+//! [glTF] support is implemented using the [`gltf`] crate. Documents and
+//! buffers must be parsed and loaded using that crate, such as via
+//! `gltf::import`.
+//!
+//! # Examples
+//!
+//! Reading the primitives of a [glTF] document into [`MeshGraph`]s:
+//!
+//! ```rust
+//! # extern crate gltf;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use nalgebra::Point3;
+//! use plexus::encoding::gltf::from_gltf_with;
+//! use plexus::graph::MeshGraph;
+//! use plexus::prelude::*;
+//!
+//! type E3 = Point3<f64>;
+//!
+//! # let data: &[u8] = include_bytes!("../../../data/quad.gltf");
+//! let (document, buffers, _) = gltf::import_slice(data).unwrap();
+//! let graphs: Vec<MeshGraph<E3>> = from_gltf_with(&document, &buffers, |attributes| {
+//!     attributes.position.into_geometry()
+//! })
+//! .unwrap();
+//! ```
+//!
+//! [glTF]: https://www.khronos.org/gltf/
+//!
+//! [`gltf`]: https://crates.io/crates/gltf
+//!
+//! [`from_gltf`]: crate::encoding::gltf::from_gltf
+//! [`from_gltf_with`]: crate::encoding::gltf::from_gltf_with
+//! [`to_glb`]: crate::encoding::gltf::to_glb
+//! [`MeshGraph`]: crate::graph::MeshGraph
+
+#![cfg(feature = "encoding-gltf")]
+
+use gltf::buffer::Data;
+use gltf::mesh::Mode;
+use gltf::Document;
+use num::ToPrimitive;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::io::{self, Write};
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar};
+use theon::AsPosition;
+use thiserror::Error;
+use typenum::U3;
+
+use crate::buffer::FromRawBuffersWithArity;
+use crate::geometry::FromGeometry;
+use crate::graph::{GraphData, MeshGraph, VertexNormal, VertexPosition};
+
+const GLB_MAGIC: u32 = 0x4654_6c67;
+const GLB_VERSION: u32 = 2;
+const GLB_CHUNK_BIN: u32 = 0x004e_4942;
+const GLB_CHUNK_JSON: u32 = 0x4e4f_534a;
+
+/// Errors concerning the [glTF] encoding.
+///
+/// [glTF]: https://www.khronos.org/gltf/
+#[derive(Debug, Error)]
+pub enum GltfError {
+    /// A required attribute, such as `POSITION`, is not present in a
+    /// primitive.
+    #[error("required attribute not found")]
+    AttributeNotFound,
+    /// The topology of a primitive is not supported. Only triangles are
+    /// supported.
+    #[error("unsupported primitive mode")]
+    ModeUnsupported,
+    /// A polygonal mesh data structure is not compatible with encoded glTF
+    /// data.
+    #[error("encoding operation failed")]
+    EncodingIncompatible,
+    /// An I/O operation (read or write via the `Read` and `Write` traits)
+    /// failed.
+    #[error("I/O operation failed")]
+    Io(io::Error),
+}
+
+impl From<io::Error> for GltfError {
+    fn from(error: io::Error) -> Self {
+        GltfError::Io(error)
+    }
+}
+
+/// Vertex attributes of a [glTF] primitive.
+///
+/// Attributes are read regardless of their component type and normalization
+/// and converted into `f32`s.
+///
+/// [glTF]: https://www.khronos.org/gltf/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Attributes {
+    /// The `POSITION` attribute.
+    pub position: (f32, f32, f32),
+    /// The `NORMAL` attribute, if any.
+    pub normal: Option<(f32, f32, f32)>,
+    /// The `TEXCOORD_0` attribute, if any.
+    pub texture: Option<(f32, f32)>,
+}
+
+/// Reads the primitives of a [glTF] document into mesh data structures.
+///
+/// Vertex data is converted from [`Attributes`] via [`FromGeometry`]. See
+/// [`from_gltf_with`].
+///
+/// # Errors
+///
+/// Returns an error if a primitive is not triangular, lacks positions, or
+/// cannot be read into the mesh data structure.
+///
+/// [glTF]: https://www.khronos.org/gltf/
+///
+/// [`from_gltf_with`]: crate::encoding::gltf::from_gltf_with
+/// [`Attributes`]: crate::encoding::gltf::Attributes
+/// [`FromGeometry`]: crate::geometry::FromGeometry
+pub fn from_gltf<T, G>(document: &Document, buffers: &[Data]) -> Result<Vec<T>, GltfError>
+where
+    T: FromRawBuffersWithArity<u32, G>,
+    G: FromGeometry<Attributes>,
+{
+    from_gltf_with(document, buffers, G::from_geometry)
+}
+
+/// Reads the primitives of a [glTF] document into mesh data structures using
+/// the given function to map [`Attributes`] into vertex data.
+///
+/// One mesh data structure is read for each primitive of each mesh in the
+/// document in order. Primitives without indices are read as though their
+/// vertices are indexed in order.
+///
+/// # Errors
+///
+/// Returns an error if a primitive is not triangular, lacks positions, or
+/// cannot be read into the mesh data structure.
+///
+/// [glTF]: https://www.khronos.org/gltf/
+///
+/// [`Attributes`]: crate::encoding::gltf::Attributes
+pub fn from_gltf_with<T, G, F>(
+    document: &Document,
+    buffers: &[Data],
+    mut f: F,
+) -> Result<Vec<T>, GltfError>
+where
+    T: FromRawBuffersWithArity<u32, G>,
+    F: FnMut(Attributes) -> G,
+{
+    let mut meshes = vec![];
+    for primitive in document.meshes().flat_map(|mesh| mesh.primitives()) {
+        if primitive.mode() != Mode::Triangles {
+            return Err(GltfError::ModeUnsupported);
+        }
+        let reader = primitive.reader(|buffer| buffers.get(buffer.index()).map(|data| &*data.0));
+        let positions = reader
+            .read_positions()
+            .ok_or(GltfError::AttributeNotFound)?
+            .collect::<Vec<_>>();
+        let normals = reader
+            .read_normals()
+            .map(|normals| normals.collect::<Vec<_>>());
+        let textures = reader
+            .read_tex_coords(0)
+            .map(|textures| textures.into_f32().collect::<Vec<_>>());
+        let vertices = positions
+            .iter()
+            .enumerate()
+            .map(|(index, &[x, y, z])| {
+                f(Attributes {
+                    position: (x, y, z),
+                    normal: normals
+                        .as_ref()
+                        .and_then(|normals| normals.get(index))
+                        .map(|&[x, y, z]| (x, y, z)),
+                    texture: textures
+                        .as_ref()
+                        .and_then(|textures| textures.get(index))
+                        .map(|&[u, v]| (u, v)),
+                })
+            })
+            .collect::<Vec<_>>();
+        let indices = match reader.read_indices() {
+            Some(indices) => indices.into_u32().collect::<Vec<_>>(),
+            None => (0..positions.len() as u32).collect(),
+        };
+        meshes.push(
+            T::from_raw_buffers_with_arity(indices, vertices, 3)
+                .map_err(|_| GltfError::EncodingIncompatible)?,
+        );
+    }
+    Ok(meshes)
+}
+
+/// Writes a [`MeshGraph`] as a binary [glTF] (GLB) file.
+///
+/// The file contains a single mesh with a single triangular primitive with
+/// `POSITION` and `NORMAL` attributes and `u32` indices. Faces are
+/// triangulated as fans about their leading arcs and vertex normals are
+/// computed via [`VertexNormal`]. Components are written as little-endian
+/// `f32`s and the bounds of positions are written to the `POSITION` accessor.
+///
+/// Returns the number of bytes written.
+///
+/// # Errors
+///
+/// Returns an error if the graph has no faces, a vertex normal cannot be
+/// computed, a component of a position or normal is not finite (i.e., is NaN or
+/// infinite, including values that overflow `f32`), an index overflows, or
+/// writing fails.
+///
+/// [glTF]: https://www.khronos.org/gltf/
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`VertexNormal`]: crate::graph::VertexNormal
+pub fn to_glb<G>(graph: &MeshGraph<G>, mut write: impl Write) -> Result<usize, GltfError>
+where
+    G: GraphData + VertexNormal,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let u32_from = |n: usize| u32::try_from(n).map_err(|_| GltfError::EncodingIncompatible);
+    let xyz = |x: Scalar<VertexPosition<G>>, y, z| -> Result<[f32; 3], GltfError> {
+        // Non-finite components cannot be represented by the bounds written
+        // to the JSON chunk.
+        let f = |value: Scalar<VertexPosition<G>>| {
+            value
+                .to_f32()
+                .filter(|value| value.is_finite())
+                .ok_or(GltfError::EncodingIncompatible)
+        };
+        Ok([f(x)?, f(y)?, f(z)?])
+    };
+    let mut keys = HashMap::with_capacity(graph.vertex_count());
+    let mut positions = Vec::with_capacity(graph.vertex_count());
+    let mut normals = Vec::with_capacity(graph.vertex_count());
+    for vertex in graph.vertices() {
+        keys.insert(vertex.key(), u32_from(positions.len())?);
+        let (x, y, z) = (*vertex.position()).into_xyz();
+        positions.push(xyz(x, y, z)?);
+        let normal = vertex
+            .normal()
+            .map_err(|_| GltfError::EncodingIncompatible)?;
+        let (x, y, z) = (VertexPosition::<G>::origin() + normal).into_xyz();
+        normals.push(xyz(x, y, z)?);
+    }
+    let mut indices = vec![];
+    for face in graph.faces() {
+        let ring = face
+            .adjacent_vertices()
+            .map(|vertex| keys[&vertex.key()])
+            .collect::<Vec<_>>();
+        for bc in ring[1..].windows(2) {
+            indices.extend([ring[0], bc[0], bc[1]].iter());
+        }
+    }
+    if indices.is_empty() {
+        return Err(GltfError::EncodingIncompatible);
+    }
+
+    let (min, max) = positions.iter().skip(1).fold(
+        (positions[0], positions[0]),
+        |(mut min, mut max), position| {
+            for ((min, max), component) in min.iter_mut().zip(max.iter_mut()).zip(position) {
+                *min = min.min(*component);
+                *max = max.max(*component);
+            }
+            (min, max)
+        },
+    );
+    let mut bin = vec![];
+    for component in positions
+        .iter()
+        .chain(normals.iter())
+        .flat_map(|xyz| xyz.iter())
+    {
+        bin.extend_from_slice(&component.to_le_bytes());
+    }
+    for index in indices.iter() {
+        bin.extend_from_slice(&index.to_le_bytes());
+    }
+    let (count, length) = (positions.len(), positions.len() * 12);
+    let json = format!(
+        concat!(
+            r#"{{"asset":{{"version":"2.0","generator":"plexus"}},"#,
+            r#""buffers":[{{"byteLength":{bin}}}],"#,
+            r#""bufferViews":["#,
+            r#"{{"buffer":0,"byteOffset":0,"byteLength":{length},"target":34962}},"#,
+            r#"{{"buffer":0,"byteOffset":{length},"byteLength":{length},"target":34962}},"#,
+            r#"{{"buffer":0,"byteOffset":{offset},"byteLength":{indices},"target":34963}}],"#,
+            r#""accessors":["#,
+            r#"{{"bufferView":0,"componentType":5126,"count":{count},"type":"VEC3","#,
+            r#""min":[{min}],"max":[{max}]}},"#,
+            r#"{{"bufferView":1,"componentType":5126,"count":{count},"type":"VEC3"}},"#,
+            r#"{{"bufferView":2,"componentType":5125,"count":{n},"type":"SCALAR"}}],"#,
+            r#""meshes":[{{"primitives":[{{"#,
+            r#""attributes":{{"POSITION":0,"NORMAL":1}},"indices":2,"mode":4}}]}}],"#,
+            r#""nodes":[{{"mesh":0}}],"scenes":[{{"nodes":[0]}}],"scene":0}}"#,
+        ),
+        bin = bin.len(),
+        length = length,
+        offset = length * 2,
+        indices = indices.len() * 4,
+        count = count,
+        min = format!("{},{},{}", min[0], min[1], min[2]),
+        max = format!("{},{},{}", max[0], max[1], max[2]),
+        n = indices.len(),
+    );
+
+    // Chunks must be aligned to four bytes. JSON is padded with spaces and
+    // binary data is padded with zeros.
+    let mut json = json.into_bytes();
+    json.resize((json.len() + 3) & !3, b' ');
+    bin.resize((bin.len() + 3) & !3, 0);
+    let size = 12 + 8 + json.len() + 8 + bin.len();
+    for word in [GLB_MAGIC, GLB_VERSION, u32_from(size)?].iter() {
+        write.write_all(&word.to_le_bytes())?;
+    }
+    for (kind, chunk) in [(GLB_CHUNK_JSON, &json), (GLB_CHUNK_BIN, &bin)].iter() {
+        write.write_all(&u32_from(chunk.len())?.to_le_bytes())?;
+        write.write_all(&kind.to_le_bytes())?;
+        write.write_all(chunk)?;
+    }
+    Ok(size)
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::encoding::gltf::{from_gltf_with, to_glb, GltfError};
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn decode_into_graph() {
+        let (document, buffers, _) = {
+            let data: &[u8] = include_bytes!("../../../data/quad.gltf");
+            gltf::import_slice(data).unwrap()
+        };
+        let mut normals = vec![];
+        let graphs: Vec<MeshGraph<E3>> = from_gltf_with(&document, &buffers, |attributes| {
+            normals.push(attributes.normal.unwrap());
+            assert!(attributes.texture.is_some());
+            attributes.position.into_geometry()
+        })
+        .unwrap();
+
+        assert_eq!(1, graphs.len());
+        assert_eq!(4, graphs[0].vertex_count());
+        assert_eq!(2, graphs[0].face_count());
+        assert!(normals.iter().all(|normal| *normal == (0.0, 0.0, 1.0)));
+    }
+
+    #[test]
+    fn encode_and_decode_graph() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+        let mut glb = vec![];
+        let size = to_glb(&graph, &mut glb).unwrap();
+        assert_eq!(size, glb.len());
+
+        let (document, buffers, _) = gltf::import_slice(&glb).unwrap();
+        let mut normals = vec![];
+        let graphs: Vec<MeshGraph<E3>> = from_gltf_with(&document, &buffers, |attributes| {
+            normals.push(attributes.normal.unwrap());
+            attributes.position.into_geometry()
+        })
+        .unwrap();
+
+        assert_eq!(1, graphs.len());
+        assert_eq!(8, graphs[0].vertex_count());
+        assert_eq!(12, graphs[0].face_count());
+        for (x, y, z) in normals {
+            assert!(((x * x) + (y * y) + (z * z) - 1.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn encode_graph_with_non_finite_position() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+        let key = graph.vertices().keys().next().unwrap();
+        *graph.vertex_mut(key).unwrap().get_mut() = Point3::new(f64::NAN, 0.0, 0.0);
+
+        let mut glb = vec![];
+        assert!(matches!(
+            to_glb(&graph, &mut glb),
+            Err(GltfError::EncodingIncompatible)
+        ));
+        assert!(glb.is_empty());
+    }
+}
@@ -5,17 +5,23 @@
 //! support is enabled, the `ply` module is exposed. The following table
 //! summarizes the encodings supported by Plexus:
 //!
-//! | Feature        | Default | Encoding | Read | Write |
-//! |----------------|---------|----------|------|-------|
-//! | `encoding-ply` | No      | [PLY]    | Yes  | No    |
+//! | Feature         | Default | Encoding | Read | Write |
+//! |-----------------|---------|----------|------|-------|
+//! | `encoding-gltf` | No      | [glTF]   | Yes  | Yes   |
+//! | `encoding-obj`  | No      | [OBJ]    | Yes  | No    |
+//! | `encoding-ply`  | No      | [PLY]    | Yes  | No    |
 //!
 //! This module provides traits used by all encodings. These traits describe the
 //! outputs and inputs of decoders and encoders, respectively. Generally, these
 //! traits should **not** be used directly. Instead, prefer the conversion
 //! traits exposed for specific encodings, such as `FromPly` when using [PLY].
 //!
+//! [glTF]: https://www.khronos.org/gltf/
+//! [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
 //! [PLY]: https://en.wikipedia.org/wiki/ply_(file_format)
 
+pub mod gltf;
+pub mod obj;
 pub mod ply;
 
 use std::fmt::Debug;
@@ -0,0 +1,395 @@
+//! [OBJ] encoding.
+//!
+//! This module provides support for reading mesh data structures from the
+//! [OBJ] format via the [`FromObj`] trait. This trait can be used with a
+//! decoder to read vertex positions and faces from [OBJ] data.
+//!
+//! [OBJ] data is read one statement (line) at a time, so the data is never
+//! buffered in its entirety. Only geometric vertex (`v`) and face (`f`)
+//! statements are decoded. Other statements, such as texture coordinates,
+//! normals, groups, and materials, are ignored.
+//!
+//! # Examples
+//!
+//! Reading an [OBJ] file into a [`MeshGraph`]:
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use nalgebra::Point3;
+//! use plexus::encoding::obj::{FromObj, PositionEncoding};
+//! use plexus::graph::MeshGraph;
+//! use std::io::Read;
+//!
+//! type E3 = Point3<f64>;
+//!
+//! // Read from a file, network, etc.
+//! fn read() -> impl Read {
+//!     // ...
+//!     # let obj: &[u8] = include_bytes!("../../../data/cube.obj");
+//!     # obj
+//! }
+//!
+//! let encoding = PositionEncoding::<E3>::default();
+//! let graph = MeshGraph::<E3>::from_obj(encoding, read()).unwrap();
+//! ```
+//!
+//! [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+//!
+//! [`FromObj`]: crate::encoding::obj::FromObj
+//! [`MeshGraph`]: crate::graph::MeshGraph
+
+#![cfg(feature = "encoding-obj")]
+
+use num::cast;
+use smallvec::SmallVec;
+use std::io::{self, BufRead, BufReader, Read};
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use theon::space::{EuclideanSpace, FiniteDimensional};
+use thiserror::Error;
+use typenum::{NonZero, Unsigned, U2, U3};
+
+use crate::buffer::BufferError;
+use crate::encoding::{FaceDecoder, FromEncoding, VertexDecoder};
+use crate::graph::GraphError;
+
+/// The number of faces read between invocations of a progress function.
+pub const PROGRESS_INTERVAL: usize = 4096;
+
+/// Errors concerning the [OBJ] encoding.
+///
+/// Errors that concern a statement provide its line number, starting at one.
+///
+/// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+#[derive(Debug, Error)]
+pub enum ObjError {
+    /// A statement is missing a required element or an element cannot be
+    /// parsed.
+    #[error("malformed statement at line {0}")]
+    StatementMalformed(usize),
+    /// A face refers to a vertex that has not been defined.
+    #[error("vertex index out of bounds at line {0}")]
+    IndexOutOfBounds(usize),
+    /// A polygonal mesh data structure is not compatible with encoded OBJ data.
+    #[error("encoding operation failed")]
+    EncodingIncompatible,
+    /// A [`MeshBuffer`] cannot be constructed from decoded OBJ data.
+    ///
+    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
+    #[error("buffer operation failed: {0}")]
+    Buffer(#[from] BufferError),
+    /// A [`MeshGraph`] cannot be constructed from decoded OBJ data, such as
+    /// when faces form non-manifold topology.
+    ///
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    #[error("graph operation failed: {0}")]
+    Graph(#[from] GraphError),
+    /// An I/O operation (read via the `Read` trait) failed.
+    #[error("I/O operation failed")]
+    Io(io::Error),
+}
+
+impl From<io::Error> for ObjError {
+    fn from(error: io::Error) -> Self {
+        ObjError::Io(error)
+    }
+}
+
+pub trait VertexStatementDecoder: VertexDecoder {
+    /// Decodes a vertex from the coordinates of a geometric vertex (`v`)
+    /// statement.
+    ///
+    /// Returns `None` if the coordinates cannot be decoded.
+    fn decode_vertex(&self, coordinates: &[f64]) -> Option<Self::Vertex>;
+}
+
+pub trait FaceStatementDecoder: FaceDecoder {
+    /// Decodes a face from the zero-based vertex indices of a face (`f`)
+    /// statement.
+    fn decode_face(&self, indices: SmallVec<[usize; 4]>) -> (Self::Index, Self::Face);
+}
+
+pub trait FromObj<E>: Sized {
+    /// Reads [OBJ] data.
+    ///
+    /// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+    fn from_obj(decoder: E, read: impl Read) -> Result<Self, ObjError> {
+        Self::from_obj_with_progress(decoder, read, |_| {})
+    }
+
+    /// Reads [OBJ] data and reports progress as faces are read.
+    ///
+    /// The function `f` is invoked with the number of faces read so far every
+    /// [`PROGRESS_INTERVAL`] faces and once more when all faces have been
+    /// read. Progress is reported while the data is read, so it can be used to
+    /// display the progress of large imports. Otherwise, this behaves exactly
+    /// like [`from_obj`].
+    ///
+    /// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+    ///
+    /// [`from_obj`]: crate::encoding::obj::FromObj::from_obj
+    /// [`PROGRESS_INTERVAL`]: crate::encoding::obj::PROGRESS_INTERVAL
+    fn from_obj_with_progress<F>(decoder: E, read: impl Read, f: F) -> Result<Self, ObjError>
+    where
+        F: FnMut(usize);
+}
+
+impl<T, E> FromObj<E> for T
+where
+    T: FromEncoding<E>,
+    E: FaceStatementDecoder + VertexStatementDecoder,
+    ObjError: From<T::Error>,
+    <E as FaceDecoder>::Output: FromIterator<(E::Index, E::Face)>,
+    <E as VertexDecoder>::Output: FromIterator<E::Vertex>,
+{
+    fn from_obj_with_progress<F>(decoder: E, read: impl Read, mut f: F) -> Result<Self, ObjError>
+    where
+        F: FnMut(usize),
+    {
+        let mut read = BufReader::new(read);
+        let mut line = String::new();
+        let mut number = 0;
+        let mut vertices = Vec::new();
+        let mut faces = Vec::new();
+        // Faces may refer to vertices that are defined later, so indices are
+        // only bounded once all vertices have been read. This is the greatest
+        // index and the line on which it first occurs.
+        let mut max: Option<(usize, usize)> = None;
+        while read.read_line(&mut line)? > 0 {
+            number += 1;
+            let mut elements = line.split_whitespace();
+            match elements.next() {
+                Some("v") => {
+                    let coordinates = elements
+                        .map(|element| element.parse::<f64>())
+                        .collect::<Result<SmallVec<[_; 4]>, _>>()
+                        .map_err(|_| ObjError::StatementMalformed(number))?;
+                    let vertex = decoder
+                        .decode_vertex(&coordinates)
+                        .ok_or(ObjError::StatementMalformed(number))?;
+                    vertices.push(vertex);
+                }
+                Some("f") => {
+                    let indices = elements
+                        .map(|element| decode_index(element, vertices.len(), number))
+                        .collect::<Result<SmallVec<[_; 4]>, _>>()?;
+                    if indices.len() < 3 {
+                        return Err(ObjError::StatementMalformed(number));
+                    }
+                    let index = indices.iter().cloned().max().unwrap_or(0);
+                    if max.map_or(true, |(max, _)| index > max) {
+                        max = Some((index, number));
+                    }
+                    faces.push(decoder.decode_face(indices));
+                    if faces.len() % PROGRESS_INTERVAL == 0 {
+                        f(faces.len());
+                    }
+                }
+                _ => {}
+            }
+            line.clear();
+        }
+        if let Some((index, number)) = max {
+            if index >= vertices.len() {
+                return Err(ObjError::IndexOutOfBounds(number));
+            }
+        }
+        f(faces.len());
+        T::from_encoding(vertices.into_iter().collect(), faces.into_iter().collect())
+            .map_err(ObjError::from)
+    }
+}
+
+pub trait DecodePosition<N>: FiniteDimensional<N = N> + Sized
+where
+    N: NonZero + Unsigned,
+{
+    fn decode_position(coordinates: &[f64]) -> Option<Self>;
+}
+
+impl<T> DecodePosition<U2> for T
+where
+    T: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    fn decode_position(coordinates: &[f64]) -> Option<Self> {
+        match *coordinates {
+            [x, y, ..] => Some(EuclideanSpace::from_xy(cast::cast(x)?, cast::cast(y)?)),
+            _ => None,
+        }
+    }
+}
+
+impl<T> DecodePosition<U3> for T
+where
+    T: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    fn decode_position(coordinates: &[f64]) -> Option<Self> {
+        match *coordinates {
+            [x, y, z, ..] => Some(EuclideanSpace::from_xyz(
+                cast::cast(x)?,
+                cast::cast(y)?,
+                cast::cast(z)?,
+            )),
+            _ => None,
+        }
+    }
+}
+
+pub struct PositionEncoding<T> {
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for PositionEncoding<T> {
+    fn default() -> Self {
+        PositionEncoding {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> FaceDecoder for PositionEncoding<T> {
+    type Output = Vec<(Self::Index, Self::Face)>;
+    type Index = SmallVec<[usize; 4]>;
+    type Face = ();
+}
+
+impl<T> FaceStatementDecoder for PositionEncoding<T> {
+    fn decode_face(&self, indices: SmallVec<[usize; 4]>) -> (Self::Index, Self::Face) {
+        (indices, ())
+    }
+}
+
+impl<T> VertexDecoder for PositionEncoding<T> {
+    type Output = Vec<Self::Vertex>;
+    type Vertex = T;
+}
+
+impl<T, N> VertexStatementDecoder for PositionEncoding<T>
+where
+    T: DecodePosition<N> + FiniteDimensional<N = N>,
+    N: NonZero + Unsigned,
+{
+    fn decode_vertex(&self, coordinates: &[f64]) -> Option<Self::Vertex> {
+        T::decode_position(coordinates)
+    }
+}
+
+// Decodes the vertex index of an element of a face statement into a
+// zero-based index. Elements may also refer to texture coordinates and normals
+// (such as `1/2/3`), which are ignored. Negative indices are relative to the
+// number of vertices read so far.
+fn decode_index(element: &str, count: usize, number: usize) -> Result<usize, ObjError> {
+    let index = element
+        .split('/')
+        .next()
+        .and_then(|index| index.parse::<isize>().ok())
+        .ok_or(ObjError::StatementMalformed(number))?;
+    if index > 0 {
+        Ok(index as usize - 1)
+    }
+    else if index < 0 {
+        count
+            .checked_sub(index.unsigned_abs())
+            .ok_or(ObjError::IndexOutOfBounds(number))
+    }
+    else {
+        Err(ObjError::StatementMalformed(number))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+    use std::fmt::Write;
+
+    use crate::buffer::MeshBuffer;
+    use crate::encoding::obj::{FromObj, ObjError, PositionEncoding, PROGRESS_INTERVAL};
+    use crate::graph::{GraphError, MeshGraph};
+    use crate::primitive::Tetragon;
+
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn decode_into_buffer() {
+        let buffer = {
+            let obj: &[u8] = include_bytes!("../../../data/cube.obj");
+            MeshBuffer::<Tetragon<usize>, E3>::from_obj(PositionEncoding::<E3>::default(), obj)
+                .unwrap()
+        };
+        assert_eq!(8, buffer.as_vertex_slice().len());
+        assert_eq!(6, buffer.as_index_slice().len());
+    }
+
+    #[test]
+    fn decode_into_graph() {
+        let graph = {
+            let obj: &[u8] = include_bytes!("../../../data/cube.obj");
+            MeshGraph::<E3>::from_obj(PositionEncoding::<E3>::default(), obj).unwrap()
+        };
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(12, graph.edge_count());
+        assert_eq!(6, graph.face_count());
+    }
+
+    #[test]
+    fn decode_into_graph_with_progress() {
+        // Write a strip of triangles with more faces than the progress
+        // interval. Some faces refer to texture coordinates, which are
+        // ignored.
+        let n = PROGRESS_INTERVAL + 4;
+        let mut obj = String::new();
+        for i in 0..=(n / 2) {
+            writeln!(obj, "v {} 0.0 0.0", i).unwrap();
+            writeln!(obj, "v {} 1.0 0.0", i).unwrap();
+            writeln!(obj, "vt 0.0 0.0").unwrap();
+        }
+        for i in 0..(n / 2) {
+            let (a, b, c, d) = (2 * i + 1, 2 * i + 2, 2 * i + 3, 2 * i + 4);
+            writeln!(obj, "f {}/1 {}/1 {}/1", a, c, b).unwrap();
+            writeln!(obj, "f {} {} {}", b, c, d).unwrap();
+        }
+
+        let mut counts = vec![];
+        let graph = MeshGraph::<E3>::from_obj_with_progress(
+            PositionEncoding::<E3>::default(),
+            obj.as_bytes(),
+            |count| counts.push(count),
+        )
+        .unwrap();
+        assert_eq!(n, graph.face_count());
+        // Progress is reported at each interval and once all faces have been
+        // read.
+        assert_eq!(vec![PROGRESS_INTERVAL, n], counts);
+    }
+
+    #[test]
+    fn decode_relative_indices() {
+        let obj: &[u8] = b"v 0.0 0.0 0.0\nv 1.0 0.0 0.0\nv 0.0 1.0 0.0\nf -3 -2 -1\n";
+        let graph = MeshGraph::<E3>::from_obj(PositionEncoding::<E3>::default(), obj).unwrap();
+        assert_eq!(3, graph.vertex_count());
+        assert_eq!(1, graph.face_count());
+    }
+
+    #[test]
+    fn decode_out_of_bounds_index() {
+        let obj: &[u8] = b"v 0.0 0.0 0.0\nv 1.0 0.0 0.0\nv 0.0 1.0 0.0\nf 1 2 4\n";
+        assert!(matches!(
+            MeshGraph::<E3>::from_obj(PositionEncoding::<E3>::default(), obj),
+            Err(ObjError::IndexOutOfBounds(4)),
+        ));
+    }
+
+    #[test]
+    fn decode_non_manifold_into_graph() {
+        // Three triangles share an edge, which cannot be represented by a
+        // graph.
+        let obj: &[u8] = b"v 0.0 0.0 0.0\nv 1.0 0.0 0.0\nv 0.0 1.0 0.0\nv 0.0 -1.0 0.0\n\
+            v 0.0 0.0 1.0\nf 1 2 3\nf 2 1 4\nf 1 2 5\n";
+        assert!(matches!(
+            MeshGraph::<E3>::from_obj(PositionEncoding::<E3>::default(), obj),
+            Err(ObjError::Graph(GraphError::TopologyConflict)),
+        ));
+    }
+}
@@ -67,7 +67,10 @@ where
                 match metrics.entry(adjacent.key()) {
                     Entry::Occupied(entry) => {
                         if metric < entry.get().1 {
-                            entry.into_mut().1 = metric;
+                            *entry.into_mut() = (Some(entity.key()), metric);
+                        }
+                        else {
+                            continue;
                         }
                     }
                     Entry::Vacant(entry) => {
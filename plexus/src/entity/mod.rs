@@ -14,6 +14,8 @@ pub enum EntityError {
     EntityNotFound,
     #[error("data operation failed")]
     Data,
+    #[error("keyspace exhausted")]
+    KeyspaceExhausted,
 }
 
 pub trait Entity: Sized {
@@ -23,8 +25,12 @@ pub trait Entity: Sized {
 
 pub trait Payload: Entity {
     type Data;
+    /// Keys of adjacent entities that are captured by orphan views.
+    type Links: Copy;
 
     fn get(&self) -> &Self::Data;
 
     fn get_mut(&mut self) -> &mut Self::Data;
+
+    fn links(&self) -> Self::Links;
 }
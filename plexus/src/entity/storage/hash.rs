@@ -1,19 +1,21 @@
 use ahash::AHashMap;
+use std::collections::HashMap;
 use std::hash::Hash;
 use std::marker::PhantomData;
+use std::mem;
 
 use crate::entity::storage::{
     AsStorage, AsStorageMut, DependentStorage, Dispatch, Dynamic, Enumerate, Get, IncrementalKeyer,
-    IndependentStorage, InnerKey, Insert, InsertWithKey, Key, Keyer, Mode, Remove, Static,
-    StorageTarget,
+    IndependentStorage, InnerKey, Insert, InsertWithKey, Key, KeyIndex, Keyer, Mode, Remove,
+    Static, StorageTarget,
 };
-use crate::entity::{Entity, Payload};
+use crate::entity::{Entity, EntityError};
 
 // TODO: The `Keyer` parameter `R` of `HashStorage` cannot be parameterized when
 //       implementing the `AsStorage` and `Dispatch` traits even if the
 //       conflicting implementations use a private local type or the `Keyer`
 //       trait is private. Instead, this is implemented more specifically for
-//       `IncrementalKeyer`. Perhaps this will be possible in the future.
+//       `IncrementalKeyer<T>`. Perhaps this will be possible in the future.
 //
 //       See https://github.com/rust-lang/rust/issues/48869
 
@@ -35,11 +37,42 @@ where
     R: Default,
     P: Mode,
 {
+    pub fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional);
+    }
+
     pub fn shrink_to_fit(&mut self) {
         self.inner.shrink_to_fit();
     }
 }
 
+impl<E, R, P> HashStorage<E, R, P>
+where
+    E: Entity,
+    InnerKey<E::Key>: Eq + Hash + Ord,
+    R: Keyer<E::Key>,
+    P: Mode,
+{
+    /// Reinserts all entities with keys from a new keyer.
+    ///
+    /// Entities are reinserted in the order of their keys. Returns a mapping
+    /// from the previous keys of entities to their new keys.
+    pub fn rekey(&mut self) -> HashMap<E::Key, E::Key> {
+        let mut entities = mem::take(&mut self.inner).into_iter().collect::<Vec<_>>();
+        entities.sort_by(|(a, _), (b, _)| a.cmp(b));
+        self.keyer = R::default();
+        entities
+            .into_iter()
+            .map(|(key, entity)| {
+                let rekey = self
+                    .insert(entity)
+                    .expect("keyspace exhausted by fewer entities");
+                (Key::from_inner(key), rekey)
+            })
+            .collect()
+    }
+}
+
 impl<E> AsStorage<E> for HashStorage<E, (), Dynamic>
 where
     E: Entity<Storage = Self>,
@@ -50,10 +83,11 @@ where
     }
 }
 
-impl<E> AsStorage<E> for HashStorage<E, IncrementalKeyer, Dynamic>
+impl<E, T> AsStorage<E> for HashStorage<E, IncrementalKeyer<T>, Dynamic>
 where
     E: Entity<Storage = Self>,
-    E::Key: Key<Inner = u64>,
+    E::Key: Key<Inner = T>,
+    T: KeyIndex,
 {
     fn as_storage(&self) -> &StorageTarget<E> {
         self
@@ -81,10 +115,11 @@ where
     }
 }
 
-impl<E> AsStorageMut<E> for HashStorage<E, IncrementalKeyer, Dynamic>
+impl<E, T> AsStorageMut<E> for HashStorage<E, IncrementalKeyer<T>, Dynamic>
 where
     E: Entity<Storage = Self>,
-    E::Key: Key<Inner = u64>,
+    E::Key: Key<Inner = T>,
+    T: KeyIndex,
 {
     fn as_storage_mut(&mut self) -> &mut StorageTarget<E> {
         self
@@ -127,10 +162,11 @@ where
 }
 
 #[rustfmt::skip]
-impl<E> Dispatch<E> for HashStorage<E, IncrementalKeyer, Dynamic>
+impl<E, T> Dispatch<E> for HashStorage<E, IncrementalKeyer<T>, Dynamic>
 where
     E: Entity<Storage = Self>,
-    E::Key: Key<Inner = u64>,
+    E::Key: Key<Inner = T>,
+    T: KeyIndex,
 {
     type Target<'a> = dyn 'a + IndependentStorage<E> where E: 'a;
 }
@@ -164,14 +200,11 @@ where
         )
     }
 
-    fn iter_mut<'a>(&'a mut self) -> Box<dyn 'a + Iterator<Item = (E::Key, &mut E::Data)>>
-    where
-        E: Payload,
-    {
+    fn iter_mut<'a>(&'a mut self) -> Box<dyn 'a + Iterator<Item = (E::Key, &mut E)>> {
         Box::new(
             self.inner
                 .iter_mut()
-                .map(|(key, entity)| (E::Key::from_inner(*key), entity.get_mut())),
+                .map(|(key, entity)| (E::Key::from_inner(*key), entity)),
         )
     }
 }
@@ -199,10 +232,14 @@ where
     R: Keyer<E::Key>,
     P: Mode,
 {
-    fn insert(&mut self, entity: E) -> E::Key {
-        let key = self.keyer.next();
+    fn insert(&mut self, entity: E) -> Result<E::Key, EntityError> {
+        let key = self.keyer.next().ok_or(EntityError::KeyspaceExhausted)?;
         self.inner.insert(key, entity);
-        Key::from_inner(key)
+        Ok(Key::from_inner(key))
+    }
+
+    fn can_insert(&self, n: usize) -> bool {
+        self.keyer.can_key(n)
     }
 }
 
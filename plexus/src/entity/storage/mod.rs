@@ -1,8 +1,9 @@
 mod hash;
 
+use std::fmt::Debug;
 use std::hash::Hash;
 
-use crate::entity::{Entity, Payload};
+use crate::entity::{Entity, EntityError};
 
 pub use crate::entity::storage::hash::HashStorage;
 
@@ -22,26 +23,96 @@ pub trait Key: Copy + Eq + Hash + Sized {
     fn into_inner(self) -> Self::Inner;
 }
 
+/// Integer type used to represent keys.
+///
+/// The key index determines the size of keys and therefore the number of
+/// entities that can be inserted into storage. Smaller types reduce the size
+/// of keys, but exhaust sooner. This trait is implemented for unsigned integer
+/// types.
+pub trait KeyIndex: Copy + Debug + Default + Eq + Hash + Ord + Send + Sync + 'static {
+    /// Gets the next index or `None` if this is the greatest index.
+    fn checked_next(self) -> Option<Self>;
+
+    /// Converts a `u64` into an index or `None` if it is out of range.
+    fn from_u64(index: u64) -> Option<Self>;
+
+    /// Converts the index into a `u64`.
+    fn into_u64(self) -> u64;
+}
+
+macro_rules! impl_key_index {
+    (index => $t:ty) => {
+        impl KeyIndex for $t {
+            fn checked_next(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn from_u64(index: u64) -> Option<Self> {
+                <$t>::try_from(index).ok()
+            }
+
+            fn into_u64(self) -> u64 {
+                self as u64
+            }
+        }
+    };
+}
+impl_key_index!(index => u8);
+impl_key_index!(index => u16);
+impl_key_index!(index => u32);
+impl_key_index!(index => u64);
+impl_key_index!(index => usize);
+
 pub trait Keyer<K>: Clone + Default
 where
     K: Key,
 {
-    fn next(&mut self) -> K::Inner;
+    fn next(&mut self) -> Option<K::Inner>;
+
+    /// Returns `true` if the keyer can produce `n` more keys.
+    fn can_key(&self, n: usize) -> bool;
+}
+
+#[derive(Clone, Copy)]
+pub struct IncrementalKeyer<T = u64>
+where
+    T: KeyIndex,
+{
+    key: Option<T>,
 }
 
-#[derive(Clone, Copy, Default)]
-pub struct IncrementalKeyer {
-    key: u64,
+impl<T> Default for IncrementalKeyer<T>
+where
+    T: KeyIndex,
+{
+    fn default() -> Self {
+        IncrementalKeyer {
+            key: Some(Default::default()),
+        }
+    }
 }
 
-impl<K> Keyer<K> for IncrementalKeyer
+impl<K, T> Keyer<K> for IncrementalKeyer<T>
 where
-    K: Key<Inner = u64>,
+    K: Key<Inner = T>,
+    T: KeyIndex,
 {
-    fn next(&mut self) -> K::Inner {
-        let key = self.key;
-        self.key = self.key.checked_add(1).expect("keyspace exhausted");
-        key
+    fn next(&mut self) -> Option<K::Inner> {
+        let key = self.key?;
+        self.key = key.checked_next();
+        Some(key)
+    }
+
+    fn can_key(&self, n: usize) -> bool {
+        match (n, self.key) {
+            (0, _) => true,
+            (_, None) => false,
+            (n, Some(key)) => u64::try_from(n - 1)
+                .ok()
+                .and_then(|n| key.into_u64().checked_add(n))
+                .and_then(T::from_u64)
+                .is_some(),
+        }
     }
 }
 
@@ -77,9 +148,7 @@ where
 
     fn iter<'a>(&'a self) -> Box<dyn 'a + Iterator<Item = (E::Key, &E)>>;
 
-    fn iter_mut<'a>(&'a mut self) -> Box<dyn 'a + Iterator<Item = (E::Key, &mut E::Data)>>
-    where
-        E: Payload;
+    fn iter_mut<'a>(&'a mut self) -> Box<dyn 'a + Iterator<Item = (E::Key, &mut E)>>;
 }
 
 pub trait Get<E>
@@ -99,7 +168,11 @@ pub trait Insert<E>
 where
     E: Entity,
 {
-    fn insert(&mut self, entity: E) -> E::Key;
+    fn insert(&mut self, entity: E) -> Result<E::Key, EntityError>;
+
+    /// Returns `true` if `n` more entities can be inserted without exhausting
+    /// the keyspace.
+    fn can_insert(&self, n: usize) -> bool;
 }
 
 pub trait InsertWithKey<E>
@@ -317,6 +317,7 @@ where
 {
     data: &'a mut E::Data,
     key: E::Key,
+    links: E::Links,
 }
 
 impl<'a, E> Orphan<'a, E>
@@ -332,8 +333,13 @@ where
         View::bind(storage, key).map(Orphan::from)
     }
 
-    pub fn bind_unchecked(data: &'a mut E::Data, key: E::Key) -> Self {
-        Orphan { data, key }
+    pub fn bind_unchecked(entity: &'a mut E, key: E::Key) -> Self {
+        let links = entity.links();
+        Orphan {
+            data: entity.get_mut(),
+            key,
+            links,
+        }
     }
 
     pub fn bind_into<T, M>(storage: &'a mut M, key: E::Key) -> Option<T>
@@ -356,6 +362,11 @@ where
     pub fn key(&self) -> E::Key {
         self.key
     }
+
+    /// Gets the keys of adjacent entities captured when the orphan was bound.
+    pub fn links(&self) -> E::Links {
+        self.links
+    }
 }
 
 impl<'a, E> AsRef<E::Key> for Orphan<'a, E>
@@ -393,7 +404,7 @@ where
             .as_storage_mut()
             .get_mut(&key)
             .expect("view key invalidated");
-        Orphan::bind_unchecked(entity.get_mut(), key)
+        Orphan::bind_unchecked(entity, key)
     }
 }
 
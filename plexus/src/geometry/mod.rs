@@ -9,9 +9,13 @@
 //!
 //! [`integration`]: crate::integration
 
-use num::{One, Zero};
+use num::{NumCast, One, ToPrimitive, Zero};
+use smallvec::SmallVec;
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace};
+use typenum::U3;
 
 pub mod partition;
+pub mod tree;
 
 pub use theon::query::*;
 pub use theon::space::{Scalar, Vector};
@@ -88,3 +92,80 @@ where
 pub trait Metric: Eq + One + Ord + Zero {}
 
 impl<Q> Metric for Q where Q: Eq + One + Ord + Zero {}
+
+// Gets the cell of a uniform spatial grid with the given cell size that
+// contains a position. The coordinates of the cell are measured along each of
+// the given basis vectors.
+pub(crate) fn grid_cell<S>(basis: &[Vector<S>], position: S, size: Scalar<S>) -> SmallVec<[i64; 3]>
+where
+    S: EuclideanSpace,
+{
+    let coordinates = position.into_coordinates();
+    basis
+        .iter()
+        .map(|axis| {
+            <f64 as NumCast>::from(coordinates.dot(*axis) / size)
+                .map(|coordinate| coordinate.floor() as i64)
+                .unwrap_or(0)
+        })
+        .collect()
+}
+
+// Gets the cells of a uniform spatial grid that are adjacent to (or the same
+// as) the given cell, including diagonal neighbors. There are `3ⁿ` such cells
+// for a grid with `n` dimensions.
+pub(crate) fn neighboring_cells(origin: &[i64]) -> Vec<SmallVec<[i64; 3]>> {
+    let mut neighbors = vec![SmallVec::<[i64; 3]>::new()];
+    for coordinate in origin.iter() {
+        neighbors = neighbors
+            .into_iter()
+            .flat_map(|neighbor| {
+                (-1..=1).map(move |offset| {
+                    let mut neighbor = neighbor.clone();
+                    neighbor.push(coordinate + offset);
+                    neighbor
+                })
+            })
+            .collect();
+    }
+    neighbors
+}
+
+// Converts a position in three dimensions into `f64` coordinates for numeric
+// routines that operate on arrays, such as the solvers in the `metric` and
+// `deformation` modules. Returns `None` if any coordinate cannot be represented.
+pub(crate) fn into_f64<S>(position: S) -> Option<[f64; 3]>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let (x, y, z) = position.into_xyz();
+    Some([x.to_f64()?, y.to_f64()?, z.to_f64()?])
+}
+
+pub(crate) fn add(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+pub(crate) fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+pub(crate) fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    (a[0] * b[0]) + (a[1] * b[1]) + (a[2] * b[2])
+}
+
+pub(crate) fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        (a[1] * b[2]) - (a[2] * b[1]),
+        (a[2] * b[0]) - (a[0] * b[2]),
+        (a[0] * b[1]) - (a[1] * b[0]),
+    ]
+}
+
+pub(crate) fn norm(a: [f64; 3]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+pub(crate) fn scale(a: [f64; 3], factor: f64) -> [f64; 3] {
+    [a[0] * factor, a[1] * factor, a[2] * factor]
+}
@@ -0,0 +1,476 @@
+//! Spatial trees.
+
+use num::Zero;
+use smallvec::SmallVec;
+use std::cmp::Ordering;
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use theon::space::{Basis, EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use typenum::U3;
+
+use crate::geometry::{self, cross, dot, sub};
+
+/// $k$-d tree of points.
+///
+/// A $k$-d tree partitions points in a Euclidean space by recursively
+/// splitting them along each of the canonical basis vectors of the space. This
+/// supports nearest neighbor and range queries in logarithmic time on average.
+/// Each point is associated with a value, such as a key or index into a mesh
+/// data structure.
+///
+/// The tree is balanced and immutable. Points are inserted when the tree is
+/// constructed via [`FromIterator`].
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::Point2;
+/// use plexus::geometry::tree::KdTree;
+///
+/// let tree = vec![
+///     (Point2::new(0.0, 0.0), 'a'),
+///     (Point2::new(1.0, 0.0), 'b'),
+///     (Point2::new(0.0, 1.0), 'c'),
+///     (Point2::new(4.0, 4.0), 'd'),
+/// ]
+/// .into_iter()
+/// .collect::<KdTree<_, _>>();
+///
+/// assert_eq!(vec!['b', 'a'], tree.nearest(Point2::new(0.9, 0.1), 2));
+/// assert_eq!(3, tree.within_radius(Point2::new(0.0, 0.0), 1.5).len());
+/// ```
+///
+/// [`FromIterator`]: std::iter::FromIterator
+pub struct KdTree<S, T>
+where
+    S: EuclideanSpace,
+{
+    basis: SmallVec<[Vector<S>; 3]>,
+    // Points are ordered such that the median of any range of points is the
+    // root of the sub-tree formed by that range.
+    points: Vec<(S, T)>,
+}
+
+impl<S, T> KdTree<S, T>
+where
+    S: EuclideanSpace,
+    T: Copy,
+{
+    /// Gets the number of points in the tree.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns `true` if there are no points in the tree.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Gets the values of the `k` nearest points to the given point.
+    ///
+    /// Values are ordered by the distance of their points from the given
+    /// point, nearest first. Fewer than `k` values are returned if the tree
+    /// has fewer than `k` points.
+    pub fn nearest(&self, point: S, k: usize) -> Vec<T> {
+        let mut nearest = Vec::with_capacity(k + 1);
+        if k > 0 {
+            self.find_nearest(point, k, 0, self.points.len(), 0, &mut nearest);
+        }
+        nearest.into_iter().map(|(_, value)| value).collect()
+    }
+
+    /// Gets the values of all points within the given distance of the given
+    /// point.
+    ///
+    /// The order of values is unspecified.
+    pub fn within_radius<U>(&self, point: S, radius: U) -> Vec<T>
+    where
+        U: Into<Scalar<S>>,
+    {
+        let radius = radius.into();
+        let mut within = Vec::new();
+        self.find_within(point, radius, 0, self.points.len(), 0, &mut within);
+        within
+    }
+
+    fn find_nearest(
+        &self,
+        point: S,
+        k: usize,
+        start: usize,
+        end: usize,
+        depth: usize,
+        nearest: &mut Vec<(Scalar<S>, T)>,
+    ) {
+        if start >= end {
+            return;
+        }
+        let median = (start + end) / 2;
+        let (position, value) = self.points[median];
+        let distance = (position - point).square_magnitude();
+        if nearest.len() < k || distance < nearest[nearest.len() - 1].0 {
+            let index = nearest
+                .iter()
+                .position(|(other, _)| distance < *other)
+                .unwrap_or(nearest.len());
+            nearest.insert(index, (distance, value));
+            nearest.truncate(k);
+        }
+        let offset = self.offset(point, position, depth);
+        let (near, far) = if offset < Zero::zero() {
+            ((start, median), (median + 1, end))
+        }
+        else {
+            ((median + 1, end), (start, median))
+        };
+        self.find_nearest(point, k, near.0, near.1, depth + 1, nearest);
+        // Only visit the far side of the splitting plane if it may contain a
+        // point nearer than the farthest point found so far.
+        if nearest.len() < k || (offset * offset) < nearest[nearest.len() - 1].0 {
+            self.find_nearest(point, k, far.0, far.1, depth + 1, nearest);
+        }
+    }
+
+    fn find_within(
+        &self,
+        point: S,
+        radius: Scalar<S>,
+        start: usize,
+        end: usize,
+        depth: usize,
+        within: &mut Vec<T>,
+    ) {
+        if start >= end {
+            return;
+        }
+        let median = (start + end) / 2;
+        let (position, value) = self.points[median];
+        if (position - point).square_magnitude() <= radius * radius {
+            within.push(value);
+        }
+        let offset = self.offset(point, position, depth);
+        if offset <= radius {
+            self.find_within(point, radius, start, median, depth + 1, within);
+        }
+        if offset >= -radius {
+            self.find_within(point, radius, median + 1, end, depth + 1, within);
+        }
+    }
+
+    // Gets the signed distance from the splitting plane of a node to a point.
+    // The distance is negative if the point is on the near (lesser) side of
+    // the plane.
+    fn offset(&self, point: S, position: S, depth: usize) -> Scalar<S> {
+        let axis = self.basis[depth % self.basis.len()];
+        (point - position).dot(axis)
+    }
+}
+
+impl<S, T> FromIterator<(S, T)> for KdTree<S, T>
+where
+    S: EuclideanSpace,
+    Vector<S>: Basis,
+{
+    fn from_iter<I>(input: I) -> Self
+    where
+        I: IntoIterator<Item = (S, T)>,
+    {
+        let basis = Vector::<S>::canonical_basis()
+            .into_iter()
+            .collect::<SmallVec<[_; 3]>>();
+        let mut points = input.into_iter().collect::<Vec<_>>();
+        partition(&basis, &mut points, 0);
+        KdTree { basis, points }
+    }
+}
+
+// Recursively orders points such that the median of each range is the root of
+// a sub-tree split along the axis for its depth.
+fn partition<S, T>(basis: &[Vector<S>], points: &mut [(S, T)], depth: usize)
+where
+    S: EuclideanSpace,
+{
+    if points.len() < 2 {
+        return;
+    }
+    let axis = basis[depth % basis.len()];
+    let median = points.len() / 2;
+    points.select_nth_unstable_by(median, |(a, _), (b, _)| {
+        let (a, b) = (
+            a.into_coordinates().dot(axis),
+            b.into_coordinates().dot(axis),
+        );
+        a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+    });
+    let (lesser, greater) = points.split_at_mut(median);
+    partition(basis, lesser, depth + 1);
+    partition(basis, &mut greater[1..], depth + 1);
+}
+
+/// Bounding volume hierarchy of triangles.
+///
+/// A bounding volume hierarchy (BVH) partitions triangles in a
+/// three-dimensional Euclidean space by recursively splitting them into groups
+/// enclosed by axis-aligned bounding boxes. This supports ray intersection
+/// queries in logarithmic time on average, which is useful for picking,
+/// collision detection, and baking. Each triangle is associated with a value,
+/// such as a key or index into a mesh data structure.
+///
+/// The tree is balanced and immutable. Triangles are inserted when the tree is
+/// constructed via [`FromIterator`]. Geometry is converted to `f64` and
+/// triangles with coordinates that cannot be represented never intersect rays.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::{Point3, Vector3};
+/// use plexus::geometry::tree::BvhTree;
+///
+/// let tree = vec![
+///     (
+///         [
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///         ],
+///         'a',
+///     ),
+///     (
+///         [
+///             Point3::new(0.0, 0.0, 1.0),
+///             Point3::new(1.0, 0.0, 1.0),
+///             Point3::new(0.0, 1.0, 1.0),
+///         ],
+///         'b',
+///     ),
+/// ]
+/// .into_iter()
+/// .collect::<BvhTree<_, _>>();
+///
+/// let (value, t, _) = tree
+///     .ray_intersect(Point3::new(0.25, 0.25, 2.0), -Vector3::z())
+///     .unwrap();
+/// assert_eq!('b', value);
+/// assert_eq!(1.0, t);
+/// ```
+///
+/// [`FromIterator`]: std::iter::FromIterator
+pub struct BvhTree<S, T>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    // Nodes are ordered depth-first, such that the left child of a branch
+    // immediately follows it.
+    nodes: Vec<BvhNode>,
+    triangles: Vec<([[f64; 3]; 3], T)>,
+    phantom: PhantomData<fn() -> S>,
+}
+
+impl<S, T> BvhTree<S, T>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    T: Copy,
+{
+    /// Gets the number of triangles in the tree.
+    pub fn len(&self) -> usize {
+        self.triangles.len()
+    }
+
+    /// Returns `true` if there are no triangles in the tree.
+    pub fn is_empty(&self) -> bool {
+        self.triangles.is_empty()
+    }
+
+    /// Gets the nearest triangle intersected by a ray.
+    ///
+    /// Returns the value of the triangle, the parameter $t$ of the
+    /// intersection such that the point of intersection is $o + t\vec{d}$
+    /// for the origin $o$ and direction $\vec{d}$ of the ray, and the
+    /// barycentric coordinates of the point of intersection with respect to
+    /// the vertices of the triangle in order. Triangles are two-sided and only
+    /// intersections where $t \geq 0$ are considered. Returns `None` if the
+    /// ray does not intersect any triangle.
+    pub fn ray_intersect(&self, origin: S, direction: Vector<S>) -> Option<(T, f64, [f64; 3])> {
+        let origin = geometry::into_f64(origin).unwrap_or(UNREPRESENTABLE);
+        let direction = geometry::into_f64(S::origin() + direction).unwrap_or(UNREPRESENTABLE);
+        let inverse = [1.0 / direction[0], 1.0 / direction[1], 1.0 / direction[2]];
+        let mut nearest: Option<(usize, f64, [f64; 3])> = None;
+        let mut stack = Vec::with_capacity(32);
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            let limit = nearest.map_or(f64::INFINITY, |(_, t, _)| t);
+            if !node.is_intersected_by(origin, inverse, limit) {
+                continue;
+            }
+            if let Some(right) = node.right {
+                stack.push(right);
+                stack.push(index + 1);
+            }
+            else {
+                for n in node.start..node.end {
+                    let (triangle, _) = &self.triangles[n];
+                    if let Some((t, barycentric)) = intersect(triangle, origin, direction) {
+                        if t < nearest.map_or(f64::INFINITY, |(_, t, _)| t) {
+                            nearest = Some((n, t, barycentric));
+                        }
+                    }
+                }
+            }
+        }
+        nearest.map(|(n, t, barycentric)| (self.triangles[n].1, t, barycentric))
+    }
+}
+
+impl<S, T> FromIterator<([S; 3], T)> for BvhTree<S, T>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    fn from_iter<I>(input: I) -> Self
+    where
+        I: IntoIterator<Item = ([S; 3], T)>,
+    {
+        let mut triangles = input
+            .into_iter()
+            .map(|(triangle, value)| {
+                let triangle =
+                    triangle.map(|point| geometry::into_f64(point).unwrap_or(UNREPRESENTABLE));
+                (triangle, value)
+            })
+            .collect::<Vec<_>>();
+        let mut nodes = Vec::with_capacity(triangles.len() / BVH_LEAF_SIZE * 2);
+        if !triangles.is_empty() {
+            partition_triangles(&mut nodes, &mut triangles, 0);
+        }
+        BvhTree {
+            nodes,
+            triangles,
+            phantom: PhantomData,
+        }
+    }
+}
+
+// Maximum number of triangles in a leaf of a BVH.
+const BVH_LEAF_SIZE: usize = 4;
+
+// Coordinates of points that cannot be represented as `f64`. Triangles with
+// such points never intersect rays.
+const UNREPRESENTABLE: [f64; 3] = [f64::NAN; 3];
+
+struct BvhNode {
+    lower: [f64; 3],
+    upper: [f64; 3],
+    // Range of triangles enclosed by the node.
+    start: usize,
+    end: usize,
+    // Index of the right child of a branch. Leaves have no children.
+    right: Option<usize>,
+}
+
+impl BvhNode {
+    // Slab test of a ray against the bounding box of the node.
+    fn is_intersected_by(&self, origin: [f64; 3], inverse: [f64; 3], limit: f64) -> bool {
+        let (mut near, mut far) = (0.0f64, limit);
+        let slabs = self.lower.iter().zip(self.upper.iter());
+        for ((lower, upper), (origin, inverse)) in slabs.zip(origin.iter().zip(inverse.iter())) {
+            let t1 = (lower - origin) * inverse;
+            let t2 = (upper - origin) * inverse;
+            // `min` and `max` ignore `NaN`, which arises when the ray lies in
+            // the plane of a face of the box.
+            near = near.max(t1.min(t2));
+            far = far.min(t1.max(t2));
+        }
+        near <= far
+    }
+}
+
+// Recursively orders triangles and pushes nodes such that each node encloses
+// a contiguous range of triangles split at the median of their centroids along
+// the longest axis of its bounding box.
+fn partition_triangles<T>(
+    nodes: &mut Vec<BvhNode>,
+    triangles: &mut [([[f64; 3]; 3], T)],
+    start: usize,
+) {
+    let mut lower = [f64::INFINITY; 3];
+    let mut upper = [f64::NEG_INFINITY; 3];
+    for (triangle, _) in triangles.iter() {
+        for point in triangle {
+            for ((min, max), x) in lower.iter_mut().zip(upper.iter_mut()).zip(point.iter()) {
+                *min = min.min(*x);
+                *max = max.max(*x);
+            }
+        }
+    }
+    let index = nodes.len();
+    nodes.push(BvhNode {
+        lower,
+        upper,
+        start,
+        end: start + triangles.len(),
+        right: None,
+    });
+    if triangles.len() <= BVH_LEAF_SIZE {
+        return;
+    }
+    let axis = (0..3)
+        .max_by(|a, b| {
+            let (a, b) = (upper[*a] - lower[*a], upper[*b] - lower[*b]);
+            a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+        })
+        .unwrap();
+    let centroid = |triangle: &[[f64; 3]; 3]| triangle.iter().map(|point| point[axis]).sum::<f64>();
+    let median = triangles.len() / 2;
+    triangles.select_nth_unstable_by(median, |(a, _), (b, _)| {
+        centroid(a)
+            .partial_cmp(&centroid(b))
+            .unwrap_or(Ordering::Equal)
+    });
+    let (lesser, greater) = triangles.split_at_mut(median);
+    partition_triangles(nodes, lesser, start);
+    nodes[index].right = Some(nodes.len());
+    partition_triangles(nodes, greater, start + median);
+}
+
+// Intersects a ray with a triangle using the Möller-Trumbore algorithm.
+fn intersect(
+    [a, b, c]: &[[f64; 3]; 3],
+    origin: [f64; 3],
+    direction: [f64; 3],
+) -> Option<(f64, [f64; 3])> {
+    const EPSILON: f64 = 1e-12;
+
+    let (ab, ac) = (sub(*b, *a), sub(*c, *a));
+    let p = cross(direction, ac);
+    let determinant = dot(ab, p);
+    // Reject rays that are parallel to the triangle and degenerate triangles.
+    if determinant.is_nan() || determinant.abs() <= EPSILON {
+        return None;
+    }
+    let inverse = 1.0 / determinant;
+    let ao = sub(origin, *a);
+    let u = dot(ao, p) * inverse;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let q = cross(ao, ab);
+    let v = dot(direction, q) * inverse;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let t = dot(ac, q) * inverse;
+    if t >= 0.0 {
+        Some((t, [1.0 - u - v, u, v]))
+    }
+    else {
+        None
+    }
+}
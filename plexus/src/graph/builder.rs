@@ -1,4 +1,5 @@
 use crate::builder::{FacetBuilder, MeshBuilder, SurfaceBuilder};
+use crate::entity::storage::KeyIndex;
 use crate::geometry::{FromGeometry, IntoGeometry};
 use crate::graph::data::GraphData;
 use crate::graph::face::FaceKey;
@@ -9,16 +10,18 @@ use crate::graph::vertex::VertexKey;
 use crate::graph::{GraphError, MeshGraph};
 use crate::transact::{ClosedInput, Transact};
 
-pub struct GraphBuilder<G>
+pub struct GraphBuilder<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    mutation: Mutation<Immediate<MeshGraph<G>>>,
+    mutation: Mutation<Immediate<MeshGraph<G, K>>>,
 }
 
-impl<G> Default for GraphBuilder<G>
+impl<G, K> Default for GraphBuilder<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     fn default() -> Self {
         GraphBuilder {
@@ -27,16 +30,18 @@ where
     }
 }
 
-impl<G> ClosedInput for GraphBuilder<G>
+impl<G, K> ClosedInput for GraphBuilder<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     type Input = ();
 }
 
-impl<G> MeshBuilder for GraphBuilder<G>
+impl<G, K> MeshBuilder for GraphBuilder<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     type Builder = Self;
 
@@ -52,11 +57,12 @@ where
     }
 }
 
-impl<G> Transact<<Self as ClosedInput>::Input> for GraphBuilder<G>
+impl<G, K> Transact<<Self as ClosedInput>::Input> for GraphBuilder<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    type Commit = MeshGraph<G>;
+    type Commit = MeshGraph<G, K>;
     type Abort = ();
     type Error = GraphError;
 
@@ -68,12 +74,13 @@ where
     fn abort(self) -> Self::Abort {}
 }
 
-impl<G> SurfaceBuilder for GraphBuilder<G>
+impl<G, K> SurfaceBuilder for GraphBuilder<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     type Builder = Self;
-    type Key = VertexKey;
+    type Key = VertexKey<K>;
 
     type Vertex = G::Vertex;
     type Facet = G::Face;
@@ -90,21 +97,22 @@ where
     where
         Self::Vertex: FromGeometry<T>,
     {
-        Ok(vertex::insert(&mut self.mutation, data.into_geometry()))
+        vertex::insert(&mut self.mutation, data.into_geometry())
     }
 }
 
-impl<G> FacetBuilder<VertexKey> for GraphBuilder<G>
+impl<G, K> FacetBuilder<VertexKey<K>> for GraphBuilder<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     type Facet = G::Face;
-    type Key = FaceKey;
+    type Key = FaceKey<K>;
 
     fn insert_facet<T, U>(&mut self, keys: T, data: U) -> Result<Self::Key, Self::Error>
     where
         Self::Facet: FromGeometry<U>,
-        T: AsRef<[VertexKey]>,
+        T: AsRef<[VertexKey<K>]>,
     {
         let cache = FaceInsertCache::from_storage(&self.mutation, keys.as_ref())?;
         let data = data.into_geometry();
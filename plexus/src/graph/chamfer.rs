@@ -0,0 +1,421 @@
+use num::Zero;
+use smallvec::SmallVec;
+use std::collections::{HashMap, HashSet};
+use theon::space::{EuclideanSpace, InnerSpace, Scalar};
+use theon::AsPositionMut;
+
+use crate::entity::storage::KeyIndex;
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::edge::{ArcKey, ArcView, EdgeKey};
+use crate::graph::face::FaceKey;
+use crate::graph::geometry::{self, FaceNormal, VertexPosition};
+use crate::graph::mutation::face::FaceInsertCache;
+use crate::graph::mutation::{self, Immediate};
+use crate::graph::vertex::VertexKey;
+use crate::graph::{GraphError, MeshGraph, OptionExt as _, TopologyKind};
+use crate::transact::Transact;
+
+type Mutation<M> = mutation::Mutation<Immediate<M>>;
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Chamfers edges in the graph.
+    ///
+    /// Each edge is replaced by a face that joins its adjacent faces, which
+    /// are cut back from the edge. At each endpoint of a chamfered edge, the
+    /// adjacent faces are cut back by moving their corners the given offset
+    /// along their other edges. Where chamfered edges meet at a vertex (e.g.,
+    /// at the corners of a cube), the gap between the chamfer faces is filled
+    /// by a corner face. Returns the keys of the inserted chamfer and corner
+    /// faces.
+    ///
+    /// The offset should be less than the lengths of the edges along which
+    /// corners are moved (or half of their lengths if both of their endpoints
+    /// are moved), otherwise faces may overlap. Faces are copied with their
+    /// data, but the data of inserted faces and of all arcs and edges is
+    /// default. Layers are migrated to copied faces and to vertices that are
+    /// not moved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an edge cannot be found, if an edge or any edge at
+    /// its endpoints is a boundary edge, if the offset is not positive, or if
+    /// geometry is degenerate. Errors concerning a given edge are wrapped with
+    /// the key of that edge (see [`GraphError::AtKey`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+    /// let key = graph.edges().nth(0).unwrap().key();
+    /// graph.chamfer_edges(&[key], 0.1).unwrap();
+    ///
+    /// assert_eq!(7, graph.face_count());
+    /// ```
+    ///
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    pub fn chamfer_edges<T>(
+        &mut self,
+        edges: &[EdgeKey<K>],
+        offset: T,
+    ) -> Result<Vec<FaceKey<K>>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        // The vertices that form the corners of faces. Faces that share a
+        // site share its vertex.
+        #[derive(Clone, Copy, Eq, Hash, PartialEq)]
+        enum Site<K>
+        where
+            K: KeyIndex,
+        {
+            // A copy of a vertex for a sector of its adjacent faces. Sectors
+            // are delimited by chamfered edges.
+            Vertex(VertexKey<K>, usize),
+            // A vertex moved along an edge toward the given vertex.
+            Edge(VertexKey<K>, VertexKey<K>),
+            // A vertex moved into a face along both of its edges at the vertex.
+            Face(VertexKey<K>, FaceKey<K>),
+        }
+
+        let offset = offset.into();
+        if offset <= Zero::zero() {
+            return Err(GraphError::Geometry);
+        }
+        let mut chamfered = HashSet::with_capacity(edges.len());
+        for key in edges {
+            let edge = self
+                .edge(*key)
+                .ok_or_else(|| GraphError::TopologyNotFound(TopologyKind::Edge).at_key(*key))?;
+            let arc = edge.arc();
+            if [arc.source_vertex(), arc.destination_vertex()]
+                .iter()
+                .any(|vertex| vertex.is_boundary_vertex())
+            {
+                return Err(GraphError::TopologyMalformed.at_key(*key));
+            }
+            chamfered.insert(*key);
+        }
+        let endpoints = chamfered
+            .iter()
+            .flat_map(|key| {
+                let (a, b) = self.edge(*key).expect_consistent().arc().key().into();
+                [a, b]
+            })
+            .collect::<HashSet<VertexKey<K>>>();
+        let is_chamfered = |arc: ArcView<&Self>| chamfered.contains(&arc.edge().key());
+        // Assign sectors to the faces about each endpoint of a chamfered edge,
+        // keyed by the arc of each face that is incoming to the endpoint.
+        // Faces are visited in order, beginning after a chamfered edge.
+        let mut sectors = HashMap::<ArcKey<K>, usize>::new();
+        for key in endpoints.iter() {
+            let start = self
+                .vertex(*key)
+                .expect_consistent()
+                .into_incoming_arcs()
+                .find(|arc| is_chamfered(arc.into_next_arc()))
+                .expect_consistent();
+            let mut arc = start;
+            let mut sector = 0;
+            loop {
+                sectors.insert(arc.key(), sector);
+                if is_chamfered(arc) {
+                    sector += 1;
+                }
+                arc = arc.into_opposite_arc().into_previous_arc();
+                if arc.key() == start.key() {
+                    break;
+                }
+            }
+        }
+        // An edge `ab` has a site at `a` if a face at `a` is adjacent to `ab`
+        // and a chamfered edge.
+        let has_edge_site = |ab: ArcView<&Self>| {
+            !is_chamfered(ab)
+                && (is_chamfered(ab.into_previous_arc())
+                    || is_chamfered(ab.into_opposite_arc().into_next_arc()))
+        };
+        // Gets the sites of the corner of a face at the destination of an arc.
+        // Sites are ordered as in the ring of the face.
+        let corner = |arc: ArcView<&Self>| -> SmallVec<[Site<K>; 3]> {
+            let (u, v) = arc.key().into();
+            let next = arc.into_next_arc();
+            let (_, w) = next.key().into();
+            match sectors.get(&arc.key()) {
+                None => [Site::Vertex(v, 0)].into_iter().collect(),
+                Some(sector) => match (is_chamfered(arc), is_chamfered(next)) {
+                    (true, true) => [Site::Face(v, arc.face().expect_consistent().key())]
+                        .into_iter()
+                        .collect(),
+                    (true, false) => [Site::Edge(v, w)].into_iter().collect(),
+                    (false, true) => [Site::Edge(v, u)].into_iter().collect(),
+                    (false, false) => {
+                        let is_incoming = has_edge_site(arc.into_opposite_arc());
+                        let is_outgoing = has_edge_site(next);
+                        let mut sites = SmallVec::new();
+                        if is_incoming {
+                            sites.push(Site::Edge(v, u));
+                        }
+                        if !(is_incoming && is_outgoing) {
+                            sites.push(Site::Vertex(v, *sector));
+                        }
+                        if is_outgoing {
+                            sites.push(Site::Edge(v, w));
+                        }
+                        sites
+                    }
+                },
+            }
+        };
+        let corners = self
+            .arcs()
+            .filter(|arc| !arc.is_boundary_arc())
+            .map(|arc| (arc.key(), corner(arc)))
+            .collect::<HashMap<_, _>>();
+        // Insert a vertex for each site.
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let mut vertices = HashMap::with_capacity(self.vertex_count());
+        let direction = |a: VertexKey<K>, b: VertexKey<K>| {
+            let a = *self.vertex(a).expect_consistent().position();
+            let b = *self.vertex(b).expect_consistent().position();
+            (b - a).normalize().ok_or(GraphError::Geometry)
+        };
+        for site in corners.values().flatten() {
+            if vertices.contains_key(site) {
+                continue;
+            }
+            let (key, translation) = match *site {
+                Site::Vertex(v, _) => (v, None),
+                Site::Edge(v, w) => (v, Some(direction(v, w)? * offset)),
+                Site::Face(v, f) => {
+                    let arc = self
+                        .face(f)
+                        .expect_consistent()
+                        .into_adjacent_arcs()
+                        .find(|arc| arc.destination_vertex().key() == v)
+                        .expect_consistent();
+                    let (u, _) = arc.key().into();
+                    let (_, w) = arc.into_next_arc().key().into();
+                    (v, Some((direction(v, u)? + direction(v, w)?) * offset))
+                }
+            };
+            let data = self.vertex(key).expect_consistent().get().clone();
+            let data = match translation {
+                Some(translation) => data.map_position(|position| *position + translation),
+                _ => data,
+            };
+            vertices.insert(*site, mutation::vertex::insert(&mut mutation, data)?);
+        }
+        // Inserts a face with the vertices of the given sites. Adjacent sites
+        // may share a vertex, so the perimeter is deduplicated and no face is
+        // inserted if it has fewer than three vertices.
+        let insert = |mutation: &mut Mutation<Self>, sites: &[Site<K>], data: G::Face| {
+            let mut perimeter = sites.iter().map(|site| vertices[site]).collect::<Vec<_>>();
+            perimeter.dedup();
+            if perimeter.len() > 1 && perimeter.first() == perimeter.last() {
+                perimeter.pop();
+            }
+            if perimeter.len() < 3 {
+                return Ok(None);
+            }
+            FaceInsertCache::from_storage(&*mutation, &perimeter)
+                .and_then(|cache| {
+                    mutation::face::insert_with(mutation, cache, || (Default::default(), data))
+                })
+                .map(Some)
+        };
+        // Insert each face with its corners.
+        let mut faces = HashMap::with_capacity(self.face_count());
+        for face in self.faces() {
+            let sites = face
+                .adjacent_arcs()
+                .flat_map(|arc| corners[&arc.previous_arc().key()].clone())
+                .collect::<Vec<_>>();
+            if let Some(key) = insert(&mut mutation, &sites, face.get().clone())? {
+                faces.insert(face.key(), key);
+            }
+        }
+        // Insert a chamfer face for each chamfered edge `ab` between the cut
+        // corners of its adjacent faces `abc` and `bad`.
+        let mut keys = Vec::new();
+        for key in chamfered.iter() {
+            let ab = self.edge(*key).expect_consistent().into_arc();
+            let ba = ab.opposite_arc();
+            let sites = [
+                corners[&ab.key()][0],
+                *corners[&ab.previous_arc().key()].last().expect_consistent(),
+                corners[&ba.key()][0],
+                *corners[&ba.previous_arc().key()].last().expect_consistent(),
+            ];
+            keys.extend(insert(&mut mutation, &sites, Default::default())?);
+        }
+        // Insert a corner face at each endpoint of chamfered edges. The faces
+        // about the endpoint are visited in the same order as sectors and the
+        // sites of their corners are reversed.
+        for key in endpoints.iter() {
+            let start = self
+                .vertex(*key)
+                .expect_consistent()
+                .into_outgoing_arc()
+                .into_previous_arc();
+            let mut arc = start;
+            let mut sites = Vec::new();
+            loop {
+                sites.extend(corners[&arc.key()].iter().rev().cloned());
+                arc = arc.into_opposite_arc().into_previous_arc();
+                if arc.key() == start.key() {
+                    break;
+                }
+            }
+            keys.extend(insert(&mut mutation, &sites, Default::default())?);
+        }
+        let graph = mutation.commit().map_err(|(_, error)| error)?;
+        // Vertices retain their values in layers at the copy for their first
+        // sector. Vertices that are moved along edges or into faces do not.
+        let vertices = vertices
+            .iter()
+            .filter_map(|(site, key)| match *site {
+                Site::Vertex(v, 0) => Some((v, *key)),
+                _ => None,
+            })
+            .collect();
+        self.replace_with_rebuild(graph, &vertices, &faces);
+        Ok(keys)
+    }
+
+    /// Chamfers sharp edges in the graph.
+    ///
+    /// An edge is sharp if the angle between the normals of its adjacent faces
+    /// exceeds the given angle in radians. Edges on boundaries are never
+    /// chamfered. See [`MeshGraph::chamfer_edges`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a sharp edge has an endpoint on a boundary, if the
+    /// offset is not positive, or if geometry is degenerate.
+    ///
+    /// [`MeshGraph::chamfer_edges`]: crate::graph::MeshGraph::chamfer_edges
+    pub fn chamfer_sharp_edges<T>(
+        &mut self,
+        angle: T,
+        offset: T,
+    ) -> Result<Vec<FaceKey<K>>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: FaceNormal,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let angle = angle.into();
+        let mut edges = Vec::new();
+        for edge in self.edges() {
+            let arc = edge.arc();
+            if let (Some(abc), Some(bad)) = (arc.face(), arc.opposite_arc().face()) {
+                let (n, m) = (abc.normal()?, bad.normal()?);
+                if geometry::vector_angle::<VertexPosition<G>>(n, m) > angle {
+                    edges.push(edge.key());
+                }
+            }
+        }
+        self.chamfer_edges(&edges, offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::graph::{GraphError, MeshGraph};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn chamfer_edges() {
+        let cube = || {
+            Cube::new()
+                .polygons::<Position<E3>>()
+                .collect::<MeshGraph<Point3<f64>>>()
+        };
+
+        // Chamfering an edge of a cube cuts a triangular prism from the cube.
+        // The faces adjacent to the edge remain quadrilaterals and the faces at
+        // its endpoints become pentagons.
+        let mut graph = cube();
+        let key = graph.edges().nth(0).unwrap().key();
+        let keys = graph.chamfer_edges(&[key], 0.1).unwrap();
+        assert_eq!(1, keys.len());
+        assert_eq!(4, graph.face(keys[0]).unwrap().arity());
+        assert_eq!(10, graph.vertex_count());
+        assert_eq!(15, graph.edge_count());
+        assert_eq!(7, graph.face_count());
+        assert_eq!(2, graph.faces_with_arity(5).count());
+        assert!((graph.volume().unwrap() - 0.995).abs() < 1e-9);
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+        graph.verify_gauss_bonnet(1e-9).unwrap();
+
+        // Chamfering all edges of a cube inserts a triangle at each corner.
+        let mut graph = cube();
+        let keys = graph.chamfer_sharp_edges(0.1, 0.1).unwrap();
+        assert_eq!(20, keys.len());
+        assert_eq!(24, graph.vertex_count());
+        assert_eq!(48, graph.edge_count());
+        assert_eq!(26, graph.face_count());
+        assert_eq!(8, graph.faces_with_arity(3).count());
+        assert_eq!(18, graph.faces_with_arity(4).count());
+        assert!(graph.volume().unwrap() < 1.0 - (6.0 * 0.01 * 0.8));
+        graph.verify_gauss_bonnet(1e-9).unwrap();
+
+        // Flat edges are not sharp, so only the edges of the cube are
+        // chamfered and a triangle is inserted at each corner. Corners are
+        // moved along the diagonals of the triangulated faces, so the chamfer
+        // faces are not planar, but the surface remains closed.
+        let mut graph = cube();
+        graph.triangulate();
+        assert_eq!(20, graph.chamfer_sharp_edges(0.1, 0.1).unwrap().len());
+        assert_eq!(32, graph.face_count());
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+        assert_eq!(
+            2,
+            (graph.vertex_count() + graph.face_count()) as isize - graph.edge_count() as isize
+        );
+
+        let mut graph = cube();
+        assert_eq!(
+            GraphError::Geometry,
+            graph.chamfer_edges(&[key], 0.0).err().unwrap()
+        );
+        let mut graph = MeshGraph::<Point3<f64>>::from_heightmap(&[0.0; 9], 3, 3).unwrap();
+        let key = graph
+            .edges()
+            .find(|edge| edge.is_boundary_edge())
+            .unwrap()
+            .key();
+        assert_eq!(
+            GraphError::TopologyMalformed.at_key(key),
+            graph.chamfer_edges(&[key], 0.1).err().unwrap()
+        );
+    }
+}
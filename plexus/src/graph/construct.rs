@@ -0,0 +1,577 @@
+use num::{Integer, NumCast, Signed, ToPrimitive, Unsigned, Zero};
+use smallvec::SmallVec;
+use std::collections::HashMap;
+use theon::ops::Cross;
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use theon::AsPosition;
+use typenum::U3;
+
+use crate::entity::storage::KeyIndex;
+use crate::geometry::{FromGeometry, IntoGeometry};
+use crate::graph::data::GraphData;
+use crate::graph::face::FaceKey;
+use crate::graph::geometry::VertexPosition;
+use crate::graph::mutation::face::FaceInsertCache;
+use crate::graph::mutation::{self, Immediate};
+use crate::graph::vertex::VertexKey;
+use crate::graph::{Degeneracy, GraphError, MeshGraph, TopologyKind};
+use crate::primitive::decompose::IntoVertices;
+use crate::primitive::Polygonal;
+use crate::transact::Transact;
+
+type Mutation<M> = mutation::Mutation<Immediate<M>>;
+
+/// Mapping of indices in raw buffers to keys in a [`MeshGraph`].
+///
+/// Vertices are inserted in the order of the vertex buffer and faces are
+/// inserted in the order of the index buffer, so per-vertex and per-face data
+/// indexed by buffer position can be attached to the graph using this
+/// mapping. See [`MeshGraph::from_raw_buffers_with_keys`].
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::from_raw_buffers_with_keys`]: crate::graph::MeshGraph::from_raw_buffers_with_keys
+#[derive(Clone, Debug, Default)]
+pub struct BufferMapping<K = u64>
+where
+    K: KeyIndex,
+{
+    /// Maps the index of each vertex in the vertex buffer to its vertex key.
+    pub vertices: Vec<VertexKey<K>>,
+    /// Maps the index of each polygon in the index buffer to its face key.
+    pub faces: Vec<FaceKey<K>>,
+}
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Creates a graph from [raw buffers][`buffer`] and gets the mapping from
+    /// buffer indices to keys.
+    ///
+    /// This behaves like [`FromRawBuffers::from_raw_buffers`], but also returns
+    /// a [`BufferMapping`] that maps the index of each vertex in the vertex
+    /// buffer and each polygon in the index buffer to its key in the graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any index is out of bounds or there is an error
+    /// inserting topology into the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Tetragon;
+    /// use std::collections::HashMap;
+    ///
+    /// let colors = [0xff0000u32, 0x00ff00, 0x0000ff, 0xffffff];
+    /// let (graph, mapping) = MeshGraph::<Point2<f64>>::from_raw_buffers_with_keys(
+    ///     vec![Tetragon::new(0usize, 1, 2, 3)],
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+    /// )
+    /// .unwrap();
+    /// let colors = mapping
+    ///     .vertices
+    ///     .iter()
+    ///     .cloned()
+    ///     .zip(colors)
+    ///     .collect::<HashMap<_, _>>();
+    /// ```
+    ///
+    /// [`buffer`]: crate::buffer
+    /// [`BufferMapping`]: crate::graph::BufferMapping
+    /// [`FromRawBuffers::from_raw_buffers`]: crate::buffer::FromRawBuffers::from_raw_buffers
+    pub fn from_raw_buffers_with_keys<P, H, I, J>(
+        indices: I,
+        vertices: J,
+    ) -> Result<(Self, BufferMapping<K>), GraphError>
+    where
+        P: IntoVertices + Polygonal,
+        P::Vertex: Integer + ToPrimitive + Unsigned,
+        G::Vertex: FromGeometry<H>,
+        I: IntoIterator<Item = P>,
+        J: IntoIterator<Item = H>,
+    {
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let vertices = vertices
+            .into_iter()
+            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.into_geometry()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut faces = Vec::new();
+        for face in indices {
+            let mut perimeter = SmallVec::<[_; 4]>::with_capacity(face.arity());
+            for index in face.into_vertices() {
+                let index = <usize as NumCast>::from(index).unwrap();
+                perimeter.push(
+                    *vertices
+                        .get(index)
+                        .ok_or(GraphError::TopologyNotFound(TopologyKind::Vertex))?,
+                );
+            }
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            faces.push(mutation::face::insert_with(
+                &mut mutation,
+                cache,
+                Default::default,
+            )?);
+        }
+        let graph = mutation.commit().map_err(|(_, error)| error)?;
+        Ok((graph, BufferMapping { vertices, faces }))
+    }
+
+    /// Creates a graph from [raw buffers][`buffer`] with constant arity and
+    /// gets the mapping from buffer indices to keys.
+    ///
+    /// This behaves like [`FromRawBuffersWithArity`], but also returns a
+    /// [`BufferMapping`]. Faces are indexed by the position of their
+    /// polygon in the index buffer, which is the position of their first index
+    /// divided by the arity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the arity of the index buffer is not constant, any
+    /// index is out of bounds, or there is an error inserting topology into the
+    /// graph.
+    ///
+    /// [`buffer`]: crate::buffer
+    /// [`BufferMapping`]: crate::graph::BufferMapping
+    /// [`FromRawBuffersWithArity`]: crate::buffer::FromRawBuffersWithArity
+    pub fn from_raw_buffers_with_arity_and_keys<N, H, I, J>(
+        indices: I,
+        vertices: J,
+        arity: usize,
+    ) -> Result<(Self, BufferMapping<K>), GraphError>
+    where
+        N: Integer + ToPrimitive + Unsigned,
+        G::Vertex: FromGeometry<H>,
+        I: IntoIterator<Item = N>,
+        J: IntoIterator<Item = H>,
+    {
+        use itertools::Itertools;
+
+        if arity < 3 {
+            return Err(GraphError::ArityNonPolygonal);
+        }
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let vertices = vertices
+            .into_iter()
+            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.into_geometry()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut faces = Vec::new();
+        for face in &indices
+            .into_iter()
+            .map(|index| <usize as NumCast>::from(index).unwrap())
+            .chunks(arity)
+        {
+            let face = face.collect::<Vec<_>>();
+            if face.len() != arity {
+                // Index buffer length is not a multiple of arity.
+                return Err(GraphError::ArityConflict {
+                    expected: arity,
+                    actual: face.len(),
+                });
+            }
+            let mut perimeter = SmallVec::<[_; 4]>::with_capacity(arity);
+            for index in face {
+                perimeter.push(
+                    *vertices
+                        .get(index)
+                        .ok_or(GraphError::TopologyNotFound(TopologyKind::Vertex))?,
+                );
+            }
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            faces.push(mutation::face::insert_with(
+                &mut mutation,
+                cache,
+                Default::default,
+            )?);
+        }
+        let graph = mutation.commit().map_err(|(_, error)| error)?;
+        Ok((graph, BufferMapping { vertices, faces }))
+    }
+
+    /// Creates a graph from a grid of heights.
+    ///
+    /// The heights are samples of a regular grid with `width × height`
+    /// vertices in row-major order, such that the height at column `x` and row
+    /// `y` is `heights[(y * width) + x]`. Each vertex is positioned at `(x, y,
+    /// z)` where `z` is its height and each cell of the grid forms a
+    /// quadrilateral, so the graph has `(width - 1) × (height - 1)` faces.
+    /// Faces are wound counter-clockwise when viewed from above (along the
+    /// negative Z axis). This is useful for constructing terrain.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of heights is not `width × height` or if
+    /// either dimension is less than two.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    ///
+    /// let heights = (0..64)
+    ///     .map(|n| ((n % 8) as f64 * 0.5).sin() * ((n / 8) as f64 * 0.5).cos())
+    ///     .collect::<Vec<_>>();
+    /// let graph = MeshGraph::<Point3<f64>>::from_heightmap(&heights, 8, 8).unwrap();
+    ///
+    /// assert_eq!(49, graph.face_count());
+    /// ```
+    pub fn from_heightmap(heights: &[f64], width: usize, height: usize) -> Result<Self, GraphError>
+    where
+        G::Vertex: AsPosition + FromGeometry<VertexPosition<G>>,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        if width < 2 || height < 2 || heights.len() != width * height {
+            return Err(GraphError::TopologyMalformed);
+        }
+        let scalar =
+            |value| <Scalar<VertexPosition<G>> as NumCast>::from(value).ok_or(GraphError::Geometry);
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let vertices = heights
+            .iter()
+            .enumerate()
+            .map(|(index, z)| {
+                let position = VertexPosition::<G>::from_xyz(
+                    scalar((index % width) as f64)?,
+                    scalar((index / width) as f64)?,
+                    scalar(*z)?,
+                );
+                mutation::vertex::insert(&mut mutation, position.into_geometry())
+            })
+            .collect::<Result<Vec<_>, GraphError>>()?;
+        for y in 0..(height - 1) {
+            for x in 0..(width - 1) {
+                let a = (y * width) + x;
+                let perimeter = [
+                    vertices[a],
+                    vertices[a + 1],
+                    vertices[a + width + 1],
+                    vertices[a + width],
+                ];
+                let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+                mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+            }
+        }
+        mutation.commit().map_err(|(_, error)| error)
+    }
+
+    /// Creates a graph from the convex hull of a set of points.
+    ///
+    /// The hull is computed by incremental insertion and forms a closed graph
+    /// of triangles that are wound such that their normals face outward. Only
+    /// the corners of the hull become vertices in the graph: points that lie
+    /// within or on the hull, such as points in the interior of a planar region
+    /// of the hull, are discarded. Such points are detected using a tolerance
+    /// relative to the extent of the points. Vertices retain the data of the
+    /// corresponding points.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphError::GeometryDegenerate`] if there are fewer than four
+    /// points or if the points are coincident, collinear, or coplanar.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let points = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 0.0, 1.0),
+    ///     Point3::new(0.1, 0.1, 0.1),
+    /// ];
+    /// let graph = MeshGraph::<E3>::convex_hull(points).unwrap();
+    ///
+    /// assert_eq!(4, graph.vertex_count());
+    /// assert_eq!(4, graph.face_count());
+    /// ```
+    ///
+    /// [`GraphError::GeometryDegenerate`]: crate::graph::GraphError::GeometryDegenerate
+    pub fn convex_hull<I>(points: I) -> Result<Self, GraphError>
+    where
+        I: IntoIterator<Item = G::Vertex>,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        // Gets the index and measure of the point with the greatest measure.
+        fn farthest<S, F>(positions: &[S], f: F) -> (usize, Scalar<S>)
+        where
+            S: EuclideanSpace,
+            F: Fn(S) -> Scalar<S>,
+        {
+            positions
+                .iter()
+                .map(|position| f(*position))
+                .enumerate()
+                .fold((0, Zero::zero()), |(n, max), (m, measure)| {
+                    if measure > max {
+                        (m, measure)
+                    }
+                    else {
+                        (n, max)
+                    }
+                })
+        }
+
+        let points = points.into_iter().collect::<Vec<_>>();
+        if points.len() < 4 {
+            return Err(GraphError::GeometryDegenerate(Degeneracy::Insufficient));
+        }
+        let positions = points
+            .iter()
+            .map(|point| *point.as_position())
+            .collect::<Vec<_>>();
+        // Construct an initial tetrahedron from extreme points. Each point is
+        // chosen to be as far as possible from the line or plane of the
+        // previous points, which also detects degenerate point sets.
+        let origin = positions[0];
+        let (b, extent) = farthest(&positions, |position| (position - origin).magnitude());
+        if extent <= Zero::zero() {
+            return Err(GraphError::GeometryDegenerate(Degeneracy::Coincident));
+        }
+        let epsilon = extent * <Scalar<VertexPosition<G>> as NumCast>::from(1e-9).unwrap();
+        let ab = positions[b] - origin;
+        let (c, distance) = farthest(&positions, |position| {
+            (position - origin).cross(ab).magnitude() / extent
+        });
+        if distance <= epsilon {
+            return Err(GraphError::GeometryDegenerate(Degeneracy::Collinear));
+        }
+        let normal = ab
+            .cross(positions[c] - origin)
+            .normalize()
+            .ok_or(GraphError::Geometry)?;
+        let (d, distance) = farthest(&positions, |position| {
+            Signed::abs(&normal.dot(position - origin))
+        });
+        if distance <= epsilon {
+            return Err(GraphError::GeometryDegenerate(Degeneracy::Coplanar));
+        }
+        // Wind the base of the tetrahedron away from its apex.
+        let (a, b, c) = if normal.dot(positions[d] - origin) > Zero::zero() {
+            (0, c, b)
+        }
+        else {
+            (0, b, c)
+        };
+        let face = |[a, b, c]: [usize; 3]| {
+            (positions[b] - positions[a])
+                .cross(positions[c] - positions[a])
+                .normalize()
+                .map(|normal| ([a, b, c], normal))
+                .ok_or(GraphError::Geometry)
+        };
+        let mut faces = [[a, b, c], [a, d, b], [b, d, c], [c, d, a]]
+            .into_iter()
+            .map(face)
+            .collect::<Result<Vec<_>, _>>()?;
+        for (index, position) in positions.iter().enumerate() {
+            if [a, b, c, d].contains(&index) {
+                continue;
+            }
+            // Remove the faces that are visible from the point and replace
+            // them with faces that join the point to the horizon.
+            let (visible, hidden): (Vec<_>, Vec<_>) = faces
+                .into_iter()
+                .partition(|([a, _, _], normal)| normal.dot(*position - positions[*a]) > epsilon);
+            faces = hidden;
+            let arcs = visible
+                .iter()
+                .flat_map(|([a, b, c], _)| [(*a, *b), (*b, *c), (*c, *a)])
+                .collect::<Vec<_>>();
+            for (p, q) in arcs.iter().filter(|(p, q)| !arcs.contains(&(*q, *p))) {
+                faces.push(face([*p, *q, index])?);
+            }
+        }
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let mut keys = HashMap::new();
+        for (perimeter, _) in faces {
+            let perimeter = perimeter
+                .into_iter()
+                .map(|index| match keys.get(&index) {
+                    Some(key) => Ok(*key),
+                    None => {
+                        let key = mutation::vertex::insert(&mut mutation, points[index].clone())?;
+                        keys.insert(index, key);
+                        Ok(key)
+                    }
+                })
+                .collect::<Result<SmallVec<[_; 3]>, GraphError>>()?;
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+        }
+        mutation.commit().map_err(|(_, error)| error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Point2, Point3};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::graph::{Degeneracy, GraphError, MeshGraph};
+    use crate::prelude::*;
+    use crate::primitive::BoundedPolygon;
+
+    #[test]
+    fn from_raw_buffers_with_keys() {
+        let positions = vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (2.0, 0.5)];
+        let (graph, mapping) = MeshGraph::<Point2<f64>>::from_raw_buffers_with_keys(
+            vec![
+                BoundedPolygon::from([0usize, 1, 2, 3]),
+                BoundedPolygon::from([1, 4, 2]),
+            ],
+            positions.clone(),
+        )
+        .unwrap();
+        assert_eq!(positions.len(), mapping.vertices.len());
+        for (key, (x, y)) in mapping.vertices.iter().zip(positions.iter()) {
+            assert_eq!(Point2::new(*x, *y), *graph.vertex(*key).unwrap().position());
+        }
+        assert_eq!(2, mapping.faces.len());
+        assert_eq!(4, graph.face(mapping.faces[0]).unwrap().arity());
+        assert_eq!(3, graph.face(mapping.faces[1]).unwrap().arity());
+
+        let (graph, mapping) = MeshGraph::<Point2<f64>>::from_raw_buffers_with_arity_and_keys(
+            vec![0u32, 1, 2, 3],
+            positions[..4].to_vec(),
+            4,
+        )
+        .unwrap();
+        for (key, (x, y)) in mapping.vertices.iter().zip(positions.iter()) {
+            assert_eq!(Point2::new(*x, *y), *graph.vertex(*key).unwrap().position());
+        }
+        // The ring of the face may begin at any of its vertices, but its
+        // vertices are ordered as in the buffer.
+        let mut keys = graph
+            .face(mapping.faces[0])
+            .unwrap()
+            .adjacent_vertices()
+            .keys()
+            .collect::<Vec<_>>();
+        let n = keys
+            .iter()
+            .position(|key| *key == mapping.vertices[0])
+            .unwrap();
+        keys.rotate_left(n);
+        assert_eq!(mapping.vertices, keys);
+    }
+
+    #[test]
+    fn from_heightmap() {
+        let heights = [0.0, 1.0, 0.0, 1.0, 2.0, 1.0, 0.0, 1.0, 0.0];
+        let graph = MeshGraph::<Point3<f64>>::from_heightmap(&heights, 3, 3).unwrap();
+
+        assert_eq!(9, graph.vertex_count());
+        assert_eq!(12, graph.edge_count());
+        assert_eq!(4, graph.face_count());
+        assert_eq!(8, graph.arcs().filter(|arc| arc.is_boundary_arc()).count());
+        for vertex in graph.vertices() {
+            let position = vertex.position();
+            let index = (position.y as usize * 3) + position.x as usize;
+            assert_eq!(heights[index], position.z);
+        }
+        // Faces are wound counter-clockwise when viewed from above.
+        for face in graph.faces() {
+            assert!(face.normal().unwrap().z > 0.0);
+        }
+
+        assert_eq!(
+            GraphError::TopologyMalformed,
+            MeshGraph::<Point3<f64>>::from_heightmap(&heights, 2, 3)
+                .err()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn convex_hull() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut points = (0..1000)
+            .map(|_| {
+                Point3::new(
+                    rng.gen_range(-0.9..0.9),
+                    rng.gen_range(-0.9..0.9),
+                    rng.gen_range(-0.9..0.9),
+                )
+            })
+            .collect::<Vec<_>>();
+        // Insert the corners of the cube after the interior points, so that the
+        // hull must discard vertices as it grows.
+        for n in 0..8 {
+            let coordinate = |bit: usize| if n & bit == 0 { -1.0 } else { 1.0 };
+            points.push(Point3::new(coordinate(1), coordinate(2), coordinate(4)));
+        }
+        let graph = MeshGraph::<Point3<f64>>::convex_hull(points).unwrap();
+
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(18, graph.edge_count());
+        assert_eq!(12, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+        for vertex in graph.vertices() {
+            let position = vertex.position();
+            assert_eq!(1.0, position.x.abs());
+            assert_eq!(1.0, position.y.abs());
+            assert_eq!(1.0, position.z.abs());
+        }
+        // Faces are wound such that their normals face outward.
+        for face in graph.faces() {
+            assert!(face.normal().unwrap().dot(&face.centroid().coords) > 0.0);
+        }
+        assert!((graph.volume().unwrap() - 8.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn convex_hull_degenerate() {
+        let hull = |points: Vec<(f64, f64, f64)>| {
+            MeshGraph::<Point3<f64>>::convex_hull(
+                points.into_iter().map(|(x, y, z)| Point3::new(x, y, z)),
+            )
+            .err()
+            .unwrap()
+        };
+
+        assert_eq!(
+            GraphError::GeometryDegenerate(Degeneracy::Insufficient),
+            hull(vec![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)]),
+        );
+        assert_eq!(
+            GraphError::GeometryDegenerate(Degeneracy::Coincident),
+            hull(vec![(1.0, 1.0, 1.0); 4]),
+        );
+        assert_eq!(
+            GraphError::GeometryDegenerate(Degeneracy::Collinear),
+            hull((0..4).map(|n| (n as f64, n as f64, 0.0)).collect()),
+        );
+        assert_eq!(
+            GraphError::GeometryDegenerate(Degeneracy::Coplanar),
+            hull(
+                (0..9)
+                    .map(|n| ((n % 3) as f64, (n / 3) as f64, 1.0))
+                    .collect()
+            ),
+        );
+    }
+}
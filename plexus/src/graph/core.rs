@@ -1,19 +1,21 @@
 use std::marker::PhantomData;
 
-use crate::entity::storage::{AsStorage, AsStorageMut, Fuse, StorageTarget};
+use crate::entity::storage::{AsStorage, AsStorageMut, Fuse, KeyIndex, StorageTarget};
 use crate::entity::Entity;
 use crate::graph::data::{GraphData, Parametric};
 use crate::graph::edge::{Arc, Edge};
 use crate::graph::face::Face;
+use crate::graph::layer::Layers;
 use crate::graph::vertex::Vertex;
 
 /// A complete core that owns all of its storage.
-pub type OwnedCore<G> = Core<
+pub type OwnedCore<G, K> = Core<
     G,
-    <Vertex<G> as Entity>::Storage,
-    <Arc<G> as Entity>::Storage,
-    <Edge<G> as Entity>::Storage,
-    <Face<G> as Entity>::Storage,
+    K,
+    <Vertex<G, K> as Entity>::Storage,
+    <Arc<G, K> as Entity>::Storage,
+    <Edge<G, K> as Entity>::Storage,
+    <Face<G, K> as Entity>::Storage,
 >;
 
 /// Adaptable graph representation that can incorporate arbitrary storage.
@@ -37,20 +39,26 @@ pub type OwnedCore<G> = Core<
 /// expose storage to yet unfused entities.
 ///
 /// A `Core` with no unfused fields is _complete_.
-pub struct Core<G, V = (), A = (), E = (), F = ()>
+///
+/// A core also owns the attribute layers of a graph. Layers are not fused or
+/// unfused and are carried along with the core's storage.
+pub struct Core<G, K, V = (), A = (), E = (), F = ()>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     pub(in crate::graph) vertices: V,
     pub(in crate::graph) arcs: A,
     pub(in crate::graph) edges: E,
     pub(in crate::graph) faces: F,
-    phantom: PhantomData<fn() -> G>,
+    pub(in crate::graph) layers: Layers<K>,
+    phantom: PhantomData<fn() -> (G, K)>,
 }
 
-impl<G> Core<G>
+impl<G, K> Core<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn empty() -> Self {
         Core {
@@ -58,14 +66,16 @@ where
             arcs: (),
             edges: (),
             faces: (),
+            layers: Default::default(),
             phantom: PhantomData,
         }
     }
 }
 
-impl<G, V, A, E, F> Core<G, V, A, E, F>
+impl<G, K, V, A, E, F> Core<G, K, V, A, E, F>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn unfuse(self) -> (V, A, E, F) {
         let Core {
@@ -79,89 +89,98 @@ where
     }
 }
 
-impl<G, V, A, E, F> AsStorage<Vertex<G>> for Core<G, V, A, E, F>
+impl<G, K, V, A, E, F> AsStorage<Vertex<G, K>> for Core<G, K, V, A, E, F>
 where
-    V: AsStorage<Vertex<G>>,
+    V: AsStorage<Vertex<G, K>>,
     G: GraphData,
+    K: KeyIndex,
 {
-    fn as_storage(&self) -> &StorageTarget<Vertex<G>> {
+    fn as_storage(&self) -> &StorageTarget<Vertex<G, K>> {
         self.vertices.as_storage()
     }
 }
 
-impl<G, V, A, E, F> AsStorage<Arc<G>> for Core<G, V, A, E, F>
+impl<G, K, V, A, E, F> AsStorage<Arc<G, K>> for Core<G, K, V, A, E, F>
 where
-    A: AsStorage<Arc<G>>,
+    A: AsStorage<Arc<G, K>>,
     G: GraphData,
+    K: KeyIndex,
 {
-    fn as_storage(&self) -> &StorageTarget<Arc<G>> {
+    fn as_storage(&self) -> &StorageTarget<Arc<G, K>> {
         self.arcs.as_storage()
     }
 }
 
-impl<G, V, A, E, F> AsStorage<Edge<G>> for Core<G, V, A, E, F>
+impl<G, K, V, A, E, F> AsStorage<Edge<G, K>> for Core<G, K, V, A, E, F>
 where
-    E: AsStorage<Edge<G>>,
+    E: AsStorage<Edge<G, K>>,
     G: GraphData,
+    K: KeyIndex,
 {
-    fn as_storage(&self) -> &StorageTarget<Edge<G>> {
+    fn as_storage(&self) -> &StorageTarget<Edge<G, K>> {
         self.edges.as_storage()
     }
 }
 
-impl<G, V, A, E, F> AsStorage<Face<G>> for Core<G, V, A, E, F>
+impl<G, K, V, A, E, F> AsStorage<Face<G, K>> for Core<G, K, V, A, E, F>
 where
-    F: AsStorage<Face<G>>,
+    F: AsStorage<Face<G, K>>,
     G: GraphData,
+    K: KeyIndex,
 {
-    fn as_storage(&self) -> &StorageTarget<Face<G>> {
+    fn as_storage(&self) -> &StorageTarget<Face<G, K>> {
         self.faces.as_storage()
     }
 }
 
-impl<G, V, A, E, F> AsStorageMut<Vertex<G>> for Core<G, V, A, E, F>
+impl<G, K, V, A, E, F> AsStorageMut<Vertex<G, K>> for Core<G, K, V, A, E, F>
 where
-    V: AsStorageMut<Vertex<G>>,
+    V: AsStorageMut<Vertex<G, K>>,
     G: GraphData,
+    K: KeyIndex,
 {
-    fn as_storage_mut(&mut self) -> &mut StorageTarget<Vertex<G>> {
+    fn as_storage_mut(&mut self) -> &mut StorageTarget<Vertex<G, K>> {
         self.vertices.as_storage_mut()
     }
 }
 
-impl<G, V, A, E, F> AsStorageMut<Arc<G>> for Core<G, V, A, E, F>
+impl<G, K, V, A, E, F> AsStorageMut<Arc<G, K>> for Core<G, K, V, A, E, F>
 where
-    A: AsStorageMut<Arc<G>>,
+    A: AsStorageMut<Arc<G, K>>,
     G: GraphData,
+    K: KeyIndex,
 {
-    fn as_storage_mut(&mut self) -> &mut StorageTarget<Arc<G>> {
+    fn as_storage_mut(&mut self) -> &mut StorageTarget<Arc<G, K>> {
         self.arcs.as_storage_mut()
     }
 }
 
-impl<G, V, A, E, F> AsStorageMut<Edge<G>> for Core<G, V, A, E, F>
+impl<G, K, V, A, E, F> AsStorageMut<Edge<G, K>> for Core<G, K, V, A, E, F>
 where
-    E: AsStorageMut<Edge<G>>,
+    E: AsStorageMut<Edge<G, K>>,
     G: GraphData,
+    K: KeyIndex,
 {
-    fn as_storage_mut(&mut self) -> &mut StorageTarget<Edge<G>> {
+    fn as_storage_mut(&mut self) -> &mut StorageTarget<Edge<G, K>> {
         self.edges.as_storage_mut()
     }
 }
 
-impl<G, V, A, E, F> AsStorageMut<Face<G>> for Core<G, V, A, E, F>
+impl<G, K, V, A, E, F> AsStorageMut<Face<G, K>> for Core<G, K, V, A, E, F>
 where
-    F: AsStorageMut<Face<G>>,
+    F: AsStorageMut<Face<G, K>>,
     G: GraphData,
+    K: KeyIndex,
 {
-    fn as_storage_mut(&mut self) -> &mut StorageTarget<Face<G>> {
+    fn as_storage_mut(&mut self) -> &mut StorageTarget<Face<G, K>> {
         self.faces.as_storage_mut()
     }
 }
 
-impl<G, V, A, E, F> Default for Core<G, V, A, E, F>
+impl<G, K, V, A, E, F> Default for Core<G, K, V, A, E, F>
 where
     G: GraphData,
+    K: KeyIndex,
     V: Default,
     A: Default,
     E: Default,
@@ -173,44 +192,53 @@ where
             arcs: Default::default(),
             edges: Default::default(),
             faces: Default::default(),
+            layers: Default::default(),
             phantom: PhantomData,
         }
     }
 }
 
-impl<G, V, A, E, F> Fuse<V, Vertex<G>> for Core<G, (), A, E, F>
+impl<G, K, V, A, E, F> Fuse<V, Vertex<G, K>> for Core<G, K, (), A, E, F>
 where
-    V: AsStorage<Vertex<G>>,
+    V: AsStorage<Vertex<G, K>>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Output = Core<G, V, A, E, F>;
+    type Output = Core<G, K, V, A, E, F>;
 
     fn fuse(self, vertices: V) -> Self::Output {
         let Core {
-            arcs, edges, faces, ..
+            arcs,
+            edges,
+            faces,
+            layers,
+            ..
         } = self;
         Core {
             vertices,
             arcs,
             edges,
             faces,
+            layers,
             phantom: PhantomData,
         }
     }
 }
 
-impl<G, V, A, E, F> Fuse<A, Arc<G>> for Core<G, V, (), E, F>
+impl<G, K, V, A, E, F> Fuse<A, Arc<G, K>> for Core<G, K, V, (), E, F>
 where
-    A: AsStorage<Arc<G>>,
+    A: AsStorage<Arc<G, K>>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Output = Core<G, V, A, E, F>;
+    type Output = Core<G, K, V, A, E, F>;
 
     fn fuse(self, arcs: A) -> Self::Output {
         let Core {
             vertices,
             edges,
             faces,
+            layers,
             ..
         } = self;
         Core {
@@ -218,23 +246,26 @@ where
             arcs,
             edges,
             faces,
+            layers,
             phantom: PhantomData,
         }
     }
 }
 
-impl<G, V, A, E, F> Fuse<E, Edge<G>> for Core<G, V, A, (), F>
+impl<G, K, V, A, E, F> Fuse<E, Edge<G, K>> for Core<G, K, V, A, (), F>
 where
-    E: AsStorage<Edge<G>>,
+    E: AsStorage<Edge<G, K>>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Output = Core<G, V, A, E, F>;
+    type Output = Core<G, K, V, A, E, F>;
 
     fn fuse(self, edges: E) -> Self::Output {
         let Core {
             vertices,
             arcs,
             faces,
+            layers,
             ..
         } = self;
         Core {
@@ -242,23 +273,26 @@ where
             arcs,
             edges,
             faces,
+            layers,
             phantom: PhantomData,
         }
     }
 }
 
-impl<G, V, A, E, F> Fuse<F, Face<G>> for Core<G, V, A, E, ()>
+impl<G, K, V, A, E, F> Fuse<F, Face<G, K>> for Core<G, K, V, A, E, ()>
 where
-    F: AsStorage<Face<G>>,
+    F: AsStorage<Face<G, K>>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Output = Core<G, V, A, E, F>;
+    type Output = Core<G, K, V, A, E, F>;
 
     fn fuse(self, faces: F) -> Self::Output {
         let Core {
             vertices,
             arcs,
             edges,
+            layers,
             ..
         } = self;
         Core {
@@ -266,14 +300,17 @@ where
             arcs,
             edges,
             faces,
+            layers,
             phantom: PhantomData,
         }
     }
 }
 
-impl<G, V, A, E, F> Parametric for Core<G, V, A, E, F>
+impl<G, K, V, A, E, F> Parametric for Core<G, K, V, A, E, F>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     type Data = G;
+    type Index = K;
 }
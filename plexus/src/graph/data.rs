@@ -1,7 +1,10 @@
 use crate::entity::borrow::Reborrow;
+use crate::entity::storage::KeyIndex;
 
 pub type Data<M> = <M as Parametric>::Data;
 
+pub type Index<M> = <M as Parametric>::Index;
+
 /// Graph data.
 ///
 /// Specifies the types used to represent data in vertices, arcs, edges, and
@@ -119,6 +122,7 @@ where
 
 pub trait Parametric {
     type Data: GraphData;
+    type Index: KeyIndex;
 }
 
 impl<B> Parametric for B
@@ -127,4 +131,5 @@ where
     B::Target: Parametric,
 {
     type Data = <B::Target as Parametric>::Data;
+    type Index = <B::Target as Parametric>::Index;
 }
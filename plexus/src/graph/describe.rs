@@ -0,0 +1,277 @@
+use std::vec;
+
+use crate::entity::storage::{Key, KeyIndex};
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::edge::{ArcKey, EdgeKey};
+use crate::graph::face::FaceKey;
+use crate::graph::vertex::VertexKey;
+use crate::graph::{GraphKey, MeshGraph};
+use crate::DynamicArity;
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Gets a [Graphviz][graphviz] description of the graph in the DOT
+    /// language.
+    ///
+    /// Vertices are emitted as nodes and arcs are emitted as directed edges
+    /// labeled with their keys and the keys of their faces. Boundary arcs are
+    /// dashed. This is intended for debugging the topology of small graphs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2)],
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+    /// )
+    /// .unwrap();
+    /// println!("{}", graph.to_dot());
+    /// ```
+    ///
+    /// [graphviz]: https://graphviz.org
+    pub fn to_dot(&self) -> String {
+        let mut lines = vec!["digraph {".to_owned()];
+        for vertex in self.vertices() {
+            lines.push(format!("    {};", vertex_label(vertex.key())));
+        }
+        for arc in self.arcs() {
+            let (a, b): (VertexKey<K>, VertexKey<K>) = arc.key().into();
+            let (a, b) = (vertex_label(a), vertex_label(b));
+            let label = arc_label(arc.key());
+            lines.push(match arc.face() {
+                Some(face) => format!(
+                    "    {} -> {} [label=\"{} {}\"];",
+                    a,
+                    b,
+                    label,
+                    face_label(face.key()),
+                ),
+                _ => format!("    {} -> {} [label=\"{}\", style=dashed];", a, b, label),
+            });
+        }
+        lines.push("}".to_owned());
+        lines.join("\n")
+    }
+
+    /// Describes the topology with the given key and its immediate
+    /// neighborhood.
+    ///
+    /// The description includes the keys of the outgoing arc and adjacent
+    /// vertices of a vertex; the next, previous, and opposite arcs, edge, and
+    /// face of an arc; the arcs and faces of an edge; and the perimeter of a
+    /// face. This is intended for debugging, such as when a consistency check
+    /// fails.
+    ///
+    /// Returns `None` if the topology is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2)],
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+    /// )
+    /// .unwrap();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// println!("{}", graph.describe(key).unwrap());
+    /// ```
+    pub fn describe<T>(&self, key: T) -> Option<String>
+    where
+        T: Into<GraphKey>,
+    {
+        // Keys that cannot be narrowed into the index type of the graph cannot
+        // refer to any of its entities.
+        fn narrow<T, U>(key: T) -> Option<U>
+        where
+            T: Key<Inner = u64>,
+            U: Key,
+            U::Inner: KeyIndex,
+        {
+            U::Inner::from_u64(key.into_inner()).map(U::from_inner)
+        }
+
+        let lines = match key.into() {
+            GraphKey::Vertex(key) => {
+                let key = narrow(key)?;
+                let vertex = self.vertex(key)?;
+                let mut lines = vec![format!("vertex {}", vertex_label(key))];
+                match vertex.into_reachable_outgoing_arc() {
+                    Some(arc) => {
+                        lines.push(format!("    outgoing arc: {}", arc_label(arc.key())));
+                        lines.push(format!(
+                            "    adjacent vertices: {}",
+                            vertex
+                                .adjacent_vertices()
+                                .map(|vertex| vertex_label(vertex.key()))
+                                .collect::<Vec<_>>()
+                                .join(", "),
+                        ));
+                    }
+                    _ => {
+                        lines.push("    outgoing arc: none".to_owned());
+                    }
+                }
+                lines
+            }
+            GraphKey::Arc(key) => {
+                let (a, b) = key.into();
+                let key = ArcKey::from((narrow(a)?, narrow(b)?));
+                let arc = self.arc(key)?;
+                vec![
+                    format!("arc {}", arc_label(key)),
+                    format!("    next: {}", arc_label(arc.next_arc().key())),
+                    format!("    previous: {}", arc_label(arc.previous_arc().key())),
+                    format!("    opposite: {}", arc_label(arc.opposite_arc().key())),
+                    format!("    edge: {}", edge_label(arc.edge().key())),
+                    format!(
+                        "    face: {}",
+                        optional_face_label(arc.face().map(|face| face.key()))
+                    ),
+                ]
+            }
+            GraphKey::Edge(key) => {
+                let key = narrow(key)?;
+                let edge = self.edge(key)?;
+                let ab = edge.arc();
+                let ba = ab.opposite_arc();
+                vec![
+                    format!("edge {}", edge_label(key)),
+                    format!("    arcs: {}, {}", arc_label(ab.key()), arc_label(ba.key())),
+                    format!(
+                        "    faces: {}, {}",
+                        optional_face_label(ab.face().map(|face| face.key())),
+                        optional_face_label(ba.face().map(|face| face.key())),
+                    ),
+                ]
+            }
+            GraphKey::Face(key) => {
+                let key = narrow(key)?;
+                let face = self.face(key)?;
+                vec![
+                    format!("face {}", face_label(key)),
+                    format!("    arity: {}", face.arity()),
+                    format!("    arc: {}", arc_label(face.arc().key())),
+                    format!(
+                        "    perimeter: {}",
+                        face.adjacent_vertices()
+                            .map(|vertex| vertex_label(vertex.key()))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ),
+                ]
+            }
+        };
+        Some(lines.join("\n"))
+    }
+}
+
+fn vertex_label<K>(key: VertexKey<K>) -> String
+where
+    K: KeyIndex,
+{
+    format!("v{}", key.into_inner().into_u64())
+}
+
+fn arc_label<K>(key: ArcKey<K>) -> String
+where
+    K: KeyIndex,
+{
+    let (a, b): (VertexKey<K>, VertexKey<K>) = key.into();
+    format!("({}, {})", vertex_label(a), vertex_label(b))
+}
+
+fn edge_label<K>(key: EdgeKey<K>) -> String
+where
+    K: KeyIndex,
+{
+    format!("e{}", key.into_inner().into_u64())
+}
+
+fn face_label<K>(key: FaceKey<K>) -> String
+where
+    K: KeyIndex,
+{
+    format!("f{}", key.into_inner().into_u64())
+}
+
+fn optional_face_label<K>(key: Option<FaceKey<K>>) -> String
+where
+    K: KeyIndex,
+{
+    key.map(face_label).unwrap_or_else(|| "none".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point2;
+
+    use crate::entity::storage::Key;
+    use crate::graph::{MeshGraph, VertexKey};
+    use crate::prelude::*;
+    use crate::primitive::NGon;
+
+    #[test]
+    fn to_dot_and_describe() {
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2, 3])],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+        )
+        .unwrap();
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph {"));
+        let lines = dot.lines().map(str::trim).collect::<Vec<_>>();
+        assert_eq!(
+            4,
+            lines
+                .iter()
+                .filter(|line| line.starts_with('v') && !line.contains("->"))
+                .count()
+        );
+        assert_eq!(8, lines.iter().filter(|line| line.contains("->")).count());
+        assert_eq!(
+            4,
+            lines.iter().filter(|line| line.contains("dashed")).count()
+        );
+
+        // Describe all topology, including boundary arcs and edges.
+        for vertex in graph.vertices() {
+            assert!(graph.describe(vertex.key()).unwrap().starts_with("vertex"));
+        }
+        for arc in graph.arcs() {
+            let description = graph.describe(arc.key()).unwrap();
+            assert_eq!(arc.is_boundary_arc(), description.contains("face: none"));
+        }
+        for edge in graph.edges() {
+            assert!(graph.describe(edge.key()).unwrap().contains("none"));
+        }
+        let face = graph.faces().nth(0).unwrap();
+        assert!(graph.describe(face.key()).unwrap().contains("arity: 4"));
+        assert_eq!(None, graph.describe(VertexKey::from_inner(u64::MAX)));
+
+        assert_eq!(
+            "MeshGraph { vertices: 4, arcs: 8, edges: 4, faces: 1 }",
+            format!("{:?}", graph)
+        );
+    }
+}
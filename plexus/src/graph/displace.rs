@@ -0,0 +1,372 @@
+use num::Zero;
+use smallvec::SmallVec;
+use std::collections::HashMap;
+use theon::query::Plane;
+use theon::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
+use theon::AsPositionMut;
+
+use crate::entity::storage::KeyIndex;
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::geometry::VertexPosition;
+use crate::graph::vertex::{VertexKey, VertexView};
+use crate::graph::MeshGraph;
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Displaces the positions of vertices in the graph.
+    ///
+    /// The given function receives an immutable view of each vertex and returns
+    /// an offset for its position. Because the view is bound to the graph, the
+    /// function may inspect topology and geometry, such as adjacent vertices
+    /// and normals. Offsets are applied only after they have been computed for
+    /// all vertices, so the function always observes the original graph rather
+    /// than a partially displaced one.
+    ///
+    /// # Examples
+    ///
+    /// Displacing vertices along their normals:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = UvSphere::new(16, 8)
+    ///     .polygons::<Position<Point3<R64>>>()
+    ///     .collect();
+    /// graph.displace(|vertex| {
+    ///     let position = vertex.position();
+    ///     let height = 0.1 * (position.x * 8.0).sin() * (position.y * 8.0).cos();
+    ///     vertex.normal().unwrap() * height
+    /// });
+    /// ```
+    pub fn displace<F>(&mut self, mut f: F)
+    where
+        F: FnMut(VertexView<&Self>) -> Vector<VertexPosition<G>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let mut offsets = HashMap::with_capacity(self.vertex_count());
+        for vertex in self.vertices() {
+            offsets.insert(vertex.key(), f(vertex));
+        }
+        for mut vertex in self.vertex_orphans() {
+            let position = *vertex.position();
+            *vertex.get_mut().as_position_mut() = position + offsets.remove(&vertex.key()).unwrap();
+        }
+    }
+
+    /// Moves the positions of vertices toward a morph target.
+    ///
+    /// A morph target (or blend shape) maps vertices to target positions. The
+    /// position of each vertex in the target is linearly interpolated toward
+    /// its target position by the given weight, such that a weight of zero
+    /// leaves the vertex unchanged and a weight of one moves the vertex onto
+    /// its target position. Vertices that are not in the target are
+    /// unaffected, so targets need only include the vertices that they move.
+    ///
+    /// See [`MeshGraph::blend_morph_targets`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::{Point3, Vector3};
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut graph = MeshGraph::<Point3<f64>>::from_heightmap(&[0.0; 9], 3, 3).unwrap();
+    ///
+    /// // Raise the center of the grid halfway toward the target.
+    /// let target = graph
+    ///     .vertices()
+    ///     .filter(|vertex| !vertex.is_boundary_vertex())
+    ///     .map(|vertex| (vertex.key(), *vertex.position() + (Vector3::z() * 2.0)))
+    ///     .collect::<HashMap<_, _>>();
+    /// graph.apply_morph_target(&target, 0.5);
+    /// ```
+    ///
+    /// [`MeshGraph::blend_morph_targets`]: crate::graph::MeshGraph::blend_morph_targets
+    pub fn apply_morph_target<T>(
+        &mut self,
+        target: &HashMap<VertexKey<K>, VertexPosition<G>>,
+        weight: T,
+    ) where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.blend_morph_targets(&[(target, weight.into())]);
+    }
+
+    /// Moves the positions of vertices toward a weighted blend of morph
+    /// targets.
+    ///
+    /// Each vertex is offset by the sum of the weighted offsets from its
+    /// position to its position in each target. Vertices that are not in a
+    /// target receive no offset from that target. When every target includes
+    /// a vertex and the weights sum to one, the vertex is moved to the
+    /// weighted average of its target positions.
+    ///
+    /// See [`MeshGraph::apply_morph_target`].
+    ///
+    /// [`MeshGraph::apply_morph_target`]: crate::graph::MeshGraph::apply_morph_target
+    #[allow(clippy::type_complexity)]
+    pub fn blend_morph_targets<T>(
+        &mut self,
+        targets: &[(&HashMap<VertexKey<K>, VertexPosition<G>>, T)],
+    ) where
+        T: Copy + Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let targets = targets
+            .iter()
+            .map(|(target, weight)| (*target, (*weight).into()))
+            .collect::<SmallVec<[_; 4]>>();
+        for mut vertex in self.vertex_orphans() {
+            let key = vertex.key();
+            let position = *vertex.position();
+            let position = targets
+                .iter()
+                .filter_map(|(target, weight)| {
+                    target
+                        .get(&key)
+                        .map(|target| (*target - position) * *weight)
+                })
+                .fold(position, |position, offset| position + offset);
+            *vertex.get_mut().as_position_mut() = position;
+        }
+    }
+
+    /// Snaps the positions of vertices onto a plane.
+    ///
+    /// Any vertex with a position within the given distance of the plane is
+    /// projected onto the plane. Other vertices are unaffected. This is useful
+    /// for enforcing planarity after operations that accumulate numeric error.
+    ///
+    /// Returns the number of vertices that were snapped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::{Point3, Vector3};
+    /// use plexus::geometry::{Plane, Unit};
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Tetragon;
+    ///
+    /// let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+    ///     vec![Tetragon::new(0usize, 1, 2, 3)],
+    ///     vec![
+    ///         (0.0, 0.0, 0.0),
+    ///         (1.0, 0.0, 1e-7),
+    ///         (1.0, 1.0, 0.0),
+    ///         (0.0, 1.0, -1e-7),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// let plane = Plane {
+    ///     origin: Point3::origin(),
+    ///     normal: Unit::try_from_inner(Vector3::z()).unwrap(),
+    /// };
+    /// assert_eq!(4, graph.snap_to_plane(plane, 1e-6));
+    /// ```
+    pub fn snap_to_plane<T>(&mut self, plane: Plane<VertexPosition<G>>, epsilon: T) -> usize
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let epsilon = epsilon.into();
+        let normal = *plane.normal.get();
+        let mut count = 0;
+        for mut vertex in self.vertex_orphans() {
+            let position = *vertex.position();
+            let distance = (position - plane.origin).dot(normal);
+            if distance <= epsilon && distance >= -epsilon {
+                *vertex.get_mut().as_position_mut() = position - (normal * distance);
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Snaps the positions of vertices onto a sphere.
+    ///
+    /// Any vertex with a position within the given distance of the surface of
+    /// the sphere is projected radially onto that surface. Vertices at the
+    /// center of the sphere are unaffected.
+    ///
+    /// Returns the number of vertices that were snapped.
+    pub fn snap_to_sphere<T>(&mut self, center: VertexPosition<G>, radius: T, epsilon: T) -> usize
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let radius = radius.into();
+        let epsilon = epsilon.into();
+        let mut count = 0;
+        for mut vertex in self.vertex_orphans() {
+            let offset = *vertex.position() - center;
+            let magnitude = offset.magnitude();
+            let distance = magnitude - radius;
+            if distance <= epsilon && distance >= -epsilon && magnitude > Zero::zero() {
+                *vertex.get_mut().as_position_mut() = center + (offset * (radius / magnitude));
+                count += 1;
+            }
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::{Point3, Vector3};
+    use std::collections::HashMap;
+
+    use crate::geometry::{Plane, Unit};
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+    use crate::primitive::Tetragon;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn snap_to_plane() {
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 2, 3)],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 1e-7),
+                (1.0, 1.0, 0.5),
+                (0.0, 1.0, -1e-7),
+            ],
+        )
+        .unwrap();
+        let plane = Plane {
+            origin: Point3::origin(),
+            normal: Unit::try_from_inner(Vector3::z()).unwrap(),
+        };
+
+        assert_eq!(3, graph.snap_to_plane(plane, 1e-6));
+        for vertex in graph.vertices() {
+            let position = vertex.position();
+            if position.x == 1.0 && position.y == 1.0 {
+                // Vertices beyond the given distance are unaffected.
+                assert_eq!(0.5, position.z);
+            }
+            else {
+                // Vertices within the given distance lie exactly on the plane.
+                assert_eq!(0.0, position.z);
+            }
+        }
+    }
+
+    #[test]
+    fn snap_to_sphere() {
+        let mut graph: MeshGraph<Point3<f64>> =
+            UvSphere::new(8, 4).polygons::<Position<E3>>().collect();
+        for mut vertex in graph.vertex_orphans() {
+            *vertex.get_mut() *= 1.0 + 1e-7;
+        }
+
+        let count = graph.vertex_count();
+        assert_eq!(count, graph.snap_to_sphere(Point3::origin(), 1.0, 1e-6));
+        for vertex in graph.vertices() {
+            assert!((vertex.position().coords.norm() - 1.0).abs() < 1e-12);
+        }
+        // Vertices beyond the given distance are unaffected.
+        assert_eq!(0, graph.snap_to_sphere(Point3::origin(), 2.0, 1e-6));
+    }
+
+    #[test]
+    fn displace_radially() {
+        let height = |position: &Point3<f64>| 0.25 * (position.x + 1.0);
+        let sphere = || {
+            UvSphere::new(16, 8)
+                .polygons::<Position<E3>>()
+                .collect::<MeshGraph<Point3<f64>>>()
+        };
+        let graph = sphere();
+        let mut displaced = sphere();
+        displaced.displace(|vertex| {
+            // Reading adjacent vertices observes the original graph, because
+            // no offsets are applied until all of them have been computed.
+            for adjacent in vertex.adjacent_vertices() {
+                assert!((adjacent.position().coords.norm() - 1.0).abs() < 1e-9);
+            }
+            let position = vertex.position();
+            position.coords.normalize() * height(position)
+        });
+
+        for vertex in graph.vertices() {
+            let radius = displaced
+                .vertex(vertex.key())
+                .unwrap()
+                .position()
+                .coords
+                .norm();
+            assert!((radius - (1.0 + height(vertex.position()))).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn morph_targets() {
+        let mut graph = MeshGraph::<Point3<f64>>::from_heightmap(&[0.0; 9], 3, 3).unwrap();
+        let center = graph
+            .vertices()
+            .find(|vertex| !vertex.is_boundary_vertex())
+            .unwrap()
+            .key();
+        let boundary = graph
+            .vertices()
+            .find(|vertex| vertex.is_boundary_vertex())
+            .unwrap()
+            .key();
+        let position = |key| *graph.vertex(key).unwrap().position();
+        let up = [(center, position(center) + (Vector3::z() * 2.0))]
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        let down = [
+            (center, position(center) - (Vector3::z() * 2.0)),
+            (boundary, position(boundary) + Vector3::z()),
+        ]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+        let z = |graph: &MeshGraph<Point3<f64>>, key| graph.vertex(key).unwrap().position().z;
+
+        // Vertices that are not in the target are unaffected.
+        graph.apply_morph_target(&up, 0.25);
+        assert!((z(&graph, center) - 0.5).abs() < 1e-9);
+        assert!(z(&graph, boundary).abs() < 1e-9);
+
+        // Weights that sum to one average the targets.
+        graph.blend_morph_targets(&[(&up, 0.5), (&down, 0.5)]);
+        assert!(z(&graph, center).abs() < 1e-9);
+        assert!((z(&graph, boundary) - 0.5).abs() < 1e-9);
+    }
+}
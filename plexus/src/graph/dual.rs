@@ -0,0 +1,193 @@
+use smallvec::SmallVec;
+use std::collections::HashMap;
+use theon::AsPositionMut;
+
+use crate::entity::storage::KeyIndex;
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::face::FaceView;
+use crate::graph::geometry::FaceCentroid;
+use crate::graph::mutation::face::FaceInsertCache;
+use crate::graph::mutation::{self, Immediate};
+use crate::graph::{GraphError, MeshGraph};
+use crate::transact::Transact;
+
+type Mutation<M> = mutation::Mutation<Immediate<M>>;
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Creates the dual of the graph.
+    ///
+    /// The dual has a vertex for each face in the graph and a face for each
+    /// vertex in the graph, such that the dual of a cube is an octahedron. The
+    /// data of each vertex in the dual is provided by the given function from
+    /// its corresponding face. Faces in the dual have no data.
+    ///
+    /// Boundary vertices have no enclosing ring of faces and so are skipped:
+    /// no face is inserted into the dual for such vertices. The dual of a graph
+    /// with boundaries is therefore open along those boundaries and faces that
+    /// are only adjacent to boundary vertices have no corresponding vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphError::ArityNonPolygonal`] if a vertex that is not on a
+    /// boundary is adjacent to fewer than three faces, such as the vertices
+    /// of a closed graph with fewer than three faces. Returns an error if the
+    /// dual topology cannot be inserted into a graph. The error is wrapped with
+    /// the key of the vertex in the graph (see [`GraphError::AtKey`]).
+    ///
+    /// [`GraphError::ArityNonPolygonal`]: crate::graph::GraphError::ArityNonPolygonal
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    pub fn to_dual_with<H, F>(&self, mut f: F) -> Result<MeshGraph<H, K>, GraphError>
+    where
+        H: GraphData,
+        F: FnMut(FaceView<&Self>) -> H::Vertex,
+    {
+        let mut mutation = Mutation::from(MeshGraph::<H, K>::new());
+        // Vertices are inserted into the dual as they are first referenced by
+        // a face, because the dual cannot contain vertices that are not
+        // adjacent to any faces.
+        let mut keys = HashMap::with_capacity(self.face_count());
+        for vertex in self.vertices() {
+            if vertex.incoming_arcs().any(|arc| arc.is_boundary_arc())
+                || vertex.outgoing_arcs().any(|arc| arc.is_boundary_arc())
+            {
+                continue;
+            }
+            // Faces are circulated in the opposite direction of the winding of
+            // the faces in the graph, so the perimeter is reversed.
+            let mut perimeter = SmallVec::<[_; 8]>::new();
+            for face in vertex.adjacent_faces() {
+                let key = match keys.get(&face.key()) {
+                    Some(key) => *key,
+                    None => {
+                        let key = face.key();
+                        let vertex = mutation::vertex::insert(&mut mutation, f(face))?;
+                        keys.insert(key, vertex);
+                        vertex
+                    }
+                };
+                perimeter.push(key);
+            }
+            if perimeter.len() < 3 {
+                return Err(GraphError::ArityNonPolygonal.at_key(vertex.key()));
+            }
+            perimeter.reverse();
+            FaceInsertCache::from_storage(&mutation, &perimeter)
+                .and_then(|cache| {
+                    mutation::face::insert_with(&mut mutation, cache, Default::default)
+                })
+                .map_err(|error| error.at_key(vertex.key()))?;
+        }
+        mutation.commit().map_err(|(_, error)| error)
+    }
+
+    /// Creates the dual of the graph with vertices positioned at the centroids
+    /// of faces.
+    ///
+    /// Vertex data in the dual is copied from an arbitrary vertex of the
+    /// corresponding face and its position is replaced by the centroid of that
+    /// face. See [`MeshGraph::to_dual_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphError::ArityNonPolygonal`] if a vertex that is not on a
+    /// boundary is adjacent to fewer than three faces, such as the vertices
+    /// of a closed graph with fewer than three faces. Returns an error if the
+    /// dual topology cannot be inserted into a graph. The error is wrapped with
+    /// the key of the vertex in the graph (see [`GraphError::AtKey`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let octahedron = graph.to_dual().unwrap();
+    /// ```
+    ///
+    /// [`GraphError::ArityNonPolygonal`]: crate::graph::GraphError::ArityNonPolygonal
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    /// [`MeshGraph::to_dual_with`]: crate::graph::MeshGraph::to_dual_with
+    pub fn to_dual(&self) -> Result<Self, GraphError>
+    where
+        G: FaceCentroid,
+        G::Vertex: AsPositionMut,
+    {
+        self.to_dual_with(|face| {
+            let mut data = face.arc().source_vertex().get().clone();
+            *data.as_position_mut() = face.centroid();
+            data
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::graph::{GraphError, MeshGraph};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::Trigon;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn dual_of_cube() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+
+        let adjacency = graph.face_adjacency();
+        assert_eq!(6, adjacency.len());
+        for (_, faces) in adjacency {
+            assert_eq!(4, faces.len());
+        }
+
+        // The dual of a cube is an octahedron.
+        let dual = graph.to_dual().unwrap();
+        assert_eq!(6, dual.vertex_count());
+        assert_eq!(12, dual.edge_count());
+        assert_eq!(8, dual.face_count());
+        for face in dual.faces() {
+            assert_eq!(3, face.arity());
+        }
+        // Faces in the dual are wound consistently with the cube, so normals
+        // point away from the origin.
+        for face in dual.faces() {
+            let normal = face.normal().unwrap();
+            assert!(normal.dot(&face.centroid().coords) > R64::from(0.0));
+        }
+    }
+
+    #[test]
+    fn dual_of_closed_graph_with_two_faces() {
+        // Two triangles with opposing winding form a closed graph, but each
+        // vertex is adjacent to only two faces.
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2), Trigon::new(2, 1, 0)],
+            vec![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)],
+        )
+        .unwrap();
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+
+        let error = graph.to_dual().err().unwrap();
+        assert_eq!(&GraphError::ArityNonPolygonal, error.without_key());
+        assert!(error.key().is_some());
+    }
+}
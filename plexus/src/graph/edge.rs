@@ -1,18 +1,21 @@
 use arrayvec::ArrayVec;
 use derivative::Derivative;
+use num::{NumCast, One, ToPrimitive, Zero};
 use std::borrow::Borrow;
 use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
-use theon::space::{EuclideanSpace, Scalar, Vector};
+use theon::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
 use theon::{AsPosition, AsPositionMut};
 
 use crate::entity::borrow::{Reborrow, ReborrowInto, ReborrowMut};
-use crate::entity::storage::{AsStorage, AsStorageMut, HashStorage, IncrementalKeyer, Key};
+use crate::entity::storage::{
+    AsStorage, AsStorageMut, HashStorage, IncrementalKeyer, Key, KeyIndex,
+};
 use crate::entity::view::{Bind, ClosedView, Orphan, Rebind, Unbind, View};
 use crate::entity::{Entity, Payload};
-use crate::graph::data::{Data, GraphData, Parametric};
+use crate::graph::data::{Data, GraphData, Index, Parametric};
 use crate::graph::face::{Face, FaceKey, FaceOrphan, FaceView, Ring};
-use crate::graph::geometry::{ArcNormal, EdgeMidpoint, VertexPosition};
+use crate::graph::geometry::{ArcNormal, EdgeMidpoint, Lerp, VertexPosition};
 use crate::graph::mutation::edge::{
     self, ArcBridgeCache, ArcExtrudeCache, EdgeRemoveCache, EdgeSplitCache,
 };
@@ -21,7 +24,7 @@ use crate::graph::path::Path;
 use crate::graph::vertex::{Vertex, VertexKey, VertexOrphan, VertexView};
 use crate::graph::{
     Circulator, GraphError, OptionExt as _, OrphanCirculator, ResultExt as _, Selector,
-    ViewCirculator,
+    TopologyKind, ViewCirculator,
 };
 use crate::transact::{BypassOrCommit, Mutate};
 
@@ -30,7 +33,10 @@ type Mutation<M> = mutation::Mutation<Immediate<M>>;
 pub trait ToArc<B>: Sized
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<Data<B>>> + AsStorage<Edge<Data<B>>> + Consistent + Parametric,
+    B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+        + AsStorage<Edge<Data<B>, Index<B>>>
+        + Consistent
+        + Parametric,
 {
     fn into_arc(self) -> ArcView<B>;
 
@@ -46,26 +52,28 @@ where
 /// Arc entity.
 #[derive(Derivative)]
 #[derivative(Debug, Hash)]
-pub struct Arc<G>
+pub struct Arc<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     /// User data.
     #[derivative(Debug = "ignore", Hash = "ignore")]
     pub(crate) data: G::Arc,
     /// Required key into the next arc.
-    pub(crate) next: Option<ArcKey>,
+    pub(crate) next: Option<ArcKey<K>>,
     /// Required key into the previous arc.
-    pub(crate) previous: Option<ArcKey>,
+    pub(crate) previous: Option<ArcKey<K>>,
     /// Required key into the edge.
-    pub(crate) edge: Option<EdgeKey>,
+    pub(crate) edge: Option<EdgeKey<K>>,
     /// Optional key into the face.
-    pub(crate) face: Option<FaceKey>,
+    pub(crate) face: Option<FaceKey<K>>,
 }
 
-impl<G> Arc<G>
+impl<G, K> Arc<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn new(data: G::Arc) -> Self {
         Arc {
@@ -78,19 +86,22 @@ where
     }
 }
 
-impl<G> Entity for Arc<G>
+impl<G, K> Entity for Arc<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    type Key = ArcKey;
+    type Key = ArcKey<K>;
     type Storage = HashStorage<Self>;
 }
 
-impl<G> Payload for Arc<G>
+impl<G, K> Payload for Arc<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     type Data = G::Arc;
+    type Links = Option<FaceKey<K>>;
 
     fn get(&self) -> &Self::Data {
         &self.data
@@ -99,33 +110,43 @@ where
     fn get_mut(&mut self) -> &mut Self::Data {
         &mut self.data
     }
+
+    fn links(&self) -> Self::Links {
+        self.face
+    }
 }
 
 /// Arc key.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
-pub struct ArcKey(VertexKey, VertexKey);
+pub struct ArcKey<K = u64>(VertexKey<K>, VertexKey<K>);
 
-impl ArcKey {
-    pub(in crate::graph) fn into_opposite(self) -> ArcKey {
+impl<K> ArcKey<K>
+where
+    K: KeyIndex,
+{
+    pub(in crate::graph) fn into_opposite(self) -> ArcKey<K> {
         let (a, b) = self.into();
         (b, a).into()
     }
 }
 
-impl From<(VertexKey, VertexKey)> for ArcKey {
-    fn from(key: (VertexKey, VertexKey)) -> Self {
+impl<K> From<(VertexKey<K>, VertexKey<K>)> for ArcKey<K> {
+    fn from(key: (VertexKey<K>, VertexKey<K>)) -> Self {
         ArcKey(key.0, key.1)
     }
 }
 
-impl From<ArcKey> for (VertexKey, VertexKey) {
-    fn from(key: ArcKey) -> Self {
+impl<K> From<ArcKey<K>> for (VertexKey<K>, VertexKey<K>) {
+    fn from(key: ArcKey<K>) -> Self {
         (key.0, key.1)
     }
 }
 
-impl Key for ArcKey {
-    type Inner = (VertexKey, VertexKey);
+impl<K> Key for ArcKey<K>
+where
+    K: KeyIndex,
+{
+    type Inner = (VertexKey<K>, VertexKey<K>);
 
     fn from_inner(key: Self::Inner) -> Self {
         ArcKey(key.0, key.1)
@@ -187,15 +208,15 @@ impl Key for ArcKey {
 pub struct ArcView<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<Data<B>>> + Parametric,
+    B::Target: AsStorage<Arc<Data<B>, Index<B>>> + Parametric,
 {
-    inner: View<B, Arc<Data<B>>>,
+    inner: View<B, Arc<Data<B>, Index<B>>>,
 }
 
 impl<B, M> ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<Data<B>>> + Parametric,
+    M: AsStorage<Arc<Data<B>, Index<B>>> + Parametric,
 {
     pub fn to_ref(&self) -> ArcView<&M> {
         self.inner.to_ref().into()
@@ -212,7 +233,7 @@ where
 impl<B, M> ArcView<B>
 where
     B: ReborrowMut<Target = M>,
-    M: AsStorage<Arc<Data<B>>> + Parametric,
+    M: AsStorage<Arc<Data<B>, Index<B>>> + Parametric,
 {
     // This function is also used to implement `Ring::to_mut_unchecked`.
     #[allow(clippy::wrong_self_convention)]
@@ -221,11 +242,12 @@ where
     }
 }
 
-impl<'a, B, M, G> ArcView<B>
+impl<'a, B, M, G, K> ArcView<B>
 where
     B: ReborrowInto<'a, Target = M>,
-    M: AsStorage<Arc<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     // TODO: Relocate this documentation of `into_ref`.
     /// # Examples
@@ -265,11 +287,12 @@ where
     }
 }
 
-impl<B, M, G> ArcView<B>
+impl<B, M, G, K> ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn get<'a>(&'a self) -> &'a G::Arc
     where
@@ -279,11 +302,12 @@ where
     }
 }
 
-impl<B, M, G> ArcView<B>
+impl<B, M, G, K> ArcView<B>
 where
     B: ReborrowMut<Target = M>,
-    M: AsStorageMut<Arc<G>> + Parametric<Data = G>,
+    M: AsStorageMut<Arc<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn get_mut<'a>(&'a mut self) -> &'a mut G::Arc
     where
@@ -294,11 +318,12 @@ where
 }
 
 /// Reachable API.
-impl<B, M, G> ArcView<B>
+impl<B, M, G, K> ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub(in crate::graph) fn into_reachable_boundary_arc(self) -> Option<Self> {
         if self.is_boundary_arc() {
@@ -326,11 +351,12 @@ where
     }
 }
 
-impl<B, M, G> ArcView<B>
+impl<B, M, G, K> ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Converts the arc into its ring.
     pub fn into_ring(self) -> Ring<B> {
@@ -390,11 +416,12 @@ where
 }
 
 /// Reachable API.
-impl<B, M, G> ArcView<B>
+impl<B, M, G, K> ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub(in crate::graph) fn into_reachable_source_vertex(self) -> Option<VertexView<B>> {
         let (key, _) = self.key().into();
@@ -407,11 +434,15 @@ where
     }
 }
 
-impl<B, M, G> ArcView<B>
+impl<B, M, G, K> ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn into_path(self) -> Path<'static, B> {
         let (storage, ab) = self.unbind();
@@ -445,11 +476,12 @@ where
 }
 
 /// Reachable API.
-impl<B, M, G> ArcView<B>
+impl<B, M, G, K> ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Edge<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Edge<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub(in crate::graph) fn into_reachable_edge(self) -> Option<EdgeView<B>> {
         let key = self.edge;
@@ -457,11 +489,12 @@ where
     }
 }
 
-impl<B, M, G> ArcView<B>
+impl<B, M, G, K> ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Edge<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Edge<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Converts the arc into its edge.
     pub fn into_edge(self) -> EdgeView<B> {
@@ -475,11 +508,12 @@ where
 }
 
 /// Reachable API.
-impl<B, M, G> ArcView<B>
+impl<B, M, G, K> ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub(in crate::graph) fn into_reachable_face(self) -> Option<FaceView<B>> {
         let key = self.face;
@@ -487,11 +521,12 @@ where
     }
 }
 
-impl<B, M, G> ArcView<B>
+impl<B, M, G, K> ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Face<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Face<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Converts the arc into its face.
     ///
@@ -508,11 +543,15 @@ where
     }
 }
 
-impl<B, M, G> ArcView<B>
+impl<B, M, G, K> ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn normal(&self) -> Vector<VertexPosition<G>>
     where
@@ -523,15 +562,16 @@ where
     }
 }
 
-impl<B, M, G> ArcView<B>
+impl<B, M, G, K> ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>>
-        + AsStorage<Edge<G>>
-        + AsStorage<Vertex<G>>
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Edge<G, K>>
+        + AsStorage<Vertex<G, K>>
         + Consistent
-        + Parametric<Data = G>,
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn midpoint(&self) -> VertexPosition<G>
     where
@@ -540,13 +580,32 @@ where
     {
         G::midpoint(self.to_ref()).expect_consistent()
     }
+
+    /// Gets the length of the arc.
+    ///
+    /// The length is the distance between the positions of the source and
+    /// destination vertices of the arc.
+    pub fn length(&self) -> Scalar<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let a = *self.source_vertex().position();
+        let b = *self.destination_vertex().position();
+        (b - a).magnitude()
+    }
 }
 
-impl<'a, B, M, G> ArcView<B>
+impl<'a, B, M, G, K> ArcView<B>
 where
     B: ReborrowInto<'a, Target = M>,
-    M: 'a + AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    M: 'a
+        + AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn into_adjacent_vertices(
         self,
@@ -555,11 +614,15 @@ where
     }
 }
 
-impl<B, G> ArcView<B>
+impl<B, G, K> ArcView<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    B::Target: AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Gets an iterator of views over the vertices connected by the arc.
     pub fn adjacent_vertices(
@@ -569,22 +632,29 @@ where
     }
 }
 
-impl<'a, B, M, G> ArcView<B>
+impl<'a, B, M, G, K> ArcView<B>
 where
     B: ReborrowInto<'a, Target = M>,
-    M: 'a + AsStorage<Arc<G>> + AsStorage<Face<G>> + Consistent + Parametric<Data = G>,
+    M: 'a
+        + AsStorage<Arc<G, K>>
+        + AsStorage<Face<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn into_adjacent_faces(self) -> impl Clone + ExactSizeIterator<Item = FaceView<&'a M>> {
         FaceCirculator::from(self.into_ref())
     }
 }
 
-impl<B, G> ArcView<B>
+impl<B, G, K> ArcView<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<G>> + AsStorage<Face<G>> + Consistent + Parametric<Data = G>,
+    B::Target:
+        AsStorage<Arc<G, K>> + AsStorage<Face<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Gets an iterator of views over the faces connected to the arc.
     pub fn adjacent_faces(&self) -> impl Clone + ExactSizeIterator<Item = FaceView<&B::Target>> {
@@ -594,11 +664,14 @@ where
 
 impl<'a, M> ArcView<&'a mut M>
 where
-    M: AsStorage<Arc<M::Data>> + AsStorageMut<Vertex<M::Data>> + Consistent + Parametric,
+    M: AsStorage<Arc<M::Data, M::Index>>
+        + AsStorageMut<Vertex<M::Data, M::Index>>
+        + Consistent
+        + Parametric,
 {
     pub fn into_adjacent_vertex_orphans(
         self,
-    ) -> impl ExactSizeIterator<Item = VertexOrphan<'a, M::Data>> {
+    ) -> impl ExactSizeIterator<Item = VertexOrphan<'a, M::Data, M::Index>> {
         VertexCirculator::from(self)
     }
 }
@@ -606,23 +679,29 @@ where
 impl<B> ArcView<B>
 where
     B: ReborrowMut,
-    B::Target: AsStorage<Arc<Data<B>>> + AsStorageMut<Vertex<Data<B>>> + Consistent + Parametric,
+    B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+        + AsStorageMut<Vertex<Data<B>, Index<B>>>
+        + Consistent
+        + Parametric,
 {
     /// Gets an iterator of orphan views over the vertices connected by the arc.
     pub fn adjacent_vertex_orphans(
         &mut self,
-    ) -> impl ExactSizeIterator<Item = VertexOrphan<Data<B>>> {
+    ) -> impl ExactSizeIterator<Item = VertexOrphan<Data<B>, Index<B>>> {
         self.to_mut_unchecked().into_adjacent_vertex_orphans()
     }
 }
 
 impl<'a, M> ArcView<&'a mut M>
 where
-    M: AsStorage<Arc<M::Data>> + AsStorageMut<Face<M::Data>> + Consistent + Parametric,
+    M: AsStorage<Arc<M::Data, M::Index>>
+        + AsStorageMut<Face<M::Data, M::Index>>
+        + Consistent
+        + Parametric,
 {
     pub fn into_adjacent_face_orphans(
         self,
-    ) -> impl ExactSizeIterator<Item = FaceOrphan<'a, M::Data>> {
+    ) -> impl ExactSizeIterator<Item = FaceOrphan<'a, M::Data, M::Index>> {
         FaceCirculator::from(self)
     }
 }
@@ -630,23 +709,29 @@ where
 impl<B> ArcView<B>
 where
     B: ReborrowMut,
-    B::Target: AsStorage<Arc<Data<B>>> + AsStorageMut<Face<Data<B>>> + Consistent + Parametric,
+    B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+        + AsStorageMut<Face<Data<B>, Index<B>>>
+        + Consistent
+        + Parametric,
 {
     /// Gets an iterator of orphan views over the faces connected to the arc.
-    pub fn adjacent_face_orphans(&mut self) -> impl ExactSizeIterator<Item = FaceOrphan<Data<B>>> {
+    pub fn adjacent_face_orphans(
+        &mut self,
+    ) -> impl ExactSizeIterator<Item = FaceOrphan<Data<B>, Index<B>>> {
         self.to_mut_unchecked().into_adjacent_face_orphans()
     }
 }
 
-impl<'a, M, G> ArcView<&'a mut M>
+impl<'a, M, G, K> ArcView<&'a mut M>
 where
-    M: AsStorage<Arc<G>>
-        + AsStorage<Edge<G>>
-        + AsStorage<Face<G>>
-        + AsStorage<Vertex<G>>
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Edge<G, K>>
+        + AsStorage<Face<G, K>>
+        + AsStorage<Vertex<G, K>>
         + Default
-        + Mutable<Data = G>,
+        + Mutable<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Splits the composite edge of the arc into two adjacent edges that share
     /// a vertex.
@@ -659,6 +744,11 @@ where
     ///
     /// Returns the inserted vertex.
     ///
+    /// # Errors
+    ///
+    /// Returns an error if the keyspace of the graph is exhausted (see
+    /// [`KeyIndex`]).
+    ///
     /// # Examples
     ///
     /// Splitting an edge in a [`MeshGraph`] with weighted vertices:
@@ -681,22 +771,25 @@ where
     ///     MeshGraph::<Weight>::from_raw_buffers(vec![NGon([0usize, 1, 2])], vec![1.0, 2.0, 0.5])
     ///         .unwrap();
     /// let key = graph.arcs().nth(0).unwrap().key();
-    /// let vertex = graph.arc_mut(key).unwrap().split_with(|| 0.1);
+    /// let vertex = graph.arc_mut(key).unwrap().split_with(|| 0.1).unwrap();
     /// ```
     ///
+    /// [`KeyIndex`]: crate::graph::KeyIndex
     /// [`MeshGraph`]: crate::graph::MeshGraph
-    pub fn split_with<F>(self, f: F) -> VertexView<&'a mut M>
+    pub fn split_with<F>(self, f: F) -> Result<VertexView<&'a mut M>, GraphError>
     where
         F: FnOnce() -> G::Vertex,
     {
         // This should never fail here.
         let cache = EdgeSplitCache::from_arc(self.to_ref()).expect_consistent();
         let (storage, _) = self.unbind();
+        // Splitting inserts a vertex and replaces the composite edge with two
+        // edges.
+        mutation::check_keyspace::<M, G, K>(&*storage, 1, 2, 0)?;
         Mutation::take(storage)
             .bypass_or_commit_with(|mutation| edge::split_with(mutation, cache, f))
             .map(|(storage, m)| Bind::bind(storage, m).expect_consistent())
             .map_err(|(_, error)| error)
-            .expect_consistent()
     }
 
     /// Splits the composite edge of the arc at its midpoint.
@@ -717,6 +810,11 @@ where
     ///
     /// Returns the inserted vertex.
     ///
+    /// # Errors
+    ///
+    /// Returns an error if the keyspace of the graph is exhausted (see
+    /// [`KeyIndex`]).
+    ///
     /// # Examples
     ///
     /// Splitting an edge in a triangle at its midpoint:
@@ -736,12 +834,13 @@ where
     /// )
     /// .unwrap();
     /// let key = graph.arcs().nth(0).unwrap().key();
-    /// let vertex = graph.arc_mut(key).unwrap().split_at_midpoint();
+    /// let vertex = graph.arc_mut(key).unwrap().split_at_midpoint().unwrap();
     /// ```
     ///
     /// [`EdgeMidpoint`]: crate::graph::EdgeMidpoint
+    /// [`KeyIndex`]: crate::graph::KeyIndex
     /// [`MeshGraph`]: crate::graph::MeshGraph
-    pub fn split_at_midpoint(self) -> VertexView<&'a mut M>
+    pub fn split_at_midpoint(self) -> Result<VertexView<&'a mut M>, GraphError>
     where
         G: EdgeMidpoint,
         G::Vertex: AsPositionMut + Clone,
@@ -754,6 +853,279 @@ where
         })
     }
 
+    /// Splits the composite edge of the arc at the given parameter.
+    ///
+    /// Splitting inserts a new vertex with the data of the arc's source vertex
+    /// but modified such that the position of the vertex is interpolated
+    /// between the positions of the arc's source and destination vertices at
+    /// `t`. Splitting at $\frac{1}{2}$ is equivalent to [`split_at_midpoint`].
+    /// Use this to insert vertices exactly where a plane or ray crosses an
+    /// edge.
+    ///
+    /// Splitting an arc $\overrightarrow{AB}$ returns a vertex $M$ that
+    /// subdivides the composite edge. The leading arc of $M$ is
+    /// $\overrightarrow{MB}$ and is a part of the same ring as the initiating
+    /// arc.
+    ///
+    /// Returns the inserted vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `t` is not in the open interval $(0,1)$ or if the
+    /// keyspace of the graph is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// Splitting an edge in a triangle near its source vertex:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2)],
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+    /// )
+    /// .unwrap();
+    /// let key = graph.arcs().nth(0).unwrap().key();
+    /// let vertex = graph.arc_mut(key).unwrap().split_at(0.25).unwrap();
+    /// ```
+    ///
+    /// [`split_at_midpoint`]: crate::graph::ArcView::split_at_midpoint
+    pub fn split_at<T>(self, t: T) -> Result<VertexView<&'a mut M>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let t = t.into();
+        if t <= Zero::zero() || t >= One::one() {
+            return Err(GraphError::Geometry);
+        }
+        let a = *self.source_vertex().position();
+        let b = *self.destination_vertex().position();
+        let mut data = self.source_vertex().get().clone();
+        *data.as_position_mut() = a + ((b - a) * t);
+        self.split_with(move || data)
+    }
+
+    /// Splits the composite edge of the arc by interpolating the data of its
+    /// vertices.
+    ///
+    /// Splitting inserts a new vertex with data that is the [`Lerp`] midpoint
+    /// of the data of both of the arc's vertices. Unlike
+    /// [`split_at_midpoint`], which only computes a position and copies any
+    /// other data from the source vertex, this blends all vertex data, such as
+    /// colors and texture coordinates.
+    ///
+    /// Splitting an arc $\overrightarrow{AB}$ returns a vertex $M$ that
+    /// subdivides the composite edge. The leading arc of $M$ is
+    /// $\overrightarrow{MB}$ and is a part of the same ring as the initiating
+    /// arc.
+    ///
+    /// Returns the inserted vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the keyspace of the graph is exhausted (see
+    /// [`KeyIndex`]).
+    ///
+    /// # Examples
+    ///
+    /// Splitting an edge in a triangle with interpolated vertices:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2)],
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+    /// )
+    /// .unwrap();
+    /// let key = graph.arcs().nth(0).unwrap().key();
+    /// let vertex = graph.arc_mut(key).unwrap().split_interpolated().unwrap();
+    /// ```
+    ///
+    /// [`KeyIndex`]: crate::graph::KeyIndex
+    /// [`Lerp`]: crate::graph::Lerp
+    /// [`split_at_midpoint`]: crate::graph::ArcView::split_at_midpoint
+    pub fn split_interpolated(self) -> Result<VertexView<&'a mut M>, GraphError>
+    where
+        G::Vertex: Clone + Lerp,
+    {
+        let a = self.source_vertex().get().clone();
+        let b = self.destination_vertex().get().clone();
+        self.split_with(move || Lerp::midpoint(a, b))
+    }
+
+    /// Splits the composite edge of the arc by interpolating the data of its
+    /// vertices at the given parameter.
+    ///
+    /// Splitting inserts a new vertex with data that is the [`Lerp`]
+    /// interpolation of the data of the arc's source and destination vertices
+    /// at `t`, where zero yields the data of the source vertex and one yields
+    /// the data of the destination vertex. See [`split_interpolated`].
+    ///
+    /// Returns the inserted vertex.
+    ///
+    /// As with [`split_at`], `t` is given as a scalar of the positions of the
+    /// graph's vertices.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `t` cannot be represented as an `f64` or if the
+    /// keyspace of the graph is exhausted (see [`KeyIndex`]).
+    ///
+    /// [`KeyIndex`]: crate::graph::KeyIndex
+    /// [`Lerp`]: crate::graph::Lerp
+    /// [`split_at`]: crate::graph::ArcView::split_at
+    /// [`split_interpolated`]: crate::graph::ArcView::split_interpolated
+    pub fn split_interpolated_at<T>(self, t: T) -> Result<VertexView<&'a mut M>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPosition + Clone + Lerp,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let t = t.into().to_f64().ok_or(GraphError::Geometry)?;
+        let a = self.source_vertex().get().clone();
+        let b = self.destination_vertex().get().clone();
+        self.split_with(move || a.lerp(b, t))
+    }
+
+    /// Splits the composite edge of the arc into `n` edges of equal length.
+    ///
+    /// Splitting inserts $n - 1$ vertices with the data of the arc's source
+    /// vertex but modified such that their positions are evenly spaced between
+    /// the positions of the arc's source and destination vertices. The arity
+    /// of the faces of the arc and its opposite arc (if any) each increase by
+    /// $n - 1$. Splitting into two edges is equivalent to
+    /// [`split_at_midpoint`]. See also [`EdgeView::split_n`].
+    ///
+    /// Returns the keys of the inserted vertices ordered from the arc's source
+    /// vertex to its destination vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphError::SplitConflict`] if `n` is less than two. Returns
+    /// an error if the keyspace of the graph is exhausted, in which case any
+    /// vertices inserted before the failure remain in the graph.
+    ///
+    /// # Examples
+    ///
+    /// Splitting an edge of a triangle into thirds:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2)],
+    ///     vec![(0.0, 0.0), (3.0, 0.0), (0.0, 3.0)],
+    /// )
+    /// .unwrap();
+    /// let key = graph.arcs().nth(0).unwrap().key();
+    /// let keys = graph.arc_mut(key).unwrap().split_n(3).unwrap();
+    ///
+    /// assert_eq!(2, keys.len());
+    /// assert_eq!(5, graph.faces().nth(0).unwrap().arity());
+    /// ```
+    ///
+    /// [`EdgeView::split_n`]: crate::graph::EdgeView::split_n
+    /// [`GraphError::SplitConflict`]: crate::graph::GraphError::SplitConflict
+    /// [`split_at_midpoint`]: crate::graph::ArcView::split_at_midpoint
+    pub fn split_n(self, n: usize) -> Result<Vec<VertexKey<K>>, GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        if n < 2 {
+            return Err(GraphError::SplitConflict { min: 2, actual: n });
+        }
+        let a = *self.source_vertex().position();
+        let b = *self.destination_vertex().position();
+        let data = self.source_vertex().get().clone();
+        let count = <Scalar<VertexPosition<G>> as NumCast>::from(n).unwrap();
+        self.split_n_with(n, |index| {
+            let t = <Scalar<VertexPosition<G>> as NumCast>::from(index).unwrap() / count;
+            let mut data = data.clone();
+            *data.as_position_mut() = a + ((b - a) * t);
+            data
+        })
+    }
+
+    /// Splits the composite edge of the arc into `n` edges of equal length by
+    /// interpolating the data of its vertices.
+    ///
+    /// Splitting inserts $n - 1$ vertices with data that is the [`Lerp`]
+    /// interpolation of the data of the arc's source and destination vertices
+    /// at evenly spaced parameters. See [`split_n`] and
+    /// [`split_interpolated`].
+    ///
+    /// Returns the keys of the inserted vertices ordered from the arc's source
+    /// vertex to its destination vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphError::SplitConflict`] if `n` is less than two. Returns
+    /// an error if the keyspace of the graph is exhausted. See [`split_n`].
+    ///
+    /// [`GraphError::SplitConflict`]: crate::graph::GraphError::SplitConflict
+    /// [`Lerp`]: crate::graph::Lerp
+    /// [`split_interpolated`]: crate::graph::ArcView::split_interpolated
+    /// [`split_n`]: crate::graph::ArcView::split_n
+    pub fn split_n_interpolated(self, n: usize) -> Result<Vec<VertexKey<K>>, GraphError>
+    where
+        G::Vertex: Clone + Lerp,
+    {
+        if n < 2 {
+            return Err(GraphError::SplitConflict { min: 2, actual: n });
+        }
+        let a = self.source_vertex().get().clone();
+        let b = self.destination_vertex().get().clone();
+        self.split_n_with(n, |index| {
+            a.clone().lerp(b.clone(), index as f64 / n as f64)
+        })
+    }
+
+    // Splits the arc `n - 1` times. The function receives the index of each
+    // inserted vertex, beginning at one nearest the source vertex.
+    fn split_n_with<F>(self, n: usize, mut f: F) -> Result<Vec<VertexKey<K>>, GraphError>
+    where
+        F: FnMut(usize) -> G::Vertex,
+    {
+        let (_, destination) = self.key().into();
+        let mut keys = Vec::with_capacity(n.saturating_sub(1));
+        let mut arc = self;
+        for index in 1..n {
+            let data = f(index);
+            // The leading arc of the inserted vertex is in the same ring as
+            // the split arc and shares its destination vertex.
+            let vertex = arc.split_with(move || data)?;
+            let key = vertex.key();
+            keys.push(key);
+            arc = vertex
+                .rebind(ArcKey::from((key, destination)))
+                .expect_consistent();
+        }
+        Ok(keys)
+    }
+
     // TODO: What if an edge in the bridging quadrilateral is collapsed, such as
     //       bridging arcs within a triangular ring? Document these edge cases
     //       (no pun intended).
@@ -832,13 +1204,13 @@ where
     /// ```
     pub fn bridge(
         self,
-        destination: impl Into<Selector<ArcKey>>,
+        destination: impl Into<Selector<ArcKey<K>>>,
     ) -> Result<FaceView<&'a mut M>, GraphError> {
         let destination = destination.into().key_or_else(|index| {
             self.ring()
                 .arcs()
                 .nth(index)
-                .ok_or(GraphError::TopologyNotFound)
+                .ok_or(GraphError::TopologyNotFound(TopologyKind::Arc))
                 .map(|arc| arc.key())
         })?;
         let cache = ArcBridgeCache::from_arc(self.to_ref(), destination)?;
@@ -939,18 +1311,20 @@ where
     ///
     /// # Errors
     ///
-    /// Returns an error if the arc is not a boundary arc.
+    /// Returns an error if the arc is not a boundary arc or if the keyspace of
+    /// the graph is exhausted.
     pub fn extrude_with<F>(self, f: F) -> Result<Self, GraphError>
     where
         F: Fn(G::Vertex) -> G::Vertex,
     {
         let cache = ArcExtrudeCache::from_arc(self.to_ref())?;
         let (storage, _) = self.unbind();
-        Ok(Mutation::take(storage)
+        // Extrusion inserts two vertices, three edges, and a bridging face.
+        mutation::check_keyspace::<M, G, K>(&*storage, 2, 3, 1)?;
+        Mutation::take(storage)
             .bypass_or_commit_with(|mutation| edge::extrude_with(mutation, cache, f))
             .map(|(storage, arc)| Bind::bind(storage, arc).expect_consistent())
             .map_err(|(_, error)| error)
-            .expect_consistent())
     }
 
     /// Removes the arc and its composite edge.
@@ -974,22 +1348,23 @@ where
     }
 }
 
-impl<B> Borrow<ArcKey> for ArcView<B>
+impl<B> Borrow<ArcKey<Index<B>>> for ArcView<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<Data<B>>> + Parametric,
+    B::Target: AsStorage<Arc<Data<B>, Index<B>>> + Parametric,
 {
-    fn borrow(&self) -> &ArcKey {
+    fn borrow(&self) -> &ArcKey<Index<B>> {
         self.inner.as_ref()
     }
 }
 
-impl<B, M, G> Clone for ArcView<B>
+impl<B, M, G, K> Clone for ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
-    View<B, Arc<G>>: Clone,
+    K: KeyIndex,
+    View<B, Arc<G, K>>: Clone,
 {
     fn clone(&self) -> Self {
         ArcView {
@@ -998,14 +1373,15 @@ where
     }
 }
 
-impl<B, M, G> ClosedView for ArcView<B>
+impl<B, M, G, K> ClosedView for ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Key = ArcKey;
-    type Entity = Arc<G>;
+    type Key = ArcKey<K>;
+    type Entity = Arc<G, K>;
 
     /// Gets the key for the arc.
     fn key(&self) -> Self::Key {
@@ -1013,74 +1389,81 @@ where
     }
 }
 
-impl<B, M, G> Copy for ArcView<B>
+impl<B, M, G, K> Copy for ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
-    View<B, Arc<G>>: Copy,
+    K: KeyIndex,
+    View<B, Arc<G, K>>: Copy,
 {
 }
 
-impl<B, M, G> Deref for ArcView<B>
+impl<B, M, G, K> Deref for ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Target = Arc<G>;
+    type Target = Arc<G, K>;
 
     fn deref(&self) -> &Self::Target {
         self.inner.deref()
     }
 }
 
-impl<B, M, G> DerefMut for ArcView<B>
+impl<B, M, G, K> DerefMut for ArcView<B>
 where
     B: ReborrowMut<Target = M>,
-    M: AsStorageMut<Arc<G>> + Parametric<Data = G>,
+    M: AsStorageMut<Arc<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.inner.deref_mut()
     }
 }
 
-impl<B, M, G> Eq for ArcView<B>
+impl<B, M, G, K> Eq for ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
 }
 
-impl<B, M, G> From<Ring<B>> for ArcView<B>
+impl<B, M, G, K> From<Ring<B>> for ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn from(ring: Ring<B>) -> Self {
         ring.into_arc()
     }
 }
 
-impl<B, M, G> From<View<B, Arc<G>>> for ArcView<B>
+impl<B, M, G, K> From<View<B, Arc<G, K>>> for ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    fn from(view: View<B, Arc<G>>) -> Self {
+    fn from(view: View<B, Arc<G, K>>) -> Self {
         ArcView { inner: view }
     }
 }
 
-impl<B, M, G> From<ArcView<B>> for View<B, Arc<G>>
+impl<B, M, G, K> From<ArcView<B>> for View<B, Arc<G, K>>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn from(arc: ArcView<B>) -> Self {
         let ArcView { inner, .. } = arc;
@@ -1088,11 +1471,12 @@ where
     }
 }
 
-impl<B, M, G> Hash for ArcView<B>
+impl<B, M, G, K> Hash for ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn hash<H>(&self, state: &mut H)
     where
@@ -1102,22 +1486,24 @@ where
     }
 }
 
-impl<B, M, G> PartialEq for ArcView<B>
+impl<B, M, G, K> PartialEq for ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn eq(&self, other: &Self) -> bool {
         self.inner == other.inner
     }
 }
 
-impl<B, M, G> ToArc<B> for ArcView<B>
+impl<B, M, G, K> ToArc<B> for ArcView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Edge<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Edge<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn into_arc(self) -> ArcView<B> {
         self
@@ -1129,16 +1515,18 @@ where
 }
 
 /// Orphan view of an arc entity.
-pub struct ArcOrphan<'a, G>
+pub struct ArcOrphan<'a, G, K = u64>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    inner: Orphan<'a, Arc<G>>,
+    inner: Orphan<'a, Arc<G, K>>,
 }
 
-impl<'a, G> ArcOrphan<'a, G>
+impl<'a, G, K> ArcOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn get(&self) -> &G::Arc {
         self.inner.get()
@@ -1147,61 +1535,83 @@ where
     pub fn get_mut(&mut self) -> &mut G::Arc {
         self.inner.get_mut()
     }
+
+    /// Gets the keys of the source and destination vertices of the arc.
+    pub fn vertex_keys(&self) -> (VertexKey<K>, VertexKey<K>) {
+        self.inner.key().into()
+    }
+
+    /// Gets the key of the face of the arc, if any.
+    ///
+    /// Orphan views cannot traverse topology. This key is captured when the
+    /// orphan view is created.
+    pub fn face_key(&self) -> Option<FaceKey<K>> {
+        self.inner.links()
+    }
 }
 
-impl<'a, G> Borrow<ArcKey> for ArcOrphan<'a, G>
+impl<'a, G, K> Borrow<ArcKey<K>> for ArcOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    fn borrow(&self) -> &ArcKey {
+    fn borrow(&self) -> &ArcKey<K> {
         self.inner.as_ref()
     }
 }
 
-impl<'a, G> ClosedView for ArcOrphan<'a, G>
+impl<'a, G, K> ClosedView for ArcOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    type Key = ArcKey;
-    type Entity = Arc<G>;
+    type Key = ArcKey<K>;
+    type Entity = Arc<G, K>;
 
     fn key(&self) -> Self::Key {
         self.inner.key()
     }
 }
 
-impl<'a, G> Eq for ArcOrphan<'a, G> where G: GraphData {}
+impl<'a, G, K> Eq for ArcOrphan<'a, G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+}
 
-impl<'a, M> From<ArcView<&'a mut M>> for ArcOrphan<'a, M::Data>
+impl<'a, M> From<ArcView<&'a mut M>> for ArcOrphan<'a, M::Data, M::Index>
 where
-    M: AsStorageMut<Arc<M::Data>> + Parametric,
+    M: AsStorageMut<Arc<M::Data, M::Index>> + Parametric,
 {
     fn from(arc: ArcView<&'a mut M>) -> Self {
         Orphan::from(arc.inner).into()
     }
 }
 
-impl<'a, G> From<Orphan<'a, Arc<G>>> for ArcOrphan<'a, G>
+impl<'a, G, K> From<Orphan<'a, Arc<G, K>>> for ArcOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    fn from(inner: Orphan<'a, Arc<G>>) -> Self {
+    fn from(inner: Orphan<'a, Arc<G, K>>) -> Self {
         ArcOrphan { inner }
     }
 }
 
-impl<'a, M> From<View<&'a mut M, Arc<M::Data>>> for ArcOrphan<'a, M::Data>
+impl<'a, M> From<View<&'a mut M, Arc<M::Data, M::Index>>> for ArcOrphan<'a, M::Data, M::Index>
 where
-    M: AsStorageMut<Arc<M::Data>> + Parametric,
+    M: AsStorageMut<Arc<M::Data, M::Index>> + Parametric,
 {
-    fn from(view: View<&'a mut M, Arc<M::Data>>) -> Self {
+    fn from(view: View<&'a mut M, Arc<M::Data, M::Index>>) -> Self {
         ArcOrphan { inner: view.into() }
     }
 }
 
-impl<'a, G> Hash for ArcOrphan<'a, G>
+impl<'a, G, K> Hash for ArcOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     fn hash<H>(&self, state: &mut H)
     where
@@ -1211,9 +1621,10 @@ where
     }
 }
 
-impl<'a, G> PartialEq for ArcOrphan<'a, G>
+impl<'a, G, K> PartialEq for ArcOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     fn eq(&self, other: &Self) -> bool {
         self.inner == other.inner
@@ -1223,39 +1634,44 @@ where
 /// Edge entity.
 #[derive(Derivative)]
 #[derivative(Debug, Hash)]
-pub struct Edge<G>
+pub struct Edge<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     /// User data.
     #[derivative(Debug = "ignore", Hash = "ignore")]
     pub(crate) data: G::Edge,
     /// Required key into the leading arc.
-    pub(crate) arc: ArcKey,
+    pub(crate) arc: ArcKey<K>,
 }
 
-impl<G> Edge<G>
+impl<G, K> Edge<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    pub fn new(arc: ArcKey, data: G::Edge) -> Self {
+    pub fn new(arc: ArcKey<K>, data: G::Edge) -> Self {
         Edge { data, arc }
     }
 }
 
-impl<G> Entity for Edge<G>
+impl<G, K> Entity for Edge<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    type Key = EdgeKey;
-    type Storage = HashStorage<Self, IncrementalKeyer>;
+    type Key = EdgeKey<K>;
+    type Storage = HashStorage<Self, IncrementalKeyer<K>>;
 }
 
-impl<G> Payload for Edge<G>
+impl<G, K> Payload for Edge<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     type Data = G::Edge;
+    type Links = ArcKey<K>;
 
     fn get(&self) -> &Self::Data {
         &self.data
@@ -1264,14 +1680,21 @@ where
     fn get_mut(&mut self) -> &mut Self::Data {
         &mut self.data
     }
+
+    fn links(&self) -> Self::Links {
+        self.arc
+    }
 }
 
 /// Edge key.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
-pub struct EdgeKey(u64);
+pub struct EdgeKey<K = u64>(K);
 
-impl Key for EdgeKey {
-    type Inner = u64;
+impl<K> Key for EdgeKey<K>
+where
+    K: KeyIndex,
+{
+    type Inner = K;
 
     fn from_inner(key: Self::Inner) -> Self {
         EdgeKey(key)
@@ -1293,37 +1716,39 @@ impl Key for EdgeKey {
 pub struct EdgeView<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Edge<<B::Target as Parametric>::Data>> + Parametric,
+    B::Target: AsStorage<Edge<Data<B>, Index<B>>> + Parametric,
 {
-    inner: View<B, Edge<<B::Target as Parametric>::Data>>,
+    inner: View<B, Edge<Data<B>, Index<B>>>,
 }
 
 impl<B, M> EdgeView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Edge<Data<B>>> + Parametric,
+    M: AsStorage<Edge<Data<B>, Index<B>>> + Parametric,
 {
     pub fn to_ref(&self) -> EdgeView<&M> {
         self.inner.to_ref().into()
     }
 }
 
-impl<'a, B, M, G> EdgeView<B>
+impl<'a, B, M, G, K> EdgeView<B>
 where
     B: ReborrowInto<'a, Target = M>,
-    M: AsStorage<Edge<G>> + Parametric<Data = G>,
+    M: AsStorage<Edge<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn into_ref(self) -> EdgeView<&'a M> {
         self.inner.into_ref().into()
     }
 }
 
-impl<B, M, G> EdgeView<B>
+impl<B, M, G, K> EdgeView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Edge<G>> + Parametric<Data = G>,
+    M: AsStorage<Edge<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn get<'a>(&'a self) -> &'a G::Edge
     where
@@ -1333,11 +1758,12 @@ where
     }
 }
 
-impl<B, M, G> EdgeView<B>
+impl<B, M, G, K> EdgeView<B>
 where
     B: ReborrowMut<Target = M>,
-    M: AsStorageMut<Edge<G>> + Parametric<Data = G>,
+    M: AsStorageMut<Edge<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn get_mut<'a>(&'a mut self) -> &'a mut G::Edge
     where
@@ -1348,11 +1774,12 @@ where
 }
 
 /// Reachable API.
-impl<B, M, G> EdgeView<B>
+impl<B, M, G, K> EdgeView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Edge<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Edge<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub(in crate::graph) fn into_reachable_arc(self) -> Option<ArcView<B>> {
         let key = self.arc;
@@ -1360,11 +1787,12 @@ where
     }
 }
 
-impl<B, M, G> EdgeView<B>
+impl<B, M, G, K> EdgeView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Edge<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Edge<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn into_arc(self) -> ArcView<B> {
         self.into_reachable_arc().expect_consistent()
@@ -1374,21 +1802,49 @@ where
         self.to_ref().into_arc()
     }
 
+    /// Gets the pair of arcs that form the edge.
+    ///
+    /// The first arc is the leading arc of the edge and the second arc is its
+    /// opposite arc. Iterating over edges rather than arcs visits each pair of
+    /// arcs exactly once, which is useful for algorithms that operate on
+    /// undirected edges.
+    pub fn arc_pair(&self) -> (ArcView<&M>, ArcView<&M>) {
+        self.to_ref().into_arc_pair()
+    }
+
     pub fn is_boundary_edge(&self) -> bool {
         let arc = self.arc();
         arc.is_boundary_arc() || arc.opposite_arc().is_boundary_arc()
     }
 }
 
-impl<B, M, G> EdgeView<B>
+impl<'a, M, G, K> EdgeView<&'a M>
+where
+    M: AsStorage<Arc<G, K>> + AsStorage<Edge<G, K>> + Consistent + Parametric<Data = G, Index = K>,
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Converts the edge into the pair of arcs that form the edge.
+    ///
+    /// See [`EdgeView::arc_pair`].
+    ///
+    /// [`EdgeView::arc_pair`]: crate::graph::EdgeView::arc_pair
+    pub fn into_arc_pair(self) -> (ArcView<&'a M>, ArcView<&'a M>) {
+        let arc = self.into_arc();
+        (arc, arc.into_opposite_arc())
+    }
+}
+
+impl<B, M, G, K> EdgeView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>>
-        + AsStorage<Edge<G>>
-        + AsStorage<Vertex<G>>
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Edge<G, K>>
+        + AsStorage<Vertex<G, K>>
         + Consistent
-        + Parametric<Data = G>,
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn midpoint(&self) -> VertexPosition<G>
     where
@@ -1397,24 +1853,71 @@ where
     {
         G::midpoint(self.to_ref()).expect_consistent()
     }
+
+    /// Gets the length of the edge.
+    ///
+    /// The length is the distance between the positions of the vertices of
+    /// the edge.
+    pub fn length(&self) -> Scalar<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.arc().length()
+    }
 }
 
-impl<B> Borrow<EdgeKey> for EdgeView<B>
+impl<'a, M, G, K> EdgeView<&'a mut M>
+where
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Edge<G, K>>
+        + AsStorage<Face<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Default
+        + Mutable<Data = G, Index = K>,
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Splits the edge into `n` edges of equal length.
+    ///
+    /// This splits the leading arc of the edge (see [`EdgeView::into_arc`]),
+    /// so the keys of the inserted vertices are ordered from the source vertex
+    /// of that arc to its destination vertex. See [`ArcView::split_n`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphError::SplitConflict`] if `n` is less than two. Returns
+    /// an error if the keyspace of the graph is exhausted.
+    ///
+    /// [`ArcView::split_n`]: crate::graph::ArcView::split_n
+    /// [`EdgeView::into_arc`]: crate::graph::EdgeView::into_arc
+    /// [`GraphError::SplitConflict`]: crate::graph::GraphError::SplitConflict
+    pub fn split_n(self, n: usize) -> Result<Vec<VertexKey<K>>, GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.into_arc().split_n(n)
+    }
+}
+
+impl<B> Borrow<EdgeKey<Index<B>>> for EdgeView<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Edge<Data<B>>> + Parametric,
+    B::Target: AsStorage<Edge<Data<B>, Index<B>>> + Parametric,
 {
-    fn borrow(&self) -> &EdgeKey {
+    fn borrow(&self) -> &EdgeKey<Index<B>> {
         self.inner.as_ref()
     }
 }
 
-impl<B, M, G> Clone for EdgeView<B>
+impl<B, M, G, K> Clone for EdgeView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Edge<G>> + Parametric<Data = G>,
+    M: AsStorage<Edge<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
-    View<B, Edge<G>>: Clone,
+    K: KeyIndex,
+    View<B, Edge<G, K>>: Clone,
 {
     fn clone(&self) -> Self {
         EdgeView {
@@ -1423,14 +1926,15 @@ where
     }
 }
 
-impl<B, M, G> ClosedView for EdgeView<B>
+impl<B, M, G, K> ClosedView for EdgeView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Edge<G>> + Parametric<Data = G>,
+    M: AsStorage<Edge<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Key = EdgeKey;
-    type Entity = Edge<G>;
+    type Key = EdgeKey<K>;
+    type Entity = Edge<G, K>;
 
     /// Gets the key for the edge.
     fn key(&self) -> Self::Key {
@@ -1438,63 +1942,69 @@ where
     }
 }
 
-impl<B, M, G> Copy for EdgeView<B>
+impl<B, M, G, K> Copy for EdgeView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Edge<G>> + Parametric<Data = G>,
+    M: AsStorage<Edge<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
-    View<B, Edge<G>>: Copy,
+    K: KeyIndex,
+    View<B, Edge<G, K>>: Copy,
 {
 }
 
-impl<B, M, G> Deref for EdgeView<B>
+impl<B, M, G, K> Deref for EdgeView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Edge<G>> + Parametric<Data = G>,
+    M: AsStorage<Edge<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Target = Edge<G>;
+    type Target = Edge<G, K>;
 
     fn deref(&self) -> &Self::Target {
         self.inner.deref()
     }
 }
 
-impl<B, M, G> DerefMut for EdgeView<B>
+impl<B, M, G, K> DerefMut for EdgeView<B>
 where
     B: ReborrowMut<Target = M>,
-    M: AsStorageMut<Edge<G>> + Parametric<Data = G>,
+    M: AsStorageMut<Edge<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.inner.deref_mut()
     }
 }
 
-impl<B, M, G> Eq for EdgeView<B>
+impl<B, M, G, K> Eq for EdgeView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Edge<G>> + Parametric<Data = G>,
+    M: AsStorage<Edge<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
 }
 
-impl<B, M, G> From<View<B, Edge<G>>> for EdgeView<B>
+impl<B, M, G, K> From<View<B, Edge<G, K>>> for EdgeView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Edge<G>> + Parametric<Data = G>,
+    M: AsStorage<Edge<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    fn from(view: View<B, Edge<G>>) -> Self {
+    fn from(view: View<B, Edge<G, K>>) -> Self {
         EdgeView { inner: view }
     }
 }
 
-impl<B, M, G> From<EdgeView<B>> for View<B, Edge<G>>
+impl<B, M, G, K> From<EdgeView<B>> for View<B, Edge<G, K>>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Edge<G>> + Parametric<Data = G>,
+    M: AsStorage<Edge<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn from(edge: EdgeView<B>) -> Self {
         let EdgeView { inner, .. } = edge;
@@ -1502,11 +2012,12 @@ where
     }
 }
 
-impl<B, M, G> Hash for EdgeView<B>
+impl<B, M, G, K> Hash for EdgeView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Edge<G>> + Parametric<Data = G>,
+    M: AsStorage<Edge<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn hash<H>(&self, state: &mut H)
     where
@@ -1516,22 +2027,24 @@ where
     }
 }
 
-impl<B, M, G> PartialEq for EdgeView<B>
+impl<B, M, G, K> PartialEq for EdgeView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Edge<G>> + Parametric<Data = G>,
+    M: AsStorage<Edge<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn eq(&self, other: &Self) -> bool {
         self.inner == other.inner
     }
 }
 
-impl<B, M, G> ToArc<B> for EdgeView<B>
+impl<B, M, G, K> ToArc<B> for EdgeView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Edge<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Edge<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn into_arc(self) -> ArcView<B> {
         EdgeView::into_arc(self)
@@ -1543,16 +2056,18 @@ where
 }
 
 /// Orphan view of an edge entity.
-pub struct EdgeOrphan<'a, G>
+pub struct EdgeOrphan<'a, G, K = u64>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    inner: Orphan<'a, Edge<G>>,
+    inner: Orphan<'a, Edge<G, K>>,
 }
 
-impl<'a, G> EdgeOrphan<'a, G>
+impl<'a, G, K> EdgeOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn get(&self) -> &G::Edge {
         self.inner.get()
@@ -1561,61 +2076,88 @@ where
     pub fn get_mut(&mut self) -> &mut G::Edge {
         self.inner.get_mut()
     }
+
+    /// Gets the key of the leading arc of the edge.
+    ///
+    /// Orphan views cannot traverse topology. This key is captured when the
+    /// orphan view is created.
+    pub fn arc_key(&self) -> ArcKey<K> {
+        self.inner.links()
+    }
+
+    /// Gets the keys of the vertices of the edge.
+    ///
+    /// The keys are ordered by the leading arc of the edge (source and then
+    /// destination). See [`EdgeOrphan::arc_key`].
+    ///
+    /// [`EdgeOrphan::arc_key`]: crate::graph::EdgeOrphan::arc_key
+    pub fn vertex_keys(&self) -> (VertexKey<K>, VertexKey<K>) {
+        self.arc_key().into()
+    }
 }
 
-impl<'a, G> Borrow<EdgeKey> for EdgeOrphan<'a, G>
+impl<'a, G, K> Borrow<EdgeKey<K>> for EdgeOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    fn borrow(&self) -> &EdgeKey {
+    fn borrow(&self) -> &EdgeKey<K> {
         self.inner.as_ref()
     }
 }
 
-impl<'a, G> ClosedView for EdgeOrphan<'a, G>
+impl<'a, G, K> ClosedView for EdgeOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    type Key = EdgeKey;
-    type Entity = Edge<G>;
+    type Key = EdgeKey<K>;
+    type Entity = Edge<G, K>;
 
     fn key(&self) -> Self::Key {
         self.inner.key()
     }
 }
 
-impl<'a, G> Eq for EdgeOrphan<'a, G> where G: GraphData {}
+impl<'a, G, K> Eq for EdgeOrphan<'a, G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+}
 
-impl<'a, M> From<EdgeView<&'a mut M>> for EdgeOrphan<'a, M::Data>
+impl<'a, M> From<EdgeView<&'a mut M>> for EdgeOrphan<'a, M::Data, M::Index>
 where
-    M: AsStorageMut<Edge<M::Data>> + Parametric,
+    M: AsStorageMut<Edge<M::Data, M::Index>> + Parametric,
 {
     fn from(edge: EdgeView<&'a mut M>) -> Self {
         Orphan::from(edge.inner).into()
     }
 }
 
-impl<'a, G> From<Orphan<'a, Edge<G>>> for EdgeOrphan<'a, G>
+impl<'a, G, K> From<Orphan<'a, Edge<G, K>>> for EdgeOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    fn from(inner: Orphan<'a, Edge<G>>) -> Self {
+    fn from(inner: Orphan<'a, Edge<G, K>>) -> Self {
         EdgeOrphan { inner }
     }
 }
 
-impl<'a, M> From<View<&'a mut M, Edge<M::Data>>> for EdgeOrphan<'a, M::Data>
+impl<'a, M> From<View<&'a mut M, Edge<M::Data, M::Index>>> for EdgeOrphan<'a, M::Data, M::Index>
 where
-    M: AsStorageMut<Edge<M::Data>> + Parametric,
+    M: AsStorageMut<Edge<M::Data, M::Index>> + Parametric,
 {
-    fn from(view: View<&'a mut M, Edge<M::Data>>) -> Self {
+    fn from(view: View<&'a mut M, Edge<M::Data, M::Index>>) -> Self {
         EdgeOrphan { inner: view.into() }
     }
 }
 
-impl<'a, G> Hash for EdgeOrphan<'a, G>
+impl<'a, G, K> Hash for EdgeOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     fn hash<H>(&self, state: &mut H)
     where
@@ -1625,9 +2167,10 @@ where
     }
 }
 
-impl<'a, G> PartialEq for EdgeOrphan<'a, G>
+impl<'a, G, K> PartialEq for EdgeOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     fn eq(&self, other: &Self) -> bool {
         self.inner == other.inner
@@ -1637,30 +2180,32 @@ where
 pub struct VertexCirculator<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Vertex<Data<B>>> + Parametric,
+    B::Target: AsStorage<Vertex<Data<B>, Index<B>>> + Parametric,
 {
     storage: B,
-    inner: <ArrayVec<VertexKey, 2> as IntoIterator>::IntoIter,
+    inner: <ArrayVec<VertexKey<Index<B>>, 2> as IntoIterator>::IntoIter,
 }
 
-impl<B, M, G> Circulator<B> for VertexCirculator<B>
+impl<B, M, G, K> Circulator<B> for VertexCirculator<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Entity = Vertex<G>;
+    type Entity = Vertex<G, K>;
 
     fn next(&mut self) -> Option<<Self::Entity as Entity>::Key> {
         self.inner.next()
     }
 }
 
-impl<B, M, G> From<ArcView<B>> for VertexCirculator<B>
+impl<B, M, G, K> From<ArcView<B>> for VertexCirculator<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn from(arc: ArcView<B>) -> Self {
         let (a, b) = arc.key().into();
@@ -1672,11 +2217,12 @@ where
     }
 }
 
-impl<B, M, G> Clone for VertexCirculator<B>
+impl<B, M, G, K> Clone for VertexCirculator<B>
 where
     B: Clone + Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn clone(&self) -> Self {
         VertexCirculator {
@@ -1686,19 +2232,21 @@ where
     }
 }
 
-impl<B, M, G> ExactSizeIterator for VertexCirculator<B>
+impl<B, M, G, K> ExactSizeIterator for VertexCirculator<B>
 where
     Self: Iterator,
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
 }
 
-impl<'a, M, G> Iterator for VertexCirculator<&'a M>
+impl<'a, M, G, K> Iterator for VertexCirculator<&'a M>
 where
-    M: AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     type Item = VertexView<&'a M>;
 
@@ -1713,9 +2261,9 @@ where
 
 impl<'a, M> Iterator for VertexCirculator<&'a mut M>
 where
-    M: AsStorageMut<Vertex<M::Data>> + Parametric,
+    M: AsStorageMut<Vertex<M::Data, M::Index>> + Parametric,
 {
-    type Item = VertexOrphan<'a, M::Data>;
+    type Item = VertexOrphan<'a, M::Data, M::Index>;
 
     fn next(&mut self) -> Option<Self::Item> {
         unsafe { self.bind_next_orphan() }
@@ -1728,7 +2276,7 @@ where
 
 impl<'a, M> OrphanCirculator<'a, M> for VertexCirculator<&'a mut M>
 where
-    M: AsStorageMut<Vertex<M::Data>> + Parametric,
+    M: AsStorageMut<Vertex<M::Data, M::Index>> + Parametric,
 {
     fn target(&mut self) -> &mut M {
         self.storage
@@ -1737,7 +2285,7 @@ where
 
 impl<'a, M> ViewCirculator<'a, M> for VertexCirculator<&'a M>
 where
-    M: AsStorage<Vertex<M::Data>> + Parametric,
+    M: AsStorage<Vertex<M::Data, M::Index>> + Parametric,
 {
     fn target(&self) -> &'a M {
         self.storage
@@ -1747,30 +2295,32 @@ where
 pub struct FaceCirculator<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Face<Data<B>>> + Parametric,
+    B::Target: AsStorage<Face<Data<B>, Index<B>>> + Parametric,
 {
     storage: B,
-    inner: <ArrayVec<FaceKey, 2> as IntoIterator>::IntoIter,
+    inner: <ArrayVec<FaceKey<Index<B>>, 2> as IntoIterator>::IntoIter,
 }
 
-impl<B, M, G> Circulator<B> for FaceCirculator<B>
+impl<B, M, G, K> Circulator<B> for FaceCirculator<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Entity = Face<G>;
+    type Entity = Face<G, K>;
 
     fn next(&mut self) -> Option<<Self::Entity as Entity>::Key> {
         self.inner.next()
     }
 }
 
-impl<B, M, G> Clone for FaceCirculator<B>
+impl<B, M, G, K> Clone for FaceCirculator<B>
 where
     B: Clone + Reborrow<Target = M>,
-    M: AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn clone(&self) -> Self {
         FaceCirculator {
@@ -1780,20 +2330,22 @@ where
     }
 }
 
-impl<B, M, G> ExactSizeIterator for FaceCirculator<B>
+impl<B, M, G, K> ExactSizeIterator for FaceCirculator<B>
 where
     Self: Iterator,
     B: Reborrow<Target = M>,
-    M: AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
 }
 
-impl<B, M, G> From<ArcView<B>> for FaceCirculator<B>
+impl<B, M, G, K> From<ArcView<B>> for FaceCirculator<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn from(arc: ArcView<B>) -> Self {
         let inner = arc
@@ -1811,10 +2363,11 @@ where
     }
 }
 
-impl<'a, M, G> Iterator for FaceCirculator<&'a M>
+impl<'a, M, G, K> Iterator for FaceCirculator<&'a M>
 where
-    M: AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     type Item = FaceView<&'a M>;
 
@@ -1829,9 +2382,9 @@ where
 
 impl<'a, M> Iterator for FaceCirculator<&'a mut M>
 where
-    M: AsStorageMut<Face<M::Data>> + Parametric,
+    M: AsStorageMut<Face<M::Data, M::Index>> + Parametric,
 {
-    type Item = FaceOrphan<'a, M::Data>;
+    type Item = FaceOrphan<'a, M::Data, M::Index>;
 
     fn next(&mut self) -> Option<Self::Item> {
         unsafe { self.bind_next_orphan() }
@@ -1844,7 +2397,7 @@ where
 
 impl<'a, M> OrphanCirculator<'a, M> for FaceCirculator<&'a mut M>
 where
-    M: AsStorageMut<Face<M::Data>> + Parametric,
+    M: AsStorageMut<Face<M::Data, M::Index>> + Parametric,
 {
     fn target(&mut self) -> &mut M {
         self.storage
@@ -1853,7 +2406,7 @@ where
 
 impl<'a, M> ViewCirculator<'a, M> for FaceCirculator<&'a M>
 where
-    M: AsStorage<Face<M::Data>> + Parametric,
+    M: AsStorage<Face<M::Data, M::Index>> + Parametric,
 {
     fn target(&self) -> &'a M {
         self.storage
@@ -1864,14 +2417,15 @@ where
 mod tests {
     use decorum::R64;
     use nalgebra::{Point2, Point3};
+    use std::collections::{HashMap, HashSet};
 
-    use crate::geometry::FromGeometry;
-    use crate::graph::{ArcKey, GraphData, MeshGraph};
+    use crate::geometry::{AsPosition, FromGeometry};
+    use crate::graph::{ArcKey, FaceKey, GraphData, GraphError, Lerp, MeshGraph, VertexKey};
     use crate::index::HashIndexer;
     use crate::prelude::*;
     use crate::primitive::cube::Cube;
     use crate::primitive::generate::Position;
-    use crate::primitive::Tetragon;
+    use crate::primitive::{Tetragon, Trigon};
 
     type E2 = Point2<R64>;
     type E3 = Point3<R64>;
@@ -1949,7 +2503,12 @@ mod tests {
             .index_vertices::<Tetragon<usize>, _>(HashIndexer::default());
         let mut graph = MeshGraph::<E3>::from_raw_buffers(indices, vertices).unwrap();
         let key = graph.arcs().nth(0).unwrap().key();
-        let vertex = graph.arc_mut(key).unwrap().split_at_midpoint().into_ref();
+        let vertex = graph
+            .arc_mut(key)
+            .unwrap()
+            .split_at_midpoint()
+            .unwrap()
+            .into_ref();
 
         assert_eq!(5, vertex.into_outgoing_arc().into_face().unwrap().arity());
         assert_eq!(
@@ -1963,6 +2522,239 @@ mod tests {
         );
     }
 
+    #[test]
+    fn split_edge_at() {
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![(0.0, 0.0), (4.0, 0.0), (0.0, 4.0)],
+        )
+        .unwrap();
+        let key = find_arc(&graph, ((0.0, 0.0), (4.0, 0.0))).unwrap();
+
+        assert!(graph.arc_mut(key).unwrap().split_at(0.0).is_err());
+        assert!(graph.arc_mut(key).unwrap().split_at(1.0).is_err());
+        assert_eq!(3, graph.vertex_count());
+
+        let vertex = graph
+            .arc_mut(key)
+            .unwrap()
+            .split_at(0.25)
+            .unwrap()
+            .into_ref();
+        assert_eq!(Point2::new(1.0, 0.0), *vertex.position());
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(4, graph.faces().next().unwrap().arity());
+    }
+
+    #[test]
+    fn split_edge_n() {
+        // Construct two triangles that share the edge from (0, 0) to (1, 0).
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2), Trigon::new(1, 0, 3)],
+            vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (0.0, -1.0)],
+        )
+        .unwrap();
+        let key = find_arc(&graph, ((0.0, 0.0), (1.0, 0.0))).unwrap();
+
+        assert_eq!(
+            Err(GraphError::SplitConflict { min: 2, actual: 0 }),
+            graph.arc_mut(key).unwrap().split_n(0),
+        );
+        assert_eq!(
+            Err(GraphError::SplitConflict { min: 2, actual: 1 }),
+            graph.arc_mut(key).unwrap().split_n_interpolated(1),
+        );
+        assert_eq!(4, graph.vertex_count());
+
+        let keys = graph.arc_mut(key).unwrap().split_n(4).unwrap();
+        assert_eq!(
+            vec![
+                Point2::new(0.25, 0.0),
+                Point2::new(0.5, 0.0),
+                Point2::new(0.75, 0.0),
+            ],
+            keys.iter()
+                .map(|key| *graph.vertex(*key).unwrap().position())
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(7, graph.vertex_count());
+        assert_eq!(8, graph.edge_count());
+        for face in graph.faces() {
+            assert_eq!(6, face.arity());
+        }
+
+        // Split an edge of the boundary into halves.
+        let key = find_arc(&graph, ((0.0, 1.0), (0.0, 0.0))).unwrap();
+        let key = graph.arc(key).unwrap().edge().key();
+        assert_eq!(
+            Err(GraphError::SplitConflict { min: 2, actual: 1 }),
+            graph.edge_mut(key).unwrap().split_n(1),
+        );
+        let keys = graph.edge_mut(key).unwrap().split_n(2).unwrap();
+        assert_eq!(
+            Point2::new(0.0, 0.5),
+            *graph.vertex(keys[0]).unwrap().position(),
+        );
+    }
+
+    #[test]
+    fn split_edge_interpolated() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Vertex {
+            position: Point2<f64>,
+            color: [f64; 3],
+        }
+
+        impl GraphData for Vertex {
+            type Vertex = Self;
+            type Arc = ();
+            type Edge = ();
+            type Face = ();
+        }
+
+        impl AsPosition for Vertex {
+            type Position = Point2<f64>;
+
+            fn as_position(&self) -> &Self::Position {
+                &self.position
+            }
+        }
+
+        impl Lerp for Vertex {
+            fn lerp(self, other: Self, t: f64) -> Self {
+                Vertex {
+                    position: Lerp::lerp(self.position, other.position, t),
+                    color: Lerp::lerp(self.color, other.color, t),
+                }
+            }
+        }
+
+        let mut graph = MeshGraph::<Vertex>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![
+                Vertex {
+                    position: Point2::new(0.0, 0.0),
+                    color: [1.0, 0.0, 0.0],
+                },
+                Vertex {
+                    position: Point2::new(1.0, 0.0),
+                    color: [0.0, 1.0, 0.0],
+                },
+                Vertex {
+                    position: Point2::new(0.0, 1.0),
+                    color: [0.0, 0.0, 1.0],
+                },
+            ],
+        )
+        .unwrap();
+        let key = graph
+            .arcs()
+            .find(|arc| {
+                arc.source_vertex().get().color == [1.0, 0.0, 0.0]
+                    && arc.destination_vertex().get().color == [0.0, 1.0, 0.0]
+            })
+            .unwrap()
+            .key();
+        let vertex = graph
+            .arc_mut(key)
+            .unwrap()
+            .split_interpolated()
+            .unwrap()
+            .into_ref();
+
+        assert_eq!(Point2::new(0.5, 0.0), vertex.get().position);
+        assert_eq!([0.5, 0.5, 0.0], vertex.get().color);
+
+        // Split the arc from the inserted vertex to the second vertex near its
+        // source.
+        let key = vertex
+            .into_outgoing_arcs()
+            .find(|arc| arc.destination_vertex().get().color == [0.0, 1.0, 0.0])
+            .unwrap()
+            .key();
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(4, graph.faces().next().unwrap().arity());
+        let vertex = graph
+            .arc_mut(key)
+            .unwrap()
+            .split_interpolated_at(0.25)
+            .unwrap()
+            .into_ref();
+
+        assert_eq!(Point2::new(0.5 + 0.125, 0.0), vertex.get().position);
+        assert_eq!([0.375, 0.625, 0.0], vertex.get().color);
+    }
+
+    #[test]
+    fn orphan_keys() {
+        enum Keys {}
+
+        impl GraphData for Keys {
+            type Vertex = E3;
+            type Arc = Option<FaceKey>;
+            type Edge = Option<(VertexKey, VertexKey)>;
+            type Face = Option<ArcKey>;
+        }
+
+        let mut graph: MeshGraph<Keys> = Cube::new().polygons::<Position<E3>>().collect();
+
+        // Write data derived from the keys of adjacent topology without
+        // traversal.
+        for mut arc in graph.arc_orphans() {
+            let face = arc.face_key();
+            *arc.get_mut() = face;
+        }
+        for mut edge in graph.edge_orphans() {
+            let vertices = edge.vertex_keys();
+            *edge.get_mut() = Some(vertices);
+        }
+        for mut face in graph.face_orphans() {
+            let arc = face.arc_key();
+            *face.get_mut() = Some(arc);
+        }
+        for arc in graph.arcs() {
+            assert_eq!(arc.face().map(|face| face.key()), *arc.get());
+        }
+        for edge in graph.edges() {
+            let arc = edge.arc();
+            assert_eq!(
+                Some((arc.source_vertex().key(), arc.destination_vertex().key())),
+                *edge.get()
+            );
+        }
+        for face in graph.faces() {
+            assert_eq!(Some(face.arc().key()), *face.get());
+        }
+
+        // Bucket edges by the keys of their vertices.
+        let mut buckets = HashMap::<VertexKey, usize>::new();
+        for edge in graph.edge_orphans() {
+            let (a, b) = edge.vertex_keys();
+            *buckets.entry(a).or_default() += 1;
+            *buckets.entry(b).or_default() += 1;
+        }
+        assert_eq!(8, buckets.len());
+        assert!(buckets.values().all(|count| *count == 3));
+    }
+
+    #[test]
+    fn edge_arc_pairs() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+
+        // Each arc is visited exactly once via the arc pairs of edges.
+        let mut arcs = HashSet::new();
+        for edge in graph.edges() {
+            let (ab, ba) = edge.arc_pair();
+            assert_eq!(ab.key(), edge.arc().key());
+            assert_eq!(ab.key().into_opposite(), ba.key());
+            assert_eq!(edge.key(), ba.edge().key());
+            assert!(arcs.insert(ab.key()));
+            assert!(arcs.insert(ba.key()));
+        }
+        assert_eq!(graph.arc_count(), arcs.len());
+        assert_eq!(12, graph.edges().count());
+    }
+
     #[test]
     fn remove_edge() {
         // Construct a graph with two connected quadrilaterals.
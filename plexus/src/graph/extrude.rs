@@ -0,0 +1,704 @@
+use num::One;
+use smallvec::SmallVec;
+use std::collections::{HashMap, HashSet};
+use std::vec;
+use theon::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
+use theon::AsPositionMut;
+
+use crate::entity::storage::KeyIndex;
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::edge::EdgeKey;
+use crate::graph::face::FaceKey;
+use crate::graph::geometry::{FaceNormal, VertexNormal, VertexPosition};
+use crate::graph::mutation::face::FaceInsertCache;
+use crate::graph::mutation::{self, Immediate};
+use crate::graph::vertex::VertexKey;
+use crate::graph::{GraphError, MeshGraph, OptionExt as _, TopologyKind};
+use crate::transact::{BypassOrCommit, Mutate, Transact};
+
+type Mutation<M> = mutation::Mutation<Immediate<M>>;
+
+/// Direction along which faces are extruded.
+///
+/// See [`MeshGraph::extrude_faces_with`].
+///
+/// [`MeshGraph::extrude_faces_with`]: crate::graph::MeshGraph::extrude_faces_with
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ExtrusionNormal {
+    /// Offsets each region of faces along the area-weighted average of the
+    /// normals of its faces.
+    ///
+    /// Regions are translated rigidly and keep their shape.
+    Region,
+    /// Offsets each vertex along the average of the normals of its adjacent
+    /// faces in the region.
+    Vertex,
+}
+
+impl Default for ExtrusionNormal {
+    fn default() -> Self {
+        ExtrusionNormal::Region
+    }
+}
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Thickens the surface of the graph into a closed shell.
+    ///
+    /// Each vertex is offset along its normal by half of the given thickness
+    /// to form an outer surface and by half of the thickness in the opposite
+    /// direction to form an inner surface with reversed winding. The two
+    /// surfaces are then joined by quadrilaterals along each boundary loop of
+    /// the graph, forming a closed shell. This is typically used to prepare
+    /// open surfaces for 3-D printing.
+    ///
+    /// Vertex and face data are copied into both surfaces. Faces joining the
+    /// surfaces use default data. If the graph has no boundaries, then the
+    /// resulting graph consists of two disjoint nested shells.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a vertex normal cannot be computed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Tetragon;
+    ///
+    /// let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+    ///     vec![Tetragon::new(0usize, 1, 2, 3)],
+    ///     vec![
+    ///         (0.0, 0.0, 0.0),
+    ///         (1.0, 0.0, 0.0),
+    ///         (1.0, 1.0, 0.0),
+    ///         (0.0, 1.0, 0.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// let graph = graph.thicken(0.1).unwrap();
+    ///
+    /// assert_eq!(6, graph.face_count());
+    /// assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+    /// ```
+    pub fn thicken<T>(self, thickness: T) -> Result<Self, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: VertexNormal,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let two = Scalar::<VertexPosition<G>>::one() + One::one();
+        let offset = thickness.into() / two;
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let mut keys = HashMap::with_capacity(self.vertex_count());
+        for vertex in self.vertices() {
+            let normal = vertex.normal()? * offset;
+            let outer = vertex
+                .get()
+                .clone()
+                .map_position(|position| *position + normal);
+            let inner = vertex
+                .get()
+                .clone()
+                .map_position(|position| *position - normal);
+            let outer = mutation::vertex::insert(&mut mutation, outer)?;
+            let inner = mutation::vertex::insert(&mut mutation, inner)?;
+            keys.insert(vertex.key(), (outer, inner));
+        }
+        for face in self.faces() {
+            let (outer, mut inner): (SmallVec<[_; 4]>, SmallVec<[_; 4]>) = face
+                .adjacent_vertices()
+                .map(|vertex| keys[&vertex.key()])
+                .unzip();
+            inner.reverse();
+            for perimeter in [outer, inner] {
+                let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+                let data = face.get().clone();
+                mutation::face::insert_with(&mut mutation, cache, || (Default::default(), data))?;
+            }
+        }
+        // The outer surface shares the boundary arcs of the graph and the
+        // inner surface has boundary arcs in the opposite direction, so each
+        // boundary arc `ab` is joined by the quadrilateral `(a, b, b', a')`.
+        for arc in self.arcs().filter(|arc| arc.is_boundary_arc()) {
+            let (a, b) = arc.key().into();
+            let ((oa, ia), (ob, ib)) = (keys[&a], keys[&b]);
+            let cache = FaceInsertCache::from_storage(&mutation, &[oa, ob, ib, ia])?;
+            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+        }
+        mutation.commit().map_err(|(_, error)| error)
+    }
+
+    /// Extrudes regions of faces along their normals.
+    ///
+    /// The given faces are grouped into regions of edge-connected faces and
+    /// each region is extruded as a single unit. Only the vertices along the
+    /// boundary of a region are duplicated and quadrilaterals are inserted
+    /// only along the boundary of a region, so no walls are formed between
+    /// adjacent faces of the same region. Each region is offset along the
+    /// area-weighted average of the normals of its faces. See
+    /// [`extrude_faces_with`] to offset vertices along their normals instead.
+    ///
+    /// The graph is rebuilt by this operation, so the keys of its topology
+    /// are invalidated and arc and edge data is replaced with default values.
+    /// Layers are migrated to the rebuilt graph: existing topology retains its
+    /// values and inserted topology has default values.
+    ///
+    /// Returns the keys of the extruded faces in the order in which they were
+    /// given.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a face is not found or if a normal cannot be
+    /// computed. The error is wrapped with the key of the offending face or
+    /// vertex (see [`GraphError::AtKey`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+    /// let face = graph.faces().nth(0).unwrap();
+    /// let keys = face
+    ///     .adjacent_faces()
+    ///     .take(1)
+    ///     .map(|face| face.key())
+    ///     .chain(Some(face.key()))
+    ///     .collect::<Vec<_>>();
+    /// graph.extrude_faces(&keys, 1.0).unwrap();
+    ///
+    /// assert_eq!(12, graph.face_count());
+    /// ```
+    ///
+    /// [`extrude_faces_with`]: crate::graph::MeshGraph::extrude_faces_with
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    pub fn extrude_faces<T>(
+        &mut self,
+        faces: &[FaceKey<K>],
+        offset: T,
+    ) -> Result<Vec<FaceKey<K>>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: FaceNormal,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.extrude_faces_with(faces, offset, ExtrusionNormal::Region)
+    }
+
+    /// Extrudes regions of faces along the given kind of normal.
+    ///
+    /// See [`extrude_faces`] and [`ExtrusionNormal`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a face is not found or if a normal cannot be
+    /// computed. The error is wrapped with the key of the offending face or
+    /// vertex (see [`GraphError::AtKey`]).
+    ///
+    /// [`ExtrusionNormal`]: crate::graph::ExtrusionNormal
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    /// [`extrude_faces`]: crate::graph::MeshGraph::extrude_faces
+    pub fn extrude_faces_with<T>(
+        &mut self,
+        faces: &[FaceKey<K>],
+        offset: T,
+        normal: ExtrusionNormal,
+    ) -> Result<Vec<FaceKey<K>>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: FaceNormal,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let offset = offset.into();
+        if let Some(key) = faces.iter().find(|key| self.face(**key).is_none()) {
+            return Err(GraphError::TopologyNotFound(TopologyKind::Face).at_key(*key));
+        }
+        let selection = faces.iter().cloned().collect::<HashSet<_>>();
+        // Group the faces into regions of edge-connected faces.
+        let mut regions = HashMap::<FaceKey<K>, usize>::with_capacity(faces.len());
+        let mut count = 0;
+        for key in faces {
+            if regions.contains_key(key) {
+                continue;
+            }
+            regions.insert(*key, count);
+            let mut keys = vec![*key];
+            while let Some(key) = keys.pop() {
+                for face in self.face(key).expect_consistent().adjacent_faces() {
+                    if selection.contains(&face.key()) && !regions.contains_key(&face.key()) {
+                        regions.insert(face.key(), count);
+                        keys.push(face.key());
+                    }
+                }
+            }
+            count += 1;
+        }
+        // Compute the translation of each vertex in each region.
+        let mut normals = HashMap::<(usize, VertexKey<K>), Vector<VertexPosition<G>>>::new();
+        let mut areas = vec![None; count];
+        for face in self.faces() {
+            if let Some(region) = regions.get(&face.key()) {
+                let normal = face.normal().map_err(|error| error.at_key(face.key()))?;
+                let weighted = normal * face.area();
+                areas[*region] = Some(match areas[*region] {
+                    Some(sum) => sum + weighted,
+                    _ => weighted,
+                });
+                for vertex in face.adjacent_vertices() {
+                    normals
+                        .entry((*region, vertex.key()))
+                        .and_modify(|sum| *sum = *sum + normal)
+                        .or_insert(normal);
+                }
+            }
+        }
+        let areas = areas
+            .into_iter()
+            .map(|normal| normal.and_then(|normal| normal.normalize()))
+            .collect::<Vec<_>>();
+        let mut translations = HashMap::with_capacity(normals.len());
+        for ((region, key), sum) in normals {
+            let direction = match normal {
+                ExtrusionNormal::Region => areas[region],
+                ExtrusionNormal::Vertex => sum.normalize(),
+            };
+            let direction = direction.ok_or_else(|| GraphError::Geometry.at_key(key))?;
+            translations.insert((region, key), direction * offset);
+        }
+        // Copy each vertex that remains in place. Vertices that are only
+        // adjacent to faces of a single region and are not on a boundary are
+        // moved along with the region and are not copied.
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let mut sources = HashMap::with_capacity(self.vertex_count());
+        for vertex in self.vertices() {
+            let mut adjacent = vertex.adjacent_faces().peekable();
+            let is_enclosed = adjacent.peek().is_some()
+                && adjacent.all(|face| regions.contains_key(&face.key()))
+                && !vertex.is_boundary_vertex();
+            if !is_enclosed {
+                let data = vertex.get().clone();
+                sources.insert(vertex.key(), mutation::vertex::insert(&mut mutation, data)?);
+            }
+        }
+        let mut destinations = HashMap::with_capacity(translations.len());
+        for vertex in self.vertices() {
+            let mut adjacent = vertex
+                .adjacent_faces()
+                .filter_map(|face| regions.get(&face.key()).cloned())
+                .collect::<SmallVec<[_; 4]>>();
+            adjacent.sort_unstable();
+            adjacent.dedup();
+            for region in adjacent {
+                let translation = translations[&(region, vertex.key())];
+                let data = vertex
+                    .get()
+                    .clone()
+                    .map_position(|position| *position + translation);
+                let key = mutation::vertex::insert(&mut mutation, data)?;
+                destinations.insert((region, vertex.key()), key);
+            }
+        }
+        let mut extrusions = HashMap::with_capacity(self.face_count());
+        for face in self.faces() {
+            let perimeter = face
+                .adjacent_vertices()
+                .map(|vertex| match regions.get(&face.key()) {
+                    Some(region) => destinations[&(*region, vertex.key())],
+                    _ => sources[&vertex.key()],
+                })
+                .collect::<SmallVec<[_; 4]>>();
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            let data = face.get().clone();
+            let key =
+                mutation::face::insert_with(&mut mutation, cache, || (Default::default(), data))?;
+            extrusions.insert(face.key(), key);
+        }
+        // Each arc `ab` along the boundary of a region is joined to its
+        // extrusion by the quadrilateral `(a, b, b', a')`.
+        for face in self.faces() {
+            if let Some(region) = regions.get(&face.key()) {
+                for arc in face.adjacent_arcs() {
+                    let is_boundary = arc
+                        .opposite_arc()
+                        .face()
+                        .map_or(true, |face| regions.get(&face.key()) != Some(region));
+                    if is_boundary {
+                        let (a, b) = arc.key().into();
+                        let perimeter = [
+                            sources[&a],
+                            sources[&b],
+                            destinations[&(*region, b)],
+                            destinations[&(*region, a)],
+                        ];
+                        let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+                        mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+                    }
+                }
+            }
+        }
+        let graph = mutation.commit().map_err(|(_, error)| error)?;
+        // Vertices that are moved along with a region retain their values in
+        // layers, but copies of vertices that are left in place do not.
+        for ((_, key), destination) in destinations.iter() {
+            sources.entry(*key).or_insert(*destination);
+        }
+        self.replace_with_rebuild(graph, &sources, &extrusions);
+        let mut keys = HashSet::with_capacity(selection.len());
+        Ok(faces
+            .iter()
+            .filter(|key| keys.insert(**key))
+            .map(|key| extrusions[key])
+            .collect())
+    }
+
+    /// Extrudes a path of boundary edges into a strip of quadrilaterals.
+    ///
+    /// The edges must form a connected sequence such that the boundary arc of
+    /// each edge ends where the boundary arc of the next edge begins. Each
+    /// vertex along the path is copied and translated by the given distance
+    /// along the given direction, which is normalized. A quadrilateral is then
+    /// inserted between each boundary arc and its translated copy.
+    /// Consecutive quadrilaterals share the vertices and edges along their
+    /// common side, so the strip is connected. If the path is closed, then so
+    /// is the strip.
+    ///
+    /// Unlike [`Path::extrude_contour_with`], which inserts a single face,
+    /// this inserts a face for each edge in the path. This can be used to
+    /// model fins, walls, and flanges.
+    ///
+    /// Returns the keys of the inserted faces in the order of the path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an edge is not found, an edge is not a boundary
+    /// edge, the edges are not connected in order, the path repeats an edge or
+    /// crosses itself, or if the direction cannot be normalized. Errors
+    /// concerning an edge are wrapped with the key of that edge (see
+    /// [`GraphError::AtKey`]). In this case, the graph is left unmodified.
+    ///
+    /// # Examples
+    ///
+    /// Extruding a wall from the boundary of a square:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::{Point3, Vector3};
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Tetragon;
+    ///
+    /// let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+    ///     vec![Tetragon::new(0usize, 1, 2, 3)],
+    ///     vec![
+    ///         (0.0, 0.0, 0.0),
+    ///         (1.0, 0.0, 0.0),
+    ///         (1.0, 1.0, 0.0),
+    ///         (0.0, 1.0, 0.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// // Get the boundary edges of the square in order.
+    /// let mut path = graph
+    ///     .faces()
+    ///     .nth(0)
+    ///     .unwrap()
+    ///     .adjacent_arcs()
+    ///     .map(|arc| arc.edge().key())
+    ///     .collect::<Vec<_>>();
+    /// path.reverse();
+    /// let faces = graph.extrude_path(&path[..2], Vector3::z(), 1.0).unwrap();
+    ///
+    /// assert_eq!(2, faces.len());
+    /// assert_eq!(3, graph.face_count());
+    /// ```
+    ///
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    /// [`Path::extrude_contour_with`]: crate::graph::Path::extrude_contour_with
+    pub fn extrude_path<T>(
+        &mut self,
+        path: &[EdgeKey<K>],
+        direction: Vector<VertexPosition<G>>,
+        distance: T,
+    ) -> Result<Vec<FaceKey<K>>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let translation = direction.normalize().ok_or(GraphError::Geometry)? * distance.into();
+        let arcs = path
+            .iter()
+            .map(|key| {
+                let arc = self
+                    .edge(*key)
+                    .ok_or_else(|| GraphError::TopologyNotFound(TopologyKind::Edge).at_key(*key))?
+                    .into_arc();
+                if arc.is_boundary_arc() {
+                    Ok((arc.source_vertex().key(), arc.destination_vertex().key()))
+                }
+                else {
+                    let arc = arc.into_opposite_arc();
+                    if arc.is_boundary_arc() {
+                        Ok((arc.source_vertex().key(), arc.destination_vertex().key()))
+                    }
+                    else {
+                        Err(GraphError::TopologyMalformed.at_key(*key))
+                    }
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(index) = arcs.windows(2).position(|arcs| arcs[0].1 != arcs[1].0) {
+            return Err(GraphError::TopologyMalformed.at_key(path[index + 1]));
+        }
+        // The path must not repeat edges or cross itself, because the strip
+        // would then be non-manifold. The path may only return to its first
+        // vertex, in which case it is closed. This is checked before the graph
+        // is mutated so that the graph is left unmodified on error.
+        let mut sources = HashSet::with_capacity(arcs.len());
+        if let Some(index) = arcs.iter().position(|(a, _)| !sources.insert(*a)) {
+            return Err(GraphError::TopologyConflict.at_key(path[index]));
+        }
+        if let Some((a, b)) = arcs.first().zip(arcs.last()) {
+            if a.0 != b.1 && sources.contains(&b.1) {
+                return Err(GraphError::TopologyConflict.at_key(path[arcs.len() - 1]));
+            }
+        }
+        // Translate each vertex along the path once, so that consecutive faces
+        // share the translated vertices.
+        let mut translations = HashMap::with_capacity(arcs.len() + 1);
+        for (a, b) in arcs.iter() {
+            for key in [*a, *b] {
+                translations.entry(key).or_insert_with(|| {
+                    let vertex = self.vertex(key).expect_consistent();
+                    vertex
+                        .get()
+                        .clone()
+                        .map_position(|position| *position + translation)
+                });
+            }
+        }
+        Mutation::take(self)
+            .bypass_or_commit_with(|mutation| {
+                let translations = translations
+                    .into_iter()
+                    .map(|(key, data)| {
+                        Ok((key, mutation::vertex::insert(mutation.as_mut(), data)?))
+                    })
+                    .collect::<Result<HashMap<_, _>, GraphError>>()?;
+                arcs.iter()
+                    .map(|(a, b)| {
+                        let perimeter = [*a, *b, translations[b], translations[a]];
+                        let cache = FaceInsertCache::from_storage(mutation.as_mut(), &perimeter)?;
+                        mutation::face::insert_with(mutation.as_mut(), cache, Default::default)
+                    })
+                    .collect::<Result<Vec<_>, GraphError>>()
+            })
+            .map(|(_, keys)| keys)
+            .map_err(|(_, error)| error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::{Point3, Vector3};
+    use std::collections::HashSet;
+
+    use crate::graph::{ExtrusionNormal, GraphError, MeshGraph, TopologyKind};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::NGon;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn thicken() {
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 4, 3]), NGon([1, 2, 5, 4])],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (2.0, 0.0, 0.0),
+                (0.0, 1.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (2.0, 1.0, 0.0),
+            ],
+        )
+        .unwrap();
+        let graph = graph.thicken(0.5).unwrap();
+
+        assert_eq!(12, graph.vertex_count());
+        // Two faces for each surface and one face for each boundary arc.
+        assert_eq!(10, graph.face_count());
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+        for vertex in graph.vertices() {
+            assert!((vertex.position().z.abs() - 0.25).abs() < 1e-9);
+        }
+        // The shell is consistently wound outward.
+        assert!((graph.volume().unwrap() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn extrude_faces() {
+        let build = || {
+            Cube::new()
+                .polygons::<Position<E3>>()
+                .collect::<MeshGraph<Point3<f64>>>()
+        };
+        let cube = build();
+        let face = cube.faces().nth(0).unwrap();
+        let adjacent = face.adjacent_faces().nth(0).unwrap().key();
+        let opposite = cube
+            .faces()
+            .find(|other| {
+                other.key() != face.key()
+                    && other
+                        .adjacent_faces()
+                        .all(|other| other.key() != face.key())
+            })
+            .unwrap()
+            .key();
+        let face = face.key();
+
+        // Adjacent faces are extruded as a single region with walls only
+        // along the boundary of the region.
+        let mut graph = build();
+        let keys = graph.extrude_faces(&[face, adjacent], 1.0).unwrap();
+        assert_eq!(2, keys.len());
+        assert_eq!(14, graph.vertex_count());
+        assert_eq!(12, graph.face_count());
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+        let positions = keys
+            .iter()
+            .flat_map(|key| graph.face(*key).unwrap().into_adjacent_vertices())
+            .map(|vertex| vertex.position().map(R64::from))
+            .collect::<HashSet<_>>();
+        assert_eq!(6, positions.len());
+
+        // Non-adjacent faces are extruded as distinct regions.
+        let mut graph = build();
+        graph.extrude_faces(&[face, opposite], 1.0).unwrap();
+        assert_eq!(16, graph.vertex_count());
+        assert_eq!(14, graph.face_count());
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+        assert!((graph.volume().unwrap() - 3.0).abs() < 1e-9);
+
+        // Vertices are offset along their normals within the region.
+        let mut graph = build();
+        graph
+            .extrude_faces_with(&[face, adjacent], 1.0, ExtrusionNormal::Vertex)
+            .unwrap();
+        assert_eq!(12, graph.face_count());
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+
+        let mut graph = build();
+        graph.face_mut(face).unwrap().remove();
+        assert_eq!(
+            GraphError::TopologyNotFound(TopologyKind::Face).at_key(face),
+            graph.extrude_faces(&[face], 1.0).err().unwrap()
+        );
+    }
+
+    #[test]
+    fn extrude_path() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.faces().nth(0).unwrap().key();
+        graph.face_mut(key).unwrap().remove();
+        // Get the boundary loop left by the removed face in order.
+        let mut path = vec![];
+        let mut arc = graph.arcs().find(|arc| arc.is_boundary_arc()).unwrap();
+        for _ in 0..4 {
+            path.push(arc.edge().key());
+            arc = arc.into_next_arc();
+        }
+        let interior = graph
+            .edges()
+            .find(|edge| !edge.is_boundary_edge())
+            .unwrap()
+            .key();
+
+        // Edges that are not boundary edges or that are not connected cannot
+        // be extruded.
+        // Errors refer to the offending edge.
+        assert_eq!(
+            Err(GraphError::TopologyMalformed.at_key(interior)),
+            graph.extrude_path(&[path[0], interior], Vector3::z(), 1.0),
+        );
+        assert_eq!(
+            Err(GraphError::TopologyMalformed.at_key(path[2])),
+            graph.extrude_path(&[path[0], path[2]], Vector3::z(), 1.0),
+        );
+        assert_eq!(
+            Err(GraphError::Geometry),
+            graph.extrude_path(&path, Vector3::zeros(), 1.0),
+        );
+        // Paths cannot repeat edges. The graph is left unmodified.
+        let repeated = [path[0], path[1], path[2], path[3], path[0]];
+        assert_eq!(
+            Err(GraphError::TopologyConflict.at_key(path[0])),
+            graph.extrude_path(&repeated, Vector3::z(), 1.0),
+        );
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(12, graph.edge_count());
+        assert_eq!(5, graph.face_count());
+
+        // Extrude the closed loop into a closed strip.
+        let positions = path
+            .iter()
+            .map(|key| {
+                *graph
+                    .edge(*key)
+                    .unwrap()
+                    .into_arc()
+                    .source_vertex()
+                    .position()
+            })
+            .collect::<Vec<_>>();
+        let faces = graph.extrude_path(&path, Vector3::x(), 2.0).unwrap();
+        assert_eq!(4, faces.len());
+        assert_eq!(12, graph.vertex_count());
+        assert_eq!(20, graph.edge_count());
+        assert_eq!(9, graph.face_count());
+        for key in faces {
+            assert_eq!(4, graph.face(key).unwrap().arity());
+        }
+        // The boundary moves to the translated loop.
+        let boundary = graph
+            .arcs()
+            .filter(|arc| arc.is_boundary_arc())
+            .map(|arc| *arc.source_vertex().position())
+            .collect::<Vec<_>>();
+        assert_eq!(4, boundary.len());
+        for position in positions {
+            let translated = position + Vector3::new(2.0, 0.0, 0.0);
+            assert!(boundary.contains(&translated));
+        }
+    }
+}
@@ -1,4 +1,6 @@
+use approx::abs_diff_eq;
 use derivative::Derivative;
+use num::{One, Zero};
 use smallvec::SmallVec;
 use std::borrow::Borrow;
 use std::cmp;
@@ -6,21 +8,21 @@ use std::collections::HashSet;
 use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
 use theon::query::{Intersection, Line, Plane};
-use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
 use theon::{AsPosition, AsPositionMut};
 use typenum::U3;
 
 use crate::entity::borrow::{Reborrow, ReborrowInto, ReborrowMut};
 use crate::entity::storage::prelude::*;
 use crate::entity::storage::{
-    AsStorage, AsStorageMut, AsStorageOf, HashStorage, IncrementalKeyer, Key,
+    AsStorage, AsStorageMut, AsStorageOf, HashStorage, IncrementalKeyer, Key, KeyIndex,
 };
 use crate::entity::traverse::{Adjacency, Breadth, Depth, Trace, TraceFirst, Traversal};
 use crate::entity::view::{Bind, ClosedView, Orphan, Rebind, Unbind, View};
 use crate::entity::{Entity, Payload};
-use crate::graph::data::{Data, GraphData, Parametric};
-use crate::graph::edge::{Arc, ArcKey, ArcOrphan, ArcView, Edge};
-use crate::graph::geometry::{FaceCentroid, FaceNormal, FacePlane, VertexPosition};
+use crate::graph::data::{Data, GraphData, Index, Parametric};
+use crate::graph::edge::{Arc, ArcKey, ArcOrphan, ArcView, Edge, EdgeKey};
+use crate::graph::geometry::{self, FaceCentroid, FaceNormal, FacePlane, VertexPosition};
 use crate::graph::mutation::face::{
     self, FaceBridgeCache, FaceExtrudeCache, FaceInsertCache, FacePokeCache, FaceRemoveCache,
     FaceSplitCache,
@@ -30,7 +32,7 @@ use crate::graph::path::Path;
 use crate::graph::vertex::{Vertex, VertexKey, VertexOrphan, VertexView};
 use crate::graph::{
     Circulator, GraphError, MeshGraph, OptionExt as _, OrphanCirculator, ResultExt as _, Selector,
-    ViewCirculator,
+    TopologyKind, ViewCirculator,
 };
 use crate::transact::{BypassOrCommit, Mutate};
 use crate::{DynamicArity, IteratorExt as _, StaticArity};
@@ -40,7 +42,7 @@ type Mutation<M> = mutation::Mutation<Immediate<M>>;
 pub trait ToRing<B>: DynamicArity<Dynamic = usize> + Sized
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<Data<B>>> + Consistent + Parametric,
+    B::Target: AsStorage<Arc<Data<B>, Index<B>>> + Consistent + Parametric,
 {
     fn into_ring(self) -> Ring<B>;
 
@@ -50,39 +52,44 @@ where
 /// Face entity.
 #[derive(Derivative)]
 #[derivative(Debug, Hash)]
-pub struct Face<G>
+pub struct Face<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     /// User data.
     #[derivative(Debug = "ignore", Hash = "ignore")]
     pub(crate) data: G::Face,
     /// Required key into the leading arc.
-    pub(crate) arc: ArcKey,
+    pub(crate) arc: ArcKey<K>,
 }
 
-impl<G> Face<G>
+impl<G, K> Face<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    pub fn new(arc: ArcKey, data: G::Face) -> Self {
+    pub fn new(arc: ArcKey<K>, data: G::Face) -> Self {
         Face { data, arc }
     }
 }
 
-impl<G> Entity for Face<G>
+impl<G, K> Entity for Face<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    type Key = FaceKey;
-    type Storage = HashStorage<Self, IncrementalKeyer>;
+    type Key = FaceKey<K>;
+    type Storage = HashStorage<Self, IncrementalKeyer<K>>;
 }
 
-impl<G> Payload for Face<G>
+impl<G, K> Payload for Face<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     type Data = G::Face;
+    type Links = ArcKey<K>;
 
     fn get(&self) -> &Self::Data {
         &self.data
@@ -91,14 +98,21 @@ where
     fn get_mut(&mut self) -> &mut Self::Data {
         &mut self.data
     }
+
+    fn links(&self) -> Self::Links {
+        self.arc
+    }
 }
 
 /// Face key.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
-pub struct FaceKey(u64);
+pub struct FaceKey<K = u64>(K);
 
-impl Key for FaceKey {
-    type Inner = u64;
+impl<K> Key for FaceKey<K>
+where
+    K: KeyIndex,
+{
+    type Inner = K;
 
     fn from_inner(key: Self::Inner) -> Self {
         FaceKey(key)
@@ -124,15 +138,15 @@ impl Key for FaceKey {
 pub struct FaceView<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Face<Data<B>>> + Parametric,
+    B::Target: AsStorage<Face<Data<B>, Index<B>>> + Parametric,
 {
-    inner: View<B, Face<Data<B>>>,
+    inner: View<B, Face<Data<B>, Index<B>>>,
 }
 
 impl<B, M> FaceView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Face<Data<B>>> + Parametric,
+    M: AsStorage<Face<Data<B>, Index<B>>> + Parametric,
 {
     pub fn to_ref(&self) -> FaceView<&M> {
         self.inner.to_ref().into()
@@ -142,7 +156,7 @@ where
 impl<B, M> FaceView<B>
 where
     B: ReborrowMut<Target = M>,
-    M: AsStorage<Face<Data<B>>> + Parametric,
+    M: AsStorage<Face<Data<B>, Index<B>>> + Parametric,
 {
     #[allow(clippy::wrong_self_convention)]
     fn to_mut_unchecked(&mut self) -> FaceView<&mut M> {
@@ -150,11 +164,12 @@ where
     }
 }
 
-impl<'a, B, M, G> FaceView<B>
+impl<'a, B, M, G, K> FaceView<B>
 where
     B: ReborrowInto<'a, Target = M>,
-    M: AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     // TODO: Relocate this documentation of `into_ref`.
     /// # Examples
@@ -191,11 +206,12 @@ where
     }
 }
 
-impl<B, M, G> FaceView<B>
+impl<B, M, G, K> FaceView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn get<'a>(&'a self) -> &'a G::Face
     where
@@ -205,11 +221,12 @@ where
     }
 }
 
-impl<B, M, G> FaceView<B>
+impl<B, M, G, K> FaceView<B>
 where
     B: ReborrowMut<Target = M>,
-    M: AsStorageMut<Face<G>> + Parametric<Data = G>,
+    M: AsStorageMut<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn get_mut<'a>(&'a mut self) -> &'a mut G::Face
     where
@@ -220,11 +237,12 @@ where
 }
 
 /// Reachable API.
-impl<B, M, G> FaceView<B>
+impl<B, M, G, K> FaceView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub(in crate::graph) fn into_reachable_arc(self) -> Option<ArcView<B>> {
         let key = self.arc;
@@ -232,11 +250,12 @@ where
     }
 }
 
-impl<B, M, G> FaceView<B>
+impl<B, M, G, K> FaceView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Face<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Face<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Converts the face into its ring.
     pub fn into_ring(self) -> Ring<B> {
@@ -259,15 +278,16 @@ where
     }
 }
 
-impl<B, M, G> FaceView<B>
+impl<B, M, G, K> FaceView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>>
-        + AsStorage<Face<G>>
-        + AsStorage<Vertex<G>>
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Face<G, K>>
+        + AsStorage<Vertex<G, K>>
         + Consistent
-        + Parametric<Data = G>,
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn centroid(&self) -> VertexPosition<G>
     where
@@ -277,6 +297,38 @@ where
         G::centroid(self.to_ref()).expect_consistent()
     }
 
+    /// Gets the area of the face.
+    ///
+    /// The area is half of the magnitude of the vector area of the face, which
+    /// is its Newell normal in three dimensions. The area is exact for planar
+    /// faces, including concave faces.
+    pub fn area(&self) -> Scalar<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let positions = self
+            .adjacent_vertices()
+            .map(|vertex| *vertex.position())
+            .collect::<SmallVec<[_; 4]>>();
+        geometry::polygon_area(&positions)
+    }
+
+    /// Gets the length of the perimeter of the face.
+    ///
+    /// This is the sum of the lengths of the arcs of the face.
+    pub fn perimeter_length(&self) -> Scalar<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.adjacent_vertices()
+            .map(|vertex| *vertex.position())
+            .perimeter()
+            .map(|(a, b)| (b - a).magnitude())
+            .fold(Zero::zero(), |length, arc| length + arc)
+    }
+
     pub fn normal(&self) -> Result<Vector<VertexPosition<G>>, GraphError>
     where
         G: FaceNormal,
@@ -293,17 +345,84 @@ where
     {
         G::plane(self.to_ref())
     }
+
+    /// Gets the position at the given barycentric coordinates of the face.
+    ///
+    /// Each coordinate weights the position of the vertex with the same index
+    /// in the face's ring. For triangles, these are barycentric coordinates.
+    /// For faces with a higher arity, these are generalized coordinates and
+    /// the position is an affine combination of the positions of its vertices.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of coordinates differs from the arity of
+    /// the face or if the coordinates do not sum to one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2)],
+    ///     vec![(0.0, 0.0), (2.0, 0.0), (0.0, 2.0)],
+    /// )
+    /// .unwrap();
+    /// let face = graph.faces().nth(0).unwrap();
+    /// let position = face.point_at(&[0.5, 0.25, 0.25]).unwrap();
+    /// ```
+    pub fn point_at<T>(&self, coordinates: &[T]) -> Result<VertexPosition<G>, GraphError>
+    where
+        T: Copy + Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let arity = self.arity();
+        if coordinates.len() != arity {
+            return Err(GraphError::ArityConflict {
+                expected: arity,
+                actual: coordinates.len(),
+            });
+        }
+        let mut weights = coordinates.iter().map(|weight| (*weight).into());
+        let sum = weights
+            .clone()
+            .fold(Scalar::<VertexPosition<G>>::zero(), |sum, weight| {
+                sum + weight
+            });
+        if !abs_diff_eq!(sum, Scalar::<VertexPosition<G>>::one()) {
+            return Err(GraphError::Geometry);
+        }
+        let mut positions = self
+            .adjacent_vertices()
+            .map(|vertex| vertex.position().into_coordinates());
+        // The arity of a face is always three or higher, so there is at least
+        // one position and weight.
+        let first = positions.next().expect_consistent() * weights.next().expect_consistent();
+        Ok(VertexPosition::<G>::from_coordinates(
+            positions
+                .zip(weights)
+                .fold(first, |sum, (position, weight)| sum + (position * weight)),
+        ))
+    }
 }
 
-impl<B, M, G> FaceView<B>
+impl<B, M, G, K> FaceView<B>
 where
     B: ReborrowMut<Target = M>,
-    M: AsStorage<Arc<G>>
-        + AsStorage<Face<G>>
-        + AsStorageMut<Vertex<G>>
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Face<G, K>>
+        + AsStorageMut<Vertex<G, K>>
         + Consistent
-        + Parametric<Data = G>,
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Flattens the face by translating the positions of all vertices into a
     /// best-fit plane.
@@ -340,63 +459,85 @@ where
     }
 }
 
-impl<'a, B, M, G> FaceView<B>
+impl<'a, B, M, G, K> FaceView<B>
 where
     B: ReborrowInto<'a, Target = M>,
-    M: 'a + AsStorage<Arc<G>> + AsStorage<Face<G>> + Consistent + Parametric<Data = G>,
+    M: 'a
+        + AsStorage<Arc<G, K>>
+        + AsStorage<Face<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn into_adjacent_arcs(self) -> impl Clone + Iterator<Item = ArcView<&'a M>> {
         self.into_ref().into_ring().into_arcs()
     }
 
+    pub fn into_adjacent_arcs_reversed(self) -> impl Clone + Iterator<Item = ArcView<&'a M>> {
+        self.into_ref().into_ring().into_arcs_reversed()
+    }
+
     pub fn into_adjacent_faces(self) -> impl Clone + Iterator<Item = FaceView<&'a M>> {
         FaceCirculator::from(ArcCirculator::from(self.into_ref().into_ring()))
     }
 }
 
-impl<B, G> FaceView<B>
+impl<B, G, K> FaceView<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<G>> + AsStorage<Face<G>> + Consistent + Parametric<Data = G>,
+    B::Target:
+        AsStorage<Arc<G, K>> + AsStorage<Face<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Gets an iterator of views over the arcs in the face's ring.
     pub fn adjacent_arcs(&self) -> impl Clone + Iterator<Item = ArcView<&B::Target>> {
         self.to_ref().into_adjacent_arcs()
     }
 
+    /// Gets an iterator of views over the arcs in the face's ring in reverse.
+    ///
+    /// See [`Ring::arcs_reversed`].
+    ///
+    /// [`Ring::arcs_reversed`]: crate::graph::Ring::arcs_reversed
+    pub fn adjacent_arcs_reversed(&self) -> impl Clone + Iterator<Item = ArcView<&B::Target>> {
+        self.to_ref().into_adjacent_arcs_reversed()
+    }
+
     /// Gets an iterator of views over adjacent faces.
     pub fn adjacent_faces(&self) -> impl Clone + Iterator<Item = FaceView<&B::Target>> {
         self.to_ref().into_adjacent_faces()
     }
 }
 
-impl<'a, B, M, G> FaceView<B>
+impl<'a, B, M, G, K> FaceView<B>
 where
     B: ReborrowInto<'a, Target = M>,
     M: 'a
-        + AsStorage<Arc<G>>
-        + AsStorage<Face<G>>
-        + AsStorage<Vertex<G>>
+        + AsStorage<Arc<G, K>>
+        + AsStorage<Face<G, K>>
+        + AsStorage<Vertex<G, K>>
         + Consistent
-        + Parametric<Data = G>,
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn into_adjacent_vertices(self) -> impl Clone + Iterator<Item = VertexView<&'a M>> {
         self.into_ref().into_ring().into_vertices()
     }
 }
 
-impl<B, G> FaceView<B>
+impl<B, G, K> FaceView<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<G>>
-        + AsStorage<Face<G>>
-        + AsStorage<Vertex<G>>
+    B::Target: AsStorage<Arc<G, K>>
+        + AsStorage<Face<G, K>>
+        + AsStorage<Vertex<G, K>>
         + Consistent
-        + Parametric<Data = G>,
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Gets an iterator of views over the vertices that form the face.
     pub fn adjacent_vertices(&self) -> impl Clone + Iterator<Item = VertexView<&B::Target>> {
@@ -404,11 +545,66 @@ where
     }
 }
 
+impl<B, M, G, K> FaceView<B>
+where
+    B: Reborrow<Target = M>,
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Edge<G, K>>
+        + AsStorage<Face<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Returns `true` if the face shares an edge with the given face.
+    ///
+    /// Faces that only share vertices are not adjacent.
+    pub fn is_adjacent_to(&self, other: FaceKey<K>) -> bool {
+        self.adjacent_arcs()
+            .any(|arc| arc.opposite_arc().face().map(|face| face.key()) == Some(other))
+    }
+
+    /// Gets the edges shared by the face and the given face.
+    ///
+    /// Faces may share more than one edge, such as after merging faces.
+    /// Edges are ordered by the face's ring. If the given face does not
+    /// exist, then the returned collection is empty.
+    pub fn shared_edges(&self, other: FaceKey<K>) -> Vec<EdgeKey<K>> {
+        self.adjacent_arcs()
+            .filter(|arc| arc.opposite_arc().face().map(|face| face.key()) == Some(other))
+            .map(|arc| arc.edge().key())
+            .collect()
+    }
+
+    /// Gets the vertices shared by the face and the given face.
+    ///
+    /// Faces may share vertices without sharing any edges. Vertices are
+    /// ordered by the face's ring. If the given face does not exist, then the
+    /// returned collection is empty.
+    pub fn shared_vertices(&self, other: FaceKey<K>) -> Vec<VertexKey<K>> {
+        let face: Option<FaceView<&M>> = self.to_ref().rebind(other);
+        face.map(|face| {
+            let keys = face.adjacent_vertices().keys().collect::<HashSet<_>>();
+            self.adjacent_vertices()
+                .keys()
+                .filter(|key| keys.contains(key))
+                .collect()
+        })
+        .unwrap_or_default()
+    }
+}
+
 impl<'a, M> FaceView<&'a mut M>
 where
-    M: AsStorageMut<Arc<M::Data>> + AsStorage<Face<M::Data>> + Consistent + Parametric,
+    M: AsStorageMut<Arc<M::Data, M::Index>>
+        + AsStorage<Face<M::Data, M::Index>>
+        + Consistent
+        + Parametric,
 {
-    pub fn into_adjacent_arc_orphans(self) -> impl Iterator<Item = ArcOrphan<'a, M::Data>> {
+    pub fn into_adjacent_arc_orphans(
+        self,
+    ) -> impl Iterator<Item = ArcOrphan<'a, M::Data, M::Index>> {
         self.into_ring().into_arc_orphans()
     }
 }
@@ -416,19 +612,27 @@ where
 impl<B> FaceView<B>
 where
     B: ReborrowMut,
-    B::Target: AsStorageMut<Arc<Data<B>>> + AsStorage<Face<Data<B>>> + Consistent + Parametric,
+    B::Target: AsStorageMut<Arc<Data<B>, Index<B>>>
+        + AsStorage<Face<Data<B>, Index<B>>>
+        + Consistent
+        + Parametric,
 {
     /// Gets an iterator of orphan views over the arcs in the face's ring.
-    pub fn adjacent_arc_orphans(&mut self) -> impl Iterator<Item = ArcOrphan<Data<B>>> {
+    pub fn adjacent_arc_orphans(&mut self) -> impl Iterator<Item = ArcOrphan<Data<B>, Index<B>>> {
         self.to_mut_unchecked().into_adjacent_arc_orphans()
     }
 }
 
 impl<'a, M> FaceView<&'a mut M>
 where
-    M: AsStorage<Arc<M::Data>> + AsStorageMut<Face<M::Data>> + Consistent + Parametric,
+    M: AsStorage<Arc<M::Data, M::Index>>
+        + AsStorageMut<Face<M::Data, M::Index>>
+        + Consistent
+        + Parametric,
 {
-    pub fn into_adjacent_face_orphans(self) -> impl Iterator<Item = FaceOrphan<'a, M::Data>> {
+    pub fn into_adjacent_face_orphans(
+        self,
+    ) -> impl Iterator<Item = FaceOrphan<'a, M::Data, M::Index>> {
         FaceCirculator::from(ArcCirculator::from(self.into_ring()))
     }
 }
@@ -436,23 +640,28 @@ where
 impl<B> FaceView<B>
 where
     B: ReborrowMut,
-    B::Target: AsStorage<Arc<Data<B>>> + AsStorageMut<Face<Data<B>>> + Consistent + Parametric,
+    B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+        + AsStorageMut<Face<Data<B>, Index<B>>>
+        + Consistent
+        + Parametric,
 {
     /// Gets an iterator of orphan views over adjacent faces.
-    pub fn adjacent_face_orphans(&mut self) -> impl Iterator<Item = FaceOrphan<Data<B>>> {
+    pub fn adjacent_face_orphans(&mut self) -> impl Iterator<Item = FaceOrphan<Data<B>, Index<B>>> {
         self.to_mut_unchecked().into_adjacent_face_orphans()
     }
 }
 
 impl<'a, M> FaceView<&'a mut M>
 where
-    M: AsStorage<Arc<Data<M>>>
-        + AsStorage<Face<Data<M>>>
-        + AsStorageMut<Vertex<Data<M>>>
+    M: AsStorage<Arc<Data<M>, Index<M>>>
+        + AsStorage<Face<Data<M>, Index<M>>>
+        + AsStorageMut<Vertex<Data<M>, Index<M>>>
         + Consistent
         + Parametric,
 {
-    pub fn into_adjacent_vertex_orphans(self) -> impl Iterator<Item = VertexOrphan<'a, Data<M>>> {
+    pub fn into_adjacent_vertex_orphans(
+        self,
+    ) -> impl Iterator<Item = VertexOrphan<'a, Data<M>, Index<M>>> {
         VertexCirculator::from(ArcCirculator::from(self.into_ring()))
     }
 }
@@ -460,23 +669,27 @@ where
 impl<B> FaceView<B>
 where
     B: ReborrowMut,
-    B::Target: AsStorage<Arc<Data<B>>>
-        + AsStorage<Face<Data<B>>>
-        + AsStorageMut<Vertex<Data<B>>>
+    B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+        + AsStorage<Face<Data<B>, Index<B>>>
+        + AsStorageMut<Vertex<Data<B>, Index<B>>>
         + Consistent
         + Parametric,
 {
     /// Gets an iterator of orphan views over the vertices that form the face.
-    pub fn adjacent_vertex_orphans(&mut self) -> impl Iterator<Item = VertexOrphan<Data<B>>> {
+    pub fn adjacent_vertex_orphans(
+        &mut self,
+    ) -> impl Iterator<Item = VertexOrphan<Data<B>, Index<B>>> {
         self.to_mut_unchecked().into_adjacent_vertex_orphans()
     }
 }
 
-impl<B, G> FaceView<B>
+impl<B, G, K> FaceView<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<G>> + AsStorage<Face<G>> + Consistent + Parametric<Data = G>,
+    B::Target:
+        AsStorage<Arc<G, K>> + AsStorage<Face<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Gets an iterator that traverses adjacent faces by breadth.
     ///
@@ -497,15 +710,16 @@ where
     }
 }
 
-impl<'a, M, G> FaceView<&'a mut M>
+impl<'a, M, G, K> FaceView<&'a mut M>
 where
-    M: AsStorage<Arc<G>>
-        + AsStorage<Edge<G>>
-        + AsStorage<Face<G>>
-        + AsStorage<Vertex<G>>
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Edge<G, K>>
+        + AsStorage<Face<G, K>>
+        + AsStorage<Vertex<G, K>>
         + Default
-        + Mutable<Data = G>,
+        + Mutable<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Splits the face by bisecting it with a composite edge inserted between
     /// two non-adjacent vertices within the face's perimeter.
@@ -522,8 +736,9 @@ where
     /// # Errors
     ///
     /// Returns an error if either of the given vertices cannot be found, are
-    /// not within the face's perimeter, or the distance between the vertices
-    /// along the ring is less than two.
+    /// not within the face's perimeter, the distance between the vertices
+    /// along the ring is less than two, or if the keyspace of the graph is
+    /// exhausted.
     ///
     /// # Examples
     ///
@@ -553,24 +768,25 @@ where
     /// ```
     pub fn split(
         self,
-        source: impl Into<Selector<VertexKey>>,
-        destination: impl Into<Selector<VertexKey>>,
+        source: impl Into<Selector<VertexKey<K>>>,
+        destination: impl Into<Selector<VertexKey<K>>>,
     ) -> Result<ArcView<&'a mut M>, GraphError> {
         let key_at_index = |index| {
             self.adjacent_vertices()
                 .nth(index)
-                .ok_or(GraphError::TopologyNotFound)
+                .ok_or(GraphError::TopologyNotFound(TopologyKind::Vertex))
                 .map(|vertex| vertex.key())
         };
         let source = source.into().key_or_else(key_at_index)?;
         let destination = destination.into().key_or_else(key_at_index)?;
         let cache = FaceSplitCache::from_face(self.to_ref(), source, destination)?;
         let (storage, _) = self.unbind();
-        Ok(Mutation::take(storage)
+        // Splitting inserts an edge and replaces the face with two faces.
+        mutation::check_keyspace::<M, G, K>(&*storage, 0, 1, 2)?;
+        Mutation::take(storage)
             .bypass_or_commit_with(|mutation| face::split(mutation, cache))
             .map(|(storage, arc)| Bind::bind(storage, arc).expect_consistent())
             .map_err(|(_, error)| error)
-            .expect_consistent())
     }
 
     /// Merges the face into an adjacent face over a shared edge.
@@ -619,11 +835,11 @@ where
     ///     .unwrap()
     ///     .into_ref();
     /// ```
-    pub fn merge(self, destination: impl Into<Selector<FaceKey>>) -> Result<Self, GraphError> {
+    pub fn merge(self, destination: impl Into<Selector<FaceKey<K>>>) -> Result<Self, GraphError> {
         let destination = destination.into().key_or_else(|index| {
             self.adjacent_faces()
                 .nth(index)
-                .ok_or(GraphError::TopologyNotFound)
+                .ok_or(GraphError::TopologyNotFound(TopologyKind::Face))
                 .map(|face| face.key())
         })?;
         let ab = self
@@ -633,7 +849,7 @@ where
                 _ => false,
             })
             .map(|arc| arc.key())
-            .ok_or(GraphError::TopologyNotFound)?;
+            .ok_or(GraphError::TopologyNotFound(TopologyKind::Arc))?;
         // TODO: `Clone` should not be needed here. Consolidate this using the
         //       mutation API and move the necessary face data instead of
         //       cloning it.
@@ -657,15 +873,24 @@ where
     /// # Errors
     ///
     /// Returns an error if the destination face cannot be found or the arity of
-    /// the face and its destination are not the same.
-    pub fn bridge(self, destination: FaceKey) -> Result<(), GraphError> {
+    /// the face and its destination are not the same. Returns
+    /// [`GraphError::KeyspaceExhausted`] if the keyspace of the graph cannot
+    /// accommodate the inserted edges and faces (see [`KeyIndex`]). In these
+    /// cases, the graph is not modified.
+    ///
+    /// [`GraphError::KeyspaceExhausted`]: crate::graph::GraphError::KeyspaceExhausted
+    /// [`KeyIndex`]: crate::graph::KeyIndex
+    pub fn bridge(self, destination: FaceKey<K>) -> Result<(), GraphError> {
         let cache = FaceBridgeCache::from_face(self.to_ref(), destination)?;
+        let arity = self.arity();
         let (storage, _) = self.unbind();
+        // Bridging inserts an edge between each pair of vertices and a
+        // connecting face for each pair of arcs.
+        mutation::check_keyspace::<M, G, K>(&*storage, 0, arity, arity)?;
         Mutation::take(storage)
             .bypass_or_commit_with(|mutation| face::bridge(mutation, cache))
+            .map(|_| ())
             .map_err(|(_, error)| error)
-            .expect_consistent();
-        Ok(())
     }
 
     /// Decomposes the face into triangles. Does nothing if the face is
@@ -699,6 +924,11 @@ where
     ///
     /// Returns the inserted vertex.
     ///
+    /// # Errors
+    ///
+    /// Returns an error if the keyspace of the graph is exhausted (see
+    /// [`KeyIndex`]).
+    ///
     /// # Examples
     ///
     /// Forming a pyramid from a triangular face:
@@ -723,27 +953,38 @@ where
     ///
     /// // See also `poke_with_offset`, which provides this functionality.
     /// let position = face.centroid() + face.normal().unwrap();
-    /// face.poke_with(move || position);
+    /// face.poke_with(move || position).unwrap();
     /// ```
-    pub fn poke_with<F>(self, f: F) -> VertexView<&'a mut M>
+    ///
+    /// [`KeyIndex`]: crate::graph::KeyIndex
+    pub fn poke_with<F>(self, f: F) -> Result<VertexView<&'a mut M>, GraphError>
     where
         F: FnOnce() -> G::Vertex,
     {
         // This should never fail here.
         let cache = FacePokeCache::from_face(self.to_ref()).expect_consistent();
+        let arity = self.arity();
         let (storage, _) = self.unbind();
+        // Poking inserts a vertex and an edge and a triangle for each arc.
+        mutation::check_keyspace::<M, G, K>(&*storage, 1, arity, arity)?;
         Mutation::take(storage)
             .bypass_or_commit_with(|mutation| face::poke_with(mutation, cache, f))
             .map(|(storage, vertex)| Bind::bind(storage, vertex).expect_consistent())
             .map_err(|(_, error)| error)
-            .expect_consistent()
     }
 
     /// Subdivides the face about its centroid. A triangle fan is formed from
     /// each arc in the face's perimeter and a vertex inserted at the centroid.
     ///
     /// Returns the inserted vertex.
-    pub fn poke_at_centroid(self) -> VertexView<&'a mut M>
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the keyspace of the graph is exhausted (see
+    /// [`KeyIndex`]).
+    ///
+    /// [`KeyIndex`]: crate::graph::KeyIndex
+    pub fn poke_at_centroid(self) -> Result<VertexView<&'a mut M>, GraphError>
     where
         G: FaceCentroid,
         G::Vertex: AsPositionMut,
@@ -765,7 +1006,8 @@ where
     ///
     /// # Errors
     ///
-    /// Returns an error if the geometry could not be computed.
+    /// Returns an error if the geometry could not be computed or if the
+    /// keyspace of the graph is exhausted.
     ///
     /// # Examples
     ///
@@ -800,10 +1042,72 @@ where
     {
         let mut data = self.arc().source_vertex().get().clone();
         let position = self.centroid() + (self.normal()? * offset.into());
-        Ok(self.poke_with(move || {
+        self.poke_with(move || {
             *data.as_position_mut() = position;
             data
-        }))
+        })
+    }
+
+    /// Insets the face toward its centroid.
+    ///
+    /// A copy of the face is shrunk toward its centroid by the given factor and
+    /// is joined to the perimeter of the original face by quadrilaterals. A
+    /// factor of zero does not move vertices (leaving degenerate
+    /// quadrilaterals) and factors nearer to one produce smaller faces. This
+    /// is typically followed by an extrusion of the inset face.
+    ///
+    /// Returns the inset face.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the factor is not in the interval $[0,1)$ or if the
+    /// keyspace of the graph is exhausted. A factor of one would collapse the
+    /// inset face into a point; see [`poke_at_centroid`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// graph
+    ///     .face_mut(key)
+    ///     .unwrap()
+    ///     .inset(0.25)
+    ///     .unwrap()
+    ///     .extrude_with_offset(-0.25)
+    ///     .unwrap();
+    /// ```
+    ///
+    /// [`poke_at_centroid`]: crate::graph::FaceView::poke_at_centroid
+    pub fn inset<T>(self, factor: T) -> Result<Self, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: FaceCentroid,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let factor = factor.into();
+        if factor < Zero::zero() || factor >= One::one() {
+            return Err(GraphError::Geometry);
+        }
+        let centroid = self.centroid();
+        self.extrude_with(|data| {
+            data.clone()
+                .map_position(|position| *position + ((centroid - *position) * factor))
+        })
     }
 
     /// Extrudes the face along its normal.
@@ -812,7 +1116,8 @@ where
     ///
     /// # Errors
     ///
-    /// Returns an error if the geometry could not be computed.
+    /// Returns an error if the geometry could not be computed or if the
+    /// keyspace of the graph is exhausted.
     pub fn extrude_with_offset<T>(self, offset: T) -> Result<Self, GraphError>
     where
         T: Into<Scalar<VertexPosition<G>>>,
@@ -821,14 +1126,23 @@ where
         VertexPosition<G>: EuclideanSpace,
     {
         let translation = self.normal()? * offset.into();
-        Ok(self.extrude_with_translation(translation))
+        self.extrude_with_translation(translation)
     }
 
     /// Extrudes the face along a translation.
     ///
     /// Returns the extruded face.
-    #[allow(clippy::return_self_not_must_use)]
-    pub fn extrude_with_translation(self, translation: Vector<VertexPosition<G>>) -> Self
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the keyspace of the graph is exhausted (see
+    /// [`KeyIndex`]).
+    ///
+    /// [`KeyIndex`]: crate::graph::KeyIndex
+    pub fn extrude_with_translation(
+        self,
+        translation: Vector<VertexPosition<G>>,
+    ) -> Result<Self, GraphError>
     where
         G::Vertex: AsPositionMut,
         VertexPosition<G>: EuclideanSpace,
@@ -842,24 +1156,68 @@ where
     /// Extrudes a face using the given vertex data.
     ///
     /// Returns the extruded face.
-    #[allow(clippy::return_self_not_must_use)]
-    pub fn extrude_with<F>(self, f: F) -> Self
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the keyspace of the graph is exhausted (see
+    /// [`KeyIndex`]).
+    ///
+    /// [`KeyIndex`]: crate::graph::KeyIndex
+    pub fn extrude_with<F>(self, f: F) -> Result<Self, GraphError>
     where
         F: Fn(&G::Vertex) -> G::Vertex,
     {
         // This should never fail here.
         let cache = FaceExtrudeCache::from_face(self.to_ref()).expect_consistent();
+        let arity = self.arity();
         let (storage, _) = self.unbind();
+        // Extrusion inserts a copy of each vertex, an edge for each arc of the
+        // extruded face and between each vertex and its copy, the extruded
+        // face, and a connecting face for each arc.
+        mutation::check_keyspace::<M, G, K>(&*storage, arity, 2 * arity, arity + 1)?;
         Mutation::take(storage)
             .bypass_or_commit_with(|mutation| face::extrude_with(mutation, cache, f))
             .map(|(storage, face)| Bind::bind(storage, face).expect_consistent())
             .map_err(|(_, error)| error)
-            .expect_consistent()
     }
 
     /// Removes the face.
     ///
+    /// The arcs of the face are disconnected from it and become boundary arcs,
+    /// leaving a hole in the graph. The vertices, arcs, and edges of the face
+    /// are not removed. The hole can be closed again by inserting a face into
+    /// the remaining ring (see [`Ring::get_or_insert_face`]).
+    ///
     /// Returns the remaining ring of the face if it is not entirely disjoint, otherwise `None`.
+    ///
+    /// # Examples
+    ///
+    /// Punching a hole into a cube and closing it again:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// let ring = graph.face_mut(key).unwrap().remove().unwrap();
+    /// assert!(ring.arc().is_boundary_arc());
+    ///
+    /// ring.get_or_insert_face();
+    /// assert_eq!(6, graph.face_count());
+    /// ```
+    ///
+    /// [`Ring::get_or_insert_face`]: crate::graph::Ring::get_or_insert_face
     pub fn remove(self) -> Option<Ring<&'a mut M>> {
         // This should never fail here.
         let cache = FaceRemoveCache::from_face(self.to_ref()).expect_consistent();
@@ -873,11 +1231,12 @@ where
     }
 }
 
-impl<B, M, G> Adjacency for FaceView<B>
+impl<B, M, G, K> Adjacency for FaceView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Face<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Face<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     type Output = SmallVec<[Self::Key; 8]>;
 
@@ -886,22 +1245,23 @@ where
     }
 }
 
-impl<B> Borrow<FaceKey> for FaceView<B>
+impl<B> Borrow<FaceKey<Index<B>>> for FaceView<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Face<Data<B>>> + Parametric,
+    B::Target: AsStorage<Face<Data<B>, Index<B>>> + Parametric,
 {
-    fn borrow(&self) -> &FaceKey {
+    fn borrow(&self) -> &FaceKey<Index<B>> {
         self.inner.as_ref()
     }
 }
 
-impl<B, M, G> Clone for FaceView<B>
+impl<B, M, G, K> Clone for FaceView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
-    View<B, Face<G>>: Clone,
+    K: KeyIndex,
+    View<B, Face<G, K>>: Clone,
 {
     fn clone(&self) -> Self {
         FaceView {
@@ -910,14 +1270,15 @@ where
     }
 }
 
-impl<B, M, G> ClosedView for FaceView<B>
+impl<B, M, G, K> ClosedView for FaceView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Key = FaceKey;
-    type Entity = Face<G>;
+    type Key = FaceKey<K>;
+    type Entity = Face<G, K>;
 
     /// Gets the key for the face.
     fn key(&self) -> Self::Key {
@@ -925,44 +1286,48 @@ where
     }
 }
 
-impl<B, M, G> Copy for FaceView<B>
+impl<B, M, G, K> Copy for FaceView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
-    View<B, Face<G>>: Copy,
+    K: KeyIndex,
+    View<B, Face<G, K>>: Copy,
 {
 }
 
-impl<B, M, G> Deref for FaceView<B>
+impl<B, M, G, K> Deref for FaceView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Target = Face<G>;
+    type Target = Face<G, K>;
 
     fn deref(&self) -> &Self::Target {
         self.inner.deref()
     }
 }
 
-impl<B, M, G> DerefMut for FaceView<B>
+impl<B, M, G, K> DerefMut for FaceView<B>
 where
     B: ReborrowMut<Target = M>,
-    M: AsStorageMut<Face<G>> + Parametric<Data = G>,
+    M: AsStorageMut<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.inner.deref_mut()
     }
 }
 
-impl<B, M, G> DynamicArity for FaceView<B>
+impl<B, M, G, K> DynamicArity for FaceView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Face<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Face<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     type Dynamic = usize;
 
@@ -973,30 +1338,33 @@ where
     }
 }
 
-impl<B, M, G> Eq for FaceView<B>
+impl<B, M, G, K> Eq for FaceView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Face<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Face<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
 }
 
-impl<B, M, G> From<View<B, Face<G>>> for FaceView<B>
+impl<B, M, G, K> From<View<B, Face<G, K>>> for FaceView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    fn from(view: View<B, Face<G>>) -> Self {
+    fn from(view: View<B, Face<G, K>>) -> Self {
         FaceView { inner: view }
     }
 }
 
-impl<B, M, G> From<FaceView<B>> for View<B, Face<G>>
+impl<B, M, G, K> From<FaceView<B>> for View<B, Face<G, K>>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn from(face: FaceView<B>) -> Self {
         let FaceView { inner, .. } = face;
@@ -1004,11 +1372,12 @@ where
     }
 }
 
-impl<B, M, G> Hash for FaceView<B>
+impl<B, M, G, K> Hash for FaceView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn hash<H>(&self, state: &mut H)
     where
@@ -1018,33 +1387,36 @@ where
     }
 }
 
-impl<B, M, G> PartialEq for FaceView<B>
+impl<B, M, G, K> PartialEq for FaceView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Face<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Face<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn eq(&self, other: &Self) -> bool {
         self.inner == other.inner
     }
 }
 
-impl<B, M, G> StaticArity for FaceView<B>
+impl<B, M, G, K> StaticArity for FaceView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Static = <MeshGraph<G> as StaticArity>::Static;
+    type Static = <MeshGraph<G, K> as StaticArity>::Static;
 
-    const ARITY: Self::Static = MeshGraph::<G>::ARITY;
+    const ARITY: Self::Static = MeshGraph::<G, K>::ARITY;
 }
 
-impl<B, M, G> ToRing<B> for FaceView<B>
+impl<B, M, G, K> ToRing<B> for FaceView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Face<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Face<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn into_ring(self) -> Ring<B> {
         FaceView::into_ring(self)
@@ -1056,16 +1428,18 @@ where
 }
 
 /// Orphan view of a face entity.
-pub struct FaceOrphan<'a, G>
+pub struct FaceOrphan<'a, G, K = u64>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    inner: Orphan<'a, Face<G>>,
+    inner: Orphan<'a, Face<G, K>>,
 }
 
-impl<'a, G> FaceOrphan<'a, G>
+impl<'a, G, K> FaceOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn get(&self) -> &G::Face {
         self.inner.get()
@@ -1074,52 +1448,68 @@ where
     pub fn get_mut(&mut self) -> &mut G::Face {
         self.inner.get_mut()
     }
+
+    /// Gets the key of the leading arc of the face.
+    ///
+    /// Orphan views cannot traverse topology. This key is captured when the
+    /// orphan view is created.
+    pub fn arc_key(&self) -> ArcKey<K> {
+        self.inner.links()
+    }
 }
 
-impl<'a, G> ClosedView for FaceOrphan<'a, G>
+impl<'a, G, K> ClosedView for FaceOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    type Key = FaceKey;
-    type Entity = Face<G>;
+    type Key = FaceKey<K>;
+    type Entity = Face<G, K>;
 
     fn key(&self) -> Self::Key {
         self.inner.key()
     }
 }
 
-impl<'a, G> Eq for FaceOrphan<'a, G> where G: GraphData {}
+impl<'a, G, K> Eq for FaceOrphan<'a, G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+}
 
-impl<'a, M> From<FaceView<&'a mut M>> for FaceOrphan<'a, M::Data>
+impl<'a, M> From<FaceView<&'a mut M>> for FaceOrphan<'a, M::Data, M::Index>
 where
-    M: AsStorageMut<Face<M::Data>> + Parametric,
+    M: AsStorageMut<Face<M::Data, M::Index>> + Parametric,
 {
     fn from(face: FaceView<&'a mut M>) -> Self {
         Orphan::from(face.inner).into()
     }
 }
 
-impl<'a, G> From<Orphan<'a, Face<G>>> for FaceOrphan<'a, G>
+impl<'a, G, K> From<Orphan<'a, Face<G, K>>> for FaceOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    fn from(inner: Orphan<'a, Face<G>>) -> Self {
+    fn from(inner: Orphan<'a, Face<G, K>>) -> Self {
         FaceOrphan { inner }
     }
 }
 
-impl<'a, M> From<View<&'a mut M, Face<M::Data>>> for FaceOrphan<'a, M::Data>
+impl<'a, M> From<View<&'a mut M, Face<M::Data, M::Index>>> for FaceOrphan<'a, M::Data, M::Index>
 where
-    M: AsStorageMut<Face<M::Data>> + Parametric,
+    M: AsStorageMut<Face<M::Data, M::Index>> + Parametric,
 {
-    fn from(view: View<&'a mut M, Face<M::Data>>) -> Self {
+    fn from(view: View<&'a mut M, Face<M::Data, M::Index>>) -> Self {
         FaceOrphan { inner: view.into() }
     }
 }
 
-impl<'a, G> PartialEq for FaceOrphan<'a, G>
+impl<'a, G, K> PartialEq for FaceOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     fn eq(&self, other: &Self) -> bool {
         self.inner == other.inner
@@ -1139,27 +1529,29 @@ where
 pub struct Ring<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<Data<B>>> + Consistent + Parametric,
+    B::Target: AsStorage<Arc<Data<B>, Index<B>>> + Consistent + Parametric,
 {
     arc: ArcView<B>,
 }
 
-impl<B, M, G> Ring<B>
+impl<B, M, G, K> Ring<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn to_ref(&self) -> Ring<&M> {
         self.arc.to_ref().into_ring()
     }
 }
 
-impl<B, M, G> Ring<B>
+impl<B, M, G, K> Ring<B>
 where
     B: ReborrowMut<Target = M>,
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     #[allow(clippy::wrong_self_convention)]
     fn to_mut_unchecked(&mut self) -> Ring<&mut M> {
@@ -1167,22 +1559,24 @@ where
     }
 }
 
-impl<'a, B, M, G> Ring<B>
+impl<'a, B, M, G, K> Ring<B>
 where
     B: ReborrowInto<'a, Target = M>,
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn into_ref(self) -> Ring<&'a M> {
         self.arc.into_ref().into_ring()
     }
 }
 
-impl<B, M, G> Ring<B>
+impl<B, M, G, K> Ring<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Converts the ring into its leading arc.
     pub fn into_arc(self) -> ArcView<B> {
@@ -1195,11 +1589,15 @@ where
     }
 }
 
-impl<B, M, G> Ring<B>
+impl<B, M, G, K> Ring<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn into_path(self) -> Path<'static, B> {
         self.into()
@@ -1218,8 +1616,8 @@ where
     /// null path with a zero metric.
     pub fn shortest_logical_metric(
         &self,
-        from: impl Into<Selector<VertexKey>>,
-        to: impl Into<Selector<VertexKey>>,
+        from: impl Into<Selector<VertexKey<K>>>,
+        to: impl Into<Selector<VertexKey<K>>>,
     ) -> Result<usize, GraphError> {
         let arity = self.arity();
         let index = |selector: Selector<_>| match selector {
@@ -1229,10 +1627,10 @@ where
                 .enumerate()
                 .find(|(_, a)| *a == key)
                 .map(|(index, _)| index)
-                .ok_or(GraphError::TopologyNotFound),
+                .ok_or(GraphError::TopologyNotFound(TopologyKind::Vertex)),
             Selector::ByIndex(index) => {
                 if index >= arity {
-                    Err(GraphError::TopologyNotFound)
+                    Err(GraphError::TopologyNotFound(TopologyKind::Vertex))
                 }
                 else {
                     Ok(index)
@@ -1246,11 +1644,12 @@ where
     }
 }
 
-impl<B, M, G> Ring<B>
+impl<B, M, G, K> Ring<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Face<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Face<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Converts the ring into its face.
     ///
@@ -1269,45 +1668,71 @@ where
     }
 }
 
-impl<'a, B, M, G> Ring<B>
+impl<'a, B, M, G, K> Ring<B>
 where
     B: ReborrowInto<'a, Target = M>,
-    M: 'a + AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: 'a + AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn into_arcs(self) -> impl Clone + Iterator<Item = ArcView<&'a M>> {
         ArcCirculator::from(self.into_ref())
     }
+
+    pub fn into_arcs_reversed(self) -> impl Clone + Iterator<Item = ArcView<&'a M>> {
+        ArcCirculator::reversed(self.into_ref())
+    }
 }
 
-impl<B, G> Ring<B>
+impl<B, G, K> Ring<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    B::Target: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Gets an iterator of views over the arcs within the ring.
     pub fn arcs(&self) -> impl Clone + Iterator<Item = ArcView<&B::Target>> {
         self.to_ref().into_arcs()
     }
+
+    /// Gets an iterator of views over the arcs within the ring in reverse.
+    ///
+    /// Arcs are visited by following previous arcs, beginning with the
+    /// previous arc of the ring's arc. This is the reverse order of
+    /// [`Ring::arcs`].
+    ///
+    /// [`Ring::arcs`]: crate::graph::Ring::arcs
+    pub fn arcs_reversed(&self) -> impl Clone + Iterator<Item = ArcView<&B::Target>> {
+        self.to_ref().into_arcs_reversed()
+    }
 }
 
-impl<'a, B, M, G> Ring<B>
+impl<'a, B, M, G, K> Ring<B>
 where
     B: ReborrowInto<'a, Target = M>,
-    M: 'a + AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    M: 'a
+        + AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn into_vertices(self) -> impl Clone + Iterator<Item = VertexView<&'a M>> {
         VertexCirculator::from(ArcCirculator::from(self.into_ref()))
     }
 }
 
-impl<B, G> Ring<B>
+impl<B, G, K> Ring<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    B::Target: AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Gets an iterator of views over the vertices within the ring.
     pub fn vertices(&self) -> impl Clone + Iterator<Item = VertexView<&B::Target>> {
@@ -1317,9 +1742,9 @@ where
 
 impl<'a, M> Ring<&'a mut M>
 where
-    M: AsStorageMut<Arc<M::Data>> + Consistent + Parametric,
+    M: AsStorageMut<Arc<M::Data, M::Index>> + Consistent + Parametric,
 {
-    pub fn into_arc_orphans(self) -> impl Iterator<Item = ArcOrphan<'a, M::Data>> {
+    pub fn into_arc_orphans(self) -> impl Iterator<Item = ArcOrphan<'a, M::Data, M::Index>> {
         ArcCirculator::from(self)
     }
 }
@@ -1327,19 +1752,22 @@ where
 impl<B> Ring<B>
 where
     B: ReborrowMut,
-    B::Target: AsStorageMut<Arc<Data<B>>> + Consistent + Parametric,
+    B::Target: AsStorageMut<Arc<Data<B>, Index<B>>> + Consistent + Parametric,
 {
     /// Gets an iterator of orphan views over the arcs in the ring.
-    pub fn arc_orphans(&mut self) -> impl Iterator<Item = ArcOrphan<Data<B>>> {
+    pub fn arc_orphans(&mut self) -> impl Iterator<Item = ArcOrphan<Data<B>, Index<B>>> {
         self.to_mut_unchecked().into_arc_orphans()
     }
 }
 
 impl<'a, M> Ring<&'a mut M>
 where
-    M: AsStorage<Arc<M::Data>> + AsStorageMut<Vertex<M::Data>> + Consistent + Parametric,
+    M: AsStorage<Arc<M::Data, M::Index>>
+        + AsStorageMut<Vertex<M::Data, M::Index>>
+        + Consistent
+        + Parametric,
 {
-    pub fn into_vertex_orphans(self) -> impl Iterator<Item = VertexOrphan<'a, M::Data>> {
+    pub fn into_vertex_orphans(self) -> impl Iterator<Item = VertexOrphan<'a, M::Data, M::Index>> {
         VertexCirculator::from(ArcCirculator::from(self))
     }
 }
@@ -1347,18 +1775,26 @@ where
 impl<B> Ring<B>
 where
     B: ReborrowMut,
-    B::Target: AsStorage<Arc<Data<B>>> + AsStorageMut<Vertex<Data<B>>> + Consistent + Parametric,
+    B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+        + AsStorageMut<Vertex<Data<B>, Index<B>>>
+        + Consistent
+        + Parametric,
 {
     /// Gets an iterator of views over the vertices within the ring.
-    pub fn vertex_orphans(&mut self) -> impl Iterator<Item = VertexOrphan<Data<B>>> {
+    pub fn vertex_orphans(&mut self) -> impl Iterator<Item = VertexOrphan<Data<B>, Index<B>>> {
         self.to_mut_unchecked().into_vertex_orphans()
     }
 }
 
-impl<'a, M, G> Ring<&'a mut M>
+impl<'a, M, G, K> Ring<&'a mut M>
 where
-    M: AsStorage<Vertex<G>> + AsStorage<Arc<G>> + AsStorage<Face<G>> + Default + Mutable<Data = G>,
+    M: AsStorage<Vertex<G, K>>
+        + AsStorage<Arc<G, K>>
+        + AsStorage<Face<G, K>>
+        + Default
+        + Mutable<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Gets the face of the ring or inserts a face if one does not already
     /// exist.
@@ -1398,11 +1834,12 @@ where
     }
 }
 
-impl<B, M, G> DynamicArity for Ring<B>
+impl<B, M, G, K> DynamicArity for Ring<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     type Dynamic = usize;
 
@@ -1413,22 +1850,24 @@ where
     }
 }
 
-impl<B, M, G> From<ArcView<B>> for Ring<B>
+impl<B, M, G, K> From<ArcView<B>> for Ring<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn from(arc: ArcView<B>) -> Self {
         Ring { arc }
     }
 }
 
-impl<B, M, G> PartialEq for Ring<B>
+impl<B, M, G, K> PartialEq for Ring<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn eq(&self, other: &Self) -> bool {
         let keys = |ring: &Self| ring.arcs().keys().collect::<HashSet<_>>();
@@ -1436,22 +1875,24 @@ where
     }
 }
 
-impl<B, M, G> StaticArity for Ring<B>
+impl<B, M, G, K> StaticArity for Ring<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Static = <MeshGraph<G> as StaticArity>::Static;
+    type Static = <MeshGraph<G, K> as StaticArity>::Static;
 
-    const ARITY: Self::Static = MeshGraph::<G>::ARITY;
+    const ARITY: Self::Static = MeshGraph::<G, K>::ARITY;
 }
 
-impl<B, M, G> ToRing<B> for Ring<B>
+impl<B, M, G, K> ToRing<B> for Ring<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn into_ring(self) -> Ring<B> {
         self
@@ -1465,18 +1906,22 @@ where
 pub struct VertexCirculator<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<Data<B>>> + Consistent + Parametric,
+    B::Target: AsStorage<Arc<Data<B>, Index<B>>> + Consistent + Parametric,
 {
     inner: ArcCirculator<B>,
 }
 
-impl<B, M, G> Circulator<B> for VertexCirculator<B>
+impl<B, M, G, K> Circulator<B> for VertexCirculator<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Entity = Vertex<G>;
+    type Entity = Vertex<G, K>;
 
     fn next(&mut self) -> Option<<Self::Entity as Entity>::Key> {
         let ab = self.inner.next();
@@ -1487,11 +1932,12 @@ where
     }
 }
 
-impl<B, M, G> Clone for VertexCirculator<B>
+impl<B, M, G, K> Clone for VertexCirculator<B>
 where
     B: Clone + Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn clone(&self) -> Self {
         VertexCirculator {
@@ -1500,21 +1946,26 @@ where
     }
 }
 
-impl<B, M, G> From<ArcCirculator<B>> for VertexCirculator<B>
+impl<B, M, G, K> From<ArcCirculator<B>> for VertexCirculator<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn from(inner: ArcCirculator<B>) -> Self {
         VertexCirculator { inner }
     }
 }
 
-impl<'a, M, G> Iterator for VertexCirculator<&'a M>
+impl<'a, M, G, K> Iterator for VertexCirculator<&'a M>
 where
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     type Item = VertexView<&'a M>;
 
@@ -1531,9 +1982,12 @@ where
 
 impl<'a, M> Iterator for VertexCirculator<&'a mut M>
 where
-    M: AsStorage<Arc<M::Data>> + AsStorageMut<Vertex<M::Data>> + Consistent + Parametric,
+    M: AsStorage<Arc<M::Data, M::Index>>
+        + AsStorageMut<Vertex<M::Data, M::Index>>
+        + Consistent
+        + Parametric,
 {
-    type Item = VertexOrphan<'a, M::Data>;
+    type Item = VertexOrphan<'a, M::Data, M::Index>;
 
     fn next(&mut self) -> Option<Self::Item> {
         unsafe { self.bind_next_orphan() }
@@ -1548,7 +2002,10 @@ where
 
 impl<'a, M> OrphanCirculator<'a, M> for VertexCirculator<&'a mut M>
 where
-    M: AsStorage<Arc<M::Data>> + AsStorageMut<Vertex<M::Data>> + Consistent + Parametric,
+    M: AsStorage<Arc<M::Data, M::Index>>
+        + AsStorageMut<Vertex<M::Data, M::Index>>
+        + Consistent
+        + Parametric,
 {
     fn target(&mut self) -> &mut M {
         self.inner.storage
@@ -1557,7 +2014,10 @@ where
 
 impl<'a, M> ViewCirculator<'a, M> for VertexCirculator<&'a M>
 where
-    M: AsStorage<Arc<M::Data>> + AsStorage<Vertex<M::Data>> + Consistent + Parametric,
+    M: AsStorage<Arc<M::Data, M::Index>>
+        + AsStorage<Vertex<M::Data, M::Index>>
+        + Consistent
+        + Parametric,
 {
     fn target(&self) -> &'a M {
         self.inner.storage
@@ -1567,20 +2027,44 @@ where
 pub struct ArcCirculator<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<Data<B>>> + Consistent + Parametric,
+    B::Target: AsStorage<Arc<Data<B>, Index<B>>> + Consistent + Parametric,
 {
     storage: B,
-    arc: Option<ArcKey>,
-    trace: TraceFirst<ArcKey>,
+    arc: Option<ArcKey<Index<B>>>,
+    trace: TraceFirst<ArcKey<Index<B>>>,
+    // Whether or not previous arcs are traversed rather than next arcs.
+    reversed: bool,
 }
 
-impl<B, M, G> Circulator<B> for ArcCirculator<B>
+impl<B, M, G, K> ArcCirculator<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Entity = Arc<G>;
+    // Circulates the ring in reverse, beginning with the previous arc of the
+    // ring's arc. This visits arcs in the opposite order of the forward
+    // circulator.
+    fn reversed(ring: Ring<B>) -> Self {
+        let (storage, key) = ring.into_arc().into_previous_arc().unbind();
+        ArcCirculator {
+            storage,
+            arc: Some(key),
+            trace: Default::default(),
+            reversed: true,
+        }
+    }
+}
+
+impl<B, M, G, K> Circulator<B> for ArcCirculator<B>
+where
+    B: Reborrow<Target = M>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
+    G: GraphData,
+    K: KeyIndex,
+{
+    type Entity = Arc<G, K>;
 
     fn next(&mut self) -> Option<<Self::Entity as Entity>::Key> {
         self.arc
@@ -1591,32 +2075,42 @@ where
                     .reborrow()
                     .as_storage()
                     .get(&arc)
-                    .and_then(|arc| arc.next);
+                    .and_then(|arc| {
+                        if self.reversed {
+                            arc.previous
+                        }
+                        else {
+                            arc.next
+                        }
+                    });
                 arc
             })
     }
 }
 
-impl<B, M, G> Clone for ArcCirculator<B>
+impl<B, M, G, K> Clone for ArcCirculator<B>
 where
     B: Clone + Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn clone(&self) -> Self {
         ArcCirculator {
             storage: self.storage.clone(),
             arc: self.arc,
             trace: self.trace,
+            reversed: self.reversed,
         }
     }
 }
 
-impl<B, M, G> From<Ring<B>> for ArcCirculator<B>
+impl<B, M, G, K> From<Ring<B>> for ArcCirculator<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn from(ring: Ring<B>) -> Self {
         let (storage, key) = ring.into_arc().unbind();
@@ -1624,14 +2118,16 @@ where
             storage,
             arc: Some(key),
             trace: Default::default(),
+            reversed: false,
         }
     }
 }
 
-impl<'a, M, G> Iterator for ArcCirculator<&'a M>
+impl<'a, M, G, K> Iterator for ArcCirculator<&'a M>
 where
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     type Item = ArcView<&'a M>;
 
@@ -1648,9 +2144,9 @@ where
 
 impl<'a, M> Iterator for ArcCirculator<&'a mut M>
 where
-    M: AsStorageMut<Arc<M::Data>> + Consistent + Parametric,
+    M: AsStorageMut<Arc<M::Data, M::Index>> + Consistent + Parametric,
 {
-    type Item = ArcOrphan<'a, M::Data>;
+    type Item = ArcOrphan<'a, M::Data, M::Index>;
 
     fn next(&mut self) -> Option<Self::Item> {
         unsafe { self.bind_next_orphan() }
@@ -1665,17 +2161,18 @@ where
 
 impl<'a, M> OrphanCirculator<'a, M> for ArcCirculator<&'a mut M>
 where
-    M: AsStorageMut<Arc<M::Data>> + Consistent + Parametric,
+    M: AsStorageMut<Arc<M::Data, M::Index>> + Consistent + Parametric,
 {
     fn target(&mut self) -> &mut M {
         self.storage
     }
 }
 
-impl<'a, M, G> ViewCirculator<'a, M> for ArcCirculator<&'a M>
+impl<'a, M, G, K> ViewCirculator<'a, M> for ArcCirculator<&'a M>
 where
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn target(&self) -> &'a M {
         self.storage
@@ -1685,18 +2182,19 @@ where
 pub struct FaceCirculator<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<Data<B>>> + Consistent + Parametric,
+    B::Target: AsStorage<Arc<Data<B>, Index<B>>> + Consistent + Parametric,
 {
     inner: ArcCirculator<B>,
 }
 
-impl<B, M, G> Circulator<B> for FaceCirculator<B>
+impl<B, M, G, K> Circulator<B> for FaceCirculator<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Face<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Face<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Entity = Face<G>;
+    type Entity = Face<G, K>;
 
     fn next(&mut self) -> Option<<Self::Entity as Entity>::Key> {
         while let Some(ba) = self.inner.next().map(|ab| ab.into_opposite()) {
@@ -1704,7 +2202,7 @@ where
                 .inner
                 .storage
                 .reborrow()
-                .as_storage_of::<Arc<_>>()
+                .as_storage_of::<Arc<_, _>>()
                 .get(&ba)
                 .and_then(|opposite| opposite.face)
             {
@@ -1720,11 +2218,12 @@ where
     }
 }
 
-impl<B, M, G> Clone for FaceCirculator<B>
+impl<B, M, G, K> Clone for FaceCirculator<B>
 where
     B: Clone + Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn clone(&self) -> Self {
         FaceCirculator {
@@ -1733,21 +2232,23 @@ where
     }
 }
 
-impl<B, M, G> From<ArcCirculator<B>> for FaceCirculator<B>
+impl<B, M, G, K> From<ArcCirculator<B>> for FaceCirculator<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn from(inner: ArcCirculator<B>) -> Self {
         FaceCirculator { inner }
     }
 }
 
-impl<'a, M, G> Iterator for FaceCirculator<&'a M>
+impl<'a, M, G, K> Iterator for FaceCirculator<&'a M>
 where
-    M: AsStorage<Arc<G>> + AsStorage<Face<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Face<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     type Item = FaceView<&'a M>;
 
@@ -1758,9 +2259,12 @@ where
 
 impl<'a, M> Iterator for FaceCirculator<&'a mut M>
 where
-    M: AsStorage<Arc<M::Data>> + AsStorageMut<Face<M::Data>> + Consistent + Parametric,
+    M: AsStorage<Arc<M::Data, M::Index>>
+        + AsStorageMut<Face<M::Data, M::Index>>
+        + Consistent
+        + Parametric,
 {
-    type Item = FaceOrphan<'a, M::Data>;
+    type Item = FaceOrphan<'a, M::Data, M::Index>;
 
     fn next(&mut self) -> Option<Self::Item> {
         unsafe { self.bind_next_orphan() }
@@ -1769,17 +2273,21 @@ where
 
 impl<'a, M> OrphanCirculator<'a, M> for FaceCirculator<&'a mut M>
 where
-    M: AsStorage<Arc<M::Data>> + AsStorageMut<Face<M::Data>> + Consistent + Parametric,
+    M: AsStorage<Arc<M::Data, M::Index>>
+        + AsStorageMut<Face<M::Data, M::Index>>
+        + Consistent
+        + Parametric,
 {
     fn target(&mut self) -> &mut M {
         self.inner.storage
     }
 }
 
-impl<'a, M, G> ViewCirculator<'a, M> for FaceCirculator<&'a M>
+impl<'a, M, G, K> ViewCirculator<'a, M> for FaceCirculator<&'a M>
 where
-    M: AsStorage<Arc<G>> + AsStorage<Face<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Face<G, K>> + Consistent + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn target(&self) -> &'a M {
         self.inner.storage
@@ -1790,14 +2298,15 @@ where
 mod tests {
     use decorum::R64;
     use nalgebra::{Point2, Point3};
+    use std::collections::{HashMap, HashSet};
 
-    use crate::graph::MeshGraph;
+    use crate::graph::{GraphError, MeshGraph, TopologyKind};
     use crate::index::HashIndexer;
     use crate::prelude::*;
     use crate::primitive::cube::Cube;
     use crate::primitive::generate::Position;
     use crate::primitive::sphere::UvSphere;
-    use crate::primitive::Tetragon;
+    use crate::primitive::{Tetragon, Trigon};
 
     type E2 = Point2<R64>;
     type E3 = Point3<R64>;
@@ -1813,6 +2322,32 @@ mod tests {
         assert_eq!(3, face.adjacent_arcs().count());
     }
 
+    #[test]
+    fn circulate_over_arcs_reversed() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+
+        for arc in graph.arcs() {
+            assert_eq!(arc.key(), arc.previous_arc().next_arc().key());
+            assert_eq!(arc.key(), arc.next_arc().previous_arc().key());
+        }
+        for face in graph.faces() {
+            let mut forward = face
+                .adjacent_arcs()
+                .map(|arc| arc.key())
+                .collect::<Vec<_>>();
+            let reversed = face
+                .adjacent_arcs_reversed()
+                .map(|arc| arc.key())
+                .collect::<Vec<_>>();
+            assert_eq!(4, reversed.len());
+            forward.reverse();
+            assert_eq!(forward, reversed);
+            for (ab, bc) in reversed.iter().skip(1).zip(reversed.iter()) {
+                assert_eq!(*bc, graph.arc(*ab).unwrap().next_arc().key());
+            }
+        }
+    }
+
     #[test]
     fn circulate_over_faces() {
         let graph: MeshGraph<E3> = UvSphere::new(3, 2)
@@ -1848,6 +2383,175 @@ mod tests {
         assert_eq!(5, graph.face_count());
     }
 
+    #[test]
+    fn remove_and_insert_face() {
+        let mut graph: MeshGraph<E3> = Cube::new()
+            .polygons::<Position<E3>>() // 6 quadrilaterals, 24 vertices.
+            .collect();
+
+        assert_eq!(12, graph.edge_count());
+
+        // Remove a face and re-insert a face into the remaining ring.
+        let key = graph.faces().nth(0).unwrap().key();
+        let face = graph
+            .face_mut(key)
+            .unwrap()
+            .remove()
+            .unwrap()
+            .get_or_insert_face()
+            .into_ref();
+
+        // The boundary arcs of the ring should be reused by the face.
+        assert_eq!(4, face.arity());
+        assert!(face.adjacent_arcs().all(|arc| !arc.is_boundary_arc()));
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(24, graph.arc_count());
+        assert_eq!(12, graph.edge_count());
+        assert_eq!(6, graph.face_count());
+    }
+
+    #[test]
+    fn remove_adjacent_faces_and_fill() {
+        let mut graph: MeshGraph<E3> = Cube::new()
+            .polygons::<Position<E3>>() // 6 quadrilaterals, 24 vertices.
+            .collect();
+
+        // Remove two adjacent faces. The edge between them no longer has any
+        // faces, but it and its vertices remain.
+        let abc = graph.faces().nth(0).unwrap().key();
+        let def = graph
+            .face(abc)
+            .unwrap()
+            .adjacent_faces()
+            .nth(0)
+            .unwrap()
+            .key();
+        let ab = graph.face_mut(abc).unwrap().remove().unwrap().arc().key();
+        let de = graph.face_mut(def).unwrap().remove().unwrap().arc().key();
+
+        assert_eq!(4, graph.face_count());
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(12, graph.edge_count());
+        assert_eq!(8, graph.arcs().filter(|arc| arc.is_boundary_arc()).count());
+
+        // Fill the holes left by the removed faces.
+        for key in [ab, de] {
+            graph.arc_mut(key).unwrap().into_ring().get_or_insert_face();
+        }
+        assert_eq!(6, graph.face_count());
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+    }
+
+    #[test]
+    fn remove_boundary_face() {
+        // Construct a strip of two quadrilaterals and remove one of them.
+        let mut graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 4, 3), Tetragon::new(1, 2, 5, 4)],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (2.0, 0.0),
+                (0.0, 1.0),
+                (1.0, 1.0),
+                (2.0, 1.0),
+            ],
+        )
+        .unwrap();
+        let outgoing = graph
+            .vertices()
+            .map(|vertex| {
+                let arcs = vertex
+                    .outgoing_arcs()
+                    .map(|arc| arc.key())
+                    .collect::<HashSet<_>>();
+                (vertex.key(), arcs)
+            })
+            .collect::<HashMap<_, _>>();
+        let key = graph.faces().nth(0).unwrap().key();
+        graph.face_mut(key).unwrap().remove();
+
+        // Shared edges and vertices remain and all arcs of the removed face
+        // are now boundary arcs.
+        assert_eq!(6, graph.vertex_count());
+        assert_eq!(7, graph.edge_count());
+        assert_eq!(1, graph.face_count());
+        assert_eq!(10, graph.arcs().filter(|arc| arc.is_boundary_arc()).count());
+        // Topology remains traversable. Every vertex circulates over the same
+        // outgoing arcs as before the removal.
+        for vertex in graph.vertices() {
+            let arcs = vertex
+                .outgoing_arcs()
+                .map(|arc| arc.key())
+                .collect::<HashSet<_>>();
+            assert_eq!(outgoing[&vertex.key()], arcs);
+            assert!(vertex
+                .outgoing_arcs()
+                .all(|arc| arc.source_vertex().key() == vertex.key()));
+        }
+        for arc in graph.arcs() {
+            assert_eq!(arc.key(), arc.next_arc().previous_arc().key());
+        }
+    }
+
+    #[test]
+    fn shared_topology() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let face = graph.faces().nth(0).unwrap();
+
+        // Adjacent faces of a cube share one edge and two vertices.
+        let adjacent = face.adjacent_faces().nth(0).unwrap().key();
+        assert!(face.is_adjacent_to(adjacent));
+        assert_eq!(1, face.shared_edges(adjacent).len());
+        assert_eq!(2, face.shared_vertices(adjacent).len());
+
+        // Opposite faces of a cube share nothing.
+        let opposite = graph
+            .faces()
+            .find(|other| other.key() != face.key() && !face.is_adjacent_to(other.key()))
+            .unwrap()
+            .key();
+        assert!(face.shared_edges(opposite).is_empty());
+        assert!(face.shared_vertices(opposite).is_empty());
+    }
+
+    #[test]
+    fn shared_topology_with_multiple_edges() {
+        // Construct two quadrilaterals that share two consecutive edges.
+        let graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 2, 3), Tetragon::new(2, 1, 0, 4)],
+            vec![(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (1.0, 1.0), (1.0, -1.0)],
+        )
+        .unwrap();
+        let abc = graph.faces().nth(0).unwrap();
+        let def = graph.faces().nth(1).unwrap().key();
+
+        assert!(abc.is_adjacent_to(def));
+        assert_eq!(2, abc.shared_edges(def).len());
+        assert_eq!(3, abc.shared_vertices(def).len());
+    }
+
+    #[test]
+    fn shared_topology_without_edges() {
+        // Construct two triangles that share a single vertex.
+        let graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 3, 4)],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (1.0, 1.0),
+                (-1.0, 0.0),
+                (-1.0, -1.0),
+            ],
+        )
+        .unwrap();
+        let abc = graph.faces().nth(0).unwrap();
+        let def = graph.faces().nth(1).unwrap().key();
+
+        assert!(!abc.is_adjacent_to(def));
+        assert!(abc.shared_edges(def).is_empty());
+        assert_eq!(1, abc.shared_vertices(def).len());
+    }
+
     #[test]
     fn split_face() {
         let mut graph = MeshGraph::<E2>::from_raw_buffers_with_arity(
@@ -1900,6 +2604,32 @@ mod tests {
         assert_eq!(9, graph.face_count());
     }
 
+    #[test]
+    fn inset_face() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.faces().nth(0).unwrap().key();
+        let area = graph.face(key).unwrap().area();
+        let face = graph.face_mut(key).unwrap().inset(0.5).unwrap().into_ref();
+
+        // The inset face has half of the width and height of the original face.
+        let error = face.area() - (area * R64::from(0.25));
+        assert!(error < R64::from(1e-9) && error > R64::from(-1e-9));
+        assert_eq!(4, face.adjacent_faces().count());
+
+        // The inset face and four quadrilaterals replace the original face.
+        assert_eq!(12, graph.vertex_count());
+        assert_eq!(10, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 4));
+        assert!(!graph.arcs().any(|arc| arc.is_boundary_arc()));
+
+        // Collapsing the face into a point is rejected.
+        let key = graph.faces().nth(0).unwrap().key();
+        assert_eq!(
+            GraphError::Geometry,
+            graph.face_mut(key).unwrap().inset(1.0).err().unwrap()
+        );
+    }
+
     #[test]
     fn merge_faces() {
         // Construct a graph with two connected quadrilaterals.
@@ -1936,7 +2666,7 @@ mod tests {
             .polygons::<Position<E3>>() // 6 quadrilaterals, 24 vertices.
             .collect();
         let key = graph.faces().nth(0).unwrap().key();
-        let vertex = graph.face_mut(key).unwrap().poke_at_centroid();
+        let vertex = graph.face_mut(key).unwrap().poke_at_centroid().unwrap();
 
         // Diverging a quadrilateral yields a tetrahedron.
         assert_eq!(4, vertex.adjacent_faces().count());
@@ -1947,7 +2677,7 @@ mod tests {
         assert_eq!(3, face.arity());
 
         // Diverge the triangle.
-        let vertex = face.poke_at_centroid();
+        let vertex = face.poke_at_centroid().unwrap();
 
         assert_eq!(3, vertex.adjacent_faces().count());
     }
@@ -1968,6 +2698,39 @@ mod tests {
         assert_eq!(12, graph.face_count());
     }
 
+    #[test]
+    fn point_at_coordinates() {
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![(0.0, 0.0), (2.0, 0.0), (0.0, 2.0)],
+        )
+        .unwrap();
+        let face = graph.faces().nth(0).unwrap();
+        let positions = face
+            .adjacent_vertices()
+            .map(|vertex| *vertex.position())
+            .collect::<Vec<_>>();
+
+        // Unit coordinates select the positions of vertices.
+        assert_eq!(positions[1], face.point_at(&[0.0, 1.0, 0.0]).unwrap());
+        // Uniform coordinates select the centroid.
+        let third = 1.0 / 3.0;
+        let centroid = face.point_at(&[third, third, third]).unwrap();
+        assert!((centroid - face.centroid()).norm() < 1e-9);
+        // Coordinates must match the arity of the face and sum to one.
+        assert_eq!(
+            GraphError::ArityConflict {
+                expected: 3,
+                actual: 2
+            },
+            face.point_at(&[0.5, 0.5]).unwrap_err()
+        );
+        assert_eq!(
+            GraphError::Geometry,
+            face.point_at(&[0.5, 0.5, 0.5]).unwrap_err()
+        );
+    }
+
     #[test]
     fn logical_metrics() {
         let graph = MeshGraph::<Point2<f32>>::from_raw_buffers_with_arity(
@@ -1985,5 +2748,44 @@ mod tests {
         assert_eq!(2, ring.shortest_logical_metric(keys[0], keys[2]).unwrap());
         assert_eq!(1, ring.shortest_logical_metric(keys[0], keys[3]).unwrap());
         assert_eq!(0, ring.shortest_logical_metric(keys[0], keys[0]).unwrap());
+        assert_eq!(
+            Err(GraphError::TopologyNotFound(TopologyKind::Vertex)),
+            ring.shortest_logical_metric(keys[0], ByIndex(4))
+        );
+    }
+
+    #[test]
+    fn merge_non_adjacent_faces() {
+        let mut graph = MeshGraph::<E2>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 3, 4, 5, 6, 7],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (1.0, 1.0),
+                (0.0, 1.0),
+                (2.0, 0.0),
+                (3.0, 0.0),
+                (3.0, 1.0),
+                (2.0, 1.0),
+            ],
+            4,
+        )
+        .unwrap();
+        let abc = graph.faces().nth(0).unwrap().key();
+        let def = graph.faces().nth(1).unwrap().key();
+
+        assert_eq!(
+            GraphError::TopologyNotFound(TopologyKind::Arc),
+            graph.face_mut(abc).unwrap().merge(def).err().unwrap()
+        );
+        assert_eq!(
+            GraphError::TopologyNotFound(TopologyKind::Face),
+            graph
+                .face_mut(abc)
+                .unwrap()
+                .merge(ByIndex(0))
+                .err()
+                .unwrap()
+        );
     }
 }
@@ -0,0 +1,356 @@
+use decorum::R64;
+use num::{NumCast, ToPrimitive};
+use std::collections::HashMap;
+use std::vec;
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar};
+use theon::AsPosition;
+use typenum::U3;
+
+use crate::entity::dijkstra::{self, MetricTree};
+use crate::entity::storage::KeyIndex;
+use crate::graph::data::GraphData;
+use crate::graph::geometry::VertexPosition;
+use crate::graph::vertex::VertexKey;
+use crate::graph::{GraphError, MeshGraph};
+use crate::metric::{self, CotangentLaplacian};
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Gets the shortest path between two vertices as a sequence of vertex
+    /// keys.
+    ///
+    /// Edges are weighted by their length, so the path minimizes the distance
+    /// travelled along edges rather than the number of edges. The path begins
+    /// with `from` and ends with `to`.
+    ///
+    /// Returns `None` if either vertex is not in the graph or if `to` is not
+    /// reachable from `from`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+    /// let from = graph.vertices().nth(0).unwrap();
+    /// let to = graph
+    ///     .vertices()
+    ///     .find(|vertex| *vertex.position() == Point3::from(-from.position().coords))
+    ///     .unwrap();
+    ///
+    /// let keys = graph.shortest_path(from.key(), to.key()).unwrap();
+    /// assert_eq!(4, keys.len());
+    /// ```
+    pub fn shortest_path(&self, from: VertexKey<K>, to: VertexKey<K>) -> Option<Vec<VertexKey<K>>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Scalar<VertexPosition<G>>: NumCast,
+    {
+        let metrics = self.edge_length_metrics(from, Some(to))?;
+        let mut key = to;
+        let mut keys = vec![key];
+        while let (Some(previous), _) = metrics.get(&key)? {
+            key = *previous;
+            keys.push(key);
+        }
+        keys.reverse();
+        Some(keys)
+    }
+
+    /// Gets the distance along edges from a vertex to every vertex that is
+    /// reachable from it.
+    ///
+    /// Edges are weighted by their length. The map includes `from` itself
+    /// with a distance of zero and excludes vertices that are not reachable.
+    /// The map is empty if `from` is not in the graph.
+    pub fn distance_field(
+        &self,
+        from: VertexKey<K>,
+    ) -> HashMap<VertexKey<K>, Scalar<VertexPosition<G>>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Scalar<VertexPosition<G>>: NumCast,
+    {
+        self.edge_length_metrics(from, None)
+            .into_iter()
+            .flatten()
+            .map(|(key, (_, distance))| {
+                let distance = <Scalar<VertexPosition<G>> as NumCast>::from(distance.into_inner());
+                (key, distance.expect("distance not representable"))
+            })
+            .collect()
+    }
+
+    /// Gets the geodesic distance from the nearest of the given source
+    /// vertices to every vertex in the graph using the heat method.
+    ///
+    /// Unlike [`MeshGraph::distance_field`], distances are not restricted to
+    /// paths along edges, so they vary smoothly across the interiors of faces.
+    /// This uses [`metric::heat_geodesics`] with a time of the square of the
+    /// mean edge length of the graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a source vertex is not found or no source vertices
+    /// are given, if the graph has any non-triangular faces, or if geometry is
+    /// degenerate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let graph: MeshGraph<E3> = UvSphere::new(32, 16)
+    ///     .polygons::<Position<Point3<R64>>>()
+    ///     .triangulate()
+    ///     .collect();
+    /// let key = graph.vertices().nth(0).unwrap().key();
+    /// let distances = graph.geodesic_heat(&[key]).unwrap();
+    /// ```
+    ///
+    /// [`metric::heat_geodesics`]: crate::metric::heat_geodesics
+    /// [`MeshGraph::distance_field`]: crate::graph::MeshGraph::distance_field
+    pub fn geodesic_heat(
+        &self,
+        sources: &[VertexKey<K>],
+    ) -> Result<HashMap<VertexKey<K>, f64>, GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let length = self
+            .edge_length_stats()
+            .and_then(|(_, _, mean)| mean.to_f64())
+            .ok_or(GraphError::Geometry)?;
+        metric::heat_geodesics(self, sources, length * length)
+    }
+
+    /// Gets the cotangent Laplacian and (lumped) mass matrix of the graph.
+    ///
+    /// Vertices are densely indexed and the mapping between indices and
+    /// vertex keys is provided by the returned [`CotangentLaplacian`]. Its
+    /// sparse matrices can be exported as triplets for use with external
+    /// solvers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph has any non-triangular faces or if
+    /// geometry is degenerate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// let graph: MeshGraph<Point3<f64>> = Cube::new()
+    ///     .polygons::<Position<Point3<R64>>>()
+    ///     .triangulate()
+    ///     .collect();
+    /// let laplacian = graph.cotangent_laplacian().unwrap();
+    /// let triplets = laplacian
+    ///     .laplacian()
+    ///     .triplets()
+    ///     .map(|(row, column, value)| (laplacian.keys()[row], laplacian.keys()[column], value))
+    ///     .collect::<Vec<_>>();
+    /// ```
+    ///
+    /// [`CotangentLaplacian`]: crate::metric::CotangentLaplacian
+    pub fn cotangent_laplacian(&self) -> Result<CotangentLaplacian<K>, GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        CotangentLaplacian::from_graph(self)
+    }
+
+    fn edge_length_metrics(
+        &self,
+        from: VertexKey<K>,
+        to: Option<VertexKey<K>>,
+    ) -> Option<MetricTree<VertexKey<K>, R64>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Scalar<VertexPosition<G>>: NumCast,
+    {
+        let vertex = self.vertex(from)?;
+        dijkstra::metrics_with(vertex, to, |a, b| {
+            let length = (*b.position() - *a.position()).magnitude();
+            R64::from_inner(length.to_f64().expect("length not representable"))
+        })
+        .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::{Point2, Point3};
+
+    use crate::graph::{GraphError, MeshGraph, TopologyKind};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+    use crate::primitive::NGon;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn shortest_path_and_distance_field() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let from = graph.vertices().nth(0).unwrap();
+        let to = graph
+            .vertices()
+            .find(|vertex| *vertex.position() == Point3::from(-from.position().coords))
+            .unwrap();
+
+        // Opposite corners of a cube are three edges apart.
+        let keys = graph.shortest_path(from.key(), to.key()).unwrap();
+        assert_eq!(4, keys.len());
+        assert_eq!(from.key(), keys[0]);
+        assert_eq!(to.key(), keys[3]);
+        assert!(graph.path(&keys).is_ok());
+        assert_eq!(
+            Some(vec![from.key()]),
+            graph.shortest_path(from.key(), from.key())
+        );
+
+        // Distances along the edges of a cube are Manhattan distances.
+        let distances = graph.distance_field(from.key());
+        assert_eq!(8, distances.len());
+        for vertex in graph.vertices() {
+            let expected = (*vertex.position() - *from.position()).abs().sum();
+            assert!((distances[&vertex.key()] - expected).abs() < 1e-9);
+        }
+
+        // Vertices in disjoint components are not reachable.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2]), NGon([3, 4, 5])],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (0.0, 1.0),
+                (2.0, 0.0),
+                (3.0, 0.0),
+                (2.0, 1.0),
+            ],
+        )
+        .unwrap();
+        let keys = graph.vertices().keys().collect::<Vec<_>>();
+        let from = keys[0];
+        let distances = graph.distance_field(from);
+        assert_eq!(3, distances.len());
+        let to = keys
+            .iter()
+            .find(|key| !distances.contains_key(key))
+            .unwrap();
+        assert_eq!(None, graph.shortest_path(from, *to));
+    }
+
+    #[test]
+    fn geodesic_heat() {
+        let graph: MeshGraph<Point3<f64>> = UvSphere::new(32, 16)
+            .polygons::<Position<E3>>()
+            .triangulate()
+            .collect();
+        let source = graph
+            .vertices()
+            .max_by(|a, b| a.position().z.partial_cmp(&b.position().z).unwrap())
+            .unwrap()
+            .key();
+        let (_, _, length) = graph.edge_length_stats().unwrap();
+        let distances = graph.geodesic_heat(&[source]).unwrap();
+        assert_eq!(graph.vertex_count(), distances.len());
+
+        // Geodesic distances on the unit sphere from a pole are polar angles.
+        let error = graph
+            .vertices()
+            .map(|vertex| {
+                let angle = vertex.position().z.clamp(-1.0, 1.0).acos();
+                (distances[&vertex.key()] - angle).abs()
+            })
+            .sum::<f64>()
+            / graph.vertex_count() as f64;
+        assert!(error < length);
+
+        assert_eq!(
+            GraphError::TopologyNotFound(TopologyKind::Vertex),
+            graph.geodesic_heat(&[]).err().unwrap()
+        );
+    }
+
+    #[test]
+    fn cotangent_laplacian() {
+        let graph: MeshGraph<Point3<f64>> = UvSphere::new(16, 8)
+            .polygons::<Position<E3>>()
+            .triangulate()
+            .collect();
+        let laplacian = graph.cotangent_laplacian().unwrap();
+        let matrix = laplacian.laplacian();
+        assert_eq!(graph.vertex_count(), matrix.dimension());
+        assert_eq!(graph.vertex_count(), laplacian.keys().len());
+        for (index, key) in laplacian.keys().iter().enumerate() {
+            assert_eq!(Some(index), laplacian.index(*key));
+        }
+        // Rows sum to zero and the matrix is symmetric.
+        for row in 0..matrix.dimension() {
+            let sum = matrix.row(row).iter().map(|(_, value)| value).sum::<f64>();
+            assert!(sum.abs() < 1e-9);
+        }
+        for (row, column, value) in matrix.triplets() {
+            assert!((matrix.get(column, row) - value).abs() < 1e-9);
+        }
+        // The mass matrix distributes the area of the surface.
+        let area = graph
+            .faces()
+            .map(|face| {
+                let positions = face
+                    .adjacent_vertices()
+                    .map(|vertex| *vertex.position())
+                    .collect::<Vec<_>>();
+                (positions[1] - positions[0])
+                    .cross(&(positions[2] - positions[0]))
+                    .norm()
+                    / 2.0
+            })
+            .sum::<f64>();
+        assert!((laplacian.mass().iter().sum::<f64>() - area).abs() < 1e-9);
+    }
+}
@@ -7,15 +7,17 @@
 // necessary, constraints are specified there so that they do not pollute user
 // code.
 
+use decorum::{Finite, Float, NotNan, Primitive, Real, Total};
+use num::{One, Zero};
 use theon::ops::{Cross, Interpolate, Project};
 use theon::query::Plane;
-use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Vector, VectorSpace};
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector, VectorSpace};
 use theon::{AsPosition, Position};
 use typenum::U3;
 
 use crate::entity::borrow::Reborrow;
 use crate::entity::storage::AsStorage;
-use crate::graph::data::{GraphData, Parametric};
+use crate::graph::data::{GraphData, Index, Parametric};
 use crate::graph::edge::{Arc, ArcView, Edge, ToArc};
 use crate::graph::face::{Face, ToRing};
 use crate::graph::mutation::Consistent;
@@ -25,6 +27,283 @@ use crate::IteratorExt as _;
 
 pub type VertexPosition<G> = Position<<G as GraphData>::Vertex>;
 
+// This only uses inner products and so computes the area of a triangle embedded
+// in a Euclidean space of any dimension.
+#[cfg(feature = "rand")]
+pub(in crate::graph) fn triangle_area<S>(a: S, b: S, c: S) -> Scalar<S>
+where
+    S: EuclideanSpace,
+{
+    let ab = b - a;
+    let ac = c - a;
+    let dot = ab.dot(ac);
+    let square = (ab.square_magnitude() * ac.square_magnitude()) - (dot * dot);
+    if square > Zero::zero() {
+        Real::sqrt(square) / (Scalar::<S>::one() + One::one())
+    }
+    else {
+        Zero::zero()
+    }
+}
+
+// Computes the area of a polygon as half of the magnitude of its vector area,
+// which is the Newell normal in three dimensions. The vector area is a sum of
+// wedge products and its squared magnitude is expanded into inner products, so
+// like `triangle_area` this computes the area of a polygon embedded in a
+// Euclidean space of any dimension. The area is exact for planar polygons,
+// including concave polygons.
+pub(in crate::graph) fn polygon_area<S>(positions: &[S]) -> Scalar<S>
+where
+    S: EuclideanSpace,
+{
+    let zero = Scalar::<S>::zero();
+    let wedges = polygon_wedges(positions);
+    let square = wedges
+        .iter()
+        .flat_map(|ab| wedges.iter().map(move |cd| wedge_dot::<S>(*ab, *cd)))
+        .fold(zero, |square, x| square + x);
+    if square > zero {
+        Real::sqrt(square) / (Scalar::<S>::one() + One::one())
+    }
+    else {
+        zero
+    }
+}
+
+// Triangulates a simple polygon by clipping ears. The orientation of a triangle
+// is the sign of the inner product of its wedge product with the vector area of
+// the polygon, so like `polygon_area` this only uses inner products. Returns
+// triangles as indices into the positions. Any part of the polygon that has no
+// ears, such as a degenerate polygon, is triangulated as a fan.
+pub(in crate::graph) fn polygon_triangles<S>(positions: &[S]) -> Vec<[usize; 3]>
+where
+    S: EuclideanSpace,
+{
+    let zero = Scalar::<S>::zero();
+    let wedges = polygon_wedges(positions);
+    let orientation = |a: usize, b: usize, c: usize| {
+        let abc = (positions[b] - positions[a], positions[c] - positions[a]);
+        wedges
+            .iter()
+            .fold(zero, |sum, cd| sum + wedge_dot::<S>(abc, *cd))
+    };
+    let mut indices = (0..positions.len()).collect::<Vec<_>>();
+    let mut triangles = Vec::with_capacity(positions.len().saturating_sub(2));
+    while indices.len() > 3 {
+        let n = indices.len();
+        let ear = (0..n)
+            .map(|index| {
+                (
+                    indices[(index + n - 1) % n],
+                    index,
+                    indices[(index + 1) % n],
+                )
+            })
+            .find(|(a, index, c)| {
+                let (a, b, c) = (*a, indices[*index], *c);
+                orientation(a, b, c) > zero
+                    && indices.iter().all(|&p| {
+                        p == a
+                            || p == b
+                            || p == c
+                            || orientation(a, b, p) < zero
+                            || orientation(b, c, p) < zero
+                            || orientation(c, a, p) < zero
+                    })
+            });
+        if let Some((a, index, c)) = ear {
+            triangles.push([a, indices[index], c]);
+            indices.remove(index);
+        }
+        else {
+            break;
+        }
+    }
+    if let Some((&a, bc)) = indices.split_first() {
+        triangles.extend(bc.windows(2).map(|bc| [a, bc[0], bc[1]]));
+    }
+    triangles
+}
+
+// Gets pairs of vectors from the leading position of a polygon to the
+// endpoints of each of its other edges. The vector area of the polygon is half
+// of the sum of the wedge products of these pairs.
+fn polygon_wedges<S>(positions: &[S]) -> Vec<(Vector<S>, Vector<S>)>
+where
+    S: EuclideanSpace,
+{
+    match positions.split_first() {
+        Some((origin, positions)) => positions
+            .windows(2)
+            .map(|bc| (bc[0] - *origin, bc[1] - *origin))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+// Computes the inner product of the wedge products `a ∧ b` and `c ∧ d`.
+fn wedge_dot<S>((a, b): (Vector<S>, Vector<S>), (c, d): (Vector<S>, Vector<S>)) -> Scalar<S>
+where
+    S: EuclideanSpace,
+{
+    (a.dot(c) * b.dot(d)) - (a.dot(d) * b.dot(c))
+}
+
+// Like `triangle_area`, this only uses inner products and so computes the
+// interior angle at `a` of a triangle embedded in a Euclidean space of any
+// dimension.
+pub(in crate::graph) fn triangle_angle<S>(a: S, b: S, c: S) -> Scalar<S>
+where
+    S: EuclideanSpace,
+{
+    vector_angle::<S>(b - a, c - a)
+}
+
+// Computes the angle between two vectors. The angle is zero if either vector
+// is zero.
+pub(in crate::graph) fn vector_angle<S>(u: Vector<S>, v: Vector<S>) -> Scalar<S>
+where
+    S: EuclideanSpace,
+{
+    let square = u.square_magnitude() * v.square_magnitude();
+    if square > Zero::zero() {
+        let one = Scalar::<S>::one();
+        let cosine = u.dot(v) / Real::sqrt(square);
+        // Clamp the cosine to the domain of `acos` to avoid rounding errors.
+        let cosine = if cosine > one {
+            one
+        }
+        else if cosine < -one {
+            -one
+        }
+        else {
+            cosine
+        };
+        Real::acos(cosine)
+    }
+    else {
+        Zero::zero()
+    }
+}
+
+/// Linear interpolation of vertex data.
+///
+/// Unlike [`EdgeMidpoint`], which only computes positions, this trait
+/// interpolates entire values and so can blend arbitrary vertex data, such as
+/// colors and texture coordinates. This is used by functions like
+/// [`ArcView::split_interpolated`] and must be implemented by the `Vertex` type
+/// of a [`GraphData`] implementation to use them.
+///
+/// This trait is implemented for floating-point types, tuples and arrays of
+/// interpolable types, and the point types of integrated geometry crates.
+///
+/// # Examples
+///
+/// Implementing `Lerp` for vertex data with a position and color:
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::Point3;
+/// use plexus::graph::Lerp;
+///
+/// #[derive(Clone, Copy)]
+/// pub struct Vertex {
+///     pub position: Point3<f64>,
+///     pub color: [f64; 3],
+/// }
+///
+/// impl Lerp for Vertex {
+///     fn lerp(self, other: Self, t: f64) -> Self {
+///         Vertex {
+///             position: Lerp::lerp(self.position, other.position, t),
+///             color: Lerp::lerp(self.color, other.color, t),
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`ArcView::split_interpolated`]: crate::graph::ArcView::split_interpolated
+/// [`EdgeMidpoint`]: crate::graph::EdgeMidpoint
+/// [`GraphData`]: crate::graph::GraphData
+pub trait Lerp: Sized {
+    /// Linearly interpolates between `self` and `other`.
+    ///
+    /// The parameter `t` is typically in the interval $[0,1]$, where zero
+    /// yields `self` and one yields `other`.
+    fn lerp(self, other: Self, t: f64) -> Self;
+
+    /// Computes the midpoint of `self` and `other`.
+    fn midpoint(self, other: Self) -> Self {
+        self.lerp(other, 0.5)
+    }
+}
+
+impl Lerp for () {
+    fn lerp(self, _: Self, _: f64) -> Self {}
+}
+
+impl Lerp for f32 {
+    fn lerp(self, other: Self, t: f64) -> Self {
+        self + ((other - self) * t as f32)
+    }
+}
+
+impl Lerp for f64 {
+    fn lerp(self, other: Self, t: f64) -> Self {
+        self + ((other - self) * t)
+    }
+}
+
+macro_rules! impl_lerp_proxy {
+    (proxy => $p:ident) => {
+        impl<T> Lerp for $p<T>
+        where
+            T: Float + Lerp + Primitive,
+        {
+            fn lerp(self, other: Self, t: f64) -> Self {
+                $p::<T>::from_inner(self.into_inner().lerp(other.into_inner(), t))
+            }
+        }
+    };
+}
+impl_lerp_proxy!(proxy => Finite);
+impl_lerp_proxy!(proxy => NotNan);
+impl_lerp_proxy!(proxy => Total);
+
+impl<T> Lerp for (T, T)
+where
+    T: Lerp,
+{
+    fn lerp(self, other: Self, t: f64) -> Self {
+        (self.0.lerp(other.0, t), self.1.lerp(other.1, t))
+    }
+}
+
+impl<T> Lerp for (T, T, T)
+where
+    T: Lerp,
+{
+    fn lerp(self, other: Self, t: f64) -> Self {
+        (
+            self.0.lerp(other.0, t),
+            self.1.lerp(other.1, t),
+            self.2.lerp(other.2, t),
+        )
+    }
+}
+
+impl<T, const N: usize> Lerp for [T; N]
+where
+    T: Lerp,
+{
+    fn lerp(self, other: Self, t: f64) -> Self {
+        let mut other = other.into_iter();
+        self.map(|a| a.lerp(other.next().expect("array length mismatch"), t))
+    }
+}
+
 pub trait VertexCentroid: GraphData
 where
     Self::Vertex: AsPosition,
@@ -32,8 +311,10 @@ where
     fn centroid<B>(vertex: VertexView<B>) -> Result<VertexPosition<Self>, GraphError>
     where
         B: Reborrow,
-        B::Target:
-            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>;
+        B::Target: AsStorage<Arc<Self, Index<B>>>
+            + AsStorage<Vertex<Self, Index<B>>>
+            + Consistent
+            + Parametric<Data = Self>;
 }
 
 impl<G> VertexCentroid for G
@@ -44,8 +325,10 @@ where
     fn centroid<B>(vertex: VertexView<B>) -> Result<VertexPosition<Self>, GraphError>
     where
         B: Reborrow,
-        B::Target:
-            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
+        B::Target: AsStorage<Arc<Self, Index<B>>>
+            + AsStorage<Vertex<Self, Index<B>>>
+            + Consistent
+            + Parametric<Data = Self>,
     {
         Ok(VertexPosition::<Self>::centroid(
             vertex
@@ -63,9 +346,9 @@ where
     fn normal<B>(vertex: VertexView<B>) -> Result<Vector<VertexPosition<Self>>, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Arc<Self>>
-            + AsStorage<Face<Self>>
-            + AsStorage<Vertex<Self>>
+        B::Target: AsStorage<Arc<Self, Index<B>>>
+            + AsStorage<Face<Self, Index<B>>>
+            + AsStorage<Vertex<Self, Index<B>>>
             + Consistent
             + Parametric<Data = Self>;
 }
@@ -78,9 +361,9 @@ where
     fn normal<B>(vertex: VertexView<B>) -> Result<Vector<VertexPosition<Self>>, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Arc<Self>>
-            + AsStorage<Face<Self>>
-            + AsStorage<Vertex<Self>>
+        B::Target: AsStorage<Arc<Self, Index<B>>>
+            + AsStorage<Face<Self, Index<B>>>
+            + AsStorage<Vertex<Self, Index<B>>>
             + Consistent
             + Parametric<Data = Self>,
     {
@@ -103,8 +386,10 @@ where
     fn normal<B>(arc: ArcView<B>) -> Result<Vector<VertexPosition<Self>>, GraphError>
     where
         B: Reborrow,
-        B::Target:
-            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>;
+        B::Target: AsStorage<Arc<Self, Index<B>>>
+            + AsStorage<Vertex<Self, Index<B>>>
+            + Consistent
+            + Parametric<Data = Self>;
 }
 
 impl<G> ArcNormal for G
@@ -117,8 +402,10 @@ where
     fn normal<B>(arc: ArcView<B>) -> Result<Vector<VertexPosition<Self>>, GraphError>
     where
         B: Reborrow,
-        B::Target:
-            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
+        B::Target: AsStorage<Arc<Self, Index<B>>>
+            + AsStorage<Vertex<Self, Index<B>>>
+            + Consistent
+            + Parametric<Data = Self>,
     {
         let (a, b) = arc
             .adjacent_vertices()
@@ -140,9 +427,9 @@ where
     fn midpoint<B, T>(edge: T) -> Result<VertexPosition<Self>, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Arc<Self>>
-            + AsStorage<Edge<Self>>
-            + AsStorage<Vertex<Self>>
+        B::Target: AsStorage<Arc<Self, Index<B>>>
+            + AsStorage<Edge<Self, Index<B>>>
+            + AsStorage<Vertex<Self, Index<B>>>
             + Consistent
             + Parametric<Data = Self>,
         T: ToArc<B>;
@@ -157,9 +444,9 @@ where
     fn midpoint<B, T>(edge: T) -> Result<VertexPosition<Self>, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Arc<Self>>
-            + AsStorage<Edge<Self>>
-            + AsStorage<Vertex<Self>>
+        B::Target: AsStorage<Arc<Self, Index<B>>>
+            + AsStorage<Edge<Self, Index<B>>>
+            + AsStorage<Vertex<Self, Index<B>>>
             + Consistent
             + Parametric<Data = Self>,
         T: ToArc<B>,
@@ -181,8 +468,10 @@ where
     fn centroid<B, T>(ring: T) -> Result<VertexPosition<Self>, GraphError>
     where
         B: Reborrow,
-        B::Target:
-            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
+        B::Target: AsStorage<Arc<Self, Index<B>>>
+            + AsStorage<Vertex<Self, Index<B>>>
+            + Consistent
+            + Parametric<Data = Self>,
         T: ToRing<B>;
 }
 
@@ -194,8 +483,10 @@ where
     fn centroid<B, T>(ring: T) -> Result<VertexPosition<Self>, GraphError>
     where
         B: Reborrow,
-        B::Target:
-            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
+        B::Target: AsStorage<Arc<Self, Index<B>>>
+            + AsStorage<Vertex<Self, Index<B>>>
+            + Consistent
+            + Parametric<Data = Self>,
         T: ToRing<B>,
     {
         let ring = ring.into_ring();
@@ -213,8 +504,10 @@ where
     fn normal<B, T>(ring: T) -> Result<Vector<VertexPosition<Self>>, GraphError>
     where
         B: Reborrow,
-        B::Target:
-            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
+        B::Target: AsStorage<Arc<Self, Index<B>>>
+            + AsStorage<Vertex<Self, Index<B>>>
+            + Consistent
+            + Parametric<Data = Self>,
         T: ToRing<B>;
 }
 
@@ -228,8 +521,10 @@ where
     fn normal<B, T>(ring: T) -> Result<Vector<VertexPosition<Self>>, GraphError>
     where
         B: Reborrow,
-        B::Target:
-            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
+        B::Target: AsStorage<Arc<Self, Index<B>>>
+            + AsStorage<Vertex<Self, Index<B>>>
+            + Consistent
+            + Parametric<Data = Self>,
         T: ToRing<B>,
     {
         let ring = ring.into_ring();
@@ -254,8 +549,10 @@ where
     fn plane<B, T>(ring: T) -> Result<Plane<VertexPosition<Self>>, GraphError>
     where
         B: Reborrow,
-        B::Target:
-            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
+        B::Target: AsStorage<Arc<Self, Index<B>>>
+            + AsStorage<Vertex<Self, Index<B>>>
+            + Consistent
+            + Parametric<Data = Self>,
         T: ToRing<B>;
 }
 
@@ -288,8 +585,8 @@ where
 //        fn plane<B, T>(ring: T) -> Result<Plane<VertexPosition<G>>, GraphError>
 //        where
 //            B: Reborrow,
-//            B::Target: AsStorage<Arc<Self>>
-//                + AsStorage<Vertex<Self>>
+//            B::Target: AsStorage<Arc<Self, Index<B>>>
+//                + AsStorage<Vertex<Self, Index<B>>>
 //                + Consistent
 //                + Parametric<Data = Self>,
 //            T: ToRing<B>,
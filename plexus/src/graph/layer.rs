@@ -0,0 +1,779 @@
+use derivative::Derivative;
+use smallvec::SmallVec;
+use std::any::Any;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{Index, IndexMut};
+
+use crate::entity::storage::KeyIndex;
+use crate::graph::data::GraphData;
+use crate::graph::edge::EdgeKey;
+use crate::graph::face::FaceKey;
+use crate::graph::vertex::VertexKey;
+use crate::graph::MeshGraph;
+
+/// Key of a [`Layer`] in a [`MeshGraph`].
+///
+/// The key is typed by the key of the topology that the layer is associated
+/// with (such as [`VertexKey`]) and the type of the values in the layer.
+///
+/// [`Layer`]: crate::graph::Layer
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`VertexKey`]: crate::graph::VertexKey
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = ""),
+    Copy(bound = ""),
+    Debug(bound = ""),
+    Eq(bound = ""),
+    Hash(bound = ""),
+    PartialEq(bound = "")
+)]
+pub struct LayerKey<K, T> {
+    index: usize,
+    phantom: PhantomData<fn() -> (K, T)>,
+}
+
+/// Attribute layer associated with topology in a [`MeshGraph`].
+///
+/// A layer stores a value of type `T` for each vertex, edge, or face (keyed by
+/// `K`) in a graph without requiring that value to be a part of the graph's
+/// [`GraphData`]. This is useful for transient or application-specific data,
+/// such as selections and weights. Topology that has no explicitly assigned
+/// value has the default value of the layer, so topology that is inserted into
+/// a graph has the default value unless the layer interpolates values. Values
+/// of topology that is removed from a graph are discarded.
+///
+/// See [`MeshGraph::create_vertex_layer`] and
+/// [`MeshGraph::create_vertex_layer_with`].
+///
+/// [`GraphData`]: crate::graph::GraphData
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::create_vertex_layer`]: crate::graph::MeshGraph::create_vertex_layer
+/// [`MeshGraph::create_vertex_layer_with`]: crate::graph::MeshGraph::create_vertex_layer_with
+pub struct Layer<K, T> {
+    name: String,
+    default: T,
+    values: HashMap<K, T>,
+    interpolation: Option<Interpolation<T>>,
+}
+
+type Interpolation<T> = Box<dyn Fn(&[&T]) -> T + Send + Sync>;
+
+impl<K, T> Layer<K, T>
+where
+    K: Copy + Eq + Hash,
+{
+    pub(in crate::graph) fn new(name: String, default: T) -> Self {
+        Layer {
+            name,
+            default,
+            values: HashMap::new(),
+            interpolation: None,
+        }
+    }
+
+    pub(in crate::graph) fn with_interpolation<F>(name: String, default: T, f: F) -> Self
+    where
+        F: 'static + Fn(&[&T]) -> T + Send + Sync,
+    {
+        Layer {
+            interpolation: Some(Box::new(f)),
+            ..Layer::new(name, default)
+        }
+    }
+
+    /// Gets the name of the layer.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the default value of the layer.
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Gets the value of the given topology.
+    pub fn get(&self, key: K) -> &T {
+        self.values.get(&key).unwrap_or(&self.default)
+    }
+
+    /// Gets the mutable value of the given topology.
+    pub fn get_mut(&mut self, key: K) -> &mut T
+    where
+        T: Clone,
+    {
+        let default = &self.default;
+        self.values.entry(key).or_insert_with(|| default.clone())
+    }
+
+    /// Sets the value of the given topology.
+    ///
+    /// Returns the previously assigned value, if any.
+    pub fn insert(&mut self, key: K, value: T) -> Option<T> {
+        self.values.insert(key, value)
+    }
+
+    /// Resets the value of the given topology to the default value of the
+    /// layer.
+    ///
+    /// Returns the previously assigned value, if any.
+    pub fn remove(&mut self, key: K) -> Option<T> {
+        self.values.remove(&key)
+    }
+
+    /// Gets an iterator over the topology and values that have been assigned
+    /// in the layer.
+    pub fn iter(&self) -> impl '_ + Iterator<Item = (K, &T)> {
+        self.values.iter().map(|(key, value)| (*key, value))
+    }
+}
+
+impl<K, T> Index<K> for Layer<K, T>
+where
+    K: Copy + Eq + Hash,
+{
+    type Output = T;
+
+    fn index(&self, key: K) -> &Self::Output {
+        self.get(key)
+    }
+}
+
+impl<K, T> IndexMut<K> for Layer<K, T>
+where
+    K: Copy + Eq + Hash,
+    T: Clone,
+{
+    fn index_mut(&mut self, key: K) -> &mut Self::Output {
+        self.get_mut(key)
+    }
+}
+
+/// Topology with which a [`Layer`] can be associated.
+///
+/// This trait is implemented by [`VertexKey`], [`EdgeKey`], and [`FaceKey`].
+///
+/// [`EdgeKey`]: crate::graph::EdgeKey
+/// [`FaceKey`]: crate::graph::FaceKey
+/// [`Layer`]: crate::graph::Layer
+/// [`VertexKey`]: crate::graph::VertexKey
+pub trait Layered: 'static + Copy + Eq + Hash + Send + Sync {
+    #[doc(hidden)]
+    type Index: KeyIndex;
+
+    #[doc(hidden)]
+    fn layers(layers: &Layers<Self::Index>) -> &LayerSet<Self>;
+
+    #[doc(hidden)]
+    fn layers_mut(layers: &mut Layers<Self::Index>) -> &mut LayerSet<Self>;
+}
+
+impl<K> Layered for VertexKey<K>
+where
+    K: KeyIndex,
+{
+    type Index = K;
+
+    fn layers(layers: &Layers<K>) -> &LayerSet<Self> {
+        &layers.vertices
+    }
+
+    fn layers_mut(layers: &mut Layers<K>) -> &mut LayerSet<Self> {
+        &mut layers.vertices
+    }
+}
+
+impl<K> Layered for EdgeKey<K>
+where
+    K: KeyIndex,
+{
+    type Index = K;
+
+    fn layers(layers: &Layers<K>) -> &LayerSet<Self> {
+        &layers.edges
+    }
+
+    fn layers_mut(layers: &mut Layers<K>) -> &mut LayerSet<Self> {
+        &mut layers.edges
+    }
+}
+
+impl<K> Layered for FaceKey<K>
+where
+    K: KeyIndex,
+{
+    type Index = K;
+
+    fn layers(layers: &Layers<K>) -> &LayerSet<Self> {
+        &layers.faces
+    }
+
+    fn layers_mut(layers: &mut Layers<K>) -> &mut LayerSet<Self> {
+        &mut layers.faces
+    }
+}
+
+// Type-erased layer. Values are discarded via `discard` when topology is
+// removed from a graph, initialized via `interpolate` when topology is derived
+// from other topology, and migrated via `rekey` when a graph is compacted.
+trait Erased<K>: Any + Send + Sync {
+    fn discard(&mut self, key: &K);
+
+    fn interpolate(&mut self, key: K, sources: &[K]);
+
+    fn rekey(&mut self, keys: &HashMap<K, K>);
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<K, T> Erased<K> for Layer<K, T>
+where
+    K: 'static + Copy + Eq + Hash + Send + Sync,
+    T: 'static + Send + Sync,
+{
+    fn discard(&mut self, key: &K) {
+        self.values.remove(key);
+    }
+
+    fn interpolate(&mut self, key: K, sources: &[K]) {
+        if let Some(f) = self.interpolation.as_ref() {
+            let values = sources
+                .iter()
+                .map(|key| self.get(*key))
+                .collect::<SmallVec<[_; 4]>>();
+            let value = f(values.as_slice());
+            self.values.insert(key, value);
+        }
+    }
+
+    fn rekey(&mut self, keys: &HashMap<K, K>) {
+        self.values = mem::take(&mut self.values)
+            .into_iter()
+            .filter_map(|(key, value)| keys.get(&key).map(|key| (*key, value)))
+            .collect();
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+#[doc(hidden)]
+pub struct LayerSet<K> {
+    layers: Vec<Option<Box<dyn Erased<K>>>>,
+}
+
+impl<K> LayerSet<K>
+where
+    K: Layered,
+{
+    pub(in crate::graph) fn insert<T>(&mut self, layer: Layer<K, T>) -> LayerKey<K, T>
+    where
+        T: 'static + Send + Sync,
+    {
+        self.layers.push(Some(Box::new(layer)));
+        LayerKey {
+            index: self.layers.len() - 1,
+            phantom: PhantomData,
+        }
+    }
+
+    pub(in crate::graph) fn get<T>(&self, key: LayerKey<K, T>) -> Option<&Layer<K, T>>
+    where
+        T: 'static,
+    {
+        self.layers
+            .get(key.index)?
+            .as_ref()?
+            .as_any()
+            .downcast_ref()
+    }
+
+    pub(in crate::graph) fn get_mut<T>(&mut self, key: LayerKey<K, T>) -> Option<&mut Layer<K, T>>
+    where
+        T: 'static,
+    {
+        self.layers
+            .get_mut(key.index)?
+            .as_mut()?
+            .as_any_mut()
+            .downcast_mut()
+    }
+
+    pub(in crate::graph) fn remove<T>(&mut self, key: LayerKey<K, T>) -> Option<Layer<K, T>>
+    where
+        T: 'static,
+    {
+        self.get(key)?;
+        self.layers[key.index]
+            .take()?
+            .into_any()
+            .downcast()
+            .ok()
+            .map(|layer| *layer)
+    }
+
+    pub(in crate::graph) fn discard(&mut self, key: &K) {
+        for layer in self.layers.iter_mut().flatten() {
+            layer.discard(key);
+        }
+    }
+
+    pub(in crate::graph) fn interpolate(&mut self, key: K, sources: &[K]) {
+        for layer in self.layers.iter_mut().flatten() {
+            layer.interpolate(key, sources);
+        }
+    }
+
+    pub(in crate::graph) fn rekey(&mut self, keys: &HashMap<K, K>) {
+        for layer in self.layers.iter_mut().flatten() {
+            layer.rekey(keys);
+        }
+    }
+}
+
+impl<K> Default for LayerSet<K> {
+    fn default() -> Self {
+        LayerSet { layers: Vec::new() }
+    }
+}
+
+/// Attribute layers of a graph.
+#[doc(hidden)]
+pub struct Layers<K>
+where
+    K: KeyIndex,
+{
+    pub(in crate::graph) vertices: LayerSet<VertexKey<K>>,
+    pub(in crate::graph) edges: LayerSet<EdgeKey<K>>,
+    pub(in crate::graph) faces: LayerSet<FaceKey<K>>,
+}
+
+impl<K> Default for Layers<K>
+where
+    K: KeyIndex,
+{
+    fn default() -> Self {
+        Layers {
+            vertices: Default::default(),
+            edges: Default::default(),
+            faces: Default::default(),
+        }
+    }
+}
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Creates an attribute layer associated with the vertices of the graph.
+    ///
+    /// Layers store values for topology without requiring those values to be a
+    /// part of the graph's [`GraphData`], such as selections, weights, and
+    /// temporary scalars. Each vertex has the given default value in the layer
+    /// until another value is assigned.
+    ///
+    /// Layers are synchronized with topology when the graph is mutated:
+    /// vertices that are inserted into the graph (e.g., by splitting an edge)
+    /// have the default value and the values of vertices that are removed from
+    /// the graph are discarded. To instead initialize inserted vertices from
+    /// the vertices from which they are derived, use
+    /// [`create_vertex_layer_with`]. Layers are also migrated by [`compact`] and by
+    /// operations that rebuild the graph in place, such as [`weld_vertices`].
+    /// Note that operations that construct a new graph (e.g., [`to_dual`]) do
+    /// not preserve layers. Values must be `Send` and `Sync`, so that graphs
+    /// with layers can be shared across threads.
+    ///
+    /// Returns the key of the layer, which can be used to access it via
+    /// [`layer`] and [`layer_mut`].
+    ///
+    /// # Examples
+    ///
+    /// Selecting vertices:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+    /// let selection = graph.create_vertex_layer("selected", false);
+    /// let key = graph.vertices().nth(0).unwrap().key();
+    /// graph.layer_mut(selection).unwrap()[key] = true;
+    ///
+    /// let selected = graph.layer(selection).unwrap();
+    /// assert_eq!(
+    ///     1,
+    ///     graph
+    ///         .vertices()
+    ///         .filter(|vertex| selected[vertex.key()])
+    ///         .count()
+    /// );
+    /// ```
+    ///
+    /// [`compact`]: crate::graph::MeshGraph::compact
+    /// [`create_vertex_layer_with`]: crate::graph::MeshGraph::create_vertex_layer_with
+    /// [`GraphData`]: crate::graph::GraphData
+    /// [`layer`]: crate::graph::MeshGraph::layer
+    /// [`layer_mut`]: crate::graph::MeshGraph::layer_mut
+    /// [`to_dual`]: crate::graph::MeshGraph::to_dual
+    /// [`weld_vertices`]: crate::graph::MeshGraph::weld_vertices
+    pub fn create_vertex_layer<T>(
+        &mut self,
+        name: impl Into<String>,
+        default: T,
+    ) -> LayerKey<VertexKey<K>, T>
+    where
+        T: 'static + Send + Sync,
+    {
+        self.core
+            .layers
+            .vertices
+            .insert(Layer::new(name.into(), default))
+    }
+
+    /// Creates an attribute layer associated with the vertices of the graph
+    /// that interpolates the values of inserted vertices.
+    ///
+    /// This behaves like [`create_vertex_layer`], but vertices that are
+    /// derived from other vertices are initialized by the given function
+    /// rather than the default value. The function receives the values of the
+    /// source vertices: the endpoints of a split edge, the perimeter of a
+    /// poked face, or the single vertex from which a vertex is extruded.
+    /// Vertices that are inserted without sources have the default value.
+    ///
+    /// # Examples
+    ///
+    /// Averaging weights:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+    /// let weights = graph.create_vertex_layer_with("weight", 0.0, |values| {
+    ///     values.iter().cloned().sum::<f64>() / values.len() as f64
+    /// });
+    /// let (ab, a) = {
+    ///     let arc = graph.arcs().nth(0).unwrap();
+    ///     (arc.key(), arc.source_vertex().key())
+    /// };
+    /// graph.layer_mut(weights).unwrap()[a] = 1.0;
+    ///
+    /// let m = graph
+    ///     .arc_mut(ab)
+    ///     .unwrap()
+    ///     .split_at_midpoint()
+    ///     .unwrap()
+    ///     .key();
+    /// assert_eq!(0.5, graph.layer(weights).unwrap()[m]);
+    /// ```
+    ///
+    /// [`create_vertex_layer`]: crate::graph::MeshGraph::create_vertex_layer
+    pub fn create_vertex_layer_with<T, F>(
+        &mut self,
+        name: impl Into<String>,
+        default: T,
+        f: F,
+    ) -> LayerKey<VertexKey<K>, T>
+    where
+        T: 'static + Send + Sync,
+        F: 'static + Fn(&[&T]) -> T + Send + Sync,
+    {
+        self.core
+            .layers
+            .vertices
+            .insert(Layer::with_interpolation(name.into(), default, f))
+    }
+
+    /// Creates an attribute layer associated with the edges of the graph.
+    ///
+    /// See [`create_vertex_layer`].
+    ///
+    /// [`create_vertex_layer`]: crate::graph::MeshGraph::create_vertex_layer
+    pub fn create_edge_layer<T>(
+        &mut self,
+        name: impl Into<String>,
+        default: T,
+    ) -> LayerKey<EdgeKey<K>, T>
+    where
+        T: 'static + Send + Sync,
+    {
+        self.core
+            .layers
+            .edges
+            .insert(Layer::new(name.into(), default))
+    }
+
+    /// Creates an attribute layer associated with the faces of the graph.
+    ///
+    /// See [`create_vertex_layer`].
+    ///
+    /// [`create_vertex_layer`]: crate::graph::MeshGraph::create_vertex_layer
+    pub fn create_face_layer<T>(
+        &mut self,
+        name: impl Into<String>,
+        default: T,
+    ) -> LayerKey<FaceKey<K>, T>
+    where
+        T: 'static + Send + Sync,
+    {
+        self.core
+            .layers
+            .faces
+            .insert(Layer::new(name.into(), default))
+    }
+
+    /// Gets the attribute layer with the given key.
+    ///
+    /// Returns `None` if the layer has been removed.
+    pub fn layer<L, T>(&self, key: LayerKey<L, T>) -> Option<&Layer<L, T>>
+    where
+        L: Layered<Index = K>,
+        T: 'static,
+    {
+        L::layers(&self.core.layers).get(key)
+    }
+
+    /// Gets the mutable attribute layer with the given key.
+    ///
+    /// Returns `None` if the layer has been removed.
+    pub fn layer_mut<L, T>(&mut self, key: LayerKey<L, T>) -> Option<&mut Layer<L, T>>
+    where
+        L: Layered<Index = K>,
+        T: 'static,
+    {
+        L::layers_mut(&mut self.core.layers).get_mut(key)
+    }
+
+    /// Removes the attribute layer with the given key from the graph.
+    ///
+    /// Returns the removed layer or `None` if the layer has already been
+    /// removed.
+    pub fn remove_layer<L, T>(&mut self, key: LayerKey<L, T>) -> Option<Layer<L, T>>
+    where
+        L: Layered<Index = K>,
+        T: 'static,
+    {
+        L::layers_mut(&mut self.core.layers).remove(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn vertex_layer() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let selection = graph.create_vertex_layer("selected", false);
+        let keys = graph.vertices().keys().collect::<Vec<_>>();
+        for key in keys.iter().step_by(2) {
+            graph.layer_mut(selection).unwrap()[*key] = true;
+        }
+
+        // Split an edge between two vertices. The inserted vertex has the
+        // default value and existing values are untouched.
+        let arc = graph.arcs().nth(0).unwrap().key();
+        let key = graph
+            .arc_mut(arc)
+            .unwrap()
+            .split_at_midpoint()
+            .unwrap()
+            .key();
+        let layer = graph.layer(selection).unwrap();
+        assert_eq!("selected", layer.name());
+        assert!(!layer[key]);
+        for (index, key) in keys.iter().enumerate() {
+            assert_eq!(index % 2 == 0, layer[*key]);
+        }
+
+        // Remove a vertex. Its value is discarded.
+        graph.vertex_mut(keys[0]).unwrap().remove();
+        assert_eq!(3, graph.layer(selection).unwrap().iter().count());
+        assert!(graph
+            .layer(selection)
+            .unwrap()
+            .iter()
+            .all(|(key, _)| graph.vertex(key).is_some()));
+
+        // Compacting the graph rekeys values.
+        let compaction = graph.compact();
+        let layer = graph.layer(selection).unwrap();
+        for (index, key) in keys.iter().enumerate().skip(1) {
+            assert_eq!(index % 2 == 0, layer[compaction.vertices[key]]);
+        }
+
+        assert!(graph.remove_layer(selection).is_some());
+        assert!(graph.layer(selection).is_none());
+    }
+
+    #[test]
+    fn interpolate_vertex_layer() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let weights = graph.create_vertex_layer_with("weight", 0.0f64, |values| {
+            values.iter().cloned().sum::<f64>() / values.len() as f64
+        });
+        let keys = graph.vertices().keys().collect::<Vec<_>>();
+        for (index, key) in keys.iter().enumerate() {
+            graph.layer_mut(weights).unwrap()[*key] = index as f64;
+        }
+        let sorted_weights = |graph: &MeshGraph<Point3<f64>>, face| {
+            let layer = graph.layer(weights).unwrap();
+            let mut values = graph
+                .face(face)
+                .unwrap()
+                .adjacent_vertices()
+                .map(|vertex| layer[vertex.key()])
+                .collect::<Vec<_>>();
+            values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            values
+        };
+
+        // Splitting an edge averages the values of its vertices.
+        let (ab, a, b) = {
+            let arc = graph.arcs().nth(0).unwrap();
+            (
+                arc.key(),
+                arc.source_vertex().key(),
+                arc.destination_vertex().key(),
+            )
+        };
+        let m = graph
+            .arc_mut(ab)
+            .unwrap()
+            .split_at_midpoint()
+            .unwrap()
+            .key();
+        let layer = graph.layer(weights).unwrap();
+        assert_eq!((layer[a] + layer[b]) / 2.0, layer[m]);
+
+        // Poking a face averages the values of its perimeter.
+        let face = graph.faces().nth(0).unwrap().key();
+        let values = sorted_weights(&graph, face);
+        let c = graph
+            .face_mut(face)
+            .unwrap()
+            .poke_at_centroid()
+            .unwrap()
+            .key();
+        assert_eq!(
+            values.iter().sum::<f64>() / values.len() as f64,
+            graph.layer(weights).unwrap()[c]
+        );
+
+        // Extruding a face copies the values of its vertices.
+        let face = graph.faces().nth(0).unwrap().key();
+        let values = sorted_weights(&graph, face);
+        let extrusion = graph
+            .face_mut(face)
+            .unwrap()
+            .extrude_with_offset(1.0)
+            .unwrap()
+            .key();
+        assert_eq!(values, sorted_weights(&graph, extrusion));
+    }
+
+    #[test]
+    fn face_layer() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let weights = graph.create_face_layer("weight", 1.0f64);
+        let edges = graph.create_edge_layer("crease", 0u8);
+        let keys = graph.faces().keys().collect::<Vec<_>>();
+        for key in keys.iter() {
+            graph.layer_mut(weights).unwrap().insert(*key, 2.0);
+        }
+        let edge = graph.edges().nth(0).unwrap().key();
+        graph.layer_mut(edges).unwrap()[edge] = 1;
+
+        // Poking a face removes it and inserts new faces.
+        graph.face_mut(keys[0]).unwrap().poke_at_centroid().unwrap();
+        let layer = graph.layer(weights).unwrap();
+        assert_eq!(5, layer.iter().count());
+        for face in graph.faces() {
+            let expected = if keys.contains(&face.key()) { 2.0 } else { 1.0 };
+            assert_eq!(expected, layer[face.key()]);
+        }
+        assert_eq!(1, graph.layer(edges).unwrap()[edge]);
+
+        // Keys of other kinds of topology or layers do not alias.
+        let other = graph.create_face_layer("other", 0u8);
+        assert!(graph.layer(other).unwrap().iter().next().is_none());
+    }
+
+    #[test]
+    fn rebuild_layers() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let labels = graph.create_vertex_layer("label", 0usize);
+        let positions = graph
+            .vertices()
+            .map(|vertex| (vertex.key(), *vertex.position()))
+            .collect::<Vec<_>>();
+        for (index, (key, _)) in positions.iter().enumerate() {
+            graph.layer_mut(labels).unwrap()[*key] = index + 1;
+        }
+        let weights = graph.create_face_layer("weight", 1.0f64);
+        let face = graph.faces().nth(0).unwrap().key();
+        graph.layer_mut(weights).unwrap()[face] = 2.0;
+
+        // Extruding a face rebuilds the graph. Existing vertices retain their
+        // values and the vertices of the extrusion have the default value.
+        let extrusion = graph.extrude_faces(&[face], 1.0).unwrap()[0];
+        assert_eq!(12, graph.vertex_count());
+        let layer = graph.layer(labels).unwrap();
+        assert_eq!(8, layer.iter().count());
+        for vertex in graph.vertices() {
+            match positions
+                .iter()
+                .position(|(_, position)| position == vertex.position())
+            {
+                Some(index) => assert_eq!(index + 1, layer[vertex.key()]),
+                _ => assert_eq!(0, layer[vertex.key()]),
+            }
+        }
+        assert_eq!(2.0, graph.layer(weights).unwrap()[extrusion]);
+    }
+}
@@ -0,0 +1,735 @@
+use decorum::Real;
+use num::{NumCast, One, Signed, Zero};
+use smallvec::SmallVec;
+use std::cmp;
+use std::iter;
+use std::mem;
+use std::vec;
+use theon::ops::Cross;
+use theon::space::{Basis, EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use theon::AsPosition;
+use typenum::U3;
+
+use crate::entity::storage::KeyIndex;
+use crate::geometry::{FromGeometry, IntoGeometry};
+use crate::graph::data::GraphData;
+use crate::graph::geometry::{Lerp, VertexPosition};
+use crate::graph::mutation::face::{FaceBatchInsertCache, FaceInsertCache};
+use crate::graph::mutation::{self, Immediate};
+use crate::graph::vertex::VertexKey;
+use crate::graph::{GraphError, MeshGraph, ResultExt as _};
+use crate::transact::Transact;
+use crate::IteratorExt as _;
+
+type Mutation<M> = mutation::Mutation<Immediate<M>>;
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Stitches two boundary loops together with quadrilateral faces.
+    ///
+    /// The loops are given as ordered vertices and corresponding vertices
+    /// (those with the same index) are connected by edges. A quadrilateral is
+    /// inserted between each pair of adjacent edges, effectively zipping the
+    /// loops together. This can be used to join separate parts of a graph or
+    /// to close the open ends of tubes.
+    ///
+    /// The orientation of the faces is determined by the boundary arcs of the
+    /// first loop. The loops must be wound in opposing directions, as is the
+    /// case for facing boundaries with consistent orientation. If the second
+    /// loop is ordered in the same direction as the first, then `reverse` can
+    /// be used to reverse its ordering before stitching.
+    ///
+    /// Returns the number of inserted faces.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the loops differ in length, contain fewer than three
+    /// vertices, or if any face cannot be inserted, such as when a loop is not
+    /// a boundary, the loops share vertices, or two faces would claim the same
+    /// arc. In the latter case the error is wrapped with the index of the
+    /// offending face (see [`GraphError::AtIndex`]) and the graph is left
+    /// unmodified. Returns [`GraphError::KeyspaceExhausted`] if the keyspace of
+    /// the graph cannot accommodate the inserted edges and faces (see
+    /// [`KeyIndex`]), in which case the graph is also left unmodified.
+    ///
+    /// [`GraphError::AtIndex`]: crate::graph::GraphError::AtIndex
+    /// [`GraphError::KeyspaceExhausted`]: crate::graph::GraphError::KeyspaceExhausted
+    /// [`KeyIndex`]: crate::graph::KeyIndex
+    pub fn stitch_loops(
+        &mut self,
+        a: &[VertexKey<K>],
+        b: &[VertexKey<K>],
+        reverse: bool,
+    ) -> Result<usize, GraphError> {
+        if a.len() != b.len() {
+            return Err(GraphError::ArityConflict {
+                expected: a.len(),
+                actual: b.len(),
+            });
+        }
+        if a.len() < 3 {
+            return Err(GraphError::ArityNonPolygonal);
+        }
+        let b = if reverse {
+            b.iter().rev().cloned().collect::<Vec<_>>()
+        }
+        else {
+            b.to_vec()
+        };
+        let forward = self
+            .arc((a[0], a[1]).into())
+            .map_or(false, |arc| arc.is_boundary_arc());
+        let faces = a
+            .iter()
+            .cloned()
+            .perimeter()
+            .zip(b.iter().cloned().perimeter())
+            .map(|((a0, a1), (b0, b1))| {
+                if forward {
+                    SmallVec::from_buf([a0, a1, b1, b0])
+                }
+                else {
+                    SmallVec::from_buf([b0, b1, a1, a0])
+                }
+            })
+            .collect::<Vec<_>>();
+        let n = faces.len();
+        // Validate the faces against the graph and one another before mutating
+        // the graph. Each arc may be claimed by at most one face.
+        let cache = FaceBatchInsertCache::from_storage(&*self, faces)?;
+        let mut mutation = Mutation::from(mem::take(self));
+        // This should never fail here.
+        mutation::face::insert_batch_with(
+            &mut mutation,
+            cache,
+            iter::repeat_with(Default::default).take(n),
+        )
+        .expect_consistent();
+        *self = mutation
+            .commit()
+            .map_err(|(_, error)| error)
+            .expect_consistent();
+        Ok(n)
+    }
+
+    /// Creates a graph by sweeping a planar profile along a path.
+    ///
+    /// The profile is a polygon given as two-dimensional coordinates and is
+    /// placed at each point of the path in the plane orthogonal to the path's
+    /// tangent. The orientation of the profile is propagated along the path
+    /// using a rotation minimizing frame (computed via double reflection), so
+    /// the sweep does not twist about the path. Consecutive cross sections are
+    /// connected by quadrilaterals. If `closed` is `true`, then the last cross
+    /// section is connected to the first. Note that the frame of a closed path
+    /// may not align at this connection, in which case the final segment
+    /// absorbs the resulting twist.
+    ///
+    /// The profile should be wound counter-clockwise for the faces of the
+    /// resulting graph to face outward.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profile has fewer than three points, the path
+    /// has too few points (two if open and three if closed), or the tangent of
+    /// the path cannot be computed (e.g., if consecutive points coincide).
+    ///
+    /// # Examples
+    ///
+    /// Sweeping a square along a helix:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let profile = [(-0.1, -0.1), (0.1, -0.1), (0.1, 0.1), (-0.1, 0.1)];
+    /// let path = (0..64)
+    ///     .map(|n| {
+    ///         let angle = n as f64 * 0.2;
+    ///         Point3::new(angle.cos(), angle.sin(), n as f64 * 0.05)
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let graph = MeshGraph::<E3>::sweep(&profile, &path, false).unwrap();
+    /// ```
+    pub fn sweep<T>(
+        profile: &[(T, T)],
+        path: &[VertexPosition<G>],
+        closed: bool,
+    ) -> Result<Self, GraphError>
+    where
+        T: Copy + Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPosition + FromGeometry<VertexPosition<G>>,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Basis + Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        if profile.len() < 3 {
+            return Err(GraphError::ArityNonPolygonal);
+        }
+        let n = path.len();
+        if n < 2 || (closed && n < 3) {
+            return Err(GraphError::TopologyMalformed);
+        }
+        let two = Scalar::<VertexPosition<G>>::one() + One::one();
+        let tangents = (0..n)
+            .map(|i| {
+                let (previous, next) = if closed {
+                    ((i + n - 1) % n, (i + 1) % n)
+                }
+                else {
+                    (i.saturating_sub(1), cmp::min(i + 1, n - 1))
+                };
+                (path[next] - path[previous])
+                    .normalize()
+                    .ok_or(GraphError::Geometry)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // Choose an initial normal from the basis vector that is least aligned
+        // with the initial tangent.
+        let t = tangents[0];
+        let axis = Vector::<VertexPosition<G>>::canonical_basis()
+            .into_iter()
+            .map(|axis| {
+                let dot = t.dot(axis);
+                (axis, dot * dot)
+            })
+            .fold(
+                None,
+                |min: Option<(_, Scalar<VertexPosition<G>>)>, (axis, dot)| match min {
+                    Some(min) if min.1 <= dot => Some(min),
+                    _ => Some((axis, dot)),
+                },
+            )
+            .map(|(axis, _)| axis)
+            .ok_or(GraphError::Geometry)?;
+        let mut normals = Vec::with_capacity(n);
+        normals.push(
+            (axis - (t * t.dot(axis)))
+                .normalize()
+                .ok_or(GraphError::Geometry)?,
+        );
+        // Propagate the normal using the double reflection method.
+        for i in 1..n {
+            let r = normals[i - 1];
+            let v = path[i] - path[i - 1];
+            let c = v.dot(v);
+            if c <= Zero::zero() {
+                return Err(GraphError::Geometry);
+            }
+            let r = r - (v * ((two / c) * v.dot(r)));
+            let t = tangents[i - 1] - (v * ((two / c) * v.dot(tangents[i - 1])));
+            let v = tangents[i] - t;
+            let c = v.dot(v);
+            normals.push(if c > Zero::zero() {
+                r - (v * ((two / c) * v.dot(r)))
+            }
+            else {
+                r
+            });
+        }
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let sections = path
+            .iter()
+            .zip(tangents.iter().zip(normals.iter()))
+            .map(|(origin, (t, normal))| {
+                let binormal = t.cross(*normal);
+                profile
+                    .iter()
+                    .map(|&(x, y)| {
+                        let (x, y): (Scalar<VertexPosition<G>>, _) = (x.into(), y.into());
+                        let position = *origin + (*normal * x) + (binormal * y);
+                        mutation::vertex::insert(&mut mutation, position.into_geometry())
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, GraphError>>()?;
+        let count = if closed { n } else { n - 1 };
+        for i in 0..count {
+            let (a, b) = (&sections[i], &sections[(i + 1) % n]);
+            for (j, k) in (0..profile.len()).perimeter() {
+                let cache = FaceInsertCache::from_storage(&mutation, &[a[j], a[k], b[k], b[j]])?;
+                mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+            }
+        }
+        mutation.commit().map_err(|(_, error)| error)
+    }
+
+    /// Creates a closed graph by lofting between two profiles.
+    ///
+    /// The profiles are polygons given as vertex data with the same number of
+    /// vertices. Corresponding vertices of the profiles are connected by
+    /// quadrilaterals and both ends are capped with a face formed from the
+    /// profile. The profiles need not be parallel. If `subdivisions` is
+    /// non-zero, then that many intermediate cross sections are interpolated
+    /// between the profiles via [`Lerp`].
+    ///
+    /// The profiles should be wound counter-clockwise about the direction from
+    /// the first profile to the second for the faces of the resulting graph to
+    /// face outward.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profiles have fewer than three vertices or do
+    /// not have the same number of vertices.
+    ///
+    /// # Examples
+    ///
+    /// Lofting between a square and a rotated square:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let a = [
+    ///     Point3::new(-1.0, -1.0, 0.0),
+    ///     Point3::new(1.0, -1.0, 0.0),
+    ///     Point3::new(1.0, 1.0, 0.0),
+    ///     Point3::new(-1.0, 1.0, 0.0),
+    /// ];
+    /// let b = [
+    ///     Point3::new(0.0, -1.0, 2.0),
+    ///     Point3::new(1.0, 0.0, 2.0),
+    ///     Point3::new(0.0, 1.0, 2.0),
+    ///     Point3::new(-1.0, 0.0, 2.0),
+    /// ];
+    /// let graph = MeshGraph::<E3>::loft(&a, &b, 2).unwrap();
+    ///
+    /// assert_eq!(14, graph.face_count());
+    /// ```
+    ///
+    /// [`Lerp`]: crate::graph::Lerp
+    pub fn loft(
+        profile_a: &[G::Vertex],
+        profile_b: &[G::Vertex],
+        subdivisions: usize,
+    ) -> Result<Self, GraphError>
+    where
+        G::Vertex: Lerp,
+    {
+        let n = profile_a.len();
+        if n < 3 {
+            return Err(GraphError::ArityNonPolygonal);
+        }
+        if profile_b.len() != n {
+            return Err(GraphError::ArityConflict {
+                expected: n,
+                actual: profile_b.len(),
+            });
+        }
+        let m = subdivisions + 1;
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let sections = (0..=m)
+            .map(|i| {
+                let t = i as f64 / m as f64;
+                profile_a
+                    .iter()
+                    .zip(profile_b.iter())
+                    .map(|(a, b)| {
+                        let data = if i == 0 {
+                            a.clone()
+                        }
+                        else if i == m {
+                            b.clone()
+                        }
+                        else {
+                            a.clone().lerp(b.clone(), t)
+                        };
+                        mutation::vertex::insert(&mut mutation, data)
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, GraphError>>()?;
+        for (a, b) in sections.iter().zip(sections.iter().skip(1)) {
+            for (j, k) in (0..n).perimeter() {
+                let cache = FaceInsertCache::from_storage(&mutation, &[a[j], a[k], b[k], b[j]])?;
+                mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+            }
+        }
+        // Cap the ends. The first profile is reversed so that its face is
+        // wound in the opposite direction of the last profile's face.
+        let first = sections[0].iter().rev().cloned().collect::<Vec<_>>();
+        for perimeter in [first.as_slice(), sections[m].as_slice()] {
+            let cache = FaceInsertCache::from_storage(&mutation, perimeter)?;
+            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+        }
+        mutation.commit().map_err(|(_, error)| error)
+    }
+
+    /// Creates a surface of revolution by revolving a profile about an axis.
+    ///
+    /// The profile is a polyline of positions that is rotated about the axis
+    /// through `origin` in `segments` increments of `angle / segments` radians.
+    /// Consecutive cross sections are connected by quadrilaterals. If the
+    /// angle is a full revolution ($2\pi$ or more), then the last cross section
+    /// is connected to the first. Points of the profile that lie on the axis
+    /// are poles: they are not duplicated and the cross sections are capped
+    /// about them with fans of triangles.
+    ///
+    /// The faces of the resulting graph face away from the axis if the profile
+    /// proceeds in the direction of the axis and revolves counter-clockwise
+    /// about it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profile has fewer than two points, `segments`
+    /// is zero (or less than three for a full revolution), or the axis is
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// Revolving a semicircle into a sphere:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::{Point3, Vector3};
+    /// use plexus::graph::MeshGraph;
+    /// use std::f64::consts::PI;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let profile = (0..=8)
+    ///     .map(|n| {
+    ///         let angle = (n as f64 / 8.0) * PI;
+    ///         Point3::new(angle.sin(), 0.0, -angle.cos())
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let graph =
+    ///     MeshGraph::<E3>::revolve(&profile, Point3::origin(), Vector3::z(), 16, 2.0 * PI).unwrap();
+    ///
+    /// assert_eq!(7 * 16 + 2, graph.vertex_count());
+    /// assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+    /// ```
+    pub fn revolve<T>(
+        profile: &[VertexPosition<G>],
+        origin: VertexPosition<G>,
+        axis: Vector<VertexPosition<G>>,
+        segments: usize,
+        angle: T,
+    ) -> Result<Self, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPosition + FromGeometry<VertexPosition<G>>,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        let scalar = |value: usize| <Scalar<VertexPosition<G>> as NumCast>::from(value).unwrap();
+        let angle = angle.into();
+        let two = Scalar::<VertexPosition<G>>::one() + One::one();
+        let epsilon = <Scalar<VertexPosition<G>> as NumCast>::from(1e-9).unwrap();
+        let closed =
+            Signed::abs(&angle) >= (two * <Scalar<VertexPosition<G>> as Real>::PI) - epsilon;
+        if profile.len() < 2 || segments == 0 || (closed && segments < 3) {
+            return Err(GraphError::TopologyMalformed);
+        }
+        let axis = axis.normalize().ok_or(GraphError::Geometry)?;
+        // Decompose each point of the profile into a projection onto the axis
+        // and a radial vector orthogonal to the axis.
+        let points = profile
+            .iter()
+            .map(|position| {
+                let v = *position - origin;
+                let projection = axis * axis.dot(v);
+                (origin + projection, v - projection)
+            })
+            .collect::<Vec<_>>();
+        let extent = points.iter().map(|(_, radial)| radial.magnitude()).fold(
+            Zero::zero(),
+            |max: Scalar<VertexPosition<G>>, radius| {
+                if radius > max {
+                    radius
+                }
+                else {
+                    max
+                }
+            },
+        );
+        let is_pole = |radial: &Vector<VertexPosition<G>>| radial.magnitude() <= extent * epsilon;
+        let columns = if closed { segments } else { segments + 1 };
+        let mut mutation = Mutation::from(MeshGraph::new());
+        // Insert a column of vertices for each cross section. Poles are
+        // inserted once and shared by all cross sections.
+        let rings = points
+            .iter()
+            .map(|(center, radial)| {
+                if is_pole(radial) {
+                    let key = mutation::vertex::insert(&mut mutation, (*center).into_geometry())?;
+                    Ok(vec![key; columns])
+                }
+                else {
+                    (0..columns)
+                        .map(|j| {
+                            // Rotate the radial vector via Rodrigues' formula.
+                            // It is orthogonal to the axis, so the axial term
+                            // vanishes.
+                            let theta = angle * scalar(j) / scalar(segments);
+                            let (sin, cos) = (Real::sin(theta), Real::cos(theta));
+                            let position = *center + (*radial * cos) + (axis.cross(*radial) * sin);
+                            mutation::vertex::insert(&mut mutation, position.into_geometry())
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                }
+            })
+            .collect::<Result<Vec<_>, GraphError>>()?;
+        for (i, (a, b)) in rings.iter().zip(rings.iter().skip(1)).enumerate() {
+            let (pa, pb) = (is_pole(&points[i].1), is_pole(&points[i + 1].1));
+            if pa && pb {
+                continue;
+            }
+            for j in 0..segments {
+                let k = (j + 1) % columns;
+                let perimeter: SmallVec<[_; 4]> = if pa {
+                    SmallVec::from_slice(&[a[j], b[k], b[j]])
+                }
+                else if pb {
+                    SmallVec::from_slice(&[a[j], a[k], b[j]])
+                }
+                else {
+                    SmallVec::from_slice(&[a[j], a[k], b[k], b[j]])
+                };
+                let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+                mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+            }
+        }
+        mutation.commit().map_err(|(_, error)| error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Point3, Vector3};
+
+    use crate::graph::{GraphError, MeshGraph};
+    use crate::prelude::*;
+    use crate::primitive::NGon;
+
+    #[test]
+    fn stitch_loops() {
+        type E3 = Point3<f64>;
+
+        // Construct the bottom and top faces of a cube.
+        let build = || {
+            MeshGraph::<E3>::from_raw_buffers(
+                vec![NGon([0usize, 3, 2, 1]), NGon([4, 5, 6, 7])],
+                vec![
+                    (0.0, 0.0, 0.0),
+                    (1.0, 0.0, 0.0),
+                    (1.0, 1.0, 0.0),
+                    (0.0, 1.0, 0.0),
+                    (0.0, 0.0, 1.0),
+                    (1.0, 0.0, 1.0),
+                    (1.0, 1.0, 1.0),
+                    (0.0, 1.0, 1.0),
+                ],
+            )
+            .unwrap()
+        };
+        let graph = build();
+        let keys = |z: f64| {
+            [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]
+                .iter()
+                .map(|&(x, y)| {
+                    graph
+                        .vertices()
+                        .find(|vertex| *vertex.position() == Point3::new(x, y, z))
+                        .unwrap()
+                        .key()
+                })
+                .collect::<Vec<_>>()
+        };
+        let (bottom, top) = (keys(0.0), keys(1.0));
+
+        let mut cube = build();
+        assert_eq!(4, cube.stitch_loops(&bottom, &top, false).unwrap());
+        assert_eq!(8, cube.vertex_count());
+        assert_eq!(12, cube.edge_count());
+        assert_eq!(6, cube.face_count());
+        assert!(cube.arcs().all(|arc| !arc.is_boundary_arc()));
+        assert!((cube.volume().unwrap() - 1.0).abs() < 1e-9);
+
+        // Reversing the second loop yields the same pairing.
+        let mut cube = build();
+        let mut reversed = top.clone();
+        reversed.reverse();
+        assert_eq!(4, cube.stitch_loops(&bottom, &reversed, true).unwrap());
+        assert_eq!(6, cube.face_count());
+
+        let mut cube = build();
+        assert_eq!(
+            Err(GraphError::ArityConflict {
+                expected: 4,
+                actual: 3
+            }),
+            cube.stitch_loops(&bottom, &top[..3], false)
+        );
+
+        // Loops that share vertices are rejected before the graph is mutated.
+        assert_eq!(
+            Err(GraphError::TopologyMalformed.at_index(0)),
+            cube.stitch_loops(&bottom, &bottom, false)
+        );
+        let mut shared = top.clone();
+        shared[2] = bottom[2];
+        assert_eq!(
+            Err(GraphError::TopologyMalformed.at_index(1)),
+            cube.stitch_loops(&bottom, &shared, false)
+        );
+        assert_eq!(8, cube.vertex_count());
+        assert_eq!(8, cube.edge_count());
+        assert_eq!(2, cube.face_count());
+    }
+
+    #[test]
+    fn sweep() {
+        type E3 = Point3<f64>;
+
+        let profile = (0..8)
+            .map(|n| {
+                let angle = n as f64 * std::f64::consts::PI / 4.0;
+                (0.25 * angle.cos(), 0.25 * angle.sin())
+            })
+            .collect::<Vec<_>>();
+
+        // Sweep along a straight line. The profile must not twist and the
+        // ends of the tube are open.
+        let path = (0..4)
+            .map(|n| Point3::new(0.0, 0.0, n as f64))
+            .collect::<Vec<_>>();
+        let graph = MeshGraph::<E3>::sweep(&profile, &path, false).unwrap();
+        assert_eq!(32, graph.vertex_count());
+        assert_eq!(24, graph.face_count());
+        assert_eq!(16, graph.arcs().filter(|arc| arc.is_boundary_arc()).count());
+        for vertex in graph.vertices() {
+            let position = vertex.position();
+            assert!((position.coords.xy().norm() - 0.25).abs() < 1e-9);
+        }
+        // Faces face outward from the path.
+        for face in graph.faces() {
+            let centroid = face.centroid();
+            let normal = face.normal().unwrap();
+            assert!(normal.xy().dot(&centroid.coords.xy()) > 0.0);
+        }
+
+        // Sweep along a circle to form a torus.
+        let path = (0..16)
+            .map(|n| {
+                let angle = n as f64 * std::f64::consts::PI / 8.0;
+                Point3::new(angle.cos(), angle.sin(), 0.0)
+            })
+            .collect::<Vec<_>>();
+        let graph = MeshGraph::<E3>::sweep(&profile, &path, true).unwrap();
+        assert_eq!(128, graph.vertex_count());
+        assert_eq!(128, graph.face_count());
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+
+        assert_eq!(
+            GraphError::ArityNonPolygonal,
+            MeshGraph::<E3>::sweep(&profile[..2], &path, true)
+                .err()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn loft() {
+        let profile = |z: f64| {
+            [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]
+                .iter()
+                .map(|&(x, y)| Point3::new(x, y, z))
+                .collect::<Vec<_>>()
+        };
+        let graph = MeshGraph::<Point3<f64>>::loft(&profile(0.0), &profile(1.0), 3).unwrap();
+
+        assert_eq!(20, graph.vertex_count());
+        assert_eq!(18, graph.face_count());
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+        // The intermediate cross sections are interpolated and the faces face
+        // outward.
+        assert!((graph.volume().unwrap() - 1.0).abs() < 1e-9);
+        assert_eq!(
+            4,
+            graph
+                .vertices()
+                .filter(|vertex| (vertex.position().z - 0.5).abs() < 1e-9)
+                .count()
+        );
+
+        assert_eq!(
+            GraphError::ArityConflict {
+                expected: 4,
+                actual: 3,
+            },
+            MeshGraph::<Point3<f64>>::loft(&profile(0.0), &profile(1.0)[..3], 0)
+                .err()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn revolve() {
+        let pi = std::f64::consts::PI;
+        let profile = (0..=8)
+            .map(|n| {
+                let angle = (n as f64 / 8.0) * pi;
+                Point3::new(angle.sin(), 0.0, -angle.cos())
+            })
+            .collect::<Vec<_>>();
+
+        // A full revolution of a semicircle is a closed sphere with poles.
+        let graph = MeshGraph::<Point3<f64>>::revolve(
+            &profile,
+            Point3::origin(),
+            Vector3::z(),
+            16,
+            2.0 * pi,
+        )
+        .unwrap();
+        assert_eq!((7 * 16) + 2, graph.vertex_count());
+        assert_eq!(8 * 16, graph.face_count());
+        assert_eq!(32, graph.faces().filter(|face| face.arity() == 3).count());
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+        assert!(graph.non_manifold_vertices().is_empty());
+        let volume = graph.volume().unwrap();
+        assert!(volume > 3.5 && volume < (4.0 / 3.0) * pi);
+
+        // A partial revolution is open and does not connect the last cross
+        // section to the first.
+        let graph =
+            MeshGraph::<Point3<f64>>::revolve(&profile, Point3::origin(), Vector3::z(), 8, pi)
+                .unwrap();
+        assert_eq!((7 * 9) + 2, graph.vertex_count());
+        assert_eq!(8 * 8, graph.face_count());
+        assert!(graph.arcs().any(|arc| arc.is_boundary_arc()));
+
+        assert_eq!(
+            GraphError::TopologyMalformed,
+            MeshGraph::<Point3<f64>>::revolve(
+                &profile,
+                Point3::origin(),
+                Vector3::z(),
+                2,
+                2.0 * pi
+            )
+            .err()
+            .unwrap()
+        );
+        assert_eq!(
+            GraphError::Geometry,
+            MeshGraph::<Point3<f64>>::revolve(&profile, Point3::origin(), Vector3::zeros(), 8, pi)
+                .err()
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,418 @@
+use smallvec::SmallVec;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+use crate::entity::storage::KeyIndex;
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::edge::EdgeKey;
+use crate::graph::face::FaceKey;
+use crate::graph::mutation::face::FaceInsertCache;
+use crate::graph::mutation::{self, Immediate};
+use crate::graph::vertex::VertexKey;
+use crate::graph::{GraphError, MeshGraph, ResultExt as _};
+use crate::transact::Transact;
+
+type Mutation<M> = mutation::Mutation<Immediate<M>>;
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Gets the keys of the non-manifold vertices in the graph.
+    ///
+    /// A vertex is manifold if its incident faces form a single fan in which
+    /// consecutive faces share an edge that is incident to the vertex. A
+    /// "bowtie" vertex, where two or more fans meet at only that vertex, is
+    /// non-manifold. Such graphs can be constructed, but circulators over a
+    /// non-manifold vertex only visit the fan of its leading arc and
+    /// operations that average over adjacent topology, such as vertex normals,
+    /// may produce unexpected results.
+    ///
+    /// Vertices without incident faces are considered manifold.
+    ///
+    /// # Examples
+    ///
+    /// Detecting a bowtie vertex shared by two triangles:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 3, 4)],
+    ///     vec![
+    ///         (0.0, 0.0),
+    ///         (1.0, 0.0),
+    ///         (1.0, 1.0),
+    ///         (-1.0, 0.0),
+    ///         (-1.0, -1.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(1, graph.non_manifold_vertices().len());
+    /// ```
+    pub fn non_manifold_vertices(&self) -> Vec<VertexKey<K>> {
+        let fans = self.vertex_fans();
+        self.vertices()
+            .map(|vertex| vertex.key())
+            .filter(|key| {
+                fans.get(key)
+                    .map(|fans| fans.values().collect::<HashSet<_>>().len() > 1)
+                    .unwrap_or(false)
+            })
+            .collect()
+    }
+
+    /// Splits non-manifold vertices into manifold vertices.
+    ///
+    /// Each non-manifold vertex is duplicated once for each fan of its incident
+    /// faces, so that every fan is incident to a distinct manifold vertex. See
+    /// [`MeshGraph::non_manifold_vertices`]. This is a common repair for
+    /// scanned and CAD meshes and most topological operations assume that
+    /// vertices are manifold.
+    ///
+    /// If any vertices are split, then the graph is rebuilt. Vertex and face
+    /// data are preserved, but arc and edge data are not, edges and vertices
+    /// that are not part of any face are discarded, and keys are invalidated. Layers are
+    /// migrated to the rebuilt graph, where the first copy of a split vertex
+    /// retains its values and other copies have default values.
+    ///
+    /// Returns the number of vertices inserted into the graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the keyspace of the graph is exhausted by the
+    /// inserted vertices (see [`KeyIndex`]). In this case, the graph is left
+    /// unmodified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 3, 4)],
+    ///     vec![
+    ///         (0.0, 0.0),
+    ///         (1.0, 0.0),
+    ///         (1.0, 1.0),
+    ///         (-1.0, 0.0),
+    ///         (-1.0, -1.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(1, graph.split_non_manifold_vertices().unwrap());
+    /// assert_eq!(6, graph.vertex_count());
+    /// assert!(graph.non_manifold_vertices().is_empty());
+    /// ```
+    ///
+    /// [`KeyIndex`]: crate::graph::KeyIndex
+    /// [`MeshGraph::non_manifold_vertices`]: crate::graph::MeshGraph::non_manifold_vertices
+    pub fn split_non_manifold_vertices(&mut self) -> Result<usize, GraphError> {
+        let fans = self.vertex_fans();
+        let count = fans
+            .values()
+            .map(|fans| {
+                fans.values()
+                    .collect::<HashSet<_>>()
+                    .len()
+                    .saturating_sub(1)
+            })
+            .sum::<usize>();
+        if count == 0 {
+            return Ok(0);
+        }
+        // Vertices are inserted once for each distinct pair of a vertex and
+        // the representative face of one of its fans.
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let mut vertices = HashMap::new();
+        // Layers are migrated to the first copy of each vertex. Only faces are
+        // rebuilt, so vertices that are not part of any face are discarded
+        // along with their edges.
+        let mut keys = HashMap::with_capacity(self.vertex_count());
+        let mut faces = HashMap::with_capacity(self.face_count());
+        for face in self.faces() {
+            let perimeter = face
+                .adjacent_vertices()
+                .map(|vertex| {
+                    let fan = fans[&vertex.key()][&face.key()];
+                    let key = match vertices.entry((vertex.key(), fan)) {
+                        Entry::Occupied(entry) => *entry.get(),
+                        Entry::Vacant(entry) => *entry.insert(mutation::vertex::insert(
+                            &mut mutation,
+                            vertex.get().clone(),
+                        )?),
+                    };
+                    keys.entry(vertex.key()).or_insert(key);
+                    Ok(key)
+                })
+                .collect::<Result<SmallVec<[_; 4]>, GraphError>>()?;
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter).expect_consistent();
+            let data = face.get().clone();
+            let key =
+                mutation::face::insert_with(&mut mutation, cache, || (Default::default(), data))
+                    .expect_consistent();
+            faces.insert(face.key(), key);
+        }
+        let graph = mutation
+            .commit()
+            .map_err(|(_, error)| error)
+            .expect_consistent();
+        self.replace_with_rebuild(graph, &keys, &faces);
+        Ok(count)
+    }
+
+    // Partitions the incident faces of each vertex into fans. Maps each vertex
+    // to a map from each of its incident faces to a representative face of the
+    // fan that contains it. Vertices without incident faces are not mapped.
+    fn vertex_fans(&self) -> HashMap<VertexKey<K>, HashMap<FaceKey<K>, FaceKey<K>>> {
+        self.vertex_fans_with(|_| true)
+    }
+
+    // Partitions the incident faces of each vertex into fans like
+    // `vertex_fans`, but only joins the faces on either side of an edge if the
+    // given function returns `true` for that edge.
+    pub(in crate::graph) fn vertex_fans_with<F>(
+        &self,
+        mut f: F,
+    ) -> HashMap<VertexKey<K>, HashMap<FaceKey<K>, FaceKey<K>>>
+    where
+        F: FnMut(EdgeKey<K>) -> bool,
+    {
+        // Finds the representative of a face in a disjoint set of faces.
+        fn root<K>(parents: &mut HashMap<FaceKey<K>, FaceKey<K>>, key: FaceKey<K>) -> FaceKey<K>
+        where
+            K: KeyIndex,
+        {
+            let parent = parents[&key];
+            if parent == key {
+                key
+            }
+            else {
+                let parent = root(parents, parent);
+                parents.insert(key, parent);
+                parent
+            }
+        }
+
+        // Collect the pairs of faces on either side of each edge for both of
+        // the edge's vertices. The incident faces of a vertex are joined into
+        // fans by these pairs.
+        let mut pairs =
+            HashMap::<VertexKey<K>, Vec<(Option<FaceKey<K>>, Option<FaceKey<K>>)>>::new();
+        for edge in self.edges() {
+            let arc = edge.arc();
+            let (a, b) = (
+                arc.face().map(|face| face.key()),
+                arc.opposite_arc().face().map(|face| face.key()),
+            );
+            let joined = f(edge.key());
+            for vertex in [arc.source_vertex(), arc.destination_vertex()] {
+                let pairs = pairs.entry(vertex.key()).or_default();
+                if joined {
+                    pairs.push((a, b));
+                }
+                else {
+                    pairs.push((a, None));
+                    pairs.push((b, None));
+                }
+            }
+        }
+        pairs
+            .into_iter()
+            .filter_map(|(key, pairs)| {
+                let mut parents = pairs
+                    .iter()
+                    .flat_map(|(a, b)| a.iter().chain(b.iter()))
+                    .map(|face| (*face, *face))
+                    .collect::<HashMap<_, _>>();
+                if parents.is_empty() {
+                    return None;
+                }
+                for (a, b) in pairs.iter() {
+                    if let Some((a, b)) = a.zip(*b) {
+                        let (a, b) = (root(&mut parents, a), root(&mut parents, b));
+                        parents.insert(a, b);
+                    }
+                }
+                let faces = parents.keys().cloned().collect::<Vec<_>>();
+                let fans = faces
+                    .into_iter()
+                    .map(|face| (face, root(&mut parents, face)))
+                    .collect::<HashMap<_, _>>();
+                Some((key, fans))
+            })
+            .collect()
+    }
+
+    /// Verifies that all vertices in the graph are manifold.
+    ///
+    /// Graphs may be constructed with non-manifold vertices. This function can
+    /// be used after construction to reject such graphs. See
+    /// [`MeshGraph::non_manifold_vertices`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with the key of a non-manifold vertex if any vertex is
+    /// not manifold (see [`GraphError::AtKey`]).
+    ///
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    /// [`MeshGraph::non_manifold_vertices`]: crate::graph::MeshGraph::non_manifold_vertices
+    pub fn verify_manifold_vertices(&self) -> Result<(), GraphError> {
+        match self.non_manifold_vertices().into_iter().next() {
+            Some(vertex) => Err(GraphError::NonManifoldVertex.at_key(vertex)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::{Point2, Point3};
+    use std::collections::HashSet;
+
+    use crate::graph::{GraphError, MeshGraph};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::NGon;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn non_manifold_vertex() {
+        // Construct a "bowtie" of two triangles that share only a vertex.
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2]), NGon([0, 3, 4])],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (1.0, 1.0),
+                (-1.0, 0.0),
+                (-1.0, -1.0),
+            ],
+        )
+        .unwrap();
+        let key = graph
+            .vertices()
+            .find(|vertex| *vertex.get() == Point2::origin())
+            .unwrap()
+            .key();
+
+        assert_eq!(vec![key], graph.non_manifold_vertices());
+        assert_eq!(
+            Err(GraphError::NonManifoldVertex.at_key(key)),
+            graph.verify_manifold_vertices(),
+        );
+        // Circulators terminate and visit at most every incident face.
+        let vertex = graph.vertex(key).unwrap();
+        assert!(vertex.adjacent_faces().count() <= 2);
+        assert!(vertex.outgoing_arcs().count() <= 4);
+
+        // Joining the fans with a face makes the vertex manifold.
+        let keys = [(0.0, 0.0), (1.0, 1.0), (-1.0, 0.0)]
+            .iter()
+            .map(|position| {
+                graph
+                    .vertices()
+                    .find(|vertex| *vertex.get() == Point2::new(position.0, position.1))
+                    .unwrap()
+                    .key()
+            })
+            .collect::<Vec<_>>();
+        graph.insert_faces(vec![(keys, ())]).unwrap();
+        assert!(graph.non_manifold_vertices().is_empty());
+        assert!(graph.verify_manifold_vertices().is_ok());
+
+        // A closed manifold has no non-manifold vertices.
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        assert!(graph.non_manifold_vertices().is_empty());
+    }
+
+    #[test]
+    fn split_non_manifold_vertices() {
+        // Construct three triangles that share only a vertex.
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2]), NGon([0, 3, 4]), NGon([0, 5, 6])],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (1.0, 1.0),
+                (-1.0, 0.0),
+                (-1.0, -1.0),
+                (0.0, -1.0),
+                (1.0, -1.0),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(2, graph.split_non_manifold_vertices().unwrap());
+        assert_eq!(9, graph.vertex_count());
+        assert_eq!(9, graph.edge_count());
+        assert_eq!(3, graph.face_count());
+        assert!(graph.non_manifold_vertices().is_empty());
+        assert_eq!(
+            3,
+            graph
+                .vertices()
+                .filter(|vertex| *vertex.get() == Point2::origin())
+                .count()
+        );
+        assert_eq!(0, graph.split_non_manifold_vertices().unwrap());
+
+        // Vertices and edges that are not part of any face are discarded.
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2]), NGon([0, 3, 4]), NGon([5, 6, 7])],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (1.0, 1.0),
+                (-1.0, 0.0),
+                (-1.0, -1.0),
+                (2.0, 0.0),
+                (3.0, 0.0),
+                (3.0, 1.0),
+            ],
+        )
+        .unwrap();
+        let key = graph
+            .faces()
+            .find(|face| {
+                face.adjacent_vertices()
+                    .any(|vertex| *vertex.get() == Point2::new(2.0, 0.0))
+            })
+            .unwrap()
+            .key();
+        graph.face_mut(key).unwrap().remove();
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(9, graph.edge_count());
+
+        assert_eq!(1, graph.split_non_manifold_vertices().unwrap());
+        assert_eq!(6, graph.vertex_count());
+        assert_eq!(6, graph.edge_count());
+        assert_eq!(2, graph.face_count());
+        assert!(graph.non_manifold_vertices().is_empty());
+
+        // Manifold graphs are unchanged.
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let keys = graph.vertices().keys().collect::<HashSet<_>>();
+        assert_eq!(0, graph.split_non_manifold_vertices().unwrap());
+        assert_eq!(keys, graph.vertices().keys().collect::<HashSet<_>>());
+    }
+}
@@ -0,0 +1,376 @@
+use decorum::Real;
+use num::{NumCast, One, Zero};
+use smallvec::SmallVec;
+use std::collections::HashMap;
+use theon::ops::Cross;
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use theon::AsPosition;
+use typenum::U3;
+
+use crate::entity::storage::KeyIndex;
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::geometry::VertexPosition;
+use crate::graph::vertex::VertexKey;
+use crate::graph::{GraphError, MeshGraph};
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Gets the minimum, maximum, and mean lengths of the edges in the graph.
+    ///
+    /// Lengths are computed over (undirected) edges, so each edge is counted
+    /// once regardless of its arcs. See [`EdgeView::length`]. This is useful
+    /// for choosing parameters for operations like remeshing and for
+    /// detecting degenerate edges with zero length.
+    ///
+    /// Returns `None` if the graph has no edges.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+    /// let (min, max, mean) = graph.edge_length_stats().unwrap();
+    ///
+    /// assert_eq!(min, max);
+    /// assert_eq!(min, mean);
+    /// ```
+    ///
+    /// [`EdgeView::length`]: crate::graph::EdgeView::length
+    pub fn edge_length_stats(
+        &self,
+    ) -> Option<(
+        Scalar<VertexPosition<G>>,
+        Scalar<VertexPosition<G>>,
+        Scalar<VertexPosition<G>>,
+    )>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let mut lengths = self.edges().map(|edge| edge.length());
+        let first = lengths.next()?;
+        let (mut min, mut max, mut sum) = (first, first, first);
+        for length in lengths {
+            if length < min {
+                min = length;
+            }
+            if length > max {
+                max = length;
+            }
+            sum = sum + length;
+        }
+        let count = <Scalar<VertexPosition<G>> as NumCast>::from(self.edge_count()).unwrap();
+        Some((min, max, sum / count))
+    }
+
+    /// Gets the surface area of the graph.
+    ///
+    /// This is the sum of the areas of the faces in the graph. See
+    /// [`FaceView::area`].
+    ///
+    /// [`FaceView::area`]: crate::graph::FaceView::area
+    pub fn surface_area(&self) -> Scalar<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.faces()
+            .map(|face| face.area())
+            .fold(Zero::zero(), |area, face| area + face)
+    }
+
+    /// Gets the volume enclosed by the graph.
+    ///
+    /// The volume is computed as the sum of the signed volumes of tetrahedra
+    /// formed by the origin and a triangle fan of each face. The volume is
+    /// positive if faces are wound counter-clockwise when viewed from outside
+    /// of the graph and negative otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not closed (has boundary arcs).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+    /// let volume = graph.volume().unwrap();
+    /// ```
+    pub fn volume(&self) -> Result<Scalar<VertexPosition<G>>, GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        if self.arcs().any(|arc| arc.is_boundary_arc()) {
+            return Err(GraphError::TopologyNotClosed);
+        }
+        let six = <Scalar<VertexPosition<G>> as NumCast>::from(6.0).unwrap();
+        let mut volume = Scalar::<VertexPosition<G>>::zero();
+        for face in self.faces() {
+            let coordinates = face
+                .adjacent_vertices()
+                .map(|vertex| (*vertex.position()).into_coordinates())
+                .collect::<SmallVec<[_; 4]>>();
+            let a = coordinates[0];
+            for bc in coordinates[1..].windows(2) {
+                volume = volume + a.dot(bc[0].cross(bc[1]));
+            }
+        }
+        Ok(volume / six)
+    }
+
+    /// Gets the angle defect of each vertex in the graph.
+    ///
+    /// See [`VertexView::angle_defect`].
+    ///
+    /// [`VertexView::angle_defect`]: crate::graph::VertexView::angle_defect
+    pub fn angle_defects(&self) -> HashMap<VertexKey<K>, Scalar<VertexPosition<G>>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.vertices()
+            .map(|vertex| (vertex.key(), vertex.angle_defect()))
+            .collect()
+    }
+
+    /// Verifies the discrete Gauss-Bonnet theorem for the graph.
+    ///
+    /// The sum of the angle defects of the vertices in a graph is $2\pi\chi$,
+    /// where $\chi$ is the Euler characteristic of the graph (the number of
+    /// vertices less the number of edges plus the number of faces). This
+    /// identity only holds for manifold graphs with non-degenerate faces, so
+    /// it can be used to detect such problems in a graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sum of the angle defects differs from
+    /// $2\pi\chi$ by more than the given epsilon.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+    /// graph.verify_gauss_bonnet(1e-9).unwrap();
+    /// ```
+    ///
+    /// [`VertexView::angle_defect`]: crate::graph::VertexView::angle_defect
+    pub fn verify_gauss_bonnet<T>(&self, epsilon: T) -> Result<(), GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let characteristic =
+            (self.vertex_count() + self.face_count()) as isize - self.edge_count() as isize;
+        let characteristic = <Scalar<VertexPosition<G>> as NumCast>::from(characteristic).unwrap();
+        let two = Scalar::<VertexPosition<G>>::one() + One::one();
+        let pi = <Scalar<VertexPosition<G>> as Real>::PI;
+        let defect = self
+            .vertices()
+            .map(|vertex| vertex.angle_defect())
+            .fold(Scalar::<VertexPosition<G>>::zero(), |sum, defect| {
+                sum + defect
+            });
+        let difference = defect - (two * pi * characteristic);
+        let difference = if difference < Zero::zero() {
+            -difference
+        }
+        else {
+            difference
+        };
+        if difference > epsilon.into() {
+            Err(GraphError::Geometry)
+        }
+        else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+    #[cfg(feature = "rand")]
+    use rand::rngs::StdRng;
+    #[cfg(feature = "rand")]
+    use rand::SeedableRng;
+
+    use crate::graph::{GraphError, MeshGraph};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+    use crate::primitive::NGon;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn angle_defect() {
+        use std::f64::consts::PI;
+
+        // Each corner of a cube has three right angles.
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let defects = graph.angle_defects();
+        assert_eq!(8, defects.len());
+        for defect in defects.values() {
+            assert!((defect - (PI / 2.0)).abs() < 1e-9);
+        }
+        graph.verify_gauss_bonnet(1e-9).unwrap();
+
+        // Only the corners of a flat grid have angle defects.
+        let graph = MeshGraph::<Point3<f64>>::from_heightmap(&[0.0; 9], 3, 3).unwrap();
+        let defects = graph.angle_defects();
+        assert_eq!(
+            4,
+            defects
+                .values()
+                .filter(|defect| defect.abs() > 1e-9)
+                .count()
+        );
+        assert!((defects.values().sum::<f64>() - (2.0 * PI)).abs() < 1e-9);
+        graph.verify_gauss_bonnet(1e-9).unwrap();
+
+        // A sphere is closed and so has an Euler characteristic of two.
+        let graph: MeshGraph<Point3<f64>> =
+            UvSphere::new(8, 8).polygons::<Position<E3>>().collect();
+        assert!((graph.angle_defects().values().sum::<f64>() - (4.0 * PI)).abs() < 1e-9);
+        graph.verify_gauss_bonnet(1e-9).unwrap();
+    }
+
+    #[test]
+    fn surface_area_and_volume() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        assert!((graph.surface_area() - 6.0).abs() < 1e-9);
+        assert!((graph.volume().unwrap() - 1.0).abs() < 1e-9);
+        for edge in graph.edges() {
+            assert!((edge.length() - 1.0).abs() < 1e-9);
+        }
+        for face in graph.faces() {
+            assert!((face.perimeter_length() - 4.0).abs() < 1e-9);
+        }
+
+        // The volume of an open graph is undefined.
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2, 3])],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (0.0, 1.0, 0.0),
+            ],
+        )
+        .unwrap();
+        assert!((graph.surface_area() - 1.0).abs() < 1e-9);
+        assert_eq!(Err(GraphError::TopologyNotClosed), graph.volume());
+
+        // The volume of a sphere converges on that of a unit sphere.
+        let volume = 4.0 * std::f64::consts::PI / 3.0;
+        let error = |n: usize| {
+            let graph: MeshGraph<Point3<f64>> =
+                UvSphere::new(n, n).polygons::<Position<E3>>().collect();
+            volume - graph.volume().unwrap()
+        };
+        let (coarse, fine) = (error(8), error(64));
+        assert!(coarse > fine);
+        assert!(fine > 0.0 && fine < 5e-2);
+    }
+
+    #[test]
+    fn concave_area() {
+        // An L-shaped face is concave and triangle fans about some of its
+        // vertices overlap, so the area and samples are checked for each choice
+        // of leading vertex.
+        let positions = [
+            (0.0, 0.0, 0.0),
+            (2.0, 0.0, 0.0),
+            (2.0, 1.0, 0.0),
+            (1.0, 1.0, 0.0),
+            (1.0, 2.0, 0.0),
+            (0.0, 2.0, 0.0),
+        ];
+        for offset in 0..positions.len() {
+            let mut positions = positions;
+            positions.rotate_left(offset);
+            let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+                vec![NGon([0usize, 1, 2, 3, 4, 5])],
+                positions.to_vec(),
+            )
+            .unwrap();
+            assert!((graph.faces().nth(0).unwrap().area() - 3.0).abs() < 1e-9);
+            assert!((graph.surface_area() - 3.0).abs() < 1e-9);
+            // No samples lie in the notch of the face.
+            #[cfg(feature = "rand")]
+            assert!(graph
+                .sample_surface(256, &mut StdRng::seed_from_u64(0))
+                .iter()
+                .all(|point| point.x <= 1.0 + 1e-9 || point.y <= 1.0 + 1e-9));
+        }
+    }
+
+    #[test]
+    fn edge_length_stats() {
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2, 3])],
+            vec![
+                (0.0, 0.0, 0.0),
+                (2.0, 0.0, 0.0),
+                (2.0, 1.0, 0.0),
+                (0.0, 1.0, 0.0),
+            ],
+        )
+        .unwrap();
+        let (min, max, mean) = graph.edge_length_stats().unwrap();
+
+        assert_eq!(1.0, min);
+        assert_eq!(2.0, max);
+        // Each edge is counted once rather than once per arc.
+        assert_eq!(1.5, mean);
+
+        assert_eq!(None, MeshGraph::<Point3<f64>>::new().edge_length_stats());
+    }
+}
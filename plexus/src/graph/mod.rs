@@ -140,7 +140,7 @@
 //!     let mut arc = face.into_arc();
 //!     let mut splits = SmallVec::<[_; 4]>::with_capacity(arity);
 //!     for _ in 0..arity {
-//!         let vertex = arc.split_at_midpoint();
+//!         let vertex = arc.split_at_midpoint().unwrap();
 //!         splits.push(vertex.key());
 //!         arc = vertex.into_outgoing_arc().into_next_arc();
 //!     }
@@ -246,23 +246,47 @@
 //! [`UvSphere`]: crate::primitive::sphere::UvSphere
 
 mod builder;
+mod chamfer;
+mod construct;
 mod core;
 mod data;
+mod describe;
+mod displace;
+mod dual;
 mod edge;
+mod extrude;
 mod face;
+mod geodesic;
 mod geometry;
+mod layer;
+mod loft;
+mod manifold;
+mod measure;
 mod mutation;
 mod path;
+mod reflect;
+mod repair;
+mod sample;
+mod segment;
+mod smooth;
+mod spatial;
+mod split;
+mod triangulation;
 mod vertex;
+mod weld;
+mod winged;
+mod wireframe;
 
 use decorum::cmp::IntrinsicOrd;
 use decorum::R64;
 use num::{Integer, NumCast, ToPrimitive, Unsigned};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 use smallvec::SmallVec;
 use std::borrow::Borrow;
 use std::collections::{HashMap, HashSet};
 use std::convert::TryFrom;
-use std::fmt::Debug;
+use std::fmt::{self, Debug, Display};
 use std::hash::Hash;
 use std::iter::FromIterator;
 use std::mem;
@@ -270,7 +294,7 @@ use std::vec;
 use theon::adjunct::Map;
 use theon::query::Aabb;
 use theon::space::{EuclideanSpace, Scalar};
-use theon::{AsPosition, AsPositionMut};
+use theon::AsPosition;
 use thiserror::Error;
 use typenum::NonZero;
 
@@ -282,51 +306,128 @@ use crate::entity::borrow::Reborrow;
 use crate::entity::storage::prelude::*;
 use crate::entity::storage::{AsStorage, AsStorageMut, AsStorageOf, Key, StorageTarget};
 use crate::entity::view::{Bind, Orphan, View};
-use crate::entity::{Entity, EntityError, Payload};
+use crate::entity::{Entity, Payload};
 use crate::geometry::{FromGeometry, IntoGeometry};
 use crate::graph::builder::GraphBuilder;
 use crate::graph::core::{Core, OwnedCore};
 use crate::graph::data::Parametric;
 use crate::graph::edge::{Arc, Edge};
 use crate::graph::face::Face;
-use crate::graph::mutation::face::FaceInsertCache;
+use crate::graph::mutation::face::{FaceBatchInsertCache, FaceInsertCache};
 use crate::graph::mutation::{Consistent, Immediate};
 use crate::graph::vertex::Vertex;
-use crate::index::{Flat, FromIndexer, Grouping, HashIndexer, IndexBuffer, IndexVertices, Indexer};
+use crate::index::{Flat, FromIndexer, Grouping, HashIndexer, Indexer};
 use crate::primitive::decompose::IntoVertices;
 use crate::primitive::{IntoPolygons, Polygonal, UnboundedPolygon};
 use crate::transact::Transact;
-use crate::{DynamicArity, MeshArity, StaticArity};
+use crate::{DynamicArity, IteratorExt as _, MeshArity, StaticArity};
 
+pub use crate::entity::storage::KeyIndex;
 pub use crate::entity::view::{ClosedView, Rebind};
+pub use crate::graph::construct::BufferMapping;
 pub use crate::graph::data::GraphData;
 pub use crate::graph::edge::{ArcKey, ArcOrphan, ArcView, EdgeKey, EdgeOrphan, EdgeView, ToArc};
+pub use crate::graph::extrude::ExtrusionNormal;
 pub use crate::graph::face::{FaceKey, FaceOrphan, FaceView, Ring, ToRing};
 pub use crate::graph::geometry::{
-    ArcNormal, EdgeMidpoint, FaceCentroid, FaceNormal, FacePlane, VertexCentroid, VertexNormal,
-    VertexPosition,
+    ArcNormal, EdgeMidpoint, FaceCentroid, FaceNormal, FacePlane, Lerp, VertexCentroid,
+    VertexNormal, VertexPosition,
 };
+pub use crate::graph::layer::{Layer, LayerKey, Layered};
 pub use crate::graph::path::Path;
+pub use crate::graph::repair::{RepairOptions, RepairReport};
+pub use crate::graph::split::SplitGraph;
+pub use crate::graph::triangulation::TriangulationStrategy;
 pub use crate::graph::vertex::{VertexKey, VertexOrphan, VertexView};
+pub use crate::graph::weld::WeldMode;
+pub use crate::graph::winged::{WingedEdge, WingedEdgeMesh, WingedFace, WingedVertex};
 
 pub use Selector::ByIndex;
 pub use Selector::ByKey;
 
 type Mutation<M> = mutation::Mutation<Immediate<M>>;
 
+/// Kinds of topology in a [`MeshGraph`].
+///
+/// This is used by [`GraphError`] to describe which topology is involved in an
+/// error.
+///
+/// [`GraphError`]: crate::graph::GraphError
+/// [`MeshGraph`]: crate::graph::MeshGraph
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TopologyKind {
+    Vertex,
+    Arc,
+    Edge,
+    Face,
+}
+
+impl Display for TopologyKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            TopologyKind::Vertex => "vertex",
+            TopologyKind::Arc => "arc",
+            TopologyKind::Edge => "edge",
+            TopologyKind::Face => "face",
+        })
+    }
+}
+
+/// Kinds of degenerate geometry.
+///
+/// This is used by [`GraphError`] to describe why geometry cannot be used to
+/// construct a [`MeshGraph`], such as in [`MeshGraph::convex_hull`].
+///
+/// [`GraphError`]: crate::graph::GraphError
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::convex_hull`]: crate::graph::MeshGraph::convex_hull
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Degeneracy {
+    /// There are too few points.
+    Insufficient,
+    /// All points coincide.
+    Coincident,
+    /// All points lie on a line.
+    Collinear,
+    /// All points lie on a plane.
+    Coplanar,
+}
+
+impl Display for Degeneracy {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Degeneracy::Insufficient => "too few points",
+            Degeneracy::Coincident => "coincident points",
+            Degeneracy::Collinear => "collinear points",
+            Degeneracy::Coplanar => "coplanar points",
+        })
+    }
+}
+
 /// Errors concerning [`MeshGraph`]s.
 ///
 /// [`MeshGraph`]: crate::graph::MeshGraph
 #[derive(Debug, Eq, Error, PartialEq)]
 pub enum GraphError {
-    #[error("required topology not found")]
-    TopologyNotFound,
+    /// Required topology is not present in a [`MeshGraph`].
+    ///
+    /// This error occurs when a key or index does not refer to any topology
+    /// of the given kind, such as when an operation references a removed
+    /// face.
+    #[error("required {0} not found")]
+    TopologyNotFound(TopologyKind),
     #[error("conflicting topology found")]
     TopologyConflict,
     #[error("topology malformed")]
     TopologyMalformed,
     #[error("topology unreachable")]
     TopologyUnreachable,
+    /// Topology is not closed.
+    ///
+    /// This error occurs when an operation requires a closed graph (a graph
+    /// without boundary arcs) but a graph has one or more boundaries.
+    #[error("topology not closed")]
+    TopologyNotClosed,
     #[error("arity is non-polygonal")]
     ArityNonPolygonal,
     /// The arity of a [`MeshGraph`] or other data structure is not compatible
@@ -347,12 +448,169 @@ pub enum GraphError {
     /// [`MeshArity`]: crate::MeshArity
     #[error("arity is non-uniform")]
     ArityNonUniform,
+    /// The number of dimensions requested of an operation is not compatible
+    /// with a [`MeshGraph`].
+    ///
+    /// This error occurs when an operation computes coordinates with more
+    /// dimensions than a graph can support, such as a spectral embedding with
+    /// at least as many dimensions as the graph has vertices. See
+    /// [`metric::spectral_embedding`].
+    ///
+    /// [`metric::spectral_embedding`]: crate::metric::spectral_embedding
+    #[error("conflicting dimensions; expected at most {max}, but got {actual}")]
+    DimensionConflict {
+        /// The greatest number of dimensions that is supported.
+        max: usize,
+        /// The number of dimensions that was requested.
+        actual: usize,
+    },
+    /// The number of parts requested of a split is not compatible with an
+    /// operation.
+    ///
+    /// This error occurs when an edge is split into fewer than two edges. See
+    /// [`ArcView::split_n`].
+    ///
+    /// [`ArcView::split_n`]: crate::graph::ArcView::split_n
+    #[error("conflicting split; expected at least {min} parts, but got {actual}")]
+    SplitConflict {
+        /// The least number of parts that is supported.
+        min: usize,
+        /// The number of parts that was requested.
+        actual: usize,
+    },
     /// Geometry is incompatible or cannot be computed.
     #[error("geometric operation failed")]
     Geometry,
+    /// Geometry is degenerate.
+    ///
+    /// This error occurs when geometry cannot be used to construct a
+    /// [`MeshGraph`], such as when computing the convex hull of coplanar
+    /// points. See [`Degeneracy`].
+    ///
+    /// [`Degeneracy`]: crate::graph::Degeneracy
+    #[error("degenerate geometry: {0}")]
+    GeometryDegenerate(Degeneracy),
     /// A graph or other data structure is not compatible with an encoding.
     #[error("encoding operation failed")]
     EncodingIncompatible,
+    /// A vertex is not manifold.
+    ///
+    /// This error occurs when the faces incident to a vertex do not form a
+    /// single edge-connected fan, such as a "bowtie" vertex where two fans of
+    /// faces meet at only that vertex. This error is wrapped with the key of
+    /// the vertex (see [`GraphError::AtKey`]). See
+    /// [`MeshGraph::non_manifold_vertices`].
+    ///
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    /// [`MeshGraph::non_manifold_vertices`]: crate::graph::MeshGraph::non_manifold_vertices
+    #[error("non-manifold vertex")]
+    NonManifoldVertex,
+    /// The keys of a [`MeshGraph`] are exhausted.
+    ///
+    /// This error occurs when topology is inserted into a graph that has
+    /// already used every key that can be represented by its key index. See
+    /// [`KeyIndex`].
+    ///
+    /// [`KeyIndex`]: crate::graph::KeyIndex
+    #[error("keyspace exhausted")]
+    KeyspaceExhausted,
+    /// An error was caused by a particular entity in a [`MeshGraph`].
+    ///
+    /// This error wraps another error with the key of the vertex, arc, edge,
+    /// or face that caused it. It is returned by operations over collections
+    /// of keys, such as [`MeshGraph::extrude_faces`], so that the offending
+    /// key can be identified. See [`GraphError::key`] and
+    /// [`GraphError::without_key`].
+    ///
+    /// [`GraphError::key`]: crate::graph::GraphError::key
+    /// [`GraphError::without_key`]: crate::graph::GraphError::without_key
+    /// [`MeshGraph::extrude_faces`]: crate::graph::MeshGraph::extrude_faces
+    #[error("{error} at {key:?}")]
+    AtKey {
+        /// The key of the entity that caused the error.
+        key: GraphKey,
+        /// The error.
+        #[source]
+        error: Box<GraphError>,
+    },
+    /// An error was caused by an item at a particular index in a collection.
+    ///
+    /// This error wraps another error with the index of the item that caused
+    /// it. It is returned by operations over ordered collections, such as
+    /// [`MeshGraph::gather_vertices`], so that the offending item can be
+    /// identified even if it occurs more than once. The wrapped error may
+    /// itself be wrapped with a key (see [`GraphError::AtKey`]). See
+    /// [`GraphError::index`].
+    ///
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    /// [`GraphError::index`]: crate::graph::GraphError::index
+    /// [`MeshGraph::gather_vertices`]: crate::graph::MeshGraph::gather_vertices
+    #[error("{error} at index {index}")]
+    AtIndex {
+        /// The index of the item that caused the error.
+        index: usize,
+        /// The error.
+        #[source]
+        error: Box<GraphError>,
+    },
+}
+
+impl GraphError {
+    /// Wraps the error with the key of the entity that caused it.
+    ///
+    /// See [`GraphError::AtKey`].
+    ///
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    pub fn at_key<K>(self, key: K) -> Self
+    where
+        K: Into<GraphKey>,
+    {
+        GraphError::AtKey {
+            key: key.into(),
+            error: Box::new(self),
+        }
+    }
+
+    /// Wraps the error with the index of the item that caused it.
+    ///
+    /// See [`GraphError::AtIndex`].
+    ///
+    /// [`GraphError::AtIndex`]: crate::graph::GraphError::AtIndex
+    pub fn at_index(self, index: usize) -> Self {
+        GraphError::AtIndex {
+            index,
+            error: Box::new(self),
+        }
+    }
+
+    /// Gets the key of the entity that caused the error, if any.
+    pub fn key(&self) -> Option<GraphKey> {
+        match self {
+            GraphError::AtKey { key, .. } => Some(*key),
+            GraphError::AtIndex { error, .. } => error.key(),
+            _ => None,
+        }
+    }
+
+    /// Gets the index of the item that caused the error, if any.
+    pub fn index(&self) -> Option<usize> {
+        match self {
+            GraphError::AtIndex { index, .. } => Some(*index),
+            GraphError::AtKey { error, .. } => error.index(),
+            _ => None,
+        }
+    }
+
+    /// Gets the error without the keys or indices of any entities that caused
+    /// it.
+    pub fn without_key(&self) -> &GraphError {
+        match self {
+            GraphError::AtKey { error, .. } | GraphError::AtIndex { error, .. } => {
+                error.without_key()
+            }
+            _ => self,
+        }
+    }
 }
 
 // TODO: How should buffer errors be handled? Is this sufficient?
@@ -367,12 +625,69 @@ impl From<BufferError> for GraphError {
     }
 }
 
-impl From<EntityError> for GraphError {
-    fn from(error: EntityError) -> Self {
-        match error {
-            EntityError::EntityNotFound => GraphError::TopologyNotFound,
-            EntityError::Data => GraphError::Geometry,
-        }
+/// Mapping of keys before and after compacting a [`MeshGraph`].
+///
+/// See [`MeshGraph::compact`].
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::compact`]: crate::graph::MeshGraph::compact
+#[derive(Clone, Debug, Default)]
+pub struct Compaction<K = u64>
+where
+    K: KeyIndex,
+{
+    /// Maps vertex keys before compaction to vertex keys after compaction.
+    pub vertices: HashMap<VertexKey<K>, VertexKey<K>>,
+    /// Maps edge keys before compaction to edge keys after compaction.
+    pub edges: HashMap<EdgeKey<K>, EdgeKey<K>>,
+    /// Maps face keys before compaction to face keys after compaction.
+    pub faces: HashMap<FaceKey<K>, FaceKey<K>>,
+}
+
+impl<K> Compaction<K>
+where
+    K: KeyIndex,
+{
+    /// Maps an arc key before compaction to the arc key after compaction.
+    ///
+    /// Arcs are keyed by their vertices, so this is derived from the mapping
+    /// of vertex keys.
+    pub fn arc(&self, ab: ArcKey<K>) -> Option<ArcKey<K>> {
+        let (a, b) = ab.into();
+        Some((*self.vertices.get(&a)?, *self.vertices.get(&b)?).into())
+    }
+}
+
+/// Mapping of keys from a [`MeshGraph`] into a subgraph extracted from it.
+///
+/// See [`MeshGraph::subgraph`].
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::subgraph`]: crate::graph::MeshGraph::subgraph
+#[derive(Clone, Debug, Default)]
+pub struct SubgraphMapping<K = u64>
+where
+    K: KeyIndex,
+{
+    /// Maps vertex keys in the graph to vertex keys in the subgraph.
+    pub vertices: HashMap<VertexKey<K>, VertexKey<K>>,
+    /// Maps edge keys in the graph to edge keys in the subgraph.
+    pub edges: HashMap<EdgeKey<K>, EdgeKey<K>>,
+    /// Maps face keys in the graph to face keys in the subgraph.
+    pub faces: HashMap<FaceKey<K>, FaceKey<K>>,
+}
+
+impl<K> SubgraphMapping<K>
+where
+    K: KeyIndex,
+{
+    /// Maps an arc key in the graph to the arc key in the subgraph.
+    ///
+    /// Arcs are keyed by their vertices, so this is derived from the mapping
+    /// of vertex keys.
+    pub fn arc(&self, ab: ArcKey<K>) -> Option<ArcKey<K>> {
+        let (a, b) = ab.into();
+        Some((*self.vertices.get(&a)?, *self.vertices.get(&b)?).into())
     }
 }
 
@@ -454,18 +769,15 @@ where
     /// [`TraceAny`]: crate::entity::traverse::TraceAny
     unsafe fn bind_next_orphan<T>(&mut self) -> Option<T>
     where
+        Self::Entity: 'a,
         <Self::Entity as Payload>::Data: 'a,
         T: 'a + From<Orphan<'a, Self::Entity>>,
     {
         self.next().and_then(|key| {
             let entity = self.target().as_storage_mut().get_mut(&key);
             entity.map(|entity| {
-                let data = entity.get_mut();
-                let data = mem::transmute::<
-                    &'_ mut <Self::Entity as Payload>::Data,
-                    &'a mut <Self::Entity as Payload>::Data,
-                >(data);
-                Orphan::bind_unchecked(data, key).into()
+                let entity = mem::transmute::<&'_ mut Self::Entity, &'a mut Self::Entity>(entity);
+                Orphan::bind_unchecked(entity, key).into()
             })
         })
     }
@@ -559,6 +871,15 @@ impl<K> From<usize> for Selector<K> {
     }
 }
 
+/// Key of a vertex, arc, edge, or face in a [`MeshGraph`].
+///
+/// This is used by [`GraphError::AtKey`] to identify the entity that caused
+/// an error. Keys are widened to `u64`, so a `GraphKey` can be compared with
+/// the keys of a graph using any [`KeyIndex`] via `From`.
+///
+/// [`KeyIndex`]: crate::graph::KeyIndex
+/// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+/// [`MeshGraph`]: crate::graph::MeshGraph
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum GraphKey {
     Vertex(VertexKey),
@@ -567,27 +888,41 @@ pub enum GraphKey {
     Face(FaceKey),
 }
 
-impl From<VertexKey> for GraphKey {
-    fn from(key: VertexKey) -> Self {
-        GraphKey::Vertex(key)
+impl<K> From<VertexKey<K>> for GraphKey
+where
+    K: KeyIndex,
+{
+    fn from(key: VertexKey<K>) -> Self {
+        GraphKey::Vertex(VertexKey::from_inner(key.into_inner().into_u64()))
     }
 }
 
-impl From<ArcKey> for GraphKey {
-    fn from(key: ArcKey) -> Self {
-        GraphKey::Arc(key)
+impl<K> From<ArcKey<K>> for GraphKey
+where
+    K: KeyIndex,
+{
+    fn from(key: ArcKey<K>) -> Self {
+        let (a, b) = key.into();
+        let widen = |key: VertexKey<K>| VertexKey::from_inner(key.into_inner().into_u64());
+        GraphKey::Arc((widen(a), widen(b)).into())
     }
 }
 
-impl From<EdgeKey> for GraphKey {
-    fn from(key: EdgeKey) -> Self {
-        GraphKey::Edge(key)
+impl<K> From<EdgeKey<K>> for GraphKey
+where
+    K: KeyIndex,
+{
+    fn from(key: EdgeKey<K>) -> Self {
+        GraphKey::Edge(EdgeKey::from_inner(key.into_inner().into_u64()))
     }
 }
 
-impl From<FaceKey> for GraphKey {
-    fn from(key: FaceKey) -> Self {
-        GraphKey::Face(key)
+impl<K> From<FaceKey<K>> for GraphKey
+where
+    K: KeyIndex,
+{
+    fn from(key: FaceKey<K>) -> Self {
+        GraphKey::Face(FaceKey::from_inner(key.into_inner().into_u64()))
     }
 }
 
@@ -610,16 +945,18 @@ impl From<FaceKey> for GraphKey {
 ///
 /// [`GraphData`]: crate::graph::GraphData
 /// [`graph`]: crate::graph
-pub struct MeshGraph<G = (R64, R64, R64)>
+pub struct MeshGraph<G = (R64, R64, R64), K = u64>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    core: OwnedCore<G>,
+    core: OwnedCore<G, K>,
 }
 
-impl<G> MeshGraph<G>
+impl<G, K> MeshGraph<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     /// Creates an empty `MeshGraph`.
     ///
@@ -640,12 +977,12 @@ where
     }
 
     /// Gets an immutable view of the vertex with the given key.
-    pub fn vertex(&self, key: VertexKey) -> Option<VertexView<&Self>> {
+    pub fn vertex(&self, key: VertexKey<K>) -> Option<VertexView<&Self>> {
         Bind::bind(self, key)
     }
 
     /// Gets a mutable view of the vertex with the given key.
-    pub fn vertex_mut(&mut self, key: VertexKey) -> Option<VertexView<&mut Self>> {
+    pub fn vertex_mut(&mut self, key: VertexKey<K>) -> Option<VertexView<&mut Self>> {
         Bind::bind(self, key)
     }
 
@@ -660,32 +997,101 @@ where
             .map(From::from)
     }
 
+    /// Gets an iterator of immutable views over the vertices in the graph with
+    /// a valence other than the given regular valence.
+    ///
+    /// The regular valence of vertices in triangular meshes is six and in
+    /// quadrilateral meshes is four. Note that boundary vertices typically have
+    /// a lower valence and so are usually irregular. See
+    /// [`VertexView::valence`].
+    ///
+    /// [`VertexView::valence`]: crate::graph::VertexView::valence
+    pub fn irregular_vertices(&self, regular: usize) -> impl Iterator<Item = VertexView<&Self>> {
+        self.vertices()
+            .filter(move |vertex| vertex.valence() != regular)
+    }
+
+    /// Gets an iterator of immutable views over the boundary vertices in the
+    /// graph.
+    ///
+    /// Boundary vertices are typically pinned by smoothing and remeshing
+    /// operations. See [`VertexView::is_boundary_vertex`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    ///
+    /// // Construct a grid of 4x4 vertices.
+    /// let graph = MeshGraph::<Point3<f64>>::from_heightmap(&[0.0; 16], 4, 4).unwrap();
+    ///
+    /// assert_eq!(12, graph.boundary_vertices().count());
+    /// ```
+    ///
+    /// [`VertexView::is_boundary_vertex`]: crate::graph::VertexView::is_boundary_vertex
+    pub fn boundary_vertices(&self) -> impl Iterator<Item = VertexView<&Self>> {
+        self.vertices().filter(|vertex| vertex.is_boundary_vertex())
+    }
+
     /// Gets an iterator of orphan views over the vertices in the graph.
-    pub fn vertex_orphans(&mut self) -> impl Iterator<Item = VertexOrphan<G>> {
+    pub fn vertex_orphans(&mut self) -> impl Iterator<Item = VertexOrphan<G, K>> {
         self.core
             .vertices
             .iter_mut()
-            .map(|(key, data)| Orphan::bind_unchecked(data, key))
+            .map(|(key, entity)| Orphan::bind_unchecked(entity, key))
             .map(From::from)
     }
 
     /// Gets the number of arcs in the graph.
+    ///
+    /// Every edge is formed from a pair of opposite arcs, so this is always
+    /// twice the number of edges in the graph.
     pub fn arc_count(&self) -> usize {
         self.core.arcs.len()
     }
 
     /// Gets an immutable view of the arc with the given key.
-    pub fn arc(&self, key: ArcKey) -> Option<ArcView<&Self>> {
+    pub fn arc(&self, key: ArcKey<K>) -> Option<ArcView<&Self>> {
         Bind::bind(self, key)
     }
 
     /// Gets a mutable view of the arc with the given key.
-    pub fn arc_mut(&mut self, key: ArcKey) -> Option<ArcView<&mut Self>> {
+    pub fn arc_mut(&mut self, key: ArcKey<K>) -> Option<ArcView<&mut Self>> {
         Bind::bind(self, key)
     }
 
     // TODO: Return `Clone + Iterator`.
     /// Gets an iterator of immutable views over the arcs in the graph.
+    ///
+    /// Arcs are directed (they are sometimes referred to as _half-edges_), so
+    /// both arcs of every edge are visited, including boundary arcs that have
+    /// no leading face. To visit undirected edges, use [`MeshGraph::edges`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2)],
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(6, graph.arcs().count());
+    /// assert_eq!(3, graph.arcs().filter(|arc| arc.is_boundary_arc()).count());
+    /// assert_eq!(3, graph.edges().count());
+    /// ```
     pub fn arcs(&self) -> impl Iterator<Item = ArcView<&Self>> {
         self.core
             .arcs
@@ -696,11 +1102,11 @@ where
     }
 
     /// Gets an iterator of orphan views over the arcs in the graph.
-    pub fn arc_orphans(&mut self) -> impl Iterator<Item = ArcOrphan<G>> {
+    pub fn arc_orphans(&mut self) -> impl Iterator<Item = ArcOrphan<G, K>> {
         self.core
             .arcs
             .iter_mut()
-            .map(|(key, data)| Orphan::bind_unchecked(data, key))
+            .map(|(key, entity)| Orphan::bind_unchecked(entity, key))
             .map(From::from)
     }
 
@@ -710,17 +1116,22 @@ where
     }
 
     /// Gets an immutable view of the edge with the given key.
-    pub fn edge(&self, key: EdgeKey) -> Option<EdgeView<&Self>> {
+    pub fn edge(&self, key: EdgeKey<K>) -> Option<EdgeView<&Self>> {
         Bind::bind(self, key)
     }
 
     /// Gets a mutable view of the edge with the given key.
-    pub fn edge_mut(&mut self, key: EdgeKey) -> Option<EdgeView<&mut Self>> {
+    pub fn edge_mut(&mut self, key: EdgeKey<K>) -> Option<EdgeView<&mut Self>> {
         Bind::bind(self, key)
     }
 
     // TODO: Return `Clone + Iterator`.
     /// Gets an iterator of immutable views over the edges in the graph.
+    ///
+    /// Edges are undirected, so each pair of opposite arcs is visited once.
+    /// See [`EdgeView::arc_pair`] and [`MeshGraph::arcs`].
+    ///
+    /// [`EdgeView::arc_pair`]: crate::graph::EdgeView::arc_pair
     pub fn edges(&self) -> impl Iterator<Item = EdgeView<&Self>> {
         self.core
             .edges
@@ -731,11 +1142,11 @@ where
     }
 
     /// Gets an iterator of orphan views over the edges in the graph.
-    pub fn edge_orphans(&mut self) -> impl Iterator<Item = EdgeOrphan<G>> {
+    pub fn edge_orphans(&mut self) -> impl Iterator<Item = EdgeOrphan<G, K>> {
         self.core
             .edges
             .iter_mut()
-            .map(|(key, data)| Orphan::bind_unchecked(data, key))
+            .map(|(key, entity)| Orphan::bind_unchecked(entity, key))
             .map(From::from)
     }
 
@@ -745,12 +1156,12 @@ where
     }
 
     /// Gets an immutable view of the face with the given key.
-    pub fn face(&self, key: FaceKey) -> Option<FaceView<&Self>> {
+    pub fn face(&self, key: FaceKey<K>) -> Option<FaceView<&Self>> {
         Bind::bind(self, key)
     }
 
     /// Gets a mutable view of the face with the given key.
-    pub fn face_mut(&mut self, key: FaceKey) -> Option<FaceView<&mut Self>> {
+    pub fn face_mut(&mut self, key: FaceKey<K>) -> Option<FaceView<&mut Self>> {
         Bind::bind(self, key)
     }
 
@@ -765,12 +1176,75 @@ where
             .map(From::from)
     }
 
+    /// Gets an iterator of immutable views over the faces in the graph with the
+    /// given arity.
+    ///
+    /// This can be used to process faces grouped by arity, such as when
+    /// exporting triangles and quadrilaterals into separate buffers. See also
+    /// [`DynamicArity::arity`], which summarizes the arity of the graph as a
+    /// [`MeshArity`].
+    ///
+    /// [`DynamicArity::arity`]: crate::DynamicArity::arity
+    /// [`MeshArity`]: crate::MeshArity
+    pub fn faces_with_arity(&self, arity: usize) -> impl Iterator<Item = FaceView<&Self>> {
+        self.faces().filter(move |face| face.arity() == arity)
+    }
+
+    /// Maps the faces in the graph in parallel.
+    ///
+    /// The given function is evaluated for each face using a thread pool and
+    /// the results are collected into a map keyed by face. This is useful for
+    /// expensive per-face computations, such as normals or areas, over large
+    /// graphs.
+    ///
+    /// The function receives immutable views of faces and the graph is
+    /// borrowed immutably for the duration of the map, so the function may
+    /// read any topology and data in the graph but cannot modify it. Any
+    /// interior mutability in the function (or graph data) must be
+    /// synchronized.
+    ///
+    /// This function requires the `rayon` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let graph: MeshGraph<E3> = UvSphere::new(64, 32)
+    ///     .polygons::<Position<Point3<R64>>>()
+    ///     .collect();
+    /// let areas = graph.par_face_map(|face| face.area());
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_face_map<T, F>(&self, f: F) -> HashMap<FaceKey<K>, T>
+    where
+        Self: Sync,
+        T: Send,
+        F: Fn(FaceView<&Self>) -> T + Send + Sync,
+    {
+        let keys = self.faces().map(|face| face.key()).collect::<Vec<_>>();
+        keys.into_par_iter()
+            .map(|key| (key, f(self.face(key).expect_consistent())))
+            .collect()
+    }
+
     /// Gets an iterator of orphan views over the faces in the graph.
-    pub fn face_orphans(&mut self) -> impl Iterator<Item = FaceOrphan<G>> {
+    pub fn face_orphans(&mut self) -> impl Iterator<Item = FaceOrphan<G, K>> {
         self.core
             .faces
             .iter_mut()
-            .map(|(key, data)| Orphan::bind_unchecked(data, key))
+            .map(|(key, entity)| Orphan::bind_unchecked(entity, key))
             .map(From::from)
     }
 
@@ -782,7 +1256,7 @@ where
     pub fn path<I>(&self, keys: I) -> Result<Path<'static, &Self>, GraphError>
     where
         I: IntoIterator,
-        I::Item: Borrow<VertexKey>,
+        I::Item: Borrow<VertexKey<K>>,
     {
         Path::bind(self, keys)
     }
@@ -795,7 +1269,7 @@ where
     pub fn path_mut<I>(&mut self, keys: I) -> Result<Path<'static, &mut Self>, GraphError>
     where
         I: IntoIterator,
-        I::Item: Borrow<VertexKey>,
+        I::Item: Borrow<VertexKey<K>>,
     {
         Path::bind(self, keys)
     }
@@ -855,32 +1329,101 @@ where
         }
     }
 
-    /// Smooths the positions of vertices in the graph.
+    /// Inserts faces into the graph.
+    ///
+    /// Each face is given as an ordered perimeter of vertex keys and its face
+    /// data. Faces are inserted as a batch: edges are shared by all faces in
+    /// the batch, so the arcs of adjacent faces are paired with one another as
+    /// they are inserted, and the boundary arcs about the faces are connected
+    /// once after all faces have been inserted. This is more efficient than
+    /// inserting faces one at a time.
+    ///
+    /// The batch is validated against the graph before any face is inserted,
+    /// so the graph is left unmodified if any face cannot be inserted.
+    ///
+    /// Returns the keys of the inserted faces in the order they were given.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a face cannot be inserted, such as when a perimeter
+    /// has fewer than three vertices or repeats a vertex, a vertex is not
+    /// found, an arc is already occupied by a face in the graph or an earlier
+    /// face in the batch, or an arc of the graph bisects a perimeter. The error
+    /// is wrapped with the index of the offending face in the batch (see
+    /// [`GraphError::AtIndex`]). Errors concerning a vertex or arc are also
+    /// wrapped with its key (see [`GraphError::AtKey`]). Returns
+    /// [`GraphError::KeyspaceExhausted`] if the keyspace of the graph cannot
+    /// accommodate the inserted edges and faces (see [`KeyIndex`]). In all of
+    /// these cases, the graph is not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2)],
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+    /// )
+    /// .unwrap();
+    ///
+    /// // Insert a face into the boundary of the triangle, which has the opposite
+    /// // winding.
+    /// let perimeter = graph
+    ///     .faces()
+    ///     .nth(0)
+    ///     .unwrap()
+    ///     .adjacent_vertices()
+    ///     .keys()
+    ///     .collect::<Vec<_>>();
+    /// let faces = graph
+    ///     .insert_faces(vec![(perimeter.into_iter().rev(), ())])
+    ///     .unwrap();
+    /// assert_eq!(1, faces.len());
+    /// assert_eq!(2, graph.face_count());
+    /// ```
     ///
-    /// Each position is translated by its offset from its centroid scaled by
-    /// the given factor. The centroid of a vertex position is the mean of the
-    /// positions of its adjacent vertices. That is, given a factor $k$ and a
-    /// vertex with position $P$ and centroid $Q$, its position becomes
-    /// $P+k(Q-P)$.
-    pub fn smooth<T>(&mut self, factor: T)
+    /// [`GraphError::AtIndex`]: crate::graph::GraphError::AtIndex
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    /// [`GraphError::KeyspaceExhausted`]: crate::graph::GraphError::KeyspaceExhausted
+    /// [`KeyIndex`]: crate::graph::KeyIndex
+    pub fn insert_faces<I, P>(&mut self, faces: I) -> Result<Vec<FaceKey<K>>, GraphError>
     where
-        T: Into<Scalar<VertexPosition<G>>>,
-        G: VertexCentroid,
-        G::Vertex: AsPositionMut,
-        VertexPosition<G>: EuclideanSpace,
+        I: IntoIterator<Item = (P, G::Face)>,
+        P: IntoIterator,
+        P::Item: Borrow<VertexKey<K>>,
     {
-        let factor = factor.into();
-        let mut positions = HashMap::with_capacity(self.vertex_count());
-        for vertex in self.vertices() {
-            let position = *vertex.position();
-            positions.insert(
-                vertex.key(),
-                position + ((vertex.centroid() - position) * factor),
-            );
-        }
-        for mut vertex in self.vertex_orphans() {
-            *vertex.get_mut().as_position_mut() = positions.remove(&vertex.key()).unwrap();
-        }
+        let (perimeters, data): (Vec<_>, Vec<_>) = faces
+            .into_iter()
+            .map(|(perimeter, data)| {
+                (
+                    perimeter
+                        .into_iter()
+                        .map(|key| *key.borrow())
+                        .collect::<SmallVec<[_; 4]>>(),
+                    data,
+                )
+            })
+            .unzip();
+        // The batch is validated against the graph and its keyspace, so the
+        // graph is not taken unless the faces can be inserted. Once taken, the
+        // mutation cannot fail without leaving the graph empty.
+        let cache = FaceBatchInsertCache::from_storage(&*self, perimeters)?;
+        let mut mutation = Mutation::from(mem::take(self));
+        // This should never fail here.
+        let keys =
+            mutation::face::insert_batch_with(&mut mutation, cache, data).expect_consistent();
+        *self = mutation
+            .commit()
+            .map_err(|(_, error)| error)
+            .expect_consistent();
+        Ok(keys)
     }
 
     /// Splits the graph along a path.
@@ -986,6 +1529,110 @@ where
         unimplemented!()
     }
 
+    /// Extracts the given faces into a new and independent graph.
+    ///
+    /// The subgraph contains the given faces and the edges and vertices that
+    /// they reference. Faces that share vertices and edges in the graph share
+    /// them in the subgraph and edges on the boundary of the selection are
+    /// boundary edges in the subgraph. The data of all entities is cloned.
+    /// Returns the subgraph and a mapping of keys from the graph into the
+    /// subgraph.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a face cannot be found or if the faces cannot form
+    /// a consistent graph (e.g., if faces only meet at a vertex in a way that
+    /// cannot be represented). The error is wrapped with the key of the
+    /// offending face (see [`GraphError::AtKey`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// let (subgraph, mapping) = graph.subgraph(Some(key)).unwrap();
+    ///
+    /// assert_eq!(1, subgraph.face_count());
+    /// assert_eq!(4, subgraph.vertex_count());
+    /// ```
+    ///
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    pub fn subgraph<I>(&self, faces: I) -> Result<(Self, SubgraphMapping<K>), GraphError>
+    where
+        I: IntoIterator<Item = FaceKey<K>>,
+    {
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let mut mapping = SubgraphMapping::default();
+        for key in faces {
+            if mapping.faces.contains_key(&key) {
+                continue;
+            }
+            let face = self
+                .face(key)
+                .ok_or_else(|| GraphError::TopologyNotFound(TopologyKind::Face).at_key(key))?;
+            let mut perimeter = SmallVec::<[_; 4]>::new();
+            for vertex in face.adjacent_vertices() {
+                let key = match mapping.vertices.get(&vertex.key()) {
+                    Some(key) => *key,
+                    None => {
+                        let key = mutation::vertex::insert(&mut mutation, vertex.get().clone())?;
+                        mapping.vertices.insert(vertex.key(), key);
+                        key
+                    }
+                };
+                perimeter.push(key);
+            }
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)
+                .map_err(|error| error.at_key(key))?;
+            let data = face.get().clone();
+            let abc =
+                mutation::face::insert_with(&mut mutation, cache, || (Default::default(), data))
+                    .map_err(|error| error.at_key(key))?;
+            mapping.faces.insert(key, abc);
+        }
+        let mut graph: Self = mutation.commit().map_err(|(_, error)| error)?;
+        // Copy the data of arcs and edges, which are inserted with default
+        // data along with faces.
+        for key in mapping.faces.keys() {
+            for arc in self.face(*key).expect_consistent().adjacent_arcs() {
+                for arc in [arc.to_ref(), arc.opposite_arc()] {
+                    let ab = mapping.arc(arc.key()).expect_consistent();
+                    let mut target = graph.arc_mut(ab).expect_consistent();
+                    *target.get_mut() = arc.get().clone();
+                    let mut edge = target.into_edge();
+                    *edge.get_mut() = arc.edge().get().clone();
+                    mapping.edges.insert(arc.edge().key(), edge.key());
+                }
+            }
+        }
+        Ok((graph, mapping))
+    }
+
+    /// Reserves capacity for at least the given number of additional vertices,
+    /// edges, and faces in the graph's underlying storage.
+    ///
+    /// Capacity is also reserved for the arcs of the edges. This can avoid
+    /// repeated reallocation when inserting many entities into a graph.
+    pub fn reserve(&mut self, vertices: usize, edges: usize, faces: usize) {
+        self.core.vertices.reserve(vertices);
+        self.core.arcs.reserve(edges * 2);
+        self.core.edges.reserve(edges);
+        self.core.faces.reserve(faces);
+    }
+
     /// Shrinks the capacity of the graph's underlying storage as much as
     /// possible.
     pub fn shrink_to_fit(&mut self) {
@@ -995,44 +1642,214 @@ where
         self.core.faces.shrink_to_fit();
     }
 
-    /// Creates a [`Buildable`] mesh data structure from the graph.
+    /// Reverses the winding of all faces in the graph.
     ///
-    /// The output is created from each unique vertex in the graph. No face data
-    /// is used, and the `Facet` type is always the unit type `()`.
+    /// The ring of every face is reversed, so the orientation (and normals) of
+    /// faces are inverted. This is useful for graphs with faces that point
+    /// inward, such as those loaded from some STL files. Each arc is replaced
+    /// by its opposite arc and the links between arcs are reversed. Data is
+    /// preserved and moves with its arc, so the data of an arc remains
+    /// associated with the same face. Keys of vertices, edges, and faces are
+    /// unchanged.
     ///
-    /// # Examples
+    /// Note that because an arc is part of at most one face, the faces of a
+    /// connected graph are always consistently oriented. Reversing the winding
+    /// of a graph does not change this.
     ///
-    /// Creating a [`MeshBuffer`] from a [`MeshGraph`] used to modify a cube:
+    /// # Examples
     ///
     /// ```rust
     /// # extern crate decorum;
     /// # extern crate nalgebra;
     /// # extern crate plexus;
     /// #
-    /// use decorum::N64;
+    /// use decorum::R64;
     /// use nalgebra::Point3;
-    /// use plexus::buffer::MeshBufferN;
     /// use plexus::graph::MeshGraph;
     /// use plexus::prelude::*;
     /// use plexus::primitive::cube::Cube;
     /// use plexus::primitive::generate::Position;
     ///
-    /// type E3 = Point3<N64>;
+    /// type E3 = Point3<f64>;
     ///
-    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
-    /// let key = graph.faces().nth(0).unwrap().key();
-    /// graph
-    ///     .face_mut(key)
-    ///     .unwrap()
-    ///     .extrude_with_offset(1.0)
-    ///     .unwrap();
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+    /// let volume = graph.volume().unwrap();
     ///
-    /// let buffer: MeshBufferN<usize, E3> = graph.to_mesh_by_vertex().unwrap();
+    /// graph.flip_winding();
+    /// assert!((volume + graph.volume().unwrap()).abs() < 1e-9);
     /// ```
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the graph does not have constant arity that is
+    pub fn flip_winding(&mut self) {
+        let mut arcs = mem::take(&mut self.core.arcs);
+        let keys = arcs.iter().map(|(key, _)| key).collect::<Vec<_>>();
+        for key in keys {
+            // The arc `ab` becomes the arc `ba`. Its next arc `bc` becomes its
+            // previous arc `cb` and its previous arc `za` becomes its next arc
+            // `az`.
+            let mut arc = arcs.remove(&key).expect_consistent();
+            let (next, previous) = (arc.next, arc.previous);
+            arc.next = previous.map(ArcKey::into_opposite);
+            arc.previous = next.map(ArcKey::into_opposite);
+            self.core.arcs.insert_with_key(&key.into_opposite(), arc);
+        }
+        let keys = self
+            .core
+            .faces
+            .iter()
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+        for key in keys {
+            let face = self.core.faces.get_mut(&key).expect_consistent();
+            face.arc = face.arc.into_opposite();
+        }
+    }
+
+    /// Rekeys the topology of the graph into dense ranges of keys.
+    ///
+    /// Removing topology leaves gaps in the keys of the remaining topology.
+    /// Compacting the graph rekeys its vertices, edges, and faces such that
+    /// keys are allocated contiguously and without gaps (in the same relative
+    /// order as before) and rewrites all references between topology. Data,
+    /// connectivity, and layers are preserved. Storage is also shrunk to fit.
+    /// This can reduce memory usage and improve locality after many removals,
+    /// such as decimation.
+    ///
+    /// Returns a [`Compaction`] that maps the keys of the graph before
+    /// compaction to the keys of the graph after compaction, which can be used
+    /// to migrate any data that is associated with keys externally.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = UvSphere::new(8, 8)
+    ///     .polygons::<Position<Point3<R64>>>()
+    ///     .collect();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// graph.face_mut(key).unwrap().remove();
+    ///
+    /// let compaction = graph.compact();
+    /// for (from, to) in compaction.faces.iter() {
+    ///     // Migrate data associated with the face `from` to the face `to`.
+    /// }
+    /// ```
+    ///
+    /// [`Compaction`]: crate::graph::Compaction
+    pub fn compact(&mut self) -> Compaction<K> {
+        let compaction = Compaction {
+            vertices: self.core.vertices.rekey(),
+            edges: self.core.edges.rekey(),
+            faces: self.core.faces.rekey(),
+        };
+        self.core.layers.vertices.rekey(&compaction.vertices);
+        self.core.layers.edges.rekey(&compaction.edges);
+        self.core.layers.faces.rekey(&compaction.faces);
+        // Arcs are keyed by their vertices, so their keys and references must
+        // be rewritten using the mapping of vertex keys.
+        let map = |ab| compaction.arc(ab).expect_consistent();
+        let mut arcs = mem::take(&mut self.core.arcs);
+        let keys = arcs.iter().map(|(key, _)| key).collect::<Vec<_>>();
+        for key in keys {
+            let mut arc = arcs.remove(&key).expect_consistent();
+            arc.next = arc.next.map(map);
+            arc.previous = arc.previous.map(map);
+            arc.edge = arc.edge.map(|key| compaction.edges[&key]);
+            arc.face = arc.face.map(|key| compaction.faces[&key]);
+            self.core.arcs.insert_with_key(&map(key), arc);
+        }
+        for key in compaction.vertices.values() {
+            let vertex = self.core.vertices.get_mut(key).expect_consistent();
+            vertex.arc = vertex.arc.map(map);
+        }
+        for key in compaction.edges.values() {
+            let edge = self.core.edges.get_mut(key).expect_consistent();
+            edge.arc = map(edge.arc);
+        }
+        for key in compaction.faces.values() {
+            let face = self.core.faces.get_mut(key).expect_consistent();
+            face.arc = map(face.arc);
+        }
+        self.shrink_to_fit();
+        compaction
+    }
+
+    // Replaces the graph with a graph that has been rebuilt from it and moves
+    // its layers into the rebuilt graph. Values are rekeyed using the given
+    // mappings of vertices and faces and edges are mapped by their vertices.
+    // Values of topology that has no counterpart in the rebuilt graph are
+    // discarded and topology that is new to the rebuilt graph has the default
+    // values of its layers.
+    fn replace_with_rebuild(
+        &mut self,
+        mut graph: Self,
+        vertices: &HashMap<VertexKey<K>, VertexKey<K>>,
+        faces: &HashMap<FaceKey<K>, FaceKey<K>>,
+    ) {
+        let edges = self
+            .edges()
+            .filter_map(|edge| {
+                let (a, b) = edge.arc().key().into();
+                let ab = (*vertices.get(&a)?, *vertices.get(&b)?).into();
+                graph.arc(ab).map(|arc| (edge.key(), arc.edge().key()))
+            })
+            .collect::<HashMap<_, _>>();
+        let mut layers = mem::take(&mut self.core.layers);
+        layers.vertices.rekey(vertices);
+        layers.edges.rekey(&edges);
+        layers.faces.rekey(faces);
+        graph.core.layers = layers;
+        *self = graph;
+    }
+
+    /// Creates a [`Buildable`] mesh data structure from the graph.
+    ///
+    /// The output is created from each unique vertex in the graph. No face data
+    /// is used, and the `Facet` type is always the unit type `()`.
+    ///
+    /// # Examples
+    ///
+    /// Creating a [`MeshBuffer`] from a [`MeshGraph`] used to modify a cube:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::N64;
+    /// use nalgebra::Point3;
+    /// use plexus::buffer::MeshBufferN;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<N64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// graph
+    ///     .face_mut(key)
+    ///     .unwrap()
+    ///     .extrude_with_offset(1.0)
+    ///     .unwrap();
+    ///
+    /// let buffer: MeshBufferN<usize, E3> = graph.to_mesh_by_vertex().unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph does not have constant arity that is
     /// compatible with the index buffer. Typically, a graph is triangulated
     /// before being converted to a buffer.
     ///
@@ -1184,77 +2001,179 @@ where
         })?;
         builder.build()
     }
+
+    /// Creates a [`Buildable`] mesh data structure from the corners of faces
+    /// in the graph.
+    ///
+    /// A _corner_ is a vertex within a particular face and is represented by
+    /// the arc in that face with the vertex as its destination. The output
+    /// vertex data for each corner is computed from its arc by the given
+    /// function, so corner attributes like texture coordinates can be stored
+    /// in arc data. Corners of the same vertex with equal output data are
+    /// merged into a single output vertex, so a vertex is only inserted more
+    /// than once where its corners differ, such as along texture seams. The
+    /// data of each face is inserted into the output via [`FromGeometry`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::buffer::MeshBuffer;
+    /// use plexus::graph::{GraphData, MeshGraph};
+    /// use plexus::index::Flat3;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// // Texture coordinates are stored in arc data.
+    /// pub enum Textured {}
+    ///
+    /// impl GraphData for Textured {
+    ///     type Vertex = E3;
+    ///     type Arc = [f64; 2];
+    ///     type Edge = ();
+    ///     type Face = ();
+    /// }
+    ///
+    /// let cube = Cube::new();
+    /// let graph = MeshGraph::<Textured>::from_raw_buffers(
+    ///     cube.indexing_polygons::<Position>().triangulate(),
+    ///     cube.vertices::<Position<E3>>(),
+    /// )
+    /// .unwrap();
+    /// // ...
+    ///
+    /// let buffer: MeshBuffer<Flat3, _> = graph
+    ///     .to_mesh_by_corner_with(|arc| (*arc.destination_vertex().position(), *arc.get()))
+    ///     .unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vertex data cannot be inserted into the output,
+    /// there are arity conflicts, or the output does not support topology found
+    /// in the graph.
+    ///
+    /// [`Buildable`]: crate::builder::Buildable
+    /// [`FromGeometry`]: crate::geometry::FromGeometry
+    pub fn to_mesh_by_corner_with<B, F>(&self, mut f: F) -> Result<B, B::Error>
+    where
+        B: Buildable,
+        B::Vertex: Clone + PartialEq,
+        B::Facet: FromGeometry<G::Face>,
+        F: FnMut(ArcView<&Self>) -> B::Vertex,
+    {
+        let mut builder = B::builder();
+        builder.surface_with(|builder| {
+            let mut corners: HashMap<_, SmallVec<[(B::Vertex, _); 4]>> = HashMap::new();
+            for face in self.faces() {
+                let mut indices = SmallVec::<[_; 8]>::new();
+                for arc in face.adjacent_arcs() {
+                    let key = arc.destination_vertex().key();
+                    let data = f(arc);
+                    let corners = corners.entry(key).or_default();
+                    let index = match corners.iter().find(|(other, _)| *other == data) {
+                        Some((_, index)) => *index,
+                        _ => {
+                            let index = builder.insert_vertex(data.clone())?;
+                            corners.push((data, index));
+                            index
+                        }
+                    };
+                    indices.push(index);
+                }
+                builder.facets_with(|builder| {
+                    builder.insert_facet(indices.as_slice(), face.get().clone())
+                })?;
+            }
+            Ok(())
+        })?;
+        builder.build()
+    }
 }
 
-impl<G> AsStorage<Vertex<G>> for MeshGraph<G>
+impl<G, K> AsStorage<Vertex<G, K>> for MeshGraph<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    fn as_storage(&self) -> &StorageTarget<Vertex<G>> {
-        self.core.as_storage_of::<Vertex<_>>()
+    fn as_storage(&self) -> &StorageTarget<Vertex<G, K>> {
+        self.core.as_storage_of::<Vertex<_, _>>()
     }
 }
 
-impl<G> AsStorage<Arc<G>> for MeshGraph<G>
+impl<G, K> AsStorage<Arc<G, K>> for MeshGraph<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    fn as_storage(&self) -> &StorageTarget<Arc<G>> {
-        self.core.as_storage_of::<Arc<_>>()
+    fn as_storage(&self) -> &StorageTarget<Arc<G, K>> {
+        self.core.as_storage_of::<Arc<_, _>>()
     }
 }
 
-impl<G> AsStorage<Edge<G>> for MeshGraph<G>
+impl<G, K> AsStorage<Edge<G, K>> for MeshGraph<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    fn as_storage(&self) -> &StorageTarget<Edge<G>> {
-        self.core.as_storage_of::<Edge<_>>()
+    fn as_storage(&self) -> &StorageTarget<Edge<G, K>> {
+        self.core.as_storage_of::<Edge<_, _>>()
     }
 }
 
-impl<G> AsStorage<Face<G>> for MeshGraph<G>
+impl<G, K> AsStorage<Face<G, K>> for MeshGraph<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    fn as_storage(&self) -> &StorageTarget<Face<G>> {
-        self.core.as_storage_of::<Face<_>>()
+    fn as_storage(&self) -> &StorageTarget<Face<G, K>> {
+        self.core.as_storage_of::<Face<_, _>>()
     }
 }
 
-impl<G> AsStorageMut<Vertex<G>> for MeshGraph<G>
+impl<G, K> AsStorageMut<Vertex<G, K>> for MeshGraph<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    fn as_storage_mut(&mut self) -> &mut StorageTarget<Vertex<G>> {
-        self.core.as_storage_mut_of::<Vertex<_>>()
+    fn as_storage_mut(&mut self) -> &mut StorageTarget<Vertex<G, K>> {
+        self.core.as_storage_mut_of::<Vertex<_, _>>()
     }
 }
 
-impl<G> AsStorageMut<Arc<G>> for MeshGraph<G>
+impl<G, K> AsStorageMut<Arc<G, K>> for MeshGraph<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    fn as_storage_mut(&mut self) -> &mut StorageTarget<Arc<G>> {
-        self.core.as_storage_mut_of::<Arc<_>>()
+    fn as_storage_mut(&mut self) -> &mut StorageTarget<Arc<G, K>> {
+        self.core.as_storage_mut_of::<Arc<_, _>>()
     }
 }
 
-impl<G> AsStorageMut<Edge<G>> for MeshGraph<G>
+impl<G, K> AsStorageMut<Edge<G, K>> for MeshGraph<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    fn as_storage_mut(&mut self) -> &mut StorageTarget<Edge<G>> {
-        self.core.as_storage_mut_of::<Edge<_>>()
+    fn as_storage_mut(&mut self) -> &mut StorageTarget<Edge<G, K>> {
+        self.core.as_storage_mut_of::<Edge<_, _>>()
     }
 }
 
-impl<G> AsStorageMut<Face<G>> for MeshGraph<G>
+impl<G, K> AsStorageMut<Face<G, K>> for MeshGraph<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    fn as_storage_mut(&mut self) -> &mut StorageTarget<Face<G>> {
-        self.core.as_storage_mut_of::<Face<_>>()
+    fn as_storage_mut(&mut self) -> &mut StorageTarget<Face<G, K>> {
+        self.core.as_storage_mut_of::<Face<_, _>>()
     }
 }
 
@@ -1291,11 +2210,12 @@ where
 /// [`MeshBuilder`]: crate::builder::MeshBuilder
 /// [`builder`]: crate::builder
 /// [`MeshGraph`]: crate::graph::MeshGraph
-impl<G> Buildable for MeshGraph<G>
+impl<G, K> Buildable for MeshGraph<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    type Builder = GraphBuilder<G>;
+    type Builder = GraphBuilder<G, K>;
     type Error = GraphError;
 
     type Vertex = G::Vertex;
@@ -1306,20 +2226,43 @@ where
     }
 }
 
-impl<G> Consistent for MeshGraph<G> where G: GraphData {}
+impl<G, K> Consistent for MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+}
+
+impl<G, K> Debug for MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("MeshGraph")
+            .field("vertices", &self.vertex_count())
+            .field("arcs", &self.arc_count())
+            .field("edges", &self.edge_count())
+            .field("faces", &self.face_count())
+            .finish()
+    }
+}
 
-impl<G> Default for MeshGraph<G>
+impl<G, K> Default for MeshGraph<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     fn default() -> Self {
         MeshGraph::new()
     }
 }
 
-impl<G> DynamicArity for MeshGraph<G>
+impl<G, K> DynamicArity for MeshGraph<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     type Dynamic = MeshArity;
 
@@ -1328,10 +2271,11 @@ where
     }
 }
 
-impl<P, G> From<P> for MeshGraph<G>
+impl<P, G, K> From<P> for MeshGraph<G, K>
 where
     P: Polygonal,
     G: GraphData,
+    K: KeyIndex,
     G::Vertex: FromGeometry<P::Vertex>,
 {
     fn from(polygon: P) -> Self {
@@ -1341,29 +2285,32 @@ where
     }
 }
 
-impl<G> From<OwnedCore<G>> for MeshGraph<G>
+impl<G, K> From<OwnedCore<G, K>> for MeshGraph<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    fn from(core: OwnedCore<G>) -> Self {
+    fn from(core: OwnedCore<G, K>) -> Self {
         MeshGraph { core }
     }
 }
 
-impl<G> From<MeshGraph<G>> for OwnedCore<G>
+impl<G, K> From<MeshGraph<G, K>> for OwnedCore<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    fn from(graph: MeshGraph<G>) -> Self {
+    fn from(graph: MeshGraph<G, K>) -> Self {
         let MeshGraph { core, .. } = graph;
         core
     }
 }
 
-impl<E, G> FromEncoding<E> for MeshGraph<G>
+impl<E, G, K> FromEncoding<E> for MeshGraph<G, K>
 where
     E: FaceDecoder + VertexDecoder,
     G: GraphData,
+    K: KeyIndex,
     G::Face: FromGeometry<E::Face>,
     G::Vertex: FromGeometry<E::Vertex>,
 {
@@ -1377,7 +2324,7 @@ where
         let keys = vertices
             .into_iter()
             .map(|data| mutation::vertex::insert(&mut mutation, data.into_geometry()))
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
         for (perimeter, data) in faces {
             let perimeter = perimeter
                 .into_iter()
@@ -1391,32 +2338,45 @@ where
     }
 }
 
-impl<G, P> FromIndexer<P, P> for MeshGraph<G>
+impl<G, K, P> FromIndexer<P, P> for MeshGraph<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
     G::Vertex: FromGeometry<P::Vertex>,
     P: Map<usize> + Polygonal,
-    P::Output: Grouping<Group = P::Output> + IntoVertices + Polygonal<Vertex = usize>,
-    Vec<P::Output>: IndexBuffer<P::Output, Index = usize>,
+    P::Output: IntoVertices + Polygonal<Vertex = usize>,
 {
     type Error = GraphError;
 
-    // TODO: This appears to be a false positive. The `collect` is necessary,
-    //       because the data is transformed and read randomly by index. See
-    //       https://github.com/rust-lang/rust-clippy/issues/5991
-    #[allow(clippy::needless_collect)]
-    fn from_indexer<I, N>(input: I, indexer: N) -> Result<Self, Self::Error>
+    fn from_indexer<I, N>(input: I, mut indexer: N) -> Result<Self, Self::Error>
     where
         I: IntoIterator<Item = P>,
         N: Indexer<P, P::Vertex>,
     {
-        let mut mutation = Mutation::from(MeshGraph::new());
-        let (indices, vertices) = input.into_iter().index_vertices(indexer);
-        let vertices = vertices
-            .into_iter()
-            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.into_geometry()))
-            .collect::<Vec<_>>();
-        for face in indices {
+        // Vertices and faces are inserted into the graph as polygons are
+        // produced, so index and vertex buffers are never materialized. Storage
+        // is reserved using lower bounds for closed triangular meshes.
+        let input = input.into_iter();
+        let (n, _) = input.size_hint();
+        let mut graph = MeshGraph::<G, K>::new();
+        graph.reserve(n / 2, n, n);
+        let mut mutation = Mutation::from(graph);
+        let mut vertices = Vec::with_capacity(n / 2);
+        // Vertices that are first indexed by a polygon are inserted after the
+        // polygon is mapped, because insertion may fail.
+        let mut pending = Vec::new();
+        for polygon in input {
+            let face = polygon.map(|vertex| {
+                let (index, vertex) = indexer.index(vertex, |vertex| vertex);
+                pending.extend(vertex);
+                index
+            });
+            for vertex in pending.drain(..) {
+                vertices.push(mutation::vertex::insert(
+                    &mut mutation,
+                    vertex.into_geometry(),
+                )?);
+            }
             let perimeter = face
                 .into_vertices()
                 .into_iter()
@@ -1429,9 +2389,10 @@ where
     }
 }
 
-impl<G, P> FromIterator<P> for MeshGraph<G>
+impl<G, K, P> FromIterator<P> for MeshGraph<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
     G::Vertex: FromGeometry<P::Vertex>,
     P: Polygonal,
     P::Vertex: Clone + Eq + Hash,
@@ -1445,11 +2406,12 @@ where
     }
 }
 
-impl<P, G, H> FromRawBuffers<P, H> for MeshGraph<G>
+impl<P, G, K, H> FromRawBuffers<P, H> for MeshGraph<G, K>
 where
     P: IntoVertices + Polygonal,
     P::Vertex: Integer + ToPrimitive + Unsigned,
     G: GraphData,
+    K: KeyIndex,
     G::Vertex: FromGeometry<H>,
 {
     type Error = GraphError;
@@ -1459,28 +2421,15 @@ where
         I: IntoIterator<Item = P>,
         J: IntoIterator<Item = H>,
     {
-        let mut mutation = Mutation::from(MeshGraph::new());
-        let vertices = vertices
-            .into_iter()
-            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.into_geometry()))
-            .collect::<Vec<_>>();
-        for face in indices {
-            let mut perimeter = SmallVec::<[_; 4]>::with_capacity(face.arity());
-            for index in face.into_vertices() {
-                let index = <usize as NumCast>::from(index).unwrap();
-                perimeter.push(*vertices.get(index).ok_or(GraphError::TopologyNotFound)?);
-            }
-            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
-            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
-        }
-        mutation.commit().map_err(|(_, error)| error)
+        MeshGraph::from_raw_buffers_with_keys(indices, vertices).map(|(graph, _)| graph)
     }
 }
 
-impl<N, G, H> FromRawBuffersWithArity<N, H> for MeshGraph<G>
+impl<N, G, K, H> FromRawBuffersWithArity<N, H> for MeshGraph<G, K>
 where
     N: Integer + ToPrimitive + Unsigned,
     G: GraphData,
+    K: KeyIndex,
     G::Vertex: FromGeometry<H>,
 {
     type Error = GraphError;
@@ -1527,50 +2476,20 @@ where
         I: IntoIterator<Item = N>,
         J: IntoIterator<Item = H>,
     {
-        use itertools::Itertools;
-
-        if arity < 3 {
-            return Err(GraphError::ArityNonPolygonal);
-        }
-        let mut mutation = Mutation::from(MeshGraph::new());
-        let vertices = vertices
-            .into_iter()
-            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.into_geometry()))
-            .collect::<Vec<_>>();
-        for face in &indices
-            .into_iter()
-            .map(|index| <usize as NumCast>::from(index).unwrap())
-            .chunks(arity)
-        {
-            let face = face.collect::<Vec<_>>();
-            if face.len() != arity {
-                // Index buffer length is not a multiple of arity.
-                return Err(GraphError::ArityConflict {
-                    expected: arity,
-                    actual: face.len(),
-                });
-            }
-            let mut perimeter = SmallVec::<[_; 4]>::with_capacity(arity);
-            for index in face {
-                perimeter.push(*vertices.get(index).ok_or(GraphError::TopologyNotFound)?);
-            }
-            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
-            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
-        }
-        mutation.commit().map_err(|(_, error)| error)
+        MeshGraph::from_raw_buffers_with_arity_and_keys(indices, vertices, arity)
+            .map(|(graph, _)| graph)
     }
 }
 
-impl<G> IntoPolygons for MeshGraph<G>
+impl<G, K> IntoPolygons for MeshGraph<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     type Output = vec::IntoIter<Self::Polygon>;
     type Polygon = UnboundedPolygon<G::Vertex>;
 
     fn into_polygons(self) -> Self::Output {
-        use crate::IteratorExt as _;
-
         self.faces()
             .map(|face| {
                 // The arity of a face in a graph must be polygonal (three or
@@ -1585,29 +2504,33 @@ where
     }
 }
 
-impl<G> Parametric for MeshGraph<G>
+impl<G, K> Parametric for MeshGraph<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     type Data = G;
+    type Index = K;
 }
 
-impl<G> StaticArity for MeshGraph<G>
+impl<G, K> StaticArity for MeshGraph<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     type Static = (usize, Option<usize>);
 
     const ARITY: Self::Static = (3, None);
 }
 
-impl<T, H, G, const A: usize> TryFrom<MeshBuffer<Flat<T, A>, H>> for MeshGraph<G>
+impl<T, H, G, K, const A: usize> TryFrom<MeshBuffer<Flat<T, A>, H>> for MeshGraph<G, K>
 where
     Constant<A>: ToType,
     TypeOf<A>: NonZero,
     T: Copy + Integer + NumCast + Unsigned,
     H: Clone,
     G: GraphData,
+    K: KeyIndex,
     G::Vertex: FromGeometry<H>,
 {
     type Error = GraphError;
@@ -1652,12 +2575,13 @@ where
     }
 }
 
-impl<P, H, G> TryFrom<MeshBuffer<P, H>> for MeshGraph<G>
+impl<P, H, G, K> TryFrom<MeshBuffer<P, H>> for MeshGraph<G, K>
 where
     P: Grouping<Group = P> + IntoVertices + Polygonal,
     P::Vertex: Copy + Integer + NumCast + Unsigned,
     H: Clone,
     G: GraphData,
+    K: KeyIndex,
     G::Vertex: FromGeometry<H>,
 {
     type Error = GraphError;
@@ -1702,16 +2626,26 @@ where
 
 #[cfg(test)]
 mod tests {
-    use decorum::R64;
+    use decorum::{R32, R64};
     use nalgebra::{Point2, Point3, Vector3};
     use num::Zero;
-
-    use crate::buffer::MeshBuffer3;
-    use crate::graph::{GraphData, GraphError, MeshGraph};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::{HashMap, HashSet};
+
+    use crate::buffer::{MeshBuffer, MeshBuffer3};
+    use crate::builder::Buildable;
+    use crate::entity::storage::Key;
+    use crate::graph::{
+        ArcKey, GraphData, GraphError, GraphKey, MeshGraph, TopologyKind, VertexKey,
+    };
+    use crate::index::Flat3;
     use crate::prelude::*;
+    use crate::primitive::cube::Cube;
     use crate::primitive::generate::Position;
     use crate::primitive::sphere::UvSphere;
-    use crate::primitive::NGon;
+    use crate::primitive::{BoundedPolygon, NGon};
+    use crate::MeshArity;
 
     type E2 = Point2<R64>;
     type E3 = Point3<R64>;
@@ -1727,6 +2661,78 @@ mod tests {
         assert_eq!(6, graph.face_count());
     }
 
+    #[test]
+    fn collect_like_buffer() {
+        let sphere = UvSphere::new(16, 8);
+        let buffer: MeshBuffer<BoundedPolygon<usize>, E3> =
+            sphere.polygons::<Position<E3>>().collect();
+        let buffered = MeshGraph::<Point3<f64>>::try_from(buffer).unwrap();
+        let graph: MeshGraph<Point3<f64>> = sphere.polygons::<Position<E3>>().collect();
+
+        // Collecting polygons into a graph inserts the same topology in the
+        // same order as constructing a graph from an indexed buffer.
+        assert_eq!(buffered.vertex_count(), graph.vertex_count());
+        assert_eq!(buffered.edge_count(), graph.edge_count());
+        assert_eq!(buffered.face_count(), graph.face_count());
+        for vertex in buffered.vertices() {
+            assert_eq!(
+                vertex.position(),
+                graph.vertex(vertex.key()).unwrap().position()
+            );
+        }
+        for face in buffered.faces() {
+            assert_eq!(
+                face.adjacent_vertices().keys().collect::<Vec<_>>(),
+                graph
+                    .face(face.key())
+                    .unwrap()
+                    .adjacent_vertices()
+                    .keys()
+                    .collect::<Vec<_>>(),
+            );
+        }
+    }
+
+    #[test]
+    fn build_incrementally() {
+        let mut builder = MeshGraph::<Point2<f64>>::builder();
+        let graph = builder
+            .surface_with(|builder| {
+                let a = builder.insert_vertex((0.0, 0.0))?;
+                let b = builder.insert_vertex((1.0, 0.0))?;
+                let c = builder.insert_vertex((1.0, 1.0))?;
+                let d = builder.insert_vertex((0.0, 1.0))?;
+                let e = builder.insert_vertex((2.0, 0.0))?;
+                let f = builder.insert_vertex((2.0, 1.0))?;
+                builder.facets_with(|builder| {
+                    builder.insert_facet(&[a, b, c, d], ())?;
+                    builder.insert_facet(&[b, e, f, c], ())
+                })
+            })
+            .and_then(|_| builder.build())
+            .unwrap();
+
+        assert_eq!(6, graph.vertex_count());
+        assert_eq!(7, graph.edge_count());
+        assert_eq!(2, graph.face_count());
+
+        // Vertices that are not connected to any facet are inconsistent.
+        let mut builder = MeshGraph::<Point2<f64>>::builder();
+        builder
+            .surface_with(|builder| {
+                let a = builder.insert_vertex((0.0, 0.0))?;
+                let b = builder.insert_vertex((1.0, 0.0))?;
+                let c = builder.insert_vertex((0.0, 1.0))?;
+                builder.insert_vertex((1.0, 1.0))?;
+                builder.facets_with(|builder| builder.insert_facet(&[a, b, c], ()))
+            })
+            .unwrap();
+        assert_eq!(
+            Err(GraphError::TopologyMalformed),
+            builder.build().map(|_| ())
+        );
+    }
+
     #[test]
     fn iterate() {
         let mut graph: MeshGraph<Point3<f64>> = UvSphere::new(4, 2)
@@ -1747,6 +2753,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn iterate_arcs_and_edges() {
+        let graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2, 3])],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+        )
+        .unwrap();
+
+        // Every edge is visited once and both of its arcs are visited.
+        let arcs = graph.arcs().keys().collect::<HashSet<_>>();
+        assert_eq!(8, arcs.len());
+        assert_eq!(2 * graph.edge_count(), graph.arc_count());
+        assert_eq!(
+            arcs,
+            graph
+                .edges()
+                .flat_map(|edge| {
+                    let (a, b) = edge.arc_pair();
+                    vec![a.key(), b.key()]
+                })
+                .collect::<HashSet<_>>(),
+        );
+        assert_eq!(4, graph.arcs().filter(|arc| arc.is_boundary_arc()).count());
+    }
+
     #[test]
     fn isolate_disjoint_subgraphs() {
         // Construct a graph from a quadrilateral.
@@ -1777,6 +2808,587 @@ mod tests {
         assert_eq!(2, graph.disjoint_subgraph_vertices().count());
     }
 
+    #[test]
+    fn insert_face_between_boundary_arcs() {
+        // Construct a fan of triangles about a central vertex (4). The faces
+        // are inserted out of order such that later faces reuse boundary arcs
+        // of earlier faces that are not yet adjacent.
+        let graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![
+                NGon([0u32, 1, 4]),
+                NGon([2, 3, 4]),
+                NGon([1, 2, 4]),
+                NGon([3, 0, 4]),
+            ],
+            vec![(0.0, -1.0), (1.0, 0.0), (0.0, 1.0), (-1.0, 0.0), (0.0, 0.0)],
+        )
+        .unwrap();
+
+        assert_eq!(5, graph.vertex_count());
+        assert_eq!(16, graph.arc_count());
+        assert_eq!(8, graph.edge_count());
+        assert_eq!(4, graph.face_count());
+        for arc in graph.arcs() {
+            assert_eq!(arc.key(), arc.next_arc().previous_arc().key());
+            assert_eq!(arc.key(), arc.previous_arc().next_arc().key());
+        }
+    }
+
+    #[test]
+    fn irregular_vertices() {
+        let graph: MeshGraph<Point3<f64>> =
+            UvSphere::new(8, 4).polygons::<Position<E3>>().collect();
+        // Only the poles of a UV-sphere are irregular.
+        assert_eq!(2, graph.irregular_vertices(4).count());
+        for vertex in graph.irregular_vertices(4) {
+            assert_eq!(8, vertex.valence());
+        }
+    }
+
+    #[test]
+    fn subgraph() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        // Extract the three faces about a corner of the cube.
+        let corner = graph.vertices().nth(0).unwrap();
+        let faces = corner.adjacent_faces().keys().collect::<Vec<_>>();
+        assert_eq!(3, faces.len());
+        let vertices = faces
+            .iter()
+            .flat_map(|key| graph.face(*key).unwrap().into_adjacent_vertices().keys())
+            .collect::<HashSet<_>>();
+
+        let (subgraph, mapping) = graph.subgraph(faces.iter().cloned()).unwrap();
+        assert_eq!(vertices.len(), subgraph.vertex_count());
+        assert_eq!(7, subgraph.vertex_count());
+        assert_eq!(9, subgraph.edge_count());
+        assert_eq!(3, subgraph.face_count());
+        // The subgraph is open and its boundary is the perimeter of the faces.
+        assert_eq!(
+            6,
+            subgraph.arcs().filter(|arc| arc.is_boundary_arc()).count()
+        );
+        for (key, target) in mapping.vertices.iter() {
+            assert_eq!(
+                graph.vertex(*key).unwrap().position(),
+                subgraph.vertex(*target).unwrap().position()
+            );
+        }
+        for key in faces.iter() {
+            let face = graph.face(*key).unwrap();
+            let target = subgraph.face(mapping.faces[key]).unwrap();
+            assert_eq!(face.arity(), target.arity());
+            for arc in face.adjacent_arcs() {
+                let ab = mapping.arc(arc.key()).unwrap();
+                let edge = subgraph.arc(ab).unwrap().edge().key();
+                assert_eq!(mapping.edges[&arc.edge().key()], edge);
+            }
+        }
+        let corner = subgraph.vertex(mapping.vertices[&corner.key()]).unwrap();
+        assert_eq!(3, corner.adjacent_faces().count());
+
+        let mut graph = graph;
+        graph.face_mut(faces[0]).unwrap().remove();
+        let error = graph.subgraph(faces.iter().cloned()).err().unwrap();
+        assert_eq!(Some(GraphKey::Face(faces[0])), error.key());
+        assert_eq!(
+            &GraphError::TopologyNotFound(TopologyKind::Face),
+            error.without_key()
+        );
+    }
+
+    #[test]
+    fn insert_faces() {
+        // Construct a graph and remove its faces, leaving only its edges.
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2]), NGon([0, 2, 3]), NGon([1, 4, 2])],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (2.0, 0.0)],
+        )
+        .unwrap();
+        let mut perimeters = graph
+            .faces()
+            .map(|face| face.adjacent_vertices().keys().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let keys = graph.faces().keys().collect::<Vec<_>>();
+        for key in keys {
+            graph.face_mut(key).unwrap().remove();
+        }
+        assert_eq!(0, graph.face_count());
+
+        // Faces in the batch must not conflict with one another. The
+        // offending arc is reported.
+        let duplicate = perimeters[0].clone();
+        perimeters.push(duplicate.clone());
+        assert_eq!(
+            Err(GraphError::TopologyConflict
+                .at_key(ArcKey::from((duplicate[0], duplicate[1])))
+                .at_index(3)),
+            graph.insert_faces(perimeters.iter().map(|perimeter| (perimeter.clone(), ())))
+        );
+        assert_eq!(0, graph.face_count());
+        assert_eq!(
+            Err(GraphError::ArityNonPolygonal.at_index(1)),
+            graph.insert_faces(vec![
+                (perimeters[0].clone(), ()),
+                (perimeters[1][..2].to_vec(), ()),
+            ]),
+        );
+        assert_eq!(0, graph.face_count());
+
+        perimeters.pop();
+        let faces = graph
+            .insert_faces(perimeters.iter().map(|perimeter| (perimeter.clone(), ())))
+            .unwrap();
+        assert_eq!(3, faces.len());
+        assert_eq!(3, graph.face_count());
+        assert_eq!(7, graph.edge_count());
+
+        // Faces must not conflict with faces in the graph.
+        assert_eq!(
+            Err(GraphError::TopologyConflict
+                .at_key(ArcKey::from((duplicate[0], duplicate[1])))
+                .at_index(0)),
+            graph.insert_faces(vec![(duplicate, ())]),
+        );
+        assert_eq!(3, graph.face_count());
+    }
+
+    #[test]
+    fn insert_faces_rejected() {
+        // Construct a quadrilateral from two triangles and a disjoint triangle.
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2]), NGon([0, 2, 3]), NGon([4, 5, 6])],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (1.0, 1.0),
+                (0.0, 1.0),
+                (2.0, 0.0),
+                (3.0, 0.0),
+                (2.0, 1.0),
+            ],
+        )
+        .unwrap();
+        let key = |graph: &MeshGraph<Point2<f64>>, x: f64, y: f64| {
+            graph
+                .vertices()
+                .find(|vertex| *vertex.position() == Point2::new(x, y))
+                .unwrap()
+                .key()
+        };
+        let [a, b, _, d, e, f, g] = [
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (1.0, 1.0),
+            (0.0, 1.0),
+            (2.0, 0.0),
+            (3.0, 0.0),
+            (2.0, 1.0),
+        ]
+        .map(|(x, y)| key(&graph, x, y));
+        let (vertices, edges, faces) =
+            (graph.vertex_count(), graph.edge_count(), graph.face_count());
+
+        // The first face is valid, but the boundary arc AD follows the arc BA
+        // and bisects the perimeter of the second face. No faces are inserted
+        // and the graph is left unmodified.
+        assert_eq!(
+            Err(GraphError::TopologyConflict
+                .at_key(ArcKey::from((b, a)))
+                .at_index(1)),
+            graph.insert_faces(vec![(vec![g, f, e], ()), (vec![b, a, e, d], ())]),
+        );
+        assert_eq!(vertices, graph.vertex_count());
+        assert_eq!(edges, graph.edge_count());
+        assert_eq!(faces, graph.face_count());
+        assert!(graph
+            .arcs()
+            .all(|arc| arc.next_arc().previous_arc().key() == arc.key()));
+
+        // The graph remains usable.
+        let keys = graph.insert_faces(vec![(vec![g, f, e], ())]).unwrap();
+        assert_eq!(1, keys.len());
+        assert_eq!(faces + 1, graph.face_count());
+    }
+
+    #[test]
+    fn insert_faces_batch() {
+        for seed in 0..16 {
+            let mut graph: MeshGraph<Point3<f64>> =
+                UvSphere::new(8, 6).polygons::<Position<E3>>().collect();
+            let (vertices, edges, faces) =
+                (graph.vertex_count(), graph.edge_count(), graph.face_count());
+            // Remove the top row of quadrilaterals, which also removes the
+            // edges between them. Reinsert the faces in an arbitrary order.
+            let top = graph
+                .faces()
+                .filter(|face| face.arity() == 4)
+                .map(|face| face.centroid().z)
+                .fold(f64::MIN, f64::max);
+            let mut band = graph
+                .faces()
+                .filter(|face| face.arity() == 4 && (face.centroid().z - top).abs() < 1e-9)
+                .map(|face| {
+                    (
+                        face.key(),
+                        face.adjacent_vertices().keys().collect::<Vec<_>>(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(8, band.len());
+            let mut rng = StdRng::seed_from_u64(seed);
+            for index in (1..band.len()).rev() {
+                band.swap(index, rng.gen_range(0..=index));
+            }
+            let keys = band.iter().map(|(key, _)| *key).collect::<Vec<_>>();
+            graph.remove_faces_and_dangling_edges(&keys);
+            assert_eq!(vertices, graph.vertex_count());
+            assert_eq!(edges - 8, graph.edge_count());
+
+            let keys = graph
+                .insert_faces(band.into_iter().map(|(_, perimeter)| (perimeter, ())))
+                .unwrap();
+            assert_eq!(8, keys.len());
+            assert_eq!(edges, graph.edge_count());
+            assert_eq!(faces, graph.face_count());
+            assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+            assert!(graph
+                .arcs()
+                .all(|arc| arc.next_arc().previous_arc().key() == arc.key()));
+            assert!(graph
+                .faces()
+                .all(|face| face.adjacent_arcs().count() == face.arity()));
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_face_map() {
+        let graph: MeshGraph<Point3<f64>> =
+            UvSphere::new(16, 8).polygons::<Position<E3>>().collect();
+        let arities = graph.par_face_map(|face| face.arity());
+
+        assert_eq!(graph.face_count(), arities.len());
+        for face in graph.faces() {
+            assert_eq!(face.arity(), arities[&face.key()]);
+        }
+    }
+
+    #[test]
+    fn faces_with_arity() {
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![
+                BoundedPolygon::from([0usize, 1, 2, 3]),
+                BoundedPolygon::from([1, 4, 2]),
+                BoundedPolygon::from([3, 2, 5]),
+            ],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (1.0, 1.0),
+                (0.0, 1.0),
+                (2.0, 0.0),
+                (0.0, 2.0),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(MeshArity::NonUniform(3, 4), graph.arity());
+        assert_eq!(2, graph.faces_with_arity(3).count());
+        assert_eq!(1, graph.faces_with_arity(4).count());
+        assert_eq!(0, graph.faces_with_arity(5).count());
+    }
+
+    #[test]
+    fn u32_keys() {
+        let mut graph: MeshGraph<Point3<f32>, u32> =
+            Cube::new().polygons::<Position<Point3<R32>>>().collect();
+        assert_eq!(8, graph.vertex_count());
+
+        let arc = graph.arcs().nth(0).unwrap().key();
+        let vertex: VertexKey<u32> = graph
+            .arc_mut(arc)
+            .unwrap()
+            .split_at_midpoint()
+            .unwrap()
+            .key();
+        assert_eq!(9, graph.vertex_count());
+        assert_eq!(2, graph.vertex(vertex).unwrap().valence());
+
+        let face = graph.faces().find(|face| face.arity() == 4).unwrap().key();
+        let face = graph
+            .face_mut(face)
+            .unwrap()
+            .extrude_with_offset(1.0)
+            .unwrap()
+            .key();
+        assert_eq!(13, graph.vertex_count());
+        assert_eq!(10, graph.face_count());
+
+        graph.extrude_faces(&[face], 1.0).unwrap();
+        assert_eq!(17, graph.vertex_count());
+        assert_eq!(14, graph.face_count());
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+    }
+
+    #[test]
+    fn keyspace_exhausted() {
+        // Builds a strip of triangles with `n` vertices.
+        let strip = |n: u16| {
+            MeshGraph::<Point3<f64>, u8>::from_raw_buffers(
+                (0..(usize::from(n) - 2)).map(|i| {
+                    if i % 2 == 0 {
+                        NGon([i, i + 1, i + 2])
+                    }
+                    else {
+                        NGon([i + 1, i, i + 2])
+                    }
+                }),
+                (0..n).map(|i| (f64::from(i / 2), f64::from(i % 2), 0.0)),
+            )
+        };
+
+        // Each kind of topology has its own keyspace. A strip with `n`
+        // vertices has `2n - 3` edges, so edges exhaust their keyspace first.
+        let mut graph = strip(129).unwrap();
+        assert_eq!(255, graph.edge_count());
+
+        // Extrusion requires more edges than the keyspace can provide.
+        let face = graph.faces().nth(0).unwrap().key();
+        assert_eq!(
+            GraphError::KeyspaceExhausted,
+            graph.extrude_faces(&[face], 1.0).err().unwrap()
+        );
+        assert_eq!(255, graph.edge_count());
+        let arc = graph
+            .arcs()
+            .find(|arc| arc.is_boundary_arc())
+            .unwrap()
+            .key();
+        assert_eq!(
+            GraphError::KeyspaceExhausted,
+            graph
+                .arc_mut(arc)
+                .unwrap()
+                .extrude_with_offset(1.0)
+                .err()
+                .unwrap()
+        );
+        assert_eq!(
+            GraphError::KeyspaceExhausted,
+            graph
+                .face_mut(face)
+                .unwrap()
+                .extrude_with_offset(1.0)
+                .err()
+                .unwrap()
+        );
+        assert_eq!(
+            GraphError::KeyspaceExhausted,
+            graph
+                .face_mut(face)
+                .unwrap()
+                .poke_at_centroid()
+                .err()
+                .unwrap()
+        );
+        // Splitting replaces an edge with two edges, but only one key remains.
+        assert_eq!(
+            GraphError::KeyspaceExhausted,
+            graph
+                .arc_mut(arc)
+                .unwrap()
+                .split_at_midpoint()
+                .err()
+                .unwrap()
+        );
+        // Bridging and batch insertion insert edges too.
+        let vertex = |graph: &MeshGraph<Point3<f64>, u8>, i: u16| {
+            let position = Point3::new(f64::from(i / 2), f64::from(i % 2), 0.0);
+            graph
+                .vertices()
+                .find(|vertex| *vertex.position() == position)
+                .unwrap()
+                .key()
+        };
+        let destination = graph
+            .vertex(vertex(&graph, 100))
+            .unwrap()
+            .adjacent_faces()
+            .nth(0)
+            .unwrap()
+            .key();
+        assert_eq!(
+            GraphError::KeyspaceExhausted,
+            graph
+                .face_mut(face)
+                .unwrap()
+                .bridge(destination)
+                .err()
+                .unwrap()
+        );
+        assert_eq!(
+            GraphError::KeyspaceExhausted,
+            graph
+                .insert_faces(vec![(
+                    [vertex(&graph, 0), vertex(&graph, 2), vertex(&graph, 4)],
+                    ()
+                )])
+                .err()
+                .unwrap()
+        );
+        assert_eq!(
+            GraphError::KeyspaceExhausted,
+            graph
+                .stitch_loops(
+                    &[vertex(&graph, 0), vertex(&graph, 4), vertex(&graph, 8)],
+                    &[vertex(&graph, 10), vertex(&graph, 14), vertex(&graph, 18)],
+                    false,
+                )
+                .err()
+                .unwrap()
+        );
+        // Operations that fail with an exhausted keyspace do not modify the
+        // graph.
+        assert_eq!(129, graph.vertex_count());
+        assert_eq!(255, graph.edge_count());
+        assert_eq!(127, graph.face_count());
+
+        assert_eq!(GraphError::KeyspaceExhausted, strip(130).err().unwrap());
+    }
+
+    #[test]
+    fn default_key_index() {
+        // The default key index is `u64`, so these types are interchangeable.
+        fn graph(graph: MeshGraph<E3>) -> MeshGraph<E3, u64> {
+            graph
+        }
+        fn vertex(key: VertexKey<u64>) -> VertexKey {
+            key
+        }
+
+        let graph = graph(Cube::new().polygons::<Position<E3>>().collect());
+        let key = vertex(graph.vertices().nth(0).unwrap().key());
+        assert!(graph.vertex(key).is_some());
+    }
+
+    #[test]
+    fn compact() {
+        enum Weight {}
+
+        impl GraphData for Weight {
+            type Vertex = [i32; 2];
+            type Arc = ();
+            type Edge = ();
+            type Face = u64;
+        }
+
+        // Construct a strip of quadrilaterals with weights and remove every
+        // other face, leaving gaps in the face keys.
+        let mut graph = MeshGraph::<Weight>::from_raw_buffers(
+            (0..4usize).map(|n| NGon([n, n + 1, n + 6, n + 5])),
+            (0..10i32).map(|n| [n % 5, n / 5]),
+        )
+        .unwrap();
+        let keys = graph.faces().map(|face| face.key()).collect::<Vec<_>>();
+        for (n, key) in keys.iter().enumerate() {
+            *graph.face_mut(*key).unwrap().get_mut() = n as u64;
+        }
+        for key in keys.iter().step_by(2) {
+            graph.face_mut(*key).unwrap().remove();
+        }
+        let before = graph
+            .faces()
+            .map(|face| {
+                (
+                    face.key(),
+                    *face.get(),
+                    face.adjacent_vertices()
+                        .map(|vertex| *vertex.get())
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let compaction = graph.compact();
+
+        assert_eq!(10, compaction.vertices.len());
+        assert_eq!(2, compaction.faces.len());
+        assert_eq!(13, compaction.edges.len());
+        let mut faces = graph
+            .faces()
+            .map(|face| face.key().into_inner())
+            .collect::<Vec<_>>();
+        faces.sort_unstable();
+        assert_eq!(vec![0, 1], faces);
+        for (key, weight, positions) in before {
+            let face = graph.face(compaction.faces[&key]).unwrap();
+            assert_eq!(weight, *face.get());
+            assert_eq!(
+                positions,
+                face.adjacent_vertices()
+                    .map(|vertex| *vertex.get())
+                    .collect::<Vec<_>>(),
+            );
+        }
+        assert_eq!(26, graph.arc_count());
+        assert_eq!(18, graph.arcs().filter(|arc| arc.is_boundary_arc()).count());
+    }
+
+    #[test]
+    fn to_mesh_by_corner() {
+        enum Textured {}
+
+        impl GraphData for Textured {
+            type Vertex = Point3<f64>;
+            type Arc = [f64; 3];
+            type Edge = ();
+            type Face = ();
+        }
+
+        let cube = Cube::new();
+        let mut graph = MeshGraph::<Textured>::from_raw_buffers(
+            cube.indexing_polygons::<Position>().triangulate(),
+            cube.vertices::<Position<Point3<f64>>>(),
+        )
+        .unwrap();
+        // Map each side of the cube into a distinct region of texture space by
+        // identifying the side by its normal and projecting positions onto it.
+        let corners = graph
+            .faces()
+            .flat_map(|face| {
+                let normal = face.normal().unwrap();
+                let axis =
+                    (0..3).max_by(|i, j| normal[*i].abs().partial_cmp(&normal[*j].abs()).unwrap());
+                let axis = axis.unwrap();
+                let side = (axis * 2) as f64 + if normal[axis] > 0.0 { 1.0 } else { 0.0 };
+                face.adjacent_arcs()
+                    .map(|arc| {
+                        let position = *arc.destination_vertex().position();
+                        let (u, v) = (position[(axis + 1) % 3], position[(axis + 2) % 3]);
+                        (arc.key(), [side, u, v])
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        for (key, data) in corners {
+            *graph.arc_mut(key).unwrap().get_mut() = data;
+        }
+
+        // Each vertex has a distinct corner on each of its three sides.
+        let buffer: MeshBuffer<Flat3, _> = graph
+            .to_mesh_by_corner_with(|arc| (*arc.destination_vertex().position(), *arc.get()))
+            .unwrap();
+        assert_eq!(24, buffer.as_vertex_slice().len());
+        assert_eq!(36, buffer.as_index_slice().len());
+
+        // Corners are merged when their data is equal.
+        let buffer: MeshBuffer<Flat3, _> = graph
+            .to_mesh_by_corner_with(|arc| *arc.destination_vertex().position())
+            .unwrap();
+        assert_eq!(8, buffer.as_vertex_slice().len());
+        assert_eq!(36, buffer.as_index_slice().len());
+    }
+
     #[test]
     fn non_manifold_error_deferred() {
         let graph: MeshGraph<E3> = UvSphere::new(32, 32)
@@ -1801,6 +3413,66 @@ mod tests {
         assert_eq!(graph.err().unwrap(), GraphError::TopologyConflict);
     }
 
+    #[test]
+    fn flip_winding() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let normals = graph
+            .faces()
+            .map(|face| (face.key(), face.normal().unwrap()))
+            .collect::<HashMap<_, _>>();
+        let arcs = graph.arcs().keys().collect::<HashSet<_>>();
+
+        graph.flip_winding();
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(12, graph.edge_count());
+        assert_eq!(6, graph.face_count());
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+        for face in graph.faces() {
+            assert!((face.normal().unwrap() + normals[&face.key()]).magnitude() < 1e-9);
+            for arc in face.adjacent_arcs() {
+                assert_eq!(arc.key(), arc.next_arc().previous_arc().key());
+                assert_eq!(face.key(), arc.face().unwrap().key());
+            }
+        }
+        for vertex in graph.vertices() {
+            assert_eq!(3, vertex.outgoing_arcs().count());
+        }
+
+        // Flipping the winding twice restores the original arcs.
+        graph.flip_winding();
+        assert_eq!(arcs, graph.arcs().keys().collect::<HashSet<_>>());
+    }
+
+    #[test]
+    fn boundary_vertices() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        assert_eq!(0, graph.boundary_vertices().count());
+
+        // Removing a face exposes its vertices.
+        let face = graph.faces().nth(0).unwrap();
+        let keys = face.adjacent_vertices().keys().collect::<HashSet<_>>();
+        let key = face.key();
+        graph.face_mut(key).unwrap().remove();
+        assert_eq!(
+            keys,
+            graph.boundary_vertices().keys().collect::<HashSet<_>>()
+        );
+    }
+
+    #[test]
+    fn error_on_missing_vertex() {
+        // The index `3` does not refer to any vertex.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![NGon([0u32, 1, 3])],
+            vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+        );
+
+        assert_eq!(
+            graph.err().unwrap(),
+            GraphError::TopologyNotFound(TopologyKind::Vertex)
+        );
+    }
+
     // This test is a sanity check for circulators over orphan views and the
     // unsafe transmutations used to coerce lifetimes. It is a good target for
     // Miri, which can detect certain memory safety issues.
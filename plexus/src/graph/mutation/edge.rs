@@ -2,39 +2,41 @@ use std::ops::{Deref, DerefMut};
 
 use crate::entity::borrow::Reborrow;
 use crate::entity::storage::prelude::*;
-use crate::entity::storage::{AsStorage, AsStorageMut, Fuse, StorageTarget};
+use crate::entity::storage::{AsStorage, AsStorageMut, Fuse, KeyIndex, StorageTarget};
 use crate::entity::view::{Bind, ClosedView, Rebind};
 use crate::graph::core::Core;
-use crate::graph::data::{Data, GraphData, Parametric};
+use crate::graph::data::{Data, GraphData, Index, Parametric};
 use crate::graph::edge::{Arc, ArcKey, ArcView, Edge, EdgeKey};
 use crate::graph::face::{Face, FaceKey};
 use crate::graph::mutation::face::{self, FaceInsertCache, FaceRemoveCache};
 use crate::graph::mutation::vertex::{self, VertexMutation};
 use crate::graph::mutation::{Consistent, Immediate, Mode, Mutable, Mutation};
 use crate::graph::vertex::{Vertex, VertexKey, VertexView};
-use crate::graph::GraphError;
+use crate::graph::{GraphError, TopologyKind};
 use crate::transact::{Bypass, Transact};
 use crate::IteratorExt as _;
 
-pub type CompositeEdge<G> = (Edge<G>, (Arc<G>, Arc<G>));
+pub type CompositeEdge<G, K> = (Edge<G, K>, (Arc<G, K>, Arc<G, K>));
 pub type CompositeEdgeData<G> = (
     <G as GraphData>::Edge,
     (<G as GraphData>::Arc, <G as GraphData>::Arc),
 );
-pub type CompositeEdgeKey = (EdgeKey, (ArcKey, ArcKey));
+pub type CompositeEdgeKey<K> = (EdgeKey<K>, (ArcKey<K>, ArcKey<K>));
 
 type ModalCore<P> = Core<
     Data<<P as Mode>::Graph>,
+    Index<<P as Mode>::Graph>,
     <P as Mode>::VertexStorage,
     <P as Mode>::ArcStorage,
     <P as Mode>::EdgeStorage,
     (),
 >;
-type RefCore<'a, G> = Core<
+type RefCore<'a, G, K> = Core<
     G,
-    &'a StorageTarget<'a, Vertex<G>>,
-    &'a StorageTarget<'a, Arc<G>>,
-    &'a StorageTarget<'a, Edge<G>>,
+    K,
+    &'a StorageTarget<'a, Vertex<G, K>>,
+    &'a StorageTarget<'a, Arc<G, K>>,
+    &'a StorageTarget<'a, Edge<G, K>>,
     (),
 >;
 
@@ -51,20 +53,27 @@ impl<P> EdgeMutation<P>
 where
     P: Mode,
 {
-    pub fn to_ref_core(&self) -> RefCore<Data<P::Graph>> {
+    pub fn to_ref_core(&self) -> RefCore<Data<P::Graph>, Index<P::Graph>> {
         self.inner
             .to_ref_core()
             .fuse(self.storage.0.as_storage())
             .fuse(self.storage.1.as_storage())
     }
 
-    pub fn connect_adjacent_arcs(&mut self, ab: ArcKey, bc: ArcKey) -> Result<(), GraphError> {
+    pub fn connect_adjacent_arcs(
+        &mut self,
+        ab: ArcKey<Index<P::Graph>>,
+        bc: ArcKey<Index<P::Graph>>,
+    ) -> Result<(), GraphError> {
         self.with_arc_mut(ab, |arc| arc.next = Some(bc))?;
         self.with_arc_mut(bc, |arc| arc.previous = Some(ab))?;
         Ok(())
     }
 
-    pub fn disconnect_next_arc(&mut self, ab: ArcKey) -> Result<Option<ArcKey>, GraphError> {
+    pub fn disconnect_next_arc(
+        &mut self,
+        ab: ArcKey<Index<P::Graph>>,
+    ) -> Result<Option<ArcKey<Index<P::Graph>>>, GraphError> {
         let bx = self.with_arc_mut(ab, |arc| arc.next.take())?;
         if let Some(bx) = bx.as_ref() {
             self.with_arc_mut(*bx, |arc| arc.previous.take())
@@ -73,7 +82,10 @@ where
         Ok(bx)
     }
 
-    pub fn disconnect_previous_arc(&mut self, ab: ArcKey) -> Result<Option<ArcKey>, GraphError> {
+    pub fn disconnect_previous_arc(
+        &mut self,
+        ab: ArcKey<Index<P::Graph>>,
+    ) -> Result<Option<ArcKey<Index<P::Graph>>>, GraphError> {
         let xa = self.with_arc_mut(ab, |arc| arc.previous.take())?;
         if let Some(xa) = xa.as_ref() {
             self.with_arc_mut(*xa, |arc| arc.next.take())
@@ -82,46 +94,57 @@ where
         Ok(xa)
     }
 
-    pub fn connect_arc_to_edge(&mut self, ab: ArcKey, ab_ba: EdgeKey) -> Result<(), GraphError> {
+    pub fn connect_arc_to_edge(
+        &mut self,
+        ab: ArcKey<Index<P::Graph>>,
+        ab_ba: EdgeKey<Index<P::Graph>>,
+    ) -> Result<(), GraphError> {
         self.with_arc_mut(ab, |arc| arc.edge = Some(ab_ba))
     }
 
-    pub fn connect_arc_to_face(&mut self, ab: ArcKey, abc: FaceKey) -> Result<(), GraphError> {
+    pub fn connect_arc_to_face(
+        &mut self,
+        ab: ArcKey<Index<P::Graph>>,
+        abc: FaceKey<Index<P::Graph>>,
+    ) -> Result<(), GraphError> {
         self.with_arc_mut(ab, |arc| arc.face = Some(abc))
     }
 
-    pub fn disconnect_arc_from_face(&mut self, ab: ArcKey) -> Result<Option<FaceKey>, GraphError> {
+    pub fn disconnect_arc_from_face(
+        &mut self,
+        ab: ArcKey<Index<P::Graph>>,
+    ) -> Result<Option<FaceKey<Index<P::Graph>>>, GraphError> {
         self.with_arc_mut(ab, |arc| arc.face.take())
     }
 
-    fn with_arc_mut<T, F>(&mut self, ab: ArcKey, mut f: F) -> Result<T, GraphError>
+    fn with_arc_mut<T, F>(&mut self, ab: ArcKey<Index<P::Graph>>, mut f: F) -> Result<T, GraphError>
     where
-        F: FnMut(&mut Arc<Data<P::Graph>>) -> T,
+        F: FnMut(&mut Arc<Data<P::Graph>, Index<P::Graph>>) -> T,
     {
         let arc = self
             .storage
             .0
             .as_storage_mut()
             .get_mut(&ab)
-            .ok_or(GraphError::TopologyNotFound)?;
+            .ok_or(GraphError::TopologyNotFound(TopologyKind::Arc))?;
         Ok(f(arc))
     }
 }
 
-impl<P> AsStorage<Arc<Data<P::Graph>>> for EdgeMutation<P>
+impl<P> AsStorage<Arc<Data<P::Graph>, Index<P::Graph>>> for EdgeMutation<P>
 where
     P: Mode,
 {
-    fn as_storage(&self) -> &StorageTarget<Arc<Data<P::Graph>>> {
+    fn as_storage(&self) -> &StorageTarget<Arc<Data<P::Graph>, Index<P::Graph>>> {
         self.storage.0.as_storage()
     }
 }
 
-impl<P> AsStorage<Edge<Data<P::Graph>>> for EdgeMutation<P>
+impl<P> AsStorage<Edge<Data<P::Graph>, Index<P::Graph>>> for EdgeMutation<P>
 where
     P: Mode,
 {
-    fn as_storage(&self) -> &StorageTarget<Edge<Data<P::Graph>>> {
+    fn as_storage(&self) -> &StorageTarget<Edge<Data<P::Graph>, Index<P::Graph>>> {
         self.storage.1.as_storage()
     }
 }
@@ -201,22 +224,28 @@ where
     fn abort(self) -> Self::Abort {}
 }
 
-struct ArcRemoveCache {
-    ab: ArcKey,
-    xa: Option<ArcKey>,
-    bx: Option<ArcKey>,
-    cache: Option<FaceRemoveCache>,
+struct ArcRemoveCache<K>
+where
+    K: KeyIndex,
+{
+    ab: ArcKey<K>,
+    xa: Option<ArcKey<K>>,
+    bx: Option<ArcKey<K>>,
+    cache: Option<FaceRemoveCache<K>>,
 }
 
-impl ArcRemoveCache {
+impl<K> ArcRemoveCache<K>
+where
+    K: KeyIndex,
+{
     pub fn from_arc<B>(arc: ArcView<B>) -> Result<Self, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Arc<Data<B>>>
-            + AsStorage<Face<Data<B>>>
-            + AsStorage<Vertex<Data<B>>>
+        B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+            + AsStorage<Face<Data<B>, Index<B>>>
+            + AsStorage<Vertex<Data<B>, Index<B>>>
             + Consistent
-            + Parametric,
+            + Parametric<Index = K>,
     {
         // If the edge has no neighbors, then `xa` and `bx` will refer to the
         // opposite arc of `ab`. In this case, the vertices `a` and `b` should
@@ -241,24 +270,30 @@ impl ArcRemoveCache {
     }
 }
 
-pub struct EdgeRemoveCache {
-    a: VertexKey,
-    b: VertexKey,
-    ab_ba: EdgeKey,
-    arc: ArcRemoveCache,
-    opposite: ArcRemoveCache,
+pub struct EdgeRemoveCache<K>
+where
+    K: KeyIndex,
+{
+    a: VertexKey<K>,
+    b: VertexKey<K>,
+    ab_ba: EdgeKey<K>,
+    arc: ArcRemoveCache<K>,
+    opposite: ArcRemoveCache<K>,
 }
 
-impl EdgeRemoveCache {
+impl<K> EdgeRemoveCache<K>
+where
+    K: KeyIndex,
+{
     pub fn from_arc<B>(arc: ArcView<B>) -> Result<Self, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Arc<Data<B>>>
-            + AsStorage<Edge<Data<B>>>
-            + AsStorage<Face<Data<B>>>
-            + AsStorage<Vertex<Data<B>>>
+        B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+            + AsStorage<Edge<Data<B>, Index<B>>>
+            + AsStorage<Face<Data<B>, Index<B>>>
+            + AsStorage<Vertex<Data<B>, Index<B>>>
             + Consistent
-            + Parametric,
+            + Parametric<Index = K>,
     {
         let a = arc.source_vertex().key();
         let b = arc.destination_vertex().key();
@@ -273,22 +308,28 @@ impl EdgeRemoveCache {
     }
 }
 
-pub struct EdgeSplitCache {
-    a: VertexKey,
-    b: VertexKey,
-    ab: ArcKey,
-    ba: ArcKey,
-    ab_ba: EdgeKey,
+pub struct EdgeSplitCache<K>
+where
+    K: KeyIndex,
+{
+    a: VertexKey<K>,
+    b: VertexKey<K>,
+    ab: ArcKey<K>,
+    ba: ArcKey<K>,
+    ab_ba: EdgeKey<K>,
 }
 
-impl EdgeSplitCache {
+impl<K> EdgeSplitCache<K>
+where
+    K: KeyIndex,
+{
     pub fn from_arc<B>(arc: ArcView<B>) -> Result<Self, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Arc<Data<B>>>
-            + AsStorage<Edge<Data<B>>>
-            + AsStorage<Vertex<Data<B>>>
-            + Parametric,
+        B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+            + AsStorage<Edge<Data<B>, Index<B>>>
+            + AsStorage<Vertex<Data<B>, Index<B>>>
+            + Parametric<Index = K>,
     {
         let opposite = arc
             .to_ref()
@@ -305,7 +346,7 @@ impl EdgeSplitCache {
         let edge = arc
             .to_ref()
             .into_reachable_edge()
-            .ok_or(GraphError::TopologyNotFound)?;
+            .ok_or(GraphError::TopologyNotFound(TopologyKind::Edge))?;
         Ok(EdgeSplitCache {
             a: source.key(),
             b: destination.key(),
@@ -316,23 +357,31 @@ impl EdgeSplitCache {
     }
 }
 
-pub struct ArcBridgeCache {
-    a: VertexKey,
-    b: VertexKey,
-    c: VertexKey,
-    d: VertexKey,
+pub struct ArcBridgeCache<K>
+where
+    K: KeyIndex,
+{
+    a: VertexKey<K>,
+    b: VertexKey<K>,
+    c: VertexKey<K>,
+    d: VertexKey<K>,
 }
 
-impl ArcBridgeCache {
-    pub fn from_arc<B>(arc: ArcView<B>, destination: ArcKey) -> Result<Self, GraphError>
+impl<K> ArcBridgeCache<K>
+where
+    K: KeyIndex,
+{
+    pub fn from_arc<B>(arc: ArcView<B>, destination: ArcKey<K>) -> Result<Self, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Arc<Data<B>>> + AsStorage<Vertex<Data<B>>> + Parametric,
+        B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+            + AsStorage<Vertex<Data<B>, Index<B>>>
+            + Parametric<Index = K>,
     {
         let destination: ArcView<_> = arc
             .to_ref()
             .rebind(destination)
-            .ok_or(GraphError::TopologyNotFound)?;
+            .ok_or(GraphError::TopologyNotFound(TopologyKind::Arc))?;
         let a = arc
             .to_ref()
             .into_reachable_source_vertex()
@@ -369,33 +418,42 @@ impl ArcBridgeCache {
 
     pub fn from_storage<B>(
         storage: B,
-        source: ArcKey,
-        destination: ArcKey,
+        source: ArcKey<K>,
+        destination: ArcKey<K>,
     ) -> Result<Self, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Arc<Data<B>>> + AsStorage<Vertex<Data<B>>> + Parametric,
+        B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+            + AsStorage<Vertex<Data<B>, Index<B>>>
+            + Parametric<Index = K>,
     {
         ArcBridgeCache::from_arc(
-            ArcView::bind(storage, source).ok_or(GraphError::TopologyNotFound)?,
+            ArcView::bind(storage, source)
+                .ok_or(GraphError::TopologyNotFound(TopologyKind::Arc))?,
             destination,
         )
     }
 }
 
-pub struct ArcExtrudeCache {
-    ab: ArcKey,
+pub struct ArcExtrudeCache<K>
+where
+    K: KeyIndex,
+{
+    ab: ArcKey<K>,
 }
 
-impl ArcExtrudeCache {
+impl<K> ArcExtrudeCache<K>
+where
+    K: KeyIndex,
+{
     pub fn from_arc<B>(arc: ArcView<B>) -> Result<Self, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Arc<Data<B>>>
-            + AsStorage<Face<Data<B>>>
-            + AsStorage<Vertex<Data<B>>>
+        B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+            + AsStorage<Face<Data<B>, Index<B>>>
+            + AsStorage<Vertex<Data<B>, Index<B>>>
             + Consistent
-            + Parametric,
+            + Parametric<Index = K>,
     {
         if !arc.is_boundary_arc() {
             Err(GraphError::TopologyConflict)
@@ -406,22 +464,24 @@ impl ArcExtrudeCache {
     }
 }
 
+#[allow(clippy::type_complexity)]
 pub fn get_or_insert_with<N, P, F>(
     mut mutation: N,
-    endpoints: (VertexKey, VertexKey),
+    endpoints: (VertexKey<Index<P::Graph>>, VertexKey<Index<P::Graph>>),
     f: F,
-) -> Result<CompositeEdgeKey, GraphError>
+) -> Result<CompositeEdgeKey<Index<P::Graph>>, GraphError>
 where
     N: AsMut<Mutation<P>>,
     P: Mode,
     P::Graph: Mutable,
     F: FnOnce() -> CompositeEdgeData<Data<P::Graph>>,
 {
+    #[allow(clippy::type_complexity)]
     fn get_or_insert_arc<N, P>(
         mut mutation: N,
-        endpoints: (VertexKey, VertexKey),
+        endpoints: (VertexKey<Index<P::Graph>>, VertexKey<Index<P::Graph>>),
         data: <Data<P::Graph> as GraphData>::Arc,
-    ) -> (Option<EdgeKey>, ArcKey)
+    ) -> (Option<EdgeKey<Index<P::Graph>>>, ArcKey<Index<P::Graph>>)
     where
         N: AsMut<Mutation<P>>,
         P: Mode,
@@ -456,7 +516,8 @@ where
                 .storage
                 .1
                 .as_storage_mut()
-                .insert(Edge::new(ab, data.0));
+                .insert(Edge::new(ab, data.0))
+                .map_err(|_| GraphError::KeyspaceExhausted)?;
             mutation.as_mut().connect_arc_to_edge(ab, ab_ba)?;
             mutation.as_mut().connect_arc_to_edge(ba, ab_ba)?;
             Ok((ab_ba, (ab, ba)))
@@ -468,23 +529,21 @@ where
     }
 }
 
-// TODO: Removing arcs must also remove disjoint vertices. More importantly, the
-//       leading arc of vertices may be invalidated by this operation and must
-//       be healed. This code does not handle these cases, and so can become
-//       inconsistent.
+#[allow(clippy::type_complexity)]
 pub fn remove<N, P>(
     mut mutation: N,
-    cache: EdgeRemoveCache,
-) -> Result<CompositeEdge<Data<P::Graph>>, GraphError>
+    cache: EdgeRemoveCache<Index<P::Graph>>,
+) -> Result<CompositeEdge<Data<P::Graph>, Index<P::Graph>>, GraphError>
 where
     N: AsMut<Mutation<P>>,
     P: Mode,
     P::Graph: Mutable,
 {
+    #[allow(clippy::type_complexity)]
     fn remove_arc<N, P>(
         mut mutation: N,
-        cache: ArcRemoveCache,
-    ) -> Result<Arc<Data<P::Graph>>, GraphError>
+        cache: ArcRemoveCache<Index<P::Graph>>,
+    ) -> Result<Arc<Data<P::Graph>, Index<P::Graph>>, GraphError>
     where
         N: AsMut<Mutation<P>>,
         P: Mode,
@@ -500,7 +559,7 @@ where
             .0
             .as_storage_mut()
             .remove(&ab)
-            .ok_or(GraphError::TopologyNotFound)
+            .ok_or(GraphError::TopologyNotFound(TopologyKind::Arc))
     }
 
     let EdgeRemoveCache {
@@ -524,13 +583,22 @@ where
     if let (Some(xb), Some(bx)) = (opposite.xa, arc.bx) {
         mutation.as_mut().connect_adjacent_arcs(xb, bx)?;
     }
+    // Remove any vertices that become disjoint. Such a vertex has no other
+    // outgoing arc.
+    for (key, bx) in [(a, opposite.bx), (b, arc.bx)] {
+        if bx.is_none() {
+            mutation.as_mut().disconnect_outgoing_arc(key)?;
+            vertex::remove_isolated(mutation.as_mut(), key)?;
+        }
+    }
     let edge = mutation
         .as_mut()
         .storage
         .1
         .as_storage_mut()
         .remove(&ab_ba)
-        .ok_or(GraphError::TopologyNotFound)?;
+        .ok_or(GraphError::TopologyNotFound(TopologyKind::Edge))?;
+    mutation.as_mut().discard_layers(ab_ba);
     Ok((
         edge,
         (
@@ -540,18 +608,67 @@ where
     ))
 }
 
+// Removes an edge and its arcs without connecting the remaining adjacent arcs
+// or vertices. The arcs must not be connected to faces. Callers are responsible
+// for restoring consistency.
+#[allow(clippy::type_complexity)]
+pub fn remove_faceless<N, P>(
+    mut mutation: N,
+    ab: ArcKey<Index<P::Graph>>,
+) -> Result<CompositeEdge<Data<P::Graph>, Index<P::Graph>>, GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+{
+    let ba = ab.into_opposite();
+    let mut remove_arc = |ab| {
+        mutation
+            .as_mut()
+            .storage
+            .0
+            .as_storage_mut()
+            .remove(&ab)
+            .ok_or(GraphError::TopologyNotFound(TopologyKind::Arc))
+            .and_then(|arc: Arc<_, _>| {
+                if arc.face.is_some() {
+                    Err(GraphError::TopologyConflict)
+                }
+                else {
+                    Ok(arc)
+                }
+            })
+    };
+    let arc = remove_arc(ab)?;
+    let opposite = remove_arc(ba)?;
+    let ab_ba = arc.edge.ok_or(GraphError::TopologyMalformed)?;
+    let edge = mutation
+        .as_mut()
+        .storage
+        .1
+        .as_storage_mut()
+        .remove(&ab_ba)
+        .ok_or(GraphError::TopologyNotFound(TopologyKind::Edge))?;
+    mutation.as_mut().discard_layers(ab_ba);
+    Ok((edge, (arc, opposite)))
+}
+
 pub fn split_with<N, P, F>(
     mut mutation: N,
-    cache: EdgeSplitCache,
+    cache: EdgeSplitCache<Index<P::Graph>>,
     f: F,
-) -> Result<VertexKey, GraphError>
+) -> Result<VertexKey<Index<P::Graph>>, GraphError>
 where
     N: AsMut<Mutation<P>>,
     P: Mode,
     P::Graph: Mutable,
     F: FnOnce() -> <Data<P::Graph> as GraphData>::Vertex,
 {
-    fn remove<N, P>(mut mutation: N, ab: ArcKey) -> Result<Arc<Data<P::Graph>>, GraphError>
+    #[allow(clippy::type_complexity)]
+    fn remove<N, P>(
+        mut mutation: N,
+        ab: ArcKey<Index<P::Graph>>,
+    ) -> Result<Arc<Data<P::Graph>, Index<P::Graph>>, GraphError>
     where
         N: AsMut<Mutation<P>>,
         P: Mode,
@@ -580,14 +697,15 @@ where
         Ok(arc)
     }
 
+    #[allow(clippy::type_complexity)]
     fn split_at_vertex<N, P>(
         mut mutation: N,
-        a: VertexKey,
-        b: VertexKey,
-        m: VertexKey,
-        ab: ArcKey,
+        a: VertexKey<Index<P::Graph>>,
+        b: VertexKey<Index<P::Graph>>,
+        m: VertexKey<Index<P::Graph>>,
+        ab: ArcKey<Index<P::Graph>>,
         edge_data: <Data<P::Graph> as GraphData>::Edge,
-    ) -> Result<(ArcKey, ArcKey), GraphError>
+    ) -> Result<(ArcKey<Index<P::Graph>>, ArcKey<Index<P::Graph>>), GraphError>
     where
         N: AsMut<Mutation<P>>,
         P: Mode,
@@ -630,7 +748,7 @@ where
         ba,
         ab_ba,
     } = cache;
-    let m = vertex::insert(mutation.as_mut(), f());
+    let m = vertex::insert(mutation.as_mut(), f())?;
     // Remove the edge.
     let Edge { data, .. } = mutation
         .as_mut()
@@ -639,13 +757,18 @@ where
         .as_storage_mut()
         .remove(&ab_ba)
         .ok_or(GraphError::TopologyMalformed)?;
+    mutation.as_mut().discard_layers(ab_ba);
+    mutation.as_mut().interpolate_layers(m, &[a, b]);
     // Split the arcs.
     split_at_vertex(mutation.as_mut(), a, b, m, ab, data.clone())?;
     split_at_vertex(mutation.as_mut(), b, a, m, ba, data)?;
     Ok(m)
 }
 
-pub fn bridge<N, P>(mut mutation: N, cache: ArcBridgeCache) -> Result<FaceKey, GraphError>
+pub fn bridge<N, P>(
+    mut mutation: N,
+    cache: ArcBridgeCache<Index<P::Graph>>,
+) -> Result<FaceKey<Index<P::Graph>>, GraphError>
 where
     N: AsMut<Mutation<P>>,
     P: Mode,
@@ -662,9 +785,9 @@ where
 #[allow(clippy::many_single_char_names)]
 pub fn extrude_with<N, P, F>(
     mut mutation: N,
-    cache: ArcExtrudeCache,
+    cache: ArcExtrudeCache<Index<P::Graph>>,
     f: F,
-) -> Result<ArcKey, GraphError>
+) -> Result<ArcKey<Index<P::Graph>>, GraphError>
 where
     N: AsMut<Mutation<P>>,
     P: Mode,
@@ -672,20 +795,22 @@ where
     F: Fn(<Data<P::Graph> as GraphData>::Vertex) -> <Data<P::Graph> as GraphData>::Vertex,
 {
     let ArcExtrudeCache { ab } = cache;
+    let (a, b) = ab.into();
     let (c, d) = {
-        let (a, b) = ab.into();
         let c = VertexView::bind(mutation.as_mut(), b)
-            .ok_or(GraphError::TopologyNotFound)?
+            .ok_or(GraphError::TopologyNotFound(TopologyKind::Vertex))?
             .get()
             .clone();
         let d = VertexView::bind(mutation.as_mut(), a)
-            .ok_or(GraphError::TopologyNotFound)?
+            .ok_or(GraphError::TopologyNotFound(TopologyKind::Vertex))?
             .get()
             .clone();
         (f(c), f(d))
     };
-    let c = vertex::insert(mutation.as_mut(), c);
-    let d = vertex::insert(mutation.as_mut(), d);
+    let c = vertex::insert(mutation.as_mut(), c)?;
+    let d = vertex::insert(mutation.as_mut(), d)?;
+    mutation.as_mut().interpolate_layers(c, &[b]);
+    mutation.as_mut().interpolate_layers(d, &[a]);
     let cd =
         get_or_insert_with(mutation.as_mut(), (c, d), Default::default).map(|(_, (cd, _))| cd)?;
     let cache = ArcBridgeCache::from_storage(mutation.as_mut(), ab, cd)?;
@@ -1,36 +1,40 @@
 use smallvec::SmallVec;
 use std::borrow::Borrow;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
+use std::mem;
 use std::ops::{Deref, DerefMut};
 
 use crate::entity::borrow::Reborrow;
 use crate::entity::storage::prelude::*;
-use crate::entity::storage::{AsStorage, AsStorageMut, Fuse, StorageTarget};
+use crate::entity::storage::{AsStorage, AsStorageMut, Fuse, KeyIndex, StorageTarget};
 use crate::entity::view::{Bind, ClosedView, Rebind, Unbind};
 use crate::graph::core::Core;
-use crate::graph::data::{Data, GraphData, Parametric};
+use crate::graph::data::{Data, GraphData, Index, Parametric};
 use crate::graph::edge::{Arc, ArcKey, ArcView, Edge};
 use crate::graph::face::{Face, FaceKey, FaceView, ToRing};
+use crate::graph::layer::{Layered, Layers};
 use crate::graph::mutation::edge::{self, ArcBridgeCache, EdgeMutation};
-use crate::graph::mutation::{vertex, Consistent, Immediate, Mode, Mutable, Mutation};
+use crate::graph::mutation::{self, vertex, Consistent, Immediate, Mode, Mutable, Mutation};
 use crate::graph::vertex::{Vertex, VertexKey, VertexView};
-use crate::graph::GraphError;
+use crate::graph::{GraphError, TopologyKind};
 use crate::transact::{Bypass, Transact};
 use crate::{DynamicArity, IteratorExt as _};
 
 type ModalCore<P> = Core<
     Data<<P as Mode>::Graph>,
+    Index<<P as Mode>::Graph>,
     <P as Mode>::VertexStorage,
     <P as Mode>::ArcStorage,
     <P as Mode>::EdgeStorage,
     <P as Mode>::FaceStorage,
 >;
-pub type RefCore<'a, G> = Core<
+pub type RefCore<'a, G, K> = Core<
     G,
-    &'a StorageTarget<'a, Vertex<G>>,
-    &'a StorageTarget<'a, Arc<G>>,
-    &'a StorageTarget<'a, Edge<G>>,
-    &'a StorageTarget<'a, Face<G>>,
+    K,
+    &'a StorageTarget<'a, Vertex<G, K>>,
+    &'a StorageTarget<'a, Arc<G, K>>,
+    &'a StorageTarget<'a, Edge<G, K>>,
+    &'a StorageTarget<'a, Face<G, K>>,
 >;
 
 pub struct FaceMutation<P>
@@ -39,23 +43,51 @@ where
 {
     inner: EdgeMutation<P>,
     storage: P::FaceStorage,
+    layers: Layers<Index<P::Graph>>,
 }
 
 impl<P> FaceMutation<P>
 where
     P: Mode,
 {
-    pub fn to_ref_core(&self) -> RefCore<Data<P::Graph>> {
+    pub fn to_ref_core(&self) -> RefCore<Data<P::Graph>, Index<P::Graph>> {
         self.inner.to_ref_core().fuse(self.storage.as_storage())
     }
 
+    // Discards the values of the given topology in layers. This must be called
+    // when topology is removed from storage.
+    pub fn discard_layers<L>(&mut self, key: L)
+    where
+        L: Layered<Index = Index<P::Graph>>,
+    {
+        L::layers_mut(&mut self.layers).discard(&key);
+    }
+
+    // Initializes the values of the given topology in layers from the values of
+    // the topology from which it is derived. Layers that do not interpolate
+    // values are unaffected and read their default value.
+    pub fn interpolate_layers<L>(&mut self, key: L, sources: &[L])
+    where
+        L: Layered<Index = Index<P::Graph>>,
+    {
+        L::layers_mut(&mut self.layers).interpolate(key, sources);
+    }
+
     // TODO: Should there be a distinction between `connect_face_to_arc` and
     //       `connect_arc_to_face`?
-    pub fn connect_face_to_arc(&mut self, ab: ArcKey, abc: FaceKey) -> Result<(), GraphError> {
+    pub fn connect_face_to_arc(
+        &mut self,
+        ab: ArcKey<Index<P::Graph>>,
+        abc: FaceKey<Index<P::Graph>>,
+    ) -> Result<(), GraphError> {
         self.with_face_mut(abc, |face| face.arc = ab)
     }
 
-    fn connect_face_interior(&mut self, arcs: &[ArcKey], face: FaceKey) -> Result<(), GraphError> {
+    fn connect_face_interior(
+        &mut self,
+        arcs: &[ArcKey<Index<P::Graph>>],
+        face: FaceKey<Index<P::Graph>>,
+    ) -> Result<(), GraphError> {
         for (ab, bc) in arcs.iter().cloned().perimeter() {
             self.connect_adjacent_arcs(ab, bc)?;
             self.connect_arc_to_face(ab, face)?;
@@ -63,89 +95,212 @@ where
         Ok(())
     }
 
-    fn disconnect_face_interior(&mut self, arcs: &[ArcKey]) -> Result<(), GraphError> {
+    fn disconnect_face_interior(
+        &mut self,
+        arcs: &[ArcKey<Index<P::Graph>>],
+    ) -> Result<(), GraphError> {
         for ab in arcs {
             self.disconnect_arc_from_face(*ab)?;
         }
         Ok(())
     }
 
+    // Relinks the arcs about the perimeter of a face that is about to be
+    // inserted and gets the connections that join its exterior arcs to the
+    // boundary. This must be called before the arcs of the face are inserted,
+    // because it examines which arcs already exist.
+    //
+    // If consecutive arcs AB and BC of the face already exist but are not
+    // adjacent, then the boundary arcs between them are moved into another
+    // boundary gap at B. The remaining connections are returned so that they
+    // can be made once the arcs of the face have been inserted.
+    #[allow(clippy::type_complexity)]
     fn connect_face_exterior(
         &mut self,
-        arcs: &[ArcKey],
-        connectivity: (
-            HashMap<VertexKey, Vec<ArcKey>>,
-            HashMap<VertexKey, Vec<ArcKey>>,
-        ),
-    ) -> Result<(), GraphError> {
-        let (incoming, outgoing) = connectivity;
-        for ab in arcs.iter().cloned() {
-            let (a, b) = ab.into();
-            let ba = ab.into_opposite();
-            let adjacent = {
-                let core = &self.to_ref_core();
-                if ArcView::bind(core, ba)
-                    .ok_or(GraphError::TopologyMalformed)?
-                    .is_boundary_arc()
+        perimeter: &[VertexKey<Index<P::Graph>>],
+    ) -> Result<Vec<(ArcKey<Index<P::Graph>>, ArcKey<Index<P::Graph>>)>, GraphError> {
+        let arcs = perimeter
+            .iter()
+            .cloned()
+            .perimeter()
+            .map(ArcKey::from)
+            .collect::<Vec<_>>();
+        let n = arcs.len();
+        let is_new = arcs
+            .iter()
+            .map(|ab| self.arc(*ab).is_none())
+            .collect::<Vec<_>>();
+        for i in 0..n {
+            let (ab, bc) = (arcs[i], arcs[(i + 1) % n]);
+            if is_new[i] || is_new[(i + 1) % n] {
+                continue;
+            }
+            let bx = self.next_arc(ab)?;
+            if bx == bc {
+                continue;
+            }
+            let yb = self.previous_arc(bc)?;
+            // Find another boundary arc ZB incoming to B into which the arcs
+            // from BX to YB can be moved.
+            let mut zb = bc.into_opposite();
+            let mut count = 0;
+            loop {
+                zb = self.next_arc(zb)?.into_opposite();
+                if zb != ab
+                    && self
+                        .arc(zb)
+                        .ok_or(GraphError::TopologyMalformed)?
+                        .face
+                        .is_none()
                 {
-                    // The next arc of BA is the outgoing arc of the destination
-                    // vertex A that is also a boundary arc or, if there is no
-                    // such outgoing arc, the next exterior arc of the face. The
-                    // previous arc is similar.
-                    let ax = outgoing[&a]
-                        .iter()
-                        .cloned()
-                        .flat_map(|ax| ArcView::bind(core, ax))
-                        .find(|next| next.is_boundary_arc())
-                        .or_else(|| {
-                            ArcView::bind(core, ab)
-                                .and_then(|arc| arc.into_reachable_previous_arc())
-                                .and_then(|previous| previous.into_reachable_opposite_arc())
-                        })
-                        .map(|next| next.key());
-                    let xb = incoming[&b]
-                        .iter()
-                        .cloned()
-                        .flat_map(|xb| ArcView::bind(core, xb))
-                        .find(|previous| previous.is_boundary_arc())
-                        .or_else(|| {
-                            ArcView::bind(core, ab)
-                                .and_then(|arc| arc.into_reachable_next_arc())
-                                .and_then(|next| next.into_reachable_opposite_arc())
-                        })
-                        .map(|previous| previous.key());
-                    ax.into_iter().zip(xb.into_iter()).next()
+                    break;
                 }
-                else {
-                    None
+                count += 1;
+                if count > self.arc_count() {
+                    return Err(GraphError::TopologyMalformed);
                 }
-            };
-            if let Some((ax, xb)) = adjacent {
-                self.connect_adjacent_arcs(ba, ax)?;
-                self.connect_adjacent_arcs(xb, ba)?;
             }
+            let bz = self.next_arc(zb)?;
+            if bz == bc {
+                // There is no other boundary at B.
+                return Err(GraphError::TopologyConflict);
+            }
+            self.connect_adjacent_arcs(zb, bx)?;
+            self.connect_adjacent_arcs(yb, bz)?;
+            self.connect_adjacent_arcs(ab, bc)?;
         }
-        Ok(())
+        let mut connections = Vec::with_capacity(2 * n);
+        for i in 0..n {
+            let (ab, bc) = (arcs[i], arcs[(i + 1) % n]);
+            let (ba, cb) = (ab.into_opposite(), bc.into_opposite());
+            match (is_new[i], is_new[(i + 1) % n]) {
+                (true, false) => {
+                    connections.push((self.previous_arc(bc)?, ba));
+                }
+                (false, true) => {
+                    connections.push((cb, self.next_arc(ab)?));
+                }
+                (true, true) => {
+                    let (_, b) = ab.into();
+                    if let Some(bx) = self.boundary_outgoing_arc(b)? {
+                        connections.push((self.previous_arc(bx)?, ba));
+                        connections.push((cb, bx));
+                    }
+                    else {
+                        // B is isolated.
+                        connections.push((cb, ba));
+                    }
+                }
+                (false, false) => {}
+            }
+        }
+        Ok(connections)
+    }
+
+    // Gets an outgoing boundary arc of the given vertex. Returns `None` if the
+    // vertex is isolated and fails if the vertex has no boundary, because a
+    // face cannot be inserted there without making the vertex non-manifold.
+    #[allow(clippy::type_complexity)]
+    fn boundary_outgoing_arc(
+        &self,
+        a: VertexKey<Index<P::Graph>>,
+    ) -> Result<Option<ArcKey<Index<P::Graph>>>, GraphError> {
+        let vertices: &StorageTarget<Vertex<Data<P::Graph>, Index<P::Graph>>> =
+            self.inner.deref().as_storage();
+        let start = match vertices
+            .get(&a)
+            .ok_or(GraphError::TopologyNotFound(TopologyKind::Vertex))?
+            .arc
+        {
+            Some(ab) => ab,
+            None => return Ok(None),
+        };
+        let mut ab = start;
+        for _ in 0..=self.arc_count() {
+            if self
+                .arc(ab)
+                .ok_or(GraphError::TopologyMalformed)?
+                .face
+                .is_none()
+            {
+                return Ok(Some(ab));
+            }
+            ab = self.next_arc(ab.into_opposite())?;
+            if ab == start {
+                return Err(GraphError::TopologyConflict);
+            }
+        }
+        Err(GraphError::TopologyMalformed)
+    }
+
+    // Gets the boundary arc that follows the boundary arc XB by rotating about
+    // B through its adjacent faces.
+    fn next_boundary_arc(
+        &self,
+        xb: ArcKey<Index<P::Graph>>,
+    ) -> Result<ArcKey<Index<P::Graph>>, GraphError> {
+        let mut bx = xb.into_opposite();
+        for _ in 0..=self.arc_count() {
+            let arc = self.arc(bx).ok_or(GraphError::TopologyMalformed)?;
+            if arc.face.is_none() {
+                return Ok(bx);
+            }
+            bx = arc
+                .previous
+                .ok_or(GraphError::TopologyMalformed)?
+                .into_opposite();
+        }
+        Err(GraphError::TopologyMalformed)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn arc(&self, ab: ArcKey<Index<P::Graph>>) -> Option<&Arc<Data<P::Graph>, Index<P::Graph>>> {
+        AsStorage::<Arc<Data<P::Graph>, Index<P::Graph>>>::as_storage(&self.inner).get(&ab)
+    }
+
+    fn arc_count(&self) -> usize {
+        AsStorage::<Arc<Data<P::Graph>, Index<P::Graph>>>::as_storage(&self.inner).len()
+    }
+
+    // Arcs of an edge that has been inserted but not yet connected are treated
+    // like a dangling edge, where each arc is adjacent to its opposite arc.
+    fn next_arc(&self, ab: ArcKey<Index<P::Graph>>) -> Result<ArcKey<Index<P::Graph>>, GraphError> {
+        self.arc(ab)
+            .map(|arc| arc.next.unwrap_or_else(|| ab.into_opposite()))
+            .ok_or(GraphError::TopologyMalformed)
+    }
+
+    fn previous_arc(
+        &self,
+        ab: ArcKey<Index<P::Graph>>,
+    ) -> Result<ArcKey<Index<P::Graph>>, GraphError> {
+        self.arc(ab)
+            .map(|arc| arc.previous.unwrap_or_else(|| ab.into_opposite()))
+            .ok_or(GraphError::TopologyMalformed)
     }
 
-    fn with_face_mut<T, F>(&mut self, abc: FaceKey, mut f: F) -> Result<T, GraphError>
+    fn with_face_mut<T, F>(
+        &mut self,
+        abc: FaceKey<Index<P::Graph>>,
+        mut f: F,
+    ) -> Result<T, GraphError>
     where
-        F: FnMut(&mut Face<Data<P::Graph>>) -> T,
+        F: FnMut(&mut Face<Data<P::Graph>, Index<P::Graph>>) -> T,
     {
         let face = self
             .storage
             .as_storage_mut()
             .get_mut(&abc)
-            .ok_or(GraphError::TopologyNotFound)?;
+            .ok_or(GraphError::TopologyNotFound(TopologyKind::Face))?;
         Ok(f(face))
     }
 }
 
-impl<P> AsStorage<Face<Data<P::Graph>>> for FaceMutation<P>
+impl<P> AsStorage<Face<Data<P::Graph>, Index<P::Graph>>> for FaceMutation<P>
 where
     P: Mode,
 {
-    fn as_storage(&self) -> &StorageTarget<Face<Data<P::Graph>>> {
+    fn as_storage(&self) -> &StorageTarget<Face<Data<P::Graph>, Index<P::Graph>>> {
         self.storage.as_storage()
     }
 }
@@ -158,9 +313,11 @@ where
         let FaceMutation {
             inner,
             storage: faces,
-            ..
+            layers,
         } = self;
-        inner.bypass().fuse(faces)
+        let mut core = inner.bypass().fuse(faces);
+        core.layers = layers;
+        core
     }
 }
 
@@ -189,11 +346,15 @@ impl<P> From<ModalCore<P>> for FaceMutation<P>
 where
     P: Mode,
 {
-    fn from(core: ModalCore<P>) -> Self {
+    fn from(mut core: ModalCore<P>) -> Self {
+        // Layers are carried through the mutation. Values are discarded and
+        // interpolated as topology is removed and inserted.
+        let layers = mem::take(&mut core.layers);
         let (vertices, arcs, edges, faces) = core.unfuse();
         FaceMutation {
             storage: faces,
             inner: Core::empty().fuse(vertices).fuse(arcs).fuse(edges).into(),
+            layers,
         }
     }
 }
@@ -211,31 +372,37 @@ where
         let FaceMutation {
             inner,
             storage: faces,
-            ..
+            layers,
         } = self;
-        inner.commit().map(move |core| core.fuse(faces))
+        inner.commit().map(move |core| {
+            let mut core = core.fuse(faces);
+            core.layers = layers;
+            core
+        })
     }
 
     fn abort(self) -> Self::Abort {}
 }
 
-pub struct FaceInsertCache {
-    perimeter: SmallVec<[VertexKey; 4]>,
-    connectivity: (
-        HashMap<VertexKey, Vec<ArcKey>>,
-        HashMap<VertexKey, Vec<ArcKey>>,
-    ),
+pub struct FaceInsertCache<K>
+where
+    K: KeyIndex,
+{
+    perimeter: SmallVec<[VertexKey<K>; 4]>,
 }
 
-impl FaceInsertCache {
+impl<K> FaceInsertCache<K>
+where
+    K: KeyIndex,
+{
     pub fn from_ring<B, T>(ring: T) -> Result<Self, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Arc<Data<B>>>
-            + AsStorage<Face<Data<B>>>
-            + AsStorage<Vertex<Data<B>>>
+        B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+            + AsStorage<Face<Data<B>, Index<B>>>
+            + AsStorage<Vertex<Data<B>, Index<B>>>
             + Consistent
-            + Parametric,
+            + Parametric<Index = K>,
         T: ToRing<B>,
     {
         let ring = ring.into_ring();
@@ -243,15 +410,15 @@ impl FaceInsertCache {
         FaceInsertCache::from_storage(storage, ring.vertices().keys())
     }
 
-    pub fn from_storage<B, K>(storage: B, perimeter: K) -> Result<Self, GraphError>
+    pub fn from_storage<B, I>(storage: B, perimeter: I) -> Result<Self, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Arc<Data<B>>>
-            + AsStorage<Face<Data<B>>>
-            + AsStorage<Vertex<Data<B>>>
-            + Parametric,
-        K: IntoIterator,
-        K::Item: Borrow<VertexKey>,
+        B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+            + AsStorage<Face<Data<B>, Index<B>>>
+            + AsStorage<Vertex<Data<B>, Index<B>>>
+            + Parametric<Index = K>,
+        I: IntoIterator,
+        I::Item: Borrow<VertexKey<K>>,
     {
         let perimeter = perimeter
             .into_iter()
@@ -265,11 +432,10 @@ impl FaceInsertCache {
         }
 
         let storage = storage.reborrow();
-        let vertices = perimeter
-            .iter()
-            .cloned()
-            .map(|key| VertexView::bind(storage, key).ok_or(GraphError::TopologyNotFound))
-            .collect::<Result<SmallVec<[_; 4]>, _>>()?;
+        for key in perimeter.iter().cloned() {
+            VertexView::bind(storage, key)
+                .ok_or(GraphError::TopologyNotFound(TopologyKind::Vertex))?;
+        }
         for (previous, next) in perimeter
             .iter()
             .cloned()
@@ -300,35 +466,133 @@ impl FaceInsertCache {
             }
         }
 
-        let mut incoming = HashMap::with_capacity(arity);
-        let mut outgoing = HashMap::with_capacity(arity);
-        for vertex in vertices {
-            let key = vertex.key();
-            incoming.insert(key, vertex.reachable_incoming_arcs().keys().collect());
-            outgoing.insert(key, vertex.reachable_outgoing_arcs().keys().collect());
+        Ok(FaceInsertCache { perimeter })
+    }
+}
+
+pub struct FaceBatchInsertCache<K>
+where
+    K: KeyIndex,
+{
+    perimeters: Vec<SmallVec<[VertexKey<K>; 4]>>,
+    boundaries: Vec<ArcKey<K>>,
+}
+
+impl<K> FaceBatchInsertCache<K>
+where
+    K: KeyIndex,
+{
+    pub fn from_storage<B>(
+        storage: B,
+        perimeters: Vec<SmallVec<[VertexKey<K>; 4]>>,
+    ) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+            + AsStorage<Edge<Data<B>, Index<B>>>
+            + AsStorage<Face<Data<B>, Index<B>>>
+            + AsStorage<Vertex<Data<B>, Index<B>>>
+            + Consistent
+            + Parametric<Index = K>,
+    {
+        let storage = storage.reborrow();
+        let mut arcs = HashSet::new();
+        let mut vertices = HashSet::new();
+        for (index, perimeter) in perimeters.iter().enumerate() {
+            if perimeter.len() < 3 {
+                return Err(GraphError::ArityNonPolygonal.at_index(index));
+            }
+            if perimeter.iter().collect::<HashSet<_>>().len() != perimeter.len() {
+                // Vertex keys are not unique.
+                return Err(GraphError::TopologyMalformed.at_index(index));
+            }
+            if let Some(key) = perimeter
+                .iter()
+                .find(|key| VertexView::bind(storage, **key).is_none())
+            {
+                return Err(GraphError::TopologyNotFound(TopologyKind::Vertex)
+                    .at_key(*key)
+                    .at_index(index));
+            }
+            // Each arc may be occupied by at most one face, whether that face is
+            // in the graph or in the batch.
+            for ab in perimeter.iter().cloned().perimeter().map(ArcKey::from) {
+                let is_occupied =
+                    ArcView::bind(storage, ab).map_or(false, |arc| arc.face.is_some());
+                if is_occupied || !arcs.insert(ab) {
+                    return Err(GraphError::TopologyConflict.at_key(ab).at_index(index));
+                }
+            }
+            // As with the insertion of a single face, if an arc AB exists but
+            // BC does not, then the arc BX that follows AB must not bisect the
+            // perimeter.
+            let set = perimeter.iter().cloned().collect::<HashSet<_>>();
+            for (previous, next) in perimeter
+                .iter()
+                .cloned()
+                .perimeter()
+                .map(|keys| ArcView::bind(storage, keys.into()))
+                .perimeter()
+            {
+                if let (Some(previous), None) = (previous, next) {
+                    let ab = previous.key();
+                    if let Some(next) = previous.into_reachable_next_arc() {
+                        let (_, destination) = next.key().into();
+                        if set.contains(&destination) {
+                            return Err(GraphError::TopologyConflict.at_key(ab).at_index(index));
+                        }
+                    }
+                }
+            }
+            vertices.extend(perimeter.iter().cloned());
         }
-        Ok(FaceInsertCache {
-            perimeter,
-            connectivity: (incoming, outgoing),
+        // Keys are not reused, so the keyspace must accommodate an edge for
+        // each pair of arcs that is not yet in the graph and each face.
+        let edges = arcs
+            .iter()
+            .filter(|ab| ArcView::bind(storage, **ab).is_none())
+            .flat_map(|ab| [*ab, ab.into_opposite()])
+            .collect::<HashSet<_>>()
+            .len()
+            / 2;
+        mutation::check_keyspace::<_, Data<B>, K>(storage, 0, edges, perimeters.len())?;
+        // Boundary arcs that are incoming to the vertices of the batch may be
+        // relinked once the faces have been inserted.
+        let boundaries = vertices
+            .into_iter()
+            .flat_map(|key| VertexView::bind(storage, key))
+            .flat_map(|vertex| vertex.into_incoming_arcs())
+            .filter(|arc| arc.is_boundary_arc())
+            .map(|arc| arc.key())
+            .collect();
+        Ok(FaceBatchInsertCache {
+            perimeters,
+            boundaries,
         })
     }
 }
 
-pub struct FaceRemoveCache {
-    abc: FaceKey,
-    arcs: Vec<ArcKey>,
+pub struct FaceRemoveCache<K>
+where
+    K: KeyIndex,
+{
+    abc: FaceKey<K>,
+    arcs: Vec<ArcKey<K>>,
 }
 
-impl FaceRemoveCache {
+impl<K> FaceRemoveCache<K>
+where
+    K: KeyIndex,
+{
     // TODO: Should this require consistency?
     pub fn from_face<B>(face: FaceView<B>) -> Result<Self, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Arc<Data<B>>>
-            + AsStorage<Face<Data<B>>>
-            + AsStorage<Vertex<Data<B>>>
+        B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+            + AsStorage<Face<Data<B>, Index<B>>>
+            + AsStorage<Vertex<Data<B>, Index<B>>>
             + Consistent
-            + Parametric,
+            + Parametric<Index = K>,
     {
         let arcs = face.adjacent_arcs().keys().collect();
         Ok(FaceRemoveCache {
@@ -338,25 +602,31 @@ impl FaceRemoveCache {
     }
 }
 
-pub struct FaceSplitCache {
-    cache: FaceRemoveCache,
-    left: Vec<VertexKey>,
-    right: Vec<VertexKey>,
+pub struct FaceSplitCache<K>
+where
+    K: KeyIndex,
+{
+    cache: FaceRemoveCache<K>,
+    left: Vec<VertexKey<K>>,
+    right: Vec<VertexKey<K>>,
 }
 
-impl FaceSplitCache {
+impl<K> FaceSplitCache<K>
+where
+    K: KeyIndex,
+{
     pub fn from_face<B>(
         face: FaceView<B>,
-        source: VertexKey,
-        destination: VertexKey,
+        source: VertexKey<K>,
+        destination: VertexKey<K>,
     ) -> Result<Self, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Arc<Data<B>>>
-            + AsStorage<Face<Data<B>>>
-            + AsStorage<Vertex<Data<B>>>
+        B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+            + AsStorage<Face<Data<B>, Index<B>>>
+            + AsStorage<Vertex<Data<B>, Index<B>>>
             + Consistent
-            + Parametric,
+            + Parametric<Index = K>,
     {
         let perimeter = |face: FaceView<_>| {
             face.adjacent_vertices()
@@ -417,20 +687,26 @@ impl FaceSplitCache {
     }
 }
 
-pub struct FacePokeCache {
-    vertices: Vec<VertexKey>,
-    cache: FaceRemoveCache,
+pub struct FacePokeCache<K>
+where
+    K: KeyIndex,
+{
+    vertices: Vec<VertexKey<K>>,
+    cache: FaceRemoveCache<K>,
 }
 
-impl FacePokeCache {
+impl<K> FacePokeCache<K>
+where
+    K: KeyIndex,
+{
     pub fn from_face<B>(face: FaceView<B>) -> Result<Self, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Arc<Data<B>>>
-            + AsStorage<Face<Data<B>>>
-            + AsStorage<Vertex<Data<B>>>
+        B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+            + AsStorage<Face<Data<B>, Index<B>>>
+            + AsStorage<Vertex<Data<B>, Index<B>>>
             + Consistent
-            + Parametric,
+            + Parametric<Index = K>,
     {
         let vertices = face.adjacent_vertices().keys().collect();
         Ok(FacePokeCache {
@@ -440,26 +716,32 @@ impl FacePokeCache {
     }
 }
 
-pub struct FaceBridgeCache {
-    source: SmallVec<[ArcKey; 4]>,
-    destination: SmallVec<[ArcKey; 4]>,
-    cache: (FaceRemoveCache, FaceRemoveCache),
+pub struct FaceBridgeCache<K>
+where
+    K: KeyIndex,
+{
+    source: SmallVec<[ArcKey<K>; 4]>,
+    destination: SmallVec<[ArcKey<K>; 4]>,
+    cache: (FaceRemoveCache<K>, FaceRemoveCache<K>),
 }
 
-impl FaceBridgeCache {
-    pub fn from_face<B>(face: FaceView<B>, destination: FaceKey) -> Result<Self, GraphError>
+impl<K> FaceBridgeCache<K>
+where
+    K: KeyIndex,
+{
+    pub fn from_face<B>(face: FaceView<B>, destination: FaceKey<K>) -> Result<Self, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Arc<Data<B>>>
-            + AsStorage<Face<Data<B>>>
-            + AsStorage<Vertex<Data<B>>>
+        B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+            + AsStorage<Face<Data<B>, Index<B>>>
+            + AsStorage<Vertex<Data<B>, Index<B>>>
             + Consistent
-            + Parametric,
+            + Parametric<Index = K>,
     {
         let destination: FaceView<_> = face
             .to_ref()
             .rebind(destination)
-            .ok_or(GraphError::TopologyNotFound)?;
+            .ok_or(GraphError::TopologyNotFound(TopologyKind::Face))?;
         let cache = (
             FaceRemoveCache::from_face(face.to_ref())?,
             FaceRemoveCache::from_face(destination.to_ref())?,
@@ -476,20 +758,26 @@ impl FaceBridgeCache {
     }
 }
 
-pub struct FaceExtrudeCache {
-    sources: Vec<VertexKey>,
-    cache: FaceRemoveCache,
+pub struct FaceExtrudeCache<K>
+where
+    K: KeyIndex,
+{
+    sources: Vec<VertexKey<K>>,
+    cache: FaceRemoveCache<K>,
 }
 
-impl FaceExtrudeCache {
+impl<K> FaceExtrudeCache<K>
+where
+    K: KeyIndex,
+{
     pub fn from_face<B>(face: FaceView<B>) -> Result<Self, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Arc<Data<B>>>
-            + AsStorage<Face<Data<B>>>
-            + AsStorage<Vertex<Data<B>>>
+        B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+            + AsStorage<Face<Data<B>, Index<B>>>
+            + AsStorage<Vertex<Data<B>, Index<B>>>
             + Consistent
-            + Parametric,
+            + Parametric<Index = K>,
     {
         let sources = face.adjacent_vertices().keys().collect();
         let cache = FaceRemoveCache::from_face(face)?;
@@ -500,9 +788,9 @@ impl FaceExtrudeCache {
 // TODO: Should this accept arc data at all?
 pub fn insert_with<N, P, F>(
     mut mutation: N,
-    cache: FaceInsertCache,
+    cache: FaceInsertCache<Index<P::Graph>>,
     f: F,
-) -> Result<FaceKey, GraphError>
+) -> Result<FaceKey<Index<P::Graph>>, GraphError>
 where
     N: AsMut<Mutation<P>>,
     P: Mode,
@@ -512,11 +800,9 @@ where
         <Data<P::Graph> as GraphData>::Face,
     ),
 {
-    let FaceInsertCache {
-        perimeter,
-        connectivity,
-    } = cache;
+    let FaceInsertCache { perimeter } = cache;
     let data = f();
+    let connections = mutation.as_mut().connect_face_exterior(&perimeter)?;
     // Insert edges and collect the interior arcs.
     let arcs = perimeter
         .iter()
@@ -534,21 +820,80 @@ where
         .as_mut()
         .storage
         .as_storage_mut()
-        .insert(Face::new(arcs[0], data.1));
+        .insert(Face::new(arcs[0], data.1))
+        .map_err(|_| GraphError::KeyspaceExhausted)?;
     mutation.as_mut().connect_face_interior(&arcs, face)?;
-    mutation
-        .as_mut()
-        .connect_face_exterior(&arcs, connectivity)?;
+    for (ab, bc) in connections {
+        mutation.as_mut().connect_adjacent_arcs(ab, bc)?;
+    }
     Ok(face)
 }
 
+pub fn insert_batch_with<N, P, I>(
+    mut mutation: N,
+    cache: FaceBatchInsertCache<Index<P::Graph>>,
+    data: I,
+) -> Result<Vec<FaceKey<Index<P::Graph>>>, GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+    I: IntoIterator<Item = <Data<P::Graph> as GraphData>::Face>,
+{
+    let FaceBatchInsertCache {
+        perimeters,
+        mut boundaries,
+    } = cache;
+    // Insert the faces and connect their interiors. Edges are shared by all
+    // faces in the batch, so the arcs of adjacent faces are paired as they
+    // are inserted.
+    let mut faces = Vec::with_capacity(perimeters.len());
+    for (perimeter, data) in perimeters.iter().zip(data) {
+        let arcs = perimeter
+            .iter()
+            .cloned()
+            .perimeter()
+            .map(|(a, b)| {
+                edge::get_or_insert_with(mutation.as_mut(), (a, b), Default::default)
+                    .map(|(_, (ab, _))| ab)
+            })
+            .collect::<Result<SmallVec<[_; 4]>, _>>()?;
+        boundaries.extend(arcs.iter().map(|ab| ab.into_opposite()));
+        let face = mutation
+            .as_mut()
+            .storage
+            .as_storage_mut()
+            .insert(Face::new(arcs[0], data))
+            .map_err(|_| GraphError::KeyspaceExhausted)?;
+        mutation.as_mut().connect_face_interior(&arcs, face)?;
+        faces.push(face);
+    }
+    // Connect the exterior of the batch. Once all faces have been inserted,
+    // each boundary arc about the vertices of the batch is connected to the
+    // boundary arc that follows it about its destination vertex.
+    for xb in boundaries {
+        let is_boundary = mutation
+            .as_mut()
+            .arc(xb)
+            .ok_or(GraphError::TopologyMalformed)?
+            .face
+            .is_none();
+        if is_boundary {
+            let bx = mutation.as_mut().next_boundary_arc(xb)?;
+            mutation.as_mut().connect_adjacent_arcs(xb, bx)?;
+        }
+    }
+    Ok(faces)
+}
+
 // TODO: Does this require a cache (or consistency)?
 // TODO: This may need to be more destructive to maintain consistency. Edges,
 //       arcs, and vertices may also need to be removed.
+#[allow(clippy::type_complexity)]
 pub fn remove<N, P>(
     mut mutation: N,
-    cache: FaceRemoveCache,
-) -> Result<Face<Data<P::Graph>>, GraphError>
+    cache: FaceRemoveCache<Index<P::Graph>>,
+) -> Result<Face<Data<P::Graph>, Index<P::Graph>>, GraphError>
 where
     N: AsMut<Mutation<P>>,
     P: Mode,
@@ -561,11 +906,15 @@ where
         .storage
         .as_storage_mut()
         .remove(&abc)
-        .ok_or(GraphError::TopologyNotFound)?;
+        .ok_or(GraphError::TopologyNotFound(TopologyKind::Face))?;
+    mutation.as_mut().discard_layers(abc);
     Ok(face)
 }
 
-pub fn split<N, P>(mut mutation: N, cache: FaceSplitCache) -> Result<ArcKey, GraphError>
+pub fn split<N, P>(
+    mut mutation: N,
+    cache: FaceSplitCache<Index<P::Graph>>,
+) -> Result<ArcKey<Index<P::Graph>>, GraphError>
 where
     N: AsMut<Mutation<P>>,
     P: Mode,
@@ -583,9 +932,9 @@ where
 
 pub fn poke_with<N, P, F>(
     mut mutation: N,
-    cache: FacePokeCache,
+    cache: FacePokeCache<Index<P::Graph>>,
     f: F,
-) -> Result<VertexKey, GraphError>
+) -> Result<VertexKey<Index<P::Graph>>, GraphError>
 where
     N: AsMut<Mutation<P>>,
     P: Mode,
@@ -594,7 +943,8 @@ where
 {
     let FacePokeCache { vertices, cache } = cache;
     let face = remove(mutation.as_mut(), cache)?;
-    let c = vertex::insert(mutation.as_mut(), f());
+    let c = vertex::insert(mutation.as_mut(), f())?;
+    mutation.as_mut().interpolate_layers(c, &vertices);
     for (a, b) in vertices.into_iter().perimeter() {
         let cache = FaceInsertCache::from_storage(mutation.as_mut(), &[a, b, c])?;
         insert_with(mutation.as_mut(), cache, || {
@@ -604,7 +954,10 @@ where
     Ok(c)
 }
 
-pub fn bridge<N, P>(mut mutation: N, cache: FaceBridgeCache) -> Result<(), GraphError>
+pub fn bridge<N, P>(
+    mut mutation: N,
+    cache: FaceBridgeCache<Index<P::Graph>>,
+) -> Result<(), GraphError>
 where
     N: AsMut<Mutation<P>>,
     P: Mode,
@@ -632,9 +985,9 @@ where
 
 pub fn extrude_with<N, P, F>(
     mut mutation: N,
-    cache: FaceExtrudeCache,
+    cache: FaceExtrudeCache<Index<P::Graph>>,
     f: F,
-) -> Result<FaceKey, GraphError>
+) -> Result<FaceKey<Index<P::Graph>>, GraphError>
 where
     N: AsMut<Mutation<P>>,
     P: Mode,
@@ -653,12 +1006,15 @@ where
             .collect::<Vec<_>>()
     };
     if sources.len() != destinations.len() {
-        return Err(GraphError::TopologyNotFound);
+        return Err(GraphError::TopologyNotFound(TopologyKind::Vertex));
     }
     let destinations = destinations
         .into_iter()
         .map(|data| vertex::insert(mutation.as_mut(), data))
-        .collect::<Vec<_>>();
+        .collect::<Result<Vec<_>, _>>()?;
+    for (a, c) in sources.iter().zip(destinations.iter()) {
+        mutation.as_mut().interpolate_layers(*c, &[*a]);
+    }
     // Use the keys for the existing vertices and the translated geometries to
     // construct the extruded face and its connective faces.
     let cache = FaceInsertCache::from_storage(mutation.as_mut(), &destinations)?;
@@ -6,10 +6,12 @@ pub mod vertex;
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 
-use crate::entity::storage::{AsStorage, AsStorageMut, StorageTarget};
+use crate::entity::storage::{
+    AsStorage, AsStorageMut, AsStorageOf, Insert, KeyIndex, StorageTarget,
+};
 use crate::entity::Entity;
 use crate::graph::core::OwnedCore;
-use crate::graph::data::{Data, Parametric};
+use crate::graph::data::{Data, Index, Parametric};
 use crate::graph::edge::{Arc, Edge};
 use crate::graph::face::Face;
 use crate::graph::mutation::face::FaceMutation;
@@ -49,10 +51,10 @@ impl<'a, T> Consistent for &'a mut T where T: Consistent {}
 
 pub trait Mode {
     type Graph: Parametric;
-    type VertexStorage: AsStorageMut<Vertex<Data<Self::Graph>>>;
-    type ArcStorage: AsStorageMut<Arc<Data<Self::Graph>>>;
-    type EdgeStorage: AsStorageMut<Edge<Data<Self::Graph>>>;
-    type FaceStorage: AsStorageMut<Face<Data<Self::Graph>>>;
+    type VertexStorage: AsStorageMut<Vertex<Data<Self::Graph>, Index<Self::Graph>>>;
+    type ArcStorage: AsStorageMut<Arc<Data<Self::Graph>, Index<Self::Graph>>>;
+    type EdgeStorage: AsStorageMut<Edge<Data<Self::Graph>, Index<Self::Graph>>>;
+    type FaceStorage: AsStorageMut<Face<Data<Self::Graph>, Index<Self::Graph>>>;
 }
 
 pub struct Immediate<M>
@@ -67,17 +69,19 @@ where
     M: Parametric,
 {
     type Graph = M;
-    type VertexStorage = <Vertex<Data<M>> as Entity>::Storage;
-    type ArcStorage = <Arc<Data<M>> as Entity>::Storage;
-    type EdgeStorage = <Edge<Data<M>> as Entity>::Storage;
-    type FaceStorage = <Face<Data<M>> as Entity>::Storage;
+    type VertexStorage = <Vertex<Data<M>, Index<M>> as Entity>::Storage;
+    type ArcStorage = <Arc<Data<M>, Index<M>> as Entity>::Storage;
+    type EdgeStorage = <Edge<Data<M>, Index<M>> as Entity>::Storage;
+    type FaceStorage = <Face<Data<M>, Index<M>> as Entity>::Storage;
 }
 
 /// Graph mutation.
 pub struct Mutation<P>
 where
     P: Mode,
-    P::Graph: Consistent + From<OwnedCore<Data<P::Graph>>> + Into<OwnedCore<Data<P::Graph>>>,
+    P::Graph: Consistent
+        + From<OwnedCore<Data<P::Graph>, Index<P::Graph>>>
+        + Into<OwnedCore<Data<P::Graph>, Index<P::Graph>>>,
 {
     inner: FaceMutation<P>,
 }
@@ -85,7 +89,9 @@ where
 impl<P> AsRef<Self> for Mutation<P>
 where
     P: Mode,
-    P::Graph: Consistent + From<OwnedCore<Data<P::Graph>>> + Into<OwnedCore<Data<P::Graph>>>,
+    P::Graph: Consistent
+        + From<OwnedCore<Data<P::Graph>, Index<P::Graph>>>
+        + Into<OwnedCore<Data<P::Graph>, Index<P::Graph>>>,
 {
     fn as_ref(&self) -> &Self {
         self
@@ -95,56 +101,69 @@ where
 impl<P> AsMut<Self> for Mutation<P>
 where
     P: Mode,
-    P::Graph: Consistent + From<OwnedCore<Data<P::Graph>>> + Into<OwnedCore<Data<P::Graph>>>,
+    P::Graph: Consistent
+        + From<OwnedCore<Data<P::Graph>, Index<P::Graph>>>
+        + Into<OwnedCore<Data<P::Graph>, Index<P::Graph>>>,
 {
     fn as_mut(&mut self) -> &mut Self {
         self
     }
 }
 
-impl<P> AsStorage<Arc<Data<P::Graph>>> for Mutation<P>
+impl<P> AsStorage<Arc<Data<P::Graph>, Index<P::Graph>>> for Mutation<P>
 where
     P: Mode,
-    P::Graph: Consistent + From<OwnedCore<Data<P::Graph>>> + Into<OwnedCore<Data<P::Graph>>>,
+    P::Graph: Consistent
+        + From<OwnedCore<Data<P::Graph>, Index<P::Graph>>>
+        + Into<OwnedCore<Data<P::Graph>, Index<P::Graph>>>,
 {
-    fn as_storage(&self) -> &StorageTarget<Arc<Data<P::Graph>>> {
+    fn as_storage(&self) -> &StorageTarget<Arc<Data<P::Graph>, Index<P::Graph>>> {
         self.inner.to_ref_core().unfuse().1
     }
 }
 
-impl<P> AsStorage<Edge<Data<P::Graph>>> for Mutation<P>
+impl<P> AsStorage<Edge<Data<P::Graph>, Index<P::Graph>>> for Mutation<P>
 where
     P: Mode,
-    P::Graph: Consistent + From<OwnedCore<Data<P::Graph>>> + Into<OwnedCore<Data<P::Graph>>>,
+    P::Graph: Consistent
+        + From<OwnedCore<Data<P::Graph>, Index<P::Graph>>>
+        + Into<OwnedCore<Data<P::Graph>, Index<P::Graph>>>,
 {
-    fn as_storage(&self) -> &StorageTarget<Edge<Data<P::Graph>>> {
+    fn as_storage(&self) -> &StorageTarget<Edge<Data<P::Graph>, Index<P::Graph>>> {
         self.inner.to_ref_core().unfuse().2
     }
 }
 
-impl<P> AsStorage<Face<Data<P::Graph>>> for Mutation<P>
+impl<P> AsStorage<Face<Data<P::Graph>, Index<P::Graph>>> for Mutation<P>
 where
     P: Mode,
-    P::Graph: Consistent + From<OwnedCore<Data<P::Graph>>> + Into<OwnedCore<Data<P::Graph>>>,
+    P::Graph: Consistent
+        + From<OwnedCore<Data<P::Graph>, Index<P::Graph>>>
+        + Into<OwnedCore<Data<P::Graph>, Index<P::Graph>>>,
 {
-    fn as_storage(&self) -> &StorageTarget<Face<Data<P::Graph>>> {
+    fn as_storage(&self) -> &StorageTarget<Face<Data<P::Graph>, Index<P::Graph>>> {
         self.inner.to_ref_core().unfuse().3
     }
 }
 
-impl<P> AsStorage<Vertex<Data<P::Graph>>> for Mutation<P>
+impl<P> AsStorage<Vertex<Data<P::Graph>, Index<P::Graph>>> for Mutation<P>
 where
     P: Mode,
-    P::Graph: Consistent + From<OwnedCore<Data<P::Graph>>> + Into<OwnedCore<Data<P::Graph>>>,
+    P::Graph: Consistent
+        + From<OwnedCore<Data<P::Graph>, Index<P::Graph>>>
+        + Into<OwnedCore<Data<P::Graph>, Index<P::Graph>>>,
 {
-    fn as_storage(&self) -> &StorageTarget<Vertex<Data<P::Graph>>> {
+    fn as_storage(&self) -> &StorageTarget<Vertex<Data<P::Graph>, Index<P::Graph>>> {
         self.inner.to_ref_core().unfuse().0
     }
 }
 
 impl<M> Bypass<M> for Mutation<Immediate<M>>
 where
-    M: Consistent + From<OwnedCore<Data<M>>> + Parametric + Into<OwnedCore<Data<M>>>,
+    M: Consistent
+        + From<OwnedCore<Data<M>, Index<M>>>
+        + Parametric
+        + Into<OwnedCore<Data<M>, Index<M>>>,
 {
     fn bypass(self) -> Self::Commit {
         self.inner.bypass().into()
@@ -155,7 +174,9 @@ where
 impl<P> Deref for Mutation<P>
 where
     P: Mode,
-    P::Graph: Consistent + From<OwnedCore<Data<P::Graph>>> + Into<OwnedCore<Data<P::Graph>>>,
+    P::Graph: Consistent
+        + From<OwnedCore<Data<P::Graph>, Index<P::Graph>>>
+        + Into<OwnedCore<Data<P::Graph>, Index<P::Graph>>>,
 {
     type Target = FaceMutation<P>;
 
@@ -167,7 +188,9 @@ where
 impl<P> DerefMut for Mutation<P>
 where
     P: Mode,
-    P::Graph: Consistent + From<OwnedCore<Data<P::Graph>>> + Into<OwnedCore<Data<P::Graph>>>,
+    P::Graph: Consistent
+        + From<OwnedCore<Data<P::Graph>, Index<P::Graph>>>
+        + Into<OwnedCore<Data<P::Graph>, Index<P::Graph>>>,
 {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.inner
@@ -176,7 +199,10 @@ where
 
 impl<M> From<M> for Mutation<Immediate<M>>
 where
-    M: Consistent + From<OwnedCore<Data<M>>> + Parametric + Into<OwnedCore<Data<M>>>,
+    M: Consistent
+        + From<OwnedCore<Data<M>, Index<M>>>
+        + Parametric
+        + Into<OwnedCore<Data<M>, Index<M>>>,
 {
     fn from(graph: M) -> Self {
         Mutation {
@@ -188,14 +214,20 @@ where
 impl<P> Parametric for Mutation<P>
 where
     P: Mode,
-    P::Graph: Consistent + From<OwnedCore<Data<P::Graph>>> + Into<OwnedCore<Data<P::Graph>>>,
+    P::Graph: Consistent
+        + From<OwnedCore<Data<P::Graph>, Index<P::Graph>>>
+        + Into<OwnedCore<Data<P::Graph>, Index<P::Graph>>>,
 {
     type Data = Data<P::Graph>;
+    type Index = Index<P::Graph>;
 }
 
 impl<M> Transact<M> for Mutation<Immediate<M>>
 where
-    M: Consistent + From<OwnedCore<Data<M>>> + Parametric + Into<OwnedCore<Data<M>>>,
+    M: Consistent
+        + From<OwnedCore<Data<M>, Index<M>>>
+        + Parametric
+        + Into<OwnedCore<Data<M>, Index<M>>>,
 {
     type Commit = M;
     type Abort = ();
@@ -209,13 +241,45 @@ where
 }
 
 pub trait Mutable:
-    Consistent + From<OwnedCore<Data<Self>>> + Parametric + Into<OwnedCore<Data<Self>>>
+    Consistent
+    + From<OwnedCore<Data<Self>, Index<Self>>>
+    + Parametric
+    + Into<OwnedCore<Data<Self>, Index<Self>>>
 {
 }
 
-impl<M, G> Mutable for M
+impl<M, G, K> Mutable for M
 where
-    M: Consistent + From<OwnedCore<G>> + Parametric<Data = G> + Into<OwnedCore<G>>,
+    M: Consistent + From<OwnedCore<G, K>> + Parametric<Data = G, Index = K> + Into<OwnedCore<G, K>>,
     G: GraphData,
+    K: KeyIndex,
 {
 }
+
+/// Fails with `GraphError::KeyspaceExhausted` if the graph cannot key the given
+/// numbers of vertices, edges, and faces.
+///
+/// Keys are never reused and mutations that exhaust a keyspace cannot restore
+/// the graph, so views check the keyspace before taking the graph for a
+/// mutation that inserts topology.
+pub fn check_keyspace<M, G, K>(
+    graph: &M,
+    vertices: usize,
+    edges: usize,
+    faces: usize,
+) -> Result<(), GraphError>
+where
+    M: AsStorage<Vertex<G, K>> + AsStorage<Edge<G, K>> + AsStorage<Face<G, K>>,
+    G: GraphData,
+    K: KeyIndex,
+{
+    if graph.as_storage_of::<Vertex<G, K>>().can_insert(vertices)
+        && graph.as_storage_of::<Edge<G, K>>().can_insert(edges)
+        && graph.as_storage_of::<Face<G, K>>().can_insert(faces)
+    {
+        Ok(())
+    }
+    else {
+        Err(GraphError::KeyspaceExhausted)
+    }
+}
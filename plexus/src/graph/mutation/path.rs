@@ -1,9 +1,9 @@
 use smallvec::SmallVec;
 
 use crate::entity::borrow::Reborrow;
-use crate::entity::storage::AsStorage;
+use crate::entity::storage::{AsStorage, KeyIndex};
 use crate::entity::view::Bind;
-use crate::graph::data::{Data, GraphData, Parametric};
+use crate::graph::data::{Data, GraphData, Index, Parametric};
 use crate::graph::edge::Arc;
 use crate::graph::face::{Face, FaceKey};
 use crate::graph::mutation::face::{self, FaceInsertCache};
@@ -11,23 +11,29 @@ use crate::graph::mutation::vertex;
 use crate::graph::mutation::{Consistent, Mode, Mutable, Mutation};
 use crate::graph::path::Path;
 use crate::graph::vertex::{Vertex, VertexKey, VertexView};
-use crate::graph::GraphError;
+use crate::graph::{GraphError, TopologyKind};
 use crate::IteratorExt as _;
 
-pub struct PathExtrudeCache {
+pub struct PathExtrudeCache<K>
+where
+    K: KeyIndex,
+{
     // Avoid allocations for single arc extrusions.
-    sources: SmallVec<[VertexKey; 2]>,
+    sources: SmallVec<[VertexKey<K>; 2]>,
 }
 
-impl PathExtrudeCache {
+impl<K> PathExtrudeCache<K>
+where
+    K: KeyIndex,
+{
     pub fn from_path<B>(path: Path<B>) -> Result<Self, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Arc<Data<B>>>
-            + AsStorage<Face<Data<B>>>
-            + AsStorage<Vertex<Data<B>>>
+        B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+            + AsStorage<Face<Data<B>, Index<B>>>
+            + AsStorage<Vertex<Data<B>, Index<B>>>
             + Consistent
-            + Parametric,
+            + Parametric<Index = K>,
     {
         if path.arcs().any(|arc| !arc.is_boundary_arc()) {
             Err(GraphError::TopologyMalformed)
@@ -42,9 +48,9 @@ impl PathExtrudeCache {
 
 pub fn extrude_contour_with<N, P, F>(
     mut mutation: N,
-    cache: PathExtrudeCache,
+    cache: PathExtrudeCache<Index<P::Graph>>,
     f: F,
-) -> Result<FaceKey, GraphError>
+) -> Result<FaceKey<Index<P::Graph>>, GraphError>
 where
     N: AsMut<Mutation<P>>,
     P: Mode,
@@ -57,10 +63,10 @@ where
         .cloned()
         .rev()
         .map(|source| -> Result<_, GraphError> {
-            let vertex =
-                VertexView::bind(mutation.as_mut(), source).ok_or(GraphError::TopologyNotFound)?;
+            let vertex = VertexView::bind(mutation.as_mut(), source)
+                .ok_or(GraphError::TopologyNotFound(TopologyKind::Vertex))?;
             let data = f(vertex.get());
-            Ok(vertex::insert(mutation.as_mut(), data))
+            vertex::insert(mutation.as_mut(), data)
         })
         .collect::<Result<_, _>>()?;
     let cache =
@@ -1,17 +1,27 @@
 use crate::entity::borrow::Reborrow;
 use crate::entity::storage::prelude::*;
-use crate::entity::storage::{AsStorage, AsStorageMut, Fuse, StorageTarget};
+use crate::entity::storage::{AsStorage, AsStorageMut, Fuse, KeyIndex, StorageTarget};
+use crate::entity::view::ClosedView;
 use crate::graph::core::Core;
-use crate::graph::data::{Data, GraphData, Parametric};
-use crate::graph::edge::ArcKey;
-use crate::graph::mutation::edge::{self, EdgeRemoveCache};
+use crate::graph::data::{Data, GraphData, Index, Parametric};
+use crate::graph::edge::{Arc, ArcKey};
+use crate::graph::face::Face;
+use crate::graph::mutation::edge;
+use crate::graph::mutation::face::{self, FaceRemoveCache};
 use crate::graph::mutation::{Consistent, Immediate, Mode, Mutable, Mutation};
 use crate::graph::vertex::{Vertex, VertexKey, VertexView};
-use crate::graph::GraphError;
+use crate::graph::{GraphError, TopologyKind};
 use crate::transact::{Bypass, Transact};
 
-type ModalCore<P> = Core<Data<<P as Mode>::Graph>, <P as Mode>::VertexStorage, (), (), ()>;
-type RefCore<'a, G> = Core<G, &'a StorageTarget<'a, Vertex<G>>, (), (), ()>;
+type ModalCore<P> = Core<
+    Data<<P as Mode>::Graph>,
+    Index<<P as Mode>::Graph>,
+    <P as Mode>::VertexStorage,
+    (),
+    (),
+    (),
+>;
+type RefCore<'a, G, K> = Core<G, K, &'a StorageTarget<'a, Vertex<G, K>>, (), (), ()>;
 
 pub struct VertexMutation<P>
 where
@@ -24,38 +34,47 @@ impl<P> VertexMutation<P>
 where
     P: Mode,
 {
-    pub fn to_ref_core(&self) -> RefCore<Data<P::Graph>> {
+    pub fn to_ref_core(&self) -> RefCore<Data<P::Graph>, Index<P::Graph>> {
         Core::empty().fuse(self.storage.as_storage())
     }
 
-    pub fn connect_outgoing_arc(&mut self, a: VertexKey, ab: ArcKey) -> Result<(), GraphError> {
+    pub fn connect_outgoing_arc(
+        &mut self,
+        a: VertexKey<Index<P::Graph>>,
+        ab: ArcKey<Index<P::Graph>>,
+    ) -> Result<(), GraphError> {
         self.with_vertex_mut(a, |vertex| vertex.arc = Some(ab))
     }
 
-    // TODO: See `edge::split_with_cache`.
-    #[allow(dead_code)]
-    pub fn disconnect_outgoing_arc(&mut self, a: VertexKey) -> Result<Option<ArcKey>, GraphError> {
+    pub fn disconnect_outgoing_arc(
+        &mut self,
+        a: VertexKey<Index<P::Graph>>,
+    ) -> Result<Option<ArcKey<Index<P::Graph>>>, GraphError> {
         self.with_vertex_mut(a, |vertex| vertex.arc.take())
     }
 
-    fn with_vertex_mut<T, F>(&mut self, a: VertexKey, mut f: F) -> Result<T, GraphError>
+    fn with_vertex_mut<T, F>(
+        &mut self,
+        a: VertexKey<Index<P::Graph>>,
+        mut f: F,
+    ) -> Result<T, GraphError>
     where
-        F: FnMut(&mut Vertex<Data<P::Graph>>) -> T,
+        F: FnMut(&mut Vertex<Data<P::Graph>, Index<P::Graph>>) -> T,
     {
         let vertex = self
             .storage
             .as_storage_mut()
             .get_mut(&a)
-            .ok_or(GraphError::TopologyNotFound)?;
+            .ok_or(GraphError::TopologyNotFound(TopologyKind::Vertex))?;
         Ok(f(vertex))
     }
 }
 
-impl<P> AsStorage<Vertex<Data<P::Graph>>> for VertexMutation<P>
+impl<P> AsStorage<Vertex<Data<P::Graph>, Index<P::Graph>>> for VertexMutation<P>
 where
     P: Mode,
 {
-    fn as_storage(&self) -> &StorageTarget<Vertex<Data<P::Graph>>> {
+    fn as_storage(&self) -> &StorageTarget<Vertex<Data<P::Graph>, Index<P::Graph>>> {
         self.storage.as_storage()
     }
 }
@@ -106,22 +125,74 @@ where
     fn abort(self) -> Self::Abort {}
 }
 
-pub struct VertexRemoveCache {
-    cache: Vec<EdgeRemoveCache>,
+pub struct VertexRemoveCache<K>
+where
+    K: KeyIndex,
+{
+    a: VertexKey<K>,
+    arcs: Vec<ArcRemoveCache<K>>,
+    faces: Vec<FaceRemoveCache<K>>,
 }
 
-impl VertexRemoveCache {
+impl<K> VertexRemoveCache<K>
+where
+    K: KeyIndex,
+{
     pub fn from_vertex<B>(vertex: VertexView<B>) -> Result<Self, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Vertex<Data<B>>> + Consistent + Parametric,
+        B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+            + AsStorage<Face<Data<B>, Index<B>>>
+            + AsStorage<Vertex<Data<B>, Index<B>>>
+            + Consistent
+            + Parametric<Index = K>,
     {
-        let _ = vertex;
-        unimplemented!()
+        let mut faces = Vec::new();
+        let mut keys = Vec::new();
+        for face in vertex.adjacent_faces() {
+            if !keys.contains(&face.key()) {
+                keys.push(face.key());
+                faces.push(FaceRemoveCache::from_face(face)?);
+            }
+        }
+        let arcs = vertex
+            .outgoing_arcs()
+            .map(|ab| {
+                let b = ab.destination_vertex().key();
+                // Once the faces of the vertex have been removed, the arcs
+                // preceding BA and following AB bound the hole left by the
+                // vertex. If these are AB itself, then B is only connected to
+                // A and is removed with it.
+                let xb = ab.opposite_arc().previous_arc().key();
+                let bx = ab.next_arc().key();
+                ArcRemoveCache {
+                    ab: ab.key(),
+                    b,
+                    splice: if xb != ab.key() { Some((xb, bx)) } else { None },
+                }
+            })
+            .collect();
+        Ok(VertexRemoveCache {
+            a: vertex.key(),
+            arcs,
+            faces,
+        })
     }
 }
 
-pub fn insert<N, P>(mut mutation: N, data: <Data<P::Graph> as GraphData>::Vertex) -> VertexKey
+struct ArcRemoveCache<K>
+where
+    K: KeyIndex,
+{
+    ab: ArcKey<K>,
+    b: VertexKey<K>,
+    splice: Option<(ArcKey<K>, ArcKey<K>)>,
+}
+
+pub fn insert<N, P>(
+    mut mutation: N,
+    data: <Data<P::Graph> as GraphData>::Vertex,
+) -> Result<VertexKey<Index<P::Graph>>, GraphError>
 where
     N: AsMut<Mutation<P>>,
     P: Mode,
@@ -132,20 +203,75 @@ where
         .storage
         .as_storage_mut()
         .insert(Vertex::new(data))
+        .map_err(|_| GraphError::KeyspaceExhausted)
 }
 
+#[allow(clippy::type_complexity)]
+pub fn remove_isolated<N, P>(
+    mut mutation: N,
+    a: VertexKey<Index<P::Graph>>,
+) -> Result<Vertex<Data<P::Graph>, Index<P::Graph>>, GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+{
+    let vertex = mutation
+        .as_mut()
+        .storage
+        .as_storage()
+        .get(&a)
+        .ok_or(GraphError::TopologyNotFound(TopologyKind::Vertex))?;
+    if vertex.arc.is_some() {
+        return Err(GraphError::TopologyConflict);
+    }
+    let vertex = mutation
+        .as_mut()
+        .storage
+        .as_storage_mut()
+        .remove(&a)
+        .ok_or(GraphError::TopologyNotFound(TopologyKind::Vertex))?;
+    mutation.as_mut().discard_layers(a);
+    Ok(vertex)
+}
+
+#[allow(clippy::type_complexity)]
 pub fn remove<N, P>(
     mut mutation: N,
-    cache: VertexRemoveCache,
-) -> Result<Vertex<Data<P::Graph>>, GraphError>
+    cache: VertexRemoveCache<Index<P::Graph>>,
+) -> Result<Vertex<Data<P::Graph>, Index<P::Graph>>, GraphError>
 where
     N: AsMut<Mutation<P>>,
     P: Mode,
     P::Graph: Mutable,
 {
-    let VertexRemoveCache { cache } = cache;
-    for cache in cache {
-        edge::remove(mutation.as_mut(), cache)?;
+    let VertexRemoveCache { a, arcs, faces } = cache;
+    for cache in faces {
+        face::remove(mutation.as_mut(), cache)?;
+    }
+    for ArcRemoveCache { ab, b, splice } in arcs {
+        edge::remove_faceless(mutation.as_mut(), ab)?;
+        if let Some((xb, bx)) = splice {
+            // Connect the remaining arcs of B across the removed edge.
+            mutation.as_mut().connect_adjacent_arcs(xb, bx)?;
+            mutation.as_mut().connect_outgoing_arc(b, bx)?;
+        }
+        else {
+            mutation
+                .as_mut()
+                .storage
+                .as_storage_mut()
+                .remove(&b)
+                .ok_or(GraphError::TopologyNotFound(TopologyKind::Vertex))?;
+            mutation.as_mut().discard_layers(b);
+        }
     }
-    unimplemented!()
+    let vertex = mutation
+        .as_mut()
+        .storage
+        .as_storage_mut()
+        .remove(&a)
+        .ok_or(GraphError::TopologyNotFound(TopologyKind::Vertex))?;
+    mutation.as_mut().discard_layers(a);
+    Ok(vertex)
 }
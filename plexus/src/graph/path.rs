@@ -4,16 +4,16 @@ use std::cmp;
 use std::collections::{HashSet, VecDeque};
 
 use crate::entity::borrow::{Reborrow, ReborrowInto};
-use crate::entity::storage::AsStorage;
+use crate::entity::storage::{AsStorage, KeyIndex};
 use crate::entity::view::{Bind, ClosedView, Unbind, View};
 use crate::geometry::Metric;
-use crate::graph::data::{Data, GraphData, Parametric};
+use crate::graph::data::{Data, GraphData, Index, Parametric};
 use crate::graph::edge::{Arc, ArcKey, ArcView, Edge};
 use crate::graph::face::{Face, FaceView, Ring};
 use crate::graph::mutation::path::{self, PathExtrudeCache};
 use crate::graph::mutation::{self, Consistent, Immediate, Mutable};
 use crate::graph::vertex::{Vertex, VertexKey, VertexView};
-use crate::graph::{GraphError, OptionExt as _, ResultExt as _, Selector};
+use crate::graph::{GraphError, OptionExt as _, Selector, TopologyKind};
 use crate::transact::{BypassOrCommit, Mutate};
 use crate::IteratorExt as _;
 
@@ -38,17 +38,24 @@ type Mutation<M> = mutation::Mutation<Immediate<M>>;
 pub struct Path<'k, B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<Data<B>>> + AsStorage<Vertex<Data<B>>> + Consistent + Parametric,
+    B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+        + AsStorage<Vertex<Data<B>, Index<B>>>
+        + Consistent
+        + Parametric,
 {
-    keys: Cow<'k, VecDeque<ArcKey>>,
+    keys: Cow<'k, VecDeque<ArcKey<Index<B>>>>,
     storage: B,
 }
 
-impl<B, M, G> Path<'static, B>
+impl<B, M, G, K> Path<'static, B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     // Paths bind multiple keys to storage and so do not support view APIs.
     // This bespoke `bind` function ensures that the path is not empty and that
@@ -56,13 +63,14 @@ where
     pub(in crate::graph) fn bind<I>(storage: B, keys: I) -> Result<Self, GraphError>
     where
         I: IntoIterator,
-        I::Item: Borrow<VertexKey>,
+        I::Item: Borrow<VertexKey<K>>,
     {
         let mut keys = keys.into_iter().map(|key| *key.borrow());
         let a = keys.next().ok_or(GraphError::TopologyMalformed)?;
         let b = keys.next().ok_or(GraphError::TopologyMalformed)?;
         let ab = (a, b).into();
-        ArcView::bind(storage.reborrow(), ab).ok_or(GraphError::TopologyNotFound)?;
+        ArcView::bind(storage.reborrow(), ab)
+            .ok_or(GraphError::TopologyNotFound(TopologyKind::Arc))?;
         let mut path = Path {
             keys: Cow::Owned([ab].into_iter().collect()),
             storage,
@@ -76,18 +84,22 @@ where
     fn bind_unchecked<I>(storage: B, keys: I) -> Self
     where
         I: IntoIterator,
-        I::Item: Borrow<ArcKey>,
+        I::Item: Borrow<ArcKey<K>>,
     {
         let keys = Cow::Owned(keys.into_iter().map(|key| *key.borrow()).collect());
         Path { keys, storage }
     }
 }
 
-impl<'k, B, M, G> Path<'k, B>
+impl<'k, B, M, G, K> Path<'k, B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn to_ref(&self) -> Path<&M> {
         Path {
@@ -128,8 +140,8 @@ where
     /// or the given vertex does not form an arc with the back of the path.
     pub fn push_back(
         &mut self,
-        destination: impl Into<Selector<VertexKey>>,
-    ) -> Result<ArcKey, GraphError> {
+        destination: impl Into<Selector<VertexKey<K>>>,
+    ) -> Result<ArcKey<K>, GraphError> {
         if self.is_closed() {
             return Err(GraphError::TopologyMalformed);
         }
@@ -145,7 +157,7 @@ where
                     .adjacent_vertices()
                     .keys()
                     .nth(index)
-                    .ok_or(GraphError::TopologyNotFound)?;
+                    .ok_or(GraphError::TopologyNotFound(TopologyKind::Vertex))?;
                 (x, back.key()).into()
             }
         };
@@ -168,7 +180,7 @@ where
     }
 
     /// Pushes the source vertex of the previous arc onto the back of the path.
-    pub fn push_previous_arc(&mut self) -> Result<ArcKey, GraphError> {
+    pub fn push_previous_arc(&mut self) -> Result<ArcKey<K>, GraphError> {
         let key = *self.keys.to_mut().back().expect("empty path");
         let key = ArcView::from(View::bind_unchecked(self.storage.reborrow(), key))
             .into_previous_arc()
@@ -178,7 +190,7 @@ where
     }
 
     /// Pops a vertex from the back of the path.
-    pub fn pop_back(&mut self) -> Option<ArcKey> {
+    pub fn pop_back(&mut self) -> Option<ArcKey<K>> {
         // Empty paths are forbidden.
         if self.keys.len() > 1 {
             self.keys.to_mut().pop_back()
@@ -206,8 +218,8 @@ where
     /// or the given vertex does not form an arc with the front of the path.
     pub fn push_front(
         &mut self,
-        destination: impl Into<Selector<VertexKey>>,
-    ) -> Result<ArcKey, GraphError> {
+        destination: impl Into<Selector<VertexKey<K>>>,
+    ) -> Result<ArcKey<K>, GraphError> {
         if self.is_closed() {
             return Err(GraphError::TopologyMalformed);
         }
@@ -223,7 +235,7 @@ where
                     .adjacent_vertices()
                     .keys()
                     .nth(index)
-                    .ok_or(GraphError::TopologyNotFound)?;
+                    .ok_or(GraphError::TopologyNotFound(TopologyKind::Vertex))?;
                 (front.key(), x).into()
             }
         };
@@ -247,7 +259,7 @@ where
 
     /// Pushes the destination vertex of the next arc onto the front of the
     /// path.
-    pub fn push_next_arc(&mut self) -> Result<ArcKey, GraphError> {
+    pub fn push_next_arc(&mut self) -> Result<ArcKey<K>, GraphError> {
         let key = *self.keys.front().expect("empty path");
         let key = ArcView::from(View::bind_unchecked(self.storage.reborrow(), key))
             .into_next_arc()
@@ -257,7 +269,7 @@ where
     }
 
     /// Pops a vertex from the front of the path.
-    pub fn pop_front(&mut self) -> Option<ArcKey> {
+    pub fn pop_front(&mut self) -> Option<ArcKey<K>> {
         // Empty paths are forbidden.
         if self.keys.len() > 1 {
             self.keys.to_mut().pop_front()
@@ -270,19 +282,19 @@ where
     /// Gets the vertex at the back of the path.
     pub fn back(&self) -> VertexView<&M> {
         let (key, _) = self.terminals();
-        View::<_, Vertex<_>>::bind_unchecked(self.storage.reborrow(), key).into()
+        View::<_, Vertex<_, _>>::bind_unchecked(self.storage.reborrow(), key).into()
     }
 
     /// Gets the vertex at the front of the path.
     pub fn front(&self) -> VertexView<&M> {
         let (_, key) = self.terminals();
-        View::<_, Vertex<_>>::bind_unchecked(self.storage.reborrow(), key).into()
+        View::<_, Vertex<_, _>>::bind_unchecked(self.storage.reborrow(), key).into()
     }
 
     pub fn shortest_metric_with<Q, F>(
         &self,
-        from: impl Into<Selector<VertexKey>>,
-        to: impl Into<Selector<VertexKey>>,
+        from: impl Into<Selector<VertexKey<K>>>,
+        to: impl Into<Selector<VertexKey<K>>>,
         f: F,
     ) -> Result<Q, GraphError>
     where
@@ -314,7 +326,7 @@ where
         a == b
     }
 
-    fn terminals(&self) -> (VertexKey, VertexKey) {
+    fn terminals(&self) -> (VertexKey<K>, VertexKey<K>) {
         let (a, _) = self.keys.back().cloned().expect("empty path").into();
         let (_, b) = self.keys.front().cloned().expect("empty path").into();
         (a, b)
@@ -322,9 +334,9 @@ where
 
     fn shortest_subpath_terminals(
         &self,
-        from: impl Into<Selector<VertexKey>>,
-        to: impl Into<Selector<VertexKey>>,
-    ) -> Result<(VertexKey, VertexKey), GraphError> {
+        from: impl Into<Selector<VertexKey<K>>>,
+        to: impl Into<Selector<VertexKey<K>>>,
+    ) -> Result<(VertexKey<K>, VertexKey<K>), GraphError> {
         let index_key = |selector| {
             match selector {
                 Selector::ByKey(key) => self
@@ -336,7 +348,7 @@ where
                     .nth(index)
                     .map(|vertex| (index, vertex.key())),
             }
-            .ok_or(GraphError::TopologyNotFound)
+            .ok_or(GraphError::TopologyNotFound(TopologyKind::Vertex))
         };
         let (i, from) = index_key(from.into())?;
         let (j, to) = index_key(to.into())?;
@@ -358,11 +370,15 @@ where
     }
 }
 
-impl<'k, 'a, B, M, G> Path<'k, B>
+impl<'k, 'a, B, M, G, K> Path<'k, B>
 where
     B: ReborrowInto<'a, Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Converts a mutable view into an immutable view.
     pub fn into_ref(self) -> Path<'k, &'a M> {
@@ -396,7 +412,7 @@ where
     /// [`MeshGraph::split_at_path`]: crate::graph::MeshGraph::split_at_path
     pub fn split(
         self,
-        at: impl Into<Selector<VertexKey>>,
+        at: impl Into<Selector<VertexKey<K>>>,
     ) -> Result<(Path<'static, &'a M>, Path<'static, &'a M>), GraphError> {
         let index = at.into().index_or_else(|key| {
             self.vertices()
@@ -404,7 +420,7 @@ where
                 .enumerate()
                 .find(|(_, a)| *a == key)
                 .map(|(n, _)| n)
-                .ok_or(GraphError::TopologyNotFound)
+                .ok_or(GraphError::TopologyNotFound(TopologyKind::Vertex))
         })?;
         if index == 0 || index >= self.keys.len() {
             return Err(GraphError::TopologyMalformed);
@@ -425,11 +441,15 @@ where
     }
 }
 
-impl<'k, B, G> Path<'k, B>
+impl<'k, B, G, K> Path<'k, B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    B::Target: AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Gets an iterator over the vertices in the path.
     pub fn vertices(&self) -> impl Clone + Iterator<Item = VertexView<&B::Target>> {
@@ -455,15 +475,16 @@ where
     }
 }
 
-impl<'k, 'a, M, G> Path<'k, &'a mut M>
+impl<'k, 'a, M, G, K> Path<'k, &'a mut M>
 where
-    M: AsStorage<Arc<G>>
-        + AsStorage<Edge<G>>
-        + AsStorage<Face<G>>
-        + AsStorage<Vertex<G>>
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Edge<G, K>>
+        + AsStorage<Face<G, K>>
+        + AsStorage<Vertex<G, K>>
         + Default
-        + Mutable<Data = G>,
+        + Mutable<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Extrudes the contour of a boundary path.
     ///
@@ -479,18 +500,18 @@ where
     ///
     /// # Errors
     ///
-    /// Returns an error if the path is not a boundary path.
+    /// Returns an error if the path is not a boundary path or if the keyspace of
+    /// the graph is exhausted.
     pub fn extrude_contour_with<F>(self, f: F) -> Result<FaceView<&'a mut M>, GraphError>
     where
         F: Fn(&G::Vertex) -> G::Vertex,
     {
         let cache = PathExtrudeCache::from_path(self.to_ref())?;
         let Path { storage, .. } = self;
-        Ok(Mutation::take(storage)
+        Mutation::take(storage)
             .bypass_or_commit_with(|mutation| path::extrude_contour_with(mutation, cache, f))
             .map(|(storage, face)| Bind::bind(storage, face).expect_consistent())
             .map_err(|(_, error)| error)
-            .expect_consistent())
     }
 
     /// Extrudes the surface of a closed path.
@@ -503,11 +524,15 @@ where
     }
 }
 
-impl<B, M, G> From<Ring<B>> for Path<'static, B>
+impl<B, M, G, K> From<Ring<B>> for Path<'static, B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn from(ring: Ring<B>) -> Self {
         let keys = Cow::Owned(ring.arcs().keys().collect());
@@ -516,11 +541,15 @@ where
     }
 }
 
-impl<'k, B, M, G> PartialEq for Path<'k, B>
+impl<'k, B, M, G, K> PartialEq for Path<'k, B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn eq(&self, other: &Self) -> bool {
         let keys = |path: &Self| path.keys.iter().cloned().collect::<HashSet<_>>();
@@ -528,13 +557,14 @@ where
     }
 }
 
-fn truncate<T>(
+fn truncate<T, K>(
     arcs: impl IntoIterator<Item = T>,
-    from: VertexKey,
-    to: VertexKey,
+    from: VertexKey<K>,
+    to: VertexKey<K>,
 ) -> impl Iterator<Item = T>
 where
-    T: Borrow<ArcKey> + Copy,
+    T: Borrow<ArcKey<K>> + Copy,
+    K: KeyIndex,
 {
     arcs.into_iter()
         .map(|arc| (arc, (*arc.borrow()).into()))
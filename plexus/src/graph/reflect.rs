@@ -0,0 +1,503 @@
+use smallvec::SmallVec;
+use std::collections::HashMap;
+use theon::query::Plane;
+use theon::space::{EuclideanSpace, InnerSpace, Scalar};
+use theon::AsPositionMut;
+
+use crate::entity::storage::KeyIndex;
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::face::FaceKey;
+use crate::graph::geometry::VertexPosition;
+use crate::graph::mutation::face::FaceInsertCache;
+use crate::graph::mutation::{self, Immediate};
+use crate::graph::vertex::VertexKey;
+use crate::graph::{GraphError, MeshGraph, ResultExt as _};
+use crate::transact::Transact;
+use crate::DynamicArity;
+
+type Mutation<M> = mutation::Mutation<Immediate<M>>;
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Creates a mirror copy of the graph reflected across a plane.
+    ///
+    /// The positions of vertices are reflected across the plane and the
+    /// winding of faces is reversed so that the orientation of faces (and
+    /// therefore their normals) is preserved. Vertex and face data are copied;
+    /// arc and edge data are not. Edges that are not adjacent to any face are
+    /// not copied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::{Point3, Vector3};
+    /// use plexus::geometry::{Plane, Unit};
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2)],
+    ///     vec![(1.0, 0.0, 0.0), (2.0, 0.0, 0.0), (1.0, 1.0, 0.0)],
+    /// )
+    /// .unwrap();
+    /// let mirror = graph.reflect(Plane {
+    ///     origin: Point3::origin(),
+    ///     normal: Unit::try_from_inner(Vector3::x()).unwrap(),
+    /// });
+    /// ```
+    pub fn reflect(&self, plane: Plane<VertexPosition<G>>) -> Self
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let normal = *plane.normal.get();
+        let reflect = |position: &VertexPosition<G>| {
+            let distance = (*position - plane.origin).dot(normal);
+            *position - (normal * (distance + distance))
+        };
+        let mut mutation = Mutation::from(MeshGraph::new());
+        // The copy has no more vertices than the graph and is consistent
+        // exactly as the graph is, so this should never fail.
+        self.insert_copy_with(&mut mutation, true, reflect)
+            .expect_consistent();
+        mutation
+            .commit()
+            .map_err(|(_, error)| error)
+            .expect_consistent()
+    }
+
+    /// Merges the graph with its reflection across a plane.
+    ///
+    /// The graph is joined with a mirror copy of itself (see
+    /// [`MeshGraph::reflect`]). Vertices within the given distance of the
+    /// plane are snapped onto the plane and are shared by the graph and its
+    /// reflection, welding the two together along the plane. This is useful
+    /// for constructing symmetric meshes by modeling only one half. See
+    /// [`MeshGraph::mirror_in_place`].
+    ///
+    /// Vertex and face data is retained. Arc and edge data is reset to its
+    /// default value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reflection cannot be joined with the graph,
+    /// such as when an edge that lies in the plane is already adjacent to two
+    /// faces. The error is wrapped with the key of the face that could not be
+    /// joined (see [`GraphError::AtKey`]).
+    ///
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    /// [`MeshGraph::mirror_in_place`]: crate::graph::MeshGraph::mirror_in_place
+    /// [`MeshGraph::reflect`]: crate::graph::MeshGraph::reflect
+    pub fn merge_with_reflection<T>(
+        self,
+        plane: Plane<VertexPosition<G>>,
+        epsilon: T,
+    ) -> Result<Self, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let mut graph = self;
+        graph.join_reflection(plane, Some(epsilon.into()))?;
+        Ok(graph)
+    }
+
+    /// Appends a reflection of the graph across a plane to the graph.
+    ///
+    /// The graph is joined with a mirror copy of itself (see
+    /// [`MeshGraph::reflect`]). If a distance is given, then vertices within
+    /// that distance of the plane are snapped onto the plane and are shared by
+    /// the graph and its reflection rather than duplicated, welding the two
+    /// together along the plane as with [`MeshGraph::weld_vertices`].
+    /// Otherwise, the reflection is disjoint from the graph.
+    ///
+    /// Vertex and face data is retained. Arc and edge data is reset to its
+    /// default value and edges that are not part of any face are discarded.
+    /// Layers are migrated to the original topology, while the reflection has
+    /// default values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reflection cannot be joined with the graph,
+    /// such as when an edge that lies in the plane is already adjacent to two
+    /// faces. The error is wrapped with the key of the face that could not be
+    /// joined (see [`GraphError::AtKey`]). In this case, the graph is left
+    /// unmodified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::{Point3, Vector3};
+    /// use plexus::geometry::{Plane, Unit};
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Tetragon;
+    ///
+    /// let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+    ///     vec![Tetragon::new(0usize, 1, 2, 3)],
+    ///     vec![
+    ///         (0.0, 0.0, 0.0),
+    ///         (1.0, 0.0, 0.0),
+    ///         (1.0, 1.0, 0.0),
+    ///         (0.0, 1.0, 0.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// // Vertices on the plane are shared by the graph and its reflection.
+    /// graph
+    ///     .mirror_in_place(
+    ///         Plane {
+    ///             origin: Point3::origin(),
+    ///             normal: Unit::try_from_inner(Vector3::x()).unwrap(),
+    ///         },
+    ///         Some(1e-6),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert_eq!(2, graph.face_count());
+    /// assert_eq!(6, graph.vertex_count());
+    /// ```
+    ///
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    /// [`MeshGraph::reflect`]: crate::graph::MeshGraph::reflect
+    /// [`MeshGraph::weld_vertices`]: crate::graph::MeshGraph::weld_vertices
+    pub fn mirror_in_place<T>(
+        &mut self,
+        plane: Plane<VertexPosition<G>>,
+        epsilon: Option<T>,
+    ) -> Result<(), GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.join_reflection(plane, epsilon.map(Into::into))
+    }
+
+    // Joins the graph with its reflection across a plane. If a distance is
+    // given, then vertices within that distance of the plane are snapped onto
+    // the plane and shared rather than reflected. Layers are migrated to the
+    // original topology and the reflection has default values.
+    fn join_reflection(
+        &mut self,
+        plane: Plane<VertexPosition<G>>,
+        epsilon: Option<Scalar<VertexPosition<G>>>,
+    ) -> Result<(), GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let normal = *plane.normal.get();
+        let distance = |position: &VertexPosition<G>| (*position - plane.origin).dot(normal);
+        let is_snapped = |distance: Scalar<VertexPosition<G>>| {
+            epsilon.map_or(false, |epsilon| distance <= epsilon && distance >= -epsilon)
+        };
+        // Vertices near the plane are snapped onto it in both the graph and its
+        // reflection, so that they coincide and can be welded.
+        let snap = |position: &VertexPosition<G>| {
+            let distance = distance(position);
+            if is_snapped(distance) {
+                *position - (normal * distance)
+            }
+            else {
+                *position
+            }
+        };
+        let reflect = |position: &VertexPosition<G>| {
+            let distance = distance(position);
+            if is_snapped(distance) {
+                *position - (normal * distance)
+            }
+            else {
+                *position - (normal * (distance + distance))
+            }
+        };
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let (vertices, faces) = self.insert_copy_with(&mut mutation, false, snap)?;
+        let (reflections, reflected) = self.insert_copy_with(&mut mutation, true, reflect)?;
+        // The copies are disjoint, so this should never fail.
+        let graph = mutation
+            .commit()
+            .map_err(|(_, error)| error)
+            .expect_consistent();
+        let welds = self
+            .vertices()
+            .filter(|vertex| is_snapped(distance(vertex.position())))
+            .filter_map(|vertex| {
+                Some((
+                    *reflections.get(&vertex.key())?,
+                    *vertices.get(&vertex.key())?,
+                ))
+            })
+            .collect::<HashMap<_, _>>();
+        let sources = faces
+            .iter()
+            .chain(reflected.iter())
+            .map(|(face, copy)| (*copy, *face))
+            .collect::<HashMap<_, _>>();
+        let (graph, welded, rebuilt) = graph
+            .rebuild_welded(&welds)
+            .map_err(|(face, error)| error.at_key(sources[&face]))?;
+        let vertices = vertices
+            .into_iter()
+            .filter_map(|(vertex, copy)| Some((vertex, *welded.get(&copy)?)))
+            .collect();
+        let faces = faces
+            .into_iter()
+            .filter_map(|(face, copy)| Some((face, *rebuilt.get(&copy)?)))
+            .collect();
+        self.replace_with_rebuild(graph, &vertices, &faces);
+        Ok(())
+    }
+
+    // Inserts a copy of the faces of the graph and their vertices into a
+    // mutation, mapping the positions of vertices. The winding of faces is
+    // reversed if `reverse` is `true`. Returns the mappings from vertices and
+    // faces to their copies.
+    #[allow(clippy::type_complexity)]
+    fn insert_copy_with<F>(
+        &self,
+        mutation: &mut Mutation<Self>,
+        reverse: bool,
+        f: F,
+    ) -> Result<
+        (
+            HashMap<VertexKey<K>, VertexKey<K>>,
+            HashMap<FaceKey<K>, FaceKey<K>>,
+        ),
+        GraphError,
+    >
+    where
+        F: Fn(&VertexPosition<G>) -> VertexPosition<G>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let mut vertices = HashMap::with_capacity(self.vertex_count());
+        let mut faces = HashMap::with_capacity(self.face_count());
+        for face in self.faces() {
+            let mut perimeter = SmallVec::<[_; 4]>::with_capacity(face.arity());
+            for vertex in face.adjacent_vertices() {
+                let key = match vertices.get(&vertex.key()) {
+                    Some(key) => *key,
+                    None => {
+                        let data = vertex.get().clone().map_position(&f);
+                        let key = mutation::vertex::insert(&mut *mutation, data)?;
+                        vertices.insert(vertex.key(), key);
+                        key
+                    }
+                };
+                perimeter.push(key);
+            }
+            if reverse {
+                perimeter.reverse();
+            }
+            // The copied faces are disjoint from any other topology in the
+            // mutation exactly as they are in the graph, so this should never
+            // fail.
+            let cache = FaceInsertCache::from_storage(&*mutation, &perimeter).expect_consistent();
+            let data = face.get().clone();
+            let key =
+                mutation::face::insert_with(&mut *mutation, cache, || (Default::default(), data))
+                    .expect_consistent();
+            faces.insert(face.key(), key);
+        }
+        Ok((vertices, faces))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Point3, Vector3};
+
+    use crate::geometry::{Plane, Unit};
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::NGon;
+
+    #[test]
+    fn reflect() {
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![NGon([0u32, 1, 2])],
+            vec![(1.0, 0.0, 0.0), (2.0, 0.0, 0.0), (1.0, 1.0, 0.0)],
+        )
+        .unwrap();
+        let plane = Plane {
+            origin: Point3::origin(),
+            normal: Unit::try_from_inner(Vector3::x()).unwrap(),
+        };
+
+        let mirror = graph.reflect(plane);
+        assert_eq!(3, mirror.vertex_count());
+        assert_eq!(1, mirror.face_count());
+        for vertex in mirror.vertices() {
+            assert!(vertex.position().x < 0.0);
+        }
+        // The orientation of the face is preserved.
+        assert_eq!(
+            graph.faces().nth(0).unwrap().normal().unwrap(),
+            mirror.faces().nth(0).unwrap().normal().unwrap(),
+        );
+    }
+
+    #[test]
+    fn merge_with_reflection() {
+        // Construct a quadrilateral with an edge that lies (approximately) in
+        // the plane of reflection.
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![NGon([0u32, 1, 2, 3])],
+            vec![
+                (1e-9, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (-1e-9, 1.0, 0.0),
+            ],
+        )
+        .unwrap();
+        let plane = Plane {
+            origin: Point3::origin(),
+            normal: Unit::try_from_inner(Vector3::x()).unwrap(),
+        };
+
+        let graph = graph.merge_with_reflection(plane, 1e-6).unwrap();
+        assert_eq!(6, graph.vertex_count());
+        assert_eq!(7, graph.edge_count());
+        assert_eq!(2, graph.face_count());
+        // The edge in the plane is shared by both faces.
+        assert_eq!(
+            1,
+            graph
+                .edges()
+                .filter(|edge| !edge.is_boundary_edge())
+                .count()
+        );
+    }
+
+    #[test]
+    fn mirror_in_place() {
+        // Construct a closed unit cube that lies entirely on one side of the
+        // plane of reflection and has a face in the plane `x = 0.5`.
+        let cube = || {
+            MeshGraph::<Point3<f64>>::from_raw_buffers(
+                vec![
+                    NGon([0u32, 1, 3, 2]),
+                    NGon([4, 6, 7, 5]),
+                    NGon([2, 3, 7, 6]),
+                    NGon([0, 4, 5, 1]),
+                    NGon([1, 5, 7, 3]),
+                    NGon([0, 2, 6, 4]),
+                ],
+                vec![
+                    (0.5, -0.5, -0.5),
+                    (0.5, -0.5, 0.5),
+                    (0.5, 0.5, -0.5),
+                    (0.5, 0.5, 0.5),
+                    (1.5, -0.5, -0.5),
+                    (1.5, -0.5, 0.5),
+                    (1.5, 0.5, -0.5),
+                    (1.5, 0.5, 0.5),
+                ],
+            )
+            .unwrap()
+        };
+        let plane = |x: f64| Plane {
+            origin: Point3::new(x, 0.0, 0.0),
+            normal: Unit::try_from_inner(Vector3::x()).unwrap(),
+        };
+        let assert_consistent = |graph: &MeshGraph<Point3<f64>>| {
+            for arc in graph.arcs() {
+                assert!(!arc.is_boundary_arc());
+                assert_eq!(arc.key(), arc.next_arc().previous_arc().key());
+                assert_eq!(arc.key(), arc.opposite_arc().opposite_arc().key());
+            }
+            assert!(graph.vertices().all(|vertex| vertex.valence() == 3));
+            assert!(graph.faces().all(|face| face.arity() == 4));
+        };
+
+        let mut graph = cube();
+        assert!((graph.volume().unwrap() - 1.0).abs() < 1e-9);
+
+        // No vertices lie in the plane, so the reflection is a disjoint cube.
+        graph.mirror_in_place(plane(0.0), Some(1e-6)).unwrap();
+        assert_eq!(16, graph.vertex_count());
+        assert_eq!(24, graph.edge_count());
+        assert_eq!(12, graph.face_count());
+        assert!((graph.volume().unwrap() - 2.0).abs() < 1e-9);
+        assert_consistent(&graph);
+        for vertex in graph.vertices() {
+            assert!((vertex.position().x.abs() - 1.0).abs() <= 0.5 + 1e-9);
+        }
+
+        // Refuse to join the cube with a reflection across one of its faces,
+        // because the edges of that face are already adjacent to two faces.
+        // The graph is left unmodified.
+        let mut graph = cube();
+        assert!(graph.mirror_in_place(plane(0.5), Some(1e-6)).is_err());
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(6, graph.face_count());
+        assert!((graph.volume().unwrap() - 1.0).abs() < 1e-9);
+        assert_consistent(&graph);
+
+        // Without welding, the reflection is disjoint even though it touches
+        // the cube.
+        let mut graph = cube();
+        graph.mirror_in_place(plane(0.5), None::<f64>).unwrap();
+        assert_eq!(16, graph.vertex_count());
+        assert_eq!(12, graph.face_count());
+        assert!((graph.volume().unwrap() - 2.0).abs() < 1e-9);
+        assert_consistent(&graph);
+    }
+
+    #[test]
+    fn mirror_in_place_closed() {
+        // Construct an open pyramid with a triangular rim that lies
+        // (approximately) in the plane of reflection.
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![NGon([0u32, 1, 3]), NGon([1, 2, 3]), NGon([2, 0, 3])],
+            vec![
+                (0.0, 0.0, 1e-9),
+                (1.0, 0.0, -1e-9),
+                (0.0, 1.0, 0.0),
+                (0.2, 0.2, 1.0),
+            ],
+        )
+        .unwrap();
+        assert_eq!(3, graph.arcs().filter(|arc| arc.is_boundary_arc()).count());
+
+        // Welding the pyramid to its reflection closes the rim, forming a
+        // closed bipyramid with six faces.
+        graph
+            .mirror_in_place(
+                Plane {
+                    origin: Point3::origin(),
+                    normal: Unit::try_from_inner(Vector3::z()).unwrap(),
+                },
+                Some(1e-6),
+            )
+            .unwrap();
+        assert_eq!(5, graph.vertex_count());
+        assert_eq!(9, graph.edge_count());
+        assert_eq!(6, graph.face_count());
+        for arc in graph.arcs() {
+            assert!(!arc.is_boundary_arc());
+            assert_eq!(arc.key(), arc.next_arc().previous_arc().key());
+            assert_eq!(arc.key(), arc.opposite_arc().opposite_arc().key());
+        }
+        assert!(graph.faces().all(|face| face.adjacent_arcs().count() == 3));
+        assert!(graph
+            .vertices()
+            .all(|vertex| vertex.position().z.abs() <= 1.0));
+        assert!((graph.volume().unwrap() - (1.0 / 3.0)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,418 @@
+use num::{NumCast, Zero};
+use smallvec::SmallVec;
+use std::collections::HashSet;
+use theon::ops::Cross;
+use theon::space::{Basis, EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use theon::AsPosition;
+use typenum::U3;
+
+use crate::entity::storage::{Key, KeyIndex};
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::face::FaceKey;
+use crate::graph::geometry::VertexPosition;
+use crate::graph::{GraphError, MeshGraph, OptionExt as _};
+use crate::IteratorExt as _;
+
+/// Healing passes applied by [`MeshGraph::repair`].
+///
+/// Passes are disabled by default.
+///
+/// [`MeshGraph::repair`]: crate::graph::MeshGraph::repair
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RepairOptions {
+    /// Welds vertices within the given distance of each other. See
+    /// [`MeshGraph::weld_vertices`].
+    ///
+    /// [`MeshGraph::weld_vertices`]: crate::graph::MeshGraph::weld_vertices
+    pub weld_vertices: Option<f64>,
+    /// Removes faces with the same vertices as another face, regardless of
+    /// winding. The first such face is kept.
+    pub remove_duplicate_faces: bool,
+    /// Removes faces with an area less than the given epsilon or coincident
+    /// vertices. See [`MeshGraph::remove_degenerate_faces`].
+    ///
+    /// [`MeshGraph::remove_degenerate_faces`]: crate::graph::MeshGraph::remove_degenerate_faces
+    pub remove_degenerate_faces: Option<f64>,
+    /// Inserts a face into each boundary ring.
+    pub fill_holes: bool,
+    /// Flips the winding of a closed graph with negative volume, such that
+    /// faces are wound counter-clockwise when viewed from outside of the
+    /// graph. See [`MeshGraph::volume`].
+    ///
+    /// [`MeshGraph::volume`]: crate::graph::MeshGraph::volume
+    pub fix_winding: bool,
+}
+
+/// Changes made by [`MeshGraph::repair`].
+///
+/// [`MeshGraph::repair`]: crate::graph::MeshGraph::repair
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RepairReport {
+    /// The number of vertices merged into other vertices.
+    pub welded_vertices: usize,
+    /// The number of duplicate faces removed.
+    pub duplicate_faces: usize,
+    /// The number of degenerate faces removed.
+    pub degenerate_faces: usize,
+    /// The number of holes filled.
+    pub filled_holes: usize,
+    /// Whether or not the winding of the graph was flipped.
+    pub flipped_winding: bool,
+}
+
+impl RepairReport {
+    /// Returns `true` if the graph was not modified.
+    pub fn is_unchanged(&self) -> bool {
+        *self == RepairReport::default()
+    }
+}
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Removes degenerate faces from the graph.
+    ///
+    /// A face is degenerate if its area is less than the given epsilon or if
+    /// any two of its vertices have the same position. Such faces, like the
+    /// sliver triangles often found in imported meshes, can break operations
+    /// like computing normals.
+    ///
+    /// Removing a face leaves a boundary in its place. Edges of removed faces
+    /// that are no longer part of any face are also removed, along with any
+    /// vertices that become disjoint, so that no dangling topology remains.
+    ///
+    /// Returns the number of faces removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// // A triangle and an adjacent sliver triangle.
+    /// let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2), Trigon::new(1, 0, 3)],
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (0.5, -1e-9)],
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(1, graph.remove_degenerate_faces(1e-6));
+    /// assert_eq!(1, graph.face_count());
+    /// assert_eq!(3, graph.vertex_count());
+    /// ```
+    pub fn remove_degenerate_faces<T>(&mut self, epsilon: T) -> usize
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let epsilon = epsilon.into();
+        let faces = self
+            .faces()
+            .filter(|face| {
+                let positions = face
+                    .adjacent_vertices()
+                    .map(|vertex| *vertex.position())
+                    .collect::<SmallVec<[_; 4]>>();
+                face.area() < epsilon
+                    || positions.iter().enumerate().any(|(index, a)| {
+                        positions[(index + 1)..]
+                            .iter()
+                            .any(|b| (*b - *a).square_magnitude() <= Zero::zero())
+                    })
+            })
+            .map(|face| face.key())
+            .collect::<Vec<_>>();
+        self.remove_faces_and_dangling_edges(&faces);
+        faces.len()
+    }
+
+    /// Repairs common defects in the graph.
+    ///
+    /// Healing passes are enabled by the given [`RepairOptions`] and are
+    /// applied in the following order:
+    ///
+    /// 1. Vertices are welded.
+    /// 1. Duplicate faces are removed.
+    /// 1. Degenerate faces are removed.
+    /// 1. Holes are filled by inserting a face into each boundary ring. These
+    ///    faces may be non-convex and can be triangulated afterwards. Rings
+    ///    that cannot bound a face, such as rings that visit a non-manifold
+    ///    vertex more than once, are not filled.
+    /// 1. The winding of the graph is flipped if the graph is closed and its
+    ///    volume is negative.
+    ///
+    /// Returns a [`RepairReport`] describing the changes made to the graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if vertices cannot be welded, in which case the graph
+    /// is left unmodified. See [`MeshGraph::weld_vertices`]. Returns
+    /// [`GraphError::KeyspaceExhausted`] if a hole cannot be filled because
+    /// the keyspace of the graph is exhausted, in which case any preceding
+    /// passes and filled holes remain applied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::{MeshGraph, RepairOptions};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// // Construct a cube with a missing face.
+    /// let mut graph: MeshGraph<Point3<f64>> = Cube::new()
+    ///     .polygons::<Position<Point3<R64>>>()
+    ///     .skip(1)
+    ///     .collect();
+    /// let report = graph
+    ///     .repair(RepairOptions {
+    ///         fill_holes: true,
+    ///         fix_winding: true,
+    ///         ..Default::default()
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, report.filled_holes);
+    /// assert_eq!(6, graph.face_count());
+    /// ```
+    ///
+    /// [`GraphError::KeyspaceExhausted`]: crate::graph::GraphError::KeyspaceExhausted
+    /// [`MeshGraph::weld_vertices`]: crate::graph::MeshGraph::weld_vertices
+    /// [`RepairOptions`]: crate::graph::RepairOptions
+    /// [`RepairReport`]: crate::graph::RepairReport
+    pub fn repair(&mut self, options: RepairOptions) -> Result<RepairReport, GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Basis + Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        let scalar = |epsilon: f64| {
+            <Scalar<VertexPosition<G>> as NumCast>::from(epsilon).ok_or(GraphError::Geometry)
+        };
+        let mut report = RepairReport::default();
+        if let Some(epsilon) = options.weld_vertices {
+            report.welded_vertices = self.weld_vertices(scalar(epsilon)?)?;
+        }
+        if options.remove_duplicate_faces {
+            let mut perimeters = HashSet::new();
+            let faces = self
+                .faces()
+                .filter(|face| {
+                    let mut perimeter = face.adjacent_vertices().keys().collect::<Vec<_>>();
+                    perimeter.sort_unstable_by_key(|key| key.into_inner());
+                    !perimeters.insert(perimeter)
+                })
+                .map(|face| face.key())
+                .collect::<Vec<_>>();
+            self.remove_faces_and_dangling_edges(&faces);
+            report.duplicate_faces = faces.len();
+        }
+        if let Some(epsilon) = options.remove_degenerate_faces {
+            report.degenerate_faces = self.remove_degenerate_faces(scalar(epsilon)?);
+        }
+        if options.fill_holes {
+            let mut arcs = HashSet::new();
+            let mut rings = Vec::new();
+            for arc in self.arcs().filter(|arc| arc.is_boundary_arc()) {
+                if arcs.insert(arc.key()) {
+                    arcs.extend(arc.ring().arcs().keys());
+                    rings.push(arc.key());
+                }
+            }
+            for key in rings.iter() {
+                let perimeter = self
+                    .arc(*key)
+                    .expect_consistent()
+                    .into_ring()
+                    .vertices()
+                    .keys()
+                    .collect::<Vec<_>>();
+                // Rings that cannot bound a face, such as rings that visit a
+                // non-manifold vertex more than once, are not filled.
+                match self.insert_faces(Some((perimeter, Default::default()))) {
+                    Ok(_) => {
+                        report.filled_holes += 1;
+                    }
+                    Err(error) if *error.without_key() == GraphError::KeyspaceExhausted => {
+                        return Err(GraphError::KeyspaceExhausted);
+                    }
+                    Err(_) => {}
+                }
+            }
+        }
+        if options.fix_winding {
+            if let Ok(volume) = self.volume() {
+                if volume < Zero::zero() {
+                    self.flip_winding();
+                    report.flipped_winding = true;
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    // Removes the given faces and any edges that no longer bound a face. Any
+    // vertices that become disjoint are also removed.
+    pub(in crate::graph) fn remove_faces_and_dangling_edges(&mut self, faces: &[FaceKey<K>]) {
+        let arcs = faces
+            .iter()
+            .flat_map(|key| {
+                self.face(*key)
+                    .expect_consistent()
+                    .adjacent_arcs()
+                    .keys()
+                    .collect::<SmallVec<[_; 4]>>()
+            })
+            .collect::<Vec<_>>();
+        for key in faces.iter() {
+            self.face_mut(*key).expect_consistent().remove();
+        }
+        for ab in arcs {
+            if let Some(arc) = self.arc_mut(ab) {
+                if arc.is_boundary_arc() && arc.opposite_arc().is_boundary_arc() {
+                    arc.remove();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::graph::{MeshGraph, RepairOptions, RepairReport};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::{NGon, UnboundedPolygon};
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn remove_degenerate_faces() {
+        // A quadrilateral with a sliver triangle along its top and another
+        // quadrilateral with coincident vertices along its bottom.
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![
+                UnboundedPolygon::from(NGon([0usize, 1, 2, 3])),
+                UnboundedPolygon::from(NGon([3, 2, 4])),
+                UnboundedPolygon::from(NGon([1, 0, 5, 6])),
+            ],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (0.0, 1.0, 0.0),
+                (0.5, 1.0 + 1e-9, 0.0),
+                (0.0, -1.0, 0.0),
+                (0.0, -1.0, 0.0),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(2, graph.remove_degenerate_faces(1e-6));
+        assert_eq!(1, graph.face_count());
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(4, graph.edge_count());
+        assert!(graph
+            .arcs()
+            .all(|arc| { arc.is_boundary_arc() != arc.opposite_arc().is_boundary_arc() }));
+
+        assert_eq!(0, graph.remove_degenerate_faces(1e-6));
+    }
+
+    #[test]
+    fn repair() {
+        // A triangle and a coincident triangle with opposite winding.
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2]), NGon([3, 5, 4])],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (0.0, 1.0, 0.0),
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (0.0, 1.0, 0.0),
+            ],
+        )
+        .unwrap();
+        let report = graph
+            .repair(RepairOptions {
+                weld_vertices: Some(1e-6),
+                remove_duplicate_faces: true,
+                remove_degenerate_faces: Some(1e-9),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(
+            RepairReport {
+                welded_vertices: 3,
+                duplicate_faces: 1,
+                ..Default::default()
+            },
+            report
+        );
+        assert_eq!(3, graph.vertex_count());
+        assert_eq!(3, graph.edge_count());
+        assert_eq!(1, graph.face_count());
+
+        // A cube with a missing face and inverted winding.
+        let mut graph: MeshGraph<Point3<f64>> =
+            Cube::new().polygons::<Position<E3>>().skip(1).collect();
+        graph.flip_winding();
+        let options = RepairOptions {
+            fill_holes: true,
+            fix_winding: true,
+            ..Default::default()
+        };
+        let report = graph.repair(options).unwrap();
+        assert_eq!(1, report.filled_holes);
+        assert_eq!(6, graph.face_count());
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+        assert!(graph.volume().unwrap() > 0.0);
+        assert!(graph.repair(options).unwrap().is_unchanged());
+    }
+
+    #[test]
+    fn fill_holes_about_non_manifold_vertex() {
+        // A "bowtie" of two triangles that share only a vertex. Depending on
+        // how its boundary is linked, the boundary about the shared vertex may
+        // form a single ring that cannot bound a face.
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2]), NGon([0, 3, 4])],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (-1.0, 0.0, 0.0),
+                (-1.0, -1.0, 0.0),
+            ],
+        )
+        .unwrap();
+        let report = graph
+            .repair(RepairOptions {
+                fill_holes: true,
+                ..Default::default()
+            })
+            .unwrap();
+        // Only inserted faces are counted.
+        assert_eq!(graph.face_count() - 2, report.filled_holes);
+    }
+}
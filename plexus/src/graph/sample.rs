@@ -0,0 +1,367 @@
+#![cfg(feature = "rand")]
+
+use num::{NumCast, One, Zero};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use smallvec::SmallVec;
+use std::collections::HashMap;
+use theon::space::{Basis, EuclideanSpace, InnerSpace, Scalar, Vector};
+use theon::AsPosition;
+
+use crate::entity::storage::KeyIndex;
+use crate::entity::view::ClosedView;
+use crate::geometry::{grid_cell, neighboring_cells};
+use crate::graph::data::GraphData;
+use crate::graph::face::FaceKey;
+use crate::graph::geometry::{self, VertexPosition};
+use crate::graph::MeshGraph;
+use crate::DynamicArity;
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Samples points uniformly over the surface of the graph.
+    ///
+    /// Faces are chosen with probability proportional to their area and points
+    /// are then sampled uniformly within those faces using barycentric
+    /// coordinates. Faces are decomposed into triangles by clipping ears, so
+    /// sampling is uniform for planar faces, including concave faces. See
+    /// [`FaceView::area`].
+    ///
+    /// Returns an empty collection if the graph has no area.
+    ///
+    /// This function requires the `rand` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// # extern crate rand;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let graph: MeshGraph<E3> = UvSphere::new(16, 8)
+    ///     .polygons::<Position<Point3<R64>>>()
+    ///     .collect();
+    /// let points = graph.sample_surface(1024, &mut StdRng::seed_from_u64(0));
+    /// ```
+    ///
+    /// [`FaceView::area`]: crate::graph::FaceView::area
+    pub fn sample_surface<R>(&self, count: usize, rng: &mut R) -> Vec<VertexPosition<G>>
+    where
+        R: Rng,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let one = Scalar::<VertexPosition<G>>::one();
+        let mut area = Scalar::<VertexPosition<G>>::zero();
+        let triangles = self
+            .surface_triangles()
+            .into_iter()
+            .map(|[a, b, c]| {
+                area = area + geometry::triangle_area(a, b, c);
+                // Store the cumulative area for sampling.
+                (area, (a, b, c))
+            })
+            .collect::<Vec<_>>();
+        if triangles.is_empty() {
+            return Vec::new();
+        }
+        let mut uniform =
+            move || <Scalar<VertexPosition<G>> as NumCast>::from(rng.gen::<f64>()).unwrap();
+        (0..count)
+            .map(|_| {
+                let target = uniform() * area;
+                let index = triangles
+                    .partition_point(|(cumulative, _)| *cumulative < target)
+                    .min(triangles.len() - 1);
+                let (_, (a, b, c)) = triangles[index];
+                let (mut u, mut v) = (uniform(), uniform());
+                if u + v > one {
+                    u = one - u;
+                    v = one - v;
+                }
+                a + ((b - a) * u) + ((c - a) * v)
+            })
+            .collect()
+    }
+
+    // Gets the triangles that cover the surface of the graph. Faces are
+    // triangulated by ear clipping and triangles with no area are discarded.
+    pub(crate) fn surface_triangles(&self) -> Vec<[VertexPosition<G>; 3]>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let zero = Scalar::<VertexPosition<G>>::zero();
+        let mut triangles = Vec::with_capacity(self.face_count());
+        for face in self.faces() {
+            let positions = face
+                .adjacent_vertices()
+                .map(|vertex| *vertex.position())
+                .collect::<SmallVec<[_; 4]>>();
+            for [a, b, c] in geometry::polygon_triangles(&positions) {
+                let (a, b, c) = (positions[a], positions[b], positions[c]);
+                if geometry::triangle_area(a, b, c) > zero {
+                    triangles.push([a, b, c]);
+                }
+            }
+        }
+        triangles
+    }
+
+    /// Samples points on the surface of the graph using Poisson disk sampling.
+    ///
+    /// Candidate points are first sampled uniformly over the surface, with a
+    /// number of candidates proportional to its area. Candidates are then
+    /// visited in a random order and accepted only if no previously accepted
+    /// sample lies within the given radius (dart throwing). The result is a
+    /// blue noise distribution of samples in which no two samples are closer
+    /// than the radius. The number of candidates is limited, so very small
+    /// radii relative to the area of the surface yield fewer samples than
+    /// could fit on the surface.
+    ///
+    /// Distances are measured in the ambient space rather than along the
+    /// surface. Because the Euclidean distance between points never exceeds
+    /// their geodesic distance, samples are also separated by at least the
+    /// radius along the surface.
+    ///
+    /// Each sample is given as the key of the face containing it and the
+    /// coordinates of the sample within that face, with one coordinate per
+    /// vertex of the face. See [`FaceView::point_at`]. Faces are decomposed
+    /// into triangles by clipping ears, so sampling is uniform for planar
+    /// faces, including concave faces. Random numbers are drawn from the
+    /// given generator, so the samples are deterministic for a given graph,
+    /// radius, and seeded generator.
+    ///
+    /// Returns an empty collection if the radius is not positive and finite or
+    /// the graph has no area.
+    ///
+    /// This function requires the `rand` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// # extern crate rand;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let graph: MeshGraph<E3> = UvSphere::new(16, 8)
+    ///     .polygons::<Position<Point3<R64>>>()
+    ///     .collect();
+    /// let mut rng = StdRng::seed_from_u64(0);
+    /// for (key, coordinates) in graph.sample_poisson_disk(0.1, &mut rng) {
+    ///     let position = graph.face(key).unwrap().point_at(&coordinates).unwrap();
+    /// }
+    /// ```
+    ///
+    /// [`FaceView::point_at`]: crate::graph::FaceView::point_at
+    pub fn sample_poisson_disk<T, R>(&self, radius: T, rng: &mut R) -> Vec<(FaceKey<K>, Vec<f64>)>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        R: Rng,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: Basis,
+    {
+        // The number of candidates sampled per disk of area `radius²`.
+        const DENSITY: f64 = 8.0;
+        // The maximum number of candidates sampled over the entire surface.
+        const CANDIDATE_LIMIT: f64 = 1048576.0;
+
+        let radius = radius.into();
+        if radius <= Zero::zero() {
+            return Vec::new();
+        }
+        let square = <f64 as NumCast>::from(radius * radius).unwrap_or(f64::NAN);
+        if !square.is_finite() || square <= 0.0 {
+            return Vec::new();
+        }
+        let mut area = 0.0;
+        let mut triangles = Vec::with_capacity(self.face_count());
+        for face in self.faces() {
+            let positions = face
+                .adjacent_vertices()
+                .map(|vertex| *vertex.position())
+                .collect::<SmallVec<[_; 4]>>();
+            for indices in geometry::polygon_triangles(&positions) {
+                let [a, b, c] = indices.map(|index| positions[index]);
+                let weight = <f64 as NumCast>::from(geometry::triangle_area(a, b, c))
+                    .filter(|weight| weight.is_finite() && *weight > 0.0);
+                if let Some(weight) = weight {
+                    area += weight;
+                    triangles.push((face.key(), face.arity(), indices, weight, [a, b, c]));
+                }
+            }
+        }
+        if !area.is_finite() || area <= 0.0 {
+            return Vec::new();
+        }
+        // Distribute the candidates among the triangles by area.
+        let total = (DENSITY * area / square).min(CANDIDATE_LIMIT);
+        let mut candidates = Vec::new();
+        for (key, arity, indices, weight, [a, b, c]) in triangles {
+            let count = (total * weight / area).ceil() as usize;
+            for _ in 0..count {
+                let (mut u, mut v) = (rng.gen::<f64>(), rng.gen::<f64>());
+                if u + v > 1.0 {
+                    u = 1.0 - u;
+                    v = 1.0 - v;
+                }
+                let position = a
+                    + ((b - a) * <Scalar<VertexPosition<G>> as NumCast>::from(u).unwrap())
+                    + ((c - a) * <Scalar<VertexPosition<G>> as NumCast>::from(v).unwrap());
+                let mut coordinates = vec![0.0; arity];
+                for (index, weight) in indices.into_iter().zip([1.0 - u - v, u, v]) {
+                    coordinates[index] = weight;
+                }
+                candidates.push((key, coordinates, position));
+            }
+        }
+        // Visit candidates in a random order.
+        candidates.shuffle(rng);
+        let basis = Vector::<VertexPosition<G>>::canonical_basis()
+            .into_iter()
+            .collect::<SmallVec<[_; 3]>>();
+        let mut cells = HashMap::<_, Vec<VertexPosition<G>>>::new();
+        let mut samples = Vec::new();
+        for (key, coordinates, position) in candidates {
+            let origin = grid_cell(&basis, position, radius);
+            let occupied = neighboring_cells(&origin)
+                .iter()
+                .flat_map(|neighbor| cells.get(neighbor).into_iter().flatten())
+                .any(|other| (*other - position).square_magnitude() < radius * radius);
+            if !occupied {
+                cells.entry(origin).or_default().push(position);
+                samples.push((key, coordinates));
+            }
+        }
+        samples
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point2;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::{BoundedPolygon, NGon};
+
+    #[test]
+    fn sample_surface() {
+        // Construct a graph from a unit square composed of a triangle and a
+        // quadrilateral with different areas.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![
+                BoundedPolygon::from([0u32, 1, 4]),
+                BoundedPolygon::from([1, 2, 3, 4]),
+            ],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.5)],
+        )
+        .unwrap();
+        let points = graph.sample_surface(4096, &mut StdRng::seed_from_u64(0));
+
+        assert_eq!(4096, points.len());
+        for point in points.iter() {
+            assert!(point.x >= 0.0 && point.x <= 1.0);
+            assert!(point.y >= 0.0 && point.y <= 1.0);
+        }
+        // The triangle covers a quarter of the square.
+        let n = points
+            .iter()
+            .filter(|point| point.y < 0.5 * (1.0 - point.x))
+            .count();
+        assert!((n as f64 / 4096.0 - 0.25).abs() < 0.05);
+    }
+
+    #[test]
+    fn sample_poisson_disk() {
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![NGon([0u32, 1, 2]), NGon([0, 2, 3])],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+        )
+        .unwrap();
+        let samples = graph.sample_poisson_disk(0.1, &mut StdRng::seed_from_u64(0));
+        let points = samples
+            .iter()
+            .map(|(key, coordinates)| graph.face(*key).unwrap().point_at(coordinates).unwrap())
+            .collect::<Vec<_>>();
+
+        // Disks with half of the sampling radius centered at each sample are
+        // disjoint and must fit within a slightly enlarged square, so there
+        // can be no more than about 150 samples.
+        assert!(points.len() > 20);
+        assert!(points.len() < 160);
+        for ((_, coordinates), point) in samples.iter().zip(points.iter()) {
+            assert!(coordinates.iter().all(|coordinate| *coordinate >= 0.0));
+            assert!(point.x >= 0.0 && point.x <= 1.0);
+            assert!(point.y >= 0.0 && point.y <= 1.0);
+        }
+        for (i, a) in points.iter().enumerate() {
+            for b in points[(i + 1)..].iter() {
+                assert!((a - b).norm() >= 0.1);
+            }
+        }
+        assert_eq!(
+            samples,
+            graph.sample_poisson_disk(0.1, &mut StdRng::seed_from_u64(0))
+        );
+        assert!(graph
+            .sample_poisson_disk(0.0, &mut StdRng::seed_from_u64(0))
+            .is_empty());
+        assert!(graph
+            .sample_poisson_disk(f64::NAN, &mut StdRng::seed_from_u64(0))
+            .is_empty());
+        assert!(graph
+            .sample_poisson_disk(f64::INFINITY, &mut StdRng::seed_from_u64(0))
+            .is_empty());
+    }
+
+    #[test]
+    fn sample_poisson_disk_polygonal() {
+        // Construct a graph from a concave quadrilateral.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![NGon([0u32, 1, 2, 3])],
+            vec![(0.0, 0.0), (1.0, 0.0), (0.25, 0.25), (0.0, 1.0)],
+        )
+        .unwrap();
+        let samples = graph.sample_poisson_disk(0.05, &mut StdRng::seed_from_u64(0));
+
+        assert!(samples.len() > 10);
+        for (key, coordinates) in samples.iter() {
+            assert_eq!(4, coordinates.len());
+            let point = graph.face(*key).unwrap().point_at(coordinates).unwrap();
+            // Samples lie within the triangles of the concave quadrilateral.
+            assert!(point.x >= 0.0 && point.y >= 0.0);
+            assert!(point.x.min(point.y) <= 0.25 + 1e-9);
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use num::Zero;
+use std::collections::HashMap;
+use std::vec;
+use theon::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
+use theon::AsPosition;
+
+use crate::entity::storage::KeyIndex;
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::face::FaceKey;
+use crate::graph::geometry::{FaceCentroid, FaceNormal, VertexPosition};
+use crate::graph::MeshGraph;
+use crate::IteratorExt as _;
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Gets the centroids of the faces in the graph.
+    ///
+    /// The centroid of each face is computed once (see [`FaceView::centroid`])
+    /// and keyed by its face. This is useful for operations that query the
+    /// centroids of faces many times, such as Catmull-Clark subdivision. Note
+    /// that the map is not updated as the graph changes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+    /// let centroids = graph.face_centroids();
+    /// for face in graph.faces() {
+    ///     let centroid = centroids[&face.key()];
+    /// }
+    /// ```
+    ///
+    /// [`FaceView::centroid`]: crate::graph::FaceView::centroid
+    pub fn face_centroids(&self) -> HashMap<FaceKey<K>, VertexPosition<G>>
+    where
+        G: FaceCentroid,
+        G::Vertex: AsPosition,
+    {
+        self.faces()
+            .map(|face| (face.key(), face.centroid()))
+            .collect()
+    }
+
+    /// Gets the adjacency list of the faces in the graph.
+    ///
+    /// Each face is paired with the faces that share an edge with it. This
+    /// describes the _dual graph_ of the mesh and can be used to run graph
+    /// algorithms (e.g., coloring or partitioning) over faces. Adjacent faces
+    /// are ordered as in [`FaceView::adjacent_faces`] and boundary edges are
+    /// skipped.
+    ///
+    /// [`FaceView::adjacent_faces`]: crate::graph::FaceView::adjacent_faces
+    pub fn face_adjacency(&self) -> Vec<(FaceKey<K>, Vec<FaceKey<K>>)> {
+        self.faces()
+            .map(|face| (face.key(), face.adjacent_faces().keys().collect()))
+            .collect()
+    }
+
+    /// Segments the faces of the graph by clustering their normals.
+    ///
+    /// Normals are clustered into `k` segments by $k$-means with at most the
+    /// given number of iterations. Initial cluster centers are chosen
+    /// deterministically by farthest-point sampling of the normals. Returns the
+    /// segment in `0..k` of each face. Faces with similar orientations share a
+    /// segment, which is useful for assigning materials or processing regions
+    /// of a graph separately. Segments are not necessarily connected and may
+    /// be empty if there are fewer distinct normals than segments.
+    ///
+    /// Faces with degenerate geometry have no normal and are not included in
+    /// the segmentation. If `k` is zero, then no faces are segmented.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+    /// let segments = graph.segment_by_normals(6, 16);
+    /// ```
+    pub fn segment_by_normals(&self, k: usize, iterations: usize) -> HashMap<FaceKey<K>, usize>
+    where
+        G: FaceNormal,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let normals = self
+            .faces()
+            .filter_map(|face| face.normal().ok().map(|normal| (face.key(), normal)))
+            .collect::<Vec<_>>();
+        if k == 0 || normals.is_empty() {
+            return HashMap::new();
+        }
+        // Gets the index of and distance to the nearest center of a normal.
+        let nearest = |centers: &[Vector<VertexPosition<G>>], normal: Vector<VertexPosition<G>>| {
+            centers
+                .iter()
+                .map(|center| (*center - normal).square_magnitude())
+                .enumerate()
+                .fold(
+                    None,
+                    |min: Option<(usize, Scalar<VertexPosition<G>>)>, (index, distance)| match min {
+                        Some(min) if min.1 <= distance => Some(min),
+                        _ => Some((index, distance)),
+                    },
+                )
+                .unwrap()
+        };
+        // Choose initial centers by farthest-point sampling.
+        let mut centers = vec![normals[0].1];
+        while centers.len() < k {
+            let (_, normal) = normals
+                .iter()
+                .map(|(_, normal)| (nearest(&centers, *normal).1, *normal))
+                .fold(
+                    None,
+                    |max: Option<(Scalar<VertexPosition<G>>, _)>, (distance, normal)| match max {
+                        Some(max) if max.0 >= distance => Some(max),
+                        _ => Some((distance, normal)),
+                    },
+                )
+                .unwrap();
+            centers.push(normal);
+        }
+        let mut segments = normals
+            .iter()
+            .map(|(_, normal)| nearest(&centers, *normal).0)
+            .collect::<Vec<_>>();
+        for _ in 0..iterations {
+            // Move each center to the mean direction of its normals. Centers
+            // of empty segments are not moved.
+            let mut sums = vec![Vector::<VertexPosition<G>>::zero(); k];
+            for ((_, normal), segment) in normals.iter().zip(segments.iter()) {
+                sums[*segment] = sums[*segment] + *normal;
+            }
+            for (center, sum) in centers.iter_mut().zip(sums) {
+                if let Some(sum) = sum.normalize() {
+                    *center = sum;
+                }
+            }
+            let mut changed = false;
+            for ((_, normal), segment) in normals.iter().zip(segments.iter_mut()) {
+                let index = nearest(&centers, *normal).0;
+                changed = changed || (index != *segment);
+                *segment = index;
+            }
+            if !changed {
+                break;
+            }
+        }
+        normals
+            .into_iter()
+            .map(|(key, _)| key)
+            .zip(segments)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+    use std::collections::{HashMap, HashSet};
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn segment_by_normals() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        graph.triangulate();
+
+        // Each side of the cube is a segment formed from two triangles.
+        let segments = graph.segment_by_normals(6, 16);
+        assert_eq!(12, segments.len());
+        let mut sides = HashMap::new();
+        for face in graph.faces() {
+            let segment = segments[&face.key()];
+            assert!(segment < 6);
+            sides
+                .entry(segment)
+                .or_insert_with(Vec::new)
+                .push(face.normal().unwrap());
+        }
+        assert_eq!(6, sides.len());
+        for normals in sides.values() {
+            assert_eq!(2, normals.len());
+            assert!((normals[0] - normals[1]).norm() < 1e-9);
+        }
+
+        // Fewer segments merge sides.
+        let segments = graph.segment_by_normals(2, 16);
+        assert!(segments.values().all(|segment| *segment < 2));
+        assert_eq!(2, segments.values().collect::<HashSet<_>>().len());
+        assert!(graph.segment_by_normals(0, 16).is_empty());
+    }
+
+    #[test]
+    fn face_centroids() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let centroids = graph.face_centroids();
+
+        assert_eq!(6, centroids.len());
+        for face in graph.faces() {
+            let centroid = centroids[&face.key()];
+            assert_eq!(face.centroid(), centroid);
+            // The centroid of each face of a unit cube is half of a unit from
+            // its center.
+            assert!((centroid.coords.norm() - 0.5).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,372 @@
+use decorum::Real;
+use smallvec::SmallVec;
+use std::collections::{HashMap, HashSet};
+use theon::space::{EuclideanSpace, InnerSpace, Scalar};
+use theon::AsPositionMut;
+
+use crate::entity::storage::KeyIndex;
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::geometry::{self, FaceNormal, VertexCentroid, VertexPosition};
+use crate::graph::vertex::VertexKey;
+use crate::graph::{GraphError, MeshGraph, OptionExt as _};
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Smooths the positions of vertices in the graph.
+    ///
+    /// Each position is translated by its offset from its centroid scaled by
+    /// the given factor. The centroid of a vertex position is the mean of the
+    /// positions of its adjacent vertices. That is, given a factor $k$ and a
+    /// vertex with position $P$ and centroid $Q$, its position becomes
+    /// $P+k(Q-P)$.
+    pub fn smooth<T>(&mut self, factor: T)
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: VertexCentroid,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.smooth_unpinned(factor.into(), &HashSet::new());
+    }
+
+    /// Smooths the positions of vertices in the graph using Taubin smoothing.
+    ///
+    /// Each iteration applies a shrinking step with the factor `lambda`
+    /// followed by an inflating step with the factor `mu` (see [`smooth`]).
+    /// `lambda` should be positive and `mu` should be negative with a greater
+    /// magnitude than `lambda`, such as $0.5$ and $-0.53$. Unlike repeated
+    /// Laplacian smoothing, this largely preserves the volume of the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = UvSphere::new(16, 8)
+    ///     .polygons::<Position<Point3<R64>>>()
+    ///     .collect();
+    /// graph.taubin_smooth(0.5, -0.53, 10);
+    /// ```
+    ///
+    /// [`smooth`]: crate::graph::MeshGraph::smooth
+    pub fn taubin_smooth<T>(&mut self, lambda: T, mu: T, iterations: usize)
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: VertexCentroid,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.taubin_smooth_pinned(lambda, mu, iterations, None)
+    }
+
+    /// Smooths the positions of vertices in the graph using Taubin smoothing
+    /// while leaving the given vertices in place.
+    ///
+    /// Pinned vertices still contribute to the centroids of their neighbors.
+    /// See [`taubin_smooth`].
+    ///
+    /// [`taubin_smooth`]: crate::graph::MeshGraph::taubin_smooth
+    pub fn taubin_smooth_pinned<T, I>(&mut self, lambda: T, mu: T, iterations: usize, pinned: I)
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        I: IntoIterator<Item = VertexKey<K>>,
+        G: VertexCentroid,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let (lambda, mu) = (lambda.into(), mu.into());
+        let pinned = pinned.into_iter().collect::<HashSet<_>>();
+        for _ in 0..iterations {
+            self.smooth_unpinned(lambda, &pinned);
+            self.smooth_unpinned(mu, &pinned);
+        }
+    }
+
+    /// Smooths the positions of vertices in the graph while preserving sharp
+    /// features.
+    ///
+    /// Edges are classified as feature edges if the angle between the normals
+    /// of their adjacent faces exceeds the given angle in radians. Boundary
+    /// edges are always feature edges. Each iteration then smooths the graph
+    /// like [`smooth`] with the factor `lambda`, but constrains the movement of
+    /// vertices on feature edges:
+    ///
+    /// - Vertices without feature edges move freely.
+    /// - Vertices with one or two feature edges slide only along the direction
+    ///   of the crease formed by those edges.
+    /// - Vertices at a junction of three or more feature edges, such as the
+    ///   corners of a box, and vertices where two feature edges meet at an
+    ///   angle that deviates from a straight line by more than the given angle
+    ///   do not move.
+    ///
+    /// Feature edges are classified once before smoothing. Unlike repeated
+    /// calls to [`smooth`], this preserves creases and corners.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the normal of a face cannot be computed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+    /// graph.triangulate();
+    /// let positions = graph
+    ///     .vertices()
+    ///     .map(|vertex| *vertex.position())
+    ///     .collect::<Vec<_>>();
+    /// graph
+    ///     .smooth_preserving_features(0.5, std::f64::consts::FRAC_PI_4, 10)
+    ///     .unwrap();
+    ///
+    /// // The corners of the cube do not move.
+    /// assert_eq!(
+    ///     positions,
+    ///     graph
+    ///         .vertices()
+    ///         .map(|vertex| *vertex.position())
+    ///         .collect::<Vec<_>>(),
+    /// );
+    /// ```
+    ///
+    /// [`smooth`]: crate::graph::MeshGraph::smooth
+    pub fn smooth_preserving_features<T>(
+        &mut self,
+        lambda: T,
+        angle: T,
+        iterations: usize,
+    ) -> Result<(), GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: FaceNormal + VertexCentroid,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let (lambda, angle) = (lambda.into(), angle.into());
+        // Map each vertex to the opposite vertices of its feature edges.
+        let mut features = HashMap::<VertexKey<K>, SmallVec<[VertexKey<K>; 2]>>::new();
+        for edge in self.edges() {
+            let arc = edge.arc();
+            let is_feature = if let (Some(abc), Some(bad)) = (arc.face(), arc.opposite_arc().face())
+            {
+                let (n, m) = (abc.normal()?, bad.normal()?);
+                geometry::vector_angle::<VertexPosition<G>>(n, m) > angle
+            }
+            else {
+                true
+            };
+            if is_feature {
+                let (a, b) = (arc.source_vertex().key(), arc.destination_vertex().key());
+                features.entry(a).or_default().push(b);
+                features.entry(b).or_default().push(a);
+            }
+        }
+        for _ in 0..iterations {
+            let mut positions = HashMap::with_capacity(self.vertex_count());
+            for vertex in self.vertices() {
+                let position = *vertex.position();
+                let offset = (vertex.centroid() - position) * lambda;
+                let direction = match features.get(&vertex.key()).map(|keys| keys.as_slice()) {
+                    None => {
+                        positions.insert(vertex.key(), position + offset);
+                        continue;
+                    }
+                    Some([b]) => *self.vertex(*b).expect_consistent().position() - position,
+                    Some([a, b]) => {
+                        let a = *self.vertex(*a).expect_consistent().position();
+                        let b = *self.vertex(*b).expect_consistent().position();
+                        // Feature edges that meet at a sharp angle form a
+                        // corner, which is pinned.
+                        let pi = <Scalar<VertexPosition<G>> as Real>::PI;
+                        if pi - geometry::triangle_angle(position, a, b) > angle {
+                            continue;
+                        }
+                        b - a
+                    }
+                    // Junctions of feature edges are pinned.
+                    Some(_) => {
+                        continue;
+                    }
+                };
+                if let Some(direction) = direction.normalize() {
+                    positions.insert(vertex.key(), position + (direction * offset.dot(direction)));
+                }
+            }
+            for mut vertex in self.vertex_orphans() {
+                if let Some(position) = positions.remove(&vertex.key()) {
+                    *vertex.get_mut().as_position_mut() = position;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn smooth_unpinned(&mut self, factor: Scalar<VertexPosition<G>>, pinned: &HashSet<VertexKey<K>>)
+    where
+        G: VertexCentroid,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let mut positions = HashMap::with_capacity(self.vertex_count());
+        for vertex in self
+            .vertices()
+            .filter(|vertex| !pinned.contains(&vertex.key()))
+        {
+            let position = *vertex.position();
+            positions.insert(
+                vertex.key(),
+                position + ((vertex.centroid() - position) * factor),
+            );
+        }
+        for mut vertex in self.vertex_orphans() {
+            if let Some(position) = positions.remove(&vertex.key()) {
+                *vertex.get_mut().as_position_mut() = position;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+    use crate::primitive::Tetragon;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn taubin_smooth() {
+        let sphere = || {
+            UvSphere::new(16, 8)
+                .polygons::<Position<E3>>()
+                .collect::<MeshGraph<Point3<f64>>>()
+        };
+        let graph = sphere();
+        let volume = graph.volume().unwrap();
+
+        let mut taubin = sphere();
+        taubin.taubin_smooth(0.5, -0.53, 10);
+        let mut laplacian = sphere();
+        for _ in 0..10 {
+            laplacian.smooth(0.5);
+        }
+        // Taubin smoothing approximately conserves volume while Laplacian
+        // smoothing shrinks the graph.
+        let error = ((taubin.volume().unwrap() - volume) / volume).abs();
+        assert!(error < 5e-2);
+        assert!(volume - laplacian.volume().unwrap() > volume - taubin.volume().unwrap());
+
+        // Pinned vertices are not moved.
+        let key = graph.vertices().nth(0).unwrap().key();
+        let mut pinned = sphere();
+        pinned.taubin_smooth_pinned(0.5, -0.53, 10, Some(key));
+        assert_eq!(
+            graph.vertex(key).unwrap().position(),
+            pinned.vertex(key).unwrap().position(),
+        );
+    }
+
+    #[test]
+    fn smooth_preserving_features() {
+        // Construct a grid of quadrilaterals folded at a right angle along a
+        // crease on the Y-axis. The floor lies in the XY-plane and the wall
+        // lies in the YZ-plane.
+        let position = |i: usize, j: usize| {
+            let u = j as f64 - 4.0;
+            if u <= 0.0 {
+                (u, i as f64, 0.0)
+            }
+            else {
+                (0.0, i as f64, u)
+            }
+        };
+        let index = |i: usize, j: usize| (i * 9) + j;
+        let mut vertices = (0..5)
+            .flat_map(|i| (0..9).map(move |j| position(i, j)))
+            .collect::<Vec<_>>();
+        let indices = (0..4)
+            .flat_map(|i| {
+                (0..8).map(move |j| {
+                    Tetragon::new(
+                        index(i, j),
+                        index(i, j + 1),
+                        index(i + 1, j + 1),
+                        index(i + 1, j),
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        // Perturb a vertex on the crease along the crease.
+        vertices[index(2, 4)].1 += 0.3;
+        let build = || {
+            MeshGraph::<Point3<f64>>::from_raw_buffers(indices.clone(), vertices.clone()).unwrap()
+        };
+        let graph = build();
+        let is_on_crease = |position: &Point3<f64>| position.x == 0.0 && position.z == 0.0;
+
+        let mut smoothed = build();
+        smoothed
+            .smooth_preserving_features(0.5, std::f64::consts::FRAC_PI_4, 5)
+            .unwrap();
+        for (vertex, original) in graph
+            .vertices()
+            .map(|vertex| (smoothed.vertex(vertex.key()).unwrap(), *vertex.position()))
+        {
+            let position = vertex.position();
+            // Vertices remain on the floor or the wall.
+            assert!(position.z.abs() < 1e-9 || position.x.abs() < 1e-9);
+            if is_on_crease(&original) {
+                // Vertices on the crease slide along the crease.
+                assert!(position.x.abs() < 1e-9);
+                assert!(position.z.abs() < 1e-9);
+            }
+        }
+        // The perturbed vertex slides back toward its neighbors.
+        let key = graph
+            .vertices()
+            .find(|vertex| *vertex.position() == Point3::new(0.0, 2.3, 0.0))
+            .unwrap()
+            .key();
+        let y = smoothed.vertex(key).unwrap().position().y;
+        assert!((y - 2.0).abs() < 0.3);
+
+        // Plain smoothing rounds the crease.
+        let mut laplacian = build();
+        laplacian.smooth(0.5);
+        let position = *laplacian.vertex(key).unwrap().position();
+        assert!(!is_on_crease(&position));
+    }
+}
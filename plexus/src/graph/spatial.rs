@@ -0,0 +1,427 @@
+use smallvec::SmallVec;
+use theon::space::{Basis, EuclideanSpace, FiniteDimensional, Vector};
+use theon::AsPosition;
+use typenum::U3;
+
+use crate::entity::storage::prelude::*;
+use crate::entity::storage::KeyIndex;
+use crate::entity::view::ClosedView;
+use crate::geometry::tree::{BvhTree, KdTree};
+use crate::graph::data::GraphData;
+use crate::graph::face::FaceKey;
+use crate::graph::geometry::{self, VertexPosition};
+use crate::graph::vertex::VertexKey;
+use crate::graph::{GraphError, MeshGraph, TopologyKind};
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Constructs a $k$-d tree of the positions of the vertices in the graph.
+    ///
+    /// The tree maps vertex positions to vertex keys and supports nearest
+    /// neighbor and range queries in logarithmic time on average. The tree is
+    /// not updated when the graph is mutated and keys in the tree may be
+    /// invalidated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let graph: MeshGraph<E3> = UvSphere::new(16, 8)
+    ///     .polygons::<Position<Point3<R64>>>()
+    ///     .collect();
+    /// let tree = graph.vertex_tree();
+    /// let key = tree.nearest(Point3::new(0.0, 0.0, 2.0), 1)[0];
+    ///
+    /// assert_eq!(1.0, graph.vertex(key).unwrap().position().z);
+    /// ```
+    pub fn vertex_tree(&self) -> KdTree<VertexPosition<G>, VertexKey<K>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: Basis,
+    {
+        self.vertices()
+            .map(|vertex| (*vertex.position(), vertex.key()))
+            .collect()
+    }
+
+    /// Constructs a bounding volume hierarchy (BVH) of the faces in the graph.
+    ///
+    /// The tree maps triangles to face keys and supports ray intersection
+    /// queries in logarithmic time on average. Faces are decomposed into
+    /// triangles by clipping ears, so planar faces are represented correctly,
+    /// including concave faces. Each triangle is mapped to the key of its face
+    /// and the indices of its corners in the ring of that face. The barycentric
+    /// coordinates of an intersection weight these corners in order, so a
+    /// coordinate at index $i$ weights the vertex at the $i$-th index of the
+    /// triangle. Like [`MeshGraph::vertex_tree`], the tree is not updated when
+    /// the graph is mutated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::{Point3, Vector3};
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let graph: MeshGraph<E3> = UvSphere::new(16, 8)
+    ///     .polygons::<Position<Point3<R64>>>()
+    ///     .collect();
+    /// let tree = graph.build_bvh();
+    /// let ((key, corners), t, _) = tree
+    ///     .ray_intersect(Point3::new(0.1, 0.2, -4.0), Vector3::z())
+    ///     .unwrap();
+    /// assert!(corners.iter().all(|index| *index < graph.face(key).unwrap().arity()));
+    /// assert!(t > 0.0 && t < 4.0);
+    /// ```
+    ///
+    /// [`MeshGraph::vertex_tree`]: crate::graph::MeshGraph::vertex_tree
+    pub fn build_bvh(&self) -> BvhTree<VertexPosition<G>, (FaceKey<K>, [usize; 3])>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        self.faces()
+            .flat_map(|face| {
+                let positions = face
+                    .adjacent_vertices()
+                    .map(|vertex| *vertex.position())
+                    .collect::<SmallVec<[_; 4]>>();
+                geometry::polygon_triangles(&positions)
+                    .into_iter()
+                    .map(|indices| {
+                        let triangle = indices.map(|index| positions[index]);
+                        (triangle, (face.key(), indices))
+                    })
+                    .collect::<SmallVec<[_; 2]>>()
+            })
+            .collect()
+    }
+
+    /// Gets an iterator over the data of the vertices with the given keys.
+    ///
+    /// Data is yielded in the same order as the keys. Each item is an error if
+    /// its key does not refer to a vertex. Such an error is wrapped with both
+    /// the key and its index in the iterator (see [`GraphError::AtKey`] and
+    /// [`GraphError::AtIndex`]). The data is borrowed, so this does not
+    /// allocate or copy. See [`MeshGraph::gather_vertices`].
+    ///
+    /// [`GraphError::AtIndex`]: crate::graph::GraphError::AtIndex
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    /// [`MeshGraph::gather_vertices`]: crate::graph::MeshGraph::gather_vertices
+    pub fn vertices_by_key<'a, I>(
+        &'a self,
+        keys: I,
+    ) -> impl 'a + Iterator<Item = Result<&'a G::Vertex, GraphError>>
+    where
+        I: IntoIterator<Item = VertexKey<K>>,
+        I::IntoIter: 'a,
+    {
+        keys.into_iter()
+            .enumerate()
+            .map(move |(index, key)| self.vertex_data_at(index, key))
+    }
+
+    /// Gathers the data of the vertices with the given keys.
+    ///
+    /// Returns the data in the same order as the keys. This queries storage
+    /// directly and avoids constructing a view for each key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any key does not refer to a vertex. The error is
+    /// wrapped with the first such key and its index in `keys` (see
+    /// [`GraphError::AtKey`] and [`GraphError::AtIndex`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+    /// let keys = graph
+    ///     .faces()
+    ///     .nth(0)
+    ///     .unwrap()
+    ///     .adjacent_vertices()
+    ///     .keys()
+    ///     .collect::<Vec<_>>();
+    /// let vertices = graph.gather_vertices(&keys).unwrap();
+    /// ```
+    ///
+    /// [`GraphError::AtIndex`]: crate::graph::GraphError::AtIndex
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    pub fn gather_vertices<'a>(
+        &'a self,
+        keys: &[VertexKey<K>],
+    ) -> Result<Vec<&'a G::Vertex>, GraphError> {
+        keys.iter()
+            .enumerate()
+            .map(|(index, key)| self.vertex_data_at(index, *key))
+            .collect()
+    }
+
+    /// Gathers the positions of the vertices with the given keys.
+    ///
+    /// Returns the positions in the same order as the keys. See
+    /// [`MeshGraph::gather_vertices`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any key does not refer to a vertex. The error is
+    /// wrapped with the first such key and its index in `keys` (see
+    /// [`GraphError::AtKey`] and [`GraphError::AtIndex`]).
+    ///
+    /// [`GraphError::AtIndex`]: crate::graph::GraphError::AtIndex
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    /// [`MeshGraph::gather_vertices`]: crate::graph::MeshGraph::gather_vertices
+    pub fn gather_positions(
+        &self,
+        keys: &[VertexKey<K>],
+    ) -> Result<Vec<VertexPosition<G>>, GraphError>
+    where
+        G::Vertex: AsPosition,
+    {
+        keys.iter()
+            .enumerate()
+            .map(|(index, key)| {
+                self.vertex_data_at(index, *key)
+                    .map(|data| *data.as_position())
+            })
+            .collect()
+    }
+
+    fn vertex_data_at(&self, index: usize, key: VertexKey<K>) -> Result<&G::Vertex, GraphError> {
+        self.core
+            .vertices
+            .get(&key)
+            .map(|vertex| &vertex.data)
+            .ok_or_else(|| {
+                GraphError::TopologyNotFound(TopologyKind::Vertex)
+                    .at_key(key)
+                    .at_index(index)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::{Point3, Vector3};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::HashSet;
+
+    use crate::graph::{GraphError, MeshGraph, TopologyKind, VertexKey};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+    use crate::primitive::NGon;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn vertex_tree() {
+        let graph: MeshGraph<Point3<f64>> =
+            UvSphere::new(16, 8).polygons::<Position<E3>>().collect();
+        let tree = graph.vertex_tree();
+        assert_eq!(graph.vertex_count(), tree.len());
+
+        let distance = |key: VertexKey, point: Point3<f64>| {
+            (graph.vertex(key).unwrap().position() - point).norm()
+        };
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..32 {
+            let point = Point3::new(rng.gen_range(-1.5..1.5), rng.gen_range(-1.5..1.5), 0.0);
+            // Compare queries against an exhaustive search of the vertices.
+            let mut keys = graph
+                .vertices()
+                .map(|vertex| vertex.key())
+                .collect::<Vec<_>>();
+            keys.sort_by(|a, b| {
+                distance(*a, point)
+                    .partial_cmp(&distance(*b, point))
+                    .unwrap()
+            });
+            let nearest = tree.nearest(point, 4);
+            for (key, expected) in nearest.iter().zip(keys.iter()) {
+                assert!((distance(*key, point) - distance(*expected, point)).abs() < 1e-9);
+            }
+            let within = tree
+                .within_radius(point, 0.5)
+                .into_iter()
+                .collect::<HashSet<_>>();
+            let expected = keys
+                .into_iter()
+                .filter(|key| distance(*key, point) <= 0.5)
+                .collect::<HashSet<_>>();
+            assert_eq!(expected, within);
+        }
+    }
+
+    #[test]
+    fn build_bvh() {
+        let graph: MeshGraph<Point3<f64>> = UvSphere::new(16, 8)
+            .polygons::<Position<E3>>()
+            .triangulate()
+            .collect();
+        let tree = graph.build_bvh();
+        assert_eq!(graph.face_count(), tree.len());
+
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..32 {
+            let direction = Vector3::new(
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+            )
+            .normalize();
+            // Cast rays from outside of the sphere toward its center.
+            let origin = Point3::origin() + (direction * 4.0);
+            let ((key, corners), t, barycentric) = tree.ray_intersect(origin, -direction).unwrap();
+            let point = origin - (direction * t);
+            assert!(point.coords.norm() <= 1.0 + 1e-9);
+            assert!(point.coords.norm() >= 0.8);
+            // The barycentric coordinates of the intersection reconstruct it
+            // from the corners of the triangle in the ring of the face.
+            let positions = graph
+                .face(key)
+                .unwrap()
+                .adjacent_vertices()
+                .map(|vertex| vertex.position().coords)
+                .collect::<Vec<_>>();
+            let expected = corners
+                .iter()
+                .zip(barycentric)
+                .fold(Vector3::zeros(), |sum, (index, weight)| {
+                    sum + (positions[*index] * weight)
+                });
+            assert!((expected - point.coords).norm() < 1e-9);
+            // Rays cast away from the sphere do not intersect it.
+            assert!(tree.ray_intersect(origin, direction).is_none());
+        }
+
+        // Non-triangular faces are triangulated.
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let ((key, _), t, _) = graph
+            .build_bvh()
+            .ray_intersect(Point3::new(0.1, 0.2, 4.0), -Vector3::z())
+            .unwrap();
+        assert!((t - 3.5).abs() < 1e-9);
+        assert!(graph
+            .face(key)
+            .unwrap()
+            .adjacent_vertices()
+            .all(|vertex| (vertex.position().z - 0.5).abs() < 1e-9));
+
+        // Concave faces are triangulated by clipping ears.
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![NGon([0u32, 1, 2, 3])],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (0.25, 0.25, 0.0),
+                (0.0, 1.0, 0.0),
+            ],
+        )
+        .unwrap();
+        let tree = graph.build_bvh();
+        assert_eq!(2, tree.len());
+        assert!(tree
+            .ray_intersect(Point3::new(0.5, 0.5, 1.0), -Vector3::z())
+            .is_none());
+        let ((key, corners), _, barycentric) = tree
+            .ray_intersect(Point3::new(0.1, 0.05, 1.0), -Vector3::z())
+            .unwrap();
+        let positions = graph
+            .face(key)
+            .unwrap()
+            .adjacent_vertices()
+            .map(|vertex| vertex.position().coords)
+            .collect::<Vec<_>>();
+        let expected = corners
+            .iter()
+            .zip(barycentric)
+            .fold(Vector3::zeros(), |sum, (index, weight)| {
+                sum + (positions[*index] * weight)
+            });
+        assert!((expected - Vector3::new(0.1, 0.05, 0.0)).norm() < 1e-9);
+    }
+
+    #[test]
+    fn gather_vertices() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let mut keys = graph.vertices().keys().collect::<Vec<_>>();
+        keys.reverse();
+
+        let vertices = graph.gather_vertices(&keys).unwrap();
+        let positions = graph.gather_positions(&keys).unwrap();
+        assert_eq!(8, vertices.len());
+        for ((key, data), position) in keys.iter().zip(vertices).zip(positions) {
+            assert_eq!(graph.vertex(*key).unwrap().get(), data);
+            assert_eq!(*graph.vertex(*key).unwrap().position(), position);
+        }
+
+        // Remove a vertex and gather again. The error refers to the index of
+        // the first occurrence of the missing key as well as the key itself.
+        let removed = keys[3];
+        graph.vertex_mut(removed).unwrap().remove();
+        keys.insert(1, removed);
+        let error = graph.gather_positions(&keys).unwrap_err();
+        assert_eq!(Some(1), error.index());
+        assert_eq!(Some(removed.into()), error.key());
+        assert_eq!(
+            &GraphError::TopologyNotFound(TopologyKind::Vertex),
+            error.without_key(),
+        );
+        assert_eq!(
+            Err(GraphError::TopologyNotFound(TopologyKind::Vertex)
+                .at_key(removed)
+                .at_index(1)),
+            graph.gather_vertices(&keys),
+        );
+        assert_eq!(
+            7,
+            graph
+                .vertices_by_key(keys.iter().copied())
+                .filter(|data| data.is_ok())
+                .count()
+        );
+    }
+}
@@ -0,0 +1,579 @@
+use num::NumCast;
+use smallvec::SmallVec;
+use std::cmp;
+use std::collections::{HashMap, HashSet};
+use std::vec;
+use theon::query::Plane;
+use theon::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
+use theon::{AsPosition, AsPositionMut};
+
+use crate::entity::storage::KeyIndex;
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::edge::EdgeKey;
+use crate::graph::geometry::{self, FaceNormal, Lerp, VertexNormal, VertexPosition};
+use crate::graph::mutation::face::FaceInsertCache;
+use crate::graph::mutation::{self, Immediate};
+use crate::graph::vertex::VertexKey;
+use crate::graph::{ByKey, GraphError, MeshGraph, OptionExt as _, ResultExt as _};
+use crate::transact::Transact;
+
+type Mutation<M> = mutation::Mutation<Immediate<M>>;
+
+/// Graph with vertices split along sharp edges.
+///
+/// A split graph is constructed by [`MeshGraph::split_at_sharp_edges`]. Each
+/// vertex of the original graph is duplicated once for each fan of its
+/// incident faces that is bounded by sharp edges, so that faces on either side
+/// of a sharp edge do not share vertices. This allows such faces to have
+/// different vertex normals, as is typical of vertex buffers used for
+/// rendering.
+///
+/// [`MeshGraph::split_at_sharp_edges`]: crate::graph::MeshGraph::split_at_sharp_edges
+pub struct SplitGraph<G, K = u64>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Graph with split vertices.
+    pub graph: MeshGraph<G, K>,
+    /// Map from the key of each vertex in the original graph to the keys of
+    /// the vertices in `graph` that it has been split into.
+    pub splits: HashMap<VertexKey<K>, Vec<VertexKey<K>>>,
+}
+
+impl<G, K> SplitGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Gets flat buffers of positions and vertex normals.
+    ///
+    /// The buffers describe a list of triangles: each consecutive triple of
+    /// positions and normals forms a triangle. Faces are triangulated as fans,
+    /// so non-convex faces may not be triangulated correctly.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a vertex normal cannot be computed, such as when a
+    /// face is degenerate.
+    pub fn to_gpu_buffers(
+        &self,
+    ) -> Result<(Vec<VertexPosition<G>>, Vec<Vector<VertexPosition<G>>>), GraphError>
+    where
+        G: VertexNormal,
+        G::Vertex: AsPosition,
+    {
+        let vertex_normals = self
+            .graph
+            .vertices()
+            .map(|vertex| vertex.normal().map(|normal| (vertex.key(), normal)))
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        let mut positions = Vec::new();
+        let mut normals = Vec::new();
+        for face in self.graph.faces() {
+            let vertices = face.adjacent_vertices().collect::<SmallVec<[_; 4]>>();
+            for index in 1..vertices.len().saturating_sub(1) {
+                for vertex in [vertices[0], vertices[index], vertices[index + 1]] {
+                    positions.push(*vertex.position());
+                    normals.push(vertex_normals[&vertex.key()]);
+                }
+            }
+        }
+        Ok((positions, normals))
+    }
+}
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Splits the graph along a plane.
+    ///
+    /// Every edge that crosses the plane is split at its intersection with the
+    /// plane and every face that straddles the plane is split such that no
+    /// face crosses it. Vertices within the given distance of the plane are
+    /// considered on the plane and are reused rather than split again. Faces
+    /// that lie entirely on one side of the plane are unaffected.
+    ///
+    /// Returns the keys of the edges inserted into faces along the plane. These
+    /// edges form the contour of the cut.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a face cannot be split or if the keyspace of the
+    /// graph is exhausted. A face may not be split if it is not convex, in
+    /// which case the error is wrapped with the key of the face (see
+    /// [`GraphError::AtKey`]).
+    ///
+    /// Faces are not validated before the graph is modified, so the graph may
+    /// be partially split if an error occurs: edges that cross the plane have
+    /// been split and faces visited before the offending face have been split,
+    /// but the remaining faces may still straddle the plane.
+    ///
+    /// # Examples
+    ///
+    /// Splitting a cube and removing the faces above the cut:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::{Point3, Vector3};
+    /// use plexus::geometry::{Plane, Unit};
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+    /// let plane = Plane {
+    ///     origin: Point3::origin(),
+    ///     normal: Unit::try_from_inner(Vector3::z()).unwrap(),
+    /// };
+    /// let contour = graph.split_by_plane(plane, 1e-9).unwrap();
+    /// let keys = graph
+    ///     .faces()
+    ///     .filter(|face| face.centroid().z > 0.0)
+    ///     .map(|face| face.key())
+    ///     .collect::<Vec<_>>();
+    /// for key in keys {
+    ///     graph.face_mut(key).unwrap().remove();
+    /// }
+    /// ```
+    ///
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    pub fn split_by_plane<T>(
+        &mut self,
+        plane: Plane<VertexPosition<G>>,
+        epsilon: T,
+    ) -> Result<Vec<EdgeKey<K>>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.split_by_plane_with(plane, epsilon, |a, _, _| a.clone())
+    }
+
+    /// Splits the graph along a plane, interpolating the data of vertices
+    /// inserted along the cut.
+    ///
+    /// This function behaves like [`split_by_plane`], but the data of each
+    /// inserted vertex is interpolated via [`Lerp`] from the vertices of the
+    /// split edge at its intersection with the plane. Non-positional data,
+    /// such as colors and texture coordinates, is blended rather than copied
+    /// from one of the vertices.
+    ///
+    /// Returns the keys of the edges inserted into faces along the plane.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a face cannot be split or if the keyspace of the
+    /// graph is exhausted. A face may not be split if it is not convex, in
+    /// which case the error is wrapped with the key of the face (see
+    /// [`GraphError::AtKey`]). As with [`split_by_plane`], the graph may be
+    /// partially split if an error occurs.
+    ///
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    /// [`Lerp`]: crate::graph::Lerp
+    /// [`split_by_plane`]: crate::graph::MeshGraph::split_by_plane
+    pub fn split_by_plane_interpolated<T>(
+        &mut self,
+        plane: Plane<VertexPosition<G>>,
+        epsilon: T,
+    ) -> Result<Vec<EdgeKey<K>>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut + Lerp,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.split_by_plane_with(plane, epsilon, |a, b, t| a.clone().lerp(b.clone(), t))
+    }
+
+    // The given function computes the data of vertices inserted along the cut
+    // from the data of the vertices of a split edge and the parameter of the
+    // intersection along that edge. The position of the data is then set to
+    // the exact intersection.
+    //
+    // Errors are not rolled back. Crossing edges are split before any face
+    // is examined, so an error leaves the graph partially split.
+    fn split_by_plane_with<T, F>(
+        &mut self,
+        plane: Plane<VertexPosition<G>>,
+        epsilon: T,
+        mut f: F,
+    ) -> Result<Vec<EdgeKey<K>>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        F: FnMut(&G::Vertex, &G::Vertex, f64) -> G::Vertex,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let epsilon = epsilon.into();
+        let normal = *plane.normal.get();
+        let distance = |position: VertexPosition<G>| (position - plane.origin).dot(normal);
+        let side = |position: VertexPosition<G>| {
+            let distance = distance(position);
+            if distance > epsilon {
+                cmp::Ordering::Greater
+            }
+            else if distance < -epsilon {
+                cmp::Ordering::Less
+            }
+            else {
+                cmp::Ordering::Equal
+            }
+        };
+
+        let crossings = self
+            .edges()
+            .filter_map(|edge| {
+                let arc = edge.arc();
+                let a = *arc.source_vertex().position();
+                let b = *arc.destination_vertex().position();
+                if side(a) != cmp::Ordering::Equal
+                    && side(b) != cmp::Ordering::Equal
+                    && side(a) != side(b)
+                {
+                    let (da, db) = (distance(a), distance(b));
+                    let t = da / (da - db);
+                    let mut data = f(
+                        arc.source_vertex().get(),
+                        arc.destination_vertex().get(),
+                        <f64 as NumCast>::from(t).unwrap_or(0.5),
+                    );
+                    *data.as_position_mut() = a + ((b - a) * t);
+                    Some((arc.key(), data))
+                }
+                else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        // Inserted vertices are on the plane by construction, so they are not
+        // classified by distance, which may suffer from rounding errors.
+        let mut inserted = HashSet::with_capacity(crossings.len());
+        for (key, data) in crossings {
+            let arc = self.arc_mut(key).expect_consistent();
+            inserted.insert(arc.split_with(move || data)?.key());
+        }
+
+        let mut edges = vec![];
+        let keys = self.faces().map(|face| face.key()).collect::<Vec<_>>();
+        for mut key in keys {
+            loop {
+                let sides = self
+                    .face(key)
+                    .expect_consistent()
+                    .adjacent_vertices()
+                    .map(|vertex| {
+                        if inserted.contains(&vertex.key()) {
+                            (vertex.key(), cmp::Ordering::Equal)
+                        }
+                        else {
+                            (vertex.key(), side(*vertex.position()))
+                        }
+                    })
+                    .collect::<SmallVec<[_; 8]>>();
+                if !(sides.iter().any(|(_, side)| *side == cmp::Ordering::Less)
+                    && sides
+                        .iter()
+                        .any(|(_, side)| *side == cmp::Ordering::Greater))
+                {
+                    break;
+                }
+                // Every crossing edge has been split, so the vertices between
+                // consecutive vertices on the plane in the ring all lie on the
+                // same side of the plane. Split off the first such run.
+                let n = sides.len();
+                let on_plane = |index: usize| sides[index % n].1 == cmp::Ordering::Equal;
+                let source = (0..n)
+                    .find(|&index| on_plane(index) && !on_plane(index + 1))
+                    .ok_or_else(|| GraphError::TopologyMalformed.at_key(key))?;
+                let destination = (source + 2..source + n)
+                    .find(|&index| on_plane(index))
+                    .ok_or_else(|| GraphError::TopologyMalformed.at_key(key))?;
+                let arc = self
+                    .face_mut(key)
+                    .expect_consistent()
+                    .split(ByKey(sides[source].0), ByKey(sides[destination % n].0))
+                    .map_err(|error| error.at_key(key))?;
+                edges.push(arc.edge().key());
+                key = arc.face().expect_consistent().key();
+            }
+        }
+        Ok(edges)
+    }
+
+    /// Splits vertices along sharp edges.
+    ///
+    /// An edge is sharp if the angle between the normals of its adjacent faces
+    /// exceeds the given angle in radians. Each vertex is duplicated once for
+    /// each fan of its incident faces that is bounded by sharp edges (or
+    /// boundaries), so that the faces on either side of a sharp edge can have
+    /// different vertex normals. This is typically necessary before rendering.
+    /// See [`SplitGraph`].
+    ///
+    /// The split graph is a new graph. Vertex and face data are preserved, but
+    /// arc and edge data are not and vertices without incident faces are
+    /// discarded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the normal of a face cannot be computed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+    /// let split = graph.split_at_sharp_edges(0.5).unwrap();
+    ///
+    /// // Each corner of the cube is split once for each of its faces.
+    /// assert_eq!(24, split.graph.vertex_count());
+    /// let (positions, normals) = split.to_gpu_buffers().unwrap();
+    /// assert_eq!(36, positions.len());
+    /// ```
+    ///
+    /// [`SplitGraph`]: crate::graph::SplitGraph
+    pub fn split_at_sharp_edges<T>(&self, angle: T) -> Result<SplitGraph<G, K>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: FaceNormal,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let angle = angle.into();
+        let mut edges = HashSet::new();
+        for edge in self.edges() {
+            let arc = edge.arc();
+            if let (Some(abc), Some(bad)) = (arc.face(), arc.opposite_arc().face()) {
+                let (n, m) = (abc.normal()?, bad.normal()?);
+                if geometry::vector_angle::<VertexPosition<G>>(n, m) > angle {
+                    edges.insert(edge.key());
+                }
+            }
+        }
+        let fans = self.vertex_fans_with(|key| !edges.contains(&key));
+        // Vertices are inserted once for each distinct pair of a vertex and
+        // the representative face of one of its fans.
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let mut vertices = HashMap::new();
+        let mut splits = HashMap::<_, Vec<_>>::new();
+        for face in self.faces() {
+            let perimeter = face
+                .adjacent_vertices()
+                .map(|vertex| {
+                    let fan = fans[&vertex.key()][&face.key()];
+                    match vertices.get(&(vertex.key(), fan)) {
+                        Some(key) => Ok(*key),
+                        None => {
+                            let key =
+                                mutation::vertex::insert(&mut mutation, vertex.get().clone())?;
+                            vertices.insert((vertex.key(), fan), key);
+                            splits.entry(vertex.key()).or_default().push(key);
+                            Ok(key)
+                        }
+                    }
+                })
+                .collect::<Result<SmallVec<[_; 4]>, GraphError>>()?;
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter).expect_consistent();
+            let data = face.get().clone();
+            mutation::face::insert_with(&mut mutation, cache, || (Default::default(), data))
+                .expect_consistent();
+        }
+        let graph = mutation
+            .commit()
+            .map_err(|(_, error)| error)
+            .expect_consistent();
+        Ok(SplitGraph { graph, splits })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::{Point3, Vector3};
+
+    use crate::geometry::{AsPosition, AsPositionMut, Plane, Unit};
+    use crate::graph::{GraphData, Lerp, MeshGraph};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::NGon;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn split_by_plane() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let plane = Plane {
+            origin: Point3::origin(),
+            normal: Unit::try_from_inner(Vector3::z()).unwrap(),
+        };
+        let contour = graph.split_by_plane(plane, 1e-9).unwrap();
+
+        // Each of the four sides of the cube is split in half.
+        assert_eq!(4, contour.len());
+        assert_eq!(12, graph.vertex_count());
+        assert_eq!(20, graph.edge_count());
+        assert_eq!(10, graph.face_count());
+        for key in contour.iter() {
+            let arc = graph.edge(*key).unwrap().into_arc();
+            assert!(arc.source_vertex().position().z.abs() < 1e-9);
+            assert!(arc.destination_vertex().position().z.abs() < 1e-9);
+        }
+        // Splitting again along the same plane does nothing.
+        assert!(graph.split_by_plane(plane, 1e-9).unwrap().is_empty());
+        assert_eq!(10, graph.face_count());
+
+        // Removing the faces above the plane leaves a boundary along the
+        // contour. Removing faces does not remove their edges, so the edges
+        // above the plane remain with no faces on either side.
+        let keys = graph
+            .faces()
+            .filter(|face| face.centroid().z > 0.0)
+            .map(|face| face.key())
+            .collect::<Vec<_>>();
+        for key in keys {
+            graph.face_mut(key).unwrap().remove();
+        }
+        assert_eq!(5, graph.face_count());
+        assert_eq!(20, graph.edge_count());
+        let (boundary, disjoint): (Vec<_>, Vec<_>) = graph
+            .arcs()
+            .filter(|arc| arc.is_boundary_arc())
+            .partition(|arc| !arc.opposite_arc().is_boundary_arc());
+        assert_eq!(4, boundary.len());
+        for arc in boundary {
+            assert!(contour.contains(&arc.edge().key()));
+        }
+        assert_eq!(16, disjoint.len());
+        for arc in disjoint {
+            assert!(!contour.contains(&arc.edge().key()));
+            let (a, b) = (arc.source_vertex(), arc.destination_vertex());
+            assert!(a.position().z.max(b.position().z) > 0.0);
+        }
+    }
+
+    #[test]
+    fn split_by_plane_interpolated() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Vertex {
+            position: Point3<f64>,
+            weight: f64,
+        }
+
+        impl GraphData for Vertex {
+            type Vertex = Self;
+            type Arc = ();
+            type Edge = ();
+            type Face = ();
+        }
+
+        impl AsPosition for Vertex {
+            type Position = Point3<f64>;
+
+            fn as_position(&self) -> &Self::Position {
+                &self.position
+            }
+        }
+
+        impl AsPositionMut for Vertex {
+            fn as_position_mut(&mut self) -> &mut Self::Position {
+                &mut self.position
+            }
+        }
+
+        impl Lerp for Vertex {
+            fn lerp(self, other: Self, t: f64) -> Self {
+                Vertex {
+                    position: Lerp::lerp(self.position, other.position, t),
+                    weight: self.weight.lerp(other.weight, t),
+                }
+            }
+        }
+
+        // Construct a unit square with weights that vary along the x-axis.
+        let mut graph = MeshGraph::<Vertex>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2, 3])],
+            vec![
+                (Point3::new(0.0, 0.0, 0.0), 0.0),
+                (Point3::new(1.0, 0.0, 0.0), 1.0),
+                (Point3::new(1.0, 1.0, 0.0), 1.0),
+                (Point3::new(0.0, 1.0, 0.0), 0.0),
+            ]
+            .into_iter()
+            .map(|(position, weight)| Vertex { position, weight })
+            .collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let plane = Plane {
+            origin: Point3::new(0.25, 0.0, 0.0),
+            normal: Unit::try_from_inner(Vector3::x()).unwrap(),
+        };
+        let contour = graph.split_by_plane_interpolated(plane, 1e-9).unwrap();
+
+        assert_eq!(1, contour.len());
+        assert_eq!(6, graph.vertex_count());
+        assert_eq!(2, graph.face_count());
+        // The weights of inserted vertices are interpolated from the split
+        // edges rather than copied from a vertex.
+        let inserted = graph
+            .vertices()
+            .filter(|vertex| (vertex.position().x - 0.25).abs() < 1e-9)
+            .collect::<Vec<_>>();
+        assert_eq!(2, inserted.len());
+        for vertex in inserted {
+            assert!((vertex.get().weight - 0.25).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn split_at_sharp_edges() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+
+        // All edges of a cube are sharp, so each corner is split once for each
+        // of its faces.
+        let split = graph.split_at_sharp_edges(0.5).unwrap();
+        assert_eq!(24, split.graph.vertex_count());
+        assert_eq!(6, split.graph.face_count());
+        assert_eq!(8, split.splits.len());
+        for (key, splits) in split.splits.iter() {
+            assert_eq!(3, splits.len());
+            let position = *graph.vertex(*key).unwrap().position();
+            assert!(splits
+                .iter()
+                .all(|key| *split.graph.vertex(*key).unwrap().position() == position));
+        }
+        let (positions, normals) = split.to_gpu_buffers().unwrap();
+        assert_eq!(36, positions.len());
+        assert_eq!(36, normals.len());
+        assert!(normals
+            .iter()
+            .all(|normal| (normal.abs().sum() - 1.0).abs() < 1e-9));
+
+        // No edges are sharp given a large enough angle.
+        let split = graph.split_at_sharp_edges(2.0).unwrap();
+        assert_eq!(8, split.graph.vertex_count());
+        assert!(split.splits.values().all(|splits| splits.len() == 1));
+    }
+}
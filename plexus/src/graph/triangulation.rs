@@ -0,0 +1,732 @@
+//! Geometric triangulation of polygons.
+
+use decorum::Real;
+use num::{One, Zero};
+use std::cmp;
+use std::collections::HashSet;
+use std::vec;
+use theon::ops::Cross;
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use theon::AsPosition;
+use typenum::U3;
+
+use crate::entity::storage::KeyIndex;
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::geometry::{self, FaceNormal, VertexPosition};
+use crate::graph::{ByKey, GraphError, MeshGraph, OptionExt as _, ResultExt as _};
+use crate::{DynamicArity, IteratorExt as _};
+
+/// Strategy used to triangulate faces.
+///
+/// All strategies except [`Fan`] consider the geometry of faces and project
+/// them onto their planes, so non-planar faces are triangulated as if they were
+/// flattened along their normals. See [`MeshGraph::triangulate_all`].
+///
+/// [`Fan`]: crate::graph::TriangulationStrategy::Fan
+/// [`MeshGraph::triangulate_all`]: crate::graph::MeshGraph::triangulate_all
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TriangulationStrategy {
+    /// Forms a fan of triangles about the leading vertex of a face.
+    ///
+    /// This is the fastest strategy, but it ignores geometry and only produces
+    /// a valid triangulation of convex faces.
+    Fan,
+    /// Repeatedly clips ears (convex corners that contain no other vertices)
+    /// from a face.
+    ///
+    /// This supports concave faces.
+    EarClipping,
+    /// Chooses the triangulation that minimizes the variance of the areas of
+    /// its triangles.
+    ///
+    /// This supports concave faces, but is cubic in the arity of a face.
+    MinimumArea,
+    /// Computes a constrained Delaunay triangulation in the plane of a face,
+    /// which maximizes the minimum angle of its triangles.
+    ///
+    /// This supports concave faces.
+    Delaunay2D,
+}
+
+/// Triangulates a polygon with the given positions.
+///
+/// The polygon is expected to be simple and wound counter-clockwise about its
+/// normal. Returns triangles as indices into the positions, wound in the same
+/// direction as the polygon.
+pub(in crate::graph) fn triangulate<S>(
+    positions: &[S],
+    strategy: TriangulationStrategy,
+) -> Vec<[usize; 3]>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    let polygon = Polygon::new(positions);
+    match strategy {
+        TriangulationStrategy::Fan => polygon.fan(),
+        TriangulationStrategy::EarClipping => polygon.ear_clipping(),
+        TriangulationStrategy::MinimumArea => polygon
+            .minimum_area()
+            .unwrap_or_else(|| polygon.ear_clipping()),
+        TriangulationStrategy::Delaunay2D => polygon.delaunay(),
+    }
+}
+
+struct Polygon<'a, S>
+where
+    S: EuclideanSpace,
+{
+    positions: &'a [S],
+    normal: Vector<S>,
+}
+
+impl<'a, S> Polygon<'a, S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    fn new(positions: &'a [S]) -> Self {
+        // Use Newell's method, which is robust to concave polygons.
+        let origin = positions[0];
+        let normal = (1..positions.len())
+            .map(|index| (index, (index + 1) % positions.len()))
+            .fold(Vector::<S>::zero(), |normal, (a, b)| {
+                normal + (positions[a] - origin).cross(positions[b] - origin)
+            });
+        Polygon { positions, normal }
+    }
+
+    fn arity(&self) -> usize {
+        self.positions.len()
+    }
+
+    // Twice the signed area of a triangle projected onto the plane of the
+    // polygon. This is positive if the triangle is wound counter-clockwise.
+    fn orientation(&self, a: usize, b: usize, c: usize) -> Scalar<S> {
+        let a = self.positions[a];
+        (self.positions[b] - a)
+            .cross(self.positions[c] - a)
+            .dot(self.normal)
+    }
+
+    fn is_left(&self, a: usize, b: usize, c: usize) -> bool {
+        self.orientation(a, b, c) > Zero::zero()
+    }
+
+    fn is_left_or_on(&self, a: usize, b: usize, c: usize) -> bool {
+        self.orientation(a, b, c) >= Zero::zero()
+    }
+
+    fn contains(&self, [a, b, c]: [usize; 3], p: usize) -> bool {
+        self.is_left_or_on(a, b, p) && self.is_left_or_on(b, c, p) && self.is_left_or_on(c, a, p)
+    }
+
+    fn intersects(&self, [a, b]: [usize; 2], [c, d]: [usize; 2]) -> bool {
+        let zero = Zero::zero();
+        (self.orientation(a, b, c) * self.orientation(a, b, d) < zero)
+            && (self.orientation(c, d, a) * self.orientation(c, d, b) < zero)
+    }
+
+    // Determines if the segment between two non-adjacent vertices is a
+    // diagonal that lies within the polygon.
+    fn is_diagonal(&self, a: usize, b: usize) -> bool {
+        let n = self.arity();
+        let (previous, next) = ((a + n - 1) % n, (a + 1) % n);
+        // Determine if the segment begins in the interior cone of `a`.
+        let is_in_cone = if self.is_left_or_on(a, next, previous) {
+            self.is_left(a, b, previous) && self.is_left(b, a, next)
+        }
+        else {
+            !(self.is_left_or_on(a, b, next) && self.is_left_or_on(b, a, previous))
+        };
+        is_in_cone
+            && (0..n)
+                .map(|c| (c, (c + 1) % n))
+                .filter(|(c, d)| ![a, b].contains(c) && ![a, b].contains(d))
+                .all(|(c, d)| !self.intersects([a, b], [c, d]))
+    }
+
+    fn fan(&self) -> Vec<[usize; 3]> {
+        (1..(self.arity() - 1)).map(|b| [0, b, b + 1]).collect()
+    }
+
+    fn ear_clipping(&self) -> Vec<[usize; 3]> {
+        let mut ring = (0..self.arity()).collect::<Vec<_>>();
+        let mut triangles = Vec::with_capacity(self.arity() - 2);
+        while ring.len() > 3 {
+            let n = ring.len();
+            let corner = |index: usize| {
+                [
+                    ring[(index + n - 1) % n],
+                    ring[index],
+                    ring[(index + 1) % n],
+                ]
+            };
+            // If no ear is found, then the polygon is degenerate. Clip the
+            // leading corner so that triangulation always terminates.
+            let index = (0..n)
+                .find(|index| {
+                    let [a, b, c] = corner(*index);
+                    self.is_left(a, b, c)
+                        && ring
+                            .iter()
+                            .filter(|p| ![a, b, c].contains(p))
+                            .all(|p| !self.contains([a, b, c], *p))
+                })
+                .unwrap_or(0);
+            triangles.push(corner(index));
+            ring.remove(index);
+        }
+        triangles.push([ring[0], ring[1], ring[2]]);
+        triangles
+    }
+
+    // Minimizing the sum of the squared areas of triangles also minimizes the
+    // variance of their areas, because the number of triangles and their total
+    // area are the same for any triangulation of the polygon.
+    fn minimum_area(&self) -> Option<Vec<[usize; 3]>> {
+        let n = self.arity();
+        let is_valid =
+            |a: usize, b: usize| b == a + 1 || (a == 0 && b == n - 1) || self.is_diagonal(a, b);
+        let valid = (0..n)
+            .map(|a| (0..n).map(|b| a < b && is_valid(a, b)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        // The cost and splitting vertex of the optimal triangulation of each
+        // sub-polygon formed by the vertices from `a` to `b`.
+        let mut costs = vec![vec![None::<(Scalar<S>, usize)>; n]; n];
+        for (a, row) in costs.iter_mut().enumerate().take(n - 1) {
+            row[a + 1] = Some((Zero::zero(), a));
+        }
+        for span in 2..n {
+            for a in 0..(n - span) {
+                let b = a + span;
+                if !valid[a][b] {
+                    continue;
+                }
+                costs[a][b] = ((a + 1)..b)
+                    .filter(|c| valid[a][*c] && valid[*c][b] && self.is_left(a, *c, b))
+                    .filter_map(|c| {
+                        let (ac, _) = costs[a][c]?;
+                        let (cb, _) = costs[c][b]?;
+                        let area = self.orientation(a, c, b);
+                        Some((ac + cb + (area * area), c))
+                    })
+                    .fold(None, |min, (cost, c)| match min {
+                        Some((min, d)) if min <= cost => Some((min, d)),
+                        _ => Some((cost, c)),
+                    });
+            }
+        }
+        let mut triangles = Vec::with_capacity(n - 2);
+        let mut spans = vec![(0, n - 1)];
+        while let Some((a, b)) = spans.pop() {
+            if b - a < 2 {
+                continue;
+            }
+            let (_, c) = costs[a][b]?;
+            triangles.push([a, c, b]);
+            spans.push((a, c));
+            spans.push((c, b));
+        }
+        Some(triangles)
+    }
+
+    fn delaunay(&self) -> Vec<[usize; 3]> {
+        let pi = <Scalar<S> as Real>::PI;
+        let mut triangles = self.ear_clipping();
+        // Flip interior edges that are not locally Delaunay until none remain.
+        // The number of passes is bounded to guard against cycles caused by
+        // cocircular vertices and rounding.
+        for _ in 0..(self.arity() * self.arity()) {
+            let mut flipped = false;
+            for i in 0..triangles.len() {
+                for j in (i + 1)..triangles.len() {
+                    if let Some([a, b, c, d]) = shared_edge(triangles[i], triangles[j]) {
+                        let angle = geometry::triangle_angle(
+                            self.positions[c],
+                            self.positions[a],
+                            self.positions[b],
+                        ) + geometry::triangle_angle(
+                            self.positions[d],
+                            self.positions[b],
+                            self.positions[a],
+                        );
+                        if angle > pi && self.is_left(a, d, c) && self.is_left(d, b, c) {
+                            triangles[i] = [a, d, c];
+                            triangles[j] = [d, b, c];
+                            flipped = true;
+                        }
+                    }
+                }
+            }
+            if !flipped {
+                break;
+            }
+        }
+        triangles
+    }
+}
+
+// Gets the vertices `[a, b, c, d]` of two triangles `abc` and `bad` that share
+// the edge `ab`.
+fn shared_edge(abc: [usize; 3], bad: [usize; 3]) -> Option<[usize; 4]> {
+    (0..3).find_map(|i| {
+        let (a, b, c) = (abc[i], abc[(i + 1) % 3], abc[(i + 2) % 3]);
+        (0..3).find_map(|j| {
+            let (x, y, d) = (bad[j], bad[(j + 1) % 3], bad[(j + 2) % 3]);
+            if x == b && y == a {
+                Some([a, b, c, d])
+            }
+            else {
+                None
+            }
+        })
+    })
+}
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Triangulates the graph using the given strategy, tessellating all faces
+    /// into triangles.
+    ///
+    /// Unlike [`triangulate`], this considers the geometry of faces (unless
+    /// the strategy is [`TriangulationStrategy::Fan`]) and so can triangulate
+    /// concave faces. Each face is triangulated independently, so graphs with
+    /// faces of mixed arity are supported. Triangles retain the data of the
+    /// faces from which they are formed.
+    ///
+    /// Returns the number of faces added to the graph. A face with arity $n$
+    /// is replaced by $n-2$ triangles and so adds $n-3$ faces.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a diagonal of a face conflicts with an existing
+    /// edge or with a diagonal of another face. The error is wrapped with the
+    /// key of the face (see [`GraphError::AtKey`]). Diagonals are checked
+    /// before any face is split, so the graph is left unmodified on failure.
+    ///
+    /// # Examples
+    ///
+    /// Triangulating a concave face:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::graph::{MeshGraph, TriangulationStrategy};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::NGon;
+    ///
+    /// // An L-shaped hexagon.
+    /// let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+    ///     vec![NGon([0usize, 1, 2, 3, 4, 5])],
+    ///     vec![
+    ///         (0.0, 0.0, 0.0),
+    ///         (2.0, 0.0, 0.0),
+    ///         (2.0, 1.0, 0.0),
+    ///         (1.0, 1.0, 0.0),
+    ///         (1.0, 2.0, 0.0),
+    ///         (0.0, 2.0, 0.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// let count = graph
+    ///     .triangulate_all(TriangulationStrategy::EarClipping)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(3, count);
+    /// assert!(graph.faces().all(|face| face.normal().unwrap().z > 0.0));
+    /// ```
+    ///
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    /// [`triangulate`]: crate::graph::MeshGraph::triangulate
+    /// [`TriangulationStrategy::Fan`]: crate::graph::TriangulationStrategy::Fan
+    pub fn triangulate_all(&mut self, strategy: TriangulationStrategy) -> Result<usize, GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        // Get the diagonals of the triangulation of each face. Each interior
+        // edge of a triangulation is shared by two triangles, so only the
+        // ordered pair of its indices is kept.
+        let faces = self
+            .faces()
+            .filter(|face| face.arity() > 3)
+            .map(|face| {
+                let keys = face.adjacent_vertices().keys().collect::<Vec<_>>();
+                let positions = face
+                    .adjacent_vertices()
+                    .map(|vertex| *vertex.position())
+                    .collect::<Vec<_>>();
+                let n = keys.len();
+                let diagonals = triangulate(&positions, strategy)
+                    .into_iter()
+                    .flat_map(|[a, b, c]| [(a, b), (b, c), (c, a)])
+                    .filter(|(a, b)| a < b && b - a > 1 && !(*a == 0 && *b == n - 1))
+                    .map(|(a, b)| (keys[a], keys[b]))
+                    .collect::<Vec<_>>();
+                (face.key(), face.get().clone(), diagonals)
+            })
+            .collect::<Vec<_>>();
+        // Reject diagonals that conflict with existing edges or with one
+        // another before splitting any faces, so that the graph is left
+        // unmodified on failure.
+        let mut edges = HashSet::new();
+        for (key, _, diagonals) in faces.iter() {
+            for (a, b) in diagonals.iter() {
+                if self.arc((*a, *b).into()).is_some()
+                    || edges.contains(&(*b, *a))
+                    || !edges.insert((*a, *b))
+                {
+                    return Err(GraphError::TopologyConflict.at_key(*key));
+                }
+            }
+        }
+        // Split each face along its diagonals. Diagonals do not cross, so each
+        // diagonal splits exactly one of the faces formed from the original
+        // face.
+        let mut count = 0;
+        for (key, data, diagonals) in faces {
+            let mut keys = vec![key];
+            for (a, b) in diagonals {
+                let index = keys
+                    .iter()
+                    .position(|key| {
+                        let face = self.face(*key).expect_consistent();
+                        let mut vertices = face.adjacent_vertices().keys();
+                        vertices.clone().any(|key| key == a) && vertices.any(|key| key == b)
+                    })
+                    .expect_consistent();
+                let arc = self
+                    .face_mut(keys[index])
+                    .expect_consistent()
+                    .split(ByKey(a), ByKey(b))
+                    .expect_consistent()
+                    .into_ref();
+                keys[index] = arc.face().expect_consistent().key();
+                keys.push(arc.opposite_arc().face().expect_consistent().key());
+                count += 1;
+            }
+            // Splitting faces does not retain their data.
+            for key in keys {
+                *self.face_mut(key).expect_consistent().get_mut() = data.clone();
+            }
+        }
+        Ok(count)
+    }
+
+    /// Merges pairs of adjacent triangles into quadrilaterals.
+    ///
+    /// Each edge between two triangles is a candidate for a merge. Candidates
+    /// are scored by how planar and how rectangular the resulting
+    /// quadrilateral would be and are greedily merged from best to worst such
+    /// that each triangle is merged at most once. Returns the number of
+    /// quadrilaterals formed.
+    ///
+    /// Planarity is measured as the angle between the normals of the
+    /// triangles and rectangularity is measured as the largest deviation of
+    /// an interior angle of the quadrilateral from a right angle. Both
+    /// thresholds are in radians and candidates that exceed either threshold
+    /// are not merged. Concave quadrilaterals are never formed for thresholds
+    /// less than $\frac{\pi}{2}$. Triangles that cannot be paired are left
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+    /// graph.triangulate();
+    ///
+    /// assert_eq!(6, graph.pair_triangles_into_quads(0.01, 0.1));
+    /// assert_eq!(6, graph.face_count());
+    /// ```
+    pub fn pair_triangles_into_quads<T>(&mut self, planarity: T, angle: T) -> usize
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: FaceNormal,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let planarity = planarity.into();
+        let angle = angle.into();
+        let right = <Scalar<VertexPosition<G>> as Real>::PI
+            / (Scalar::<VertexPosition<G>>::one() + One::one());
+        let deviation = |theta: Scalar<VertexPosition<G>>| {
+            if theta < right {
+                right - theta
+            }
+            else {
+                theta - right
+            }
+        };
+        let mut candidates = self
+            .edges()
+            .filter_map(|edge| {
+                let ab = edge.arc();
+                let ba = ab.opposite_arc();
+                let (abc, bad) = (ab.face()?, ba.face()?);
+                if abc.arity() != 3 || bad.arity() != 3 || abc.key() == bad.key() {
+                    return None;
+                }
+                let (bc, ad) = (ab.next_arc(), ba.next_arc());
+                let (c, d) = (bc.destination_vertex(), ad.destination_vertex());
+                if c.key() == d.key() {
+                    return None;
+                }
+                let fold = geometry::vector_angle::<VertexPosition<G>>(
+                    abc.normal().ok()?,
+                    bad.normal().ok()?,
+                );
+                if fold > planarity {
+                    return None;
+                }
+                // The quadrilateral is formed by the ring `cadb`. The interior
+                // angles at `a` and `b` span both triangles.
+                let (a, b) = (
+                    *ab.source_vertex().position(),
+                    *ab.destination_vertex().position(),
+                );
+                let (c, d) = (*c.position(), *d.position());
+                let corners = [
+                    geometry::triangle_angle(c, a, b),
+                    geometry::triangle_angle(a, c, b) + geometry::triangle_angle(a, b, d),
+                    geometry::triangle_angle(d, b, a),
+                    geometry::triangle_angle(b, d, a) + geometry::triangle_angle(b, a, c),
+                ];
+                let skew = corners
+                    .into_iter()
+                    .map(deviation)
+                    .fold(Zero::zero(), |max, skew| {
+                        if skew > max {
+                            skew
+                        }
+                        else {
+                            max
+                        }
+                    });
+                if skew > angle {
+                    return None;
+                }
+                Some((fold + skew, abc.key(), bad.key()))
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by(|(a, ..), (b, ..)| a.partial_cmp(b).unwrap_or(cmp::Ordering::Equal));
+        let mut merged = HashSet::with_capacity(self.face_count());
+        let mut count = 0;
+        for (_, abc, bad) in candidates {
+            if merged.contains(&abc) || merged.contains(&bad) {
+                continue;
+            }
+            if self
+                .face_mut(abc)
+                .expect_consistent()
+                .merge(ByKey(bad))
+                .is_ok()
+            {
+                merged.insert(abc);
+                merged.insert(bad);
+                count += 1;
+            }
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::graph::{GraphError, MeshGraph, TriangulationStrategy};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+    use crate::primitive::{NGon, UnboundedPolygon};
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn triangulate_all() {
+        // A concave pentagon with a reflex vertex at `3` that is adjacent to a
+        // quadrilateral and a triangle.
+        let graph = || {
+            MeshGraph::<Point3<f64>>::from_raw_buffers(
+                vec![
+                    UnboundedPolygon::from(NGon([0usize, 1, 2, 3, 4])),
+                    UnboundedPolygon::from(NGon([6, 5, 1, 0])),
+                    UnboundedPolygon::from(NGon([1, 5, 7])),
+                ],
+                vec![
+                    (0.0, 0.0, 0.0),
+                    (4.0, 0.0, 0.0),
+                    (4.0, 4.0, 0.0),
+                    (2.0, 1.0, 0.0),
+                    (0.0, 4.0, 0.0),
+                    (4.0, -2.0, 0.0),
+                    (0.0, -2.0, 0.0),
+                    (6.0, 0.0, 0.0),
+                ],
+            )
+            .unwrap()
+        };
+
+        for strategy in [
+            TriangulationStrategy::EarClipping,
+            TriangulationStrategy::MinimumArea,
+            TriangulationStrategy::Delaunay2D,
+        ] {
+            let mut graph = graph();
+            assert_eq!(3, graph.triangulate_all(strategy).unwrap());
+
+            assert_eq!(6, graph.face_count());
+            assert!(graph.faces().all(|face| face.arity() == 3));
+            assert!(graph.faces().all(|face| face.normal().unwrap().z > 0.0));
+            assert!((graph.surface_area() - 20.0).abs() < 1e-9);
+        }
+
+        // Fanning ignores geometry and inverts a triangle in the concave face.
+        let mut graph = graph();
+        assert_eq!(
+            3,
+            graph.triangulate_all(TriangulationStrategy::Fan).unwrap()
+        );
+        assert!(graph.faces().any(|face| face.normal().unwrap().z < 0.0));
+    }
+
+    #[test]
+    fn triangulate_all_delaunay() {
+        // A thin rhombus. Ear clipping forms either diagonal depending on the
+        // leading vertex of the face, so the rhombus is built with each of its
+        // vertices leading.
+        let build = |n: usize| {
+            let mut indices = [0usize, 1, 2, 3];
+            indices.rotate_left(n);
+            MeshGraph::<Point3<f64>>::from_raw_buffers(
+                vec![NGon(indices)],
+                vec![
+                    (4.0, -1.0, 0.0),
+                    (8.0, 0.0, 0.0),
+                    (4.0, 1.0, 0.0),
+                    (0.0, 0.0, 0.0),
+                ],
+            )
+            .unwrap()
+        };
+
+        let mut is_long = false;
+        for n in 0..4 {
+            let mut graph = build(n);
+            graph
+                .triangulate_all(TriangulationStrategy::EarClipping)
+                .unwrap();
+            is_long |= graph.edges().any(|edge| edge.length() > 5.0);
+        }
+        assert!(is_long);
+
+        // The Delaunay triangulation maximizes the minimum angle and so always
+        // forms the short diagonal.
+        for n in 0..4 {
+            let mut graph = build(n);
+            graph
+                .triangulate_all(TriangulationStrategy::Delaunay2D)
+                .unwrap();
+            assert!(graph.edges().all(|edge| edge.length() < 5.0));
+        }
+    }
+
+    #[test]
+    fn triangulate_all_conflict() {
+        // A concave quadrilateral with a reflex vertex at `0` and a triangle
+        // that joins `0` and `2`, so the only diagonal of the quadrilateral is
+        // already an edge.
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![
+                UnboundedPolygon::from(NGon([0usize, 1, 2, 3])),
+                UnboundedPolygon::from(NGon([0, 3, 2])),
+            ],
+            vec![
+                (1.0, 2.0, 0.0),
+                (0.0, 0.0, 0.0),
+                (4.0, 2.0, 0.0),
+                (0.0, 4.0, 1.0),
+            ],
+        )
+        .unwrap();
+        let face = graph.faces().find(|face| face.arity() == 4).unwrap().key();
+
+        assert_eq!(
+            Err(GraphError::TopologyConflict.at_key(face)),
+            graph.triangulate_all(TriangulationStrategy::EarClipping),
+        );
+        assert_eq!(2, graph.face_count());
+        assert_eq!(5, graph.edge_count());
+        assert_eq!(4, graph.face(face).unwrap().arity());
+    }
+
+    #[test]
+    fn pair_triangles_into_quads() {
+        // The faces of a triangulated cube are paired back into its planar
+        // quadrilaterals. Edges of the cube are folded and are never merged.
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        graph.triangulate();
+        assert_eq!(12, graph.face_count());
+        assert_eq!(6, graph.pair_triangles_into_quads(0.01, 0.1));
+        assert_eq!(6, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 4));
+        assert!((graph.surface_area() - 6.0).abs() < 1e-9);
+
+        // Pairing is greedy and respects the planarity threshold.
+        let mut graph: MeshGraph<Point3<f64>> =
+            UvSphere::new(16, 8).polygons::<Position<E3>>().collect();
+        graph.triangulate();
+        let n = graph.face_count();
+        let count = graph.pair_triangles_into_quads(0.05, 0.8);
+        assert!(count > 0);
+        assert_eq!(n - count, graph.face_count());
+        assert_eq!(
+            2,
+            (graph.vertex_count() + graph.face_count()) as isize - graph.edge_count() as isize
+        );
+        for face in graph.faces() {
+            let positions = face
+                .adjacent_vertices()
+                .map(|vertex| *vertex.position())
+                .collect::<Vec<_>>();
+            match *positions.as_slice() {
+                [_, _, _] => {}
+                [a, b, c, d] => {
+                    let fold = |[a, b, c, d]: [Point3<f64>; 4]| {
+                        let n = (b - a).cross(&(c - a));
+                        let m = (c - a).cross(&(d - a));
+                        n.angle(&m)
+                    };
+                    assert!(fold([a, b, c, d]).min(fold([b, c, d, a])) <= 0.05 + 1e-9);
+                }
+                _ => panic!(),
+            }
+        }
+        for vertex in graph.vertices() {
+            assert!(vertex.outgoing_arcs().all(|arc| arc.face().is_some()));
+        }
+    }
+}
@@ -1,30 +1,34 @@
+use decorum::Real;
 use derivative::Derivative;
+use num::Zero;
 use smallvec::SmallVec;
 use std::borrow::Borrow;
 use std::hash::{Hash, Hasher};
+use std::iter;
 use std::ops::{Deref, DerefMut};
-use theon::space::Vector;
+use theon::space::{EuclideanSpace, Scalar, Vector};
 use theon::AsPosition;
 
 use crate::entity::borrow::{Reborrow, ReborrowInto, ReborrowMut};
 use crate::entity::dijkstra;
 use crate::entity::storage::prelude::*;
 use crate::entity::storage::{
-    AsStorage, AsStorageMut, AsStorageOf, HashStorage, IncrementalKeyer, Key,
+    AsStorage, AsStorageMut, AsStorageOf, HashStorage, IncrementalKeyer, Key, KeyIndex,
 };
 use crate::entity::traverse::{Adjacency, Breadth, Depth, Trace, TraceAny, TraceFirst, Traversal};
 use crate::entity::view::{ClosedView, Orphan, Rebind, Unbind, View};
-use crate::entity::{Entity, Payload};
+use crate::entity::{Entity, EntityError, Payload};
 use crate::geometry::Metric;
-use crate::graph::data::{Data, GraphData, Parametric};
+use crate::graph::data::{Data, GraphData, Index, Parametric};
 use crate::graph::edge::{Arc, ArcKey, ArcOrphan, ArcView, Edge};
 use crate::graph::face::{Face, FaceOrphan, FaceView};
-use crate::graph::geometry::{VertexCentroid, VertexNormal, VertexPosition};
+use crate::graph::geometry::{self, VertexCentroid, VertexNormal, VertexPosition};
 use crate::graph::mutation::vertex::{self, VertexRemoveCache};
 use crate::graph::mutation::{self, Consistent, Immediate, Mutable};
 use crate::graph::path::Path;
 use crate::graph::{
-    Circulator, GraphError, OptionExt as _, OrphanCirculator, ResultExt as _, ViewCirculator,
+    Circulator, GraphError, OptionExt as _, OrphanCirculator, ResultExt as _, TopologyKind,
+    ViewCirculator,
 };
 use crate::transact::{BypassOrCommit, Mutate};
 use crate::IteratorExt as _;
@@ -34,39 +38,44 @@ type Mutation<M> = mutation::Mutation<Immediate<M>>;
 /// Vertex entity.
 #[derive(Derivative)]
 #[derivative(Debug, Hash)]
-pub struct Vertex<G>
+pub struct Vertex<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     /// User data.
     #[derivative(Debug = "ignore", Hash = "ignore")]
     pub(crate) data: G::Vertex,
     /// Required key into the leading arc.
-    pub(crate) arc: Option<ArcKey>,
+    pub(crate) arc: Option<ArcKey<K>>,
 }
 
-impl<G> Vertex<G>
+impl<G, K> Vertex<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn new(data: G::Vertex) -> Self {
         Vertex { data, arc: None }
     }
 }
 
-impl<G> Entity for Vertex<G>
+impl<G, K> Entity for Vertex<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    type Key = VertexKey;
-    type Storage = HashStorage<Self, IncrementalKeyer>;
+    type Key = VertexKey<K>;
+    type Storage = HashStorage<Self, IncrementalKeyer<K>>;
 }
 
-impl<G> Payload for Vertex<G>
+impl<G, K> Payload for Vertex<G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     type Data = G::Vertex;
+    type Links = ();
 
     fn get(&self) -> &Self::Data {
         &self.data
@@ -75,14 +84,19 @@ where
     fn get_mut(&mut self) -> &mut Self::Data {
         &mut self.data
     }
+
+    fn links(&self) -> Self::Links {}
 }
 
 /// Vertex key.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
-pub struct VertexKey(u64);
+pub struct VertexKey<K = u64>(K);
 
-impl Key for VertexKey {
-    type Inner = u64;
+impl<K> Key for VertexKey<K>
+where
+    K: KeyIndex,
+{
+    type Inner = K;
 
     fn from_inner(key: Self::Inner) -> Self {
         VertexKey(key)
@@ -101,15 +115,15 @@ impl Key for VertexKey {
 pub struct VertexView<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Vertex<Data<B>>> + Parametric,
+    B::Target: AsStorage<Vertex<Data<B>, Index<B>>> + Parametric,
 {
-    inner: View<B, Vertex<Data<B>>>,
+    inner: View<B, Vertex<Data<B>, Index<B>>>,
 }
 
 impl<B, M> VertexView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Vertex<Data<B>>> + Parametric,
+    M: AsStorage<Vertex<Data<B>, Index<B>>> + Parametric,
 {
     pub fn to_ref(&self) -> VertexView<&M> {
         self.inner.to_ref().into()
@@ -119,7 +133,7 @@ where
 impl<B, M> VertexView<B>
 where
     B: ReborrowMut<Target = M>,
-    M: AsStorage<Vertex<Data<B>>> + Parametric,
+    M: AsStorage<Vertex<Data<B>, Index<B>>> + Parametric,
 {
     #[allow(clippy::wrong_self_convention)]
     fn to_mut_unchecked(&mut self) -> VertexView<&mut M> {
@@ -127,11 +141,12 @@ where
     }
 }
 
-impl<'a, B, M, G> VertexView<B>
+impl<'a, B, M, G, K> VertexView<B>
 where
     B: ReborrowInto<'a, Target = M>,
-    M: AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     // TODO: Relocate this documentation of `into_ref`.
     /// # Examples
@@ -152,7 +167,12 @@ where
     ///
     /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
     /// let key = graph.arcs().nth(0).unwrap().key();
-    /// let vertex = graph.arc_mut(key).unwrap().split_at_midpoint().into_ref();
+    /// let vertex = graph
+    ///     .arc_mut(key)
+    ///     .unwrap()
+    ///     .split_at_midpoint()
+    ///     .unwrap()
+    ///     .into_ref();
     ///
     /// // This would not be possible without conversion into an immutable view.
     /// let _ = vertex.into_outgoing_arc().into_face().unwrap();
@@ -167,11 +187,12 @@ where
     }
 }
 
-impl<B, M, G> VertexView<B>
+impl<B, M, G, K> VertexView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn get<'a>(&'a self) -> &'a G::Vertex
     where
@@ -189,11 +210,12 @@ where
     }
 }
 
-impl<B, M, G> VertexView<B>
+impl<B, M, G, K> VertexView<B>
 where
     B: ReborrowMut<Target = M>,
-    M: AsStorageMut<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorageMut<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn get_mut<'a>(&'a mut self) -> &'a mut G::Vertex
     where
@@ -204,11 +226,12 @@ where
 }
 
 /// Reachable API.
-impl<B, M, G> VertexView<B>
+impl<B, M, G, K> VertexView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub(in crate::graph) fn into_reachable_outgoing_arc(self) -> Option<ArcView<B>> {
         let key = self.arc;
@@ -216,11 +239,15 @@ where
     }
 }
 
-impl<B, M, G> VertexView<B>
+impl<B, M, G, K> VertexView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Converts the vertex into its outgoing (leading) arc.
     pub fn into_outgoing_arc(self) -> ArcView<B> {
@@ -232,17 +259,17 @@ where
         self.to_ref().into_outgoing_arc()
     }
 
-    pub fn shortest_path(&self, key: VertexKey) -> Result<Path<'static, &M>, GraphError> {
+    pub fn shortest_path(&self, key: VertexKey<K>) -> Result<Path<'static, &M>, GraphError> {
         self.to_ref().into_shortest_path(key)
     }
 
-    pub fn into_shortest_path(self, key: VertexKey) -> Result<Path<'static, B>, GraphError> {
+    pub fn into_shortest_path(self, key: VertexKey<K>) -> Result<Path<'static, B>, GraphError> {
         self.into_shortest_path_with(key, |_, _| 1usize)
     }
 
     pub fn shortest_path_with<Q, F>(
         &self,
-        key: VertexKey,
+        key: VertexKey<K>,
         f: F,
     ) -> Result<Path<'static, &M>, GraphError>
     where
@@ -254,14 +281,19 @@ where
 
     pub fn into_shortest_path_with<Q, F>(
         self,
-        mut key: VertexKey,
+        mut key: VertexKey<K>,
         f: F,
     ) -> Result<Path<'static, B>, GraphError>
     where
         Q: Copy + Metric,
         F: Fn(VertexView<&M>, VertexView<&M>) -> Q,
     {
-        let metrics = dijkstra::metrics_with(self.to_ref(), Some(key), f)?;
+        let metrics =
+            dijkstra::metrics_with(self.to_ref(), Some(key), f).map_err(|error| match error {
+                EntityError::EntityNotFound => GraphError::TopologyNotFound(TopologyKind::Vertex),
+                EntityError::Data => GraphError::Geometry,
+                EntityError::KeyspaceExhausted => GraphError::KeyspaceExhausted,
+            })?;
         let mut keys = vec![key];
         while let Some((Some(previous), _)) = metrics.get(&key) {
             key = *previous;
@@ -290,17 +322,53 @@ where
     {
         <G as VertexCentroid>::centroid(self.to_ref()).expect_consistent()
     }
+
+    /// Gets the angle defect of the vertex.
+    ///
+    /// The _angle defect_ of a vertex is a discrete analog of Gaussian
+    /// curvature. It is $2\pi$ less the sum of the interior angles at the
+    /// vertex of its adjacent faces. For boundary vertices, it is $\pi$ less
+    /// this sum instead. Vertices in flat regions of a surface have no angle
+    /// defect.
+    ///
+    /// See [`MeshGraph::verify_gauss_bonnet`].
+    ///
+    /// [`MeshGraph::verify_gauss_bonnet`]: crate::graph::MeshGraph::verify_gauss_bonnet
+    pub fn angle_defect(&self) -> Scalar<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let pi = <Scalar<VertexPosition<G>> as Real>::PI;
+        let position = *self.position();
+        let angle = self
+            .incoming_arcs()
+            .filter(|arc| !arc.is_boundary_arc())
+            .map(|arc| {
+                let a = *arc.source_vertex().position();
+                let b = *arc.next_arc().destination_vertex().position();
+                geometry::triangle_angle(position, a, b)
+            })
+            .fold(Zero::zero(), |sum, angle| sum + angle);
+        if self.is_boundary_vertex() {
+            pi - angle
+        }
+        else {
+            pi + pi - angle
+        }
+    }
 }
 
-impl<B, M, G> VertexView<B>
+impl<B, M, G, K> VertexView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>>
-        + AsStorage<Face<G>>
-        + AsStorage<Vertex<G>>
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Face<G, K>>
+        + AsStorage<Vertex<G, K>>
         + Consistent
-        + Parametric<Data = G>,
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn normal(&self) -> Result<Vector<VertexPosition<G>>, GraphError>
     where
@@ -311,54 +379,16 @@ where
     }
 }
 
-/// Reachable API.
-impl<'a, B, M, G> VertexView<B>
-where
-    B: ReborrowInto<'a, Target = M>,
-    M: 'a + AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Parametric<Data = G>,
-    G: GraphData,
-{
-    pub(in crate::graph) fn into_reachable_incoming_arcs(
-        self,
-    ) -> impl Clone + Iterator<Item = ArcView<&'a M>> {
-        // This reachable circulator is needed for face insertions.
-        ArcCirculator::<TraceAny<_>, _>::from(self.into_ref())
-    }
-
-    pub(in crate::graph) fn into_reachable_outgoing_arcs(
-        self,
-    ) -> impl Clone + Iterator<Item = ArcView<&'a M>> {
-        // This reachable circulator is needed for face insertions.
-        self.into_reachable_incoming_arcs()
-            .flat_map(|arc| arc.into_reachable_opposite_arc())
-    }
-}
-
-/// Reachable API.
-impl<B, G> VertexView<B>
-where
-    B: Reborrow,
-    B::Target: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Parametric<Data = G>,
-    G: GraphData,
-{
-    pub(in crate::graph) fn reachable_incoming_arcs(
-        &self,
-    ) -> impl Clone + Iterator<Item = ArcView<&B::Target>> {
-        self.to_ref().into_reachable_incoming_arcs()
-    }
-
-    pub(in crate::graph) fn reachable_outgoing_arcs(
-        &self,
-    ) -> impl Clone + Iterator<Item = ArcView<&B::Target>> {
-        self.to_ref().into_reachable_outgoing_arcs()
-    }
-}
-
-impl<'a, B, M, G> VertexView<B>
+impl<'a, B, M, G, K> VertexView<B>
 where
     B: ReborrowInto<'a, Target = M>,
-    M: 'a + AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    M: 'a
+        + AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn into_adjacent_vertices(self) -> impl Clone + Iterator<Item = VertexView<&'a M>> {
         VertexCirculator::from(ArcCirculator::<TraceFirst<_>, _>::from(self.into_ref()))
@@ -371,18 +401,49 @@ where
     pub fn into_outgoing_arcs(self) -> impl Clone + Iterator<Item = ArcView<&'a M>> {
         self.into_incoming_arcs().map(|arc| arc.into_opposite_arc())
     }
+
+    pub fn into_arcs(self) -> impl Clone + Iterator<Item = ArcView<&'a M>> {
+        let vertex = self.into_ref();
+        // Begin at the arc that follows the gap in an open fan (if any), so
+        // that the arcs of a boundary vertex are yielded as a path from one
+        // boundary edge to the other.
+        let first = vertex
+            .into_outgoing_arcs()
+            .find(|arc| arc.opposite_arc().is_boundary_arc())
+            .or_else(|| vertex.into_outgoing_arcs().next());
+        let key = first.map(|arc| arc.key());
+        iter::successors(first, move |arc| {
+            let arc = arc.into_previous_arc().into_opposite_arc();
+            (Some(arc.key()) != key).then_some(arc)
+        })
+    }
 }
 
-impl<B, G> VertexView<B>
+impl<B, G, K> VertexView<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    B::Target: AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn adjacent_vertices(&self) -> impl Clone + Iterator<Item = VertexView<&B::Target>> {
         self.to_ref().into_adjacent_vertices()
     }
 
+    /// Returns `true` if this is a boundary vertex.
+    ///
+    /// A boundary vertex is connected to at least one boundary arc. See
+    /// [`ArcView::is_boundary_arc`].
+    ///
+    /// [`ArcView::is_boundary_arc`]: crate::graph::ArcView::is_boundary_arc
+    pub fn is_boundary_vertex(&self) -> bool {
+        self.incoming_arcs()
+            .any(|arc| arc.is_boundary_arc() || arc.opposite_arc().is_boundary_arc())
+    }
+
     /// Gets an iterator of views over the incoming arcs of the vertex.
     ///
     /// The ordering of arcs is deterministic and is based on the leading arc of
@@ -399,6 +460,25 @@ where
         self.to_ref().into_outgoing_arcs()
     }
 
+    /// Gets an iterator of views over the outgoing arcs of the vertex in
+    /// cyclic order.
+    ///
+    /// Unlike [`outgoing_arcs`], the ordering of arcs is geometric: arcs are
+    /// yielded in counter-clockwise order about the vertex when viewed from
+    /// outside of the surface, assuming that faces are wound consistently
+    /// such that their normals face outward. Each consecutive pair of arcs
+    /// bounds a face, except for the last and first arcs of a boundary vertex.
+    ///
+    /// The arcs of a boundary vertex form an open fan. In that case, the first
+    /// arc is the outgoing arc whose opposite arc is a boundary arc and the
+    /// last arc is the outgoing boundary arc, so that the fan is traversed
+    /// from one boundary edge to the other.
+    ///
+    /// [`outgoing_arcs`]: crate::graph::VertexView::outgoing_arcs
+    pub fn arcs(&self) -> impl Clone + Iterator<Item = ArcView<&B::Target>> {
+        self.to_ref().into_arcs()
+    }
+
     /// Gets an iterator that traverses adjacent vertices by breadth.
     ///
     /// The traversal moves from the vertex to its adjacent vertices and so on.
@@ -418,31 +498,33 @@ where
     }
 }
 
-impl<'a, B, M, G> VertexView<B>
+impl<'a, B, M, G, K> VertexView<B>
 where
     B: ReborrowInto<'a, Target = M>,
     M: 'a
-        + AsStorage<Arc<G>>
-        + AsStorage<Face<G>>
-        + AsStorage<Vertex<G>>
+        + AsStorage<Arc<G, K>>
+        + AsStorage<Face<G, K>>
+        + AsStorage<Vertex<G, K>>
         + Consistent
-        + Parametric<Data = G>,
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn into_adjacent_faces(self) -> impl Clone + Iterator<Item = FaceView<&'a M>> {
         FaceCirculator::from(ArcCirculator::<TraceFirst<_>, _>::from(self.into_ref()))
     }
 }
 
-impl<B, G> VertexView<B>
+impl<B, G, K> VertexView<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Arc<G>>
-        + AsStorage<Face<G>>
-        + AsStorage<Vertex<G>>
+    B::Target: AsStorage<Arc<G, K>>
+        + AsStorage<Face<G, K>>
+        + AsStorage<Vertex<G, K>>
         + Consistent
-        + Parametric<Data = G>,
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     /// Gets an iterator of views over the adjacent faces of the vertex.
     ///
@@ -455,9 +537,14 @@ where
 
 impl<'a, M> VertexView<&'a mut M>
 where
-    M: AsStorage<Arc<M::Data>> + AsStorageMut<Vertex<M::Data>> + Consistent + Parametric,
+    M: AsStorage<Arc<M::Data, M::Index>>
+        + AsStorageMut<Vertex<M::Data, M::Index>>
+        + Consistent
+        + Parametric,
 {
-    pub fn into_adjacent_vertex_orphans(self) -> impl Iterator<Item = VertexOrphan<'a, M::Data>> {
+    pub fn into_adjacent_vertex_orphans(
+        self,
+    ) -> impl Iterator<Item = VertexOrphan<'a, M::Data, M::Index>> {
         VertexCirculator::from(ArcCirculator::<TraceFirst<_>, _>::from(self))
     }
 }
@@ -465,18 +552,28 @@ where
 impl<B> VertexView<B>
 where
     B: ReborrowMut,
-    B::Target: AsStorage<Arc<Data<B>>> + AsStorageMut<Vertex<Data<B>>> + Consistent + Parametric,
+    B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+        + AsStorageMut<Vertex<Data<B>, Index<B>>>
+        + Consistent
+        + Parametric,
 {
-    pub fn adjacent_vertex_orphans(&mut self) -> impl Iterator<Item = VertexOrphan<Data<B>>> {
+    pub fn adjacent_vertex_orphans(
+        &mut self,
+    ) -> impl Iterator<Item = VertexOrphan<Data<B>, Index<B>>> {
         self.to_mut_unchecked().into_adjacent_vertex_orphans()
     }
 }
 
 impl<'a, M> VertexView<&'a mut M>
 where
-    M: AsStorageMut<Arc<M::Data>> + AsStorage<Vertex<M::Data>> + Consistent + Parametric,
+    M: AsStorageMut<Arc<M::Data, M::Index>>
+        + AsStorage<Vertex<M::Data, M::Index>>
+        + Consistent
+        + Parametric,
 {
-    pub fn into_incoming_arc_orphans(self) -> impl Iterator<Item = ArcOrphan<'a, M::Data>> {
+    pub fn into_incoming_arc_orphans(
+        self,
+    ) -> impl Iterator<Item = ArcOrphan<'a, M::Data, M::Index>> {
         ArcCirculator::<TraceFirst<_>, _>::from(self)
     }
 }
@@ -484,26 +581,31 @@ where
 impl<B> VertexView<B>
 where
     B: ReborrowMut,
-    B::Target: AsStorageMut<Arc<Data<B>>> + AsStorage<Vertex<Data<B>>> + Consistent + Parametric,
+    B::Target: AsStorageMut<Arc<Data<B>, Index<B>>>
+        + AsStorage<Vertex<Data<B>, Index<B>>>
+        + Consistent
+        + Parametric,
 {
     /// Gets an iterator of orphan views over the incoming arcs of the vertex.
     ///
     /// The ordering of arcs is deterministic and is based on the leading arc of
     /// the vertex.
-    pub fn incoming_arc_orphans(&mut self) -> impl Iterator<Item = ArcOrphan<Data<B>>> {
+    pub fn incoming_arc_orphans(&mut self) -> impl Iterator<Item = ArcOrphan<Data<B>, Index<B>>> {
         self.to_mut_unchecked().into_incoming_arc_orphans()
     }
 }
 
 impl<'a, M> VertexView<&'a mut M>
 where
-    M: AsStorage<Arc<M::Data>>
-        + AsStorageMut<Face<M::Data>>
-        + AsStorage<Vertex<M::Data>>
+    M: AsStorage<Arc<M::Data, M::Index>>
+        + AsStorageMut<Face<M::Data, M::Index>>
+        + AsStorage<Vertex<M::Data, M::Index>>
         + Consistent
         + Parametric,
 {
-    pub fn into_adjacent_face_orphans(self) -> impl Iterator<Item = FaceOrphan<'a, M::Data>> {
+    pub fn into_adjacent_face_orphans(
+        self,
+    ) -> impl Iterator<Item = FaceOrphan<'a, M::Data, M::Index>> {
         FaceCirculator::from(ArcCirculator::<TraceFirst<_>, _>::from(self))
     }
 }
@@ -511,9 +613,9 @@ where
 impl<B> VertexView<B>
 where
     B: ReborrowMut,
-    B::Target: AsStorage<Arc<Data<B>>>
-        + AsStorageMut<Face<Data<B>>>
-        + AsStorage<Vertex<Data<B>>>
+    B::Target: AsStorage<Arc<Data<B>, Index<B>>>
+        + AsStorageMut<Face<Data<B>, Index<B>>>
+        + AsStorage<Vertex<Data<B>, Index<B>>>
         + Consistent
         + Parametric,
 {
@@ -521,37 +623,41 @@ where
     ///
     /// The ordering of faces is deterministic and is based on the leading arc
     /// of the vertex.
-    pub fn adjacent_face_orphans(&mut self) -> impl Iterator<Item = FaceOrphan<Data<B>>> {
+    pub fn adjacent_face_orphans(&mut self) -> impl Iterator<Item = FaceOrphan<Data<B>, Index<B>>> {
         self.to_mut_unchecked().into_adjacent_face_orphans()
     }
 }
 
-impl<'a, M, G> VertexView<&'a mut M>
+impl<'a, M, G, K> VertexView<&'a mut M>
 where
-    M: AsStorage<Arc<G>>
-        + AsStorage<Edge<G>>
-        + AsStorage<Face<G>>
-        + AsStorage<Vertex<G>>
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Edge<G, K>>
+        + AsStorage<Face<G, K>>
+        + AsStorage<Vertex<G, K>>
         + Default
-        + Mutable<Data = G>,
+        + Mutable<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    // TODO: This is not yet implemented, so examples use `no_run`. Run these
-    //       examples in doc tests once this no longer intentionally panics.
     /// Removes the vertex.
     ///
     /// Any and all dependent entities are also removed, such as arcs and edges
     /// connected to the vertex, faces connected to such arcs, vertices with no
-    /// remaining leading arc, etc.
+    /// remaining leading arc, etc. The remaining arcs that bounded the faces of
+    /// the vertex form a boundary about the hole left by its removal.
     ///
     /// Vertex removal is the most destructive removal, because vertices are a
     /// dependency of all other entities.
     ///
+    /// A consistent graph never contains isolated vertices (vertices with no
+    /// leading arc), so removing topology always removes the vertices that no
+    /// longer participate in any edges.
+    ///
     /// # Examples
     ///
     /// Removing a corner from a cube by removing its vertex:
     ///
-    /// ```rust,no_run
+    /// ```rust
     /// # extern crate decorum;
     /// # extern crate nalgebra;
     /// # extern crate plexus;
@@ -568,6 +674,9 @@ where
     /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
     /// let key = graph.vertices().nth(0).unwrap().key();
     /// graph.vertex_mut(key).unwrap().remove();
+    ///
+    /// assert_eq!(7, graph.vertex_count());
+    /// assert_eq!(3, graph.face_count());
     /// ```
     pub fn remove(self) {
         // This should never fail here.
@@ -581,11 +690,15 @@ where
     }
 }
 
-impl<B, M, G> Adjacency for VertexView<B>
+impl<B, M, G, K> Adjacency for VertexView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>>
+        + AsStorage<Vertex<G, K>>
+        + Consistent
+        + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     type Output = SmallVec<[Self::Key; 8]>;
 
@@ -594,22 +707,23 @@ where
     }
 }
 
-impl<B> Borrow<VertexKey> for VertexView<B>
+impl<B> Borrow<VertexKey<Index<B>>> for VertexView<B>
 where
     B: Reborrow,
-    B::Target: AsStorage<Vertex<Data<B>>> + Parametric,
+    B::Target: AsStorage<Vertex<Data<B>, Index<B>>> + Parametric,
 {
-    fn borrow(&self) -> &VertexKey {
+    fn borrow(&self) -> &VertexKey<Index<B>> {
         self.inner.as_ref()
     }
 }
 
-impl<B, M, G> Clone for VertexView<B>
+impl<B, M, G, K> Clone for VertexView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
-    View<B, Vertex<G>>: Clone,
+    K: KeyIndex,
+    View<B, Vertex<G, K>>: Clone,
 {
     fn clone(&self) -> Self {
         VertexView {
@@ -618,77 +732,84 @@ where
     }
 }
 
-impl<B, M, G> ClosedView for VertexView<B>
+impl<B, M, G, K> ClosedView for VertexView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Key = VertexKey;
-    type Entity = Vertex<G>;
+    type Key = VertexKey<K>;
+    type Entity = Vertex<G, K>;
 
     fn key(&self) -> Self::Key {
         self.inner.key()
     }
 }
 
-impl<B, M, G> Copy for VertexView<B>
+impl<B, M, G, K> Copy for VertexView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
-    View<B, Vertex<G>>: Copy,
+    K: KeyIndex,
+    View<B, Vertex<G, K>>: Copy,
 {
 }
 
-impl<B, M, G> Deref for VertexView<B>
+impl<B, M, G, K> Deref for VertexView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Target = Vertex<G>;
+    type Target = Vertex<G, K>;
 
     fn deref(&self) -> &Self::Target {
         self.inner.deref()
     }
 }
 
-impl<B, M, G> DerefMut for VertexView<B>
+impl<B, M, G, K> DerefMut for VertexView<B>
 where
     B: ReborrowMut<Target = M>,
-    M: AsStorageMut<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorageMut<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.inner.deref_mut()
     }
 }
 
-impl<B, M, G> Eq for VertexView<B>
+impl<B, M, G, K> Eq for VertexView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
 }
 
-impl<B, M, G> From<View<B, Vertex<G>>> for VertexView<B>
+impl<B, M, G, K> From<View<B, Vertex<G, K>>> for VertexView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    fn from(view: View<B, Vertex<G>>) -> Self {
+    fn from(view: View<B, Vertex<G, K>>) -> Self {
         VertexView { inner: view }
     }
 }
 
-impl<B, M, G> From<VertexView<B>> for View<B, Vertex<G>>
+impl<B, M, G, K> From<VertexView<B>> for View<B, Vertex<G, K>>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn from(vertex: VertexView<B>) -> Self {
         let VertexView { inner, .. } = vertex;
@@ -696,11 +817,12 @@ where
     }
 }
 
-impl<B, M, G> Hash for VertexView<B>
+impl<B, M, G, K> Hash for VertexView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn hash<H>(&self, state: &mut H)
     where
@@ -710,11 +832,12 @@ where
     }
 }
 
-impl<B, M, G> PartialEq for VertexView<B>
+impl<B, M, G, K> PartialEq for VertexView<B>
 where
     B: Reborrow<Target = M>,
-    M: AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn eq(&self, other: &Self) -> bool {
         self.inner == other.inner
@@ -722,16 +845,18 @@ where
 }
 
 /// Orphan view of a vertex entity.
-pub struct VertexOrphan<'a, G>
+pub struct VertexOrphan<'a, G, K = u64>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    inner: Orphan<'a, Vertex<G>>,
+    inner: Orphan<'a, Vertex<G, K>>,
 }
 
-impl<'a, G> VertexOrphan<'a, G>
+impl<'a, G, K> VertexOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn position(&self) -> &VertexPosition<G>
     where
@@ -741,9 +866,10 @@ where
     }
 }
 
-impl<'a, G> VertexOrphan<'a, G>
+impl<'a, G, K> VertexOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     pub fn get(&self) -> &G::Vertex {
         self.inner.get()
@@ -754,59 +880,68 @@ where
     }
 }
 
-impl<'a, G> Borrow<VertexKey> for VertexOrphan<'a, G>
+impl<'a, G, K> Borrow<VertexKey<K>> for VertexOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    fn borrow(&self) -> &VertexKey {
+    fn borrow(&self) -> &VertexKey<K> {
         self.inner.as_ref()
     }
 }
 
-impl<'a, G> ClosedView for VertexOrphan<'a, G>
+impl<'a, G, K> ClosedView for VertexOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    type Key = VertexKey;
-    type Entity = Vertex<G>;
+    type Key = VertexKey<K>;
+    type Entity = Vertex<G, K>;
 
     fn key(&self) -> Self::Key {
         self.inner.key()
     }
 }
 
-impl<'a, G> Eq for VertexOrphan<'a, G> where G: GraphData {}
+impl<'a, G, K> Eq for VertexOrphan<'a, G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+}
 
-impl<'a, G> From<Orphan<'a, Vertex<G>>> for VertexOrphan<'a, G>
+impl<'a, G, K> From<Orphan<'a, Vertex<G, K>>> for VertexOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
-    fn from(inner: Orphan<'a, Vertex<G>>) -> Self {
+    fn from(inner: Orphan<'a, Vertex<G, K>>) -> Self {
         VertexOrphan { inner }
     }
 }
 
-impl<'a, M> From<View<&'a mut M, Vertex<M::Data>>> for VertexOrphan<'a, M::Data>
+impl<'a, M> From<View<&'a mut M, Vertex<M::Data, M::Index>>> for VertexOrphan<'a, M::Data, M::Index>
 where
-    M: AsStorageMut<Vertex<M::Data>> + Parametric,
+    M: AsStorageMut<Vertex<M::Data, M::Index>> + Parametric,
 {
-    fn from(view: View<&'a mut M, Vertex<M::Data>>) -> Self {
+    fn from(view: View<&'a mut M, Vertex<M::Data, M::Index>>) -> Self {
         VertexOrphan { inner: view.into() }
     }
 }
 
-impl<'a, M> From<VertexView<&'a mut M>> for VertexOrphan<'a, M::Data>
+impl<'a, M> From<VertexView<&'a mut M>> for VertexOrphan<'a, M::Data, M::Index>
 where
-    M: AsStorageMut<Vertex<M::Data>> + Parametric,
+    M: AsStorageMut<Vertex<M::Data, M::Index>> + Parametric,
 {
     fn from(vertex: VertexView<&'a mut M>) -> Self {
         Orphan::from(vertex.inner).into()
     }
 }
 
-impl<'a, G> Hash for VertexOrphan<'a, G>
+impl<'a, G, K> Hash for VertexOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     fn hash<H>(&self, state: &mut H)
     where
@@ -816,9 +951,10 @@ where
     }
 }
 
-impl<'a, G> PartialEq for VertexOrphan<'a, G>
+impl<'a, G, K> PartialEq for VertexOrphan<'a, G, K>
 where
     G: GraphData,
+    K: KeyIndex,
 {
     fn eq(&self, other: &Self) -> bool {
         self.inner == other.inner
@@ -827,21 +963,23 @@ where
 
 pub struct VertexCirculator<P, B>
 where
-    P: Trace<ArcKey>,
+    P: Trace<ArcKey<Index<B>>>,
     B: Reborrow,
-    B::Target: AsStorage<Arc<Data<B>>> + AsStorage<Vertex<Data<B>>> + Parametric,
+    B::Target:
+        AsStorage<Arc<Data<B>, Index<B>>> + AsStorage<Vertex<Data<B>, Index<B>>> + Parametric,
 {
     inner: ArcCirculator<P, B>,
 }
 
-impl<P, B, M, G> Circulator<B> for VertexCirculator<P, B>
+impl<P, B, M, G, K> Circulator<B> for VertexCirculator<P, B>
 where
-    P: Trace<ArcKey>,
+    P: Trace<ArcKey<K>>,
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Entity = Vertex<G>;
+    type Entity = Vertex<G, K>;
 
     fn next(&mut self) -> Option<<Self::Entity as Entity>::Key> {
         self.inner.next().map(|arc| {
@@ -851,12 +989,13 @@ where
     }
 }
 
-impl<P, B, M, G> Clone for VertexCirculator<P, B>
+impl<P, B, M, G, K> Clone for VertexCirculator<P, B>
 where
-    P: Clone + Trace<ArcKey>,
+    P: Clone + Trace<ArcKey<K>>,
     B: Clone + Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn clone(&self) -> Self {
         VertexCirculator {
@@ -865,12 +1004,13 @@ where
     }
 }
 
-impl<P, B, M, G> From<ArcCirculator<P, B>> for VertexCirculator<P, B>
+impl<P, B, M, G, K> From<ArcCirculator<P, B>> for VertexCirculator<P, B>
 where
-    P: Trace<ArcKey>,
+    P: Trace<ArcKey<K>>,
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn from(inner: ArcCirculator<P, B>) -> Self {
         VertexCirculator { inner }
@@ -879,8 +1019,8 @@ where
 
 impl<'a, P, M> Iterator for VertexCirculator<P, &'a M>
 where
-    P: Trace<ArcKey>,
-    M: AsStorage<Arc<M::Data>> + AsStorage<Vertex<M::Data>> + Parametric,
+    P: Trace<ArcKey<M::Index>>,
+    M: AsStorage<Arc<M::Data, M::Index>> + AsStorage<Vertex<M::Data, M::Index>> + Parametric,
 {
     type Item = VertexView<&'a M>;
 
@@ -889,22 +1029,25 @@ where
     }
 }
 
-impl<'a, M> Iterator for VertexCirculator<TraceAny<ArcKey>, &'a mut M>
+impl<'a, M> Iterator for VertexCirculator<TraceAny<ArcKey<M::Index>>, &'a mut M>
 where
-    M: AsStorage<Arc<M::Data>> + AsStorageMut<Vertex<M::Data>> + Parametric,
+    M: AsStorage<Arc<M::Data, M::Index>> + AsStorageMut<Vertex<M::Data, M::Index>> + Parametric,
 {
-    type Item = VertexOrphan<'a, M::Data>;
+    type Item = VertexOrphan<'a, M::Data, M::Index>;
 
     fn next(&mut self) -> Option<Self::Item> {
         unsafe { self.bind_next_orphan() }
     }
 }
 
-impl<'a, M> Iterator for VertexCirculator<TraceFirst<ArcKey>, &'a mut M>
+impl<'a, M> Iterator for VertexCirculator<TraceFirst<ArcKey<M::Index>>, &'a mut M>
 where
-    M: AsStorage<Arc<M::Data>> + AsStorageMut<Vertex<M::Data>> + Consistent + Parametric,
+    M: AsStorage<Arc<M::Data, M::Index>>
+        + AsStorageMut<Vertex<M::Data, M::Index>>
+        + Consistent
+        + Parametric,
 {
-    type Item = VertexOrphan<'a, M::Data>;
+    type Item = VertexOrphan<'a, M::Data, M::Index>;
 
     fn next(&mut self) -> Option<Self::Item> {
         unsafe { self.bind_next_orphan() }
@@ -913,8 +1056,8 @@ where
 
 impl<'a, P, M> OrphanCirculator<'a, M> for VertexCirculator<P, &'a mut M>
 where
-    P: Trace<ArcKey>,
-    M: AsStorage<Arc<M::Data>> + AsStorageMut<Vertex<M::Data>> + Parametric,
+    P: Trace<ArcKey<M::Index>>,
+    M: AsStorage<Arc<M::Data, M::Index>> + AsStorageMut<Vertex<M::Data, M::Index>> + Parametric,
 {
     fn target(&mut self) -> &mut M {
         self.inner.storage
@@ -923,8 +1066,8 @@ where
 
 impl<'a, P, M> ViewCirculator<'a, M> for VertexCirculator<P, &'a M>
 where
-    P: Trace<ArcKey>,
-    M: AsStorage<Arc<M::Data>> + AsStorage<Vertex<M::Data>> + Parametric,
+    P: Trace<ArcKey<M::Index>>,
+    M: AsStorage<Arc<M::Data, M::Index>> + AsStorage<Vertex<M::Data, M::Index>> + Parametric,
 {
     fn target(&self) -> &'a M {
         self.inner.storage
@@ -933,23 +1076,24 @@ where
 
 pub struct ArcCirculator<P, B>
 where
-    P: Trace<ArcKey>,
+    P: Trace<ArcKey<Index<B>>>,
     B: Reborrow,
-    B::Target: AsStorage<Arc<Data<B>>> + Parametric,
+    B::Target: AsStorage<Arc<Data<B>, Index<B>>> + Parametric,
 {
     storage: B,
-    outgoing: Option<ArcKey>,
+    outgoing: Option<ArcKey<Index<B>>>,
     trace: P,
 }
 
-impl<P, B, M, G> Circulator<B> for ArcCirculator<P, B>
+impl<P, B, M, G, K> Circulator<B> for ArcCirculator<P, B>
 where
-    P: Trace<ArcKey>,
+    P: Trace<ArcKey<K>>,
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Entity = Arc<G>;
+    type Entity = Arc<G, K>;
 
     fn next(&mut self) -> Option<<Self::Entity as Entity>::Key> {
         self.outgoing
@@ -970,12 +1114,13 @@ where
     }
 }
 
-impl<P, B, M, G> Clone for ArcCirculator<P, B>
+impl<P, B, M, G, K> Clone for ArcCirculator<P, B>
 where
-    P: Clone + Trace<ArcKey>,
+    P: Clone + Trace<ArcKey<K>>,
     B: Clone + Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn clone(&self) -> Self {
         ArcCirculator {
@@ -986,12 +1131,13 @@ where
     }
 }
 
-impl<P, B, M, G> From<VertexView<B>> for ArcCirculator<P, B>
+impl<P, B, M, G, K> From<VertexView<B>> for ArcCirculator<P, B>
 where
-    P: Default + Trace<ArcKey>,
+    P: Default + Trace<ArcKey<K>>,
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Vertex<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn from(vertex: VertexView<B>) -> Self {
         let key = vertex.arc;
@@ -1006,8 +1152,8 @@ where
 
 impl<'a, P, M> Iterator for ArcCirculator<P, &'a M>
 where
-    P: Trace<ArcKey>,
-    M: AsStorage<Arc<M::Data>> + Parametric,
+    P: Trace<ArcKey<M::Index>>,
+    M: AsStorage<Arc<M::Data, M::Index>> + Parametric,
 {
     type Item = ArcView<&'a M>;
 
@@ -1016,22 +1162,22 @@ where
     }
 }
 
-impl<'a, M> Iterator for ArcCirculator<TraceAny<ArcKey>, &'a mut M>
+impl<'a, M> Iterator for ArcCirculator<TraceAny<ArcKey<M::Index>>, &'a mut M>
 where
-    M: AsStorageMut<Arc<M::Data>> + Parametric,
+    M: AsStorageMut<Arc<M::Data, M::Index>> + Parametric,
 {
-    type Item = ArcOrphan<'a, M::Data>;
+    type Item = ArcOrphan<'a, M::Data, M::Index>;
 
     fn next(&mut self) -> Option<Self::Item> {
         unsafe { self.bind_next_orphan() }
     }
 }
 
-impl<'a, M> Iterator for ArcCirculator<TraceFirst<ArcKey>, &'a mut M>
+impl<'a, M> Iterator for ArcCirculator<TraceFirst<ArcKey<M::Index>>, &'a mut M>
 where
-    M: AsStorageMut<Arc<M::Data>> + Consistent + Parametric,
+    M: AsStorageMut<Arc<M::Data, M::Index>> + Consistent + Parametric,
 {
-    type Item = ArcOrphan<'a, M::Data>;
+    type Item = ArcOrphan<'a, M::Data, M::Index>;
 
     fn next(&mut self) -> Option<Self::Item> {
         unsafe { self.bind_next_orphan() }
@@ -1040,8 +1186,8 @@ where
 
 impl<'a, P, M> OrphanCirculator<'a, M> for ArcCirculator<P, &'a mut M>
 where
-    P: Trace<ArcKey>,
-    M: AsStorageMut<Arc<M::Data>> + Parametric,
+    P: Trace<ArcKey<M::Index>>,
+    M: AsStorageMut<Arc<M::Data, M::Index>> + Parametric,
 {
     fn target(&mut self) -> &mut M {
         self.storage
@@ -1050,8 +1196,8 @@ where
 
 impl<'a, P, M> ViewCirculator<'a, M> for ArcCirculator<P, &'a M>
 where
-    P: Trace<ArcKey>,
-    M: AsStorage<Arc<M::Data>> + Parametric,
+    P: Trace<ArcKey<M::Index>>,
+    M: AsStorage<Arc<M::Data, M::Index>> + Parametric,
 {
     fn target(&self) -> &'a M {
         self.storage
@@ -1060,21 +1206,22 @@ where
 
 pub struct FaceCirculator<P, B>
 where
-    P: Trace<ArcKey>,
+    P: Trace<ArcKey<Index<B>>>,
     B: Reborrow,
-    B::Target: AsStorage<Arc<Data<B>>> + AsStorage<Face<Data<B>>> + Parametric,
+    B::Target: AsStorage<Arc<Data<B>, Index<B>>> + AsStorage<Face<Data<B>, Index<B>>> + Parametric,
 {
     inner: ArcCirculator<P, B>,
 }
 
-impl<P, B, M, G> Circulator<B> for FaceCirculator<P, B>
+impl<P, B, M, G, K> Circulator<B> for FaceCirculator<P, B>
 where
-    P: Trace<ArcKey>,
+    P: Trace<ArcKey<K>>,
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
-    type Entity = Face<G>;
+    type Entity = Face<G, K>;
 
     fn next(&mut self) -> Option<<Self::Entity as Entity>::Key> {
         while let Some(arc) = self.inner.next() {
@@ -1082,7 +1229,7 @@ where
                 .inner
                 .storage
                 .reborrow()
-                .as_storage_of::<Arc<_>>()
+                .as_storage_of::<Arc<_, _>>()
                 .get(&arc)
                 .and_then(|arc| arc.face)
             {
@@ -1098,12 +1245,13 @@ where
     }
 }
 
-impl<P, B, M, G> Clone for FaceCirculator<P, B>
+impl<P, B, M, G, K> Clone for FaceCirculator<P, B>
 where
-    P: Clone + Trace<ArcKey>,
+    P: Clone + Trace<ArcKey<K>>,
     B: Clone + Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn clone(&self) -> Self {
         FaceCirculator {
@@ -1112,12 +1260,13 @@ where
     }
 }
 
-impl<P, B, M, G> From<ArcCirculator<P, B>> for FaceCirculator<P, B>
+impl<P, B, M, G, K> From<ArcCirculator<P, B>> for FaceCirculator<P, B>
 where
-    P: Trace<ArcKey>,
+    P: Trace<ArcKey<K>>,
     B: Reborrow<Target = M>,
-    M: AsStorage<Arc<G>> + AsStorage<Face<G>> + Parametric<Data = G>,
+    M: AsStorage<Arc<G, K>> + AsStorage<Face<G, K>> + Parametric<Data = G, Index = K>,
     G: GraphData,
+    K: KeyIndex,
 {
     fn from(inner: ArcCirculator<P, B>) -> Self {
         FaceCirculator { inner }
@@ -1126,8 +1275,8 @@ where
 
 impl<'a, P, M> Iterator for FaceCirculator<P, &'a M>
 where
-    P: Trace<ArcKey>,
-    M: AsStorage<Arc<M::Data>> + AsStorage<Face<M::Data>> + Parametric,
+    P: Trace<ArcKey<M::Index>>,
+    M: AsStorage<Arc<M::Data, M::Index>> + AsStorage<Face<M::Data, M::Index>> + Parametric,
 {
     type Item = FaceView<&'a M>;
 
@@ -1136,22 +1285,25 @@ where
     }
 }
 
-impl<'a, M> Iterator for FaceCirculator<TraceAny<ArcKey>, &'a mut M>
+impl<'a, M> Iterator for FaceCirculator<TraceAny<ArcKey<M::Index>>, &'a mut M>
 where
-    M: AsStorage<Arc<M::Data>> + AsStorageMut<Face<M::Data>> + Parametric,
+    M: AsStorage<Arc<M::Data, M::Index>> + AsStorageMut<Face<M::Data, M::Index>> + Parametric,
 {
-    type Item = FaceOrphan<'a, M::Data>;
+    type Item = FaceOrphan<'a, M::Data, M::Index>;
 
     fn next(&mut self) -> Option<Self::Item> {
         unsafe { self.bind_next_orphan() }
     }
 }
 
-impl<'a, M> Iterator for FaceCirculator<TraceFirst<ArcKey>, &'a mut M>
+impl<'a, M> Iterator for FaceCirculator<TraceFirst<ArcKey<M::Index>>, &'a mut M>
 where
-    M: AsStorage<Arc<M::Data>> + AsStorageMut<Face<M::Data>> + Consistent + Parametric,
+    M: AsStorage<Arc<M::Data, M::Index>>
+        + AsStorageMut<Face<M::Data, M::Index>>
+        + Consistent
+        + Parametric,
 {
-    type Item = FaceOrphan<'a, M::Data>;
+    type Item = FaceOrphan<'a, M::Data, M::Index>;
 
     fn next(&mut self) -> Option<Self::Item> {
         unsafe { self.bind_next_orphan() }
@@ -1160,8 +1312,8 @@ where
 
 impl<'a, P, M> OrphanCirculator<'a, M> for FaceCirculator<P, &'a mut M>
 where
-    P: Trace<ArcKey>,
-    M: AsStorage<Arc<M::Data>> + AsStorageMut<Face<M::Data>> + Parametric,
+    P: Trace<ArcKey<M::Index>>,
+    M: AsStorage<Arc<M::Data, M::Index>> + AsStorageMut<Face<M::Data, M::Index>> + Parametric,
 {
     fn target(&mut self) -> &mut M {
         self.inner.storage
@@ -1170,8 +1322,8 @@ where
 
 impl<'a, P, M> ViewCirculator<'a, M> for FaceCirculator<P, &'a M>
 where
-    P: Trace<ArcKey>,
-    M: AsStorage<Arc<M::Data>> + AsStorage<Face<M::Data>> + Parametric,
+    P: Trace<ArcKey<M::Index>>,
+    M: AsStorage<Arc<M::Data, M::Index>> + AsStorage<Face<M::Data, M::Index>> + Parametric,
 {
     fn target(&self) -> &'a M {
         self.inner.storage
@@ -1182,6 +1334,7 @@ where
 mod tests {
     use decorum::R64;
     use nalgebra::{Point2, Point3};
+    use std::collections::HashSet;
 
     use crate::graph::{GraphError, MeshGraph};
     use crate::prelude::*;
@@ -1205,6 +1358,135 @@ mod tests {
         }
     }
 
+    #[test]
+    fn valence() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        for vertex in graph.vertices() {
+            assert_eq!(3, vertex.valence());
+            assert!(!vertex.is_boundary_vertex());
+        }
+
+        // The poles of a UV-sphere are connected to each segment.
+        let graph: MeshGraph<E3> = UvSphere::new(8, 4).polygons::<Position<E3>>().collect();
+        assert_eq!(
+            2,
+            graph
+                .vertices()
+                .filter(|vertex| vertex.valence() == 8)
+                .count()
+        );
+
+        // Circulation about vertices in an open grid traverses boundary arcs.
+        let graph = MeshGraph::<Point3<f64>>::from_heightmap(&[0.0; 9], 3, 3).unwrap();
+        let count = |valence, boundary| {
+            graph
+                .vertices()
+                .filter(|vertex| vertex.valence() == valence)
+                .filter(|vertex| vertex.is_boundary_vertex() == boundary)
+                .count()
+        };
+        assert_eq!(4, count(2, true)); // Corners.
+        assert_eq!(4, count(3, true));
+        assert_eq!(1, count(4, false));
+    }
+
+    #[test]
+    fn circulate_over_arcs_in_order() {
+        let graph = MeshGraph::<Point3<f64>>::from_heightmap(&[0.0; 9], 3, 3).unwrap();
+        let vertex = |x, y| {
+            graph
+                .vertices()
+                .find(|vertex| *vertex.position() == Point3::new(x, y, 0.0))
+                .unwrap()
+        };
+        let destinations = |x, y| {
+            vertex(x, y)
+                .arcs()
+                .map(|arc| *arc.destination_vertex().position())
+                .collect::<Vec<_>>()
+        };
+
+        // Arcs about an interior vertex are counter-clockwise when viewed
+        // from above.
+        let center = vertex(1.0, 1.0);
+        assert_eq!(4, center.arcs().count());
+        assert_eq!(
+            center
+                .outgoing_arcs()
+                .map(|arc| arc.key())
+                .collect::<HashSet<_>>(),
+            center.arcs().map(|arc| arc.key()).collect::<HashSet<_>>(),
+        );
+        let directions = destinations(1.0, 1.0)
+            .into_iter()
+            .map(|position| position - center.position())
+            .collect::<Vec<_>>();
+        for (a, b) in directions.iter().zip(directions.iter().cycle().skip(1)) {
+            assert!(a.cross(b).z > 0.0);
+        }
+
+        // Arcs about a boundary vertex begin and end at the boundary.
+        assert_eq!(
+            vec![
+                Point3::new(2.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(0.0, 0.0, 0.0),
+            ],
+            destinations(1.0, 0.0),
+        );
+        assert_eq!(
+            vec![Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+            destinations(0.0, 0.0),
+        );
+        let vertex = vertex(1.0, 0.0);
+        let arcs = vertex.arcs().collect::<Vec<_>>();
+        assert!(arcs.first().unwrap().opposite_arc().is_boundary_arc());
+        assert!(arcs.last().unwrap().is_boundary_arc());
+    }
+
+    #[test]
+    fn remove_vertex() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.vertices().nth(0).unwrap().key();
+        graph.vertex_mut(key).unwrap().remove();
+
+        assert_eq!(7, graph.vertex_count());
+        assert_eq!(9, graph.edge_count());
+        assert_eq!(3, graph.face_count());
+        // The arcs of the removed faces form a hexagonal boundary.
+        let arc = graph.arcs().find(|arc| arc.is_boundary_arc()).unwrap();
+        assert_eq!(6, arc.into_ring().arity());
+        assert_eq!(6, graph.arcs().filter(|arc| arc.is_boundary_arc()).count());
+    }
+
+    #[test]
+    fn remove_vertex_of_triangle() {
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+        )
+        .unwrap();
+        let key = graph.vertices().nth(0).unwrap().key();
+        graph.vertex_mut(key).unwrap().remove();
+
+        assert_eq!(2, graph.vertex_count());
+        assert_eq!(1, graph.edge_count());
+        assert_eq!(0, graph.face_count());
+    }
+
+    #[test]
+    fn remove_vertex_of_cube() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.vertices().nth(0).unwrap().key();
+        graph.vertex_mut(key).unwrap().remove();
+
+        // Removal never leaves isolated vertices behind.
+        assert!(graph.vertices().all(|vertex| vertex.arc.is_some()));
+        assert_eq!(7, graph.vertex_count());
+        assert_eq!(9, graph.edge_count());
+        assert_eq!(3, graph.face_count());
+    }
+
     #[test]
     fn reachable_shortest_path() {
         let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
@@ -0,0 +1,425 @@
+use num::Zero;
+use smallvec::SmallVec;
+use std::collections::{HashMap, HashSet};
+use theon::space::{Basis, EuclideanSpace, InnerSpace, Scalar, Vector};
+use theon::AsPosition;
+
+use crate::entity::storage::KeyIndex;
+use crate::entity::view::ClosedView;
+use crate::geometry::{grid_cell, neighboring_cells};
+use crate::graph::data::GraphData;
+use crate::graph::face::FaceKey;
+use crate::graph::geometry::VertexPosition;
+use crate::graph::mutation::face::FaceInsertCache;
+use crate::graph::mutation::{self, Immediate};
+use crate::graph::vertex::VertexKey;
+use crate::graph::{GraphError, MeshGraph, OptionExt as _, ResultExt as _};
+use crate::transact::Transact;
+
+type Mutation<M> = mutation::Mutation<Immediate<M>>;
+
+/// Handling of merges that would produce non-manifold topology when welding
+/// vertices.
+///
+/// See [`MeshGraph::weld_vertices_with`].
+///
+/// [`MeshGraph::weld_vertices_with`]: crate::graph::MeshGraph::weld_vertices_with
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum WeldMode {
+    /// Fails if any merge would produce non-manifold topology and leaves the
+    /// graph unmodified.
+    Refuse,
+    /// Keeps vertices distinct where merging them would produce non-manifold
+    /// topology and welds the remaining vertices.
+    Skip,
+}
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Welds vertices with coincident positions.
+    ///
+    /// Vertices with positions within the given distance of one another are
+    /// merged into a single vertex and faces are rewritten to reference the
+    /// merged vertices. Faces that become degenerate, such as faces with two or
+    /// more vertices merged into one, are removed along with any edges that
+    /// collapse to a single vertex. Positions are clustered using a spatial hash
+    /// with cells the size of the given distance, so welding is roughly linear
+    /// in the number of vertices. This is useful for closing seams in meshes
+    /// that have been imported or assembled from separate parts.
+    ///
+    /// The graph is rebuilt from its faces. Vertex and face data is retained.
+    /// Arc and edge data is reset to its default value and edges that are not
+    /// part of any face are discarded. Layers are migrated, but the values of
+    /// vertices that are merged into other vertices are discarded.
+    ///
+    /// Returns the number of vertices that were merged into other vertices.
+    ///
+    /// This refuses merges that would produce non-manifold topology. Use
+    /// [`weld_vertices_with`] to skip such merges instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if welding would produce non-manifold topology, such
+    /// as an edge shared by more than two faces. The error is wrapped with the
+    /// key of the offending face (see [`GraphError::AtKey`]). In this case, the
+    /// graph is left unmodified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// // Two triangles with distinct but coincident vertices along an edge.
+    /// let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2), Trigon::new(3, 4, 5)],
+    ///     vec![
+    ///         (0.0, 0.0),
+    ///         (1.0, 0.0),
+    ///         (0.0, 1.0),
+    ///         (1.0, 0.0),
+    ///         (1.0, 1.0),
+    ///         (0.0, 1.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(2, graph.weld_vertices(1e-6).unwrap());
+    /// assert_eq!(4, graph.vertex_count());
+    /// assert_eq!(5, graph.edge_count());
+    /// ```
+    ///
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    /// [`weld_vertices_with`]: crate::graph::MeshGraph::weld_vertices_with
+    pub fn weld_vertices<T>(&mut self, epsilon: T) -> Result<usize, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: Basis,
+    {
+        self.weld_vertices_with(epsilon, WeldMode::Refuse)
+    }
+
+    /// Welds vertices with coincident positions using the given handling of
+    /// merges that would produce non-manifold topology.
+    ///
+    /// See [`weld_vertices`] and [`WeldMode`]. When merges are skipped, the
+    /// vertices of each face that cannot be rebuilt are kept distinct from the
+    /// vertices with which they would be merged and welding is attempted
+    /// again, so skipping merges may rebuild the graph several times.
+    ///
+    /// Returns the number of vertices that were merged into other vertices.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if merges are refused and welding would produce
+    /// non-manifold topology. The error is wrapped with the key of the
+    /// offending face (see [`GraphError::AtKey`]). In this case, the graph is
+    /// left unmodified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::graph::{MeshGraph, WeldMode};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// // Three triangles with coincident vertices along an edge. Welding all of
+    /// // them would share the edge among three faces.
+    /// let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+    ///     vec![
+    ///         Trigon::new(0usize, 1, 2),
+    ///         Trigon::new(3, 4, 5),
+    ///         Trigon::new(6, 7, 8),
+    ///     ],
+    ///     vec![
+    ///         (0.0, 0.0, 0.0),
+    ///         (1.0, 0.0, 0.0),
+    ///         (0.0, 1.0, 0.0),
+    ///         (1.0, 0.0, 0.0),
+    ///         (0.0, 0.0, 0.0),
+    ///         (0.0, -1.0, 0.0),
+    ///         (0.0, 0.0, 0.0),
+    ///         (1.0, 0.0, 0.0),
+    ///         (0.0, 0.0, 1.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// assert!(graph.weld_vertices_with(1e-6, WeldMode::Refuse).is_err());
+    /// assert!(graph.weld_vertices_with(1e-6, WeldMode::Skip).unwrap() > 0);
+    /// ```
+    ///
+    /// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+    /// [`weld_vertices`]: crate::graph::MeshGraph::weld_vertices
+    /// [`WeldMode`]: crate::graph::WeldMode
+    pub fn weld_vertices_with<T>(&mut self, epsilon: T, mode: WeldMode) -> Result<usize, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: Basis,
+    {
+        let epsilon = epsilon.into();
+        if epsilon <= Zero::zero() {
+            return Ok(0);
+        }
+        let basis = Vector::<VertexPosition<G>>::canonical_basis()
+            .into_iter()
+            .collect::<SmallVec<[_; 3]>>();
+        // Cluster positions. Each vertex is mapped to the first vertex within
+        // the given distance in the same or a neighboring cell.
+        let mut cells = HashMap::<_, Vec<VertexKey<K>>>::new();
+        let mut keys = HashMap::with_capacity(self.vertex_count());
+        for vertex in self.vertices() {
+            let position = *vertex.position();
+            let origin = grid_cell(&basis, position, epsilon);
+            let representative = neighboring_cells(&origin)
+                .iter()
+                .flat_map(|neighbor| cells.get(neighbor).into_iter().flatten())
+                .find(|key| {
+                    let other = *self.vertex(**key).expect_consistent().position();
+                    (other - position).square_magnitude() <= epsilon * epsilon
+                })
+                .cloned();
+            if let Some(representative) = representative {
+                keys.insert(vertex.key(), representative);
+            }
+            else {
+                cells.entry(origin).or_default().push(vertex.key());
+                keys.insert(vertex.key(), vertex.key());
+            }
+        }
+        loop {
+            let count = keys.iter().filter(|(key, other)| key != other).count();
+            if count == 0 {
+                return Ok(0);
+            }
+            let (face, error) = match self.rebuild_welded(&keys) {
+                Ok((graph, vertices, faces)) => {
+                    // Vertices retain their values in layers unless they are
+                    // merged into another vertex.
+                    self.replace_with_rebuild(graph, &vertices, &faces);
+                    return Ok(count);
+                }
+                Err(error) => error,
+            };
+            if mode == WeldMode::Refuse || !unweld_face(self, &mut keys, face) {
+                return Err(error.at_key(face));
+            }
+        }
+    }
+
+    // Rebuilds the graph with vertices merged into the vertices to which they
+    // are mapped. Vertices that are not mapped are retained and degenerate
+    // faces are discarded. Only faces are rebuilt, so edges that are not part
+    // of any face are also discarded. Returns the rebuilt graph and the
+    // mappings from the retained vertices and faces to their keys in the
+    // rebuilt graph. On failure, returns the face that could not be rebuilt.
+    #[allow(clippy::type_complexity)]
+    pub(in crate::graph) fn rebuild_welded(
+        &self,
+        keys: &HashMap<VertexKey<K>, VertexKey<K>>,
+    ) -> Result<
+        (
+            Self,
+            HashMap<VertexKey<K>, VertexKey<K>>,
+            HashMap<FaceKey<K>, FaceKey<K>>,
+        ),
+        (FaceKey<K>, GraphError),
+    > {
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let mut vertices = HashMap::new();
+        let mut faces = HashMap::with_capacity(self.face_count());
+        for face in self.faces() {
+            let mut perimeter = face
+                .adjacent_vertices()
+                .map(|vertex| keys.get(&vertex.key()).cloned().unwrap_or(vertex.key()))
+                .collect::<SmallVec<[_; 4]>>();
+            perimeter.dedup();
+            while perimeter.len() > 1 && perimeter.first() == perimeter.last() {
+                perimeter.pop();
+            }
+            if perimeter.len() < 3 {
+                // Discard degenerate faces.
+                continue;
+            }
+            if perimeter.iter().collect::<HashSet<_>>().len() != perimeter.len() {
+                // Refuse to pinch a face into non-manifold topology.
+                return Err((face.key(), GraphError::TopologyConflict));
+            }
+            let perimeter = perimeter
+                .into_iter()
+                .map(|key| {
+                    *vertices.entry(key).or_insert_with(|| {
+                        // The rebuilt graph has no more vertices than the
+                        // graph, so this should never fail.
+                        let data = self.vertex(key).expect_consistent().get().clone();
+                        mutation::vertex::insert(&mut mutation, data).expect_consistent()
+                    })
+                })
+                .collect::<SmallVec<[_; 4]>>();
+            let data = face.get().clone();
+            let key = FaceInsertCache::from_storage(&mutation, &perimeter)
+                .and_then(|cache| {
+                    mutation::face::insert_with(&mut mutation, cache, || (Default::default(), data))
+                })
+                .map_err(|error| (face.key(), error))?;
+            faces.insert(face.key(), key);
+        }
+        // Vertices are only inserted along with faces, so this should never
+        // fail.
+        let graph = mutation
+            .commit()
+            .map_err(|(_, error)| error)
+            .expect_consistent();
+        Ok((graph, vertices, faces))
+    }
+}
+
+// Keeps the vertices of a face distinct from the vertices with which they would
+// be merged. Vertices of the face that are merged into other vertices are
+// unmapped first. If there are no such vertices, then the vertices that are
+// merged into the vertices of the face are unmapped instead. Returns `false` if
+// no vertices are unmapped.
+fn unweld_face<G, K>(
+    graph: &MeshGraph<G, K>,
+    keys: &mut HashMap<VertexKey<K>, VertexKey<K>>,
+    face: FaceKey<K>,
+) -> bool
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    let vertices = graph
+        .face(face)
+        .expect_consistent()
+        .adjacent_vertices()
+        .map(|vertex| vertex.key())
+        .collect::<HashSet<_>>();
+    let mut unwelded = false;
+    for key in vertices.iter() {
+        if let Some(representative) = keys.get_mut(key) {
+            if *representative != *key {
+                *representative = *key;
+                unwelded = true;
+            }
+        }
+    }
+    if !unwelded {
+        for (key, representative) in keys.iter_mut() {
+            if *key != *representative && vertices.contains(&*representative) {
+                *representative = *key;
+                unwelded = true;
+            }
+        }
+    }
+    unwelded
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Point3, Vector3};
+
+    use crate::graph::{MeshGraph, WeldMode};
+    use crate::index::LruIndexer;
+    use crate::prelude::*;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+    use crate::primitive::Trigon;
+
+    #[test]
+    fn weld_hemispheres() {
+        type E3 = Point3<f64>;
+
+        // Partition the polygons of a sphere into hemispheres and offset the
+        // lower hemisphere slightly so that its rim vertices are distinct from
+        // those of the upper hemisphere.
+        let (upper, lower): (Vec<_>, Vec<_>) = UvSphere::new(8, 4)
+            .polygons::<Position<E3>>()
+            .partition(|polygon| {
+                polygon
+                    .as_ref()
+                    .iter()
+                    .map(|position| position.z)
+                    .sum::<f64>()
+                    > 0.0
+            });
+        let mut graph: MeshGraph<E3> = upper
+            .into_iter()
+            .chain(
+                lower
+                    .into_iter()
+                    .map_vertices(|position| position + Vector3::new(1e-9, 0.0, 0.0)),
+            )
+            .collect_with_indexer(LruIndexer::with_capacity(64))
+            .unwrap();
+        assert!(graph.arcs().any(|arc| arc.is_boundary_arc()));
+
+        assert_eq!(8, graph.weld_vertices(1e-6).unwrap());
+        assert_eq!(26, graph.vertex_count());
+        assert_eq!(32, graph.face_count());
+        assert!(!graph.arcs().any(|arc| arc.is_boundary_arc()));
+    }
+
+    #[test]
+    fn weld_non_manifold() {
+        // Construct three triangles with coincident vertices along an edge.
+        // Welding all of them would share the edge among three faces.
+        let graph = || {
+            MeshGraph::<Point3<f64>>::from_raw_buffers(
+                vec![
+                    Trigon::new(0usize, 1, 2),
+                    Trigon::new(3, 4, 5),
+                    Trigon::new(6, 7, 8),
+                ],
+                vec![
+                    (0.0, 0.0, 0.0),
+                    (1.0, 0.0, 0.0),
+                    (0.0, 1.0, 0.0),
+                    (1.0, 0.0, 0.0),
+                    (0.0, 0.0, 0.0),
+                    (0.0, -1.0, 0.0),
+                    (0.0, 0.0, 0.0),
+                    (1.0, 0.0, 0.0),
+                    (0.0, 0.0, 1.0),
+                ],
+            )
+            .unwrap()
+        };
+
+        // Refusing merges leaves the graph unmodified.
+        let mut refused = graph();
+        let error = refused
+            .weld_vertices_with(1e-6, WeldMode::Refuse)
+            .unwrap_err();
+        assert!(error.key().is_some());
+        assert_eq!(9, refused.vertex_count());
+        assert_eq!(9, refused.edge_count());
+        assert!(refused.weld_vertices(1e-6).is_err());
+
+        // Skipping merges welds a pair of triangles and keeps the vertices of
+        // the remaining triangle distinct.
+        let mut skipped = graph();
+        let count = skipped.weld_vertices_with(1e-6, WeldMode::Skip).unwrap();
+        assert!(count > 0 && count < 4);
+        assert_eq!(9 - count, skipped.vertex_count());
+        assert_eq!(3, skipped.face_count());
+        // The remaining merges are skipped again.
+        assert_eq!(0, skipped.weld_vertices_with(1e-6, WeldMode::Skip).unwrap());
+        assert_eq!(9 - count, skipped.vertex_count());
+    }
+}
@@ -0,0 +1,412 @@
+use derivative::Derivative;
+use smallvec::SmallVec;
+use std::collections::HashMap;
+
+use crate::entity::storage::KeyIndex;
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::edge::ArcView;
+use crate::graph::mutation::face::FaceInsertCache;
+use crate::graph::mutation::{self, Immediate};
+use crate::graph::{GraphError, MeshGraph};
+use crate::transact::Transact;
+
+type Mutation<M> = mutation::Mutation<Immediate<M>>;
+
+/// Vertex in a [`WingedEdgeMesh`].
+///
+/// [`WingedEdgeMesh`]: crate::graph::WingedEdgeMesh
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct WingedVertex<G>
+where
+    G: GraphData,
+{
+    /// User data.
+    #[derivative(Debug = "ignore")]
+    pub data: G::Vertex,
+    /// Index of an edge that is incident to the vertex.
+    pub edge: usize,
+}
+
+/// Edge in a [`WingedEdgeMesh`].
+///
+/// An edge is directed from its first vertex to its second vertex. The left
+/// side of the edge is the ring traversed in this direction and the right side
+/// is the ring traversed in the opposite direction. Sides are indexed in that
+/// order: the first face and arc are on the left and the second face and arc
+/// are on the right.
+///
+/// [`WingedEdgeMesh`]: crate::graph::WingedEdgeMesh
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct WingedEdge<G>
+where
+    G: GraphData,
+{
+    /// Indices of the vertices of the edge in the order `[start, end]`.
+    pub vertices: [usize; 2],
+    /// Indices of the faces on the left and right of the edge. A face is
+    /// `None` if the side of the edge is a boundary.
+    pub faces: [Option<usize>; 2],
+    /// Indices of the adjacent edges (wings) in the order `[left previous,
+    /// left next, right previous, right next]`.
+    ///
+    /// Wings are defined for boundaries too, in which case they refer to the
+    /// adjacent edges along the boundary.
+    pub wings: [usize; 4],
+    /// User data of the arcs on the left and right of the edge.
+    #[derivative(Debug = "ignore")]
+    pub arcs: [G::Arc; 2],
+    /// User data.
+    #[derivative(Debug = "ignore")]
+    pub data: G::Edge,
+}
+
+impl<G> WingedEdge<G>
+where
+    G: GraphData,
+{
+    /// Gets the indices of the previous and next edges on the given side of the
+    /// edge, where zero is the left side and one is the right side.
+    pub fn wings(&self, side: usize) -> (usize, usize) {
+        (self.wings[side * 2], self.wings[(side * 2) + 1])
+    }
+
+    /// Gets the indices of the source and destination vertices when the edge is
+    /// traversed along the given side, where zero is the left side and one is
+    /// the right side.
+    pub fn endpoints(&self, side: usize) -> (usize, usize) {
+        let [a, b] = self.vertices;
+        if side == 0 {
+            (a, b)
+        }
+        else {
+            (b, a)
+        }
+    }
+}
+
+/// Face in a [`WingedEdgeMesh`].
+///
+/// [`WingedEdgeMesh`]: crate::graph::WingedEdgeMesh
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct WingedFace<G>
+where
+    G: GraphData,
+{
+    /// User data.
+    #[derivative(Debug = "ignore")]
+    pub data: G::Face,
+    /// Index of an edge in the ring of the face.
+    pub edge: usize,
+}
+
+/// Index-based winged-edge mesh.
+///
+/// Unlike [`MeshGraph`], which is a half-edge graph, each edge in a
+/// winged-edge mesh is a single record that refers to both of its adjacent
+/// faces and the edges that precede and follow it in the rings on either side.
+/// Some algorithms, such as certain CSG implementations, are expressed in terms
+/// of this representation.
+///
+/// Topology refers to other topology by its index into the corresponding
+/// collection. See [`WingedEdge`] for the layout of edges and
+/// [`MeshGraph::to_winged_edge`] and [`MeshGraph::from_winged_edge`] for
+/// conversions.
+///
+/// # Examples
+///
+/// Converting a [`MeshGraph`] into a winged-edge mesh and back:
+///
+/// ```rust
+/// # extern crate decorum;
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use decorum::R64;
+/// use nalgebra::Point3;
+/// use plexus::graph::MeshGraph;
+/// use plexus::prelude::*;
+/// use plexus::primitive::cube::Cube;
+/// use plexus::primitive::generate::Position;
+///
+/// let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+/// let mesh = graph.to_winged_edge();
+/// assert_eq!(12, mesh.edges.len());
+///
+/// let graph: MeshGraph<Point3<f64>> = MeshGraph::from_winged_edge(mesh).unwrap();
+/// ```
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::from_winged_edge`]: crate::graph::MeshGraph::from_winged_edge
+/// [`MeshGraph::to_winged_edge`]: crate::graph::MeshGraph::to_winged_edge
+/// [`WingedEdge`]: crate::graph::WingedEdge
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""), Default(bound = ""))]
+pub struct WingedEdgeMesh<G>
+where
+    G: GraphData,
+{
+    pub vertices: Vec<WingedVertex<G>>,
+    pub edges: Vec<WingedEdge<G>>,
+    pub faces: Vec<WingedFace<G>>,
+}
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Converts the graph into a winged-edge mesh.
+    ///
+    /// Each edge in the graph becomes a [`WingedEdge`] that refers to the
+    /// faces and adjacent edges on both of its sides. The left side of a
+    /// winged edge is the ring of the leading arc of the corresponding edge in
+    /// the graph. All vertex, arc, edge, and face data is copied.
+    ///
+    /// See [`MeshGraph::from_winged_edge`].
+    ///
+    /// [`MeshGraph::from_winged_edge`]: crate::graph::MeshGraph::from_winged_edge
+    /// [`WingedEdge`]: crate::graph::WingedEdge
+    pub fn to_winged_edge(&self) -> WingedEdgeMesh<G> {
+        let vertices = self
+            .vertices()
+            .enumerate()
+            .map(|(index, vertex)| (vertex.key(), index))
+            .collect::<HashMap<_, _>>();
+        let edges = self
+            .edges()
+            .enumerate()
+            .map(|(index, edge)| (edge.key(), index))
+            .collect::<HashMap<_, _>>();
+        let faces = self
+            .faces()
+            .enumerate()
+            .map(|(index, face)| (face.key(), index))
+            .collect::<HashMap<_, _>>();
+        let edge_index = |arc: ArcView<&Self>| edges[&arc.edge().key()];
+        WingedEdgeMesh {
+            vertices: self
+                .vertices()
+                .map(|vertex| WingedVertex {
+                    data: vertex.get().clone(),
+                    edge: edge_index(vertex.outgoing_arc()),
+                })
+                .collect(),
+            edges: self
+                .edges()
+                .map(|edge| {
+                    let ab = edge.arc();
+                    let ba = ab.opposite_arc();
+                    WingedEdge {
+                        vertices: [
+                            vertices[&ab.source_vertex().key()],
+                            vertices[&ab.destination_vertex().key()],
+                        ],
+                        faces: [
+                            ab.face().map(|face| faces[&face.key()]),
+                            ba.face().map(|face| faces[&face.key()]),
+                        ],
+                        wings: [
+                            edge_index(ab.previous_arc()),
+                            edge_index(ab.next_arc()),
+                            edge_index(ba.previous_arc()),
+                            edge_index(ba.next_arc()),
+                        ],
+                        arcs: [ab.get().clone(), ba.get().clone()],
+                        data: edge.get().clone(),
+                    }
+                })
+                .collect(),
+            faces: self
+                .faces()
+                .map(|face| WingedFace {
+                    data: face.get().clone(),
+                    edge: edge_index(face.arc()),
+                })
+                .collect(),
+        }
+    }
+
+    /// Constructs a graph from a winged-edge mesh.
+    ///
+    /// The rings of faces are traversed via the wings of their edges and all
+    /// vertex, arc, edge, and face data is copied. Vertices are inserted in
+    /// the same order as in the winged-edge mesh. This is the inverse of
+    /// [`MeshGraph::to_winged_edge`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the winged-edge mesh is inconsistent, such as if an
+    /// index is out of bounds, the wings of an edge do not form a ring about a
+    /// face, or an edge is not adjacent to any face. Returns an error if the
+    /// faces of the mesh do not form a manifold.
+    ///
+    /// [`MeshGraph::to_winged_edge`]: crate::graph::MeshGraph::to_winged_edge
+    pub fn from_winged_edge(mesh: WingedEdgeMesh<G>) -> Result<Self, GraphError> {
+        let WingedEdgeMesh {
+            vertices,
+            edges,
+            faces,
+        } = mesh;
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let keys = vertices
+            .into_iter()
+            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.data))
+            .collect::<Result<Vec<_>, _>>()?;
+        let key = |index: usize| {
+            keys.get(index)
+                .copied()
+                .ok_or(GraphError::TopologyMalformed)
+        };
+        for (index, face) in faces.into_iter().enumerate() {
+            // Walk the ring of the face. The side of each edge is the side
+            // that is adjacent to the face and that continues from the
+            // destination of the previous edge.
+            let mut perimeter = SmallVec::<[_; 4]>::new();
+            let mut destination = None;
+            let mut edge = face.edge;
+            loop {
+                let winged = edges.get(edge).ok_or(GraphError::TopologyMalformed)?;
+                let side = (0..2)
+                    .find(|&side| {
+                        winged.faces[side] == Some(index)
+                            && destination
+                                .map_or(true, |destination| winged.endpoints(side).0 == destination)
+                    })
+                    .ok_or(GraphError::TopologyMalformed)?;
+                let (a, b) = winged.endpoints(side);
+                perimeter.push(key(a)?);
+                destination = Some(b);
+                edge = winged.wings(side).1;
+                if edge == face.edge {
+                    break;
+                }
+                if perimeter.len() > edges.len() {
+                    return Err(GraphError::TopologyMalformed);
+                }
+            }
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            let data = face.data;
+            mutation::face::insert_with(&mut mutation, cache, || (Default::default(), data))?;
+        }
+        let mut graph: Self = mutation.commit().map_err(|(_, error)| error)?;
+        // Copy the data of arcs and edges, which are inserted with default
+        // data along with faces.
+        for winged in edges {
+            let [a, b] = winged.vertices;
+            let (a, b) = (key(a)?, key(b)?);
+            let [ab, ba] = winged.arcs;
+            let mut arc = graph
+                .arc_mut((b, a).into())
+                .ok_or(GraphError::TopologyMalformed)?;
+            *arc.get_mut() = ba;
+            let mut arc = graph
+                .arc_mut((a, b).into())
+                .ok_or(GraphError::TopologyMalformed)?;
+            *arc.get_mut() = ab;
+            let mut edge = arc.into_edge();
+            *edge.get_mut() = winged.data;
+        }
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn winged_edge_round_trip() {
+        // Gets the rings of faces as positions, rotated such that the minimum
+        // position is first and sorted, so that graphs can be compared
+        // regardless of keys.
+        fn rings(graph: &MeshGraph<Point3<f64>>) -> Vec<Vec<(R64, R64, R64)>> {
+            let mut rings = graph
+                .faces()
+                .map(|face| {
+                    let mut ring = face
+                        .adjacent_vertices()
+                        .map(|vertex| (*vertex.position()).into_geometry())
+                        .collect::<Vec<(R64, R64, R64)>>();
+                    let index = (0..ring.len()).min_by_key(|index| ring[*index]).unwrap();
+                    ring.rotate_left(index);
+                    ring
+                })
+                .collect::<Vec<_>>();
+            rings.sort();
+            rings
+        }
+
+        let cube: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let sphere: MeshGraph<Point3<f64>> =
+            UvSphere::new(8, 6).polygons::<Position<E3>>().collect();
+        for graph in [cube, sphere] {
+            let mesh = graph.to_winged_edge();
+            assert_eq!(graph.vertex_count(), mesh.vertices.len());
+            assert_eq!(graph.edge_count(), mesh.edges.len());
+            assert_eq!(graph.face_count(), mesh.faces.len());
+            for (index, edge) in mesh.edges.iter().enumerate() {
+                // The graphs are closed, so every edge has two faces.
+                assert!(edge.faces.iter().all(|face| face.is_some()));
+                // The next edge in the ring continues from the destination of
+                // the edge and its previous edge is the edge.
+                let (previous, next) = edge.wings(0);
+                let side = (0..2)
+                    .find(|side| {
+                        mesh.edges[next].faces[*side] == edge.faces[0]
+                            && mesh.edges[next].endpoints(*side).0 == edge.vertices[1]
+                    })
+                    .unwrap();
+                assert_eq!(index, mesh.edges[next].wings(side).0);
+                assert!(mesh.edges[previous].faces.contains(&edge.faces[0]));
+            }
+
+            let other = MeshGraph::from_winged_edge(mesh).unwrap();
+            assert_eq!(graph.vertex_count(), other.vertex_count());
+            assert_eq!(graph.arc_count(), other.arc_count());
+            assert_eq!(graph.edge_count(), other.edge_count());
+            assert_eq!(graph.face_count(), other.face_count());
+            assert_eq!(rings(&graph), rings(&other));
+        }
+    }
+
+    #[test]
+    fn winged_edge_boundary() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.faces().nth(0).unwrap().key();
+        graph.face_mut(key).unwrap().remove();
+
+        let mesh = graph.to_winged_edge();
+        assert_eq!(
+            4,
+            mesh.edges
+                .iter()
+                .filter(|edge| edge.faces.contains(&None))
+                .count()
+        );
+        let graph = MeshGraph::<_>::from_winged_edge(mesh).unwrap();
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(12, graph.edge_count());
+        assert_eq!(5, graph.face_count());
+        assert_eq!(4, graph.arcs().filter(|arc| arc.is_boundary_arc()).count());
+
+        // Inconsistent wings are rejected.
+        let mut mesh = graph.to_winged_edge();
+        for edge in mesh.edges.iter_mut() {
+            edge.wings = [0; 4];
+        }
+        assert!(MeshGraph::<Point3<f64>>::from_winged_edge(mesh).is_err());
+    }
+}
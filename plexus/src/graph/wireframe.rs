@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use theon::space::EuclideanSpace;
+use theon::AsPosition;
+
+use crate::entity::storage::KeyIndex;
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::geometry::VertexPosition;
+use crate::graph::vertex::{VertexKey, VertexView};
+use crate::graph::MeshGraph;
+
+impl<G, K> MeshGraph<G, K>
+where
+    G: GraphData,
+    K: KeyIndex,
+{
+    /// Gets the line segments formed by the edges in the graph.
+    ///
+    /// Each edge is represented by the positions of the source and destination
+    /// vertices of its arc. This is useful for rendering wireframes and
+    /// visualizing topology.
+    pub fn wireframe(&self) -> Vec<[VertexPosition<G>; 2]>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.edges()
+            .map(|edge| {
+                let arc = edge.arc();
+                [
+                    *arc.source_vertex().position(),
+                    *arc.destination_vertex().position(),
+                ]
+            })
+            .collect()
+    }
+
+    /// Gets the line segments formed by the boundary edges in the graph.
+    ///
+    /// Boundary edges are oriented by their boundary arcs. See
+    /// [`wireframe`] and [`ArcView::is_boundary_arc`].
+    ///
+    /// [`ArcView::is_boundary_arc`]: crate::graph::ArcView::is_boundary_arc
+    /// [`wireframe`]: crate::graph::MeshGraph::wireframe
+    pub fn boundary_wireframe(&self) -> Vec<[VertexPosition<G>; 2]>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.arcs()
+            .filter(|arc| arc.is_boundary_arc())
+            .map(|arc| {
+                [
+                    *arc.source_vertex().position(),
+                    *arc.destination_vertex().position(),
+                ]
+            })
+            .collect()
+    }
+
+    /// Gets an index buffer and vertex buffer of the line segments formed by
+    /// the edges in the graph.
+    ///
+    /// The index buffer contains pairs of indices into the vertex buffer, which
+    /// contains the position of each vertex in the graph. This can be used
+    /// directly for line list rendering. See [`wireframe`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graph has more than [`u32::MAX`] vertices.
+    ///
+    /// [`u32::MAX`]: std::u32::MAX
+    /// [`wireframe`]: crate::graph::MeshGraph::wireframe
+    pub fn wireframe_indexed(&self) -> (Vec<u32>, Vec<VertexPosition<G>>)
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.to_line_buffers_with(|vertex| *vertex.position())
+    }
+
+    /// Gets an index buffer and vertex buffer of the line segments formed by
+    /// the edges in the graph.
+    ///
+    /// Each edge is emitted once as a pair of indices into the vertex buffer,
+    /// which contains the data of each vertex in the graph. This is like
+    /// [`wireframe_indexed`], but copies vertex data rather than positions.
+    /// Boundary edges can be distinguished via [`boundary_wireframe`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graph has more than [`u32::MAX`] vertices.
+    ///
+    /// [`boundary_wireframe`]: crate::graph::MeshGraph::boundary_wireframe
+    /// [`u32::MAX`]: std::u32::MAX
+    /// [`wireframe_indexed`]: crate::graph::MeshGraph::wireframe_indexed
+    pub fn to_line_buffers(&self) -> (Vec<u32>, Vec<G::Vertex>) {
+        self.to_line_buffers_with(|vertex| vertex.get().clone())
+    }
+
+    fn to_line_buffers_with<T, F>(&self, mut f: F) -> (Vec<u32>, Vec<T>)
+    where
+        F: FnMut(VertexView<&Self>) -> T,
+    {
+        let mut indices = HashMap::with_capacity(self.vertex_count());
+        let mut vertices = Vec::with_capacity(self.vertex_count());
+        for vertex in self.vertices() {
+            let index = u32::try_from(vertices.len()).expect("index overflow");
+            indices.insert(vertex.key(), index);
+            vertices.push(f(vertex));
+        }
+        let mut segments = Vec::with_capacity(self.edge_count() * 2);
+        for edge in self.edges() {
+            let (a, b): (VertexKey<K>, VertexKey<K>) = edge.arc().key().into();
+            segments.push(indices[&a]);
+            segments.push(indices[&b]);
+        }
+        (segments, vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn wireframe() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let segments = graph.wireframe();
+        assert_eq!(12, segments.len());
+        for [a, b] in segments {
+            assert!(((b - a).norm() - 1.0).abs() < 1e-9);
+        }
+        assert!(graph.boundary_wireframe().is_empty());
+
+        let (indices, positions) = graph.wireframe_indexed();
+        assert_eq!(24, indices.len());
+        assert_eq!(8, positions.len());
+        assert!(indices
+            .iter()
+            .all(|index| (*index as usize) < positions.len()));
+        let (indices, vertices) = graph.to_line_buffers();
+        assert_eq!(24, indices.len());
+        assert_eq!(positions, vertices);
+
+        // All edges of a single quadrilateral are boundary edges.
+        let graph = MeshGraph::<Point3<f64>>::from_heightmap(&[0.0; 4], 2, 2).unwrap();
+        assert_eq!(4, graph.boundary_wireframe().len());
+    }
+}
@@ -77,16 +77,19 @@
 //! [`UnboundedPolygon`]: crate::primitive::UnboundedPolygon
 //! [`primitive`]: crate::primitive
 
-use num::{Integer, NumCast, Unsigned};
+use num::{Integer, NumCast, Unsigned, Zero};
+use smallvec::SmallVec;
 use std::cmp;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::marker::PhantomData;
 use theon::adjunct::Map;
+use theon::space::{Basis, EuclideanSpace, InnerSpace, Scalar, Vector};
 use typenum::NonZero;
 
 use crate::constant::{Constant, ToType, TypeOf};
+use crate::geometry;
 use crate::primitive::decompose::IntoVertices;
 use crate::primitive::Topological;
 use crate::{Monomorphic, StaticArity};
@@ -505,6 +508,131 @@ where
     }
 }
 
+/// Spatial vertex indexer.
+///
+/// This indexer partitions positions into a uniform grid to form an index.
+/// Positions within a given distance of a position that has already been
+/// indexed are considered the same vertex and share its index. Unlike
+/// [`HashIndexer`] and [`LruIndexer`], which compare key data exactly, this
+/// merges vertices that are equal but for rounding, such as those computed by
+/// floating-point operations in procedural geometry. Such vertices would
+/// otherwise produce cracks in the resulting mesh.
+///
+/// The vertex key data must be a position in a Euclidean space and need not
+/// implement [`Hash`]. Each vertex is merged with the first indexed vertex that
+/// is within the given distance and so indexing is sensitive to the order of
+/// vertices. See [`WeldPositions`] for welding positions in an iterator
+/// expression.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// # extern crate theon;
+/// #
+/// use nalgebra::{Point3, Vector3};
+/// use plexus::graph::MeshGraph;
+/// use plexus::index::SpatialIndexer;
+/// use plexus::prelude::*;
+/// use plexus::primitive::cube::Cube;
+/// use plexus::primitive::generate::Position;
+/// use theon::adjunct::Map;
+///
+/// type E3 = Point3<f64>;
+///
+/// // Offset the positions of each face slightly.
+/// let graph: MeshGraph<E3> = Cube::new()
+///     .polygons::<Position<E3>>()
+///     .enumerate()
+///     .map(|(n, polygon)| polygon.map(|position| position + Vector3::repeat(n as f64 * 1e-9)))
+///     .collect_with_indexer(SpatialIndexer::new(1e-6))
+///     .unwrap();
+///
+/// assert_eq!(8, graph.vertex_count());
+/// ```
+///
+/// [`Hash`]: std::hash::Hash
+/// [`HashIndexer`]: crate::index::HashIndexer
+/// [`LruIndexer`]: crate::index::LruIndexer
+/// [`WeldPositions`]: crate::primitive::WeldPositions
+pub struct SpatialIndexer<T, K>
+where
+    T: Topological,
+    K: EuclideanSpace,
+{
+    epsilon: Scalar<K>,
+    basis: SmallVec<[Vector<K>; 3]>,
+    cells: HashMap<SmallVec<[i64; 3]>, Vec<(K, usize)>>,
+    n: usize,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T, K> SpatialIndexer<T, K>
+where
+    T: Topological,
+    K: EuclideanSpace,
+    Vector<K>: Basis,
+{
+    /// Creates a new `SpatialIndexer` that merges positions within the given
+    /// distance.
+    ///
+    /// If the distance is not positive, then only positions that are exactly
+    /// equal are merged.
+    pub fn new<U>(epsilon: U) -> Self
+    where
+        U: Into<Scalar<K>>,
+    {
+        SpatialIndexer {
+            epsilon: epsilon.into(),
+            basis: Vector::<K>::canonical_basis().into_iter().collect(),
+            cells: HashMap::new(),
+            n: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    fn find(&self, origin: &[i64], key: &K) -> Option<usize> {
+        let epsilon = self.epsilon;
+        geometry::neighboring_cells(origin)
+            .iter()
+            .flat_map(|neighbor| self.cells.get(neighbor).into_iter().flatten())
+            .find(|(other, _)| (*other - *key).square_magnitude() <= epsilon * epsilon)
+            .map(|(_, index)| *index)
+    }
+}
+
+impl<T, K> Indexer<T, K> for SpatialIndexer<T, K>
+where
+    T: Topological,
+    K: EuclideanSpace,
+    Vector<K>: Basis,
+{
+    fn index<F>(&mut self, input: T::Vertex, f: F) -> (usize, Option<T::Vertex>)
+    where
+        F: Fn(&T::Vertex) -> &K,
+    {
+        let key = *f(&input);
+        // A non-positive distance cannot size the cells of the grid, so all
+        // positions are placed into the same cell.
+        let origin = if self.epsilon > Zero::zero() {
+            geometry::grid_cell(&self.basis, key, self.epsilon)
+        }
+        else {
+            SmallVec::new()
+        };
+        if let Some(index) = self.find(&origin, &key) {
+            (index, None)
+        }
+        else {
+            let m = self.n;
+            self.n += 1;
+            self.cells.entry(origin).or_default().push((key, m));
+            (m, Some(input))
+        }
+    }
+}
+
 /// Functions for collecting an iterator of $n$-gons into raw index and vertex
 /// buffers.
 ///
@@ -6,7 +6,7 @@ use decorum::{Finite, Float, NotNan, Primitive, Total};
 use num::{NumCast, ToPrimitive};
 
 use crate::geometry::{FromGeometry, UnitGeometry};
-use crate::graph::GraphData;
+use crate::graph::{GraphData, Lerp};
 
 #[doc(hidden)]
 pub use self::cgmath::*;
@@ -129,6 +129,31 @@ where
     type Face = ();
 }
 
+impl<T> Lerp for Point2<T>
+where
+    T: Lerp,
+{
+    fn lerp(self, other: Self, t: f64) -> Self {
+        Point2 {
+            x: self.x.lerp(other.x, t),
+            y: self.y.lerp(other.y, t),
+        }
+    }
+}
+
+impl<T> Lerp for Point3<T>
+where
+    T: Lerp,
+{
+    fn lerp(self, other: Self, t: f64) -> Self {
+        Point3 {
+            x: self.x.lerp(other.x, t),
+            y: self.y.lerp(other.y, t),
+            z: self.z.lerp(other.z, t),
+        }
+    }
+}
+
 impl<T> UnitGeometry for Point3<T> {}
 
 macro_rules! impl_from_geometry_ordered {
@@ -3,7 +3,7 @@
 use theon::integration::glam;
 
 use crate::geometry::{FromGeometry, UnitGeometry};
-use crate::graph::GraphData;
+use crate::graph::{GraphData, Lerp};
 
 #[doc(hidden)]
 pub use self::glam::*;
@@ -47,6 +47,25 @@ impl GraphData for Vec3A {
     type Face = ();
 }
 
+// These use the inherent `lerp` functions of `glam` types.
+impl Lerp for Vec2 {
+    fn lerp(self, other: Self, t: f64) -> Self {
+        Vec2::lerp(self, other, t as f32)
+    }
+}
+
+impl Lerp for Vec3 {
+    fn lerp(self, other: Self, t: f64) -> Self {
+        Vec3::lerp(self, other, t as f32)
+    }
+}
+
+impl Lerp for Vec3A {
+    fn lerp(self, other: Self, t: f64) -> Self {
+        Vec3A::lerp(self, other, t as f32)
+    }
+}
+
 impl UnitGeometry for Vec2 {}
 
 impl UnitGeometry for Vec3 {}
@@ -9,7 +9,7 @@ use decorum::{Finite, Float, NotNan, Primitive, Total};
 use num::{NumCast, ToPrimitive};
 
 use crate::geometry::{FromGeometry, UnitGeometry};
-use crate::graph::GraphData;
+use crate::graph::{GraphData, Lerp};
 
 #[doc(hidden)]
 pub use self::nalgebra::*;
@@ -127,6 +127,17 @@ where
     type Face = ();
 }
 
+impl<T, D> Lerp for Point<T, D>
+where
+    T: Lerp + Scalar,
+    D: DimName,
+    DefaultAllocator: Allocator<T, D>,
+{
+    fn lerp(self, other: Self, t: f64) -> Self {
+        Point::from(self.coords.zip_map(&other.coords, |a, b| a.lerp(b, t)))
+    }
+}
+
 impl<T, D> UnitGeometry for Point<T, D>
 where
     T: Scalar,
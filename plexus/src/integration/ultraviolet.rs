@@ -5,7 +5,7 @@ use theon::integration::ultraviolet;
 use ultraviolet::vec::{Vec2, Vec3};
 
 use crate::geometry::{FromGeometry, UnitGeometry};
-use crate::graph::GraphData;
+use crate::graph::{GraphData, Lerp};
 
 #[doc(hidden)]
 pub use self::ultraviolet::*;
@@ -36,6 +36,22 @@ impl GraphData for Vec3 {
     type Face = ();
 }
 
+impl Lerp for Vec2 {
+    fn lerp(self, other: Self, t: f64) -> Self {
+        Vec2::new(self.x.lerp(other.x, t), self.y.lerp(other.y, t))
+    }
+}
+
+impl Lerp for Vec3 {
+    fn lerp(self, other: Self, t: f64) -> Self {
+        Vec3::new(
+            self.x.lerp(other.x, t),
+            self.y.lerp(other.y, t),
+            self.z.lerp(other.z, t),
+        )
+    }
+}
+
 impl UnitGeometry for Vec2 {}
 
 impl UnitGeometry for Vec3 {}
@@ -16,12 +16,15 @@
 pub mod buffer;
 pub mod builder;
 pub mod constant;
+pub mod deformation;
 pub mod encoding;
 mod entity;
 pub mod geometry;
 pub mod graph;
 pub mod index;
 pub mod integration;
+pub mod metric;
+pub mod parameterization;
 pub mod primitive;
 mod transact;
 
@@ -78,7 +81,8 @@ pub mod prelude {
     };
     pub use crate::primitive::generate::Generator as _;
     pub use crate::primitive::{
-        IntoPolygons as _, MapVertices as _, Polygonal as _, Topological as _,
+        IntoPolygons as _, MapVertices as _, Polygonal as _, SnapPositions as _, Topological as _,
+        WeldPositions as _,
     };
     pub use crate::DynamicArity as _;
     pub use crate::IteratorExt as _;
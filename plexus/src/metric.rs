@@ -0,0 +1,1256 @@
+//! Metrics that compare the surfaces of meshes.
+//!
+//! Most of these metrics are approximated by sampling points on the surfaces
+//! of meshes and are useful for quantifying how much an operation like
+//! decimation or smoothing has deviated from an original mesh. Sampling is
+//! seeded, so the results for a given pair of meshes and sample count are
+//! deterministic. Sampling metrics require the `rand` feature.
+//!
+//! This module also provides metrics that assess the quality of a mapping of a
+//! surface, such as [`parameterization_stretch`], and metrics over a single
+//! surface, such as [`heat_geodesics`] and [`spectral_embedding`].
+//!
+//! [`heat_geodesics`]: crate::metric::heat_geodesics
+//! [`parameterization_stretch`]: crate::metric::parameterization_stretch
+//! [`spectral_embedding`]: crate::metric::spectral_embedding
+
+#[cfg(feature = "rand")]
+use num::Zero;
+use num::{NumCast, ToPrimitive};
+#[cfg(feature = "rand")]
+use rand::rngs::StdRng;
+#[cfg(feature = "rand")]
+use rand::SeedableRng;
+use std::collections::HashMap;
+#[cfg(feature = "rand")]
+use theon::space::{Basis, InnerSpace, Scalar, Vector};
+use theon::space::{EuclideanSpace, FiniteDimensional};
+use theon::AsPosition;
+use typenum::U3;
+
+#[cfg(feature = "rand")]
+use crate::geometry::tree::KdTree;
+use crate::geometry::{self, add, cross, dot, norm, scale, sub};
+use crate::graph::{
+    ClosedView, FaceKey, GraphData, GraphError, KeyIndex, MeshGraph, TopologyKind, VertexKey,
+    VertexPosition,
+};
+
+const SEED: u64 = 0;
+
+/// Computes the Chamfer distance between the surfaces of two graphs.
+///
+/// `samples` points are sampled uniformly on the surface of each graph (see
+/// [`MeshGraph::sample_surface`]). For each sample, the distance to the nearest
+/// point on the faces of the other graph is computed. Faces are indexed by a
+/// $k$-d tree (see [`KdTree`]), so each query only examines nearby faces. The
+/// result is the **average** of the mean nearest distances in both
+/// directions. That is, it is half of the symmetric sum that is sometimes used
+/// in the literature. Distances are not squared.
+///
+/// Returns `None` if either graph has no area or `samples` is zero.
+///
+/// This function requires the `rand` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate decorum;
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use decorum::R64;
+/// use nalgebra::Point3;
+/// use plexus::graph::MeshGraph;
+/// use plexus::metric;
+/// use plexus::prelude::*;
+/// use plexus::primitive::generate::Position;
+/// use plexus::primitive::sphere::UvSphere;
+///
+/// type E3 = Point3<f64>;
+///
+/// let a: MeshGraph<E3> = UvSphere::new(32, 16)
+///     .polygons::<Position<Point3<R64>>>()
+///     .collect();
+/// let mut b: MeshGraph<E3> = UvSphere::new(32, 16)
+///     .polygons::<Position<Point3<R64>>>()
+///     .collect();
+/// b.smooth(0.5);
+///
+/// let distance = metric::chamfer_distance(&a, &b, 1024).unwrap();
+/// ```
+///
+/// [`KdTree`]: crate::geometry::tree::KdTree
+/// [`MeshGraph::sample_surface`]: crate::graph::MeshGraph::sample_surface
+#[cfg(feature = "rand")]
+pub fn chamfer_distance<G, K>(
+    a: &MeshGraph<G, K>,
+    b: &MeshGraph<G, K>,
+    samples: usize,
+) -> Option<f64>
+where
+    G: GraphData,
+    K: KeyIndex,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: Basis,
+{
+    let (ab, ba) = surface_distances(a, b, samples)?;
+    Some((mean(ab)? + mean(ba)?) / 2.0)
+}
+
+/// Computes the Hausdorff distance between the surfaces of two graphs.
+///
+/// This is the maximum of the directed Hausdorff distances returned by
+/// [`directed_hausdorff_distances`]. Unlike [`chamfer_distance`], which
+/// averages deviation, this captures the worst-case deviation between the
+/// surfaces.
+///
+/// Returns `None` if either graph has no area or `samples` is zero.
+///
+/// This function requires the `rand` feature.
+///
+/// [`chamfer_distance`]: crate::metric::chamfer_distance
+/// [`directed_hausdorff_distances`]: crate::metric::directed_hausdorff_distances
+#[cfg(feature = "rand")]
+pub fn hausdorff_distance<G, K>(
+    a: &MeshGraph<G, K>,
+    b: &MeshGraph<G, K>,
+    samples: usize,
+) -> Option<f64>
+where
+    G: GraphData,
+    K: KeyIndex,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: Basis,
+{
+    directed_hausdorff_distances(a, b, samples).map(|(ab, ba)| ab.max(ba))
+}
+
+/// Computes the directed Hausdorff distances between the surfaces of two
+/// graphs.
+///
+/// `samples` points are sampled uniformly on the surface of each graph and
+/// their distances to the other surface are computed in the same manner as
+/// [`chamfer_distance`]. The first distance is the maximum distance from a
+/// sample on `a` to the nearest point on the surface of `b` and the second
+/// distance is the maximum distance from a sample on `b` to the nearest point
+/// on the surface of `a`.
+///
+/// Returns `None` if either graph has no area or `samples` is zero.
+///
+/// This function requires the `rand` feature.
+///
+/// [`chamfer_distance`]: crate::metric::chamfer_distance
+#[cfg(feature = "rand")]
+pub fn directed_hausdorff_distances<G, K>(
+    a: &MeshGraph<G, K>,
+    b: &MeshGraph<G, K>,
+    samples: usize,
+) -> Option<(f64, f64)>
+where
+    G: GraphData,
+    K: KeyIndex,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: Basis,
+{
+    let (ab, ba) = surface_distances(a, b, samples)?;
+    Some((max(ab)?, max(ba)?))
+}
+
+/// Stretch of a single triangle in a parameterization.
+///
+/// See [`parameterization_stretch`].
+///
+/// [`parameterization_stretch`]: crate::metric::parameterization_stretch
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stretch {
+    /// The root-mean-square stretch over all directions in the triangle.
+    pub l2: f64,
+    /// The greatest stretch in any direction in the triangle.
+    pub lmax: f64,
+}
+
+/// Stretch of a parameterization.
+///
+/// See [`parameterization_stretch`].
+///
+/// [`parameterization_stretch`]: crate::metric::parameterization_stretch
+#[derive(Clone, Debug, PartialEq)]
+pub struct StretchReport<K = u64>
+where
+    K: KeyIndex,
+{
+    /// The stretch of each triangle.
+    pub faces: HashMap<FaceKey<K>, Stretch>,
+    /// The mean of the $L^2$ stretch of the triangles.
+    pub mean: f64,
+    /// The variance of the $L^2$ stretch of the triangles.
+    pub variance: f64,
+    /// The normalized $L^2$ stretch of the parameterization.
+    pub l2: f64,
+    /// The normalized $L^\infty$ stretch of the parameterization.
+    pub lmax: f64,
+}
+
+/// Computes the stretch of a parameterization of the surface of a graph.
+///
+/// `uv` maps vertices to texture coordinates. The $L^2$ and $L^\infty$
+/// stretch metrics described by Sander et al. are computed for each
+/// triangular face that has texture coordinates for all of its vertices. Other
+/// faces are ignored. Triangles that are degenerate or flipped in texture
+/// space have infinite stretch.
+///
+/// The global $L^2$ stretch is the root of the mean squared $L^2$ stretch of
+/// the triangles weighted by surface area. The global metrics are normalized
+/// by the ratio of the total surface and texture areas, so that a
+/// parameterization that is an isometry up to scale has a stretch of one. The
+/// stretch of each triangle is not normalized.
+///
+/// Returns `None` if there are no such triangles.
+pub fn parameterization_stretch<G, K>(
+    graph: &MeshGraph<G, K>,
+    uv: &HashMap<VertexKey<K>, [f64; 2]>,
+) -> Option<StretchReport<K>>
+where
+    G: GraphData,
+    K: KeyIndex,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let mut faces = HashMap::new();
+    let mut area = (0.0, 0.0); // Surface and texture area.
+    let mut square = 0.0; // Area weighted squared L2 stretch.
+    let mut lmax = 0.0f64;
+    for face in graph.faces() {
+        let vertices = face
+            .adjacent_vertices()
+            .map(|vertex| {
+                let q = geometry::into_f64(*vertex.position())?;
+                uv.get(&vertex.key()).map(|p| (q, *p))
+            })
+            .collect::<Option<Vec<_>>>();
+        let [(q1, [s1, t1]), (q2, [s2, t2]), (q3, [s3, t3])] = match vertices.as_deref() {
+            Some(&[a, b, c]) => [a, b, c],
+            _ => continue,
+        };
+        let a = (((s2 - s1) * (t3 - t1)) - ((s3 - s1) * (t2 - t1))) / 2.0;
+        let (ab, ac) = (sub(q2, q1), sub(q3, q1));
+        let b = norm(cross(ab, ac)) / 2.0;
+        let stretch = if a > 0.0 {
+            // Partial derivatives of the mapping from texture space to the
+            // surface.
+            let derivative = |w: [f64; 3]| {
+                [0, 1, 2].map(|i| ((q1[i] * w[0]) + (q2[i] * w[1]) + (q3[i] * w[2])) / (2.0 * a))
+            };
+            let ss = derivative([t2 - t3, t3 - t1, t1 - t2]);
+            let st = derivative([s3 - s2, s1 - s3, s2 - s1]);
+            let (e, f, g) = (dot(ss, ss), dot(ss, st), dot(st, st));
+            Stretch {
+                l2: ((e + g) / 2.0).sqrt(),
+                lmax: ((e + g + ((e - g).powi(2) + (4.0 * f * f)).sqrt()) / 2.0).sqrt(),
+            }
+        }
+        else {
+            Stretch {
+                l2: f64::INFINITY,
+                lmax: f64::INFINITY,
+            }
+        };
+        area = (area.0 + b, area.1 + a.max(0.0));
+        square += stretch.l2 * stretch.l2 * b;
+        lmax = lmax.max(stretch.lmax);
+        faces.insert(face.key(), stretch);
+    }
+    let average = mean(faces.values().map(|stretch| stretch.l2))?;
+    let variance = mean(faces.values().map(|stretch| (stretch.l2 - average).powi(2)))?;
+    let scale = (area.1 / area.0).sqrt();
+    Some(StretchReport {
+        faces,
+        mean: average,
+        variance,
+        l2: (square / area.0).sqrt() * scale,
+        lmax: lmax * scale,
+    })
+}
+
+/// Computes geodesic distances from source vertices over the surface of a graph
+/// using the heat method.
+///
+/// The heat method of Crane et al. integrates the heat equation from the
+/// source vertices for the given time, normalizes the gradient of the result,
+/// and recovers distances by solving a Poisson equation. The linear systems
+/// are formed from the cotangent Laplacian of the graph and are solved
+/// iteratively. A time of the square of the mean edge length of the graph is
+/// typical. Shorter times produce more accurate distances, but are more
+/// sensitive to the quality of the triangulation.
+///
+/// Returns the distance of each vertex from the nearest source vertex. The
+/// distances of vertices that cannot be reached from any source vertex are
+/// unspecified.
+///
+/// # Errors
+///
+/// Returns an error if a source vertex is not found or no source vertices are
+/// given, if the graph has any non-triangular faces, or if the time is not
+/// positive or geometry is degenerate. Errors caused by a particular vertex or
+/// face are wrapped with its key (see [`GraphError::AtKey`]).
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate decorum;
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use decorum::R64;
+/// use nalgebra::Point3;
+/// use plexus::graph::MeshGraph;
+/// use plexus::metric;
+/// use plexus::prelude::*;
+/// use plexus::primitive::generate::Position;
+/// use plexus::primitive::sphere::UvSphere;
+///
+/// type E3 = Point3<f64>;
+///
+/// let graph: MeshGraph<E3> = UvSphere::new(32, 16)
+///     .polygons::<Position<Point3<R64>>>()
+///     .triangulate()
+///     .collect();
+/// let key = graph.vertices().nth(0).unwrap().key();
+/// let distances = metric::heat_geodesics(&graph, &[key], 0.01).unwrap();
+/// ```
+///
+/// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+pub fn heat_geodesics<G, K>(
+    graph: &MeshGraph<G, K>,
+    sources: &[VertexKey<K>],
+    time: f64,
+) -> Result<HashMap<VertexKey<K>, f64>, GraphError>
+where
+    G: GraphData,
+    K: KeyIndex,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    if sources.is_empty() {
+        return Err(GraphError::TopologyNotFound(TopologyKind::Vertex));
+    }
+    if let Some(key) = sources.iter().find(|key| graph.vertex(**key).is_none()) {
+        return Err(GraphError::TopologyNotFound(TopologyKind::Vertex).at_key(*key));
+    }
+    if time <= 0.0 {
+        return Err(GraphError::Geometry);
+    }
+    let laplacian = CotangentLaplacian::from_graph(graph)?;
+    let CotangentLaplacian {
+        keys,
+        indices,
+        positions,
+        triangles,
+        cotangents,
+        mass,
+        ..
+    } = &laplacian;
+    let n = positions.len();
+    // Integrate heat from the sources by solving `(M + tL)u = δ`.
+    let mut heat = vec![0.0; n];
+    for key in sources {
+        heat[indices[key]] = 1.0;
+    }
+    let heat = conjugate_gradient(
+        |x: &[f64]| {
+            laplacian
+                .multiply(x)
+                .into_iter()
+                .zip(x.iter().zip(mass.iter()))
+                .map(|(lx, (x, m))| (m * x) + (time * lx))
+                .collect()
+        },
+        &heat,
+    );
+    // Compute the normalized gradient field `X = -∇u/|∇u|` of the heat in each
+    // triangle and its integrated divergence at each vertex.
+    let mut divergence = vec![0.0; n];
+    for ([a, b, c], cotangent) in triangles.iter().cloned().zip(cotangents.iter().cloned()) {
+        let [p, q, r] = [positions[a], positions[b], positions[c]];
+        let normal = cross(sub(q, p), sub(r, p));
+        let gradient = [(a, sub(r, q)), (b, sub(p, r)), (c, sub(q, p))]
+            .iter()
+            .map(|(i, edge)| scale(cross(normal, *edge), heat[*i]))
+            .fold([0.0; 3], add);
+        let magnitude = norm(gradient);
+        if magnitude.is_nan() || magnitude <= 0.0 {
+            continue;
+        }
+        let field = scale(gradient, -1.0 / magnitude);
+        let corners = [(a, b, c), (b, c, a), (c, a, b)];
+        for (index, (i, j, k)) in corners.iter().cloned().enumerate() {
+            // The cotangents at `k` and `j` weight the edges `ij` and `ik`.
+            let (cj, ck) = (cotangent[(index + 1) % 3], cotangent[(index + 2) % 3]);
+            let ij = dot(sub(positions[j], positions[i]), field);
+            let ik = dot(sub(positions[k], positions[i]), field);
+            divergence[i] += ((ck * ij) + (cj * ik)) / 2.0;
+        }
+    }
+    // Recover distances by solving `Lφ = -∇·X` and shifting the solution so
+    // that the least distance is zero.
+    let rhs = divergence.iter().map(|d| -d).collect::<Vec<_>>();
+    let distance = conjugate_gradient(|x: &[f64]| laplacian.multiply(x), &rhs);
+    let min = distance.iter().cloned().fold(f64::INFINITY, f64::min);
+    Ok(keys
+        .iter()
+        .cloned()
+        .zip(distance)
+        .map(|(key, distance)| (key, distance - min))
+        .collect())
+}
+
+/// Computes a spectral embedding of the vertices of a graph.
+///
+/// The embedding of a vertex is formed from the eigenvectors of the cotangent
+/// Laplacian of the graph with the least non-zero eigenvalues, solved
+/// iteratively as a generalized eigenproblem with the (lumped) mass matrix of
+/// the graph. The trivial eigenvector (a constant function) is excluded. The
+/// first coordinate of an embedding is given by the Fiedler vector, which
+/// varies most slowly over the surface. Spectral embeddings are useful for
+/// segmentation and shape analysis, because they are intrinsic and so
+/// insensitive to isometric deformations of the surface.
+///
+/// Returns the embedding of each vertex, which has `dims` coordinates.
+/// Eigenvectors are normalized with respect to the mass matrix, but their
+/// signs are arbitrary.
+///
+/// # Errors
+///
+/// Returns [`GraphError::DimensionConflict`] if the graph does not have more
+/// vertices than `dims`, because the eigenvectors exclude the trivial
+/// eigenvector and so at most one fewer than the number of vertices are
+/// available. Returns [`GraphError::ArityConflict`] if the graph has any
+/// non-triangular faces and an error if geometry is degenerate.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate decorum;
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use decorum::R64;
+/// use nalgebra::Point3;
+/// use plexus::graph::MeshGraph;
+/// use plexus::metric;
+/// use plexus::prelude::*;
+/// use plexus::primitive::generate::Position;
+/// use plexus::primitive::sphere::UvSphere;
+///
+/// type E3 = Point3<f64>;
+///
+/// let graph: MeshGraph<E3> = UvSphere::new(16, 8)
+///     .polygons::<Position<Point3<R64>>>()
+///     .triangulate()
+///     .collect();
+/// let embedding = metric::spectral_embedding(&graph, 3).unwrap();
+/// ```
+///
+/// [`GraphError::ArityConflict`]: crate::graph::GraphError::ArityConflict
+/// [`GraphError::DimensionConflict`]: crate::graph::GraphError::DimensionConflict
+pub fn spectral_embedding<G, K>(
+    graph: &MeshGraph<G, K>,
+    dims: usize,
+) -> Result<HashMap<VertexKey<K>, Vec<f64>>, GraphError>
+where
+    G: GraphData,
+    K: KeyIndex,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    if graph.vertex_count() <= dims {
+        return Err(GraphError::DimensionConflict {
+            max: graph.vertex_count().saturating_sub(1),
+            actual: dims,
+        });
+    }
+    let laplacian = CotangentLaplacian::from_graph(graph)?;
+    let eigenvectors = laplacian
+        .least_eigenpairs(dims)
+        .into_iter()
+        .map(|(_, eigenvector)| eigenvector)
+        .collect::<Vec<_>>();
+    Ok(laplacian
+        .keys
+        .iter()
+        .cloned()
+        .enumerate()
+        .map(|(i, key)| (key, eigenvectors.iter().map(|vector| vector[i]).collect()))
+        .collect())
+}
+
+/// Sparse square matrix.
+///
+/// Rows are stored in order and only include non-zero entries, which are
+/// sorted by column. See [`CotangentLaplacian`].
+///
+/// [`CotangentLaplacian`]: crate::metric::CotangentLaplacian
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseMatrix {
+    rows: Vec<Vec<(usize, f64)>>,
+}
+
+impl SparseMatrix {
+    /// Gets the number of rows (and columns) of the matrix.
+    pub fn dimension(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Gets the non-zero entries of a row as pairs of a column and a value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn row(&self, row: usize) -> &[(usize, f64)] {
+        &self.rows[row]
+    }
+
+    /// Gets the entry at the given row and column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn get(&self, row: usize, column: usize) -> f64 {
+        let row = self.row(row);
+        row.binary_search_by_key(&column, |(column, _)| *column)
+            .map(|index| row[index].1)
+            .unwrap_or(0.0)
+    }
+
+    /// Gets an iterator over the non-zero entries of the matrix as triplets of
+    /// a row, a column, and a value.
+    ///
+    /// Triplets are ordered by row and then by column. This is the coordinate
+    /// (COO) format accepted by most sparse linear algebra libraries.
+    pub fn triplets(&self) -> impl '_ + Iterator<Item = (usize, usize, f64)> {
+        self.rows.iter().enumerate().flat_map(|(row, entries)| {
+            entries
+                .iter()
+                .map(move |(column, value)| (row, *column, *value))
+        })
+    }
+
+    /// Computes the product of the matrix and a vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the vector is less than the dimension of the
+    /// matrix.
+    pub fn multiply(&self, x: &[f64]) -> Vec<f64> {
+        self.rows
+            .iter()
+            .map(|row| row.iter().map(|(j, value)| value * x[*j]).sum::<f64>())
+            .collect()
+    }
+}
+
+/// Cotangent Laplacian and (lumped) mass matrix of a triangular graph.
+///
+/// These operators are used to solve problems over the surface of a graph,
+/// such as [`heat_geodesics`] and [`spectral_embedding`]. They are exposed so
+/// that they can be used with other solvers for parameterization, spectral
+/// analysis, deformation, etc. See [`MeshGraph::cotangent_laplacian`].
+///
+/// Vertices are densely indexed from zero and each index corresponds to a row
+/// and column of the matrices. The Laplacian is symmetric and positive
+/// semi-definite: the weight between adjacent vertices is the negated half sum
+/// of the cotangents of the angles opposite their edge and the diagonal is
+/// the sum of these weights. Rows of the Laplacian only include adjacent
+/// vertices and the diagonal. The mass matrix is diagonal and assigns a third
+/// of the area of each triangle to each of its vertices.
+///
+/// [`heat_geodesics`]: crate::metric::heat_geodesics
+/// [`spectral_embedding`]: crate::metric::spectral_embedding
+/// [`MeshGraph::cotangent_laplacian`]: crate::graph::MeshGraph::cotangent_laplacian
+pub struct CotangentLaplacian<K = u64>
+where
+    K: KeyIndex,
+{
+    keys: Vec<VertexKey<K>>,
+    indices: HashMap<VertexKey<K>, usize>,
+    positions: Vec<[f64; 3]>,
+    triangles: Vec<[usize; 3]>,
+    // Cotangents of the interior angles at the vertices of each triangle.
+    cotangents: Vec<[f64; 3]>,
+    mass: Vec<f64>,
+    laplacian: SparseMatrix,
+}
+
+impl<K> CotangentLaplacian<K>
+where
+    K: KeyIndex,
+{
+    /// Gets the keys of the vertices in index order.
+    pub fn keys(&self) -> &[VertexKey<K>] {
+        &self.keys
+    }
+
+    /// Gets the index of the vertex with the given key.
+    pub fn index(&self, key: VertexKey<K>) -> Option<usize> {
+        self.indices.get(&key).cloned()
+    }
+
+    /// Gets the Laplacian matrix.
+    pub fn laplacian(&self) -> &SparseMatrix {
+        &self.laplacian
+    }
+
+    /// Gets the diagonal of the mass matrix.
+    pub fn mass(&self) -> &[f64] {
+        &self.mass
+    }
+
+    pub(crate) fn from_graph<G>(graph: &MeshGraph<G, K>) -> Result<Self, GraphError>
+    where
+        G: GraphData,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let mut keys = Vec::with_capacity(graph.vertex_count());
+        let mut indices = HashMap::with_capacity(graph.vertex_count());
+        let mut positions = Vec::with_capacity(graph.vertex_count());
+        for vertex in graph.vertices() {
+            let position = geometry::into_f64(*vertex.position())
+                .ok_or_else(|| GraphError::Geometry.at_key(vertex.key()))?;
+            indices.insert(vertex.key(), keys.len());
+            keys.push(vertex.key());
+            positions.push(position);
+        }
+        let mut faces = Vec::with_capacity(graph.face_count());
+        let mut triangles = Vec::with_capacity(graph.face_count());
+        for face in graph.faces() {
+            let vertices = face
+                .adjacent_vertices()
+                .map(|vertex| indices[&vertex.key()])
+                .collect::<Vec<_>>();
+            triangles.push(match vertices.as_slice() {
+                &[a, b, c] => [a, b, c],
+                _ => {
+                    return Err(GraphError::ArityConflict {
+                        expected: 3,
+                        actual: vertices.len(),
+                    }
+                    .at_key(face.key()))
+                }
+            });
+            faces.push(face.key());
+        }
+        let n = positions.len();
+        let mut mass = vec![0.0; n];
+        let mut rows = vec![HashMap::<usize, f64>::new(); n];
+        let mut cotangents = Vec::with_capacity(triangles.len());
+        for (face, [a, b, c]) in faces.into_iter().zip(triangles.iter().cloned()) {
+            let [p, q, r] = [positions[a], positions[b], positions[c]];
+            let area = norm(cross(sub(q, p), sub(r, p))) / 2.0;
+            if area.is_nan() || area <= 0.0 {
+                return Err(GraphError::Geometry.at_key(face));
+            }
+            let cotangent = |i: usize, j: usize, k: usize| {
+                let (u, v) = (
+                    sub(positions[j], positions[i]),
+                    sub(positions[k], positions[i]),
+                );
+                dot(u, v) / norm(cross(u, v))
+            };
+            let cotangent = [cotangent(a, b, c), cotangent(b, c, a), cotangent(c, a, b)];
+            let vertices = [a, b, c];
+            for (index, i) in vertices.iter().cloned().enumerate() {
+                // The cotangent at `i` weights the opposite edge `jk`.
+                let (j, k) = (vertices[(index + 1) % 3], vertices[(index + 2) % 3]);
+                let weight = cotangent[index] / 2.0;
+                *rows[j].entry(k).or_insert(0.0) -= weight;
+                *rows[k].entry(j).or_insert(0.0) -= weight;
+                *rows[j].entry(j).or_insert(0.0) += weight;
+                *rows[k].entry(k).or_insert(0.0) += weight;
+                mass[i] += area / 3.0;
+            }
+            cotangents.push(cotangent);
+        }
+        let rows = rows
+            .into_iter()
+            .map(|row| {
+                let mut row = row.into_iter().collect::<Vec<_>>();
+                row.sort_unstable_by_key(|(column, _)| *column);
+                row
+            })
+            .collect::<Vec<_>>();
+        Ok(CotangentLaplacian {
+            keys,
+            indices,
+            positions,
+            triangles,
+            cotangents,
+            mass,
+            laplacian: SparseMatrix { rows },
+        })
+    }
+
+    // Computes the product `Lx`.
+    fn multiply(&self, x: &[f64]) -> Vec<f64> {
+        self.laplacian.multiply(x)
+    }
+
+    // Computes the product `Mx`.
+    fn weigh(&self, x: &[f64]) -> Vec<f64> {
+        x.iter().zip(&self.mass).map(|(x, m)| m * x).collect()
+    }
+
+    // Finds the eigenpairs of the generalized eigenproblem `Lφ = λMφ` with the
+    // least eigenvalues, excluding the constant eigenvector with eigenvalue
+    // zero. Each eigenvector is found by inverse iteration and deflated
+    // against the eigenvectors found before it, so the eigenvectors are
+    // `M`-orthonormal.
+    fn least_eigenpairs(&self, count: usize) -> Vec<(f64, Vec<f64>)> {
+        const ITERATIONS: usize = 256;
+        const TOLERANCE: f64 = 1e-12;
+
+        let n = self.mass.len();
+        let mut state = SEED;
+        let mut basis = vec![vec![1.0; n]];
+        let mut pairs = Vec::with_capacity(count);
+        // Projects a vector onto the `M`-orthogonal complement of the basis and
+        // normalizes it with respect to `M`.
+        let project = |basis: &[Vec<f64>], mut x: Vec<f64>| {
+            for _ in 0..2 {
+                for u in basis {
+                    let mu = self.weigh(u);
+                    let coefficient = inner(&x, &mu) / inner(u, &mu);
+                    for (x, u) in x.iter_mut().zip(u) {
+                        *x -= coefficient * u;
+                    }
+                }
+            }
+            let magnitude = inner(&x, &self.weigh(&x)).sqrt();
+            if magnitude > 0.0 {
+                for x in x.iter_mut() {
+                    *x /= magnitude;
+                }
+            }
+            x
+        };
+        for _ in 0..count {
+            let mut x = (0..n)
+                .map(|_| uniform(&mut state) - 0.5)
+                .collect::<Vec<_>>();
+            let mut eigenvalue = f64::INFINITY;
+            for _ in 0..ITERATIONS {
+                x = project(&basis, x);
+                let next = inner(&x, &self.multiply(&x));
+                if (eigenvalue - next).abs() <= TOLERANCE * next.abs().max(1.0) {
+                    eigenvalue = next;
+                    break;
+                }
+                eigenvalue = next;
+                // The right-hand side is orthogonal to the null space of `L`,
+                // so this (singular) system is consistent.
+                x = conjugate_gradient(|y: &[f64]| self.multiply(y), &self.weigh(&x));
+            }
+            let x = project(&basis, x);
+            basis.push(x.clone());
+            pairs.push((eigenvalue, x));
+        }
+        pairs
+    }
+}
+
+// Samples the surfaces of two graphs and computes the distance from each
+// sample to the nearest point on the surface of the other graph.
+#[cfg(feature = "rand")]
+fn surface_distances<G, K>(
+    a: &MeshGraph<G, K>,
+    b: &MeshGraph<G, K>,
+    samples: usize,
+) -> Option<(
+    Vec<Scalar<VertexPosition<G>>>,
+    Vec<Scalar<VertexPosition<G>>>,
+)>
+where
+    G: GraphData,
+    K: KeyIndex,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: Basis,
+{
+    if samples == 0 {
+        return None;
+    }
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let (sa, sb) = (
+        a.sample_surface(samples, &mut rng),
+        b.sample_surface(samples, &mut rng),
+    );
+    let (ta, tb) = (
+        SurfaceTree::new(a.surface_triangles())?,
+        SurfaceTree::new(b.surface_triangles())?,
+    );
+    Some((
+        sa.into_iter().map(|point| tb.distance(point)).collect(),
+        sb.into_iter().map(|point| ta.distance(point)).collect(),
+    ))
+}
+
+// Triangles on a surface indexed for closest point queries.
+//
+// Triangles are indexed by their centroids in a $k$-d tree. Every point of a
+// triangle lies within `radius` of its centroid, so any triangle that is
+// nearer to a point than some distance has a centroid within that distance
+// plus `radius` of the point.
+#[cfg(feature = "rand")]
+struct SurfaceTree<S>
+where
+    S: EuclideanSpace,
+{
+    tree: KdTree<S, usize>,
+    triangles: Vec<[S; 3]>,
+    radius: Scalar<S>,
+}
+
+#[cfg(feature = "rand")]
+impl<S> SurfaceTree<S>
+where
+    S: EuclideanSpace,
+    Vector<S>: Basis,
+{
+    fn new(triangles: Vec<[S; 3]>) -> Option<Self> {
+        if triangles.is_empty() {
+            return None;
+        }
+        let third = <Scalar<S> as NumCast>::from(1.0 / 3.0).unwrap();
+        let mut radius = Scalar::<S>::zero();
+        let tree = triangles
+            .iter()
+            .enumerate()
+            .map(|(index, [a, b, c])| {
+                let centroid = *a + (((*b - *a) + (*c - *a)) * third);
+                for position in [a, b, c] {
+                    let distance = (*position - centroid).magnitude();
+                    if distance > radius {
+                        radius = distance;
+                    }
+                }
+                (centroid, index)
+            })
+            .collect::<KdTree<_, _>>();
+        Some(SurfaceTree {
+            tree,
+            triangles,
+            radius,
+        })
+    }
+
+    // Gets the distance from a point to the nearest point on any triangle.
+    fn distance(&self, point: S) -> Scalar<S> {
+        let distance = |index: usize| {
+            (closest_point_on_triangle(point, self.triangles[index]) - point).magnitude()
+        };
+        // The triangle with the nearest centroid bounds the distance, so only
+        // triangles with centroids within that bound (plus the radius of the
+        // triangles) must be examined.
+        let bound = self
+            .tree
+            .nearest(point, 1)
+            .first()
+            .map(|index| distance(*index))
+            .unwrap_or_else(Zero::zero);
+        self.tree
+            .within_radius(point, bound + self.radius)
+            .into_iter()
+            .map(distance)
+            .fold(bound, |min, distance| {
+                if distance < min {
+                    distance
+                }
+                else {
+                    min
+                }
+            })
+    }
+}
+
+// Gets the point on the triangle `abc` that is nearest to the point `p`.
+//
+// This determines the Voronoi region of the triangle that contains the point
+// as described by Ericson in _Real-Time Collision Detection_. Only inner
+// products are used, so this applies to triangles in any dimension. The
+// triangle must not be degenerate.
+#[cfg(feature = "rand")]
+fn closest_point_on_triangle<S>(p: S, [a, b, c]: [S; 3]) -> S
+where
+    S: EuclideanSpace,
+{
+    let zero = Scalar::<S>::zero();
+    let (ab, ac) = (b - a, c - a);
+    let (d1, d2) = (ab.dot(p - a), ac.dot(p - a));
+    if d1 <= zero && d2 <= zero {
+        return a;
+    }
+    let (d3, d4) = (ab.dot(p - b), ac.dot(p - b));
+    if d3 >= zero && d4 <= d3 {
+        return b;
+    }
+    let vc = (d1 * d4) - (d3 * d2);
+    if vc <= zero && d1 >= zero && d3 <= zero {
+        return a + (ab * (d1 / (d1 - d3)));
+    }
+    let (d5, d6) = (ab.dot(p - c), ac.dot(p - c));
+    if d6 >= zero && d5 <= d6 {
+        return c;
+    }
+    let vb = (d5 * d2) - (d1 * d6);
+    if vb <= zero && d2 >= zero && d6 <= zero {
+        return a + (ac * (d2 / (d2 - d6)));
+    }
+    let va = (d3 * d6) - (d5 * d4);
+    if va <= zero && (d4 - d3) >= zero && (d5 - d6) >= zero {
+        return b + ((c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6))));
+    }
+    // The point projects into the interior of the triangle.
+    let area = va + vb + vc;
+    a + (ab * (vb / area)) + (ac * (vc / area))
+}
+
+// Solves the symmetric positive semi-definite system `Ax = b` by the conjugate
+// gradient method, where `f` computes the product `Ax`.
+pub(crate) fn conjugate_gradient<F>(f: F, b: &[f64]) -> Vec<f64>
+where
+    F: Fn(&[f64]) -> Vec<f64>,
+{
+    const TOLERANCE: f64 = 1e-10;
+
+    let mut x = vec![0.0; b.len()];
+    let mut r = b.to_vec();
+    let mut p = r.clone();
+    let mut square = inner(&r, &r);
+    let limit = TOLERANCE * TOLERANCE * square;
+    for _ in 0..(4 * b.len()) {
+        if square <= limit {
+            break;
+        }
+        let q = f(&p);
+        let curvature = inner(&p, &q);
+        if curvature.is_nan() || curvature <= 0.0 {
+            break;
+        }
+        let alpha = square / curvature;
+        for ((x, p), (r, q)) in x.iter_mut().zip(&p).zip(r.iter_mut().zip(&q)) {
+            *x += alpha * p;
+            *r -= alpha * q;
+        }
+        let next = inner(&r, &r);
+        let beta = next / square;
+        for (p, r) in p.iter_mut().zip(&r) {
+            *p = r + (beta * *p);
+        }
+        square = next;
+    }
+    x
+}
+
+// Gets a pseudorandom value in `[0, 1)` and advances the given state using the
+// SplitMix64 generator. This provides deterministic values that are unlikely
+// to be degenerate without requiring the `rand` feature.
+fn uniform(state: &mut u64) -> f64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+    (z >> 11) as f64 / (1u64 << 53) as f64
+}
+
+fn inner(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn mean<T, I>(distances: I) -> Option<f64>
+where
+    T: ToPrimitive,
+    I: IntoIterator<Item = T>,
+{
+    let (sum, count) = distances
+        .into_iter()
+        .fold((0.0, 0usize), |(sum, count), distance| {
+            (sum + distance.to_f64().unwrap_or(0.0), count + 1)
+        });
+    if count == 0 {
+        None
+    }
+    else {
+        <f64 as NumCast>::from(count).map(|count| sum / count)
+    }
+}
+
+#[cfg(feature = "rand")]
+fn max<T, I>(distances: I) -> Option<f64>
+where
+    T: ToPrimitive,
+    I: IntoIterator<Item = T>,
+{
+    distances
+        .into_iter()
+        .map(|distance| distance.to_f64().unwrap_or(0.0))
+        .fold(None, |max: Option<f64>, distance| {
+            Some(max.map_or(distance, |max| max.max(distance)))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+    #[cfg(feature = "rand")]
+    use nalgebra::Vector3;
+    use std::collections::HashMap;
+
+    use crate::graph::{GraphError, MeshGraph, TopologyKind};
+    use crate::metric::{self, CotangentLaplacian};
+    use crate::prelude::*;
+    #[cfg(feature = "rand")]
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+    use crate::primitive::Trigon;
+
+    type E3 = Point3<f64>;
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn chamfer_distance() {
+        let a: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+        let mut b: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+        for mut vertex in b.vertex_orphans() {
+            *vertex.get_mut() += Vector3::x() * 10.0;
+        }
+
+        let near = metric::chamfer_distance(&a, &a, 256).unwrap();
+        let far = metric::chamfer_distance(&a, &b, 256).unwrap();
+        assert!(near < 0.5);
+        // The nearest points of the translated cube are at least eight units away.
+        assert!(far >= 8.0);
+        assert!(metric::chamfer_distance(&a, &MeshGraph::new(), 256).is_none());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn surface_distance() {
+        // Distances are measured to the nearest point on the other surface
+        // rather than the nearest sample, so parallel planes are separated by
+        // exactly their offset.
+        let a = MeshGraph::<E3>::from_heightmap(&[0.0; 16], 4, 4).unwrap();
+        let b = MeshGraph::<E3>::from_heightmap(&[1.0; 16], 4, 4).unwrap();
+
+        assert!((metric::chamfer_distance(&a, &b, 64).unwrap() - 1.0).abs() < 1e-9);
+        assert!((metric::hausdorff_distance(&a, &b, 64).unwrap() - 1.0).abs() < 1e-9);
+        assert!(metric::chamfer_distance(&a, &a, 64).unwrap() < 1e-9);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn closest_point_on_triangle() {
+        let triangle = [
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+        ];
+        let closest = |x, y, z| metric::closest_point_on_triangle(Point3::new(x, y, z), triangle);
+
+        // Interior.
+        assert_eq!(Point3::new(0.25, 0.25, 0.0), closest(0.25, 0.25, 2.0));
+        // Vertices.
+        assert_eq!(Point3::new(0.0, 0.0, 0.0), closest(-1.0, -1.0, 0.0));
+        assert_eq!(Point3::new(1.0, 0.0, 0.0), closest(2.0, -1.0, 1.0));
+        assert_eq!(Point3::new(0.0, 1.0, 0.0), closest(-1.0, 2.0, -1.0));
+        // Edges.
+        assert_eq!(Point3::new(0.5, 0.0, 0.0), closest(0.5, -1.0, 0.0));
+        assert_eq!(Point3::new(0.0, 0.5, 0.0), closest(-1.0, 0.5, 0.0));
+        assert_eq!(Point3::new(0.5, 0.5, 0.0), closest(1.0, 1.0, 0.0));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn hausdorff_distance() {
+        let a: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+        // Extrude a face of the cube so that `b` deviates from `a` in only one
+        // region.
+        let mut b: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+        let key = b.faces().nth(0).unwrap().key();
+        b.face_mut(key).unwrap().extrude_with_offset(2.0).unwrap();
+
+        let (ab, ba) = metric::directed_hausdorff_distances(&a, &b, 1024).unwrap();
+        // `a` is contained in the surface of `b` (less the extruded face), so
+        // only the backward direction observes the extrusion.
+        assert!(ab < 1.0);
+        assert!(ba > 1.0);
+        assert_eq!(
+            ab.max(ba),
+            metric::hausdorff_distance(&a, &b, 1024).unwrap()
+        );
+        // The worst-case deviation exceeds the average deviation.
+        assert!(
+            metric::hausdorff_distance(&a, &b, 1024).unwrap()
+                > metric::chamfer_distance(&a, &b, 1024).unwrap()
+        );
+    }
+
+    #[test]
+    fn heat_geodesics() {
+        let graph: MeshGraph<E3> = UvSphere::new(32, 16)
+            .polygons::<Position<Point3<R64>>>()
+            .triangulate()
+            .collect();
+        let source = graph
+            .vertices()
+            .max_by(|a, b| a.position().z.partial_cmp(&b.position().z).unwrap())
+            .unwrap()
+            .key();
+        let length = graph.edges().map(|edge| edge.length()).sum::<f64>();
+        let length = length / graph.edge_count() as f64;
+        let distances = metric::heat_geodesics(&graph, &[source], length * length).unwrap();
+        assert_eq!(graph.vertex_count(), distances.len());
+        assert!(distances[&source] < length);
+
+        // Geodesic distances on the unit sphere from a pole are polar angles.
+        let errors = graph
+            .vertices()
+            .map(|vertex| {
+                let angle = vertex.position().z.clamp(-1.0, 1.0).acos();
+                (distances[&vertex.key()] - angle).abs()
+            })
+            .collect::<Vec<_>>();
+        let mean = errors.iter().sum::<f64>() / errors.len() as f64;
+        assert!(mean < length);
+
+        assert_eq!(
+            GraphError::Geometry,
+            metric::heat_geodesics(&graph, &[source], 0.0)
+                .err()
+                .unwrap()
+        );
+        assert_eq!(
+            GraphError::TopologyNotFound(TopologyKind::Vertex),
+            metric::heat_geodesics(&graph, &[], 1.0).err().unwrap()
+        );
+    }
+
+    #[test]
+    fn spectral_embedding() {
+        use std::f64::consts::PI;
+
+        // Construct an open cylinder that is much longer than its
+        // circumference, such that its Fiedler vector varies along its axis.
+        const SEGMENTS: usize = 12;
+        const RINGS: usize = 17;
+        let positions = (0..RINGS)
+            .flat_map(|i| {
+                (0..SEGMENTS).map(move |j| {
+                    let theta = (2.0 * PI * j as f64) / SEGMENTS as f64;
+                    (theta.cos(), theta.sin(), i as f64 / 2.0)
+                })
+            })
+            .collect::<Vec<_>>();
+        let triangles = (0..(RINGS - 1))
+            .flat_map(|i| {
+                (0..SEGMENTS).flat_map(move |j| {
+                    let k = (j + 1) % SEGMENTS;
+                    let [a, b] = [i * SEGMENTS + j, i * SEGMENTS + k];
+                    let [c, d] = [a + SEGMENTS, b + SEGMENTS];
+                    [Trigon::new(a, b, d), Trigon::new(a, d, c)]
+                })
+            })
+            .collect::<Vec<_>>();
+        let graph = MeshGraph::<E3>::from_raw_buffers(triangles, positions).unwrap();
+
+        // Constant functions are in the null space of the Laplacian and so
+        // have an eigenvalue of zero. The remaining eigenvalues are positive.
+        let laplacian = CotangentLaplacian::from_graph(&graph).unwrap();
+        let constant = vec![1.0; graph.vertex_count()];
+        assert!(laplacian.multiply(&constant).iter().all(|x| x.abs() < 1e-9));
+        let pairs = laplacian.least_eigenpairs(3);
+        assert!(pairs[0].0 > 1e-6);
+        assert!(pairs
+            .windows(2)
+            .all(|pairs| pairs[0].0 <= pairs[1].0 + 1e-6));
+
+        // The Fiedler vector separates the cylinder along its axis and is
+        // monotonic from one end to the other.
+        let embedding = metric::spectral_embedding(&graph, 3).unwrap();
+        assert_eq!(graph.vertex_count(), embedding.len());
+        assert!(embedding.values().all(|embedding| embedding.len() == 3));
+        let mut rings = vec![0.0; RINGS];
+        for vertex in graph.vertices() {
+            let i = (vertex.position().z * 2.0).round() as usize;
+            rings[i] += embedding[&vertex.key()][0] / SEGMENTS as f64;
+        }
+        let sign = (rings[RINGS - 1] - rings[0]).signum();
+        assert!(rings
+            .windows(2)
+            .all(|rings| (rings[1] - rings[0]) * sign > 0.0));
+
+        assert_eq!(
+            GraphError::DimensionConflict {
+                max: graph.vertex_count() - 1,
+                actual: graph.vertex_count(),
+            },
+            metric::spectral_embedding(&graph, graph.vertex_count())
+                .err()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn parameterization_stretch() {
+        let mut graph = MeshGraph::<E3>::from_heightmap(&[0.0; 9], 3, 3).unwrap();
+        graph.triangulate();
+
+        // Projecting onto the plane of the grid is an isometry.
+        let uv = graph
+            .vertices()
+            .map(|vertex| (vertex.key(), [vertex.position().x, vertex.position().y]))
+            .collect::<HashMap<_, _>>();
+        let report = metric::parameterization_stretch(&graph, &uv).unwrap();
+        assert_eq!(8, report.faces.len());
+        assert!((report.l2 - 1.0).abs() < 1e-9);
+        assert!((report.lmax - 1.0).abs() < 1e-9);
+        assert!((report.mean - 1.0).abs() < 1e-9);
+        assert!(report.variance.abs() < 1e-9);
+
+        // Scaling texture coordinates does not affect the normalized metrics,
+        // but anisotropic scaling does.
+        let scaled = uv
+            .iter()
+            .map(|(key, [u, v])| (*key, [u * 2.0, v * 2.0]))
+            .collect::<HashMap<_, _>>();
+        let report = metric::parameterization_stretch(&graph, &scaled).unwrap();
+        assert!((report.l2 - 1.0).abs() < 1e-9);
+        assert!((report.mean - 0.5).abs() < 1e-9);
+        let anisotropic = uv
+            .iter()
+            .map(|(key, [u, v])| (*key, [u * 2.0, *v]))
+            .collect::<HashMap<_, _>>();
+        let report = metric::parameterization_stretch(&graph, &anisotropic).unwrap();
+        assert!(report.l2 > 1.0);
+        assert!(report.lmax > report.l2);
+
+        // Flipped triangles have infinite stretch. Moving the central vertex
+        // beyond the boundary of the grid flips the triangles between them.
+        let center = graph
+            .vertices()
+            .find(|vertex| *vertex.position() == Point3::new(1.0, 1.0, 0.0))
+            .unwrap()
+            .key();
+        let mut flipped = uv.clone();
+        flipped.insert(center, [3.0, 1.0]);
+        let report = metric::parameterization_stretch(&graph, &flipped).unwrap();
+        assert!(report.lmax.is_infinite());
+        assert!(metric::parameterization_stretch(&graph, &HashMap::new()).is_none());
+    }
+}
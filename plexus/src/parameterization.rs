@@ -0,0 +1,539 @@
+//! Parameterization of surfaces.
+//!
+//! This module provides functions that map the surface of a mesh into the
+//! plane, such as [`lscm_parameterize`] and [`tutte_parameterize`]. The
+//! resulting texture coordinates can be assessed with
+//! [`metric::parameterization_stretch`].
+//!
+//! [`lscm_parameterize`]: crate::parameterization::lscm_parameterize
+//! [`metric::parameterization_stretch`]: crate::metric::parameterization_stretch
+//! [`tutte_parameterize`]: crate::parameterization::tutte_parameterize
+
+use std::collections::{HashMap, HashSet};
+use std::f64::consts::TAU;
+use theon::space::{EuclideanSpace, FiniteDimensional};
+use theon::AsPosition;
+use thiserror::Error;
+use typenum::U3;
+
+use crate::geometry::{self, cross, dot, norm, sub};
+use crate::graph::{
+    ClosedView, GraphData, GraphError, KeyIndex, MeshGraph, VertexKey, VertexPosition,
+};
+use crate::metric::conjugate_gradient;
+
+/// Errors concerning parameterization.
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum ParameterizationError {
+    /// The graph has no boundary.
+    ///
+    /// Parameterizations that map a surface into the plane require a boundary,
+    /// so closed surfaces must be cut before they can be parameterized.
+    #[error("topology not bounded")]
+    BoundaryNotFound,
+    /// A triangle is inverted in texture space.
+    #[error("parameterization folds over")]
+    Foldover,
+    /// The graph cannot be parameterized, such as when it has non-triangular
+    /// faces or degenerate geometry.
+    #[error("graph operation failed: {0}")]
+    Graph(#[from] GraphError),
+}
+
+/// Computes a least squares conformal map (LSCM) of the surface of a graph.
+///
+/// The least squares conformal map of Lévy et al. minimizes the angle
+/// distortion of each triangle in a least squares sense. Two boundary vertices
+/// that are far apart are pinned to fix the translation, rotation, and scale
+/// of the map, where the distance between the pinned vertices in texture
+/// space is their distance on the surface. The resulting linear system is
+/// solved iteratively.
+///
+/// Returns the texture coordinates of each vertex. The graph should be a
+/// single connected component that is topologically a disc.
+///
+/// # Errors
+///
+/// Returns an error if the graph has no boundary, if the graph has any
+/// non-triangular faces or degenerate geometry, or if any triangle is inverted
+/// in texture space. Errors caused by a particular vertex or face are wrapped
+/// with its key (see [`GraphError::AtKey`]).
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::Point3;
+/// use plexus::graph::MeshGraph;
+/// use plexus::parameterization;
+/// use plexus::prelude::*;
+///
+/// let mut graph = MeshGraph::<Point3<f64>>::from_heightmap(&[0.0; 16], 4, 4).unwrap();
+/// graph.triangulate();
+///
+/// let uv = parameterization::lscm_parameterize(&graph).unwrap();
+/// ```
+///
+/// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+pub fn lscm_parameterize<G, K>(
+    graph: &MeshGraph<G, K>,
+) -> Result<HashMap<VertexKey<K>, [f64; 2]>, ParameterizationError>
+where
+    G: GraphData,
+    K: KeyIndex,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let mut keys = Vec::with_capacity(graph.vertex_count());
+    let mut indices = HashMap::with_capacity(graph.vertex_count());
+    let mut positions = Vec::with_capacity(graph.vertex_count());
+    for vertex in graph.vertices() {
+        let position = geometry::into_f64(*vertex.position())
+            .ok_or_else(|| GraphError::Geometry.at_key(vertex.key()))?;
+        indices.insert(vertex.key(), keys.len());
+        keys.push(vertex.key());
+        positions.push(position);
+    }
+    // Pin the pair of boundary vertices that are farthest apart.
+    let boundary = graph
+        .boundary_vertices()
+        .map(|vertex| indices[&vertex.key()])
+        .collect::<Vec<_>>();
+    let mut pins = None;
+    let mut distance = 0.0;
+    for (n, a) in boundary.iter().cloned().enumerate() {
+        for b in boundary.iter().cloned().skip(n + 1) {
+            let d = norm(sub(positions[b], positions[a]));
+            if d > distance {
+                pins = Some((a, b));
+                distance = d;
+            }
+        }
+    }
+    let (a, b) = pins.ok_or(ParameterizationError::BoundaryNotFound)?;
+    let mut fixed = HashMap::new();
+    fixed.insert(a, [0.0, 0.0]);
+    fixed.insert(b, [distance, 0.0]);
+
+    // Each free vertex has two unknowns: `u` and `v`.
+    let n = positions.len();
+    let mut columns = vec![None; n];
+    let mut count = 0;
+    for (i, column) in columns.iter_mut().enumerate() {
+        if !fixed.contains_key(&i) {
+            *column = Some(count);
+            count += 1;
+        }
+    }
+    // Each triangle contributes two rows to the system, which express the
+    // Cauchy-Riemann equations in a local frame of the triangle weighted by
+    // the square root of its area. Each row is split into terms of free and
+    // pinned vertices.
+    let mut rows = Vec::with_capacity(graph.face_count() * 2);
+    let mut triangles = Vec::with_capacity(graph.face_count());
+    for face in graph.faces() {
+        let vertices = face
+            .adjacent_vertices()
+            .map(|vertex| indices[&vertex.key()])
+            .collect::<Vec<_>>();
+        let [i, j, k] = match vertices.as_slice() {
+            &[i, j, k] => [i, j, k],
+            _ => {
+                return Err(GraphError::ArityConflict {
+                    expected: 3,
+                    actual: vertices.len(),
+                }
+                .at_key(face.key())
+                .into())
+            }
+        };
+        let (ij, ik) = (
+            sub(positions[j], positions[i]),
+            sub(positions[k], positions[i]),
+        );
+        let (length, area) = (norm(ij), norm(cross(ij, ik)));
+        if length.is_nan() || area.is_nan() || length <= 0.0 || area <= 0.0 {
+            return Err(GraphError::Geometry.at_key(face.key()).into());
+        }
+        // Local coordinates of the triangle in its plane.
+        let local = [
+            [0.0, 0.0],
+            [length, 0.0],
+            [dot(ik, ij) / length, area / length],
+        ];
+        let weight = 1.0 / (2.0 * (area / 2.0).sqrt());
+        let mut equations = [(Vec::with_capacity(6), 0.0), (Vec::with_capacity(6), 0.0)];
+        for (index, vertex) in [i, j, k].iter().cloned().enumerate() {
+            // Edge opposite the vertex.
+            let (p, q) = (local[(index + 1) % 3], local[(index + 2) % 3]);
+            let (x, y) = (q[0] - p[0], q[1] - p[1]);
+            let terms = [(-y * weight, -x * weight), (x * weight, -y * weight)];
+            for ((row, constant), (cu, cv)) in equations.iter_mut().zip(terms) {
+                match (columns[vertex], fixed.get(&vertex)) {
+                    (Some(column), _) => {
+                        row.push((column, cu));
+                        row.push((column + count, cv));
+                    }
+                    (None, Some([u, v])) => {
+                        *constant += (cu * u) + (cv * v);
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        }
+        rows.extend(equations);
+        triangles.push([i, j, k]);
+    }
+    // Solve the normal equations `AᵀAx = -Aᵀb` of the least squares problem,
+    // where `b` is formed from the terms of pinned vertices.
+    let transpose = |y: &[f64]| {
+        let mut x = vec![0.0; 2 * count];
+        for ((row, _), y) in rows.iter().zip(y) {
+            for (column, coefficient) in row {
+                x[*column] += coefficient * y;
+            }
+        }
+        x
+    };
+    let multiply = |x: &[f64]| {
+        rows.iter()
+            .map(|(row, _)| {
+                row.iter()
+                    .map(|(column, coefficient)| coefficient * x[*column])
+            })
+            .map(|terms| terms.sum::<f64>())
+            .collect::<Vec<_>>()
+    };
+    let rhs = transpose(
+        &rows
+            .iter()
+            .map(|(_, constant)| -constant)
+            .collect::<Vec<_>>(),
+    );
+    let solution = conjugate_gradient(|x: &[f64]| transpose(&multiply(x)), &rhs);
+    let uv = (0..n)
+        .map(|i| match (columns[i], fixed.get(&i)) {
+            (Some(column), _) => [solution[column], solution[column + count]],
+            (None, Some(uv)) => *uv,
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    for [i, j, k] in triangles {
+        let ([s1, t1], [s2, t2], [s3, t3]) = (uv[i], uv[j], uv[k]);
+        let area = ((s2 - s1) * (t3 - t1)) - ((s3 - s1) * (t2 - t1));
+        if area.is_nan() || area <= 0.0 {
+            return Err(ParameterizationError::Foldover);
+        }
+    }
+    Ok(keys.into_iter().zip(uv).collect())
+}
+
+/// Computes a Tutte embedding of the surface of a graph.
+///
+/// The boundary of the graph is fixed to the unit circle, where the spacing
+/// of boundary vertices is proportional to the lengths of boundary edges.
+/// Each interior vertex is then placed at the centroid of its adjacent
+/// vertices by solving the harmonic system of the uniform (graph) Laplacian.
+/// Because the boundary is convex and the weights are positive, the resulting
+/// map is free of foldovers. Unlike [`lscm_parameterize`], faces may have any
+/// arity.
+///
+/// Returns the texture coordinates of each vertex.
+///
+/// # Errors
+///
+/// Returns an error if the graph has no boundary or if the graph has
+/// degenerate geometry. Returns [`GraphError::TopologyMalformed`] if the graph
+/// is not a topological disc, such as when it has more than one boundary or
+/// is not connected. Errors caused by a particular vertex are wrapped with its
+/// key (see [`GraphError::AtKey`]).
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::Point3;
+/// use plexus::graph::MeshGraph;
+/// use plexus::parameterization;
+///
+/// let graph = MeshGraph::<Point3<f64>>::from_heightmap(&[0.0; 16], 4, 4).unwrap();
+///
+/// let uv = parameterization::tutte_parameterize(&graph).unwrap();
+/// ```
+///
+/// [`GraphError::AtKey`]: crate::graph::GraphError::AtKey
+/// [`GraphError::TopologyMalformed`]: crate::graph::GraphError::TopologyMalformed
+/// [`lscm_parameterize`]: crate::parameterization::lscm_parameterize
+pub fn tutte_parameterize<G, K>(
+    graph: &MeshGraph<G, K>,
+) -> Result<HashMap<VertexKey<K>, [f64; 2]>, ParameterizationError>
+where
+    G: GraphData,
+    K: KeyIndex,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let position =
+        |position: &VertexPosition<G>| geometry::into_f64(*position).ok_or(GraphError::Geometry);
+    let ring = graph
+        .arcs()
+        .find(|arc| arc.is_boundary_arc())
+        .ok_or(ParameterizationError::BoundaryNotFound)?
+        .into_ring();
+    let boundary = ring
+        .arcs()
+        .map(|arc| {
+            let vertex = arc.source_vertex();
+            position(vertex.position())
+                .map(|position| (vertex.key(), position))
+                .map_err(|error| error.at_key(vertex.key()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    // A disc has a single boundary that visits each of its vertices once, is
+    // connected, and has an Euler characteristic of one.
+    let characteristic =
+        (graph.vertex_count() + graph.face_count()) as isize - graph.edge_count() as isize;
+    if graph.arcs().filter(|arc| arc.is_boundary_arc()).count() != boundary.len()
+        || boundary
+            .iter()
+            .map(|(key, _)| key)
+            .collect::<HashSet<_>>()
+            .len()
+            != boundary.len()
+        || graph.disjoint_subgraph_vertices().len() != 1
+        || characteristic != 1
+    {
+        return Err(GraphError::TopologyMalformed.into());
+    }
+
+    // Fix the boundary to the unit circle. Boundary arcs are wound opposite
+    // to faces, so the boundary is traversed clockwise.
+    let lengths = boundary
+        .iter()
+        .zip(boundary.iter().cycle().skip(1))
+        .map(|((_, a), (_, b))| norm(sub(*b, *a)))
+        .collect::<Vec<_>>();
+    let perimeter = lengths.iter().sum::<f64>();
+    if perimeter.is_nan() || perimeter <= 0.0 {
+        return Err(GraphError::Geometry.into());
+    }
+    let mut uv = HashMap::with_capacity(graph.vertex_count());
+    let mut distance = 0.0;
+    for ((key, _), length) in boundary.iter().zip(lengths) {
+        let angle = -TAU * (distance / perimeter);
+        uv.insert(*key, [angle.cos(), angle.sin()]);
+        distance += length;
+    }
+
+    // Each interior vertex has one unknown per coordinate. Each row of the
+    // system is split into terms of interior and boundary vertices.
+    let keys = graph
+        .vertices()
+        .map(|vertex| vertex.key())
+        .filter(|key| !uv.contains_key(key))
+        .collect::<Vec<_>>();
+    let indices = keys
+        .iter()
+        .enumerate()
+        .map(|(index, key)| (*key, index))
+        .collect::<HashMap<_, _>>();
+    let mut rows = Vec::with_capacity(keys.len());
+    let mut constants = [vec![0.0; keys.len()], vec![0.0; keys.len()]];
+    for (index, key) in keys.iter().enumerate() {
+        let mut row = Vec::new();
+        let mut degree = 0.0;
+        for vertex in graph.vertex(*key).unwrap().adjacent_vertices() {
+            degree += 1.0;
+            match (indices.get(&vertex.key()), uv.get(&vertex.key())) {
+                (Some(column), _) => {
+                    row.push(*column);
+                }
+                (None, Some([u, v])) => {
+                    constants[0][index] += u;
+                    constants[1][index] += v;
+                }
+                _ => unreachable!(),
+            }
+        }
+        rows.push((degree, row));
+    }
+    let multiply = |x: &[f64]| {
+        rows.iter()
+            .zip(x)
+            .map(|((degree, row), value)| {
+                (degree * value) - row.iter().map(|column| x[*column]).sum::<f64>()
+            })
+            .collect::<Vec<_>>()
+    };
+    let [u, v] = constants.map(|constants| conjugate_gradient(&multiply, &constants));
+    uv.extend(
+        keys.into_iter()
+            .zip(u.into_iter().zip(v))
+            .map(|(key, (u, v))| (key, [u, v])),
+    );
+
+    for face in graph.faces() {
+        let polygon = face
+            .adjacent_vertices()
+            .map(|vertex| uv[&vertex.key()])
+            .collect::<Vec<_>>();
+        let area = polygon
+            .iter()
+            .zip(polygon.iter().cycle().skip(1))
+            .map(|([s1, t1], [s2, t2])| (s1 * t2) - (s2 * t1))
+            .sum::<f64>();
+        if area.is_nan() || area <= 0.0 {
+            return Err(ParameterizationError::Foldover);
+        }
+    }
+    Ok(uv)
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::graph::{GraphError, MeshGraph};
+    use crate::metric;
+    use crate::parameterization::{self, ParameterizationError};
+    use crate::prelude::*;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn lscm_plane() {
+        // A planar grid is parameterized without distortion.
+        let mut graph = MeshGraph::<E3>::from_heightmap(&[0.0; 25], 5, 5).unwrap();
+        graph.triangulate();
+        let uv = parameterization::lscm_parameterize(&graph).unwrap();
+        assert_eq!(graph.vertex_count(), uv.len());
+        let report = metric::parameterization_stretch(&graph, &uv).unwrap();
+        assert!((report.l2 - 1.0).abs() < 1e-6);
+        assert!((report.lmax - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn lscm_curved() {
+        // Construct a hemisphere from the triangles of the northern half of a
+        // sphere.
+        let graph: MeshGraph<E3> = UvSphere::new(16, 8)
+            .polygons::<Position<Point3<R64>>>()
+            .triangulate()
+            .filter(|triangle| {
+                triangle
+                    .0
+                    .iter()
+                    .all(|position| position.z > R64::from(-1e-9))
+            })
+            .collect();
+        assert!(graph.arcs().any(|arc| arc.is_boundary_arc()));
+        let uv = parameterization::lscm_parameterize(&graph).unwrap();
+        assert_eq!(graph.vertex_count(), uv.len());
+        let report = metric::parameterization_stretch(&graph, &uv).unwrap();
+        assert!(report.l2.is_finite());
+    }
+
+    #[test]
+    fn lscm_errors() {
+        let graph: MeshGraph<E3> = UvSphere::new(8, 4)
+            .polygons::<Position<Point3<R64>>>()
+            .triangulate()
+            .collect();
+        assert_eq!(
+            ParameterizationError::BoundaryNotFound,
+            parameterization::lscm_parameterize(&graph).err().unwrap()
+        );
+
+        // The error refers to the first non-triangular face.
+        let graph = MeshGraph::<E3>::from_heightmap(&[0.0; 9], 3, 3).unwrap();
+        let key = graph.faces().nth(0).unwrap().key();
+        assert_eq!(
+            ParameterizationError::Graph(
+                GraphError::ArityConflict {
+                    expected: 3,
+                    actual: 4
+                }
+                .at_key(key)
+            ),
+            parameterization::lscm_parameterize(&graph).err().unwrap()
+        );
+    }
+
+    #[test]
+    fn tutte_plane() {
+        let graph = MeshGraph::<E3>::from_heightmap(&[0.0; 25], 5, 5).unwrap();
+        let uv = parameterization::tutte_parameterize(&graph).unwrap();
+        assert_eq!(graph.vertex_count(), uv.len());
+        for vertex in graph.vertices() {
+            let [u, v] = uv[&vertex.key()];
+            if vertex.is_boundary_vertex() {
+                // Boundary vertices lie on the unit circle.
+                assert!(((u * u) + (v * v) - 1.0).abs() < 1e-9);
+            }
+            else {
+                // Interior vertices lie at the centroid of their neighbors.
+                let (n, [su, sv]) =
+                    vertex
+                        .adjacent_vertices()
+                        .fold((0.0, [0.0, 0.0]), |(n, [su, sv]), vertex| {
+                            let [u, v] = uv[&vertex.key()];
+                            (n + 1.0, [su + u, sv + v])
+                        });
+                assert!((u - (su / n)).abs() < 1e-6);
+                assert!((v - (sv / n)).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn tutte_curved() {
+        // Construct a hemisphere from the quadrilaterals and triangles of the
+        // northern half of a sphere.
+        let graph: MeshGraph<E3> = UvSphere::new(16, 8)
+            .polygons::<Position<Point3<R64>>>()
+            .filter(|polygon| {
+                polygon
+                    .as_ref()
+                    .iter()
+                    .all(|position| position.z > R64::from(-1e-9))
+            })
+            .collect();
+        let uv = parameterization::tutte_parameterize(&graph).unwrap();
+        assert_eq!(graph.vertex_count(), uv.len());
+        assert!(uv.values().all(|[u, v]| ((u * u) + (v * v)) <= 1.0 + 1e-9));
+    }
+
+    #[test]
+    fn tutte_errors() {
+        let graph: MeshGraph<E3> = UvSphere::new(8, 4)
+            .polygons::<Position<Point3<R64>>>()
+            .collect();
+        assert_eq!(
+            ParameterizationError::BoundaryNotFound,
+            parameterization::tutte_parameterize(&graph).err().unwrap()
+        );
+
+        // Remove the center of a grid to form an annulus with two boundaries.
+        let mut graph = MeshGraph::<E3>::from_heightmap(&[0.0; 16], 4, 4).unwrap();
+        let key = graph
+            .faces()
+            .find(|face| {
+                face.adjacent_vertices()
+                    .all(|vertex| !vertex.is_boundary_vertex())
+            })
+            .unwrap()
+            .key();
+        graph.face_mut(key).unwrap().remove();
+        assert_eq!(
+            ParameterizationError::Graph(GraphError::TopologyMalformed),
+            parameterization::tutte_parameterize(&graph).err().unwrap()
+        );
+    }
+}
@@ -295,6 +295,22 @@ where
     }
 }
 
+impl<T> IntoTrigons for UnboundedPolygon<T>
+where
+    T: Clone,
+{
+    type Output = Vec<Trigon<Self::Vertex>>;
+
+    // Fans triangles about the first vertex, which preserves winding.
+    fn into_trigons(self) -> Self::Output {
+        let vertices = self.into_iter().collect::<Vec<_>>();
+        vertices[1..]
+            .windows(2)
+            .map(|bc| Trigon::new(vertices[0].clone(), bc[0].clone(), bc[1].clone()))
+            .collect()
+    }
+}
+
 impl<T> IntoSubdivisions for Trigon<T>
 where
     T: Clone + Interpolate<Output = T>,
@@ -465,3 +481,59 @@ where
     }
     ngons
 }
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+    use theon::adjunct::FromItems;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::{Trigon, UnboundedPolygon};
+
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn triangulate_generator() {
+        let quads: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+        let trigons: MeshGraph<E3> = Cube::new()
+            .polygons::<Position<Point3<R64>>>()
+            .triangulate()
+            .collect();
+
+        assert_eq!(12, trigons.face_count());
+        assert!(trigons.faces().all(|face| face.arity() == 3));
+        // Positions are unaffected and all triangles are wound consistently
+        // with the quadrilaterals from which they are decomposed.
+        let mut positions = quads
+            .vertices()
+            .map(|vertex| *vertex.position())
+            .collect::<Vec<_>>();
+        for vertex in trigons.vertices() {
+            let index = positions
+                .iter()
+                .position(|position| position == vertex.position())
+                .unwrap();
+            positions.remove(index);
+        }
+        assert!(positions.is_empty());
+        assert!((quads.volume().unwrap() - trigons.volume().unwrap()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn triangulate_unbounded_polygon() {
+        let polygon = UnboundedPolygon::from_items(vec![0usize, 1, 2, 3, 4]).unwrap();
+        let trigons = polygon.into_trigons();
+        assert_eq!(
+            vec![
+                Trigon::new(0, 1, 2),
+                Trigon::new(0, 2, 3),
+                Trigon::new(0, 3, 4)
+            ],
+            trigons,
+        );
+    }
+}
@@ -0,0 +1,949 @@
+//! Lathe primitives.
+//!
+//! A lathe revolves a profile about the $y$-axis. The profile is an ordered
+//! sequence of $(r, y)$ points, where $r$ is the distance from the axis. The
+//! first and last points of a profile may lie on the axis, in which case the
+//! corresponding end of the surface is closed by a fan of triangles about a
+//! single apex vertex.
+//!
+//! # Examples
+//!
+//! Generating a graph from the positional data of a vase.
+//!
+//! ```rust
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::N64;
+//! use nalgebra::Point3;
+//! use plexus::graph::MeshGraph;
+//! use plexus::index::HashIndexer;
+//! use plexus::prelude::*;
+//! use plexus::primitive::generate::Position;
+//! use plexus::primitive::lathe::Lathe;
+//!
+//! type E3 = Point3<N64>;
+//!
+//! let lathe = Lathe::new(vec![(0.0, 0.0), (1.0, 0.0), (0.5, 1.0), (0.75, 2.0)], 16).unwrap();
+//! let graph = lathe
+//!     .polygons::<Position<E3>>()
+//!     .collect_with_indexer::<MeshGraph<E3>, _>(HashIndexer::default())
+//!     .unwrap();
+//! ```
+//!
+//! Generating a buffer from the positional and texture data of a capped
+//! partial revolution.
+//!
+//! ```rust
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::N64;
+//! use nalgebra::{Point2, Point3};
+//! use plexus::buffer::MeshBuffer3;
+//! use plexus::prelude::*;
+//! use plexus::primitive::lathe::Lathe;
+//! use std::f64::consts::PI;
+//!
+//! type E2 = Point2<N64>;
+//! type E3 = Point3<N64>;
+//!
+//! let buffer: MeshBuffer3<usize, (E3, E2)> = Lathe::new(vec![(1.0, -1.0), (1.0, 1.0)], 8)
+//!     .unwrap()
+//!     .angle(PI)
+//!     .caps(true)
+//!     .polygons_with_texture::<E3, E2>()
+//!     .triangulate()
+//!     .collect();
+//! ```
+
+use num::{NumCast, ToPrimitive};
+use std::cmp;
+use std::f64::consts::TAU;
+use std::marker::PhantomData;
+use theon::adjunct::Map;
+use theon::query::Unit;
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
+use thiserror::Error;
+use typenum::{U2, U3};
+
+use crate::primitive::generate::{
+    Attribute, AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
+    IndexingPolygonGenerator, Normal, PolygonGenerator, Position,
+};
+use crate::primitive::{BoundedPolygon, Tetragon, Trigon};
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum LatheError {
+    /// The profile has fewer than two points or all of its points lie on the
+    /// axis.
+    #[error("insufficient profile")]
+    ProfileInsufficient,
+    /// A point of the profile lies on or behind the axis.
+    ///
+    /// Only the first and last points of a profile may lie on the axis.
+    #[error("profile point {index} on or behind axis")]
+    ProfileOnAxis { index: usize },
+}
+
+/// Meta-attribute for texture coordinates.
+///
+/// Describes the $(u, v)$ texture coordinates of vertices in a [`Lathe`].
+/// Both coordinates are in the interval $[0, 1]$, where $u$ follows the
+/// revolution about the axis and $v$ follows the arc length of the profile.
+/// The generated data is derived from the type parameter `S`, which typically
+/// requires [`EuclideanSpace`] in $\Reals^2$.
+///
+/// Texture coordinates do not wrap: the first segment is duplicated at $u =
+/// 1$ to form a seam. Apex vertices are mapped to $u = \frac{1}{2}$. Caps are
+/// mapped by projecting the profile onto the $(r, y)$ plane and normalizing
+/// it to its extent.
+///
+/// [`EuclideanSpace`]: theon::space::EuclideanSpace
+/// [`Lathe`]: crate::primitive::lathe::Lathe
+pub struct UvMap<S = ()> {
+    phantom: PhantomData<fn() -> S>,
+}
+
+impl<S> Attribute for UvMap<S> {}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Cap {
+    Start,
+    End,
+}
+
+// Vertices of the polygons of a lathe.
+#[derive(Clone, Copy)]
+enum Corner {
+    // A point of the profile and a segment about the axis. The segment is not
+    // wrapped, so it is in the interval `[0, nu]`.
+    Side(usize, usize),
+    // A point of the profile that does not lie on the axis in a cap.
+    Cap(Cap, usize),
+    // The projection of a point of the profile onto the axis in a cap.
+    Axis(Cap, usize),
+}
+
+/// Surface of revolution.
+///
+/// Revolves a profile of $(r, y)$ points about the $y$-axis. Each point is
+/// swept into a ring of vertices and consecutive rings are joined by
+/// quadrilaterals. Rings of points on the axis collapse to a single apex
+/// vertex and are joined by triangles instead.
+///
+/// Polygons face away from the axis when the profile is ordered by increasing
+/// $y$ and face toward it otherwise.
+#[derive(Clone, Debug)]
+pub struct Lathe {
+    profile: Vec<(f64, f64)>,
+    arcs: Vec<f64>, // Normalized arc length of the profile at each point.
+    n: usize,       // Segments.
+    angle: f64,
+    caps: bool,
+}
+
+impl Lathe {
+    /// Constructs a lathe from a profile and a number of segments about the
+    /// axis.
+    ///
+    /// A full revolution has at least three segments.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profile has fewer than two points, if any point
+    /// other than the first or last lies on the axis, or if any point lies
+    /// behind the axis (that is, $r < 0$).
+    pub fn new<I>(profile: I, n: usize) -> Result<Self, LatheError>
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        let profile = profile.into_iter().collect::<Vec<_>>();
+        let m = profile.len();
+        if m < 2 {
+            return Err(LatheError::ProfileInsufficient);
+        }
+        for (index, &(r, _)) in profile.iter().enumerate() {
+            if r.is_nan() || r < 0.0 || (r == 0.0 && index != 0 && index != m - 1) {
+                return Err(LatheError::ProfileOnAxis { index });
+            }
+        }
+        if profile.iter().all(|&(r, _)| r == 0.0) {
+            return Err(LatheError::ProfileInsufficient);
+        }
+        let mut arcs = Vec::with_capacity(m);
+        let mut length = 0.0;
+        arcs.push(length);
+        for window in profile.windows(2) {
+            let ((r0, y0), (r1, y1)) = (window[0], window[1]);
+            length += (r1 - r0).hypot(y1 - y0);
+            arcs.push(length);
+        }
+        for (j, arc) in arcs.iter_mut().enumerate() {
+            *arc = if length > 0.0 {
+                *arc / length
+            }
+            else {
+                j as f64 / (m - 1) as f64
+            };
+        }
+        Ok(Lathe {
+            profile,
+            arcs,
+            n: cmp::max(1, n),
+            angle: TAU,
+            caps: false,
+        })
+    }
+
+    /// Sets the angle of the revolution in radians.
+    ///
+    /// The angle is $2\pi$ by default. Angles of $2\pi$ or more are a full
+    /// revolution, otherwise the ends of the swept surface are open unless
+    /// capped. See [`caps`].
+    ///
+    /// [`caps`]: crate::primitive::lathe::Lathe::caps
+    pub fn angle(mut self, angle: f64) -> Self {
+        self.angle = angle.min(TAU);
+        self
+    }
+
+    /// Sets whether or not the ends of a partial revolution are capped.
+    ///
+    /// Ends are open by default. Caps are planar polygons spanning the profile
+    /// and its projection onto the axis. Caps have no effect on full
+    /// revolutions.
+    pub fn caps(mut self, caps: bool) -> Self {
+        self.caps = caps;
+        self
+    }
+
+    /// Gets an iterator over the set of polygons with positional and texture
+    /// data.
+    ///
+    /// Each vertex is a pair of a position and texture coordinates. See
+    /// [`UvMap`].
+    ///
+    /// [`UvMap`]: crate::primitive::lathe::UvMap
+    pub fn polygons_with_texture<S, T>(&self) -> impl '_ + Iterator<Item = BoundedPolygon<(S, T)>>
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+        T: EuclideanSpace + FiniteDimensional<N = U2>,
+    {
+        (0..self.polygon_count()).map(move |index| {
+            self.polygon_corners(index)
+                .map(|corner| (self.position_from(corner), self.texture_from(corner)))
+        })
+    }
+
+    fn is_full(&self) -> bool {
+        self.angle >= TAU
+    }
+
+    fn is_capped(&self) -> bool {
+        self.caps && !self.is_full()
+    }
+
+    fn is_axial(&self, j: usize) -> bool {
+        self.profile[j].0 == 0.0
+    }
+
+    // Gets the number of segments about the axis.
+    fn nu(&self) -> usize {
+        if self.is_full() {
+            cmp::max(3, self.n)
+        }
+        else {
+            self.n
+        }
+    }
+
+    // Gets the number of points in the profile.
+    fn nv(&self) -> usize {
+        self.profile.len()
+    }
+
+    // Gets the number of distinct positions in each ring. The last ring of a
+    // full revolution wraps to the first.
+    fn ring_count(&self) -> usize {
+        if self.is_full() {
+            self.nu()
+        }
+        else {
+            self.nu() + 1
+        }
+    }
+
+    // Gets the number of points in the profile that lie on the axis. Only the
+    // first and last points may lie on the axis.
+    fn axial_count(&self) -> usize {
+        self.is_axial(0) as usize + self.is_axial(self.nv() - 1) as usize
+    }
+
+    // Gets the index of the first point in the profile that does not lie on
+    // the axis.
+    fn axial_offset(&self) -> usize {
+        self.is_axial(0) as usize
+    }
+
+    fn apex_index(&self, j: usize) -> usize {
+        if j == 0 {
+            0
+        }
+        else {
+            self.axial_offset()
+        }
+    }
+
+    fn map_apex_index(&self, index: usize) -> usize {
+        if index == 0 && self.is_axial(0) {
+            0
+        }
+        else {
+            self.nv() - 1
+        }
+    }
+
+    fn cap_segment(&self, cap: Cap) -> usize {
+        match cap {
+            Cap::Start => 0,
+            Cap::End => self.nu(),
+        }
+    }
+
+    fn wrap(&self, i: usize) -> usize {
+        if self.is_full() {
+            i % self.nu()
+        }
+        else {
+            i
+        }
+    }
+
+    fn theta(&self, i: usize) -> f64 {
+        self.angle * (self.wrap(i) as f64 / self.nu() as f64)
+    }
+
+    // Gets the unit normal of the profile at a point in the $(r, y)$ plane.
+    // The tangent is approximated by the central difference of neighboring
+    // points.
+    fn profile_normal(&self, j: usize) -> (f64, f64) {
+        let (r0, y0) = self.profile[j.saturating_sub(1)];
+        let (r1, y1) = self.profile[cmp::min(j + 1, self.nv() - 1)];
+        let (dr, dy) = (r1 - r0, y1 - y0);
+        if self.is_axial(j) {
+            return (0.0, -dr.signum());
+        }
+        let norm = dr.hypot(dy);
+        if norm > 0.0 {
+            (dy / norm, -dr / norm)
+        }
+        else {
+            (1.0, 0.0)
+        }
+    }
+
+    fn cap_normal(&self, cap: Cap) -> (f64, f64, f64) {
+        match cap {
+            Cap::Start => (0.0, 0.0, 1.0),
+            Cap::End => (-self.angle.sin(), 0.0, -self.angle.cos()),
+        }
+    }
+
+    // Gets the vertices of a polygon. Side polygons are emitted first, then
+    // the polygons of the start and end caps (if any). Triangles are emitted
+    // where the profile meets the axis, otherwise quadrilaterals.
+    fn polygon_corners(&self, index: usize) -> BoundedPolygon<Corner> {
+        let (nu, nv) = (self.nu(), self.nv());
+        if index < nu * (nv - 1) {
+            let (i, j) = (index % nu, index / nu);
+            let (p, q) = (i + 1, j + 1);
+            if self.is_axial(j) {
+                Trigon::new(Corner::Side(j, i), Corner::Side(q, p), Corner::Side(q, i)).into()
+            }
+            else if self.is_axial(q) {
+                Trigon::new(Corner::Side(j, i), Corner::Side(j, p), Corner::Side(q, i)).into()
+            }
+            else {
+                Tetragon::new(
+                    Corner::Side(j, i),
+                    Corner::Side(j, p),
+                    Corner::Side(q, p),
+                    Corner::Side(q, i),
+                )
+                .into()
+            }
+        }
+        else {
+            let index = index - (nu * (nv - 1));
+            let (cap, j) = if index < nv - 1 {
+                (Cap::Start, index)
+            }
+            else {
+                (Cap::End, index - (nv - 1))
+            };
+            let q = j + 1;
+            let (a, b) = (Corner::Axis(cap, j), Corner::Axis(cap, q));
+            let (c, d) = (Corner::Cap(cap, j), Corner::Cap(cap, q));
+            // The end cap is reversed so that both caps face away from the
+            // swept volume.
+            match cap {
+                Cap::Start => {
+                    if self.is_axial(j) {
+                        Trigon::new(a, d, b).into()
+                    }
+                    else if self.is_axial(q) {
+                        Trigon::new(a, c, b).into()
+                    }
+                    else {
+                        Tetragon::new(a, c, d, b).into()
+                    }
+                }
+                Cap::End => {
+                    if self.is_axial(j) {
+                        Trigon::new(b, d, a).into()
+                    }
+                    else if self.is_axial(q) {
+                        Trigon::new(b, c, a).into()
+                    }
+                    else {
+                        Tetragon::new(b, d, c, a).into()
+                    }
+                }
+            }
+        }
+    }
+
+    fn position_from<S>(&self, corner: Corner) -> S
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let (j, i) = match corner {
+            Corner::Side(j, i) => (j, i),
+            Corner::Cap(cap, j) => (j, self.cap_segment(cap)),
+            Corner::Axis(_, j) => (j, 0),
+        };
+        let (r, y) = self.profile[j];
+        if matches!(corner, Corner::Axis(..)) || self.is_axial(j) {
+            // Use exact zeros on the axis, so that apex and cap vertices are
+            // not duplicated when indexing geometry.
+            return S::from_xyz(
+                into_scalar::<_, S>(0.0),
+                into_scalar::<_, S>(y),
+                into_scalar::<_, S>(0.0),
+            );
+        }
+        let theta = self.theta(i);
+        S::from_xyz(
+            into_scalar::<_, S>(r * theta.cos()),
+            into_scalar::<_, S>(y),
+            into_scalar::<_, S>(0.0 - (r * theta.sin())),
+        )
+    }
+
+    fn normal_from<S>(&self, corner: Corner) -> Unit<Vector<S>>
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let (x, y, z) = match corner {
+            Corner::Side(j, i) => {
+                let (nr, ny) = self.profile_normal(j);
+                let theta = self.theta(i);
+                (nr * theta.cos(), ny, 0.0 - (nr * theta.sin()))
+            }
+            Corner::Cap(cap, _) | Corner::Axis(cap, _) => self.cap_normal(cap),
+        };
+        let normal = S::from_xyz(
+            into_scalar::<_, S>(x),
+            into_scalar::<_, S>(y),
+            into_scalar::<_, S>(z),
+        );
+        Unit::try_from_inner(normal.into_coordinates()).expect("non-zero vector")
+    }
+
+    fn texture_from<S>(&self, corner: Corner) -> S
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U2>,
+    {
+        let (u, v) = match corner {
+            Corner::Side(j, i) => {
+                let u = if self.is_axial(j) {
+                    0.5
+                }
+                else {
+                    i as f64 / self.nu() as f64
+                };
+                (u, self.arcs[j])
+            }
+            Corner::Cap(_, j) => self.cap_texture(self.profile[j]),
+            Corner::Axis(_, j) => self.cap_texture((0.0, self.profile[j].1)),
+        };
+        S::from_xy(into_scalar::<_, S>(u), into_scalar::<_, S>(v))
+    }
+
+    // Projects a point in the $(r, y)$ plane onto the extent of the profile.
+    fn cap_texture(&self, (r, y): (f64, f64)) -> (f64, f64) {
+        let (rmax, ymin, ymax) = self.profile.iter().fold(
+            (0.0f64, f64::INFINITY, f64::NEG_INFINITY),
+            |(rmax, ymin, ymax), &(r, y)| (rmax.max(r), ymin.min(y), ymax.max(y)),
+        );
+        let height = ymax - ymin;
+        if height > 0.0 {
+            (r / rmax, (y - ymin) / height)
+        }
+        else {
+            (r / rmax, 0.0)
+        }
+    }
+
+    // Positions are ordered by rings of points off of the axis, then points on
+    // the axis. When capped, every point of the profile is projected onto the
+    // axis, which includes any apexes.
+    fn index_for_position(&self, corner: Corner) -> usize {
+        let n = (self.nv() - self.axial_count()) * self.ring_count();
+        let axis = |j: usize| {
+            if self.is_capped() {
+                n + j
+            }
+            else {
+                n + self.apex_index(j)
+            }
+        };
+        match corner {
+            Corner::Side(j, _) if self.is_axial(j) => axis(j),
+            Corner::Axis(_, j) => axis(j),
+            Corner::Side(j, i) => ((j - self.axial_offset()) * self.ring_count()) + self.wrap(i),
+            Corner::Cap(cap, j) => {
+                ((j - self.axial_offset()) * self.ring_count()) + self.cap_segment(cap)
+            }
+        }
+    }
+
+    fn map_position_index(&self, index: usize) -> Corner {
+        let n = (self.nv() - self.axial_count()) * self.ring_count();
+        if index < n {
+            Corner::Side(
+                (index / self.ring_count()) + self.axial_offset(),
+                index % self.ring_count(),
+            )
+        }
+        else if self.is_capped() {
+            Corner::Axis(Cap::Start, index - n)
+        }
+        else {
+            Corner::Side(self.map_apex_index(index - n), 0)
+        }
+    }
+
+    // Normals are ordered by rings of points off of the axis, then apexes,
+    // then caps.
+    fn index_for_normal(&self, corner: Corner) -> usize {
+        let n = (self.nv() - self.axial_count()) * self.ring_count();
+        match corner {
+            Corner::Side(j, _) if self.is_axial(j) => n + self.apex_index(j),
+            Corner::Side(j, i) => ((j - self.axial_offset()) * self.ring_count()) + self.wrap(i),
+            Corner::Cap(cap, _) | Corner::Axis(cap, _) => {
+                n + self.axial_count() + (cap == Cap::End) as usize
+            }
+        }
+    }
+
+    fn map_normal_index(&self, index: usize) -> Corner {
+        let n = (self.nv() - self.axial_count()) * self.ring_count();
+        if index < n {
+            Corner::Side(
+                (index / self.ring_count()) + self.axial_offset(),
+                index % self.ring_count(),
+            )
+        }
+        else if index < n + self.axial_count() {
+            Corner::Side(self.map_apex_index(index - n), 0)
+        }
+        else if index == n + self.axial_count() {
+            Corner::Axis(Cap::Start, 0)
+        }
+        else {
+            Corner::Axis(Cap::End, 0)
+        }
+    }
+
+    // Texture coordinates are ordered by rings of points off of the axis
+    // (including the seam), then apexes, then the points of the profile in
+    // the start and end caps, then the points of the axis in caps. Both caps
+    // share the texture coordinates of the axis.
+    fn index_for_texture(&self, corner: Corner) -> usize {
+        let m = self.nv() - self.axial_count();
+        let n = m * (self.nu() + 1);
+        match corner {
+            Corner::Side(j, _) if self.is_axial(j) => n + self.apex_index(j),
+            Corner::Side(j, i) => ((j - self.axial_offset()) * (self.nu() + 1)) + i,
+            Corner::Cap(cap, j) => {
+                let k = if cap == Cap::Start { 0 } else { m };
+                n + self.axial_count() + k + (j - self.axial_offset())
+            }
+            Corner::Axis(_, j) => n + self.axial_count() + (2 * m) + j,
+        }
+    }
+
+    fn map_texture_index(&self, index: usize) -> Corner {
+        let m = self.nv() - self.axial_count();
+        let n = m * (self.nu() + 1);
+        if index < n {
+            Corner::Side(
+                (index / (self.nu() + 1)) + self.axial_offset(),
+                index % (self.nu() + 1),
+            )
+        }
+        else if index < n + self.axial_count() {
+            Corner::Side(self.map_apex_index(index - n), 0)
+        }
+        else if index < n + self.axial_count() + (2 * m) {
+            let index = index - n - self.axial_count();
+            let cap = if index < m { Cap::Start } else { Cap::End };
+            Corner::Cap(cap, (index % m) + self.axial_offset())
+        }
+        else {
+            Corner::Axis(Cap::Start, index - n - self.axial_count() - (2 * m))
+        }
+    }
+}
+
+impl PolygonGenerator for Lathe {
+    fn polygon_count(&self) -> usize {
+        let n = self.nu() * (self.nv() - 1);
+        if self.is_capped() {
+            n + (2 * (self.nv() - 1))
+        }
+        else {
+            n
+        }
+    }
+}
+
+impl<S> AttributeGenerator<Normal<S>> for Lathe
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = ();
+}
+
+impl<S> AttributeVertexGenerator<Normal<S>> for Lathe
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Unit<Vector<S>>;
+
+    fn vertex_count(&self) -> usize {
+        let n = ((self.nv() - self.axial_count()) * self.ring_count()) + self.axial_count();
+        if self.is_capped() {
+            n + 2
+        }
+        else {
+            n
+        }
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        self.normal_from::<S>(self.map_normal_index(index))
+    }
+}
+
+impl<S> AttributePolygonGenerator<Normal<S>> for Lathe
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = BoundedPolygon<Unit<Vector<S>>>;
+
+    fn polygon_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        self.polygon_corners(index)
+            .map(|corner| self.normal_from::<S>(corner))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Normal<S>> for Lathe {
+    type Output = BoundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        self.polygon_corners(index)
+            .map(|corner| self.index_for_normal(corner))
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for Lathe
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = ();
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for Lathe
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        let n = (self.nv() - self.axial_count()) * self.ring_count();
+        if self.is_capped() {
+            n + self.nv()
+        }
+        else {
+            n + self.axial_count()
+        }
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        self.position_from(self.map_position_index(index))
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for Lathe
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = BoundedPolygon<S>;
+
+    fn polygon_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        self.polygon_corners(index)
+            .map(|corner| self.position_from(corner))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Position<S>> for Lathe {
+    type Output = BoundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        self.polygon_corners(index)
+            .map(|corner| self.index_for_position(corner))
+    }
+}
+
+impl<S> AttributeGenerator<UvMap<S>> for Lathe
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type State = ();
+}
+
+impl<S> AttributeVertexGenerator<UvMap<S>> for Lathe
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        let m = self.nv() - self.axial_count();
+        let n = (m * (self.nu() + 1)) + self.axial_count();
+        if self.is_capped() {
+            n + (2 * m) + self.nv()
+        }
+        else {
+            n
+        }
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        self.texture_from(self.map_texture_index(index))
+    }
+}
+
+impl<S> AttributePolygonGenerator<UvMap<S>> for Lathe
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type Output = BoundedPolygon<S>;
+
+    fn polygon_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        self.polygon_corners(index)
+            .map(|corner| self.texture_from(corner))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<UvMap<S>> for Lathe {
+    type Output = BoundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        self.polygon_corners(index)
+            .map(|corner| self.index_for_texture(corner))
+    }
+}
+
+impl Generator for Lathe {}
+
+fn into_scalar<T, S>(value: T) -> Scalar<S>
+where
+    T: ToPrimitive,
+    S: EuclideanSpace,
+{
+    <Scalar<S> as NumCast>::from(value).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::N64;
+    use nalgebra::{Point2, Point3, Vector3};
+    use std::collections::HashSet;
+    use std::f64::consts::PI;
+
+    use crate::graph::MeshGraph;
+    use crate::index::HashIndexer;
+    use crate::prelude::*;
+    use crate::primitive::generate::{Normal, PolygonGenerator, Position};
+    use crate::primitive::lathe::{Lathe, LatheError, UvMap};
+    use crate::primitive::sphere::UvSphere;
+    use crate::primitive::UnboundedPolygon;
+
+    type E2 = Point2<f64>;
+    type E3 = Point3<f64>;
+
+    fn from_lathe(lathe: &Lathe) -> MeshGraph<E3> {
+        MeshGraph::<E3>::from_raw_buffers(
+            lathe.indexing_polygons::<Position>(),
+            lathe.vertices::<Position<E3>>(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn cylinder() {
+        let n = 8;
+        let lathe = Lathe::new(vec![(1.0, -1.0), (1.0, 1.0)], n).unwrap();
+        let graph = from_lathe(&lathe);
+        assert_eq!(n, graph.face_count());
+        assert_eq!(2 * n, graph.vertex_count());
+        assert_eq!(
+            2 * n,
+            graph.arcs().filter(|arc| arc.is_boundary_arc()).count()
+        );
+        assert!(graph.vertices().all(|vertex| {
+            let position = vertex.position();
+            (Vector3::new(position.x, 0.0, position.z).norm() - 1.0).abs() < 1e-9
+        }));
+        // Normals are horizontal and face away from the axis.
+        for polygon in lathe.polygons::<Normal<E3>>() {
+            assert!(polygon
+                .into_iter()
+                .all(|normal| normal.get().y.abs() < 1e-9));
+        }
+        assert!(lathe
+            .polygons::<Position<E3>>()
+            .zip(lathe.polygons::<Normal<E3>>())
+            .all(|(positions, normals)| positions
+                .into_iter()
+                .zip(normals)
+                .all(|(position, normal)| position.coords.dot(normal.get()) > 0.0)));
+    }
+
+    #[test]
+    fn sphere() {
+        let (nu, nv) = (16, 8);
+        let profile = (0..=nv).map(|k| {
+            let phi = PI * (k as f64 / nv as f64);
+            let r = if k == 0 || k == nv { 0.0 } else { phi.sin() };
+            (r, -phi.cos())
+        });
+        let lathe = Lathe::new(profile, nu).unwrap();
+        let sphere = UvSphere::new(nu, nv);
+        assert_eq!(
+            sphere.vertices::<Position<E3>>().count(),
+            lathe.vertices::<Position<E3>>().count()
+        );
+        assert_eq!(sphere.polygon_count(), lathe.polygon_count());
+
+        let graph = from_lathe(&lathe);
+        assert_eq!(((nv - 1) * nu) + 2, graph.vertex_count());
+        assert_eq!(nu * nv, graph.face_count());
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+        // Polygons face away from the axis, so the volume is positive.
+        let volume = graph.volume().unwrap();
+        assert!(volume > 0.0 && volume < (4.0 / 3.0) * PI);
+    }
+
+    #[test]
+    fn partial_revolution() {
+        let n = 8;
+        let lathe = Lathe::new(vec![(1.0, -1.0), (1.0, 1.0)], n)
+            .unwrap()
+            .angle(PI);
+        let graph = from_lathe(&lathe);
+        assert_eq!(n, graph.face_count());
+        assert_eq!(2 * (n + 1), graph.vertex_count());
+
+        // Caps close the ends of the revolution, but not the ends of the
+        // profile, so the profile begins and ends on the axis.
+        let lathe = Lathe::new(vec![(0.0, -1.0), (1.0, -1.0), (1.0, 1.0), (0.0, 1.0)], n)
+            .unwrap()
+            .angle(PI)
+            .caps(true);
+        let graph = from_lathe(&lathe);
+        assert_eq!((3 * n) + 6, graph.face_count());
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+        let volume = graph.volume().unwrap();
+        assert!(volume > 0.0 && volume < PI);
+
+        // Caps collapse to triangles at an apex.
+        let lathe = Lathe::new(vec![(0.0, 0.0), (1.0, 1.0), (1.0, 2.0), (0.0, 2.0)], n)
+            .unwrap()
+            .angle(PI / 2.0)
+            .caps(true);
+        let graph = from_lathe(&lathe);
+        assert_eq!((3 * n) + 6, graph.face_count());
+        assert_eq!(4, graph.faces_with_arity(3).count() - (2 * n));
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+        assert!(graph.volume().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn texture_coordinates() {
+        for lathe in [
+            Lathe::new(vec![(1.0, -1.0), (1.0, 1.0)], 8).unwrap(),
+            Lathe::new(vec![(0.0, 0.0), (1.0, 1.0), (0.5, 2.0), (0.0, 3.0)], 8)
+                .unwrap()
+                .angle(PI)
+                .caps(true),
+        ] {
+            let uvs = lathe.vertices::<UvMap<E2>>().collect::<Vec<_>>();
+            assert!(uvs
+                .iter()
+                .all(|uv| (0.0..=1.0).contains(&uv.x) && (0.0..=1.0).contains(&uv.y)));
+
+            // Indexing the zipped position and texture data yields the same
+            // vertices as indexing the texture data alone.
+            let (_, vertices) =
+                lathe
+                    .polygons_with_texture::<Point3<N64>, Point2<N64>>()
+                    .index_vertices::<UnboundedPolygon<usize>, _>(HashIndexer::default());
+            assert_eq!(uvs.len(), vertices.len());
+            assert_eq!(
+                uvs.len(),
+                lathe
+                    .indexing_polygons::<UvMap>()
+                    .vertices()
+                    .collect::<HashSet<_>>()
+                    .len()
+            );
+        }
+    }
+
+    #[test]
+    fn profile_on_axis() {
+        assert_eq!(
+            LatheError::ProfileOnAxis { index: 1 },
+            Lathe::new(vec![(1.0, 0.0), (0.0, 1.0), (1.0, 2.0)], 8).unwrap_err()
+        );
+        assert_eq!(
+            LatheError::ProfileOnAxis { index: 0 },
+            Lathe::new(vec![(-1.0, 0.0), (1.0, 1.0)], 8).unwrap_err()
+        );
+        assert_eq!(
+            LatheError::ProfileInsufficient,
+            Lathe::new(vec![(0.0, 0.0), (0.0, 1.0)], 8).unwrap_err()
+        );
+        assert_eq!(
+            LatheError::ProfileInsufficient,
+            Lathe::new(vec![(1.0, 0.0)], 8).unwrap_err()
+        );
+    }
+}
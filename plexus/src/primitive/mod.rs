@@ -83,6 +83,7 @@
 pub mod cube;
 pub mod decompose;
 pub mod generate;
+pub mod lathe;
 pub mod sphere;
 
 use arrayvec::ArrayVec;
@@ -92,6 +93,7 @@ use itertools::structs::Zip as OuterZip; // Avoid collision with `Zip`.
 use num::{Integer, One, Signed, Unsigned, Zero};
 use smallvec::{smallvec, SmallVec};
 use std::array;
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::fmt::{self, Debug, Formatter};
 use std::marker::PhantomData;
@@ -99,11 +101,14 @@ use std::ops::{Index, IndexMut};
 use theon::adjunct::{Adjunct, Converged, Extend, Fold, FromItems, IntoItems, Map, ZipMap};
 use theon::ops::Cross;
 use theon::query::{Intersection, Line, LineLine, LinePlane, Plane, Unit};
-use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector, VectorSpace};
+use theon::space::{
+    Basis, EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector, VectorSpace,
+};
 use theon::{AsPosition, AsPositionMut, Position};
 use typenum::{Cmp, Greater, U1, U2, U3};
 
 use crate::constant::{Constant, ToType, TypeOf};
+use crate::geometry;
 use crate::geometry::partition::PointPartition;
 use crate::primitive::decompose::IntoVertices;
 use crate::{DynamicArity, IteratorExt as _, Monomorphic, StaticArity, TryFromIterator};
@@ -410,6 +415,268 @@ where
     }
 }
 
+/// Welds the positions of vertices in an iterator of polygons.
+///
+/// Floating-point error in procedural geometry can produce positions that
+/// should be coincident but differ slightly. Such positions are not merged by
+/// indexers, which compare vertex data exactly, and instead produce disjoint
+/// topology. This adapter replaces each position that is within the given
+/// distance of a position that has already been yielded with that position, so
+/// that coincident vertices are identical when indexed or collected into a
+/// mesh data structure.
+///
+/// Positions are compared against the first position observed within the
+/// given distance. Welding is not transitive, so positions that are separated
+/// by more than the given distance are never welded together. Positions are
+/// unaffected if the distance is not positive.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::Point3;
+/// use plexus::graph::MeshGraph;
+/// use plexus::index::LruIndexer;
+/// use plexus::prelude::*;
+/// use plexus::primitive::generate::Position;
+/// use plexus::primitive::sphere::UvSphere;
+///
+/// type E3 = Point3<f64>;
+///
+/// let graph: MeshGraph<E3> = UvSphere::new(16, 8)
+///     .polygons::<Position<E3>>()
+///     .weld_positions(1e-9)
+///     .collect_with_indexer(LruIndexer::with_capacity(256))
+///     .unwrap();
+/// ```
+pub trait WeldPositions<T>: Sized
+where
+    T: AsPosition,
+    Position<T>: EuclideanSpace,
+{
+    fn weld_positions<U>(self, epsilon: U) -> Weld<Self, T>
+    where
+        U: Into<Scalar<Position<T>>>;
+}
+
+impl<I, T> WeldPositions<T> for I
+where
+    I: Iterator,
+    I::Item: Map<T, Output = I::Item> + Topological<Vertex = T>,
+    T: AsPositionMut,
+    Position<T>: EuclideanSpace,
+    Vector<Position<T>>: Basis,
+{
+    fn weld_positions<U>(self, epsilon: U) -> Weld<Self, T>
+    where
+        U: Into<Scalar<Position<T>>>,
+    {
+        Weld::new(self, epsilon.into())
+    }
+}
+
+pub struct Weld<I, T>
+where
+    T: AsPosition,
+    Position<T>: EuclideanSpace,
+{
+    input: I,
+    epsilon: Scalar<Position<T>>,
+    basis: SmallVec<[Vector<Position<T>>; 3]>,
+    cells: HashMap<SmallVec<[i64; 3]>, Vec<Position<T>>>,
+}
+
+impl<I, T> Weld<I, T>
+where
+    T: AsPosition,
+    Position<T>: EuclideanSpace,
+    Vector<Position<T>>: Basis,
+{
+    fn new(input: I, epsilon: Scalar<Position<T>>) -> Self {
+        Weld {
+            input,
+            epsilon,
+            basis: Vector::<Position<T>>::canonical_basis()
+                .into_iter()
+                .collect(),
+            cells: HashMap::new(),
+        }
+    }
+
+    fn weld(&mut self, position: &mut Position<T>) {
+        let epsilon = self.epsilon;
+        if epsilon <= Zero::zero() {
+            return;
+        }
+        let origin = geometry::grid_cell(&self.basis, *position, epsilon);
+        let representative = geometry::neighboring_cells(&origin)
+            .iter()
+            .flat_map(|neighbor| self.cells.get(neighbor).into_iter().flatten())
+            .find(|other| (**other - *position).square_magnitude() <= epsilon * epsilon)
+            .cloned();
+        if let Some(representative) = representative {
+            *position = representative;
+        }
+        else {
+            self.cells.entry(origin).or_default().push(*position);
+        }
+    }
+}
+
+impl<I, T> Iterator for Weld<I, T>
+where
+    I: Iterator,
+    I::Item: Map<T, Output = I::Item> + Topological<Vertex = T>,
+    T: AsPositionMut,
+    Position<T>: EuclideanSpace,
+    Vector<Position<T>>: Basis,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input.next().map(|topology| {
+            topology.map(|mut vertex| {
+                self.weld(vertex.as_position_mut());
+                vertex
+            })
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.input.size_hint()
+    }
+}
+
+/// Snaps the positions of vertices in an iterator of polygons to a grid.
+///
+/// Each coordinate of a position is rounded to the nearest multiple of the
+/// given resolution, so positions are effectively represented with fixed-point
+/// coordinates. Unlike [`WeldPositions`], snapping does not depend on the order
+/// in which positions are observed and positions that differ by only a few
+/// ULPs are (almost always) made identical, so that they can be merged exactly
+/// by indexers like [`HashIndexer`]. However, positions that straddle the
+/// boundary between grid cells are snapped apart, regardless of how near they
+/// are to one another. Positions are unaffected if the resolution is not
+/// positive.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate decorum;
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use decorum::R64;
+/// use nalgebra::Point3;
+/// use plexus::graph::MeshGraph;
+/// use plexus::prelude::*;
+/// use plexus::primitive::generate::Position;
+/// use plexus::primitive::sphere::UvSphere;
+///
+/// type E3 = Point3<R64>;
+///
+/// let graph: MeshGraph<Point3<f64>> = UvSphere::new(16, 8)
+///     .polygons::<Position<E3>>()
+///     .snap_positions(1e-9)
+///     .collect();
+/// ```
+///
+/// [`HashIndexer`]: crate::index::HashIndexer
+/// [`WeldPositions`]: crate::primitive::WeldPositions
+pub trait SnapPositions<T>: Sized
+where
+    T: AsPosition,
+    Position<T>: EuclideanSpace,
+{
+    fn snap_positions<U>(self, resolution: U) -> Snap<Self, T>
+    where
+        U: Into<Scalar<Position<T>>>;
+}
+
+impl<I, T> SnapPositions<T> for I
+where
+    I: Iterator,
+    I::Item: Map<T, Output = I::Item> + Topological<Vertex = T>,
+    T: AsPositionMut,
+    Position<T>: EuclideanSpace,
+    Vector<Position<T>>: Basis,
+{
+    fn snap_positions<U>(self, resolution: U) -> Snap<Self, T>
+    where
+        U: Into<Scalar<Position<T>>>,
+    {
+        Snap::new(self, resolution.into())
+    }
+}
+
+pub struct Snap<I, T>
+where
+    T: AsPosition,
+    Position<T>: EuclideanSpace,
+{
+    input: I,
+    resolution: Scalar<Position<T>>,
+    basis: SmallVec<[Vector<Position<T>>; 3]>,
+}
+
+impl<I, T> Snap<I, T>
+where
+    T: AsPosition,
+    Position<T>: EuclideanSpace,
+    Vector<Position<T>>: Basis,
+{
+    fn new(input: I, resolution: Scalar<Position<T>>) -> Self {
+        Snap {
+            input,
+            resolution,
+            basis: Vector::<Position<T>>::canonical_basis()
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    fn snap(&self, position: &mut Position<T>) {
+        let resolution = self.resolution;
+        if resolution <= Zero::zero() {
+            return;
+        }
+        let coordinates = position.into_coordinates();
+        *position = self
+            .basis
+            .iter()
+            .fold(Position::<T>::origin(), |snapped, axis| {
+                let coordinate = (coordinates.dot(*axis) / resolution).round() * resolution;
+                snapped + (*axis * coordinate)
+            });
+    }
+}
+
+impl<I, T> Iterator for Snap<I, T>
+where
+    I: Iterator,
+    I::Item: Map<T, Output = I::Item> + Topological<Vertex = T>,
+    T: AsPositionMut,
+    Position<T>: EuclideanSpace,
+    Vector<Position<T>>: Basis,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input.next().map(|topology| {
+            topology.map(|mut vertex| {
+                self.snap(vertex.as_position_mut());
+                vertex
+            })
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.input.size_hint()
+    }
+}
+
 /// Monomorphic $n$-gon.
 ///
 /// `NGon` represents a polygonal structure as an array. Each array element
@@ -1318,13 +1585,19 @@ where
 
 #[cfg(test)]
 mod tests {
-    use nalgebra::Point2;
-    use theon::adjunct::Converged;
+    use nalgebra::{Point2, Point3, Vector3};
+    use theon::adjunct::{Converged, Map};
     use theon::space::EuclideanSpace;
 
+    use crate::graph::MeshGraph;
+    use crate::index::LruIndexer;
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
     use crate::primitive::{NGon, Polygonal, Tetragon, Trigon};
 
     type E2 = Point2<f64>;
+    type E3 = Point3<f64>;
 
     #[test]
     fn convexity() {
@@ -1367,4 +1640,65 @@ mod tests {
         ]);
         assert!(!pentagon.is_convex());
     }
+
+    #[test]
+    fn weld_positions() {
+        // Offset the positions of each face of a cube by a different amount so
+        // that the vertices shared by faces are no longer coincident.
+        let cube = Cube::new();
+        let perturbed = || {
+            cube.polygons::<Position<E3>>()
+                .enumerate()
+                .map(|(n, polygon)| {
+                    polygon.map(|position| position + Vector3::repeat(n as f64 * 1e-9))
+                })
+        };
+
+        let graph: MeshGraph<E3> = perturbed()
+            .collect_with_indexer(LruIndexer::with_capacity(64))
+            .unwrap();
+        assert_eq!(24, graph.vertex_count());
+
+        let graph: MeshGraph<E3> = perturbed()
+            .weld_positions(1e-6)
+            .collect_with_indexer(LruIndexer::with_capacity(64))
+            .unwrap();
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(6, graph.face_count());
+        assert!(!graph.arcs().any(|arc| arc.is_boundary_arc()));
+
+        // Positions are not welded beyond the given distance.
+        let graph: MeshGraph<E3> = perturbed()
+            .weld_positions(1e-12)
+            .collect_with_indexer(LruIndexer::with_capacity(64))
+            .unwrap();
+        assert_eq!(24, graph.vertex_count());
+    }
+
+    #[test]
+    fn snap_positions() {
+        let cube = Cube::new();
+        let perturbed = || {
+            cube.polygons::<Position<E3>>()
+                .enumerate()
+                .map(|(n, polygon)| {
+                    polygon.map(|position| position + Vector3::repeat(n as f64 * 1e-9))
+                })
+        };
+
+        let graph: MeshGraph<E3> = perturbed()
+            .snap_positions(1e-6)
+            .collect_with_indexer(LruIndexer::with_capacity(64))
+            .unwrap();
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(6, graph.face_count());
+        assert!(!graph.arcs().any(|arc| arc.is_boundary_arc()));
+
+        // Positions are not snapped together beyond the given resolution.
+        let graph: MeshGraph<E3> = perturbed()
+            .snap_positions(1e-12)
+            .collect_with_indexer(LruIndexer::with_capacity(64))
+            .unwrap();
+        assert_eq!(24, graph.vertex_count());
+    }
 }
@@ -24,17 +24,43 @@
 //!     .collect_with_indexer::<MeshGraph<E3>, _>(HashIndexer::default())
 //!     .unwrap();
 //! ```
+//!
+//! Generating a buffer from the positional and texture data of a $uv$-sphere
+//! with duplicated pole vertices.
+//!
+//! ```rust
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::N64;
+//! use nalgebra::{Point2, Point3};
+//! use plexus::buffer::MeshBuffer3;
+//! use plexus::prelude::*;
+//! use plexus::primitive::sphere::{PoleMode, UvSphere};
+//!
+//! type E2 = Point2<N64>;
+//! type E3 = Point3<N64>;
+//!
+//! let buffer: MeshBuffer3<usize, (E3, E2)> = UvSphere::new(16, 8)
+//!     .radius(2.0)
+//!     .pole_mode(PoleMode::Duplicated)
+//!     .polygons_with_texture::<E3, E2>()
+//!     .triangulate()
+//!     .collect();
+//! ```
 
 use decorum::Real;
 use num::{NumCast, One, ToPrimitive};
 use std::cmp;
+use std::marker::PhantomData;
 use theon::adjunct::Map;
 use theon::query::Unit;
 use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
-use typenum::U3;
+use typenum::{U2, U3};
 
 use crate::primitive::generate::{
-    AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
+    Attribute, AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
     IndexingPolygonGenerator, Normal, PolygonGenerator, Position,
 };
 use crate::primitive::{BoundedPolygon, Tetragon, Trigon};
@@ -77,10 +103,55 @@ where
     }
 }
 
+/// Meta-attribute for texture coordinates.
+///
+/// Describes the $(u, v)$ texture coordinates of vertices in a
+/// [$uv$-sphere][`UvSphere`]. Both coordinates are in the interval $[0, 1]$,
+/// where $u$ follows the meridians around the sphere and $v$ follows the
+/// parallels from pole to pole. The generated data is derived from the type
+/// parameter `S`, which typically requires [`EuclideanSpace`] in
+/// $\Reals^2$.
+///
+/// Unlike positions, texture coordinates do not wrap: the first meridian is
+/// duplicated at $u = 1$ to form a seam.
+///
+/// [`EuclideanSpace`]: theon::space::EuclideanSpace
+/// [`UvSphere`]: crate::primitive::sphere::UvSphere
+pub struct UvMap<S = ()> {
+    phantom: PhantomData<fn() -> S>,
+}
+
+impl<S> Attribute for UvMap<S> {}
+
+/// Topology of the poles of a [$uv$-sphere][`UvSphere`].
+///
+/// The pole mode determines the vertices of the triangles at the poles and is
+/// used consistently by all attributes of a sphere, so attributes can be
+/// zipped and indexed together.
+///
+/// [`UvSphere`]: crate::primitive::sphere::UvSphere
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum PoleMode {
+    /// Each pole is a single vertex shared by a fan of triangles.
+    ///
+    /// The texture coordinates of a shared pole vertex pinch to a single
+    /// point.
+    #[default]
+    Shared,
+    /// Each triangle at a pole has its own pole vertex.
+    ///
+    /// Pole vertices share the same position and normal, but the $u$ texture
+    /// coordinate of each is the average of the $u$ coordinates of the other
+    /// vertices in its triangle.
+    Duplicated,
+}
+
 #[derive(Clone, Copy)]
 pub struct UvSphere {
     nu: usize, // Meridians.
     nv: usize, // Parallels.
+    radius: f64,
+    poles: PoleMode,
 }
 
 impl UvSphere {
@@ -88,9 +159,57 @@ impl UvSphere {
         UvSphere {
             nu: cmp::max(3, nu),
             nv: cmp::max(2, nv),
+            radius: 1.0,
+            poles: PoleMode::default(),
         }
     }
 
+    /// Sets the radius of the sphere.
+    ///
+    /// The radius is one by default. The radius of any [`Bounds`] used to
+    /// generate positions is scaled by this radius.
+    ///
+    /// [`Bounds`]: crate::primitive::sphere::Bounds
+    pub fn radius(mut self, radius: f64) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Sets the topology of the poles of the sphere.
+    ///
+    /// Poles are shared by default. See [`PoleMode`].
+    ///
+    /// [`PoleMode`]: crate::primitive::sphere::PoleMode
+    pub fn pole_mode(mut self, poles: PoleMode) -> Self {
+        self.poles = poles;
+        self
+    }
+
+    /// Gets an iterator over the set of polygons with positional and texture
+    /// data.
+    ///
+    /// Each vertex is a pair of a position and texture coordinates. See
+    /// [`UvMap`].
+    ///
+    /// [`UvMap`]: crate::primitive::sphere::UvMap
+    pub fn polygons_with_texture<'a, S, T>(
+        &'a self,
+    ) -> impl 'a + Iterator<Item = BoundedPolygon<(S, T)>>
+    where
+        S: 'a + EuclideanSpace + FiniteDimensional<N = U3>,
+        T: 'a + EuclideanSpace + FiniteDimensional<N = U2>,
+    {
+        let state = Bounds::<S>::default();
+        (0..self.polygon_count()).map(move |index| {
+            self.polygon_grid(index).map(|(u, v)| {
+                (
+                    self.vertex_with_position_from(&state, u, v),
+                    self.vertex_with_texture_from(u, v),
+                )
+            })
+        })
+    }
+
     fn vertex_with_position_from<S>(
         &self,
         state: &<Self as AttributeGenerator<Position<S>>>::State,
@@ -101,32 +220,143 @@ impl UvSphere {
         Self: AttributeGenerator<Position<S>, State = Bounds<S>>,
         S: EuclideanSpace + FiniteDimensional<N = U3>,
     {
+        // Prevent floating point rounding errors by wrapping `u` and
+        // normalizing it to zero at the poles, where it is meaningless. This
+        // is important for indexing geometry, because small differences in the
+        // computation of spatial vertices will produce redundant output
+        // vertices. There should be exactly `(nv - 1) * nu + 2` unique values
+        // of `(u, v)` used to generate positions.
+        let u = if v == 0 || v == self.nv {
+            0
+        }
+        else {
+            u % self.nu
+        };
         let one = Scalar::<S>::one();
         let pi = Real::PI;
+        let radius = state.radius * into_scalar::<_, S>(self.radius);
         let u = (into_scalar::<_, S>(u) / into_scalar::<_, S>(self.nu)) * pi * (one + one);
         let v = (into_scalar::<_, S>(v) / into_scalar::<_, S>(self.nv)) * pi;
         S::from_xyz(
-            state.radius * u.cos() * v.sin(),
-            state.radius * u.sin() * v.sin(),
-            state.radius * v.cos(),
+            radius * u.cos() * v.sin(),
+            radius * u.sin() * v.sin(),
+            radius * v.cos(),
         )
     }
 
+    fn vertex_with_texture_from<S>(&self, u: usize, v: usize) -> S
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U2>,
+    {
+        let one = Scalar::<S>::one();
+        let half = one / (one + one);
+        let u = if v == 0 || v == self.nv {
+            match self.poles {
+                PoleMode::Shared => half,
+                PoleMode::Duplicated => {
+                    (into_scalar::<_, S>(u) + half) / into_scalar::<_, S>(self.nu)
+                }
+            }
+        }
+        else {
+            into_scalar::<_, S>(u) / into_scalar::<_, S>(self.nu)
+        };
+        let v = into_scalar::<_, S>(v) / into_scalar::<_, S>(self.nv);
+        S::from_xy(u, v)
+    }
+
+    // Gets the number of vertices at each pole.
+    fn pole_count(&self) -> usize {
+        match self.poles {
+            PoleMode::Shared => 1,
+            PoleMode::Duplicated => self.nu,
+        }
+    }
+
+    fn pole_index(&self, u: usize) -> usize {
+        match self.poles {
+            PoleMode::Shared => 0,
+            PoleMode::Duplicated => u % self.nu,
+        }
+    }
+
     fn index_for_position(&self, u: usize, v: usize) -> usize {
+        let n = self.pole_count();
         if v == 0 {
-            0
+            self.pole_index(u)
         }
         else if v == self.nv {
-            ((self.nv - 1) * self.nu) + 1
+            n + ((self.nv - 1) * self.nu) + self.pole_index(u)
+        }
+        else {
+            n + ((v - 1) * self.nu) + (u % self.nu)
+        }
+    }
+
+    fn index_for_texture(&self, u: usize, v: usize) -> usize {
+        let n = self.pole_count();
+        if v == 0 {
+            self.pole_index(u)
+        }
+        else if v == self.nv {
+            n + ((self.nv - 1) * (self.nu + 1)) + self.pole_index(u)
+        }
+        else {
+            n + ((v - 1) * (self.nu + 1)) + u
+        }
+    }
+
+    fn map_position_index(&self, index: usize) -> (usize, usize) {
+        let n = self.pole_count();
+        let m = (self.nv - 1) * self.nu;
+        if index < n {
+            (index, 0)
+        }
+        else if index < n + m {
+            let index = index - n;
+            (index % self.nu, (index / self.nu) + 1)
         }
         else {
-            ((v - 1) * self.nu) + (u % self.nu) + 1
+            (index - n - m, self.nv)
+        }
+    }
+
+    fn map_texture_index(&self, index: usize) -> (usize, usize) {
+        let n = self.pole_count();
+        let m = (self.nv - 1) * (self.nu + 1);
+        if index < n {
+            (index, 0)
+        }
+        else if index < n + m {
+            let index = index - n;
+            (index % (self.nu + 1), (index / (self.nu + 1)) + 1)
+        }
+        else {
+            (index - n - m, self.nv)
         }
     }
 
     fn map_polygon_index(&self, index: usize) -> (usize, usize) {
         (index % self.nu, index / self.nu)
     }
+
+    // Gets the `(u, v)` coordinates of the vertices of a polygon. `u` is not
+    // wrapped, so it is in the interval `[0, nu]`. At the poles, `u` is the
+    // meridian of the polygon. Triangles are emitted at the poles, otherwise
+    // quadrilaterals.
+    fn polygon_grid(&self, index: usize) -> BoundedPolygon<(usize, usize)> {
+        let (u, v) = self.map_polygon_index(index);
+        let (p, q) = (u + 1, v + 1);
+        if v == 0 {
+            Trigon::new((u, v), (u, q), (p, q)).into()
+        }
+        else if v == self.nv - 1 {
+            Trigon::new((u, q), (p, v), (u, v)).into()
+        }
+        else {
+            Tetragon::new((u, v), (u, q), (p, q), (p, v)).into()
+        }
+    }
 }
 
 impl Default for UvSphere {
@@ -155,7 +385,7 @@ where
     type Output = Unit<Vector<S>>;
 
     fn vertex_count(&self) -> usize {
-        (self.nv - 1) * self.nu + 2
+        AttributeVertexGenerator::<Position<S>>::vertex_count(self)
     }
 
     fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
@@ -201,21 +431,12 @@ where
     type Output = S;
 
     fn vertex_count(&self) -> usize {
-        (self.nv - 1) * self.nu + 2
+        ((self.nv - 1) * self.nu) + (2 * self.pole_count())
     }
 
     fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
-        let count = AttributeVertexGenerator::<Position<S>>::vertex_count(self);
-        if index == 0 {
-            self.vertex_with_position_from::<S>(state, 0, 0)
-        }
-        else if index == (count - 1) {
-            self.vertex_with_position_from::<S>(state, 0, self.nv)
-        }
-        else {
-            let index = index - 1;
-            self.vertex_with_position_from::<S>(state, index % self.nu, (index / self.nu) + 1)
-        }
+        let (u, v) = self.map_position_index(index);
+        self.vertex_with_position_from::<S>(state, u, v)
     }
 }
 
@@ -226,54 +447,8 @@ where
     type Output = BoundedPolygon<S>;
 
     fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
-        // Prevent floating point rounding errors by wrapping the incremented
-        // values for `(u, v)` into `(p, q)`. This is important for indexing
-        // geometry, because small differences in the computation of spatial
-        // vertices will produce redundant output vertices. There should be
-        // exactly `(nv - 1) * nu + 2` unique values of `(u, v)` used to
-        // generate positions.
-        //
-        // There are two important observations:
-        //
-        //   1. `u` must wrap, but `v` need not. There are `nu` meridians of
-        //      points and polygons, but there are `nv` parallels of polygons
-        //      and `nv + 1` parallels of points.
-        //   2. `u`, which represents a meridian, is meaningless at the poles,
-        //      and can be normalized to zero.
-        let (u, v) = self.map_polygon_index(index);
-        let (p, q) = ((u + 1) % self.nu, v + 1);
-
-        // Generate the vertices at the requested meridian and parallel. The
-        // lower bound of `(u, v)` is always used, so compute that in advance
-        // (`lower`). Emit triangles at the poles, otherwise quadrilaterals.
-        let lower = self.vertex_with_position_from(state, u, v);
-        if v == 0 {
-            Trigon::new(
-                lower,
-                self.vertex_with_position_from(state, u, q),
-                self.vertex_with_position_from(state, p, q),
-            )
-            .into()
-        }
-        else if v == self.nv - 1 {
-            Trigon::new(
-                // Normalize `u` at the pole, using `(0, nv)` in place of
-                // `(p, q)`.
-                self.vertex_with_position_from(state, 0, self.nv),
-                self.vertex_with_position_from(state, p, v),
-                lower,
-            )
-            .into()
-        }
-        else {
-            Tetragon::new(
-                lower,
-                self.vertex_with_position_from(state, u, q),
-                self.vertex_with_position_from(state, p, q),
-                self.vertex_with_position_from(state, p, v),
-            )
-            .into()
-        }
+        self.polygon_grid(index)
+            .map(|(u, v)| self.vertex_with_position_from(state, u, v))
     }
 }
 
@@ -281,26 +456,52 @@ impl<S> IndexingPolygonGenerator<Position<S>> for UvSphere {
     type Output = BoundedPolygon<usize>;
 
     fn indexing_polygon(&self, index: usize) -> Self::Output {
-        let (u, v) = self.map_polygon_index(index);
-        let (p, q) = (u + 1, v + 1);
+        self.polygon_grid(index)
+            .map(|(u, v)| self.index_for_position(u, v))
+    }
+}
 
-        let low = self.index_for_position(u, v);
-        let high = self.index_for_position(p, q);
-        if v == 0 {
-            Trigon::new(low, self.index_for_position(u, q), high).into()
-        }
-        else if v == self.nv - 1 {
-            Trigon::new(high, self.index_for_position(p, v), low).into()
-        }
-        else {
-            Tetragon::new(
-                low,
-                self.index_for_position(u, q),
-                high,
-                self.index_for_position(p, v),
-            )
-            .into()
-        }
+impl<S> AttributeGenerator<UvMap<S>> for UvSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type State = ();
+}
+
+impl<S> AttributeVertexGenerator<UvMap<S>> for UvSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        ((self.nv - 1) * (self.nu + 1)) + (2 * self.pole_count())
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        let (u, v) = self.map_texture_index(index);
+        self.vertex_with_texture_from(u, v)
+    }
+}
+
+impl<S> AttributePolygonGenerator<UvMap<S>> for UvSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type Output = BoundedPolygon<S>;
+
+    fn polygon_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        self.polygon_grid(index)
+            .map(|(u, v)| self.vertex_with_texture_from(u, v))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<UvMap<S>> for UvSphere {
+    type Output = BoundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        self.polygon_grid(index)
+            .map(|(u, v)| self.index_for_texture(u, v))
     }
 }
 
@@ -316,13 +517,18 @@ where
 
 #[cfg(test)]
 mod tests {
-    use nalgebra::Point3;
-    use std::collections::BTreeSet;
+    use decorum::N64;
+    use nalgebra::{Point2, Point3};
+    use std::collections::{BTreeSet, HashSet};
 
+    use crate::graph::MeshGraph;
+    use crate::index::HashIndexer;
     use crate::prelude::*;
     use crate::primitive::generate::Position;
-    use crate::primitive::sphere::UvSphere;
+    use crate::primitive::sphere::{PoleMode, UvMap, UvSphere};
+    use crate::primitive::UnboundedPolygon;
 
+    type E2 = Point2<f64>;
     type E3 = Point3<f64>;
 
     #[test]
@@ -357,4 +563,74 @@ mod tests {
                 .len()
         )
     }
+
+    #[test]
+    fn radius() {
+        assert!(UvSphere::new(8, 4)
+            .radius(2.0)
+            .vertices::<Position<E3>>()
+            .all(|position| (position.coords.norm() - 2.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn texture_coordinates() {
+        let (nu, nv) = (8, 4);
+        for (poles, n) in [(PoleMode::Shared, 1), (PoleMode::Duplicated, nu)] {
+            let sphere = UvSphere::new(nu, nv).pole_mode(poles);
+            let uvs = sphere.vertices::<UvMap<E2>>().collect::<Vec<_>>();
+            assert_eq!(((nv - 1) * (nu + 1)) + (2 * n), uvs.len());
+            assert!(uvs
+                .iter()
+                .all(|uv| (0.0..=1.0).contains(&uv.x) && (0.0..=1.0).contains(&uv.y)));
+            // `v` spans the interval `[0, 1]` from pole to pole.
+            assert_eq!(n, uvs.iter().filter(|uv| uv.y == 0.0).count());
+            assert_eq!(n, uvs.iter().filter(|uv| uv.y == 1.0).count());
+            // Only the seam column is duplicated at `u = 1`.
+            assert_eq!(nv - 1, uvs.iter().filter(|uv| uv.x == 1.0).count());
+
+            // Indexing the zipped position and texture data yields the same
+            // vertices as indexing the texture data alone.
+            let (_, vertices) =
+                sphere
+                    .polygons_with_texture::<Point3<N64>, Point2<N64>>()
+                    .index_vertices::<UnboundedPolygon<usize>, _>(HashIndexer::default());
+            assert_eq!(uvs.len(), vertices.len());
+            assert_eq!(
+                uvs.len(),
+                sphere
+                    .indexing_polygons::<UvMap>()
+                    .vertices()
+                    .collect::<HashSet<_>>()
+                    .len()
+            );
+        }
+    }
+
+    #[test]
+    fn pole_modes() {
+        let (nu, nv) = (8, 4);
+        for (poles, n) in [(PoleMode::Shared, 1), (PoleMode::Duplicated, nu)] {
+            let sphere = UvSphere::new(nu, nv).pole_mode(poles);
+            let graph = MeshGraph::<E3>::from_raw_buffers(
+                sphere.indexing_polygons::<Position>(),
+                sphere.vertices::<Position<E3>>(),
+            )
+            .unwrap();
+            assert_eq!(nu * nv, graph.face_count());
+            assert_eq!(((nv - 1) * nu) + (2 * n), graph.vertex_count());
+            assert_eq!(
+                poles == PoleMode::Duplicated,
+                graph.arcs().any(|arc| arc.is_boundary_arc())
+            );
+
+            // Duplicated pole vertices have the same position, so indexing
+            // positions alone closes the poles.
+            let graph = sphere
+                .polygons::<Position<Point3<N64>>>()
+                .collect_with_indexer::<MeshGraph<Point3<N64>>, _>(HashIndexer::default())
+                .unwrap();
+            assert_eq!(((nv - 1) * nu) + 2, graph.vertex_count());
+            assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+        }
+    }
 }